@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use pilota_build::plugin::SerdePlugin;
+use pilota_build::plugin::{CanonicalEqPlugin, PbCodecPlugin, SerdePlugin};
 
 fn main() {
     let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
@@ -30,6 +30,186 @@ fn main() {
             pilota_build::Output::File(out_dir.join("fieldmask.rs")),
         );
 
+    // For unknown enum variant
+    let unknown_enum_variant_idl = idl_dir.join("unknown_enum_variant.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .with_unknown_enum_variant(true)
+        .enum_key_policy(pilota_build::EnumKeyPolicy::Drop)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(
+                unknown_enum_variant_idl,
+            )],
+            pilota_build::Output::File(out_dir.join("unknown_enum_variant.rs")),
+        );
+
+    // For default requiredness fields
+    let default_requiredness_idl = idl_dir.join("default_requiredness.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .default_field_encoding(pilota_build::DefaultFieldEncoding::AlwaysWrite)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(
+                default_requiredness_idl,
+            )],
+            pilota_build::Output::File(out_dir.join("default_requiredness.rs")),
+        );
+
+    // For empty structs
+    let empty_idl = idl_dir.join("empty.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(empty_idl)],
+            pilota_build::Output::File(out_dir.join("empty.rs")),
+        );
+
+    // For method options from annotations
+    let method_options_idl = idl_dir.join("method_options.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(method_options_idl)],
+            pilota_build::Output::File(out_dir.join("method_options.rs")),
+        );
+
+    // For crates forbidding unsafe code
+    let forbid_unsafe_idl = idl_dir.join("forbid_unsafe.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .keep_unknown_fields([forbid_unsafe_idl.clone()])
+        .forbid_unsafe_code(true)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(forbid_unsafe_idl)],
+            pilota_build::Output::File(out_dir.join("forbid_unsafe.rs")),
+        );
+
+    // For compressed binary fields
+    let compress_idl = idl_dir.join("compress.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(compress_idl)],
+            pilota_build::Output::File(out_dir.join("compress.rs")),
+        );
+
+    // For checksummed binary fields
+    let checksum_idl = idl_dir.join("checksum.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(checksum_idl)],
+            pilota_build::Output::File(out_dir.join("checksum.rs")),
+        );
+
+    // For lenient decoding of integer fields
+    let lenient_numeric_idl = idl_dir.join("lenient_numeric.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .lenient_numeric_decode(true)
+        .duplicate_field_check(true)
+        .unknown_field_hook(true)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(lenient_numeric_idl)],
+            pilota_build::Output::File(out_dir.join("lenient_numeric.rs")),
+        );
+
+    // For uuid fields as `uuid::Uuid`
+    let typed_uuid_idl = idl_dir.join("typed_uuid.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .typed_uuid(true)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(typed_uuid_idl)],
+            pilota_build::Output::File(out_dir.join("typed_uuid.rs")),
+        );
+
+    // For the encoded size of fixed-size structs
+    let fixed_encoded_size_idl = idl_dir.join("fixed_encoded_size.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .fixed_encoded_size(true)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(fixed_encoded_size_idl)],
+            pilota_build::Output::File(out_dir.join("fixed_encoded_size.rs")),
+        );
+
+    // For the echo server and client
+    let echo_idl = idl_dir.join("echo.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(echo_idl)],
+            pilota_build::Output::File(out_dir.join("echo.rs")),
+        );
+
+    // For the sizes cached within an encode cycle
+    let cached_size_idl = idl_dir.join("cached_size.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .cached_size(true)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(cached_size_idl)],
+            pilota_build::Output::File(out_dir.join("cached_size.rs")),
+        );
+
+    // For the field names written by the SimpleJSON protocol
+    let field_names_idl = idl_dir.join("field_names.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .with_field_names(true)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(field_names_idl)],
+            pilota_build::Output::File(out_dir.join("field_names.rs")),
+        );
+
+    // For raw struct overlays
+    let overlay_idl = idl_dir.join("overlay.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(overlay_idl)],
+            pilota_build::Output::File(out_dir.join("overlay.rs")),
+        );
+
+    // For streaming methods
+    let streaming_idl = idl_dir.join("streaming.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(streaming_idl)],
+            pilota_build::Output::File(out_dir.join("streaming.rs")),
+        );
+
+    // For canonical comparisons
+    let canonical_eq_idl = idl_dir.join("canonical_eq.thrift");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .keep_unknown_fields([canonical_eq_idl.clone()])
+        .plugin(CanonicalEqPlugin)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(canonical_eq_idl)],
+            pilota_build::Output::File(out_dir.join("canonical_eq.rs")),
+        );
+
+    // For thrift structs also encoding the protobuf messages of the same name
+    let pb_codec_idl = idl_dir.join("pb_codec.thrift");
+    let pb_codec_proto = idl_dir.join("pb_codec.proto");
+    pilota_build::Builder::thrift()
+        .ignore_unused(false)
+        .plugin(PbCodecPlugin::new([pb_codec_proto.clone()]))
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(pb_codec_idl)],
+            pilota_build::Output::File(out_dir.join("pb_codec.rs")),
+        );
+    pilota_build::Builder::pb()
+        .ignore_unused(false)
+        .include_dirs(vec![idl_dir.clone()])
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(pb_codec_proto)],
+            pilota_build::Output::File(out_dir.join("pb_codec_pb.rs")),
+        );
+
     // for pilota serde plugin
     let serde_idl = idl_dir.join("serde_pb.proto");
     pilota_build::Builder::pb()
@@ -56,4 +236,15 @@ fn main() {
             )],
             pilota_build::Output::File(out_dir.join("custom_options.rs")),
         );
+
+    // For proto2 required fields
+    let proto2_required_idl = idl_dir.join("proto2_required.proto");
+    pilota_build::Builder::pb()
+        .ignore_unused(false)
+        .include_dirs(vec![proto2_required_idl.parent().unwrap().to_path_buf()])
+        .proto2_required(true)
+        .compile_with_config(
+            vec![pilota_build::IdlService::from_path(proto2_required_idl)],
+            pilota_build::Output::File(out_dir.join("proto2_required.rs")),
+        );
 }
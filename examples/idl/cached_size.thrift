@@ -0,0 +1,20 @@
+struct Leaf {
+    1: required i64 id,
+    2: optional string name,
+}
+
+struct Branch {
+    1: required list<Leaf> leaves,
+    2: optional Leaf first,
+}
+
+struct Tree {
+    1: required Branch root,
+    2: required map<string, Branch> branches,
+}
+
+struct Empty {}
+
+service TreeService {
+    Tree Get(1: i64 id),
+}
@@ -0,0 +1,18 @@
+namespace rs canonical_eq
+
+struct Point {
+    1: required double x,
+    2: required double y,
+}
+
+union Shape {
+    1: Point point,
+    2: list<Point> polygon,
+}
+
+struct Reading {
+    1: required string sensor,
+    2: optional i32 retries = 3,
+    3: required map<string, double> scores,
+    4: optional Shape shape,
+}
@@ -0,0 +1,4 @@
+struct Upload {
+    1: required binary file (pilota.checksum = "crc32c"),
+    2: optional binary archive (pilota.compress = "zstd", pilota.checksum = "crc32c"),
+}
@@ -0,0 +1,8 @@
+namespace rs default_requiredness
+
+struct Point {
+    1: i32 x,
+    2: i32 y = 7,
+    3: string label,
+    4: optional i32 z,
+}
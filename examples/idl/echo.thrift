@@ -0,0 +1,11 @@
+struct EchoRequest {
+    1: required i64 seq,
+    2: required string message,
+    3: optional binary payload,
+}
+
+struct EchoResponse {
+    1: required i64 seq,
+    2: required string message,
+    3: optional binary payload,
+}
@@ -0,0 +1,7 @@
+namespace rs empty
+
+struct Ack {}
+
+service Health {
+    void ping(),
+}
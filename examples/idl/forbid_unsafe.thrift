@@ -0,0 +1,14 @@
+struct Tag {
+    1: required string name,
+}
+
+union Value {
+    1: i64 number,
+    2: string text,
+}
+
+struct Document {
+    1: required list<Tag> tags,
+    2: optional list<list<i32>> matrix,
+    3: optional Value value,
+}
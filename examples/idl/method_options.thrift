@@ -0,0 +1,8 @@
+struct Query {
+    1: required string keyword,
+}
+
+service Search {
+    Query Find(1: Query query) (timeout.ms = "500", retry.max = "2", api.get = "/search"),
+    Query Suggest(1: Query query),
+}
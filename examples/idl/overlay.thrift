@@ -0,0 +1,13 @@
+struct Tick {
+    1: required i64 timestamp,
+    2: required double price,
+    3: required i32 volume,
+    4: required i16 venue,
+    5: required byte side,
+    6: required bool halted,
+} (pilota.overlay = "le")
+
+struct Header {
+    1: required i32 magic,
+    2: required i16 version,
+} (pilota.overlay = "be")
@@ -0,0 +1,8 @@
+struct Item {
+    1: required string name,
+}
+
+service Catalog {
+    Item List(1: Item prefix) (streaming.mode = "server"),
+    Item Echo(1: Item req) (streaming.mode = "bidirectional"),
+}
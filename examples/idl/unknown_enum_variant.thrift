@@ -0,0 +1,21 @@
+enum Status {
+    ACTIVE = 1,
+    INACTIVE = 2,
+}
+
+struct Account {
+    1: required Status status,
+    2: optional list<Status> history,
+    3: optional map<Status, string> notes,
+}
+
+struct AccountV2 {
+    1: required i32 status,
+    2: optional list<i32> history,
+    3: optional map<i32, string> notes,
+}
+
+service AccountService {
+    Account get(1: Account req),
+    AccountV2 get_v2(1: AccountV2 req),
+}
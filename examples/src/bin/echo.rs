@@ -0,0 +1,33 @@
+//! Runs the echo server on a local port and sends it a few requests with
+//! each protocol: `cargo run -p examples --bin echo`.
+
+use examples::echo::{self, Client, EchoRequest};
+use pilota::thrift::codec::{Binary, Compact, FrameProtocol};
+use tokio::net::TcpListener;
+
+async fn run<P: FrameProtocol + Send + 'static>(
+    name: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    tokio::spawn(echo::serve::<P>(listener));
+
+    let mut client = Client::<P>::connect(addr).await?;
+    for seq in 0..3 {
+        let resp = client
+            .echo(EchoRequest {
+                seq,
+                message: format!("hello #{seq}").into(),
+                payload: None,
+            })
+            .await?;
+        println!("{name} {addr}: {} {}", resp.seq, resp.message);
+    }
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    run::<Binary>("binary").await?;
+    run::<Compact>("compact").await
+}
@@ -0,0 +1,81 @@
+//! An echo server and client exchanging the types generated from
+//! `idl/echo.thrift` over tokio TCP, one message per frame of the thrift
+//! framed transport, encoded with the protocol `P`.
+
+use std::{io, net::SocketAddr};
+
+use futures::{SinkExt, StreamExt};
+use pilota::thrift::{
+    codec::{FrameProtocol, FramedCodec},
+    ThriftException,
+};
+use tokio::net::{
+    tcp::{OwnedReadHalf, OwnedWriteHalf},
+    TcpListener, TcpStream,
+};
+use tokio_util::codec::{FramedRead, FramedWrite};
+
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/echo.rs"));
+}
+
+pub use generated::echo::echo::{EchoRequest, EchoResponse};
+
+/// Accepts connections until the listener fails, answering every request
+/// with a response carrying the same fields.
+pub async fn serve<P>(listener: TcpListener) -> io::Result<()>
+where
+    P: FrameProtocol + Send + 'static,
+{
+    loop {
+        let (stream, _) = listener.accept().await?;
+        tokio::spawn(async move {
+            // a peer going away mid-frame only ends its own connection
+            let _ = handle::<P>(stream).await;
+        });
+    }
+}
+
+/// Answers the requests of one connection until the peer closes it.
+pub async fn handle<P: FrameProtocol>(stream: TcpStream) -> Result<(), ThriftException> {
+    let (read, write) = stream.into_split();
+    let mut requests = FramedRead::new(read, FramedCodec::<EchoRequest, P>::new());
+    let mut responses = FramedWrite::new(write, FramedCodec::<EchoResponse, P>::new());
+    while let Some(req) = requests.next().await {
+        let req = req?;
+        responses
+            .send(EchoResponse {
+                seq: req.seq,
+                message: req.message,
+                payload: req.payload,
+            })
+            .await?;
+    }
+    Ok(())
+}
+
+pub struct Client<P> {
+    responses: FramedRead<OwnedReadHalf, FramedCodec<EchoResponse, P>>,
+    requests: FramedWrite<OwnedWriteHalf, FramedCodec<EchoRequest, P>>,
+}
+
+impl<P: FrameProtocol> Client<P> {
+    pub async fn connect(addr: SocketAddr) -> io::Result<Self> {
+        let (read, write) = TcpStream::connect(addr).await?.into_split();
+        Ok(Client {
+            responses: FramedRead::new(read, FramedCodec::new()),
+            requests: FramedWrite::new(write, FramedCodec::new()),
+        })
+    }
+
+    pub async fn echo(&mut self, req: EchoRequest) -> Result<EchoResponse, ThriftException> {
+        self.requests.send(req).await?;
+        self.responses.next().await.unwrap_or_else(|| {
+            Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "connection closed before the response",
+            )
+            .into())
+        })
+    }
+}
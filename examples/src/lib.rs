@@ -1,3 +1,5 @@
+pub mod echo;
+
 pub mod zero_value {
     include!(concat!(env!("OUT_DIR"), "/zero_value.rs"));
 }
@@ -10,6 +12,78 @@ pub mod custom_options {
     include!(concat!(env!("OUT_DIR"), "/custom_options.rs"));
 }
 
+pub mod streaming {
+    include!(concat!(env!("OUT_DIR"), "/streaming.rs"));
+}
+
+pub mod unknown_enum_variant {
+    include!(concat!(env!("OUT_DIR"), "/unknown_enum_variant.rs"));
+}
+
+pub mod empty {
+    include!(concat!(env!("OUT_DIR"), "/empty.rs"));
+}
+
+pub mod method_options {
+    include!(concat!(env!("OUT_DIR"), "/method_options.rs"));
+}
+
+pub mod forbid_unsafe {
+    include!(concat!(env!("OUT_DIR"), "/forbid_unsafe.rs"));
+}
+
+pub mod checksum {
+    include!(concat!(env!("OUT_DIR"), "/checksum.rs"));
+}
+
+pub mod lenient_numeric {
+    include!(concat!(env!("OUT_DIR"), "/lenient_numeric.rs"));
+}
+
+pub mod typed_uuid {
+    include!(concat!(env!("OUT_DIR"), "/typed_uuid.rs"));
+}
+
+pub mod fixed_encoded_size {
+    include!(concat!(env!("OUT_DIR"), "/fixed_encoded_size.rs"));
+}
+
+pub mod cached_size {
+    include!(concat!(env!("OUT_DIR"), "/cached_size.rs"));
+}
+
+pub mod field_names {
+    include!(concat!(env!("OUT_DIR"), "/field_names.rs"));
+}
+
+pub mod overlay {
+    include!(concat!(env!("OUT_DIR"), "/overlay.rs"));
+}
+
+pub mod compress {
+    include!(concat!(env!("OUT_DIR"), "/compress.rs"));
+}
+
+pub mod default_requiredness {
+    include!(concat!(env!("OUT_DIR"), "/default_requiredness.rs"));
+}
+
+pub mod canonical_eq {
+    include!(concat!(env!("OUT_DIR"), "/canonical_eq.rs"));
+}
+
+pub mod pb_codec {
+    include!(concat!(env!("OUT_DIR"), "/pb_codec.rs"));
+}
+
+pub mod pb_codec_pb {
+    include!(concat!(env!("OUT_DIR"), "/pb_codec_pb.rs"));
+}
+
+pub mod proto2_required {
+    include!(concat!(env!("OUT_DIR"), "/proto2_required.rs"));
+}
+
 #[cfg(not(feature = "pb-encode-default-value"))]
 #[test]
 fn test_pb_size_diff_codegen() {
@@ -18,9 +92,11 @@ fn test_pb_size_diff_codegen() {
         LinkedBytes,
     };
 
-    let mut b = zero_value::zero_value::B::default();
-    b.s3 = "".into();
-    b.int_vec = Some((1..=1000).collect());
+    let b = zero_value::zero_value::B {
+        s3: "".into(),
+        int_vec: Some((1..=1000).collect()),
+        ..Default::default()
+    };
 
     let mut before = LinkedBytes::new();
     encoding::string::encode(1, &b.s3, &mut before);
@@ -79,6 +155,7 @@ fn test_pb_size_diff_manual() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_pb_encode_zero_value() {
     use std::sync::Arc;
 
@@ -189,10 +266,11 @@ fn test_pb_encode_zero_value() {
     assert_eq!(decoded_c, a.c.unwrap());
 
     // test deprecated
+    #[allow(deprecated, unused_imports)]
     use zero_value::zero_value::TestService;
 
     // test f32 and f64
-    let _ = zero_value::zero_value::file_descriptor_zero_value()
+    zero_value::zero_value::file_descriptor_zero_value()
         .messages()
         .for_each(|m| {
             // the name is same with the idl definition
@@ -345,10 +423,7 @@ fn test_thrift_fieldmask() {
 
 #[test]
 fn test_pb_options() {
-    use pilota::pb::{
-        descriptor_getter::{FieldDescriptorGetter, ItemDescriptorGetter},
-        Message as _,
-    };
+    use pilota::pb::descriptor_getter::FieldDescriptorGetter;
 
     // file options
     let fd_proto =
@@ -369,7 +444,7 @@ fn test_pb_options() {
         }
         if let Ok(v) = exts_custom_options::internal_api.get(file_opts) {
             println!("file_option internal_api: {}", v);
-            assert_eq!(v, false);
+            assert!(!v);
         }
         if let Ok(v) = exts_custom_options::file_kv.get(file_opts) {
             println!("file_option file_kv: {:?}", v);
@@ -441,7 +516,7 @@ fn test_pb_options() {
             let all = v.all_fields_required.unwrap_or(false);
             let depth = v.max_nesting_depth.unwrap_or_default();
             let msg = v.validation_message.clone().unwrap_or_default();
-            assert_eq!(all, true);
+            assert!(all);
             assert_eq!(depth, 3);
             assert_eq!(msg, "User validation failed");
             println!(
@@ -531,7 +606,7 @@ fn test_pb_options() {
                 println!("enum_option enum_description: {}", v);
             }
             if let Ok(v) = exts_custom_options::is_internal.get(enum_opts) {
-                assert_eq!(v, false);
+                assert!(!v);
                 println!("enum_option is_internal: {}", v);
             }
             if let Some(aa) = enum_opts.allow_alias {
@@ -572,3 +647,1006 @@ fn test_pb_options() {
         }
     }
 }
+
+#[test]
+fn test_thrift_unknown_enum_variant() {
+    use pilota::{
+        thrift::{binary::TBinaryProtocol, Message as _},
+        BytesMut,
+    };
+    use unknown_enum_variant::unknown_enum_variant::unknown_enum_variant::{
+        Account, AccountV2, Status,
+    };
+
+    let v2 = AccountV2 {
+        status: 3,
+        history: Some(vec![1, 4]),
+        notes: None,
+    };
+    let mut buf = BytesMut::new();
+    v2.encode(&mut TBinaryProtocol::new(&mut buf, true))
+        .unwrap();
+
+    let mut bytes = buf.freeze();
+    let account = Account::decode(&mut TBinaryProtocol::new(&mut bytes, true)).unwrap();
+    assert_eq!(account.status, Status::Unknown(3));
+    assert!(account.status.is_unknown());
+    assert_eq!(Status::try_from_i32(3), None);
+    assert_eq!(
+        account.history,
+        Some(vec![Status::ACTIVE, Status::Unknown(4)])
+    );
+
+    let mut buf = BytesMut::new();
+    account
+        .encode(&mut TBinaryProtocol::new(&mut buf, true))
+        .unwrap();
+    let mut bytes = buf.freeze();
+    let decoded = AccountV2::decode(&mut TBinaryProtocol::new(&mut bytes, true)).unwrap();
+    assert_eq!(decoded, v2);
+}
+
+#[test]
+fn test_thrift_enum_key_policy_drop() {
+    use pilota::{
+        thrift::{binary::TBinaryProtocol, Message as _},
+        AHashMap, BytesMut,
+    };
+    use unknown_enum_variant::unknown_enum_variant::unknown_enum_variant::{
+        Account, AccountV2, Status,
+    };
+
+    let v2 = AccountV2 {
+        status: 1,
+        history: None,
+        notes: Some(AHashMap::from([(1, "known".into()), (5, "unknown".into())])),
+    };
+    let mut buf = BytesMut::new();
+    v2.encode(&mut TBinaryProtocol::new(&mut buf, true))
+        .unwrap();
+
+    let mut bytes = buf.freeze();
+    let account = Account::decode(&mut TBinaryProtocol::new(&mut bytes, true)).unwrap();
+    assert_eq!(
+        account.notes,
+        Some(AHashMap::from([(Status::ACTIVE, "known".into())]))
+    );
+}
+
+#[test]
+fn test_thrift_framed_codec() {
+    use pilota::{thrift::codec::FramedCompactCodec, BytesMut};
+    use tokio_util::codec::{Decoder as _, Encoder as _};
+    use unknown_enum_variant::unknown_enum_variant::unknown_enum_variant::{Account, Status};
+
+    let account = Account {
+        status: Status::ACTIVE,
+        history: Some(vec![Status::INACTIVE]),
+        notes: None,
+    };
+    let mut codec = FramedCompactCodec::<Account>::new();
+    let mut buf = BytesMut::new();
+    codec.encode(&account, &mut buf).unwrap();
+    codec.encode(account.clone(), &mut buf).unwrap();
+
+    assert_eq!(codec.decode(&mut buf).unwrap(), Some(account.clone()));
+    assert_eq!(codec.decode(&mut buf).unwrap(), Some(account));
+    assert_eq!(codec.decode(&mut buf).unwrap(), None);
+}
+
+#[test]
+fn test_http_body() {
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use pilota::http::{
+        decode_pb, decode_thrift, pb_response, thrift_response, PROTOBUF_CONTENT_TYPE,
+        THRIFT_CONTENT_TYPE,
+    };
+    use unknown_enum_variant::unknown_enum_variant::unknown_enum_variant::{Account, Status};
+
+    // bodies built from `Full` are always ready
+    fn ready<F: Future>(fut: F) -> F::Output {
+        match pin!(fut).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future is pending"),
+        }
+    }
+
+    let account = Account {
+        status: Status::INACTIVE,
+        history: None,
+        notes: None,
+    };
+    let resp = thrift_response(&account).unwrap();
+    assert_eq!(resp.headers()["content-type"], THRIFT_CONTENT_TYPE);
+    let decoded: Account = ready(decode_thrift(resp.into_body())).unwrap();
+    assert_eq!(decoded, account);
+
+    let b = zero_value::zero_value::B {
+        s3: "hello".into(),
+        int_vec: Some(vec![1, 2, 3]),
+        ..Default::default()
+    };
+    let resp = pb_response(&b);
+    assert_eq!(resp.headers()["content-type"], PROTOBUF_CONTENT_TYPE);
+    let decoded: zero_value::zero_value::B = ready(decode_pb(resp.into_body())).unwrap();
+    assert_eq!(decoded, b);
+}
+
+#[test]
+fn test_axum_extractor() {
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use axum::{
+        body::Body,
+        extract::{FromRequest, Request},
+        http::StatusCode,
+        response::IntoResponse,
+    };
+    use pilota::{axum::Thrift, http::THRIFT_CONTENT_TYPE};
+    use unknown_enum_variant::unknown_enum_variant::unknown_enum_variant::{Account, Status};
+
+    fn ready<F: Future>(fut: F) -> F::Output {
+        match pin!(fut).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future is pending"),
+        }
+    }
+
+    let account = Account {
+        status: Status::ACTIVE,
+        history: Some(vec![Status::INACTIVE]),
+        notes: None,
+    };
+    let resp = Thrift(account.clone()).into_response();
+    assert_eq!(resp.headers()["content-type"], THRIFT_CONTENT_TYPE);
+
+    let req = Request::builder()
+        .header("content-type", THRIFT_CONTENT_TYPE)
+        .body(resp.into_body())
+        .unwrap();
+    let Thrift(decoded) = ready(Thrift::<Account>::from_request(req, &())).unwrap();
+    assert_eq!(decoded, account);
+
+    let req = Request::builder()
+        .header("content-type", "application/json")
+        .body(Body::empty())
+        .unwrap();
+    let rejection = ready(Thrift::<Account>::from_request(req, &())).unwrap_err();
+    assert_eq!(
+        rejection.into_response().status(),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE
+    );
+}
+
+#[test]
+fn test_thrift_enum_str() {
+    use unknown_enum_variant::unknown_enum_variant::unknown_enum_variant::Status;
+
+    assert_eq!(Status::ACTIVE.as_str(), Some("ACTIVE"));
+    assert_eq!(Status::Unknown(7).as_str(), None);
+    assert_eq!(Status::INACTIVE.to_string(), "INACTIVE");
+    assert_eq!(Status::Unknown(7).to_string(), "7");
+
+    assert_eq!("ACTIVE".parse::<Status>().unwrap(), Status::ACTIVE);
+    assert_eq!("7".parse::<Status>().unwrap(), Status::Unknown(7));
+    assert!("active".parse::<Status>().is_err());
+}
+
+#[test]
+fn test_thrift_streaming() {
+    use std::{
+        future::Future,
+        pin::{pin, Pin},
+        task::{Context, Poll, Waker},
+    };
+
+    use pilota::{
+        stream::{encode_thrift_frame, BoxError, Stream, Streaming, ThriftStream},
+        Bytes, BytesMut,
+    };
+    use streaming::streaming::streaming::{Catalog, Item};
+
+    fn ready<F: Future>(fut: F) -> F::Output {
+        match pin!(fut).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future is pending"),
+        }
+    }
+
+    struct Chunks(std::vec::IntoIter<Bytes>);
+
+    impl Stream for Chunks {
+        type Item = Result<Bytes, BoxError>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.0.next().map(Ok))
+        }
+    }
+
+    struct Server;
+
+    impl Catalog for Server {
+        async fn list(&self, prefix: Item) -> Result<Streaming<Item>, BoxError> {
+            // what a client would receive over the wire, one frame per chunk
+            let chunks = ["a", "b"]
+                .into_iter()
+                .map(|name| {
+                    let mut buf = BytesMut::new();
+                    let item = Item {
+                        name: format!("{}/{name}", prefix.name).into(),
+                    };
+                    encode_thrift_frame(&item, &mut buf).unwrap();
+                    buf.freeze()
+                })
+                .collect::<Vec<_>>();
+            Ok(Box::pin(ThriftStream::<_, Item>::new(Chunks(
+                chunks.into_iter(),
+            ))))
+        }
+
+        async fn echo(&self, req: Streaming<Item>) -> Result<Streaming<Item>, BoxError> {
+            Ok(req)
+        }
+    }
+
+    fn collect(mut stream: Streaming<Item>) -> Vec<Item> {
+        let mut items = Vec::new();
+        while let Poll::Ready(Some(item)) = stream
+            .as_mut()
+            .poll_next(&mut Context::from_waker(Waker::noop()))
+        {
+            items.push(item.unwrap());
+        }
+        items
+    }
+
+    let stream = ready(Server.list(Item { name: "x".into() })).unwrap();
+    let items = collect(ready(Server.echo(stream)).unwrap());
+    assert_eq!(
+        items,
+        vec![Item { name: "x/a".into() }, Item { name: "x/b".into() }]
+    );
+}
+
+#[test]
+fn test_thrift_default_requiredness() {
+    use default_requiredness::default_requiredness::default_requiredness::Point;
+    use pilota::{
+        thrift::{binary::TBinaryProtocol, Message},
+        Bytes, BytesMut,
+    };
+
+    let point = Point {
+        x: None,
+        y: None,
+        label: None,
+        z: None,
+    };
+    let mut buf = BytesMut::new();
+    point
+        .encode(&mut TBinaryProtocol::new(&mut buf, true))
+        .unwrap();
+    assert_eq!(buf.len(), point.size(&mut TBinaryProtocol::new((), true)));
+    // What Apache Thrift writes for a `Point` with only its declared
+    // defaults: the unset default requiredness fields as zero values or
+    // their IDL default, the optional field not at all.
+    assert_eq!(
+        &buf[..],
+        [
+            8, 0, 1, 0, 0, 0, 0, // x = 0
+            8, 0, 2, 0, 0, 0, 7, // y = 7
+            11, 0, 3, 0, 0, 0, 0, // label = ""
+            0, // stop
+        ]
+    );
+
+    // Missing default requiredness fields decode to their IDL default, if
+    // any.
+    let decoded = Point::decode(&mut TBinaryProtocol::new(
+        &mut Bytes::from_static(&[0]),
+        true,
+    ))
+    .unwrap();
+    assert_eq!(
+        decoded,
+        Point {
+            x: None,
+            y: Some(7),
+            label: None,
+            z: None,
+        }
+    );
+}
+
+#[test]
+fn test_thrift_empty_struct() {
+    use empty::empty::empty::{Ack, HealthPingArgsRecv, HealthPingResultRecv};
+    use pilota::{
+        thrift::{binary::TBinaryProtocol, Message, TOutputProtocol, TStructIdentifier, TType},
+        Bytes, BytesMut,
+    };
+
+    let mut buf = BytesMut::new();
+    Ack {}
+        .encode(&mut TBinaryProtocol::new(&mut buf, true))
+        .unwrap();
+    HealthPingResultRecv::Ok(())
+        .encode(&mut TBinaryProtocol::new(&mut buf, true))
+        .unwrap();
+    assert_eq!(&buf[..], [0, 0]);
+    assert_eq!(Ack {}.size(&mut TBinaryProtocol::new((), true)), 1);
+
+    // a newer peer may send fields this side doesn't know about
+    let mut buf = BytesMut::new();
+    let mut protocol = TBinaryProtocol::new(&mut buf, true);
+    protocol
+        .write_struct_begin(&TStructIdentifier { name: "Ack" })
+        .unwrap();
+    protocol.write_field_begin(TType::Binary, 1).unwrap();
+    protocol.write_string("ok").unwrap();
+    protocol.write_field_end().unwrap();
+    protocol.write_field_stop().unwrap();
+    protocol.write_struct_end().unwrap();
+    let mut bytes = Bytes::from([&buf[..], &[0]].concat());
+    let mut protocol = TBinaryProtocol::new(&mut bytes, true);
+    assert_eq!(Ack::decode(&mut protocol).unwrap(), Ack {});
+    assert_eq!(
+        HealthPingResultRecv::decode(&mut protocol).unwrap(),
+        HealthPingResultRecv::Ok(())
+    );
+    assert!(bytes.is_empty());
+
+    let mut bytes = Bytes::from_static(&[0]);
+    assert_eq!(
+        HealthPingArgsRecv::decode(&mut TBinaryProtocol::new(&mut bytes, true)).unwrap(),
+        HealthPingArgsRecv {}
+    );
+}
+
+#[test]
+fn test_thrift_method_options() {
+    use std::time::Duration;
+
+    use method_options::method_options::method_options::SEARCH_METHOD_OPTIONS;
+    use pilota::thrift::MethodOptions;
+
+    let find = MethodOptions::find(SEARCH_METHOD_OPTIONS, "Find").unwrap();
+    assert_eq!(find.timeout(), Some(Duration::from_millis(500)));
+    assert_eq!(find.get_i64("retry.max"), Some(2));
+    assert_eq!(find.get_str("api.get"), Some("/search"));
+    assert!(MethodOptions::find(SEARCH_METHOD_OPTIONS, "Suggest").is_none());
+}
+
+#[test]
+fn test_thrift_forbid_unsafe() {
+    use forbid_unsafe::forbid_unsafe::forbid_unsafe::{Document, Tag, Value};
+    use pilota::{
+        thrift::{binary::TBinaryProtocol, Message},
+        BytesMut,
+    };
+
+    let doc = Document {
+        tags: vec![
+            Tag {
+                name: "a".into(),
+                ..Default::default()
+            },
+            Tag {
+                name: "b".into(),
+                ..Default::default()
+            },
+        ],
+        matrix: Some(vec![vec![1, 2], vec![], vec![3]]),
+        value: Some(Value::Text("c".into())),
+        ..Default::default()
+    };
+    let mut buf = BytesMut::new();
+    doc.encode(&mut TBinaryProtocol::new(&mut buf, true))
+        .unwrap();
+    let mut bytes = buf.freeze();
+    let decoded = Document::decode(&mut TBinaryProtocol::new(&mut bytes, true)).unwrap();
+    assert_eq!(decoded, doc);
+}
+
+#[test]
+fn test_thrift_compress() {
+    use compress::compress::compress::Snapshot;
+    use pilota::{
+        thrift::{binary::TBinaryProtocol, Message},
+        Bytes, BytesMut,
+    };
+
+    let snapshot = Snapshot {
+        id: "s1".into(),
+        payload: Bytes::from("pilota ".repeat(1000)),
+        thumbnail: Some(Bytes::from_static(b"tiny")),
+    };
+    let size = snapshot.size(&mut TBinaryProtocol::new((), false));
+    let mut buf = BytesMut::new();
+    snapshot
+        .encode(&mut TBinaryProtocol::new(&mut buf, false))
+        .unwrap();
+    assert_eq!(buf.len(), size);
+    assert!(buf.len() < snapshot.payload.len());
+
+    let mut bytes = buf.freeze();
+    let decoded = Snapshot::decode(&mut TBinaryProtocol::new(&mut bytes, false)).unwrap();
+    assert_eq!(decoded, snapshot);
+}
+
+#[test]
+fn test_thrift_checksum() {
+    use checksum::checksum::checksum::Upload;
+    use pilota::{
+        thrift::{binary::TBinaryProtocol, Message},
+        Bytes, BytesMut,
+    };
+
+    let upload = Upload {
+        file: Bytes::from_static(b"file contents"),
+        archive: Some(Bytes::from("archive ".repeat(100))),
+    };
+    let size = upload.size(&mut TBinaryProtocol::new((), false));
+    let mut buf = BytesMut::new();
+    upload
+        .encode(&mut TBinaryProtocol::new(&mut buf, false))
+        .unwrap();
+    assert_eq!(buf.len(), size);
+
+    let mut bytes = buf.clone().freeze();
+    let decoded = Upload::decode(&mut TBinaryProtocol::new(&mut bytes, false)).unwrap();
+    assert_eq!(decoded, upload);
+
+    // flip a byte of `file`, right after its field header and length
+    buf[7] ^= 1;
+    let mut bytes = buf.freeze();
+    assert!(Upload::decode(&mut TBinaryProtocol::new(&mut bytes, false)).is_err());
+}
+
+#[test]
+fn test_thrift_overlay() {
+    use overlay::overlay::overlay::{Header, HeaderOverlay, Tick, TickOverlay};
+    use pilota::overlay::Overlay;
+
+    let tick = Tick {
+        timestamp: 1_700_000_000,
+        price: 101.25,
+        volume: 300,
+        venue: 7,
+        side: -1,
+        halted: true,
+    };
+    let overlay = TickOverlay::from(&tick);
+    assert_eq!(TickOverlay::SIZE, 8 + 8 + 4 + 2 + 1 + 1);
+    let bytes = overlay.as_bytes();
+    assert_eq!(&bytes[..8], &1_700_000_000i64.to_le_bytes());
+
+    let view = TickOverlay::ref_from_bytes(bytes).unwrap();
+    assert_eq!(view.price(), 101.25);
+    assert_eq!(Tick::from(view), tick);
+
+    let ticks = [overlay, overlay];
+    let views = TickOverlay::slice_from_bytes(TickOverlay::slice_as_bytes(&ticks)).unwrap();
+    assert_eq!(views.len(), 2);
+
+    let mut header = HeaderOverlay::from(&Header {
+        magic: 0x0102_0304,
+        version: 2,
+    });
+    assert_eq!(header.as_bytes(), &[1, 2, 3, 4, 0, 2]);
+    header.set_version(3);
+    assert_eq!(Header::from(&header).version, 3);
+}
+
+#[test]
+fn test_thrift_compact() {
+    use fieldmask::fieldmask::base::r#loop::{Base, Ex, TrafficEnv};
+    use pilota::{
+        thrift::{Message, TCompactInputProtocol, TCompactOutputProtocol},
+        BytesMut, LinkedBytes,
+    };
+
+    // the fields after the nested struct are encoded relative to the ones
+    // before it
+    let base = Base {
+        addr: "127.0.0.1:8080".into(),
+        log_id: Some("logid".into()),
+        traffic_env: Some(TrafficEnv {
+            open: Some(true),
+            code: i64::MIN,
+            ..Default::default()
+        }),
+        r#enum: Some(Ex::C),
+        ..Default::default()
+    };
+
+    let size = base.size(&mut TCompactOutputProtocol::new((), false));
+    let mut buf = BytesMut::new();
+    base.encode(&mut TCompactOutputProtocol::new(&mut buf, false))
+        .unwrap();
+    assert_eq!(buf.len(), size);
+
+    let mut linked = LinkedBytes::new();
+    base.encode(&mut TCompactOutputProtocol::new(&mut linked, true))
+        .unwrap();
+    assert_eq!(&linked.concat()[..], &buf[..]);
+
+    let mut bytes = buf.freeze();
+    let decoded = Base::decode(&mut TCompactInputProtocol::new(&mut bytes)).unwrap();
+    assert_eq!(decoded, base);
+    assert!(bytes.is_empty());
+}
+
+#[test]
+fn test_pb_proto2_required() {
+    use pilota::{pb::Message as _, LinkedBytes};
+    use proto2_required::proto2_required::proto2_required::{Item, Order};
+
+    let order = Order {
+        id: Some(7),
+        item: Some(Item {
+            sku: Some("apple".into()),
+            ..Default::default()
+        }),
+        extras: vec![Item::default()],
+        ..Default::default()
+    };
+    let err = order.encode(&mut LinkedBytes::new()).unwrap_err();
+    assert_eq!(err.missing_required(), ["Item.sku"]);
+
+    // decoding lists every missing field, nested ones included
+    let partial = Order {
+        item: Some(Item::default()),
+        ..Default::default()
+    };
+    let bytes = partial.encode_to_vec(&mut Default::default());
+    let err = Order::decode(bytes.into()).unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with("missing required fields: Order.id, Item.sku"));
+
+    let order = Order {
+        extras: vec![],
+        ..order
+    };
+    let mut buf = LinkedBytes::new();
+    order.encode(&mut buf).unwrap();
+    assert_eq!(Order::decode(buf.concat().freeze()).unwrap(), order);
+}
+
+#[test]
+fn test_thrift_lenient_numeric() {
+    use lenient_numeric::lenient_numeric::lenient_numeric::{Narrow, Wide};
+    use pilota::{
+        thrift::{binary::TBinaryProtocol, coerce, Message},
+        BytesMut,
+    };
+
+    fn roundtrip<A: Message, B: Message>(a: &A) -> Result<B, pilota::thrift::ThriftException> {
+        let mut buf = BytesMut::new();
+        a.encode(&mut TBinaryProtocol::new(&mut buf, false))
+            .unwrap();
+        B::decode(&mut TBinaryProtocol::new(&mut buf.freeze(), false))
+    }
+
+    let before = coerce::coercions();
+    let wide = Wide {
+        count: 7,
+        total: Some(3),
+    };
+    let narrow: Narrow = roundtrip(&wide).unwrap();
+    assert_eq!(
+        narrow,
+        Narrow {
+            count: 7,
+            total: Some(3)
+        }
+    );
+    assert_eq!(roundtrip::<_, Wide>(&narrow).unwrap(), wide);
+    let after = coerce::coercions();
+    assert!(after.narrowed > before.narrowed);
+    assert!(after.widened > before.widened);
+
+    let too_wide = Wide {
+        count: i64::MAX,
+        total: None,
+    };
+    assert!(roundtrip::<_, Narrow>(&too_wide).is_err());
+}
+
+#[test]
+fn test_thrift_reject_duplicate_fields() {
+    use lenient_numeric::lenient_numeric::lenient_numeric::Narrow;
+    use pilota::{
+        thrift::{
+            binary::{TAsyncBinaryProtocol, TBinaryProtocol},
+            Message, TOutputProtocol, TStructIdentifier, TType,
+        },
+        BytesMut,
+    };
+
+    // a `Narrow` whose `count` is sent twice
+    let mut buf = BytesMut::new();
+    let mut p = TBinaryProtocol::new(&mut buf, false);
+    p.write_struct_begin(&TStructIdentifier { name: "Narrow" })
+        .unwrap();
+    for count in [1, 2] {
+        p.write_field_begin(TType::I32, 1).unwrap();
+        p.write_i32(count).unwrap();
+        p.write_field_end().unwrap();
+    }
+    p.write_field_stop().unwrap();
+    p.write_struct_end().unwrap();
+    let encoded = buf.freeze();
+
+    let narrow = Narrow::decode(&mut TBinaryProtocol::new(&mut encoded.clone(), false)).unwrap();
+    assert_eq!(narrow.count, 2);
+
+    let mut bytes = encoded.clone();
+    let mut p = TBinaryProtocol::new(&mut bytes, false);
+    p.set_reject_duplicate_fields(true);
+    let e = Narrow::decode(&mut p).unwrap_err();
+    assert!(
+        e.to_string().contains("field 1 appears more than once"),
+        "{e}"
+    );
+
+    let mut p = TAsyncBinaryProtocol::new(&encoded[..]);
+    p.set_reject_duplicate_fields(true);
+    let decoded = futures::executor::block_on(Narrow::decode_async(&mut p));
+    assert!(decoded.is_err());
+}
+
+#[test]
+fn test_thrift_unknown_field_hook() {
+    use std::sync::{Arc, Mutex};
+
+    use lenient_numeric::lenient_numeric::lenient_numeric::Narrow;
+    use pilota::{
+        thrift::{
+            binary::{TAsyncBinaryProtocol, TBinaryProtocol},
+            unknown::UnknownField,
+            Message, TOutputProtocol, TStructIdentifier, TType,
+        },
+        BytesMut,
+    };
+
+    // a `Narrow` from a peer which added a string field 9 and changed the
+    // type of `total` to a string
+    let mut buf = BytesMut::new();
+    let mut p = TBinaryProtocol::new(&mut buf, false);
+    p.write_struct_begin(&TStructIdentifier { name: "Narrow" })
+        .unwrap();
+    p.write_field_begin(TType::I32, 1).unwrap();
+    p.write_i32(7).unwrap();
+    p.write_field_end().unwrap();
+    p.write_field_begin(TType::Binary, 9).unwrap();
+    p.write_string("new").unwrap();
+    p.write_field_end().unwrap();
+    p.write_field_begin(TType::Binary, 2).unwrap();
+    p.write_string("1").unwrap();
+    p.write_field_end().unwrap();
+    p.write_field_stop().unwrap();
+    p.write_struct_end().unwrap();
+    let encoded = buf.freeze();
+
+    let unknown = Arc::new(Mutex::new(Vec::new()));
+    let hook = {
+        let unknown = unknown.clone();
+        move |field| unknown.lock().unwrap().push(field)
+    };
+    let expected = [
+        UnknownField {
+            struct_name: "Narrow",
+            field_id: Some(9),
+            field_type: TType::Binary,
+            type_mismatch: false,
+        },
+        UnknownField {
+            struct_name: "Narrow",
+            field_id: Some(2),
+            field_type: TType::Binary,
+            type_mismatch: true,
+        },
+    ];
+
+    let mut bytes = encoded.clone();
+    let mut p = TBinaryProtocol::new(&mut bytes, false);
+    p.set_unknown_field_hook(hook.clone());
+    let narrow = Narrow::decode(&mut p).unwrap();
+    assert_eq!((narrow.count, narrow.total), (7, None));
+    assert_eq!(*unknown.lock().unwrap(), expected);
+
+    unknown.lock().unwrap().clear();
+    let mut p = TAsyncBinaryProtocol::new(&encoded[..]);
+    p.set_unknown_field_hook(hook);
+    futures::executor::block_on(Narrow::decode_async(&mut p)).unwrap();
+    assert_eq!(*unknown.lock().unwrap(), expected);
+}
+
+#[test]
+fn test_thrift_fixed_encoded_size() {
+    use fixed_encoded_size::fixed_encoded_size::fixed_encoded_size::{
+        Header, Level, Tick, Timestamp,
+    };
+    use pilota::{
+        thrift::{binary::TBinaryProtocol, compact::TCompactOutputProtocol, Message},
+        BytesMut,
+    };
+
+    const _: () = assert!(Tick::ENCODED_SIZE == 45);
+
+    let tick = Tick {
+        header: Header {
+            version: 1,
+            level: Level::HIGH,
+        },
+        at: Timestamp(1_700_000_000),
+        value: 0.5,
+        valid: true,
+    };
+    let mut buf = BytesMut::new();
+    tick.encode(&mut TBinaryProtocol::new(&mut buf, false))
+        .unwrap();
+    assert_eq!(buf.len(), Tick::ENCODED_SIZE);
+    assert_eq!(
+        tick.size(&mut TBinaryProtocol::new((), false)),
+        Tick::ENCODED_SIZE
+    );
+
+    // the compact protocol still measures field by field
+    let mut buf = BytesMut::new();
+    tick.encode(&mut TCompactOutputProtocol::new(&mut buf, false))
+        .unwrap();
+    assert_eq!(
+        tick.size(&mut TCompactOutputProtocol::new((), false)),
+        buf.len()
+    );
+    assert!(buf.len() < Tick::ENCODED_SIZE);
+}
+
+#[test]
+fn test_thrift_cached_size() {
+    use cached_size::cached_size::cached_size::{Branch, Leaf, Tree};
+    use pilota::{
+        thrift::{binary::TBinaryProtocol, cached_size::cycle, Message},
+        AHashMap, BytesMut,
+    };
+
+    let leaf = |id: i64| Leaf {
+        id,
+        name: Some(format!("leaf {id}").into()),
+        ..Default::default()
+    };
+    let branch = |n: i64| Branch {
+        leaves: (0..n).map(leaf).collect(),
+        first: Some(leaf(0)),
+        ..Default::default()
+    };
+    let mut tree = Tree {
+        root: branch(3),
+        branches: AHashMap::from_iter([("a".into(), branch(5)), ("b".into(), branch(1))]),
+        ..Default::default()
+    };
+    let encoded_len = |tree: &Tree| {
+        let mut buf = BytesMut::new();
+        tree.encode(&mut TBinaryProtocol::new(&mut buf, false))
+            .unwrap();
+        buf.len()
+    };
+    let size = |tree: &Tree| tree.size(&mut TBinaryProtocol::new((), false));
+
+    let len = encoded_len(&tree);
+    cycle(|| {
+        assert_eq!(size(&tree), len);
+        assert_eq!(size(&tree), len);
+    });
+
+    // a new cycle sees the changes made since the last one
+    tree.root.leaves.push(leaf(42));
+    let len = encoded_len(&tree);
+    assert_eq!(cycle(|| size(&tree)), len);
+    assert_eq!(size(&tree), len);
+
+    // the cache is left out of comparisons and decoded values
+    let mut buf = BytesMut::new();
+    tree.encode(&mut TBinaryProtocol::new(&mut buf, false))
+        .unwrap();
+    let decoded = Tree::decode(&mut TBinaryProtocol::new(&mut buf.freeze(), false)).unwrap();
+    assert_eq!(decoded, tree);
+    assert_eq!(decoded.clone(), tree);
+}
+
+#[test]
+fn test_thrift_simple_json() {
+    use field_names::field_names::field_names::{Drawing, Point, Shape};
+    use pilota::{
+        thrift::{Message, TSimpleJSONProtocol},
+        AHashMap, BytesMut,
+    };
+
+    let drawing = Drawing {
+        title: "plan \"B\"".into(),
+        shapes: Some(vec![
+            Shape::Point(Point { x: 1, y: -2 }),
+            Shape::Polygon(vec![Point { x: 0, y: 0 }, Point { x: 3, y: 4 }]),
+        ]),
+        anchors: Some(AHashMap::from_iter([(
+            "origin".into(),
+            Point { x: 0, y: 0 },
+        )])),
+    };
+    let mut buf = BytesMut::new();
+    drawing
+        .encode(&mut TSimpleJSONProtocol::new(&mut buf))
+        .unwrap();
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        r#"{"title":"plan \"B\"","shapes":[{"point":{"x":1,"y":-2}},{"polygon":[{"x":0,"y":0},{"x":3,"y":4}]}],"anchors":{"origin":{"x":0,"y":0}}}"#
+    );
+    assert_eq!(drawing.size(&mut TSimpleJSONProtocol::new(())), buf.len());
+    assert_eq!(Point::FIELD_NAMES, &[(1, "x"), (2, "y")]);
+}
+
+#[test]
+fn test_thrift_debug() {
+    use field_names::field_names::field_names::{Drawing, Point, Shape};
+    use pilota::thrift::{debug_message, Message, TDebugProtocol};
+
+    let drawing = Drawing {
+        title: "plan".into(),
+        shapes: Some(vec![Shape::Point(Point { x: 1, y: -2 })]),
+        anchors: None,
+    };
+    let text = debug_message(&drawing).unwrap();
+    assert_eq!(
+        text,
+        r#"Drawing {
+  1: title (string) = "plan",
+  2: shapes (list<struct>) = [
+    Shape {
+      1: point (struct) = Point {
+        1: x (i32) = 1,
+        2: y (i32) = -2,
+      },
+    },
+  ],
+}"#
+    );
+    assert_eq!(drawing.size(&mut TDebugProtocol::new(())), text.len());
+}
+
+#[test]
+fn test_thrift_typed_uuid() {
+    use pilota::{
+        thrift::{binary::TBinaryProtocol, compact::TCompactInputProtocol, Message},
+        uuid::Uuid,
+        BytesMut,
+    };
+    use typed_uuid::typed_uuid::typed_uuid::Session;
+
+    let session = Session {
+        id: Uuid::from_u128(1),
+        parent: Some(Uuid::from_u128(2)),
+        members: vec![Uuid::from_u128(3), Uuid::from_u128(4)],
+        owners: Some([("alice".into(), Uuid::from_u128(5))].into_iter().collect()),
+    };
+    let mut buf = BytesMut::new();
+    session
+        .encode(&mut TBinaryProtocol::new(&mut buf, false))
+        .unwrap();
+    assert_eq!(
+        buf.len(),
+        session.size(&mut TBinaryProtocol::new((), false))
+    );
+    let decoded = Session::decode(&mut TBinaryProtocol::new(&mut buf.freeze(), false)).unwrap();
+    assert_eq!(decoded, session);
+
+    let mut buf = BytesMut::new();
+    session
+        .encode(&mut pilota::thrift::compact::TCompactOutputProtocol::new(
+            &mut buf, false,
+        ))
+        .unwrap();
+    let decoded = Session::decode(&mut TCompactInputProtocol::new(&mut buf.freeze())).unwrap();
+    assert_eq!(decoded, session);
+}
+
+#[test]
+fn test_thrift_canonical_eq() {
+    use canonical_eq::canonical_eq::canonical_eq::{Point, Reading, Shape};
+    use pilota::{canonical::CanonicalEq, AHashMap};
+
+    let a = Reading {
+        sensor: "s1".into(),
+        retries: None,
+        scores: (0..32).map(|i| (i.to_string().into(), f64::NAN)).collect(),
+        shape: Some(Shape::Point(Point {
+            x: f64::NAN,
+            y: 1.0,
+            _unknown_fields: Default::default(),
+        })),
+        _unknown_fields: Default::default(),
+    };
+    let mut b = a.clone();
+    b.retries = Some(3);
+    b.scores = (0..32)
+        .rev()
+        .map(|i| (i.to_string().into(), f64::NAN))
+        .collect::<AHashMap<_, _>>();
+    assert_ne!(a, b);
+    assert!(a.canonical_eq(&b));
+
+    b.retries = Some(4);
+    assert!(!a.canonical_eq(&b));
+    b.retries = None;
+    b.shape = Some(Shape::Polygon(vec![]));
+    assert!(!a.canonical_eq(&b));
+}
+
+#[test]
+fn test_thrift_struct_pb_codec() {
+    use pb_codec::pb_codec::pb_codec::{Point, Status, User};
+    use pb_codec_pb::pb_codec_pb::pb_codec as pb;
+    use pilota::{
+        pb::{EncodeLengthContext, Message as _},
+        thrift::binary::TBinaryProtocol,
+        BytesMut,
+    };
+
+    let user = User {
+        id: 7,
+        name: "alice".into(),
+        status: Some(Status::INACTIVE),
+        path: Some(vec![Point { x: 1.0, y: -2.5 }, Point { x: 0.0, y: 0.0 }]),
+        scores: Some(vec![-3, 0, 42]),
+        places: Some(
+            [("home".into(), Point { x: 3.0, y: 4.0 })]
+                .into_iter()
+                .collect(),
+        ),
+        avatar: Some(pilota::Bytes::from_static(b"png")),
+        legacy_token: Some("left out".into()),
+    };
+
+    // the thrift struct encodes the protobuf message
+    let encoded = user.encode_to_vec(&mut EncodeLengthContext::default());
+    let decoded = pb::User::decode(encoded.into()).unwrap();
+    assert_eq!(decoded.id, 7);
+    assert_eq!(decoded.name, "alice");
+    assert_eq!(decoded.status, Some(pb::Status::INACTIVE));
+    assert_eq!(decoded.path.len(), 2);
+    assert_eq!(decoded.path[0].y, -2.5);
+    assert_eq!(decoded.scores, vec![-3, 0, 42]);
+    assert_eq!(decoded.places["home"].x, 3.0);
+    assert_eq!(decoded.avatar.as_deref(), Some(&b"png"[..]));
+
+    // and decodes it, without the thrift-only fields
+    let encoded = decoded.encode_to_vec(&mut EncodeLengthContext::default());
+    let from_pb = <User as pilota::pb::Message>::decode(encoded.into()).unwrap();
+    assert_eq!(
+        from_pb,
+        User {
+            legacy_token: None,
+            ..user.clone()
+        }
+    );
+
+    // while still encoding thrift
+    let mut buf = BytesMut::new();
+    pilota::thrift::Message::encode(&user, &mut TBinaryProtocol::new(&mut buf, true)).unwrap();
+    let mut bytes = buf.freeze();
+    let from_thrift =
+        <User as pilota::thrift::Message>::decode(&mut TBinaryProtocol::new(&mut bytes, true))
+            .unwrap();
+    assert_eq!(from_thrift, user);
+}
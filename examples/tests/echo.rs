@@ -0,0 +1,81 @@
+//! Round trips of the generated types through a real socket, with the
+//! framed binary and compact protocols.
+
+use examples::echo::{self, Client, EchoRequest, EchoResponse};
+use pilota::{
+    thrift::codec::{Binary, Compact, FrameProtocol},
+    Bytes,
+};
+use tokio::net::TcpListener;
+
+async fn start<P: FrameProtocol + Send + 'static>() -> std::net::SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(echo::serve::<P>(listener));
+    addr
+}
+
+fn request(seq: i64, payload: Option<Bytes>) -> EchoRequest {
+    EchoRequest {
+        seq,
+        message: format!("message #{seq}").into(),
+        payload,
+    }
+}
+
+async fn roundtrip<P: FrameProtocol + Send + 'static>() {
+    let addr = start::<P>().await;
+    let mut client = Client::<P>::connect(addr).await.unwrap();
+    for seq in 0..16 {
+        let payload = (seq % 2 == 0).then(|| Bytes::from(vec![seq as u8; seq as usize * 1024]));
+        let resp = client.echo(request(seq, payload.clone())).await.unwrap();
+        assert_eq!(
+            resp,
+            EchoResponse {
+                seq,
+                message: format!("message #{seq}").into(),
+                payload,
+            }
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_echo_binary() {
+    roundtrip::<Binary>().await;
+}
+
+#[tokio::test]
+async fn test_echo_compact() {
+    roundtrip::<Compact>().await;
+}
+
+#[tokio::test]
+async fn test_echo_concurrent_clients() {
+    let addr = start::<Binary>().await;
+    let clients = (0..8).map(|id| {
+        tokio::spawn(async move {
+            let mut client = Client::<Binary>::connect(addr).await.unwrap();
+            for seq in 0..8 {
+                let seq = id * 100 + seq;
+                assert_eq!(client.echo(request(seq, None)).await.unwrap().seq, seq);
+            }
+        })
+    });
+    for client in clients {
+        client.await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_echo_large_frame() {
+    let addr = start::<Compact>().await;
+    let mut client = Client::<Compact>::connect(addr).await.unwrap();
+    // spans many socket reads
+    let payload = Bytes::from(vec![7; 4 * 1024 * 1024]);
+    let resp = client
+        .echo(request(1, Some(payload.clone())))
+        .await
+        .unwrap();
+    assert_eq!(resp.payload, Some(payload));
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::missing_transmute_annotations)]
+
 //! Example demonstrating Salsa caching functionality
 
 use std::sync::Arc;
@@ -7,6 +9,7 @@ use pilota_build::{
     db::{RirDatabase, RootDatabase},
     middle::ext::ItemExts,
     rir::{self, Method, MethodSource, Node, NodeKind},
+    tags::StreamingMode,
     ty::{Ty, TyKind},
 };
 use rustc_hash::FxHashMap;
@@ -45,6 +48,7 @@ fn main() {
                 tags_id: TagId::from_u32(0),
             },
             oneway: false,
+            streaming: StreamingMode::Unary,
             exceptions: None,
             source: MethodSource::Own,
             item_exts: ItemExts::Thrift,
@@ -71,7 +75,7 @@ fn main() {
     new_nodes.insert(
         def_id,
         Node {
-            file_id: unsafe { std::mem::transmute(0u32) }, // Workaround for demo
+            file_id: unsafe { std::mem::transmute::<u32, _>(0u32) }, // Workaround for demo
             kind: NodeKind::Item(Arc::new(service)),
             parent: None,
             tags: TagId::from_u32(0),
@@ -0,0 +1,116 @@
+//! Backends generating from the resolved IDLs something other than the Rust
+//! code of [`Builder::compile`](crate::Builder::compile), run with
+//! [`Builder::emit`](crate::Builder::emit).
+//!
+//! Downstream crates implement [`Backend`] to add their own targets, such as
+//! documentation or other languages, on top of the same IR:
+//!
+//! ```
+//! use std::fmt::Write as _;
+//!
+//! use pilota_build::{Context, GeneratedFiles, backend::Backend, db::RirDatabase, rir::Item};
+//!
+//! struct StructList;
+//!
+//! impl Backend for StructList {
+//!     fn name(&self) -> &str {
+//!         "struct-list"
+//!     }
+//!
+//!     fn generate(&self, cx: &Context) -> anyhow::Result<GeneratedFiles> {
+//!         let mut out = String::new();
+//!         for def_id in &cx.cache.codegen_items {
+//!             if let Some(Item::Message(m)) = cx.db.item(*def_id).as_deref() {
+//!                 writeln!(out, "{}", m.name)?;
+//!             }
+//!         }
+//!         let mut files = GeneratedFiles::default();
+//!         files.insert("structs.txt", out);
+//!         Ok(files)
+//!     }
+//! }
+//! ```
+
+use std::path::PathBuf;
+
+use crate::{Context, GeneratedFiles};
+
+/// A generator of files from the IR, the items to generate being
+/// [`cx.cache.codegen_items`](crate::middle::context::Cache::codegen_items).
+///
+/// The context is set while `generate` runs, so the helpers of the Rust
+/// codegen resolving paths, such as [`CodegenTy`](crate::ty::CodegenTy)'s
+/// `Display`, work too.
+pub trait Backend {
+    /// Names the backend in the errors.
+    fn name(&self) -> &str;
+
+    /// Returns the generated files, keyed by their path relative to the
+    /// output directory.
+    fn generate(&self, cx: &Context) -> anyhow::Result<GeneratedFiles>;
+}
+
+/// Exports items as a [WIT](crate::wit) package, see
+/// [`Builder::wit`](crate::Builder::wit).
+pub struct WitBackend {
+    path: PathBuf,
+    package: String,
+    items: Vec<String>,
+}
+
+impl WitBackend {
+    /// Exports `items`, IDL paths such as `shop.Order`, as the package
+    /// `package` into the file at `path`.
+    pub fn new(
+        path: impl Into<PathBuf>,
+        package: impl Into<String>,
+        items: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        WitBackend {
+            path: path.into(),
+            package: package.into(),
+            items: items.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Backend for WitBackend {
+    fn name(&self) -> &str {
+        "wit"
+    }
+
+    fn generate(&self, cx: &Context) -> anyhow::Result<GeneratedFiles> {
+        let mut files = GeneratedFiles::default();
+        files.insert(
+            &self.path,
+            crate::wit::export(cx, &self.package, &self.items)?,
+        );
+        Ok(files)
+    }
+}
+
+/// Renders the [`DependencyGraph`](crate::graph::DependencyGraph) in the
+/// DOT language to `{stem}.dot` and in JSON to `{stem}.json`.
+pub struct DependencyGraphBackend {
+    stem: PathBuf,
+}
+
+impl DependencyGraphBackend {
+    pub fn new(stem: impl Into<PathBuf>) -> Self {
+        DependencyGraphBackend { stem: stem.into() }
+    }
+}
+
+impl Backend for DependencyGraphBackend {
+    fn name(&self) -> &str {
+        "dependency-graph"
+    }
+
+    fn generate(&self, cx: &Context) -> anyhow::Result<GeneratedFiles> {
+        let graph = crate::graph::DependencyGraph::collect(cx);
+        let mut files = GeneratedFiles::default();
+        files.insert(self.stem.with_extension("dot"), graph.to_dot());
+        files.insert(self.stem.with_extension("json"), graph.to_json());
+        Ok(files)
+    }
+}
@@ -1,10 +1,12 @@
 use std::{
+    collections::BTreeSet,
     io::Write,
     ops::Deref,
     path::{Path, PathBuf},
 };
 
 use ahash::{AHashMap, AHashSet};
+use anyhow::Context as _;
 use dashmap::{DashMap, mapref::one::RefMut};
 use faststr::FastStr;
 use itertools::Itertools;
@@ -19,7 +21,6 @@ use crate::{
     Context, Symbol,
     db::RirDatabase,
     dedup::def_id_equal,
-    fmt::fmt_file,
     middle::{
         self,
         context::{Mode, tls::CUR_ITEM},
@@ -27,7 +28,8 @@ use crate::{
     },
     rir::{Item, NodeKind},
     symbol::{DefId, EnumRepr, FileId, ModPath},
-    tags::protobuf::Deprecated,
+    tags::{EnumAlias, protobuf::Deprecated},
+    util,
 };
 
 pub(crate) mod pkg_tree;
@@ -153,6 +155,10 @@ where
             );
         }
 
+        if self.has_cached_size(def_id, s) {
+            fields.push_str("\npub _cached_size: ::pilota::thrift::cached_size::CachedSize,\n");
+        }
+
         let deprecated_attr = if self.is_deprecated(def_id) {
             "#[deprecated]\n"
         } else {
@@ -286,6 +292,10 @@ where
         stream: &mut String,
         e: &middle::rir::Enum,
     ) {
+        if self.config.with_unknown_enum_variant {
+            return self.write_enum_with_unknown_variant(def_id, stream, e);
+        }
+
         let name = self.rust_name(def_id);
 
         let repr = match e.repr {
@@ -313,7 +323,10 @@ where
 
                 (
                     format!("{deprecated_attr}pub const {name}: Self = Self({discr});"),
-                    format!("Self({discr}) => ::std::string::String::from(\"{name}\"),"),
+                    format!(
+                        "Self({discr}) => ::std::option::Option::Some(\"{}\"),",
+                        v.name
+                    ),
                 )
             })
             .collect::<Vec<_>>();
@@ -344,6 +357,7 @@ where
         } else {
             Default::default()
         };
+        let impl_str = self.codegen_enum_str_impls(&name, e);
 
         stream.push_str(&format! {
             r#"#[derive(Clone, PartialEq, Copy)]
@@ -357,10 +371,10 @@ where
                     self.0
                 }}
 
-                pub fn to_string(&self) -> ::std::string::String {{
+                pub fn as_str(&self) -> ::std::option::Option<&'static str> {{
                     match self {{
                         {variants_as_str_fields}
-                        Self(val) => val.to_string(),
+                        Self(_) => ::std::option::Option::None,
                     }}
                 }}
 
@@ -386,12 +400,186 @@ where
                 }}
             }}
 
+            {impl_str}
             "#
         });
 
         self.backend.codegen_enum_impl(def_id, stream, e);
     }
 
+    pub fn write_enum_with_unknown_variant(
+        &self,
+        def_id: DefId,
+        stream: &mut String,
+        e: &middle::rir::Enum,
+    ) {
+        let name = self.rust_name(def_id);
+
+        let Some(EnumRepr::I32) = e.repr else {
+            util::error_abort(format!(
+                "{}: `with_unknown_enum_variant` only supports i32 enums",
+                e.name
+            ));
+        };
+        let repr = quote!(i32);
+
+        let variants = e
+            .variants
+            .iter()
+            .map(|v| {
+                let name = self.rust_name(v.did);
+                let discr = v.discr.unwrap() as i32;
+
+                let attrs = self.with_adjust(v.did, |adjust| {
+                    adjust
+                        .iter()
+                        .flat_map(|a| a.attrs())
+                        .map(|attr| format!("{attr}\n"))
+                        .join("")
+                });
+
+                let deprecated_attr = if self.is_deprecated(v.did) {
+                    "#[deprecated]\n"
+                } else {
+                    ""
+                };
+
+                (
+                    format!("{attrs}{deprecated_attr}{name},"),
+                    format!("Self::{name} => {discr},"),
+                    format!(
+                        "Self::{name} => ::std::option::Option::Some(\"{}\"),",
+                        v.name
+                    ),
+                    format!("{discr} => Self::{name},"),
+                )
+            })
+            .collect::<Vec<_>>();
+        let variants_def = variants.iter().map(|(v, _, _, _)| v).join("\n");
+        let inner_arms = variants.iter().map(|(_, v, _, _)| v).join("\n");
+        let as_str_arms = variants.iter().map(|(_, _, v, _)| v).join("\n");
+        let from_arms = variants.iter().map(|(_, _, _, v)| v).join("\n");
+
+        let deprecated_attr = if self.is_deprecated(def_id) {
+            "#[deprecated]\n"
+        } else {
+            ""
+        };
+
+        let impl_enum_message = if self.config.with_descriptor {
+            self.backend.codegen_impl_enum_message(&name)
+        } else {
+            Default::default()
+        };
+        let impl_str = self.codegen_enum_str_impls(&name, e);
+
+        stream.push_str(&format! {
+            r#"#[derive(Clone, PartialEq, Copy)]
+            {deprecated_attr}pub enum {name} {{
+                {variants_def}
+                Unknown({repr}),
+            }}
+
+            impl {name} {{
+                pub fn inner(&self) -> {repr} {{
+                    match self {{
+                        {inner_arms}
+                        Self::Unknown(val) => *val,
+                    }}
+                }}
+
+                pub fn as_str(&self) -> ::std::option::Option<&'static str> {{
+                    match self {{
+                        {as_str_arms}
+                        Self::Unknown(_) => ::std::option::Option::None,
+                    }}
+                }}
+
+                pub fn try_from_{repr}(value: {repr}) -> ::std::option::Option<Self> {{
+                    match Self::from(value) {{
+                        Self::Unknown(_) => None,
+                        val => Some(val),
+                    }}
+                }}
+
+                pub fn is_unknown(&self) -> bool {{
+                    matches!(self, Self::Unknown(_))
+                }}
+            }}
+
+            {impl_enum_message}
+
+            impl ::std::convert::From<{repr}> for {name} {{
+                fn from(value: {repr}) -> Self {{
+                    match value {{
+                        {from_arms}
+                        _ => Self::Unknown(value),
+                    }}
+                }}
+            }}
+
+            impl ::std::convert::From<{name}> for {repr} {{
+                fn from(value: {name}) -> {repr} {{
+                    value.inner()
+                }}
+            }}
+
+            {impl_str}
+            "#
+        });
+
+        self.backend.codegen_enum_impl(def_id, stream, e);
+    }
+
+    /// `Display` and `FromStr` impls of an i32 enum, spelled with the IDL
+    /// variant names. `FromStr` also accepts the `pilota.alias` names of a
+    /// variant and plain numbers.
+    fn codegen_enum_str_impls(&self, name: &str, e: &middle::rir::Enum) -> String {
+        let from_str_arms = e
+            .variants
+            .iter()
+            .map(|v| {
+                let variant_name = self.rust_name(v.did);
+                let aliases = self
+                    .node_tags(v.did)
+                    .and_then(|tags| tags.get::<EnumAlias>().map(|alias| alias.0.clone()))
+                    .unwrap_or_default();
+                let patterns = std::iter::once(v.name.to_string())
+                    .chain(aliases.iter().map(|a| a.to_string()))
+                    .map(|n| format!("\"{n}\""))
+                    .join(" | ");
+                format!("{patterns} => ::std::result::Result::Ok(Self::{variant_name}),")
+            })
+            .join("\n");
+
+        format!(
+            r#"
+            impl ::std::fmt::Display for {name} {{
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {{
+                    match self.as_str() {{
+                        ::std::option::Option::Some(s) => f.write_str(s),
+                        ::std::option::Option::None => ::std::fmt::Display::fmt(&self.inner(), f),
+                    }}
+                }}
+            }}
+
+            impl ::std::str::FromStr for {name} {{
+                type Err = ::pilota::EnumConvertError<::std::string::String>;
+
+                fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {{
+                    match s {{
+                        {from_str_arms}
+                        _ => s
+                            .parse::<i32>()
+                            .map(Self::from)
+                            .map_err(|_| ::pilota::EnumConvertError::InvalidNum(s.to_string(), "{name}")),
+                    }}
+                }}
+            }}
+            "#
+        )
+    }
+
     pub fn write_enum(&self, def_id: DefId, stream: &mut String, e: &middle::rir::Enum) {
         if e.repr.is_some() {
             return self.write_enum_as_new_type(def_id, stream, e);
@@ -463,6 +651,7 @@ where
         let methods = methods
             .iter()
             .map(|m| self.backend.codegen_service_method(def_id, m))
+            .filter(|m| !m.is_empty())
             .join("\n");
 
         let deprecated_attr = if self.is_deprecated(def_id) {
@@ -594,7 +783,7 @@ where
         base_dir: &Path,
     ) {
         // collect mod files and file has direct
-        let mut mod_files = AHashMap::<ModPath, AHashSet<FileId>>::default();
+        let mut mod_files = AHashMap::<ModPath, BTreeSet<FileId>>::default();
         let mut file_has_direct = AHashMap::default();
 
         for (mod_path, items) in mod_items.iter() {
@@ -616,19 +805,16 @@ where
         if self.config.with_descriptor {
             let mods_files_with_direct_items = mod_items
                 .keys()
+                .sorted()
                 .flat_map(|mod_path| {
                     mod_files
                         .get(mod_path)
                         .unwrap()
                         .iter()
+                        .filter(|file_id| *file_has_direct.get(file_id).unwrap())
                         .filter_map(|file_id| {
-                            if *file_has_direct.get(file_id).unwrap()
-                                && let Some(file_path) = self.file_paths().get(file_id)
-                            {
-                                Some((mod_path.clone(), file_path.clone()))
-                            } else {
-                                None
-                            }
+                            let file_path = self.file_paths().get(file_id)?;
+                            Some((mod_path.clone(), file_path.clone()))
                         })
                         .collect::<Vec<_>>()
                 })
@@ -813,7 +999,7 @@ where
                 std::io::BufWriter::new(std::fs::File::create(full_path.clone()).unwrap());
             file.write_all(item_stream.as_bytes()).unwrap();
             file.flush().unwrap();
-            fmt_file(full_path);
+            this.finish_file(full_path);
 
             mod_stream.push_str(format!("include!(\"{file_name}\");\n").as_str());
         }
@@ -823,7 +1009,7 @@ where
         let mut mod_file = std::io::BufWriter::new(std::fs::File::create(&mod_path).unwrap());
         mod_file.write_all(mod_stream.as_bytes()).unwrap();
         mod_file.flush().unwrap();
-        fmt_file(&mod_path);
+        this.finish_file(&mod_path);
 
         stream.push_str(format!("include!(\"{mod_file_name}\");\n").as_str());
     }
@@ -867,20 +1053,26 @@ where
 
         self.write_items(&mut stream, mod_items, base_dir);
 
+        let attrs = self.config.mod_attrs();
         stream = format! {r#"pub mod {ns_name} {{
-                #![allow(warnings, clippy::all)]
+                {attrs}
                 {stream}
             }}"#};
         let stream = stream.lines().map(|s| s.trim_end()).join("\n");
         let mut file = std::io::BufWriter::new(std::fs::File::create(&file_name).unwrap());
         file.write_all(stream.as_bytes()).unwrap();
         file.flush().unwrap();
-        fmt_file(file_name)
+        self.finish_file(file_name)
     }
 
     pub fn r#gen(self) -> anyhow::Result<()> {
+        let extra_files = self.cache.extra_files.clone();
+        let base_dir = match &*self.source.mode {
+            Mode::Workspace(info) => info.dir.clone(),
+            Mode::SingleFile { file_path } => file_path.parent().unwrap().to_path_buf(),
+        };
         match &*self.source.mode.clone() {
-            Mode::Workspace(info) => self.write_workspace(info.dir.clone()),
+            Mode::Workspace(info) => self.write_workspace(info.dir.clone())?,
             Mode::SingleFile { file_path: p } => {
                 self.write_file(
                     FastStr::new(
@@ -892,8 +1084,25 @@ where
                     .into(),
                     p,
                 );
-                Ok(())
             }
         }
+        write_extra_files(&base_dir, &extra_files)
+    }
+}
+
+/// Writes the files emitted by plugins through [`Context::emit_file`].
+fn write_extra_files(base_dir: &Path, files: &DashMap<PathBuf, String>) -> anyhow::Result<()> {
+    let files = files
+        .iter()
+        .map(|f| (f.key().clone(), f.value().clone()))
+        .sorted();
+    for (path, contents) in files {
+        let path = base_dir.join(path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, contents)
+            .with_context(|| format!("failed to write {}", path.display()))?;
     }
+    Ok(())
 }
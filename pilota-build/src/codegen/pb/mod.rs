@@ -4,6 +4,7 @@ use faststr::FastStr;
 use itertools::Itertools;
 use proc_macro2::{Ident, Span};
 use quote::quote;
+use rustc_hash::FxHashSet;
 
 use crate::{
     CodegenBackend, Context, DefId, Symbol,
@@ -18,7 +19,7 @@ use crate::{
     },
     rir::{self, Field, FieldKind, Item, NodeKind},
     symbol::ModPath,
-    tags::protobuf::{OneOf, ProstType},
+    tags::protobuf::{OneOf, ProstType, Proto2Required},
     ty::Ty,
 };
 
@@ -200,6 +201,90 @@ impl ProtobufBackend {
         }
     }
 
+    // the message of a field, through `Vec` and `Arc`
+    fn field_message(&self, ty: &Ty) -> Option<DefId> {
+        match &ty.kind {
+            ty::TyKind::Vec(inner) | ty::TyKind::Arc(inner) => self.field_message(inner),
+            ty::TyKind::Path(p) => match &*self.cx.item(p.did)? {
+                Item::Message(_) => Some(p.did),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Whether a message or one nested in it has proto2 `required` fields.
+    fn has_required_fields(&self, def_id: DefId, visited: &mut FxHashSet<DefId>) -> bool {
+        if !visited.insert(def_id) {
+            return false;
+        }
+        let Some(item) = self.cx.item(def_id) else {
+            return false;
+        };
+        let Item::Message(s) = &*item else {
+            return false;
+        };
+        s.fields.iter().any(|f| {
+            self.cx.node_contains_tag::<Proto2Required>(f.did)
+                || self
+                    .field_message(&f.ty)
+                    .is_some_and(|did| self.has_required_fields(did, visited))
+        })
+    }
+
+    fn codegen_missing_required_fields(&self, def_id: DefId, s: &rir::Message) -> String {
+        if !self.has_required_fields(def_id, &mut FxHashSet::default()) {
+            return String::new();
+        }
+        let idl_name = s.name.raw_str();
+        let checks = s
+            .fields
+            .iter()
+            .filter_map(|field| {
+                let field_name = self.cx.rust_name(field.did);
+                let nested = self
+                    .field_message(&field.ty)
+                    .is_some_and(|did| self.has_required_fields(did, &mut FxHashSet::default()));
+                let check_nested = if matches!(field.ty.kind, ty::TyKind::Vec(_)) {
+                    "for value in value { ::pilota::pb::Message::missing_required_fields(value, missing); }"
+                } else {
+                    "::pilota::pb::Message::missing_required_fields(value, missing);"
+                };
+                if self.cx.node_contains_tag::<Proto2Required>(field.did) {
+                    let push = format!(r#"missing.push("{idl_name}.{}");"#, field.name.raw_str());
+                    Some(if nested {
+                        format!(
+                            r#"match &self.{field_name} {{
+                                Some(value) => {{ {check_nested} }}
+                                None => {{ {push} }}
+                            }}"#
+                        )
+                    } else {
+                        format!("if self.{field_name}.is_none() {{ {push} }}")
+                    })
+                } else if nested {
+                    Some(match field.kind {
+                        FieldKind::Required => {
+                            format!("{{ let value = &self.{field_name}; {check_nested} }}")
+                        }
+                        FieldKind::Optional => format!(
+                            "if let Some(value) = &self.{field_name} {{ {check_nested} }}"
+                        ),
+                    })
+                } else {
+                    None
+                }
+            })
+            .join("\n");
+        format!(
+            r#"
+            fn missing_required_fields(&self, missing: &mut ::std::vec::Vec<&'static str>) {{
+                {checks}
+            }}
+            "#
+        )
+    }
+
     fn is_plain_enum(&self, def_id: DefId) -> bool {
         let node = self.cx.node(def_id).unwrap();
         if let NodeKind::Item(item) = node.kind {
@@ -686,6 +771,8 @@ impl CodegenBackend for ProtobufBackend {
             stream.push_str(&getter_impl);
         }
 
+        let missing_required_fields = self.codegen_missing_required_fields(def_id, s);
+
         stream.push_str(&format!(
             r#"
             impl ::pilota::pb::Message for {name} {{
@@ -716,6 +803,7 @@ impl CodegenBackend for ProtobufBackend {
                         _ => {skip_field}
                     }}
                 }}
+                {missing_required_fields}
             }}
             "#
         ));
@@ -879,9 +967,11 @@ impl CodegenBackend for ProtobufBackend {
 
         let file_id = self.cx.node(def_id).unwrap().file_id;
         let mut getter_impl = String::new();
-        if self.cx.config.with_descriptor
-            && let ItemExts::Pb(pb) = &e.item_exts
-        {
+        let pb = match &e.item_exts {
+            ItemExts::Pb(pb) if self.cx.config.with_descriptor => Some(pb),
+            _ => None,
+        };
+        if let Some(pb) = pb {
             if let Some(p) = &pb.parent {
                 if self.cx.file_paths().get(&file_id).is_some() {
                     let path = self.cx.related_item_path(def_id, p.did);
@@ -1098,7 +1188,7 @@ pub fn file_descriptor_{filename_lower}() -> &'static ::pilota::pb::reflect::Fil
                 }}
 
                 fn to_string(&self) -> ::std::string::String {{
-                    self.to_string()
+                    ::std::string::ToString::to_string(self)
                 }}
 
                 fn try_from_i32(value: i32) -> ::std::option::Option<Self> {{
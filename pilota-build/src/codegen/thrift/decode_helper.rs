@@ -64,10 +64,12 @@ impl DecodeHelper {
     protocol_method!(read_map_end);
     protocol_method!(read_struct_begin);
     protocol_method!(read_struct_end);
+    protocol_method!(read_empty_struct);
     protocol_method!(read_field_begin);
     protocol_method!(read_field_end);
     protocol_method!(read_bool);
     protocol_method!(read_uuid);
+    protocol_method!(read_uuid_typed);
 
     protocol_len!(field_end_len);
     protocol_len!(field_stop_len);
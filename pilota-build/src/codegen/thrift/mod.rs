@@ -5,15 +5,20 @@ use itertools::Itertools;
 
 use super::traits::CodegenBackend;
 use crate::{
+    IdentName,
     db::RirDatabase,
+    metrics::Sizer,
     middle::{
-        context::{Context, Mode},
-        rir::{self, Enum, Field, Message, Method, NewType, Service},
+        context::{Context, DefaultFieldEncoding, Mode, UnionPolicy},
+        rir::{self, Enum, Field, Message, NewType, Service},
     },
     rir::EnumVariant,
     symbol::{DefId, EnumRepr, ModPath, Symbol},
-    tags::thrift::EntryMessage,
-    ty::TyKind,
+    tags::{
+        AllowEmptyUnion, Annotation, Checksum, Compress, Overlay,
+        thrift::{DefaultRequiredness, EntryMessage, MethodOptions},
+    },
+    ty::{Ty, TyKind},
 };
 
 mod ty;
@@ -22,6 +27,10 @@ pub use self::decode_helper::DecodeHelper;
 
 mod decode_helper;
 
+/// Pass the `FIELD_NAMES` of the struct just begun to the protocol.
+const WRITE_FIELD_NAMES: &str = "__protocol.write_field_names(Self::FIELD_NAMES);";
+const FIELD_NAMES_LEN: &str = "__protocol.field_names_len(Self::FIELD_NAMES) +";
+
 #[derive(Clone)]
 pub struct ThriftBackend {
     cx: Context,
@@ -42,6 +51,297 @@ impl Deref for ThriftBackend {
 }
 
 impl ThriftBackend {
+    /// The value to write for the field when it is `None`, if it must be
+    /// written anyway, see [`DefaultFieldEncoding::AlwaysWrite`].
+    fn unset_field_default(&self, f: &rir::Field) -> Option<FastStr> {
+        if self.config.default_field_encoding != DefaultFieldEncoding::AlwaysWrite
+            || !self.node_contains_tag::<DefaultRequiredness>(f.did)
+            // writing a default struct could recurse forever through a
+            // recursive type
+            || self.ttype(&f.ty) == "::pilota::thrift::TType::Struct"
+        {
+            return None;
+        }
+        Some(match self.default_val(f) {
+            Some((default, _)) if self.field_is_box(f) => {
+                format!("::std::boxed::Box::new({default})").into()
+            }
+            Some((default, _)) => default,
+            None => "::std::default::Default::default()".into(),
+        })
+    }
+
+    /// The codec of a field annotated with `pilota.compress`.
+    fn field_compression(&self, f: &rir::Field) -> Option<&'static str> {
+        let compress = *self.tags(f.tags_id)?.get::<Compress>()?;
+        Self::assert_binary_field(f, Compress::KEY);
+        Some(match compress {
+            Compress::Zstd => "::pilota::thrift::compress::Compression::Zstd",
+        })
+    }
+
+    /// Whether the field is annotated with `pilota.checksum`.
+    fn field_checksum(&self, f: &rir::Field) -> bool {
+        let Some(Checksum::Crc32c) = self
+            .tags(f.tags_id)
+            .and_then(|tags| tags.get::<Checksum>().copied())
+        else {
+            return false;
+        };
+        Self::assert_binary_field(f, Checksum::KEY);
+        true
+    }
+
+    fn assert_binary_field(f: &rir::Field, annotation: &str) {
+        if !matches!(f.ty.kind, TyKind::Bytes | TyKind::BytesVec) {
+            panic!(
+                "`{annotation}` is only supported on binary fields, `{}` is not binary",
+                f.name
+            );
+        }
+    }
+
+    /// Writes a binary field annotated with `pilota.compress` or
+    /// `pilota.checksum`, or computes its size if `size` is set. Both build
+    /// the value put on the wire, compressed first and then checksummed.
+    fn codegen_wrapped_bytes_field(
+        &self,
+        f: &rir::Field,
+        ident: &str,
+        size: bool,
+    ) -> Option<FastStr> {
+        let compression = self.field_compression(f);
+        let checksum = self.field_checksum(f);
+        if compression.is_none() && !checksum {
+            return None;
+        }
+        let id = f.id as i16;
+        let or_err = if size { ".unwrap_or_default()" } else { "?" };
+
+        let mut value = ident.to_string();
+        if let Some(compression) = compression {
+            value =
+                format!("&::pilota::thrift::compress::compress({compression}, {value}){or_err}");
+        }
+        if checksum {
+            value = format!("&::pilota::thrift::checksum::append_crc32c({value})");
+        }
+        let value = value.trim_start_matches('&');
+        Some(if size {
+            format!("__protocol.bytes_field_len(Some({id}), &{value})").into()
+        } else {
+            format!("__protocol.write_bytes_field({id}, {value})?;").into()
+        })
+    }
+
+    /// The `{Name}Overlay` of a struct annotated with `pilota.overlay`, see
+    /// `::pilota::overlay`.
+    fn codegen_overlay(&self, def_id: DefId, stream: &mut String, s: &Message) {
+        let Some(overlay) = self
+            .node_tags(def_id)
+            .and_then(|tags| tags.get::<Overlay>().copied())
+        else {
+            return;
+        };
+        let name = self.rust_name(def_id);
+        if self.config.forbid_unsafe_code {
+            panic!(
+                "`{}` on `{name}` needs unsafe code, which is forbidden",
+                Overlay::KEY
+            );
+        }
+        let endian = match overlay {
+            Overlay::LittleEndian => "le",
+            Overlay::BigEndian => "be",
+        };
+
+        let mut fields = String::new();
+        let mut accessors = String::new();
+        let mut to_overlay = String::new();
+        let mut from_overlay = String::new();
+        for f in &s.fields {
+            let field_name = self.rust_name(f.did);
+            let (ty, len) = match f.ty.kind {
+                TyKind::Bool => ("bool", 1),
+                TyKind::I8 => ("i8", 1),
+                TyKind::I16 => ("i16", 2),
+                TyKind::I32 => ("i32", 4),
+                TyKind::I64 => ("i64", 8),
+                TyKind::F64 => ("f64", 8),
+                _ => panic!(
+                    "`{}` is only supported on structs of bool, byte, integer and double fields, `{}` is not",
+                    Overlay::KEY,
+                    f.name
+                ),
+            };
+            if f.is_optional() {
+                panic!(
+                    "`{}` is only supported on structs of required fields, `{}` is not required",
+                    Overlay::KEY,
+                    f.name
+                );
+            }
+            let (get, set) = if ty == "bool" {
+                (
+                    format!("self.{field_name}[0] != 0"),
+                    "[value as u8]".to_string(),
+                )
+            } else {
+                (
+                    format!("{ty}::from_{endian}_bytes(self.{field_name})"),
+                    format!("value.to_{endian}_bytes()"),
+                )
+            };
+            fields.push_str(&format!("{field_name}: [u8; {len}],\n"));
+            accessors.push_str(&format! {
+                r#"pub fn {field_name}(&self) -> {ty} {{
+                    {get}
+                }}
+
+                pub fn set_{field_name}(&mut self, value: {ty}) {{
+                    self.{field_name} = {set};
+                }}
+                "#
+            });
+            to_overlay.push_str(&format!("overlay.set_{field_name}(value.{field_name});\n"));
+            from_overlay.push_str(&format!("{field_name}: value.{field_name}(),\n"));
+        }
+        if self.cache.keep_unknown_fields.contains(&def_id) {
+            from_overlay.push_str("_unknown_fields: ::pilota::BytesVec::new(),\n");
+        }
+        if !s.is_wrapper && self.config.with_field_mask {
+            from_overlay.push_str("_field_mask: None,\n");
+        }
+        if self.has_cached_size(def_id, s) {
+            from_overlay.push_str("_cached_size: ::std::default::Default::default(),\n");
+        }
+
+        stream.push_str(&format! {
+            r#"
+            #[repr(C)]
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+            pub struct {name}Overlay {{
+                {fields}
+            }}
+
+            const _: () = ::std::assert!(::std::mem::align_of::<{name}Overlay>() == 1);
+
+            // SAFETY: only byte arrays, so no padding and an alignment of 1
+            unsafe impl ::pilota::overlay::Overlay for {name}Overlay {{}}
+
+            impl {name}Overlay {{
+                {accessors}
+            }}
+
+            impl ::std::convert::From<&{name}> for {name}Overlay {{
+                fn from(value: &{name}) -> Self {{
+                    let mut overlay = Self::default();
+                    {to_overlay}
+                    overlay
+                }}
+            }}
+
+            impl ::std::convert::From<&{name}Overlay> for {name} {{
+                fn from(value: &{name}Overlay) -> Self {{
+                    Self {{
+                        {from_overlay}
+                    }}
+                }}
+            }}
+            "#
+        });
+    }
+
+    /// Reads a binary field annotated with `pilota.compress` or
+    /// `pilota.checksum`, undoing what [`Self::codegen_wrapped_bytes_field`]
+    /// did.
+    fn codegen_decode_wrapped_bytes(
+        &self,
+        helper: &DecodeHelper,
+        f: &rir::Field,
+    ) -> Option<FastStr> {
+        let compression = self.field_compression(f);
+        let checksum = self.field_checksum(f);
+        if compression.is_none() && !checksum {
+            return None;
+        }
+        let mut value = helper.codegen_read_bytes().to_string();
+        if checksum {
+            value = format!("::pilota::thrift::checksum::verify_crc32c({value})?");
+        }
+        if compression.is_some() {
+            value = format!("::pilota::thrift::compress::decompress({value})?");
+        }
+        if matches!(f.ty.kind, TyKind::BytesVec) {
+            value = format!("{value}.to_vec()");
+        }
+        Some(value.into())
+    }
+
+    /// Binds `value` to a reference to the field, or to `default` if the
+    /// field is `None`.
+    fn field_or_default(field_name: &str, default: &str) -> String {
+        format! {
+            r#"let __pilota_default;
+            let value = match self.{field_name}.as_ref() {{
+                Some(value) => value,
+                None => {{
+                    __pilota_default = {default};
+                    &__pilota_default
+                }}
+            }};"#
+        }
+    }
+
+    fn codegen_empty_struct_encode(name: &str) -> String {
+        format! {
+            r#"__protocol.write_empty_struct(&::pilota::thrift::TStructIdentifier {{
+                name: "{name}",
+            }})?;
+            ::std::result::Result::Ok(())"#
+        }
+    }
+
+    fn codegen_empty_struct_size(name: &str) -> String {
+        format! {
+            r#"__protocol.empty_struct_len(&::pilota::thrift::TStructIdentifier {{
+                name: "{name}",
+            }})"#
+        }
+    }
+
+    /// Decodes an empty struct into `value`.
+    fn codegen_empty_struct_decode(helper: &DecodeHelper, value: &str) -> String {
+        format!(
+            "{};\n::std::result::Result::Ok({value})",
+            helper.codegen_read_empty_struct()
+        )
+    }
+
+    /// The `FIELD_NAMES` of a struct or union, see `write_field_names`.
+    fn codegen_field_names(name: &Symbol, fields: impl Iterator<Item = (i16, FastStr)>) -> String {
+        let fields = fields
+            .map(|(id, name)| format!("({id}, \"{name}\")"))
+            .join(", ");
+        format! {
+            r#"impl {name} {{
+                /// The names of the fields by id, see
+                /// `TOutputProtocol::write_field_names`.
+                pub const FIELD_NAMES: &'static [(i16, &'static str)] = &[{fields}];
+            }}"#
+        }
+    }
+
+    /// Keeps the size computed by `size` in the `_cached_size` of the struct,
+    /// if it has one.
+    fn codegen_cached_size(&self, def_id: DefId, s: &Message, size: String) -> String {
+        if self.has_cached_size(def_id, s) {
+            format!("self._cached_size.size_with(__protocol, |__protocol| {{ {size} }})")
+        } else {
+            size
+        }
+    }
+
     fn codegen_encode_fields_size<'a>(
         &'a self,
         fields: &'a [Arc<rir::Field>],
@@ -51,12 +351,18 @@ impl ThriftBackend {
             let is_optional = f.is_optional();
             let field_id = f.id as i16;
             let write_field = if is_optional {
-                self.codegen_field_size(&f.ty, field_id, "value".into())
+                self.codegen_wrapped_bytes_field(f, "value", true)
+                    .unwrap_or_else(|| self.codegen_field_size(&f.ty, field_id, "value".into()))
             } else {
-                self.codegen_field_size(&f.ty, field_id, format!("&self.{field_name}").into())
+                let ident: FastStr = format!("&self.{field_name}").into();
+                self.codegen_wrapped_bytes_field(f, &ident, true)
+                    .unwrap_or_else(|| self.codegen_field_size(&f.ty, field_id, ident))
             };
 
-            if is_optional {
+            if let Some(default) = self.unset_field_default(f) {
+                let value = Self::field_or_default(&field_name, &default);
+                format!("{{ {value} {write_field} }}").into()
+            } else if is_optional {
                 format!("self.{field_name}.as_ref().map_or(0, |value| {write_field})").into()
             } else {
                 write_field
@@ -73,8 +379,11 @@ impl ThriftBackend {
             let is_optional = f.is_optional();
             let field_id = f.id as i16;
             let write_field = if is_optional {
-                let write_field_size_with_field_mask =
-                    self.codegen_field_size_with_field_mask(&f.ty, field_id, "value".into());
+                let write_field_size_with_field_mask = self
+                    .codegen_wrapped_bytes_field(f, "value", true)
+                    .unwrap_or_else(|| {
+                        self.codegen_field_size_with_field_mask(&f.ty, field_id, "value".into())
+                    });
                 format! {
                     r#"{{
                         let (field_fm, exist) = struct_fm.field({field_id});
@@ -87,11 +396,12 @@ impl ThriftBackend {
                 }
                 .into()
             } else {
-                let write_field_size_with_field_mask = self.codegen_field_size_with_field_mask(
-                    &f.ty,
-                    field_id,
-                    format!("&self.{field_name}").into(),
-                );
+                let ident: FastStr = format!("&self.{field_name}").into();
+                let write_field_size_with_field_mask = self
+                    .codegen_wrapped_bytes_field(f, &ident, true)
+                    .unwrap_or_else(|| {
+                        self.codegen_field_size_with_field_mask(&f.ty, field_id, ident)
+                    });
                 format! {
                     r#"{{
                         let (field_fm, exist) = struct_fm.field({field_id});
@@ -105,7 +415,10 @@ impl ThriftBackend {
                 .into()
             };
 
-            if is_optional {
+            if let Some(default) = self.unset_field_default(f) {
+                let value = Self::field_or_default(&field_name, &default);
+                format!("{{ {value} {write_field} }}").into()
+            } else if is_optional {
                 format!("self.{field_name}.as_ref().map_or(0, |value| {write_field})").into()
             } else {
                 write_field
@@ -122,12 +435,18 @@ impl ThriftBackend {
             let field_id = f.id as i16;
             let is_optional = f.is_optional();
             let write_field = if is_optional {
-                self.codegen_encode_field(field_id, &f.ty, "value".into())
+                self.codegen_wrapped_bytes_field(f, "value", false)
+                    .unwrap_or_else(|| self.codegen_encode_field(field_id, &f.ty, "value".into()))
             } else {
-                self.codegen_encode_field(field_id, &f.ty, format!("&self.{field_name}").into())
+                let ident: FastStr = format!("&self.{field_name}").into();
+                self.codegen_wrapped_bytes_field(f, &ident, false)
+                    .unwrap_or_else(|| self.codegen_encode_field(field_id, &f.ty, ident))
             };
 
-            if is_optional {
+            if let Some(default) = self.unset_field_default(f) {
+                let value = Self::field_or_default(&field_name, &default);
+                format!("{{ {value} {write_field} }}").into()
+            } else if is_optional {
                 format! {
                     r#"if let Some(value) = self.{field_name}.as_ref() {{
                         {write_field}
@@ -149,8 +468,11 @@ impl ThriftBackend {
             let field_id = f.id as i16;
             let is_optional = f.is_optional();
             let write_field = if is_optional {
-                let write_field_with_field_mask =
-                    self.codegen_encode_field_with_field_mask(field_id, &f.ty, "value".into());
+                let write_field_with_field_mask = self
+                    .codegen_wrapped_bytes_field(f, "value", false)
+                    .unwrap_or_else(|| {
+                        self.codegen_encode_field_with_field_mask(field_id, &f.ty, "value".into())
+                    });
                 format! {
                     r#"let (field_fm, exist) = struct_fm.field({field_id});
                     if exist {{
@@ -159,11 +481,12 @@ impl ThriftBackend {
                 }
                 .into()
             } else {
-                let write_field_with_field_mask = self.codegen_encode_field_with_field_mask(
-                    field_id,
-                    &f.ty,
-                    format!("&self.{field_name}").into(),
-                );
+                let ident: FastStr = format!("&self.{field_name}").into();
+                let write_field_with_field_mask = self
+                    .codegen_wrapped_bytes_field(f, &ident, false)
+                    .unwrap_or_else(|| {
+                        self.codegen_encode_field_with_field_mask(field_id, &f.ty, ident)
+                    });
                 format! {
                 r#"let (field_fm, exist) = struct_fm.field({field_id});
                 if exist {{
@@ -173,7 +496,10 @@ impl ThriftBackend {
                 .into()
             };
 
-            if is_optional {
+            if let Some(default) = self.unset_field_default(f) {
+                let value = Self::field_or_default(&field_name, &default);
+                format!("{{ {value} {write_field} }}").into()
+            } else if is_optional {
                 format! {
                     r#"if let Some(value) = self.{field_name}.as_ref() {{
                         {write_field}
@@ -277,11 +603,12 @@ impl ThriftBackend {
     fn codegen_decode(
         &self,
         helper: &DecodeHelper,
+        def_id: DefId,
         s: &rir::Message,
         name: Symbol,
         keep: bool,
-        is_arg: bool,
     ) -> String {
+        let is_arg = self.is_arg(def_id);
         let def_fields_num = if keep && is_arg && !helper.is_async {
             "let mut __pilota_fields_num = 0;"
         } else {
@@ -317,13 +644,20 @@ impl ThriftBackend {
             def_fields.push_str("let mut _unknown_fields = ::pilota::BytesVec::new();");
         }
 
+        if self.config.duplicate_field_check && !s.fields.is_empty() {
+            def_fields.push_str(
+                "let mut __pilota_seen_fields = \
+                 ::pilota::thrift::duplicate::SeenFields::new(__protocol.rejects_duplicate_fields());",
+            );
+        }
+
         let set_default_fields = s
             .fields
             .iter()
             .filter_map(|f| {
                 let field_name = f.local_var_name();
-                match self.cx.default_val(f) { Some((default, is_const)) => {
-                    if !is_const {
+                match self.cx.default_val(f) {
+                    Some((default, false)) => {
                         if f.is_optional() {
                             Some(format! {
                                 r#"if {field_name}.is_none() {{
@@ -335,18 +669,15 @@ impl ThriftBackend {
                                 r#"let {field_name} = {field_name}.unwrap_or_else(|| {default});"#
                             ))
                         }
-                    } else {
-                        None
                     }
-                } _ => {
-                    None
-                }}
+                    _ => None,
+                }
             })
             .join("\n");
 
         let read_struct_begin = helper.codegen_read_struct_begin();
         let read_struct_end = helper.codegen_read_struct_end();
-        let read_fields = self.codegen_decode_fields(helper, &s.fields, keep, is_arg);
+        let read_fields = self.codegen_decode_fields(helper, &name, &s.fields, keep, is_arg);
 
         let required_without_default_fields = s
             .fields
@@ -413,6 +744,13 @@ impl ThriftBackend {
             fields.push_str("_field_mask: ::std::option::Option::None");
         }
 
+        if self.has_cached_size(def_id, s) {
+            if !fields.is_empty() {
+                fields.push_str(", ");
+            }
+            fields.push_str("_cached_size: ::std::default::Default::default()");
+        }
+
         format! {
             r#"
             {def_fields_num}
@@ -456,6 +794,7 @@ impl ThriftBackend {
     fn codegen_decode_fields<'a>(
         &'a self,
         helper: &DecodeHelper,
+        name: &str,
         fields: &'a [Arc<Field>],
         keep: bool,
         is_arg: bool,
@@ -470,36 +809,76 @@ impl ThriftBackend {
         let field_begin_len = helper.codegen_field_begin_len(keep);
         let match_fields = fields
             .iter()
-            .map(|f| {
+            .enumerate()
+            .map(|(index, f)| {
                 let field_ident = f.local_var_name();
                 let ttype = self.ttype(&f.ty);
-                let mut read_field = self.codegen_decode_ty(helper, &f.ty);
+                let mut read_field = self
+                    .codegen_decode_wrapped_bytes(helper, f)
+                    .unwrap_or_else(|| self.codegen_decode_ty(helper, &f.ty));
                 let field_id = f.id as i16;
-                if self.field_is_box(f) {
-                    read_field = format!("::std::boxed::Box::new({read_field})").into();
-                };
-
-                if f.is_optional() || {
-                    match self.cx.default_val(f) {
-                        Some((_, is_const)) => !is_const,
-                        _ => true,
+                let wrap = |mut read_field: FastStr| {
+                    if self.field_is_box(f) {
+                        read_field = format!("::std::boxed::Box::new({read_field})").into();
+                    };
+
+                    if f.is_optional() || {
+                        match self.cx.default_val(f) {
+                            Some((_, is_const)) => !is_const,
+                            _ => true,
+                        }
+                    } {
+                        read_field = format!("Some({read_field})").into();
                     }
-                } {
-                    read_field = format!("Some({read_field})").into();
-                }
+                    read_field
+                };
+                read_field = wrap(read_field);
 
                 let fields_num = if keep && !helper.is_async && is_arg {
                     "__pilota_fields_num -= 1;"
                 } else {
                     ""
                 };
+                let seen = if self.config.duplicate_field_check {
+                    format!("__pilota_seen_fields.insert({index}, {field_id})?;")
+                } else {
+                    String::new()
+                };
 
-                format!(
+                let mut arms = format!(
                     r#"Some({field_id}) if field_ident.field_type == {ttype}  => {{
+                    {seen}
                     {field_ident} = {read_field};
                     {fields_num}
                 }},"#
-                )
+                );
+
+                // the other integer type, coerced
+                let coerced = match f.ty.kind {
+                    TyKind::I32 => Some((TyKind::I64, "narrow", "?")),
+                    TyKind::I64 => Some((TyKind::I32, "widen", "")),
+                    _ => None,
+                };
+                if let Some((kind, coerce, try_)) =
+                    coerced.filter(|_| self.config.lenient_numeric_decode)
+                {
+                    let ty = Ty {
+                        kind,
+                        tags_id: f.ty.tags_id,
+                    };
+                    let ttype = self.ttype(&ty);
+                    let read = self.codegen_decode_ty(helper, &ty);
+                    let read_field =
+                        wrap(format!("::pilota::thrift::coerce::{coerce}({read}){try_}").into());
+                    arms.push_str(&format!(
+                        r#"Some({field_id}) if field_ident.field_type == {ttype}  => {{
+                        {seen}
+                        {field_ident} = {read_field};
+                        {fields_num}
+                    }},"#
+                    ));
+                }
+                arms
             })
             .join("");
         let mut skip_ttype = helper.codegen_skip_ttype("field_ident.field_type".into());
@@ -517,6 +896,20 @@ impl ThriftBackend {
         let field_end_len = helper.codegen_field_end_len(keep);
         let field_stop_len = helper.codegen_field_stop_len(keep);
 
+        // the arms of the fields are guarded on their type, so the ids of the
+        // struct get here when sent with another type
+        let on_unknown_field = if self.config.unknown_field_hook {
+            let type_mismatch = if fields.is_empty() {
+                "false".to_string()
+            } else {
+                let ids = fields.iter().map(|f| f.id as i16).join(" | ");
+                format!("matches!(field_ident.id, Some({ids}))")
+            };
+            format!(r#"__protocol.on_unknown_field("{name}", &field_ident, {type_mismatch});"#)
+        } else {
+            String::new()
+        };
+
         let skip_all = if keep && !helper.is_async && is_arg {
             "if __pilota_fields_num == 0 {
                 let __pilota_remaining = __protocol.buf().remaining();
@@ -542,6 +935,7 @@ impl ThriftBackend {
                 match field_ident.id {{
                     {match_fields}
                     _ => {{
+                        {on_unknown_field}
                         {skip_ttype};
                         {write_unknown_field}
                     }},
@@ -559,6 +953,7 @@ impl CodegenBackend for ThriftBackend {
     const PROTOCOL: &'static str = "thrift";
 
     fn codegen_struct_impl(&self, def_id: DefId, stream: &mut String, s: &Message) {
+        self.codegen_overlay(def_id, stream, s);
         let filename = self
             .cx
             .file_name(self.cx.node(def_id).unwrap().file_id)
@@ -592,33 +987,93 @@ impl CodegenBackend for ThriftBackend {
             .map(|s| format!("{s} +"))
             .join("");
 
+        if self.config.with_field_names && !s.fields.is_empty() {
+            stream.push_str(&Self::codegen_field_names(
+                &name,
+                s.fields.iter().map(|f| (f.id as i16, f.name.raw_str())),
+            ));
+            for (encode, size) in [
+                (&mut encode_fields, &mut encode_fields_size),
+                (
+                    &mut encode_fields_with_field_mask,
+                    &mut encode_fields_size_with_field_mask,
+                ),
+            ] {
+                encode.insert_str(0, WRITE_FIELD_NAMES);
+                size.insert_str(0, FIELD_NAMES_LEN);
+            }
+        }
+
         if keep {
             encode_fields_size.push_str("self._unknown_fields.size() +");
             encode_fields_size_with_field_mask.push_str("self._unknown_fields.size() +");
         }
 
         if s.is_wrapper || !self.config.with_field_mask {
+            // empty structs, such as the args of methods without arguments, skip the
+            // generic field loop
+            let is_empty = s.fields.is_empty() && !keep;
+            let fixed_size = if s.is_wrapper || !self.config.fixed_encoded_size {
+                None
+            } else {
+                Sizer::new(&self.cx).item_fixed_size(def_id)
+            };
+            if let Some(size) = fixed_size {
+                stream.push_str(&format! {
+                    r#"impl {name} {{
+                        /// The size of the struct encoded with the binary protocols, the
+                        /// same for every value.
+                        pub const ENCODED_SIZE: usize = {size};
+                    }}"#
+                });
+            }
+            let size = self.codegen_cached_size(
+                def_id,
+                s,
+                format! {
+                    r#"__protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {{
+                            name: "{name}",
+                        }}) + {encode_fields_size} __protocol.field_stop_len() + __protocol.struct_end_len()"#
+                },
+            );
             stream.push_str(&self.codegen_impl_message_with_helper(
                 def_id,
                 name.clone(),
-                format! {
-                    r#"let struct_ident =::pilota::thrift::TStructIdentifier {{
-                        name: "{name}",
-                    }};
-    
-                    __protocol.write_struct_begin(&struct_ident)?;
-                    {encode_fields}
-                    __protocol.write_field_stop()?;
-                    __protocol.write_struct_end()?;
-                    ::std::result::Result::Ok(())
-                    "#
+                if is_empty {
+                    Self::codegen_empty_struct_encode(&name)
+                } else {
+                    format! {
+                        r#"let struct_ident =::pilota::thrift::TStructIdentifier {{
+                            name: "{name}",
+                        }};
+
+                        __protocol.write_struct_begin(&struct_ident)?;
+                        {encode_fields}
+                        __protocol.write_field_stop()?;
+                        __protocol.write_struct_end()?;
+                        ::std::result::Result::Ok(())
+                        "#
+                    }
                 },
-                format! {
-                    r#"__protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {{
-                        name: "{name}",
-                    }}) + {encode_fields_size} __protocol.field_stop_len() + __protocol.struct_end_len()"#
+                if is_empty {
+                    Self::codegen_empty_struct_size(&name)
+                } else if fixed_size.is_some() {
+                    format! {
+                        r#"if T::FIXED_WIDTH {{
+                            return Self::ENCODED_SIZE;
+                        }}
+                        {size}"#
+                    }
+                } else {
+                    size
+                },
+                |helper| {
+                    if is_empty {
+                        Self::codegen_empty_struct_decode(helper, "Self {}")
+                    } else {
+                        self.codegen_decode(helper, def_id, s, name.clone(), keep)
+                    }
                 },
-                |helper| self.codegen_decode(helper, s, name.clone(), keep, self.is_arg(def_id)),
             ));
 
             if !s.is_wrapper && self.config.with_descriptor {
@@ -665,7 +1120,7 @@ impl CodegenBackend for ThriftBackend {
                         ::std::result::Result::Ok(())
                     }}"#
                 },
-                format! {
+                self.codegen_cached_size(def_id, s, format! {
                     r#"if let Some(struct_fm) = self._field_mask.as_ref() {{
                         if !struct_fm.exist() {{
                             0
@@ -679,8 +1134,8 @@ impl CodegenBackend for ThriftBackend {
                             name: "{name}",
                         }}) + {encode_fields_size} __protocol.field_stop_len() + __protocol.struct_end_len()
                     }}"#
-                },
-                |helper| self.codegen_decode(helper, s, name.clone(), keep, self.is_arg(def_id)),
+                }),
+                |helper| self.codegen_decode(helper, def_id, s, name.clone(), keep),
             ));
         }
 
@@ -736,10 +1191,43 @@ impl CodegenBackend for ThriftBackend {
         });
     }
 
-    fn codegen_service_impl(&self, _def_id: DefId, _stream: &mut String, _s: &Service) {}
+    fn codegen_service_impl(&self, def_id: DefId, stream: &mut String, _s: &Service) {
+        let methods = self
+            .service_methods(def_id)
+            .iter()
+            .filter_map(|m| {
+                let tags = self.node_tags(m.def_id)?;
+                let options = tags.get::<MethodOptions>()?;
+                let options = options
+                    .0
+                    .iter()
+                    .map(|(key, value)| {
+                        let value = if let Ok(b) = value.parse::<bool>() {
+                            format!("Bool({b})")
+                        } else if let Ok(i) = value.parse::<i64>() {
+                            format!("Int({i})")
+                        } else {
+                            format!("Str({value:?})")
+                        };
+                        format!("({key:?}, ::pilota::thrift::OptionValue::{value})")
+                    })
+                    .join(", ");
+                Some(format!(
+                    "::pilota::thrift::MethodOptions {{ method: {:?}, options: &[{options}] }}",
+                    m.name.to_string()
+                ))
+            })
+            .join(",\n");
+        if methods.is_empty() {
+            return;
+        }
 
-    fn codegen_service_method(&self, _service_def_id: DefId, _m: &Method) -> String {
-        Default::default()
+        let name = self.rust_name(def_id).0.const_ident();
+        stream.push_str(&format! {
+            r#"pub static {name}_METHOD_OPTIONS: &[::pilota::thrift::MethodOptions] = &[
+                {methods}
+            ];"#
+        });
     }
 
     fn codegen_enum_impl(&self, def_id: DefId, stream: &mut String, e: &Enum) {
@@ -789,9 +1277,24 @@ impl CodegenBackend for ThriftBackend {
                         }
                     })
                     .join("");
+                let allow_empty = self
+                    .node_tags(def_id)
+                    .and_then(|tags| tags.get::<AllowEmptyUnion>().map(|a| a.0))
+                    .unwrap_or(false);
                 if keep {
+                    let refuse_empty = if allow_empty {
+                        ""
+                    } else {
+                        r#"if value.size() == 0 {
+                            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "refusing to encode an empty union"
+                            ));
+                        }"#
+                    };
                     encode_variants.push_str(&format! {
                         "{name}::_UnknownFields(value) => {{
+                                        {refuse_empty}
                                         for bytes in value.list.iter() {{
                                             __protocol.write_bytes_without_len(bytes.clone());
                                         }}
@@ -835,12 +1338,38 @@ impl CodegenBackend for ThriftBackend {
                     &*v.name.sym == "Ok" && v.fields.len() == 1 && v.fields[0].kind == TyKind::Void
                 };
 
+                // the result of a void method without exceptions is always an empty struct
+                if !keep && e.variants.len() == 1 && variant_is_void(&e.variants[0]) {
+                    stream.push_str(&self.codegen_impl_message_with_helper(
+                        def_id,
+                        name.clone(),
+                        Self::codegen_empty_struct_encode(&name),
+                        Self::codegen_empty_struct_size(&name),
+                        |helper| {
+                            Self::codegen_empty_struct_decode(helper, &format!("{name}::Ok(())"))
+                        },
+                    ));
+                    return;
+                }
+
+                let (write_field_names, field_names_len) = if self.config.with_field_names && !e.variants.is_empty() {
+                    stream.push_str(&Self::codegen_field_names(
+                        &name,
+                        e.variants
+                            .iter()
+                            .map(|v| (v.id.unwrap() as i16, v.name.raw_str())),
+                    ));
+                    (WRITE_FIELD_NAMES, FIELD_NAMES_LEN)
+                } else {
+                    ("", "")
+                };
+
                 stream.push_str(&self.codegen_impl_message_with_helper(def_id,
                     name.clone(),
                     format! {
                         r#"__protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {{
                             name: "{name}",
-                        }})?;
+                        }})?;{write_field_names}
                         match self {{
                             {encode_variants}
                         }}
@@ -851,7 +1380,7 @@ impl CodegenBackend for ThriftBackend {
                         format! {
                             r#"__protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {{
                                 name: "{name}",
-                            }}) + match self {{
+                            }}) + {field_names_len} match self {{
                                 {variants_size}
                             }} +  __protocol.field_stop_len() + __protocol.struct_end_len()"#
                         },
@@ -872,6 +1401,7 @@ impl CodegenBackend for ThriftBackend {
                         if keep && !helper.is_async {
                             skip = format!("__pilota_offset += {skip}")
                         }
+                        let policy = self.config.union_policy;
                         let fields = e
                             .variants
                             .iter()
@@ -900,12 +1430,16 @@ impl CodegenBackend for ThriftBackend {
                                             )
                                         }
                                     };
-                                    Some(format! {
-                                        r#"Some({variant_id}) => {{
-                                    if ret.is_none() {{
-                                        let field_ident = {decode};
+                                    let set = format!(
+                                        r#"let field_ident = {decode};
                                         {decode_len}
-                                        ret = Some({name}::{variant_name}(field_ident));
+                                        ret = Some({name}::{variant_name}(field_ident));"#
+                                    );
+                                    Some(match policy {
+                                        UnionPolicy::Error => format! {
+                                            r#"Some({variant_id}) => {{
+                                    if ret.is_none() {{
+                                        {set}
                                     }} else {{
                                         return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                                             ::pilota::thrift::ProtocolExceptionKind::InvalidData,
@@ -913,25 +1447,57 @@ impl CodegenBackend for ThriftBackend {
                                         ));
                                     }}
                                 }},"#
+                                        },
+                                        UnionPolicy::KeepFirst => format! {
+                                            r#"Some({variant_id}) => {{
+                                    if ret.is_none() {{
+                                        {set}
+                                    }} else {{
+                                        {skip};
+                                    }}
+                                }},"#
+                                        },
+                                        UnionPolicy::KeepLast => format! {
+                                            r#"Some({variant_id}) => {{
+                                    {set}
+                                }},"#
+                                        },
                                     })
                                 }
                             })
                             .join("");
-                        let write_unknown_field = if keep && !helper.is_async {
+                        // the variants are matched on their id only
+                        let on_unknown_field = if self.config.unknown_field_hook {
                             format!(
-                                r#"if ret.is_none() {{
-                                unsafe {{
-                                    let mut __pilota_linked_bytes = ::pilota::BytesVec::new();
-                                    __pilota_linked_bytes.push_back(__protocol.get_bytes(Some(__pilota_begin_ptr), __pilota_offset)?);
-                                    ret = Some({name}::_UnknownFields(__pilota_linked_bytes));
-                                }}
+                                r#"__protocol.on_unknown_field("{name}", &field_ident, false);"#
+                            )
+                        } else {
+                            String::new()
+                        };
+                        let write_unknown_field = if keep && !helper.is_async {
+                            let set = format!(
+                                r#"let mut __pilota_linked_bytes = ::pilota::BytesVec::new();
+                                __pilota_linked_bytes.push_back(__protocol.get_bytes(Some(__pilota_begin_ptr), __pilota_offset)?);
+                                ret = Some({name}::_UnknownFields(__pilota_linked_bytes));"#
+                            );
+                            match policy {
+                                UnionPolicy::Error => format!(
+                                    r#"if ret.is_none() {{
+                                {set}
                             }} else {{
                                 return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                                     ::pilota::thrift::ProtocolExceptionKind::InvalidData,
                                     "received multiple fields for union from remote Message"
                                 ));
                             }}"#
-                            )
+                                ),
+                                UnionPolicy::KeepFirst => format!(
+                                    r#"if ret.is_none() {{
+                                {set}
+                            }}"#
+                                ),
+                                UnionPolicy::KeepLast => set,
+                            }
                         } else {
                             Default::default()
                         };
@@ -939,6 +1505,8 @@ impl CodegenBackend for ThriftBackend {
                         let handle_none_ret: FastStr =
                             if e.variants.first().filter(|v| variant_is_void(v)).is_some() {
                                 format!("::std::result::Result::Ok({name}::Ok(()))").into()
+                            } else if keep && allow_empty {
+                                format!("::std::result::Result::Ok({name}::_UnknownFields(::pilota::BytesVec::new()))").into()
                             } else {
                                 r#"::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                                     ::pilota::thrift::ProtocolExceptionKind::InvalidData,
@@ -961,6 +1529,7 @@ impl CodegenBackend for ThriftBackend {
                                 match field_ident.id {{
                                     {fields}
                                     _ => {{
+                                        {on_unknown_field}
                                         {skip};
                                         {write_unknown_field}
                                     }},
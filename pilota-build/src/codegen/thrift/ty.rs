@@ -4,7 +4,7 @@ use super::{ThriftBackend, decode_helper::DecodeHelper};
 use crate::{
     DefId,
     db::RirDatabase,
-    middle::{rir, ty, ty::Ty},
+    middle::{context::EnumKeyPolicy, rir, ty, ty::Ty},
     symbol::EnumRepr,
 };
 
@@ -60,6 +60,9 @@ impl ThriftBackend {
             ty::I64 => format!("__protocol.write_i64(*{ident})?;").into(),
             ty::F64 => format!("__protocol.write_double(*{ident})?;").into(),
             ty::OrderedF64 => format!("__protocol.write_double({ident}.0)?;").into(),
+            ty::Uuid if self.config.typed_uuid => {
+                format!("__protocol.write_uuid_typed(*{ident})?;").into()
+            }
             ty::Uuid => format!("__protocol.write_uuid({ident})?;").into(),
             ty::Vec(ty) => {
                 let el_ttype = self.ttype(ty);
@@ -106,6 +109,9 @@ impl ThriftBackend {
             ty::I64 => format!(r#"__protocol.write_i64(*{ident})?;"#).into(),
             ty::F64 => format!(r#"__protocol.write_double(*{ident})?;"#).into(),
             ty::OrderedF64 => format!(r#"__protocol.write_double({ident}.0)?;"#).into(),
+            ty::Uuid if self.config.typed_uuid => {
+                format!(r#"__protocol.write_uuid_typed(*{ident})?;"#).into()
+            }
             ty::Uuid => format!(r#"__protocol.write_uuid({ident})?;"#).into(),
             ty::Vec(el) => {
                 let el_ttype = self.ttype(el);
@@ -327,6 +333,9 @@ impl ThriftBackend {
             ty::I64 => format!("__protocol.write_i64_field({id}, *{ident})?;").into(),
             ty::F64 => format!("__protocol.write_double_field({id}, *{ident})?;").into(),
             ty::OrderedF64 => format!("__protocol.write_double_field({id}, *{ident}.0)?;").into(),
+            ty::Uuid if self.config.typed_uuid => {
+                format!("__protocol.write_uuid_field({id}, (*{ident}).into_bytes())?;").into()
+            }
             ty::Uuid => format!("__protocol.write_uuid_field({id}, *{ident})?;").into(),
             ty::Vec(ty) => {
                 let el_ttype = self.ttype(ty);
@@ -392,6 +401,9 @@ impl ThriftBackend {
             ty::I64 => format!(r#"__protocol.write_i64_field({id}, *{ident})?;"#).into(),
             ty::F64 => format!(r#"__protocol.write_double_field({id}, *{ident})?;"#).into(),
             ty::OrderedF64 => format!(r#"__protocol.write_double_field({id}, *{ident}.0)?;"#).into(),
+            ty::Uuid if self.config.typed_uuid => {
+                format!(r#"__protocol.write_uuid_field({id}, (*{ident}).into_bytes())?;"#).into()
+            }
             ty::Uuid => format!(r#"__protocol.write_uuid_field({id}, *{ident})?;"#).into(),
             ty::Vec(ty) => {
                 let el_ttype = self.ttype(ty);
@@ -598,6 +610,9 @@ impl ThriftBackend {
             ty::I64 => format!("__protocol.i64_len(*{ident})").into(),
             ty::F64 => format!("__protocol.double_len(*{ident})").into(),
             ty::OrderedF64 => format!("__protocol.double_len({ident}.0)").into(),
+            ty::Uuid if self.config.typed_uuid => {
+                format!("__protocol.uuid_len((*{ident}).into_bytes())").into()
+            }
             ty::Uuid => format!("__protocol.uuid_len(*{ident})").into(),
             ty::Vec(el) => {
                 let add_el = self.codegen_ty_size(el, "el".into());
@@ -634,6 +649,9 @@ impl ThriftBackend {
             ty::I64 => format!(r#"__protocol.i64_len(*{ident})"#).into(),
             ty::F64 => format!(r#"__protocol.double_len(*{ident})"#).into(),
             ty::OrderedF64 => format!(r#"__protocol.double_len({ident}.0)"#).into(),
+            ty::Uuid if self.config.typed_uuid => {
+                format!(r#"__protocol.uuid_len((*{ident}).into_bytes())"#).into()
+            }
             ty::Uuid => format!(r#"__protocol.uuid_len(*{ident})"#).into(),
             ty::Vec(el) => {
                 let add_el = self.codegen_ty_size(el, "el".into());
@@ -798,6 +816,9 @@ impl ThriftBackend {
             ty::OrderedF64 => {
                 format!("__protocol.double_field_len(Some({id}), *{ident}.0) ").into()
             }
+            ty::Uuid if self.config.typed_uuid => {
+                format!("__protocol.uuid_field_len(Some({id}), (*{ident}).into_bytes()) ").into()
+            }
             ty::Uuid => format!("__protocol.uuid_field_len(Some({id}), *{ident}) ").into(),
             ty::Vec(el) => {
                 let add_el = self.codegen_ty_size(el, "el".into());
@@ -846,6 +867,9 @@ impl ThriftBackend {
             ty::OrderedF64 => {
                 format!(r#"__protocol.double_field_len(Some({id}), *{ident}.0)"#).into()
             }
+            ty::Uuid if self.config.typed_uuid => {
+                format!(r#"__protocol.uuid_field_len(Some({id}), (*{ident}).into_bytes())"#).into()
+            }
             ty::Uuid => format!(r#"__protocol.uuid_field_len(Some({id}), *{ident})"#).into(),
             ty::Vec(el) => {
                 let add_el = self.codegen_ty_size(el, "el".into());
@@ -1040,40 +1064,24 @@ impl ThriftBackend {
                 let read_double = helper.codegen_read_double();
                 format!("::pilota::OrderedFloat({read_double})").into()
             }
+            ty::Uuid if self.config.typed_uuid => helper.codegen_read_uuid_typed(),
             ty::Uuid => helper.codegen_read_uuid(),
             ty::Vec(ty) => {
                 let read_list_begin = helper.codegen_read_list_begin();
                 let read_list_end = helper.codegen_read_list_end();
                 let read_el = self.codegen_decode_ty(helper, ty);
-                let ty_rust_name = self.cx.db.codegen_item_ty(ty.kind.clone());
-                if !helper.is_async {
-                    format! {
-                        r#"unsafe {{
-                            let list_ident = {read_list_begin};
-                            let mut val: ::std::vec::Vec<{ty_rust_name}> = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for i in 0..list_ident.size {{
-                                val.as_mut_ptr().offset(i as isize).write({read_el});
-                            }};
-                            val.set_len(list_ident.size);
-                            {read_list_end};
-                            val
-                        }}"#
-                    }
-                    .into()
-                } else {
-                    format! {
-                        r#"{{
-                            let list_ident = {read_list_begin};
-                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {{
-                                val.push({read_el});
-                            }};
-                            {read_list_end};
-                            val
-                        }}"#
-                    }
-                    .into()
+                format! {
+                    r#"{{
+                        let list_ident = {read_list_begin};
+                        let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                        for _ in 0..list_ident.size {{
+                            val.push({read_el});
+                        }};
+                        {read_list_end};
+                        val
+                    }}"#
                 }
+                .into()
             }
             ty::Set(ty) => self.decode_set(
                 ty,
@@ -1125,12 +1133,40 @@ impl ThriftBackend {
         let read_el_val = self.codegen_decode_ty(helper, val_ty);
         let read_map_begin = helper.codegen_read_map_begin();
         let read_map_end = helper.codegen_read_map_end();
+
+        let enum_key = match &key_ty.kind {
+            ty::Path(p) if self.is_i32_enum(p.did) => {
+                Some(self.cx.db.codegen_item_ty(key_ty.kind.clone()))
+            }
+            _ => None,
+        };
+        let insert = match (enum_key, self.config.enum_key_policy) {
+            (Some(key_ty), EnumKeyPolicy::Drop) => format! {
+                r#"let key: {key_ty} = {read_el_key};
+                let value = {read_el_val};
+                if {key_ty}::try_from_i32(key.inner()).is_some() {{
+                    val.insert(key, value);
+                }}"#
+            },
+            (Some(key_ty), EnumKeyPolicy::Error) => format! {
+                r#"let key: {key_ty} = {read_el_key};
+                if {key_ty}::try_from_i32(key.inner()).is_none() {{
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        format!("unknown enum value {{}} for map key {key_ty}", key.inner()),
+                    ));
+                }}
+                val.insert(key, {read_el_val});"#
+            },
+            _ => format!("val.insert({read_el_key}, {read_el_val});"),
+        };
+
         format! {
                     r#"{{
                         let map_ident = {read_map_begin};
                         let mut val = {new};
                         for _ in 0..map_ident.size {{
-                            val.insert({read_el_key}, {read_el_val});
+                            {insert}
                         }}
                         {read_map_end};
                         val
@@ -2,11 +2,13 @@ use std::{path::PathBuf, sync::Arc};
 
 use crate::{
     Context,
+    db::RirDatabase,
     middle::{
         ext::{FileExts, ModExts},
         rir::{self, Method},
     },
     symbol::{DefId, ModPath, Symbol},
+    tags::StreamingMode,
 };
 
 pub trait CodegenBackend: Clone {
@@ -15,8 +17,36 @@ pub trait CodegenBackend: Clone {
     fn cx(&self) -> &Context;
     fn codegen_struct_impl(&self, _def_id: DefId, _stream: &mut String, _s: &rir::Message) {}
     fn codegen_service_impl(&self, _def_id: DefId, _stream: &mut String, _s: &rir::Service) {}
-    fn codegen_service_method(&self, _service_def_id: DefId, _method: &Method) -> String {
-        Default::default()
+    fn codegen_service_method(&self, _service_def_id: DefId, method: &Method) -> String {
+        if method.streaming == StreamingMode::Unary {
+            Default::default()
+        } else {
+            self.codegen_streaming_method(method)
+        }
+    }
+    /// Generate the signature of a streaming method, where streamed requests
+    /// and responses are `::pilota::stream::Streaming`s.
+    fn codegen_streaming_method(&self, method: &Method) -> String {
+        let cx = self.cx();
+        let name = cx.rust_name(method.def_id);
+        let [arg] = &*method.args else {
+            panic!(
+                "streaming method `{}` must take exactly one argument",
+                method.name
+            )
+        };
+        let arg_name = cx.rust_name(arg.def_id);
+        let mut req = cx.codegen_item_ty(arg.ty.kind.clone()).to_string();
+        let mut resp = cx.codegen_item_ty(method.ret.kind.clone()).to_string();
+        if method.streaming.is_client_streaming() {
+            req = format!("::pilota::stream::Streaming<{req}>");
+        }
+        if method.streaming.is_server_streaming() {
+            resp = format!("::pilota::stream::Streaming<{resp}>");
+        }
+        format!(
+            "fn {name}(&self, {arg_name}: {req}) -> impl ::std::future::Future<Output = ::std::result::Result<{resp}, ::pilota::stream::BoxError>> + Send;"
+        )
     }
     /// Generate methods for the service trait and use global paths to generate
     /// the types of the arguments and outputs.
@@ -9,8 +9,8 @@ use rustc_hash::FxHashMap;
 
 use super::CodegenItem;
 use crate::{
-    Codegen, CodegenBackend, Context, DefId, fmt::fmt_file, middle::context::DefLocation,
-    rir::ItemPath, symbol::ModPath,
+    Codegen, CodegenBackend, Context, DefId, middle::context::DefLocation, rir::ItemPath,
+    symbol::ModPath,
 };
 
 #[derive(Clone)]
@@ -273,7 +273,7 @@ where
 
                 std::fs::write(&custom_rs, custom_rs_stream)?;
 
-                fmt_file(custom_rs);
+                self.cg.finish_file(custom_rs);
             }
         }
 
@@ -302,8 +302,9 @@ where
                 main_mod_path.iter().map(|item| item.to_string()).join("::")
             ));
         }
+        let attrs = self.cg.config.mod_attrs();
         gen_rs_stream = format! {r#"pub mod r#gen {{
-            #![allow(warnings, clippy::all)]
+            {attrs}
             {gen_rs_stream}
         }}"#};
 
@@ -316,8 +317,8 @@ where
         std::fs::write(&lib_rs, lib_rs_stream)?;
         std::fs::write(&gen_rs, gen_rs_stream)?;
 
-        fmt_file(lib_rs);
-        fmt_file(gen_rs);
+        self.cg.finish_file(lib_rs);
+        self.cg.finish_file(gen_rs);
 
         Ok(())
     }
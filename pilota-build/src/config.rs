@@ -0,0 +1,299 @@
+//! Codegen settings shared by the crates of a workspace.
+//!
+//! A `pilota.toml`, usually at the root of the workspace, declares the
+//! targets to generate, so that every crate compiles its IDLs with the same
+//! options instead of repeating them in its build script:
+//!
+//! ```toml
+//! [[target]]
+//! name = "echo"
+//! kind = "thrift"
+//! idls = ["idl/echo.thrift"]
+//! include_dirs = ["idl"]
+//! out = "echo.rs"
+//! plugins = ["serde"]
+//!
+//! [target.options]
+//! with_comments = true
+//!
+//! [[target.file]]
+//! path = "idl/echo.thrift"
+//! keep_unknown_fields = true
+//! touch = ["Request"]
+//! ```
+//!
+//! The paths of the IDLs and include directories are relative to the
+//! directory of the `pilota.toml`, the outputs to the directory given to
+//! [`Target::compile`], the `OUT_DIR` in a build script:
+//!
+//! ```no_run
+//! let config = pilota_build::config::Config::find(env!("CARGO_MANIFEST_DIR")).unwrap();
+//! config
+//!     .target("echo")
+//!     .unwrap()
+//!     .compile(std::env::var("OUT_DIR").unwrap());
+//! ```
+//!
+//! A target can be combined with settings only the builder has, such as
+//! plugins of the crate, with [`Builder::with_target`].
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, bail};
+use faststr::FastStr;
+use serde::Deserialize;
+
+use crate::{
+    Builder, IdlService, Output,
+    parser::Parser,
+    plugin::{CanonicalEqPlugin, SerdePlugin, SqlxPlugin},
+};
+
+/// The name of the file [`Config::find`] looks for.
+pub const FILE_NAME: &str = "pilota.toml";
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default, rename = "target")]
+    pub targets: Vec<Target>,
+}
+
+impl Config {
+    /// Reads the `pilota.toml` in `dir` or the closest of its ancestors.
+    pub fn find(dir: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let dir = dir.as_ref();
+        match dir
+            .ancestors()
+            .map(|d| d.join(FILE_NAME))
+            .find(|p| p.is_file())
+        {
+            Some(path) => Self::from_path(path),
+            None => bail!("no {FILE_NAME} in {} or its ancestors", dir.display()),
+        }
+    }
+
+    /// Reads the config at `path`, resolving the paths of its targets
+    /// against its directory.
+    pub fn from_path(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        if std::env::var_os("OUT_DIR").is_some() {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let root = path.parent().unwrap_or(Path::new("."));
+        Self::parse(&content, root).with_context(|| format!("invalid {}", path.display()))
+    }
+
+    /// Parses `content`, resolving the paths of its targets against `root`.
+    pub fn parse(content: &str, root: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let mut config: Self = toml::from_str(content)?;
+        let root = root.as_ref();
+        for target in &mut config.targets {
+            if target.out.is_some() == target.workspace.is_some() {
+                bail!(
+                    "target {} needs exactly one of `out` and `workspace`",
+                    target.name
+                );
+            }
+            for path in target
+                .idls
+                .iter_mut()
+                .chain(&mut target.include_dirs)
+                .chain(target.files.iter_mut().map(|f| &mut f.path))
+            {
+                *path = root.join(&*path);
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn target(&self, name: &str) -> Option<&Target> {
+        self.targets.iter().find(|t| t.name == name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdlKind {
+    Thrift,
+    Pb,
+}
+
+/// The plugins a target can enable by name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginName {
+    Serde,
+    CanonicalEq,
+    Sqlx,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Target {
+    pub name: String,
+    pub kind: IdlKind,
+    pub idls: Vec<PathBuf>,
+    #[serde(default)]
+    pub include_dirs: Vec<PathBuf>,
+    /// The file to generate into, see [`Output::File`].
+    #[serde(default)]
+    pub out: Option<PathBuf>,
+    /// The directory of the crates to generate, see [`Output::Workspace`].
+    #[serde(default)]
+    pub workspace: Option<PathBuf>,
+    #[serde(default)]
+    pub plugins: Vec<PluginName>,
+    #[serde(default)]
+    pub options: Options,
+    #[serde(default, rename = "file")]
+    pub files: Vec<FileOptions>,
+}
+
+/// The options of the builder a target sets, those left out keeping the
+/// defaults of the builder.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Options {
+    pub split_generated_files: Option<bool>,
+    pub change_case: Option<bool>,
+    pub ignore_unused: Option<bool>,
+    pub with_descriptor: Option<bool>,
+    pub with_field_mask: Option<bool>,
+    pub with_comments: Option<bool>,
+    pub with_unknown_enum_variant: Option<bool>,
+    pub serde_i64_as_string: Option<bool>,
+    pub lenient_numeric_decode: Option<bool>,
+    pub typed_uuid: Option<bool>,
+    pub fixed_encoded_size: Option<bool>,
+    pub cached_size: Option<bool>,
+    pub with_field_names: Option<bool>,
+    pub forbid_unsafe_code: Option<bool>,
+    pub duplicate_field_check: Option<bool>,
+    pub unknown_field_hook: Option<bool>,
+    pub common_crate_name: Option<String>,
+    #[serde(default)]
+    pub dedup: Vec<String>,
+    #[serde(default)]
+    pub special_namings: Vec<String>,
+    /// Only for the `pb` targets, see
+    /// [`Builder::proto2_required`](crate::Builder::proto2_required).
+    pub proto2_required: Option<bool>,
+}
+
+/// The options of a single IDL file of a target.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FileOptions {
+    pub path: PathBuf,
+    #[serde(default)]
+    pub keep_unknown_fields: bool,
+    /// Items generated even when unused, see
+    /// [`Builder::touch`](crate::Builder::touch).
+    #[serde(default)]
+    pub touch: Vec<String>,
+}
+
+impl Target {
+    pub fn services(&self) -> Vec<IdlService> {
+        self.idls
+            .iter()
+            .cloned()
+            .map(IdlService::from_path)
+            .collect()
+    }
+
+    /// The output of the target, relative to `out_dir`.
+    pub fn output(&self, out_dir: impl AsRef<Path>) -> Output {
+        let out_dir = out_dir.as_ref();
+        match (&self.out, &self.workspace) {
+            (Some(out), _) => Output::File(out_dir.join(out)),
+            (None, Some(workspace)) => Output::Workspace(out_dir.join(workspace)),
+            (None, None) => unreachable!("checked when parsed"),
+        }
+    }
+
+    /// Generates the target into `out_dir`.
+    pub fn compile(&self, out_dir: impl AsRef<Path>) {
+        let output = self.output(out_dir);
+        match self.kind {
+            IdlKind::Thrift => Builder::thrift()
+                .with_target(self)
+                .compile_with_config(self.services(), output),
+            IdlKind::Pb => {
+                let mut builder = Builder::pb();
+                if let Some(proto2_required) = self.options.proto2_required {
+                    builder = builder.proto2_required(proto2_required);
+                }
+                builder
+                    .with_target(self)
+                    .compile_with_config(self.services(), output)
+            }
+        }
+    }
+}
+
+type SetFlag<B> = fn(B, bool) -> B;
+
+impl<MkB, P: Parser> Builder<MkB, P> {
+    /// Applies the include directories, plugins and options of `target`.
+    pub fn with_target(mut self, target: &Target) -> Self {
+        let options = &target.options;
+        self = self.include_dirs(target.include_dirs.clone());
+        for plugin in &target.plugins {
+            self = match plugin {
+                PluginName::Serde => self.plugin(SerdePlugin),
+                PluginName::CanonicalEq => self.plugin(CanonicalEqPlugin),
+                PluginName::Sqlx => self.plugin(SqlxPlugin),
+            };
+        }
+        let flags: [(Option<bool>, SetFlag<Self>); 16] = [
+            (options.split_generated_files, Self::split_generated_files),
+            (options.change_case, Self::change_case),
+            (options.ignore_unused, Self::ignore_unused),
+            (options.with_descriptor, Self::with_descriptor),
+            (options.with_field_mask, Self::with_field_mask),
+            (options.with_comments, Self::with_comments),
+            (
+                options.with_unknown_enum_variant,
+                Self::with_unknown_enum_variant,
+            ),
+            (options.serde_i64_as_string, Self::serde_i64_as_string),
+            (options.lenient_numeric_decode, Self::lenient_numeric_decode),
+            (options.typed_uuid, Self::typed_uuid),
+            (options.fixed_encoded_size, Self::fixed_encoded_size),
+            (options.cached_size, Self::cached_size),
+            (options.with_field_names, Self::with_field_names),
+            (options.forbid_unsafe_code, Self::forbid_unsafe_code),
+            (options.duplicate_field_check, Self::duplicate_field_check),
+            (options.unknown_field_hook, Self::unknown_field_hook),
+        ];
+        for (flag, set) in flags {
+            if let Some(flag) = flag {
+                self = set(self, flag);
+            }
+        }
+        if let Some(name) = &options.common_crate_name {
+            self = self.common_crate_name(FastStr::new(name));
+        }
+        self = self
+            .dedup(options.dedup.iter().map(FastStr::new))
+            .special_namings(options.special_namings.iter().map(FastStr::new))
+            .keep_unknown_fields(
+                target
+                    .files
+                    .iter()
+                    .filter(|f| f.keep_unknown_fields)
+                    .map(|f| f.path.clone()),
+            );
+        self.touch(
+            target
+                .files
+                .iter()
+                .filter(|f| !f.touch.is_empty())
+                .map(|f| (f.path.clone(), f.touch.clone())),
+        )
+    }
+}
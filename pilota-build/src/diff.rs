@@ -0,0 +1,75 @@
+//! Comparing freshly generated code with the existing output, for
+//! [`Builder::dry_run`](crate::Builder::dry_run).
+
+use std::path::{Path, PathBuf};
+
+use diffy::DiffOptions;
+
+/// Copies the directory `from` into `to`, skipping cargo's `target`
+/// directories.
+pub(crate) fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != "target" {
+                copy_dir(&path, &to.join(entry.file_name()))?;
+            }
+        } else {
+            std::fs::copy(&path, to.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the unified diff from the files under `old` to the files of the
+/// same relative path under `new`, empty if they are identical.
+///
+/// Only the files under `new` are compared, files that exist only under
+/// `old` are not reported.
+pub(crate) fn diff_dirs(old: &Path, new: &Path) -> std::io::Result<String> {
+    let mut files = Vec::new();
+    collect_files(new, PathBuf::new(), &mut files)?;
+    files.sort();
+
+    let mut diff = String::new();
+    for rel in files {
+        let modified = std::fs::read_to_string(new.join(&rel))?;
+        let old_path = old.join(&rel);
+        let (original, original_name) = if old_path.exists() {
+            (
+                std::fs::read_to_string(&old_path)?,
+                format!("a/{}", rel.display()),
+            )
+        } else {
+            (String::new(), "/dev/null".to_string())
+        };
+        if original == modified {
+            continue;
+        }
+        let patch = DiffOptions::new()
+            .set_original_filename(original_name)
+            .set_modified_filename(format!("b/{}", rel.display()))
+            .create_patch(&original, &modified);
+        diff.push_str(&patch.to_string());
+    }
+    Ok(diff)
+}
+
+pub(crate) fn collect_files(
+    root: &Path,
+    rel: PathBuf,
+    files: &mut Vec<PathBuf>,
+) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(root.join(&rel))? {
+        let entry = entry?;
+        let rel = rel.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            collect_files(root, rel, files)?;
+        } else {
+            files.push(rel);
+        }
+    }
+    Ok(())
+}
@@ -4,6 +4,81 @@ use std::{
     process::{Command, exit},
 };
 
+use quote::ToTokens;
+
+/// How generated files are formatted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Formatter {
+    /// Format in process with `prettyplease`, which is much faster than
+    /// rustfmt on large outputs but drops `//` comments.
+    #[default]
+    Prettyplease,
+    /// Run the `rustfmt` binary, or the one in the `RUSTFMT` environment
+    /// variable.
+    Rustfmt,
+}
+
+/// Formats the rust file `file` with `formatter`, other files are left
+/// untouched.
+pub fn format_file<P: AsRef<Path>>(file: P, formatter: Formatter) {
+    match formatter {
+        Formatter::Prettyplease => prettyplease_file(file.as_ref()),
+        Formatter::Rustfmt => fmt_file(file),
+    }
+}
+
+/// Like [`format_file`], but lets `rewrite` edit the syntax tree of the rust
+/// file `file` first.
+///
+/// With rustfmt the file is only rewritten if `rewrite` changed it, since
+/// printing the tree drops `//` comments.
+pub(crate) fn rewrite_and_format_file(
+    file: &Path,
+    formatter: Formatter,
+    rewrite: impl FnOnce(&mut syn::File),
+) {
+    if file.extension().is_some_and(|a| a != "rs") {
+        return;
+    }
+
+    let mut ast = parse_file(file);
+    match formatter {
+        Formatter::Prettyplease => {
+            rewrite(&mut ast);
+            std::fs::write(file, prettyplease::unparse(&ast)).unwrap()
+        }
+        Formatter::Rustfmt => {
+            let before = ast.to_token_stream().to_string();
+            rewrite(&mut ast);
+            let after = ast.to_token_stream().to_string();
+            if before != after {
+                std::fs::write(file, after).unwrap();
+            }
+            fmt_file(file)
+        }
+    }
+}
+
+fn parse_file(file: &Path) -> syn::File {
+    let content = std::fs::read_to_string(file).unwrap();
+    match syn::parse_file(&content) {
+        Ok(ast) => ast,
+        Err(e) => {
+            eprintln!("failed to parse {}: {e}", file.display());
+            exit(1)
+        }
+    }
+}
+
+fn prettyplease_file(file: &Path) {
+    if file.extension().is_some_and(|a| a != "rs") {
+        return;
+    }
+
+    let ast = parse_file(file);
+    std::fs::write(file, prettyplease::unparse(&ast)).unwrap()
+}
+
 pub fn fmt_file<P: AsRef<Path>>(file: P) {
     let file = file.as_ref();
     if let Some(a) = file.extension() {
@@ -12,15 +87,25 @@ pub fn fmt_file<P: AsRef<Path>>(file: P) {
         }
     };
 
-    let result = Command::new(std::env::var("RUSTFMT").unwrap_or_else(|_| "rustfmt".to_owned()))
-        .arg("--config")
-        .arg("wrap_comments=true")
-        .arg("--emit")
-        .arg("files")
-        .arg("--edition")
-        .arg("2024")
-        .arg(file)
-        .output();
+    // an empty config of our own, so that a `rustfmt.toml` found in the
+    // parents of the output directory can not change the generated code
+    let result = tempfile::Builder::new()
+        .prefix("rustfmt")
+        .suffix(".toml")
+        .tempfile()
+        .and_then(|config| {
+            Command::new(std::env::var("RUSTFMT").unwrap_or_else(|_| "rustfmt".to_owned()))
+                .arg("--config-path")
+                .arg(config.path())
+                .arg("--config")
+                .arg("wrap_comments=true")
+                .arg("--emit")
+                .arg("files")
+                .arg("--edition")
+                .arg("2024")
+                .arg(file)
+                .output()
+        });
 
     match result {
         Err(e) => eprintln!("{e}"),
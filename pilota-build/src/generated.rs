@@ -0,0 +1,72 @@
+//! Generated code kept in memory, see
+//! [`Builder::compile_to_memory`](crate::Builder::compile_to_memory).
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::TokenStream;
+
+/// The files produced by a compilation, keyed by their path relative to the
+/// output directory.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GeneratedFiles {
+    files: BTreeMap<PathBuf, String>,
+}
+
+impl GeneratedFiles {
+    pub(crate) fn read_dir(root: &Path) -> std::io::Result<Self> {
+        let mut paths = Vec::new();
+        crate::diff::collect_files(root, PathBuf::new(), &mut paths)?;
+        let mut files = BTreeMap::new();
+        for rel in paths {
+            let contents = std::fs::read_to_string(root.join(&rel))?;
+            files.insert(rel, contents);
+        }
+        Ok(Self { files })
+    }
+
+    /// Adds a file, replacing the one at `path` if any.
+    pub fn insert(&mut self, path: impl Into<PathBuf>, contents: impl Into<String>) {
+        self.files.insert(path.into(), contents.into());
+    }
+
+    /// Writes the files under `root`, creating the missing directories.
+    pub fn write_to(&self, root: impl AsRef<Path>) -> std::io::Result<()> {
+        for (path, contents) in &self.files {
+            let path = root.as_ref().join(path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, contents)?;
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<&str> {
+        self.files.get(path.as_ref()).map(String::as_str)
+    }
+
+    /// Parses a generated Rust file, `None` if there is no file at `path`.
+    pub fn tokens(&self, path: impl AsRef<Path>) -> Option<syn::Result<TokenStream>> {
+        self.get(path)
+            .map(|src| src.parse().map_err(syn::Error::from))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, &str)> {
+        self.files.iter().map(|(p, s)| (p.as_path(), s.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    pub fn into_inner(self) -> BTreeMap<PathBuf, String> {
+        self.files
+    }
+}
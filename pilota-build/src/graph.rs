@@ -0,0 +1,352 @@
+//! Dependency graph of the IDL types and files, see
+//! [`Builder::dependency_graph`](crate::Builder::dependency_graph).
+
+use std::{
+    collections::{BTreeSet, VecDeque},
+    fmt::Write as _,
+};
+
+use petgraph::{Graph, algo::tarjan_scc};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use crate::{
+    Context, DefId,
+    db::RirDatabase,
+    metrics::{Sizer, method_items, qualified_name},
+    middle::{
+        rir::Item,
+        ty::{Ty, TyKind},
+    },
+    symbol::{FileId, Ident},
+};
+
+/// The types and services generated from a set of IDLs with the types they
+/// reference, and the files with the files they include. Types and files
+/// taking part in a cycle are flagged, cycles usually being what keeps a
+/// schema from being split.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct DependencyGraph {
+    pub files: Vec<FileNode>,
+    pub types: Vec<TypeNode>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct FileNode {
+    pub path: String,
+    pub package: String,
+    /// The paths of the included or imported files.
+    pub includes: Vec<String>,
+    /// The include cycle the file is part of, files of the same cycle share
+    /// the index.
+    pub cycle: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TypeNode {
+    /// The name of the type, prefixed by its package.
+    pub name: String,
+    pub kind: TypeKind,
+    /// The path of the file defining the type.
+    pub file: String,
+    /// The smallest encoding with the binary protocol, only required fields
+    /// set. `None` for services.
+    pub min_size: Option<usize>,
+    /// The names of the referenced types, services for the services
+    /// extended.
+    pub deps: Vec<String>,
+    /// The cycle of references the type is part of, types of the same cycle
+    /// share the index.
+    pub cycle: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TypeKind {
+    Struct,
+    Union,
+    Enum,
+    Typedef,
+    Service,
+}
+
+impl TypeKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            TypeKind::Struct => "struct",
+            TypeKind::Union => "union",
+            TypeKind::Enum => "enum",
+            TypeKind::Typedef => "typedef",
+            TypeKind::Service => "service",
+        }
+    }
+}
+
+impl DependencyGraph {
+    pub(crate) fn collect(cx: &Context) -> Self {
+        let method_items = method_items(cx);
+        let mut sizer = Sizer::new(cx);
+
+        let mut queue = cx
+            .cache
+            .codegen_items
+            .iter()
+            .copied()
+            .filter(|def_id| !method_items.contains(def_id))
+            .collect::<VecDeque<_>>();
+        let mut seen = queue.iter().copied().collect::<FxHashSet<_>>();
+        let mut items = Vec::new();
+        while let Some(def_id) = queue.pop_front() {
+            let Some(item) = cx.db.item(def_id) else {
+                continue;
+            };
+            let kind = match &*item {
+                Item::Message(_) => TypeKind::Struct,
+                Item::Enum(e) if e.repr.is_none() => TypeKind::Union,
+                Item::Enum(_) => TypeKind::Enum,
+                Item::NewType(_) => TypeKind::Typedef,
+                Item::Service(_) => TypeKind::Service,
+                Item::Const(_) | Item::Mod(_) => continue,
+            };
+            let mut deps = Vec::new();
+            item_deps(cx, &method_items, &item, &mut deps);
+            for dep in &deps {
+                if seen.insert(*dep) {
+                    queue.push_back(*dep);
+                }
+            }
+            items.push((def_id, kind, deps));
+        }
+
+        let names = items
+            .iter()
+            .map(|(def_id, ..)| {
+                let item = cx.db.item(*def_id).unwrap();
+                (
+                    *def_id,
+                    qualified_name(cx, *def_id, &Ident::new(item.symbol_name())),
+                )
+            })
+            .collect::<FxHashMap<_, _>>();
+        let type_cycles = cycles(
+            items
+                .iter()
+                .map(|(def_id, _, deps)| (*def_id, deps.as_slice())),
+        );
+
+        let mut types = items
+            .iter()
+            .map(|(def_id, kind, deps)| TypeNode {
+                name: names[def_id].clone(),
+                kind: *kind,
+                file: cx
+                    .db
+                    .node(*def_id)
+                    .map(|node| file_path(cx, node.file_id))
+                    .unwrap_or_default(),
+                min_size: (*kind != TypeKind::Service).then(|| sizer.item_min_size(*def_id)),
+                deps: deps
+                    .iter()
+                    .filter_map(|dep| names.get(dep).cloned())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect(),
+                cycle: type_cycles.get(def_id).copied(),
+            })
+            .collect::<Vec<_>>();
+        types.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let files = cx.db.files();
+        let file_cycles = cycles(
+            files
+                .iter()
+                .map(|(file_id, file)| (*file_id, file.uses.as_slice())),
+        );
+        let mut files = files
+            .iter()
+            .map(|(file_id, file)| FileNode {
+                path: file_path(cx, *file_id),
+                package: file
+                    .package
+                    .iter()
+                    .map(|s| s.0.as_str())
+                    .collect::<Vec<_>>()
+                    .join("."),
+                includes: file
+                    .uses
+                    .iter()
+                    .map(|file_id| file_path(cx, *file_id))
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect(),
+                cycle: file_cycles.get(file_id).copied(),
+            })
+            .collect::<Vec<_>>();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        DependencyGraph { files, types }
+    }
+
+    /// Renders the graph in the Graphviz DOT language, the files and their
+    /// includes in one cluster and the types in another, with the nodes and
+    /// edges of cycles in red.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph idl {\n");
+
+        out.push_str("  subgraph cluster_files {\n    label=\"files\";\n");
+        out.push_str("    node [shape=folder];\n");
+        let file_cycles = self
+            .files
+            .iter()
+            .map(|f| (f.path.as_str(), f.cycle))
+            .collect::<FxHashMap<_, _>>();
+        for f in &self.files {
+            let _ = write!(out, "    {}", quote(&format!("file:{}", f.path)));
+            let _ = write!(out, " [label={}", quote(&f.path));
+            if f.cycle.is_some() {
+                out.push_str(", color=red");
+            }
+            out.push_str("];\n");
+        }
+        for f in &self.files {
+            for include in &f.includes {
+                let _ = write!(
+                    out,
+                    "    {} -> {} [style=dashed",
+                    quote(&format!("file:{}", f.path)),
+                    quote(&format!("file:{include}"))
+                );
+                if f.cycle.is_some() && file_cycles.get(include.as_str()) == Some(&f.cycle) {
+                    out.push_str(", color=red");
+                }
+                out.push_str("];\n");
+            }
+        }
+        out.push_str("  }\n");
+
+        out.push_str("  subgraph cluster_types {\n    label=\"types\";\n");
+        out.push_str("    node [shape=box];\n");
+        let type_cycles = self
+            .types
+            .iter()
+            .map(|t| (t.name.as_str(), t.cycle))
+            .collect::<FxHashMap<_, _>>();
+        for t in &self.types {
+            let mut label = format!("{}\n{}", t.name, t.kind.as_str());
+            if let Some(size) = t.min_size {
+                let _ = write!(label, ", {size} B");
+            }
+            let _ = write!(out, "    {} [label={}", quote(&t.name), quote(&label));
+            if t.kind == TypeKind::Service {
+                out.push_str(", shape=component");
+            }
+            if t.cycle.is_some() {
+                out.push_str(", color=red");
+            }
+            out.push_str("];\n");
+        }
+        for t in &self.types {
+            for dep in &t.deps {
+                let _ = write!(out, "    {} -> {}", quote(&t.name), quote(dep));
+                if t.cycle.is_some() && type_cycles.get(dep.as_str()) == Some(&t.cycle) {
+                    out.push_str(" [color=red]");
+                }
+                out.push_str(";\n");
+            }
+        }
+        out.push_str("  }\n}\n");
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+fn file_path(cx: &Context, file_id: FileId) -> String {
+    cx.db
+        .file_paths()
+        .get(&file_id)
+        .map(|path| path.display().to_string())
+        .unwrap_or_default()
+}
+
+fn item_deps(cx: &Context, method_items: &FxHashSet<DefId>, item: &Item, out: &mut Vec<DefId>) {
+    match item {
+        Item::Message(m) => m.fields.iter().for_each(|f| ty_deps(&f.ty, out)),
+        Item::Enum(e) => e
+            .variants
+            .iter()
+            .flat_map(|v| v.fields.iter())
+            .for_each(|ty| ty_deps(ty, out)),
+        Item::NewType(t) => ty_deps(&t.ty, out),
+        Item::Service(s) => {
+            out.extend(s.extend.iter().map(|p| p.did));
+            for m in &s.methods {
+                m.args.iter().for_each(|a| ty_deps(&a.ty, out));
+                ty_deps(&m.ret, out);
+                let Some(exceptions) = &m.exceptions else {
+                    continue;
+                };
+                // the exceptions of a method are gathered in a generated
+                // union, which isn't a node of its own
+                match cx.db.item(exceptions.did) {
+                    Some(e) if method_items.contains(&exceptions.did) => {
+                        item_deps(cx, method_items, &e, out)
+                    }
+                    _ => out.push(exceptions.did),
+                }
+            }
+        }
+        Item::Const(_) | Item::Mod(_) => {}
+    }
+}
+
+fn ty_deps(ty: &Ty, out: &mut Vec<DefId>) {
+    match &ty.kind {
+        TyKind::Vec(ty) | TyKind::Set(ty) | TyKind::BTreeSet(ty) | TyKind::Arc(ty) => {
+            ty_deps(ty, out)
+        }
+        TyKind::Map(k, v) | TyKind::BTreeMap(k, v) => {
+            ty_deps(k, out);
+            ty_deps(v, out);
+        }
+        TyKind::Path(path) => out.push(path.did),
+        _ => {}
+    }
+}
+
+/// The nodes of `edges` reaching themselves, with the index of their cycle.
+fn cycles<'a, N>(edges: impl Iterator<Item = (N, &'a [N])> + Clone) -> FxHashMap<N, usize>
+where
+    N: Copy + Eq + std::hash::Hash + 'a,
+{
+    let mut graph = Graph::<N, ()>::new();
+    let mut indices = FxHashMap::default();
+    for (node, _) in edges.clone() {
+        indices.insert(node, graph.add_node(node));
+    }
+    for (node, deps) in edges {
+        for dep in deps {
+            if let Some(dep) = indices.get(dep) {
+                graph.update_edge(indices[&node], *dep, ());
+            }
+        }
+    }
+    tarjan_scc(&graph)
+        .into_iter()
+        .filter(|scc| scc.len() > 1 || graph.contains_edge(scc[0], scc[0]))
+        .enumerate()
+        .flat_map(|(cycle, scc)| scc.into_iter().map(move |idx| (idx, cycle)))
+        .map(|(idx, cycle)| (graph[idx], cycle))
+        .collect()
+}
+
+fn quote(s: &str) -> String {
+    format!(
+        "\"{}\"",
+        s.replace('\\', r"\\")
+            .replace('"', r#"\""#)
+            .replace('\n', r"\n")
+    )
+}
@@ -11,10 +11,6 @@ pub trait Idx: Sized + Copy + 'static {
         old
     }
 
-    fn increment_by(&mut self, amount: usize) {
-        *self = self.plus(amount);
-    }
-
     #[must_use]
     fn plus(self, amount: usize) -> Self {
         Self::new(self.index() + amount)
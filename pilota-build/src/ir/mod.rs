@@ -7,7 +7,7 @@ use pilota::{Bytes, FastStr};
 
 use crate::{
     symbol::{EnumRepr, FileId, Ident, Symbol},
-    tags::Tags,
+    tags::{StreamingMode, Tags},
 };
 
 /// The extension module for the ir, including the extension types relative to
@@ -76,18 +76,13 @@ pub struct Arg {
     pub attribute: FieldKind,
 }
 
-#[derive(Clone, Debug)]
-pub struct ExceptionVariant {
-    pub id: i32,
-    pub ty: Ty,
-}
-
 #[derive(Clone, Debug)]
 pub struct Method {
     pub name: Ident,
     pub args: Vec<Arg>,
     pub ret: Ty,
     pub oneway: bool,
+    pub streaming: StreamingMode,
     pub exceptions: Option<Path>,
     pub tags: Arc<Tags>,
     pub leading_comments: FastStr,
@@ -6,28 +6,40 @@
 
 mod util;
 
+pub mod backend;
 pub mod codegen;
+pub mod config;
 pub mod db;
 pub(crate) mod errors;
 pub mod fmt;
+mod generated;
+pub mod graph;
 mod index;
 mod ir;
+pub mod metrics;
 pub mod middle;
 pub mod parser;
 mod resolve;
 mod symbol;
+pub mod wit;
 
 use faststr::FastStr;
 pub use symbol::{ModPath, Symbol};
 use tempfile::tempdir;
 pub mod tags;
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
 
 mod dedup;
+mod diff;
 pub mod plugin;
 
 pub use codegen::{Codegen, thrift::ThriftBackend, traits::CodegenBackend};
 use db::{RirDatabase, RootDatabase};
+pub use fmt::Formatter;
+pub use generated::GeneratedFiles;
 use middle::{
     context::{CollectMode, ContextBuilder, Mode, WorkspaceInfo, tls::CONTEXT},
     rir::NodeKind,
@@ -35,12 +47,12 @@ use middle::{
     workspace_graph::WorkspaceGraph,
 };
 pub use middle::{
-    context::{Context, SourceType},
+    context::{Config, Context, DefaultFieldEncoding, EnumKeyPolicy, SourceType, UnionPolicy},
     rir, ty,
 };
 use parser::{ParseResult, Parser, protobuf::ProtobufParser, thrift::ThriftParser};
 use plugin::{AutoDerivePlugin, BoxedPlugin, ImplDefaultPlugin, PredicateResult, WithAttrsPlugin};
-pub use plugin::{BoxClonePlugin, ClonePlugin, Plugin};
+pub use plugin::{BoxClonePlugin, ClonePlugin, Plugin, PluginContext};
 use resolve::{ResolveResult, Resolver};
 pub use symbol::{DefId, IdentName};
 pub use tags::TagId;
@@ -78,17 +90,13 @@ pub struct Builder<MkB, P> {
     parser: P,
     plugins: Vec<Box<dyn Plugin>>,
     ignore_unused: bool,
-    split: bool,
     touches: Vec<(std::path::PathBuf, Vec<String>)>,
-    change_case: bool,
     keep_unknown_fields: Vec<std::path::PathBuf>,
     dedups: Vec<FastStr>,
     special_namings: Vec<FastStr>,
-    common_crate_name: FastStr,
-    with_descriptor: bool,
-    with_field_mask: bool,
+    config: Config,
     temp_dir: Option<tempfile::TempDir>,
-    with_comments: bool,
+    dry_run: bool,
 }
 
 impl Builder<MkThriftBackend, ThriftParser> {
@@ -103,16 +111,12 @@ impl Builder<MkThriftBackend, ThriftParser> {
             ],
             touches: Vec::default(),
             ignore_unused: true,
-            change_case: true,
             keep_unknown_fields: Vec::default(),
             dedups: Vec::default(),
             special_namings: Vec::default(),
-            common_crate_name: "common".into(),
-            split: false,
-            with_descriptor: false,
-            with_field_mask: false,
+            config: Config::default(),
             temp_dir: None,
-            with_comments: false,
+            dry_run: false,
         }
     }
 }
@@ -148,18 +152,26 @@ impl Builder<MkPbBackend, ProtobufParser> {
             ],
             touches: Vec::default(),
             ignore_unused: true,
-            change_case: true,
             keep_unknown_fields: Vec::default(),
             dedups: Vec::default(),
             special_namings: Vec::default(),
-            common_crate_name: "common".into(),
-            split: false,
-            with_descriptor: false,
-            with_field_mask: false,
+            config: Config::default(),
             temp_dir,
-            with_comments: false,
+            dry_run: false,
         }
     }
+
+    /**
+     * Emulates the `required` fields of proto2 files, off by default.
+     *
+     * They are generated as `Option`s, decoding fails listing every one
+     * missing in the message and its nested messages, and so does
+     * [`Message::encode`](pilota::pb::Message::encode).
+     */
+    pub fn proto2_required(mut self, proto2_required: bool) -> Self {
+        self.parser.proto2_required(proto2_required);
+        self
+    }
 }
 
 impl<MkB, P> Builder<MkB, P>
@@ -181,16 +193,12 @@ impl<MkB, P> Builder<MkB, P> {
             plugins: self.plugins,
             ignore_unused: self.ignore_unused,
             touches: self.touches,
-            change_case: self.change_case,
             keep_unknown_fields: self.keep_unknown_fields,
             dedups: self.dedups,
             special_namings: self.special_namings,
-            common_crate_name: self.common_crate_name,
-            split: self.split,
-            with_descriptor: self.with_descriptor,
-            with_field_mask: self.with_field_mask,
+            config: self.config,
             temp_dir: self.temp_dir,
-            with_comments: self.with_comments,
+            dry_run: self.dry_run,
         }
     }
 
@@ -201,12 +209,12 @@ impl<MkB, P> Builder<MkB, P> {
     }
 
     pub fn split_generated_files(mut self, split: bool) -> Self {
-        self.split = split;
+        self.config.split = split;
         self
     }
 
     pub fn change_case(mut self, change_case: bool) -> Self {
-        self.change_case = change_case;
+        self.config.change_case = change_case;
         self
     }
 
@@ -250,17 +258,17 @@ impl<MkB, P> Builder<MkB, P> {
     }
 
     pub fn common_crate_name(mut self, name: FastStr) -> Self {
-        self.common_crate_name = name;
+        self.config.common_crate_name = name;
         self
     }
 
     pub fn with_descriptor(mut self, on: bool) -> Self {
-        self.with_descriptor = on;
+        self.config.with_descriptor = on;
         self
     }
 
     pub fn with_field_mask(mut self, on: bool) -> Self {
-        self.with_field_mask = on;
+        self.config.with_field_mask = on;
         self
     }
 
@@ -268,7 +276,176 @@ impl<MkB, P> Builder<MkB, P> {
      * Generate comments for the generated code
      */
     pub fn with_comments(mut self, on: bool) -> Self {
-        self.with_comments = on;
+        self.config.with_comments = on;
+        self
+    }
+
+    /**
+     * Generate i32 enums as closed rust enums with an extra `Unknown(i32)`
+     * variant instead of open newtypes.
+     *
+     * Unknown discriminants are decoded into `Unknown` and encoded back
+     * unchanged.
+     */
+    pub fn with_unknown_enum_variant(mut self, on: bool) -> Self {
+        self.config.with_unknown_enum_variant = on;
+        self
+    }
+
+    /**
+     * How to handle map entries whose i32 enum key is not defined in the
+     * IDL when decoding.
+     */
+    pub fn enum_key_policy(mut self, policy: EnumKeyPolicy) -> Self {
+        self.config.enum_key_policy = policy;
+        self
+    }
+
+    /**
+     * Make [`SerdePlugin`](crate::plugin::SerdePlugin) serialize every i64
+     * field as a JSON string, like proto3 JSON does, so JavaScript
+     * consumers don't lose precision. Single fields can opt in with the
+     * `pilota.serde_i64_as_string = "true"` annotation instead.
+     */
+    pub fn serde_i64_as_string(mut self, on: bool) -> Self {
+        self.config.serde_i64_as_string = on;
+        self
+    }
+
+    /**
+     * How to format the generated code. Defaults to
+     * [`Formatter::Prettyplease`], rustfmt is always used with
+     * [`with_comments`](Self::with_comments) since prettyplease drops
+     * comments.
+     */
+    pub fn formatter(mut self, formatter: Formatter) -> Self {
+        self.config.formatter = formatter;
+        self
+    }
+
+    /**
+     * How to encode thrift fields declared neither `required` nor
+     * `optional`, see [`DefaultFieldEncoding`].
+     */
+    pub fn default_field_encoding(mut self, encoding: DefaultFieldEncoding) -> Self {
+        self.config.default_field_encoding = encoding;
+        self
+    }
+
+    /**
+     * How to decode a thrift union carrying more than one field, see
+     * [`UnionPolicy`].
+     */
+    pub fn union_policy(mut self, policy: UnionPolicy) -> Self {
+        self.config.union_policy = policy;
+        self
+    }
+
+    /**
+     * Decodes the `i32` fields of thrift structs from `i64` values in range
+     * and the `i64` fields from `i32` values, instead of skipping them as
+     * unknown fields, to roll out a field changed from one to the other on
+     * one side before the other. The coercions are counted, see
+     * `pilota::thrift::coerce::coercions`.
+     */
+    pub fn lenient_numeric_decode(mut self, lenient: bool) -> Self {
+        self.config.lenient_numeric_decode = lenient;
+        self
+    }
+
+    /**
+     * Generates the thrift `uuid` fields as `uuid::Uuid` instead of
+     * `[u8; 16]`, read and written with the `_typed` protocol methods. The
+     * generated code then needs the `uuid` feature of `pilota`.
+     */
+    pub fn typed_uuid(mut self, typed: bool) -> Self {
+        self.config.typed_uuid = typed;
+        self
+    }
+
+    /**
+     * Generates a `const ENCODED_SIZE: usize` for the thrift structs whose
+     * binary encoding has the same size for every value, only required
+     * scalar, enum or such struct fields, and returns it from `size` with the
+     * binary protocols instead of computing it field by field.
+     */
+    pub fn fixed_encoded_size(mut self, fixed: bool) -> Self {
+        self.config.fixed_encoded_size = fixed;
+        self
+    }
+
+    /**
+     * Adds a `_cached_size` field to the thrift structs, in which `size`
+     * keeps the size it computes for the later calls of the same encode
+     * cycle, see `pilota::thrift::cached_size`.
+     */
+    pub fn cached_size(mut self, cached: bool) -> Self {
+        self.config.cached_size = cached;
+        self
+    }
+
+    /**
+     * Generates a `FIELD_NAMES` const for the thrift structs and unions,
+     * their fields by id, which `encode` passes to the protocols writing
+     * names instead of ids, such as `TSimpleJSONProtocol`.
+     */
+    pub fn with_field_names(mut self, with_field_names: bool) -> Self {
+        self.config.with_field_names = with_field_names;
+        self
+    }
+
+    /**
+     * Makes `value` available to every plugin under `key`, see
+     * [`Context::plugin_context`].
+     */
+    pub fn plugin_context<T: std::any::Any + Send + Sync>(
+        mut self,
+        key: impl Into<FastStr>,
+        value: T,
+    ) -> Self {
+        self.config.plugin_context.insert(key, value);
+        self
+    }
+
+    /**
+     * Mark the generated modules with `#![forbid(unsafe_code)]`, so that the
+     * compiler checks that crates forbidding unsafe code can include them.
+     */
+    pub fn forbid_unsafe_code(mut self, on: bool) -> Self {
+        self.config.forbid_unsafe_code = on;
+        self
+    }
+
+    /**
+     * Makes the generated decode of thrift structs track the fields it has
+     * read, failing on a field sent twice when the input protocol asks for
+     * it with `set_reject_duplicate_fields`. Off by default, the generated
+     * code then needs a `pilota` with `thrift::duplicate`.
+     */
+    pub fn duplicate_field_check(mut self, on: bool) -> Self {
+        self.config.duplicate_field_check = on;
+        self
+    }
+
+    /**
+     * Makes the generated decode of thrift structs and unions report the
+     * fields it skips to the hook set on the input protocol with
+     * `set_unknown_field_hook`. Off by default, the generated code then
+     * needs a `pilota` with `thrift::unknown::UnknownField`.
+     */
+    pub fn unknown_field_hook(mut self, on: bool) -> Self {
+        self.config.unknown_field_hook = on;
+        self
+    }
+
+    /**
+     * Generate into a temporary directory instead of the output, print a
+     * unified diff against the existing output and exit with a non-zero
+     * status if they differ. Useful to check in CI that checked-in
+     * generated code is up to date.
+     */
+    pub fn dry_run(mut self, on: bool) -> Self {
+        self.dry_run = on;
         self
     }
 }
@@ -315,6 +492,9 @@ where
         self.compile_with_config(services, out)
     }
 
+    /// Parses and resolves `services` into the [`Context`] the backends
+    /// generate from, with the options in `config`, whose `touch_all` is
+    /// set from `ignore_unused`.
     #[allow(clippy::too_many_arguments)]
     pub fn build_cx(
         services: Vec<IdlService>,
@@ -323,15 +503,10 @@ where
         touches: Vec<(PathBuf, Vec<String>)>,
         ignore_unused: bool,
         source_type: SourceType,
-        change_case: bool,
         keep_unknown_fields: Vec<PathBuf>,
         dedups: Vec<FastStr>,
         special_namings: Vec<FastStr>,
-        common_crate_name: FastStr,
-        split: bool,
-        with_descriptor: bool,
-        with_field_mask: bool,
-        with_comments: bool,
+        mut config: Config,
     ) -> Context {
         parser.inputs(services.iter().map(|s| &s.path));
         let ParseResult {
@@ -411,40 +586,38 @@ where
 
         cx.keep(keep_unknown_fields);
 
+        config.touch_all = !ignore_unused;
         cx.build(
             Arc::from(services),
             source_type,
-            change_case,
             dedups,
             special_namings,
-            common_crate_name,
-            split,
-            with_descriptor,
-            with_field_mask,
-            !ignore_unused,
-            with_comments,
+            config,
         )
     }
 
     pub fn compile_with_config(self, services: Vec<IdlService>, out: Output) {
         let _ = tracing_subscriber::fmt::try_init();
 
-        let cx = Self::build_cx(
+        if self.dry_run {
+            let diff = self.diff_with_config(services, out);
+            if !diff.is_empty() {
+                util::error_abort(format!("generated code is out of date:\n{diff}"));
+            }
+            return;
+        }
+
+        let mut cx = Self::build_cx(
             services,
             Some(out),
             self.parser,
             self.touches,
             self.ignore_unused,
             self.source_type,
-            self.change_case,
             self.keep_unknown_fields,
             self.dedups,
             self.special_namings,
-            self.common_crate_name,
-            self.split,
-            self.with_descriptor,
-            self.with_field_mask,
-            self.with_comments,
+            self.config,
         );
 
         cx.exec_plugin(BoxedPlugin);
@@ -479,9 +652,11 @@ where
             },
         ));
 
+        let mut plugins = self.plugins;
         CONTEXT.set(&cx, || {
-            self.plugins.into_iter().for_each(|p| cx.exec_plugin(p));
+            plugins.iter_mut().for_each(|p| cx.exec_plugin(p));
         });
+        cx.cache.plugins = Arc::new(Mutex::new(plugins));
 
         std::thread::scope(|scope| {
             let pool = rayon::ThreadPoolBuilder::new();
@@ -513,6 +688,155 @@ where
         .unwrap();
     }
 
+    /// Generates into a temporary directory and returns the unified diff
+    /// from the existing output to the new one, leaving the output
+    /// untouched. The diff is empty if the output is up to date.
+    pub fn diff_with_config(mut self, services: Vec<IdlService>, out: Output) -> String {
+        self.dry_run = false;
+        let dir = tempdir().unwrap();
+        let (old_dir, tmp_out) = match out {
+            Output::File(p) => (
+                p.parent().map(|p| p.to_path_buf()).unwrap_or_default(),
+                Output::File(dir.path().join(p.file_name().unwrap())),
+            ),
+            Output::Workspace(p) => {
+                // workspace generation merges into the existing manifests
+                if p.exists() {
+                    diff::copy_dir(&p, dir.path()).unwrap();
+                }
+                (p, Output::Workspace(dir.path().to_path_buf()))
+            }
+        };
+        self.compile_with_config(services, tmp_out);
+        diff::diff_dirs(&old_dir, dir.path()).unwrap()
+    }
+
+    /// Computes the [`IdlMetrics`](metrics::IdlMetrics) of the items that
+    /// would be generated for `services`, without generating them.
+    pub fn metrics(self, services: Vec<IdlService>) -> metrics::IdlMetrics {
+        let cx = Self::build_cx(
+            services,
+            None,
+            self.parser,
+            self.touches,
+            self.ignore_unused,
+            self.source_type,
+            self.keep_unknown_fields,
+            self.dedups,
+            self.special_namings,
+            self.config,
+        );
+        metrics::IdlMetrics::collect(&cx)
+    }
+
+    /// Computes the [`DependencyGraph`](graph::DependencyGraph) of the items
+    /// and files that would be generated for `services`, to be rendered with
+    /// [`to_dot`](graph::DependencyGraph::to_dot) or
+    /// [`to_json`](graph::DependencyGraph::to_json).
+    pub fn dependency_graph(self, services: Vec<IdlService>) -> graph::DependencyGraph {
+        let cx = Self::build_cx(
+            services,
+            None,
+            self.parser,
+            self.touches,
+            self.ignore_unused,
+            self.source_type,
+            self.keep_unknown_fields,
+            self.dedups,
+            self.special_namings,
+            self.config,
+        );
+        graph::DependencyGraph::collect(&cx)
+    }
+
+    /// Exports `items`, IDL paths such as `shop.Order`, and the types they
+    /// reference as a [WIT](wit) package named `package`, so that wasm
+    /// components can share the types of the generated services.
+    ///
+    /// This is experimental, types WIT can't express, such as recursive
+    /// ones, are reported as errors.
+    pub fn wit(
+        self,
+        services: Vec<IdlService>,
+        package: &str,
+        items: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> anyhow::Result<String> {
+        let cx = Self::build_cx(
+            services,
+            None,
+            self.parser,
+            self.touches,
+            self.ignore_unused,
+            self.source_type,
+            self.keep_unknown_fields,
+            self.dedups,
+            self.special_namings,
+            self.config,
+        );
+        wit::export(&cx, package, items)
+    }
+
+    /// Runs `backends` on the IR resolved from `services` and returns the
+    /// files they generate, to be written with
+    /// [`GeneratedFiles::write_to`]. The IDLs are parsed and resolved once
+    /// for all of them, and two backends generating the same path is an
+    /// error.
+    pub fn emit(
+        self,
+        services: Vec<IdlService>,
+        backends: &[&dyn backend::Backend],
+    ) -> anyhow::Result<GeneratedFiles> {
+        let cx = Self::build_cx(
+            services,
+            None,
+            self.parser,
+            self.touches,
+            self.ignore_unused,
+            self.source_type,
+            self.keep_unknown_fields,
+            self.dedups,
+            self.special_namings,
+            self.config,
+        );
+
+        let mut files = GeneratedFiles::default();
+        let mut owners = std::collections::HashMap::new();
+        for backend in backends {
+            let generated = CONTEXT
+                .set(&cx, || backend.generate(&cx))
+                .map_err(|e| e.context(format!("backend `{}` failed", backend.name())))?;
+            for (path, contents) in generated.into_inner() {
+                if let Some(owner) = owners.insert(path.clone(), backend.name()) {
+                    anyhow::bail!(
+                        "backends `{owner}` and `{}` both generate {}",
+                        backend.name(),
+                        path.display()
+                    );
+                }
+                files.insert(path, contents);
+            }
+        }
+        Ok(files)
+    }
+
+    /// Generates into a temporary directory and returns the generated files
+    /// instead of writing them to `out`, whose file or directory name is
+    /// kept. Paths are relative to the directory containing the output.
+    pub fn compile_to_memory(
+        mut self,
+        services: Vec<IdlService>,
+        out: Output,
+    ) -> anyhow::Result<GeneratedFiles> {
+        self.dry_run = false;
+        let dir = tempdir()?;
+        let tmp_out = match out {
+            Output::File(p) => Output::File(dir.path().join(p.file_name().unwrap())),
+            Output::Workspace(p) => Output::Workspace(dir.path().join(p.file_name().unwrap())),
+        };
+        self.compile_with_config(services, tmp_out);
+        Ok(GeneratedFiles::read_dir(dir.path())?)
+    }
+
     // gen service_global_name and methods for certain service in IdlService
     pub fn init_service(self, service: IdlService) -> anyhow::Result<(String, String)> {
         let _ = tracing_subscriber::fmt::try_init();
@@ -524,15 +848,10 @@ where
             self.touches,
             self.ignore_unused,
             self.source_type,
-            self.change_case,
             self.keep_unknown_fields,
             self.dedups,
             self.special_namings,
-            self.common_crate_name,
-            self.split,
-            self.with_descriptor,
-            self.with_field_mask,
-            self.with_comments,
+            self.config,
         );
 
         std::thread::scope(|_scope| {
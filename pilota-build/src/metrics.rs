@@ -0,0 +1,425 @@
+//! IDL level metrics, see [`Builder::metrics`](crate::Builder::metrics).
+
+use std::{fmt::Write as _, path::Path, sync::Arc};
+
+use rustc_hash::FxHashSet;
+
+use crate::{
+    Context, DefId,
+    db::RirDatabase,
+    middle::{
+        rir::{FieldKind, Item, NodeKind},
+        ty::{Ty, TyKind},
+    },
+    symbol::Ident,
+};
+
+/// Counts and size estimates of the items generated from a set of IDLs,
+/// meant to track the growth of a schema across releases.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IdlMetrics {
+    pub files: usize,
+    pub structs: usize,
+    pub unions: usize,
+    pub enums: usize,
+    pub typedefs: usize,
+    pub consts: usize,
+    pub services: usize,
+    /// The fields of all structs, unions excluded.
+    pub fields: usize,
+    /// The deepest nesting of structs and unions, a struct with only scalar
+    /// fields has a depth of 1. Recursive types are counted once.
+    pub max_depth: usize,
+    pub messages: Vec<MessageMetrics>,
+    pub methods: Vec<MethodMetrics>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageMetrics {
+    /// The name of the struct, prefixed by its package.
+    pub name: String,
+    pub fields: usize,
+    pub depth: usize,
+    /// The smallest encoding of the struct with the binary protocol, only
+    /// required fields set.
+    pub min_size: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MethodMetrics {
+    /// The name of the service, prefixed by its package.
+    pub service: String,
+    pub method: String,
+    pub args: usize,
+    /// The smallest request body with the binary protocol, the message
+    /// header excluded.
+    pub min_request_size: usize,
+    /// The smallest response body with the binary protocol, `None` for
+    /// oneway methods.
+    pub min_response_size: Option<usize>,
+}
+
+// field type, field id and the value
+const FIELD_HEADER_LEN: usize = 3;
+const STOP_LEN: usize = 1;
+
+impl IdlMetrics {
+    pub(crate) fn collect(cx: &Context) -> Self {
+        let mut metrics = IdlMetrics {
+            files: cx.db.files().len(),
+            ..Default::default()
+        };
+        let mut sizer = Sizer::new(cx);
+
+        let method_items = method_items(cx);
+
+        for def_id in cx.cache.codegen_items.iter().copied() {
+            if method_items.contains(&def_id) {
+                continue;
+            }
+            let Some(item) = cx.db.item(def_id) else {
+                continue;
+            };
+            match &*item {
+                Item::Message(m) => {
+                    metrics.structs += 1;
+                    metrics.fields += m.fields.len();
+                    let depth = sizer.depth(def_id);
+                    metrics.max_depth = metrics.max_depth.max(depth);
+                    metrics.messages.push(MessageMetrics {
+                        name: qualified_name(cx, def_id, &m.name),
+                        fields: m.fields.len(),
+                        depth,
+                        min_size: sizer.item_min_size(def_id),
+                    });
+                }
+                Item::Enum(e) if e.repr.is_none() => {
+                    metrics.unions += 1;
+                    metrics.max_depth = metrics.max_depth.max(sizer.depth(def_id));
+                }
+                Item::Enum(_) => metrics.enums += 1,
+                Item::NewType(_) => metrics.typedefs += 1,
+                Item::Const(_) => metrics.consts += 1,
+                Item::Service(s) => {
+                    metrics.services += 1;
+                    let service = qualified_name(cx, def_id, &s.name);
+                    metrics.methods.extend(s.methods.iter().map(|m| {
+                        let request = m
+                            .args
+                            .iter()
+                            .filter(|a| a.kind == FieldKind::Required)
+                            .map(|a| FIELD_HEADER_LEN + sizer.min_size(&a.ty))
+                            .sum::<usize>();
+                        let response = match &m.ret.kind {
+                            TyKind::Void => 0,
+                            _ => FIELD_HEADER_LEN + sizer.min_size(&m.ret),
+                        };
+                        MethodMetrics {
+                            service: service.clone(),
+                            method: m.name.sym.0.to_string(),
+                            args: m.args.len(),
+                            min_request_size: STOP_LEN + request,
+                            min_response_size: (!m.oneway).then_some(STOP_LEN + response),
+                        }
+                    }));
+                }
+                Item::Mod(_) => {}
+            }
+        }
+        metrics
+    }
+
+    /// Renders the metrics in the Prometheus text format, as read by the
+    /// textfile collector of the node exporter.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let mut gauge = |name: &str, help: &str, samples: &[(String, usize)]| {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} gauge");
+            for (labels, value) in samples {
+                let _ = writeln!(out, "{name}{labels} {value}");
+            }
+        };
+
+        gauge(
+            "pilota_idl_files",
+            "Number of IDL files.",
+            &[(String::new(), self.files)],
+        );
+        gauge(
+            "pilota_idl_items",
+            "Number of generated IDL items by kind.",
+            &[
+                (labels(&[("kind", "struct")]), self.structs),
+                (labels(&[("kind", "union")]), self.unions),
+                (labels(&[("kind", "enum")]), self.enums),
+                (labels(&[("kind", "typedef")]), self.typedefs),
+                (labels(&[("kind", "const")]), self.consts),
+                (labels(&[("kind", "service")]), self.services),
+                (labels(&[("kind", "method")]), self.methods.len()),
+            ],
+        );
+        gauge(
+            "pilota_idl_fields",
+            "Number of struct fields.",
+            &[(String::new(), self.fields)],
+        );
+        gauge(
+            "pilota_idl_max_depth",
+            "Deepest nesting of structs and unions.",
+            &[(String::new(), self.max_depth)],
+        );
+
+        let by_message = |f: fn(&MessageMetrics) -> usize| {
+            self.messages
+                .iter()
+                .map(|m| (labels(&[("message", &m.name)]), f(m)))
+                .collect::<Vec<_>>()
+        };
+        gauge(
+            "pilota_idl_message_fields",
+            "Number of fields per struct.",
+            &by_message(|m| m.fields),
+        );
+        gauge(
+            "pilota_idl_message_depth",
+            "Nesting depth per struct.",
+            &by_message(|m| m.depth),
+        );
+        gauge(
+            "pilota_idl_message_min_size_bytes",
+            "Smallest binary encoding per struct.",
+            &by_message(|m| m.min_size),
+        );
+
+        let method_labels =
+            |m: &MethodMetrics| labels(&[("service", &m.service), ("method", &m.method)]);
+        gauge(
+            "pilota_idl_method_request_min_size_bytes",
+            "Smallest binary request body per method.",
+            &self
+                .methods
+                .iter()
+                .map(|m| (method_labels(m), m.min_request_size))
+                .collect::<Vec<_>>(),
+        );
+        gauge(
+            "pilota_idl_method_response_min_size_bytes",
+            "Smallest binary response body per method, oneway methods excluded.",
+            &self
+                .methods
+                .iter()
+                .filter_map(|m| Some((method_labels(m), m.min_response_size?)))
+                .collect::<Vec<_>>(),
+        );
+        out
+    }
+
+    /// Writes [`Self::to_prometheus`] to `path`, through a temporary file so
+    /// that a collector never reads a partial file.
+    pub fn write_prometheus(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        let tmp = path.with_extension("prom.tmp");
+        std::fs::write(&tmp, self.to_prometheus())?;
+        std::fs::rename(tmp, path)
+    }
+}
+
+/// The args and results generated for the methods of services.
+pub(crate) fn method_items(cx: &Context) -> FxHashSet<DefId> {
+    cx.cache
+        .codegen_items
+        .iter()
+        .filter_map(|def_id| cx.db.node(*def_id))
+        .filter(|node| matches!(&node.kind, NodeKind::Item(item) if matches!(&**item, Item::Service(_))))
+        .flat_map(|node| node.related_nodes)
+        .collect()
+}
+
+fn labels(pairs: &[(&str, &str)]) -> String {
+    let pairs = pairs
+        .iter()
+        .map(|(k, v)| {
+            let v = v
+                .replace('\\', r"\\")
+                .replace('"', r#"\""#)
+                .replace('\n', r"\n");
+            format!(r#"{k}="{v}""#)
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", pairs.join(","))
+}
+
+pub(crate) fn qualified_name(cx: &Context, def_id: DefId, name: &Ident) -> String {
+    let package = cx
+        .db
+        .node(def_id)
+        .and_then(|node| cx.db.file(node.file_id))
+        .map(|file| {
+            file.package
+                .iter()
+                .map(|s| s.0.as_str())
+                .collect::<Vec<_>>()
+                .join(".")
+        })
+        .unwrap_or_default();
+    if package.is_empty() {
+        name.sym.0.to_string()
+    } else {
+        format!("{package}.{}", name.sym.0)
+    }
+}
+
+pub(crate) struct Sizer<'a> {
+    cx: &'a Context,
+    // the items being measured, to stop at recursive types
+    visiting: FxHashSet<DefId>,
+}
+
+impl<'a> Sizer<'a> {
+    pub(crate) fn new(cx: &'a Context) -> Self {
+        Sizer {
+            cx,
+            visiting: Default::default(),
+        }
+    }
+
+    fn item(&self, def_id: DefId) -> Option<Arc<Item>> {
+        self.cx.db.item(def_id)
+    }
+
+    fn min_size(&mut self, ty: &Ty) -> usize {
+        match &ty.kind {
+            TyKind::Void => 0,
+            TyKind::Bool | TyKind::U8 | TyKind::I8 => 1,
+            TyKind::I16 => 2,
+            TyKind::I32 | TyKind::UInt32 | TyKind::F32 => 4,
+            TyKind::I64 | TyKind::UInt64 | TyKind::F64 | TyKind::OrderedF64 => 8,
+            TyKind::Uuid => 16,
+            // the length
+            TyKind::String | TyKind::FastStr | TyKind::Bytes | TyKind::BytesVec => 4,
+            // element type and length
+            TyKind::Vec(_) | TyKind::Set(_) | TyKind::BTreeSet(_) => 5,
+            // key type, value type and length
+            TyKind::Map(..) | TyKind::BTreeMap(..) => 6,
+            TyKind::Arc(ty) => self.min_size(ty),
+            TyKind::Path(path) => self.item_min_size(path.did),
+        }
+    }
+
+    pub(crate) fn item_min_size(&mut self, def_id: DefId) -> usize {
+        let Some(item) = self.item(def_id) else {
+            return 0;
+        };
+        if !self.visiting.insert(def_id) {
+            return STOP_LEN;
+        }
+        let size = match &*item {
+            Item::Message(m) => {
+                STOP_LEN
+                    + m.fields
+                        .iter()
+                        .filter(|f| f.kind == FieldKind::Required)
+                        .map(|f| FIELD_HEADER_LEN + self.min_size(&f.ty))
+                        .sum::<usize>()
+            }
+            Item::Enum(e) if e.repr.is_none() => {
+                STOP_LEN
+                    + e.variants
+                        .iter()
+                        .filter_map(|v| v.fields.first())
+                        .map(|ty| FIELD_HEADER_LEN + self.min_size(ty))
+                        .min()
+                        .unwrap_or_default()
+            }
+            Item::Enum(_) => 4,
+            Item::NewType(t) => self.min_size(&t.ty),
+            Item::Const(_) | Item::Service(_) | Item::Mod(_) => 0,
+        };
+        self.visiting.remove(&def_id);
+        size
+    }
+
+    fn fixed_size(&mut self, ty: &Ty) -> Option<usize> {
+        match &ty.kind {
+            TyKind::Bool | TyKind::U8 | TyKind::I8 => Some(1),
+            TyKind::I16 => Some(2),
+            TyKind::I32 => Some(4),
+            TyKind::I64 | TyKind::F64 | TyKind::OrderedF64 => Some(8),
+            TyKind::Uuid => Some(16),
+            TyKind::Arc(ty) => self.fixed_size(ty),
+            TyKind::Path(path) => self.item_fixed_size(path.did),
+            _ => None,
+        }
+    }
+
+    /// The size of the binary encoding of the item if the same for every
+    /// value: structs of required scalars, enums and structs of those.
+    pub(crate) fn item_fixed_size(&mut self, def_id: DefId) -> Option<usize> {
+        let item = self.item(def_id)?;
+        if !self.visiting.insert(def_id) {
+            return None;
+        }
+        let size = match &*item {
+            Item::Message(m) if !self.cx.cache.keep_unknown_fields.contains(&def_id) => m
+                .fields
+                .iter()
+                .map(|f| {
+                    if f.is_optional() {
+                        return None;
+                    }
+                    Some(FIELD_HEADER_LEN + self.fixed_size(&f.ty)?)
+                })
+                .sum::<Option<usize>>()
+                .map(|fields| fields + STOP_LEN),
+            Item::Enum(e) if e.repr.is_some() => Some(4),
+            Item::NewType(t) => self.fixed_size(&t.ty),
+            _ => None,
+        };
+        self.visiting.remove(&def_id);
+        size
+    }
+
+    fn depth(&mut self, def_id: DefId) -> usize {
+        let Some(item) = self.item(def_id) else {
+            return 0;
+        };
+        if !self.visiting.insert(def_id) {
+            return 0;
+        }
+        let depth = match &*item {
+            Item::Message(m) => {
+                1 + m
+                    .fields
+                    .iter()
+                    .map(|f| self.ty_depth(&f.ty))
+                    .max()
+                    .unwrap_or_default()
+            }
+            Item::Enum(e) if e.repr.is_none() => {
+                1 + e
+                    .variants
+                    .iter()
+                    .flat_map(|v| v.fields.iter())
+                    .map(|ty| self.ty_depth(ty))
+                    .max()
+                    .unwrap_or_default()
+            }
+            Item::NewType(t) => self.ty_depth(&t.ty),
+            _ => 0,
+        };
+        self.visiting.remove(&def_id);
+        depth
+    }
+
+    fn ty_depth(&mut self, ty: &Ty) -> usize {
+        match &ty.kind {
+            TyKind::Vec(ty) | TyKind::Set(ty) | TyKind::BTreeSet(ty) | TyKind::Arc(ty) => {
+                self.ty_depth(ty)
+            }
+            TyKind::Map(k, v) | TyKind::BTreeMap(k, v) => self.ty_depth(k).max(self.ty_depth(v)),
+            TyKind::Path(path) => self.depth(path.did),
+            _ => 0,
+        }
+    }
+}
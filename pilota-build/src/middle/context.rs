@@ -1,6 +1,11 @@
-use std::{collections::HashMap, ops::Deref, path::PathBuf, sync::Arc};
+use std::{
+    collections::{BTreeSet, HashMap},
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
 
-use ahash::{AHashMap, HashSet};
+use ahash::AHashMap;
 use anyhow::Context as _;
 use dashmap::DashMap;
 use faststr::FastStr;
@@ -15,8 +20,9 @@ use super::{
     rir::NodeKind,
 };
 use crate::{
-    Plugin,
+    Plugin, PluginContext,
     db::{RirDatabase, RootDatabase},
+    fmt::{Formatter, rewrite_and_format_file},
     rir::{self, Field, Item, ItemPath, Literal},
     symbol::{DefId, FileId, IdentName, ModPath, SPECIAL_NAMINGS, Symbol},
     tags::{TagId, Tags},
@@ -77,6 +83,72 @@ pub struct Config {
     pub touch_all: bool,
     pub common_crate_name: FastStr,
     pub with_comments: bool,
+    pub with_unknown_enum_variant: bool,
+    pub enum_key_policy: EnumKeyPolicy,
+    pub serde_i64_as_string: bool,
+    pub formatter: Formatter,
+    pub default_field_encoding: DefaultFieldEncoding,
+    pub union_policy: UnionPolicy,
+    pub lenient_numeric_decode: bool,
+    pub typed_uuid: bool,
+    pub fixed_encoded_size: bool,
+    pub cached_size: bool,
+    pub with_field_names: bool,
+    pub plugin_context: PluginContext,
+    pub forbid_unsafe_code: bool,
+    pub duplicate_field_check: bool,
+    pub unknown_field_hook: bool,
+}
+
+impl Default for Config {
+    /// The defaults of [`Builder`](crate::Builder).
+    fn default() -> Self {
+        Config {
+            change_case: true,
+            split: false,
+            with_descriptor: false,
+            with_field_mask: false,
+            touch_all: false,
+            common_crate_name: "common".into(),
+            with_comments: false,
+            with_unknown_enum_variant: false,
+            enum_key_policy: EnumKeyPolicy::Keep,
+            serde_i64_as_string: false,
+            formatter: Formatter::default(),
+            default_field_encoding: DefaultFieldEncoding::default(),
+            union_policy: UnionPolicy::default(),
+            lenient_numeric_decode: false,
+            typed_uuid: false,
+            fixed_encoded_size: false,
+            cached_size: false,
+            with_field_names: false,
+            plugin_context: PluginContext::default(),
+            forbid_unsafe_code: false,
+            duplicate_field_check: false,
+            unknown_field_hook: false,
+        }
+    }
+}
+
+impl Config {
+    /// The inner attributes of the root module of generated files.
+    pub fn mod_attrs(&self) -> &'static str {
+        if self.forbid_unsafe_code {
+            "#![allow(warnings, clippy::all)]\n#![forbid(unsafe_code)]"
+        } else {
+            "#![allow(warnings, clippy::all)]"
+        }
+    }
+
+    /// The formatter actually used for generated files, comments only
+    /// survive rustfmt.
+    pub fn formatter(&self) -> Formatter {
+        if self.with_comments {
+            Formatter::Rustfmt
+        } else {
+            self.formatter
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -91,6 +163,8 @@ pub struct Cache {
     pub location_map: Arc<FxHashMap<DefId, DefLocation>>,
     pub entry_map: Arc<HashMap<DefLocation, Vec<(DefId, DefLocation)>>>,
     pub plugin_gen: Arc<DashMap<DefLocation, String>>,
+    pub extra_files: Arc<DashMap<PathBuf, String>>,
+    pub plugins: Arc<Mutex<Vec<Box<dyn Plugin>>>>,
     pub dedups: Vec<FastStr>,
     pub names: FxHashMap<DefId, usize>,
 }
@@ -183,6 +257,10 @@ impl ContextBuilder {
                 self.codegen_items.extend(def_ids.iter());
             }
         }
+        // Hash map iteration order must not leak into the generated code.
+        self.codegen_items.sort();
+        self.codegen_items.dedup();
+
         if matches!(self.mode, Mode::Workspace(_)) {
             let location_map = self.workspace_collect_def_ids(&self.codegen_items);
             self.location_map = location_map.clone();
@@ -457,20 +535,13 @@ impl ContextBuilder {
         });
     }
 
-    #[allow(clippy::too_many_arguments)]
     pub(crate) fn build(
         self,
         services: Arc<[crate::IdlService]>,
         source_type: SourceType,
-        change_case: bool,
         dedups: Vec<FastStr>,
         special_namings: Vec<FastStr>,
-        common_crate_name: FastStr,
-        split: bool,
-        with_descriptor: bool,
-        with_field_mask: bool,
-        touch_all: bool,
-        with_comments: bool,
+        config: Config,
     ) -> Context {
         let mode = Arc::new(self.mode);
         SPECIAL_NAMINGS.get_or_init(|| special_namings);
@@ -485,15 +556,7 @@ impl ContextBuilder {
                     Mode::SingleFile { .. } => Arc::new(DefaultPathResolver),
                 },
             },
-            config: Config {
-                change_case,
-                split,
-                with_descriptor,
-                with_field_mask,
-                touch_all,
-                common_crate_name,
-                with_comments,
-            },
+            config,
             cache: Cache {
                 adjusts: Default::default(),
                 codegen_items: self.codegen_items,
@@ -501,6 +564,8 @@ impl ContextBuilder {
                 location_map: Arc::new(self.location_map),
                 entry_map: Arc::new(self.entry_map),
                 plugin_gen: Default::default(),
+                extra_files: Default::default(),
+                plugins: Default::default(),
                 dedups,
                 names: Default::default(),
                 mod_idxes: Default::default(),
@@ -555,7 +620,7 @@ impl ContextBuilder {
         cx.cache.mod_idxes.extend(mod_idxes);
 
         if matches!(&*cx.source.mode, Mode::SingleFile { .. }) {
-            let mut mod_files = HashMap::<ModPath, HashSet<FileId>>::default();
+            let mut mod_files = HashMap::<ModPath, BTreeSet<FileId>>::default();
             let mod_items = cx
                 .cache
                 .codegen_items
@@ -597,6 +662,46 @@ pub enum SourceType {
     Protobuf,
 }
 
+/// Decode behavior for map entries keyed by an i32 enum value which is not
+/// defined in the IDL.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EnumKeyPolicy {
+    /// Keep the entry with the raw key.
+    #[default]
+    Keep,
+    /// Skip the entry and continue decoding.
+    Drop,
+    /// Fail decoding with an `InvalidData` protocol exception.
+    Error,
+}
+
+/// Encode behavior for thrift fields declared neither `required` nor
+/// `optional`. They are generated as `Option`s and decoding never requires
+/// them, in both cases.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DefaultFieldEncoding {
+    /// Write the field only when it is `Some`, like an optional field.
+    #[default]
+    SkipUnset,
+    /// Always write the field, a `None` is written as the IDL default value
+    /// or else the type's `Default`, like Apache Thrift's "opt-in, req-out"
+    /// semantics. As in Apache Thrift's Java implementation, unset struct,
+    /// union and exception fields are still skipped.
+    AlwaysWrite,
+}
+
+/// Decode behavior for thrift unions carrying more than one field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnionPolicy {
+    /// Fail decoding with an `InvalidData` protocol exception.
+    #[default]
+    Error,
+    /// Keep the first field and skip the others.
+    KeepFirst,
+    /// Keep the last field.
+    KeepLast,
+}
+
 impl Context {
     pub fn config_data(&self) -> &Config {
         &self.config
@@ -649,17 +754,48 @@ impl Context {
             .is_some()
     }
 
+    /// Whether the thrift struct `s` has a `_cached_size` field, which the
+    /// empty structs, sized at once, go without.
+    pub fn has_cached_size(&self, def_id: DefId, s: &rir::Message) -> bool {
+        self.config.cached_size
+            && matches!(self.source.source_type, SourceType::Thrift)
+            && !s.is_wrapper
+            && (!s.fields.is_empty() || self.cache.keep_unknown_fields.contains(&def_id))
+    }
+
     pub fn symbol_name(&self, def_id: DefId) -> Symbol {
         let item = self.item(def_id).unwrap();
         item.symbol_name()
     }
 
+    /// Follows a literal naming another constant, possibly one of an included
+    /// file, to the literal that constant is defined with.
+    fn resolve_const_lit(&self, lit: &Literal) -> Literal {
+        let mut lit = lit.clone();
+        let mut seen = FxHashSet::default();
+        while let Literal::Path(p) = &lit {
+            if !seen.insert(p.did) {
+                break;
+            }
+            match self.item(p.did).as_deref() {
+                Some(Item::Const(c)) => lit = c.lit.clone(),
+                _ => break,
+            }
+        }
+        lit
+    }
+
     fn get_codegen_ty_for_path(&self, def_id: DefId) -> CodegenTy {
         let node = self.node(def_id).unwrap();
         match &node.kind {
             NodeKind::Item(item) => match &**item {
                 Item::Const(c) => {
-                    let ty = self.codegen_const_ty(c.ty.kind.clone());
+                    let mut ty = self.codegen_const_ty(c.ty.kind.clone());
+                    if let (Literal::List(lit), CodegenTy::Array(_, size)) =
+                        (&self.resolve_const_lit(&c.lit), &mut ty)
+                    {
+                        *size = lit.len()
+                    }
                     if ty.should_lazy_static() {
                         CodegenTy::LazyStaticRef(Arc::new(ty))
                     } else {
@@ -937,6 +1073,25 @@ impl Context {
                 };
                 (format!("({stream}.inner() as {target})").into(), true)
             }
+            // constants of a narrower numeric type, often defined in an included
+            // file, such as `const i64 X = other.I32_LIMIT`
+            (
+                CodegenTy::I8,
+                CodegenTy::I16 | CodegenTy::I32 | CodegenTy::I64 | CodegenTy::F32 | CodegenTy::F64,
+            )
+            | (CodegenTy::I16, CodegenTy::I32 | CodegenTy::I64 | CodegenTy::F32 | CodegenTy::F64)
+            | (CodegenTy::I32, CodegenTy::I64 | CodegenTy::F64)
+            | (CodegenTy::F32, CodegenTy::F64) => {
+                let target = match target {
+                    CodegenTy::I64 => "i64",
+                    CodegenTy::I32 => "i32",
+                    CodegenTy::I16 => "i16",
+                    CodegenTy::F64 => "f64",
+                    CodegenTy::F32 => "f32",
+                    _ => unreachable!(),
+                };
+                (format!("({stream} as {target})").into(), true)
+            }
             _ => panic!("invalid convert {ident_ty:?} to {target:?}"),
         }
     }
@@ -1483,7 +1638,9 @@ impl Context {
         ty: &mut CodegenTy,
     ) -> anyhow::Result<String> {
         let should_lazy_static = ty.should_lazy_static();
-        if let (Literal::List(lit), CodegenTy::Array(_, size)) = (lit, &mut *ty) {
+        if let (Literal::List(lit), CodegenTy::Array(_, size)) =
+            (&self.resolve_const_lit(lit), &mut *ty)
+        {
             *size = lit.len()
         }
         Ok(if should_lazy_static {
@@ -1595,6 +1752,30 @@ impl Context {
         self.related_path(&mod_segs, &other_item_path)
     }
 
+    /// Returns the value registered with
+    /// [`Builder::plugin_context`](crate::Builder::plugin_context) under
+    /// `key`, if it is of type `T`.
+    pub fn plugin_context<T: std::any::Any>(&self, key: &str) -> Option<&T> {
+        self.config.plugin_context.get(key)
+    }
+
+    /// Asks for an additional file to be written next to the generated code,
+    /// `path` is relative to the output directory. Emitting the same path
+    /// twice keeps the last contents.
+    pub fn emit_file(&self, path: impl Into<PathBuf>, contents: impl Into<String>) {
+        self.cache.extra_files.insert(path.into(), contents.into());
+    }
+
+    /// Formats the generated file at `path` after passing it to
+    /// [`Plugin::on_file`] of every plugin.
+    pub(crate) fn finish_file(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        rewrite_and_format_file(path, self.config.formatter(), |file| {
+            let mut plugins = self.cache.plugins.lock().unwrap();
+            plugins.iter_mut().for_each(|p| p.on_file(self, path, file));
+        })
+    }
+
     #[allow(clippy::single_match)]
     pub fn exec_plugin<P: Plugin>(&self, mut p: P) {
         p.on_codegen_uint(self, &self.cache.codegen_items);
@@ -1742,6 +1923,21 @@ mod tests {
                 touch_all: false,
                 common_crate_name: "common".into(),
                 with_comments: false,
+                with_unknown_enum_variant: false,
+                enum_key_policy: EnumKeyPolicy::Keep,
+                serde_i64_as_string: false,
+                formatter: Formatter::default(),
+                default_field_encoding: DefaultFieldEncoding::default(),
+                union_policy: UnionPolicy::default(),
+                lenient_numeric_decode: false,
+                typed_uuid: false,
+                fixed_encoded_size: false,
+                cached_size: false,
+                with_field_names: false,
+                plugin_context: PluginContext::default(),
+                forbid_unsafe_code: false,
+                duplicate_field_check: false,
+                unknown_field_hook: false,
             },
             cache: Cache {
                 adjusts: Arc::new(DashMap::default()),
@@ -1754,6 +1950,8 @@ mod tests {
                 location_map: Arc::new(FxHashMap::default()),
                 entry_map: Arc::new(HashMap::default()),
                 plugin_gen: Arc::new(DashMap::default()),
+                extra_files: Arc::new(DashMap::default()),
+                plugins: Arc::new(Mutex::new(Vec::new())),
                 dedups: Vec::new(),
                 names: FxHashMap::default(),
             },
@@ -1,6 +1,7 @@
 pub mod adjust;
 pub mod context;
 pub mod ext;
+mod query;
 pub mod resolver;
 pub mod rir;
 pub mod ty;
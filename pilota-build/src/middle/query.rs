@@ -0,0 +1,134 @@
+//! Lookups over the whole IR for plugins, so they don't have to walk every
+//! item themselves.
+
+use std::sync::Arc;
+
+use faststr::FastStr;
+use rustc_hash::FxHashSet;
+
+use super::{
+    context::Context,
+    rir::{Item, Method, NodeKind},
+    ty::Visitor,
+};
+use crate::{db::RirDatabase, symbol::DefId, tags::thrift::MethodOptions};
+
+#[derive(Default)]
+struct Refs(FxHashSet<DefId>);
+
+impl Visitor for Refs {
+    fn visit_path(&mut self, path: &crate::rir::Path) {
+        self.0.insert(path.did);
+    }
+}
+
+impl Refs {
+    fn visit_item(&mut self, item: &Item) {
+        match item {
+            Item::Message(s) => s.fields.iter().for_each(|f| self.visit(&f.ty)),
+            Item::Enum(e) => e
+                .variants
+                .iter()
+                .flat_map(|v| &v.fields)
+                .for_each(|ty| self.visit(ty)),
+            Item::Service(s) => {
+                self.0.extend(s.extend.iter().map(|p| p.did));
+                for m in &s.methods {
+                    m.args.iter().for_each(|a| self.visit(&a.ty));
+                    self.visit(&m.ret);
+                    self.0.extend(m.exceptions.iter().map(|p| p.did));
+                }
+            }
+            Item::NewType(t) => self.visit(&t.ty),
+            Item::Const(c) => self.visit(&c.ty),
+            Item::Mod(_) => {}
+        }
+    }
+}
+
+impl Context {
+    /// Finds an item by its IDL path, the package of its file and its name
+    /// separated by dots, such as `shop.Order`. Items nested in a message,
+    /// as in protobuf, are found by the path of the message followed by
+    /// their name.
+    pub fn resolve(&self, path: &str) -> Option<DefId> {
+        let mut files = self.files().values().collect::<Vec<_>>();
+        files.sort_by_key(|f| f.file_id);
+        files.into_iter().find_map(|file| {
+            let mut rest = path;
+            for segment in file.package.iter() {
+                rest = rest.strip_prefix(segment.0.as_str())?.strip_prefix('.')?;
+            }
+            let mut items = file.items.clone();
+            let mut segments = rest.split('.').peekable();
+            while let Some(segment) = segments.next() {
+                let def_id = items.into_iter().find(|def_id| {
+                    self.item(*def_id)
+                        .is_some_and(|item| item.symbol_name().0.as_str() == segment)
+                })?;
+                if segments.peek().is_none() {
+                    return Some(def_id);
+                }
+                match &*self.item(def_id)? {
+                    Item::Mod(m) => items = m.items.clone(),
+                    _ => return None,
+                }
+            }
+            None
+        })
+    }
+
+    /// The items whose definitions mention `def_id`: the messages, enums and
+    /// new types with a field of its type, the constants of its type and the
+    /// services using it in a method or extending it. Sorted, without
+    /// duplicates.
+    pub fn who_references(&self, def_id: DefId) -> Vec<DefId> {
+        let mut referrers = self
+            .nodes()
+            .iter()
+            .filter_map(|(id, node)| match &node.kind {
+                NodeKind::Item(item) => {
+                    let mut refs = Refs::default();
+                    refs.visit_item(item);
+                    refs.0.contains(&def_id).then_some(*id)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        referrers.sort();
+        referrers
+    }
+
+    /// The methods carrying the annotation `key` not read by pilota, with
+    /// the service declaring them and the annotation's value, in the order
+    /// of the services' definitions.
+    pub fn methods_with_annotation(&self, key: &str) -> Vec<(DefId, Arc<Method>, FastStr)> {
+        let mut services = self
+            .nodes()
+            .iter()
+            .filter_map(|(id, node)| match &node.kind {
+                NodeKind::Item(item) => match &**item {
+                    Item::Service(s) => Some((*id, s.methods.clone())),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        services.sort_by_key(|(id, _)| *id);
+        services
+            .into_iter()
+            .flat_map(|(service, methods)| {
+                methods.into_iter().filter_map(move |m| {
+                    let tags = self.node_tags(m.def_id)?;
+                    let (_, value) = tags
+                        .get::<MethodOptions>()?
+                        .0
+                        .iter()
+                        .find(|(k, _)| k == key)?;
+                    let value = value.clone();
+                    Some((service, m, value))
+                })
+            })
+            .collect()
+    }
+}
@@ -7,7 +7,7 @@ use super::ty::Ty;
 use crate::{
     middle::ext::{FileExts, ItemExts, ModExts},
     symbol::{DefId, EnumRepr, FileId, Ident, Symbol},
-    tags::TagId,
+    tags::{StreamingMode, TagId},
 };
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -49,6 +49,7 @@ pub struct Method {
     pub args: Vec<Arc<Arg>>,
     pub ret: Ty,
     pub oneway: bool,
+    pub streaming: StreamingMode,
     pub exceptions: Option<Path>,
     pub source: MethodSource,
     pub leading_comments: FastStr,
@@ -4,7 +4,7 @@ pub use std::sync::Arc;
 pub use TyKind::*;
 use itertools::Itertools;
 
-use super::context::tls::with_cx;
+use super::context::tls::{CONTEXT, with_cx};
 pub use super::rir::Path;
 use crate::{db::RirDatabase, symbol::DefId, tags::TagId};
 
@@ -121,7 +121,7 @@ impl CodegenTy {
             CodegenTy::UInt32 => "u32".into(),
             CodegenTy::UInt64 => "u64".into(),
             CodegenTy::F32 => "f32".into(),
-            CodegenTy::Uuid => "[u8; 16]".into(),
+            CodegenTy::Uuid => uuid_path().into(),
             CodegenTy::StaticRef(ty) => {
                 let ty = &**ty;
                 format!("&'static {}", ty.global_path(adt_prefix)).into()
@@ -185,6 +185,16 @@ impl CodegenTy {
     }
 }
 
+/// `[u8; 16]` unless the context asks for typed uuids, see
+/// [`Builder::typed_uuid`](crate::Builder::typed_uuid).
+fn uuid_path() -> &'static str {
+    if CONTEXT.is_set() && with_cx(|cx| cx.config.typed_uuid) {
+        "::pilota::uuid::Uuid"
+    } else {
+        "[u8; 16]"
+    }
+}
+
 impl Display for CodegenTy {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -203,7 +213,7 @@ impl Display for CodegenTy {
             CodegenTy::UInt32 => f.write_str("u32"),
             CodegenTy::UInt64 => f.write_str("u64"),
             CodegenTy::F32 => f.write_str("f32"),
-            CodegenTy::Uuid => f.write_str("[u8; 16]"),
+            CodegenTy::Uuid => f.write_str(uuid_path()),
             CodegenTy::StaticRef(ty) => {
                 let ty = &**ty;
                 write!(f, "&'static {ty}")
@@ -28,10 +28,10 @@ use crate::{
     },
     symbol::{EnumRepr, FileId, Ident},
     tags::{
-        PilotaName, RustType, RustWrapperArc, SerdeAttribute, Tags,
+        PilotaName, RustType, RustWrapperArc, SerdeAttribute, StreamingMode, Tags,
         protobuf::{
-            ClientStreaming, Deprecated, OneOf, OptionalRepeated, ProstType, Repeated,
-            ServerStreaming,
+            ClientStreaming, Deprecated, OneOf, OptionalRepeated, ProstType, Proto2Required,
+            Repeated, ServerStreaming,
         },
     },
 };
@@ -41,6 +41,15 @@ pub struct ProtobufParser {
     inner: protobuf_parse::Parser,
     include_dirs: Vec<PathBuf>,
     input_files: FxHashSet<PathBuf>,
+    proto2_required: bool,
+}
+
+impl ProtobufParser {
+    /// Generates the proto2 `required` fields as `Option`s checked when
+    /// encoding and decoding, see [`Builder::proto2_required`](crate::Builder::proto2_required).
+    pub fn proto2_required(&mut self, proto2_required: bool) {
+        self.proto2_required = proto2_required;
+    }
 }
 
 #[derive(PartialEq, Eq)]
@@ -54,6 +63,7 @@ struct Lower {
     files: FxHashMap<String, FileId>,
     cur_package: Option<String>,
     cur_syntax: Syntax,
+    proto2_required: bool,
 }
 
 impl Default for Lower {
@@ -63,6 +73,7 @@ impl Default for Lower {
             files: Default::default(),
             cur_package: None,
             cur_syntax: Syntax::Proto3,
+            proto2_required: false,
         }
     }
 }
@@ -565,10 +576,17 @@ impl Lower {
                             }
                         })();
 
+                        let proto2_required = self.proto2_required
+                            && self.cur_syntax == Syntax::Proto2
+                            && f.label() == Label::LABEL_REQUIRED;
+
                         let mut tags = self.extract_field_tags(f);
                         if repeated {
                             tags.insert(Repeated);
                         }
+                        if proto2_required {
+                            tags.insert(Proto2Required);
+                        }
 
                         (
                             *idx,
@@ -607,7 +625,7 @@ impl Lower {
                                 name: FastStr::new(f.name()).into(),
                                 ty,
                                 tags: Arc::new(tags),
-                                kind: if optional {
+                                kind: if optional || proto2_required {
                                     FieldKind::Optional
                                 } else {
                                     FieldKind::Required
@@ -732,6 +750,10 @@ impl Lower {
                                 attribute: FieldKind::Required,
                             }],
                             oneway: false,
+                            streaming: StreamingMode::new(
+                                m.client_streaming(),
+                                m.server_streaming(),
+                            ),
                             ret: self.lower_ty(
                                 None,
                                 m.output_type.as_deref(),
@@ -1016,7 +1038,10 @@ impl Parser for ProtobufParser {
 
         let mut input_file_ids = vec![];
 
-        let mut lower = Lower::default();
+        let mut lower = Lower {
+            proto2_required: self.proto2_required,
+            ..Default::default()
+        };
 
         let files = lower.lower(&descriptors);
 
@@ -1231,9 +1256,11 @@ mod tests {
 
     #[test]
     fn lower_message_converts_map_entry_to_ir_map() {
-        let mut lower = Lower::default();
-        lower.cur_package = Some("pkg".into());
-        lower.cur_syntax = Syntax::Proto3;
+        let mut lower = Lower {
+            cur_package: Some("pkg".into()),
+            cur_syntax: Syntax::Proto3,
+            ..Default::default()
+        };
 
         let mut map_entry = DescriptorProto::new();
         map_entry.set_name("EntriesEntry".into());
@@ -1292,9 +1319,11 @@ mod tests {
 
     #[test]
     fn lower_message_marks_proto3_optional_scalar_as_optional() {
-        let mut lower = Lower::default();
-        lower.cur_package = Some("pkg".into());
-        lower.cur_syntax = Syntax::Proto3;
+        let mut lower = Lower {
+            cur_package: Some("pkg".into()),
+            cur_syntax: Syntax::Proto3,
+            ..Default::default()
+        };
 
         let mut message = DescriptorProto::new();
         message.set_name("Foo".into());
@@ -18,7 +18,11 @@ use crate::{
         ext::{self, FileExts},
     },
     symbol::{EnumRepr, FileId, Ident},
-    tags::{Annotation, PilotaName, RustWrapperArc, Tags},
+    tags::{
+        Annotation, PilotaName, RustWrapperArc, StreamingMode, Tags,
+        protobuf::{ClientStreaming, ServerStreaming},
+        thrift::MethodOptions,
+    },
     util::error_abort,
 };
 
@@ -345,7 +349,23 @@ impl ThriftLower {
         function_name_duplicates: &FxHashSet<&str>,
         arc_wrapper: bool,
     ) -> ir::Method {
-        let tags = self.extract_tags(&method.annotations);
+        let mut tags = self.extract_tags(&method.annotations);
+        let streaming = tags.get::<StreamingMode>().copied().unwrap_or_default();
+        if streaming.is_client_streaming() {
+            tags.insert(ClientStreaming);
+        }
+        if streaming.is_server_streaming() {
+            tags.insert(ServerStreaming);
+        }
+        let options = method
+            .annotations
+            .iter()
+            .filter(|a| !a.key.starts_with("pilota.") && a.key != StreamingMode::KEY)
+            .map(|a| (FastStr::new(&a.key), FastStr::new(&*a.value)))
+            .collect::<Vec<_>>();
+        if !options.is_empty() {
+            tags.insert(MethodOptions(options));
+        }
         let name = tags
             .get::<PilotaName>()
             .map(|name| name.0.clone())
@@ -385,6 +405,7 @@ impl ThriftLower {
                 .collect(),
             ret: self.lower_method_relative_ty(&method.result_type, arc_wrapper),
             oneway: method.oneway,
+            streaming,
             tags: tags.into(),
             exceptions: if method.throws.is_empty() {
                 None
@@ -589,7 +610,10 @@ impl ThriftLower {
         self.lower_field_with_tags(f, tags)
     }
 
-    fn lower_field_with_tags(&self, f: &thrift_parser::Field, tags: Tags) -> ir::Field {
+    fn lower_field_with_tags(&self, f: &thrift_parser::Field, mut tags: Tags) -> ir::Field {
+        if f.attribute == pilota_thrift_parser::Attribute::Default {
+            tags.insert(crate::tags::thrift::DefaultRequiredness);
+        }
         ir::Field {
             leading_comments: f.leading_comments.clone(),
             trailing_comments: f.trailing_comments.clone(),
@@ -620,7 +644,7 @@ impl ThriftLower {
         }
 
         annotations.iter().for_each(
-            |annotation| with_tags!(annotation -> crate::tags::PilotaName | crate::tags::RustType | crate::tags::RustWrapperArc | crate::tags::SerdeAttribute),
+            |annotation| with_tags!(annotation -> crate::tags::PilotaName | crate::tags::RustType | crate::tags::RustWrapperArc | crate::tags::SerdeAttribute | crate::tags::SqlxType | crate::tags::EnumAlias | crate::tags::SerdeI64AsString | crate::tags::AllowEmptyUnion | crate::tags::StreamingMode | crate::tags::Compress | crate::tags::Checksum | crate::tags::Overlay),
         );
 
         tags
@@ -0,0 +1,122 @@
+use itertools::Itertools;
+
+use crate::rir::Item;
+
+/// Generates `::pilota::canonical::CanonicalEq` impls comparing the values
+/// semantically, for the comparison of the responses of two services, such
+/// as with shadow traffic, where the derived `PartialEq` reports differences
+/// the wire format doesn't carry:
+///
+/// - NaN equals NaN, in fields, containers and unions alike.
+/// - Maps compare their entries whatever their order.
+/// - An absent optional field equals a present one set to the default from
+///   the IDL, if it has one.
+/// - Kept unknown fields compare their bytes.
+#[derive(Clone, Copy)]
+pub struct CanonicalEqPlugin;
+
+impl crate::Plugin for CanonicalEqPlugin {
+    fn on_item(
+        &mut self,
+        cx: &crate::Context,
+        def_id: crate::DefId,
+        item: std::sync::Arc<crate::rir::Item>,
+    ) {
+        let eq =
+            |a: &str, b: &str| format!("::pilota::canonical::CanonicalEq::canonical_eq({a}, {b})");
+
+        let body = match &*item {
+            Item::Message(m) => {
+                let mut fields = m
+                    .fields
+                    .iter()
+                    .map(|f| {
+                        let name = cx.rust_name(f.did);
+                        let (a, b) = (format!("&self.{name}"), format!("&other.{name}"));
+                        match cx.default_val(f).filter(|_| f.is_optional()) {
+                            Some((default, _)) => {
+                                let boxed =
+                                    cx.with_adjust(f.did, |adj| adj.is_some_and(|adj| adj.boxed()));
+                                let default = if boxed {
+                                    format!("::std::boxed::Box::new({default})")
+                                } else {
+                                    default.to_string()
+                                };
+                                format!("::pilota::canonical::optional_eq({a}, {b}, || {default})")
+                            }
+                            None => eq(&a, &b),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                if cx.cache.keep_unknown_fields.contains(&def_id) {
+                    fields.push(eq("&self._unknown_fields", "&other._unknown_fields"));
+                }
+                if fields.is_empty() {
+                    "let _ = other;\ntrue".to_string()
+                } else {
+                    fields.join("\n&& ")
+                }
+            }
+            Item::Enum(e) if e.repr.is_some() => "self == other".to_string(),
+            Item::Enum(e) => {
+                let mut arms = e
+                    .variants
+                    .iter()
+                    .map(|v| {
+                        let name = cx.rust_name(v.did);
+                        if v.fields.is_empty() {
+                            return format!("(Self::{name}, Self::{name}) => true,");
+                        }
+                        let bindings = |prefix: &str| {
+                            (0..v.fields.len())
+                                .map(|i| format!("{prefix}{i}"))
+                                .join(", ")
+                        };
+                        let eqs = (0..v.fields.len())
+                            .map(|i| eq(&format!("a{i}"), &format!("b{i}")))
+                            .join(" && ");
+                        format!(
+                            "(Self::{name}({}), Self::{name}({})) => {eqs},",
+                            bindings("a"),
+                            bindings("b")
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                // see `write_enum`, the unknown fields of a union are only
+                // kept when all its variants have fields
+                if cx.cache.keep_unknown_fields.contains(&def_id)
+                    && e.variants.iter().all(|v| !v.fields.is_empty())
+                {
+                    arms.push(format!(
+                        "(Self::_UnknownFields(a), Self::_UnknownFields(b)) => {},",
+                        eq("a", "b")
+                    ));
+                }
+                if arms.len() != 1 {
+                    arms.push("_ => false,".into());
+                }
+                format!("match (self, other) {{\n{}\n}}", arms.join("\n"))
+            }
+            Item::NewType(_) => eq("&self.0", "&other.0"),
+            _ => return crate::plugin::walk_item(self, cx, def_id, item),
+        };
+
+        let name = cx.rust_name(def_id);
+        cx.with_adjust_mut(def_id, |adj| {
+            adj.add_nested_item(
+                format!(
+                    r#"
+                    impl ::pilota::canonical::CanonicalEq for {name} {{
+                        fn canonical_eq(&self, other: &Self) -> bool {{
+                            {body}
+                        }}
+                    }}
+                    "#
+                )
+                .into(),
+            )
+        });
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
@@ -0,0 +1,34 @@
+use std::{any::Any, fmt, sync::Arc};
+
+use faststr::FastStr;
+use rustc_hash::FxHashMap;
+
+/// User supplied values handed to every plugin through
+/// [`Context::plugin_context`](crate::Context::plugin_context).
+///
+/// Values are keyed by name and read back by their concrete type, a lookup
+/// with the wrong type behaves as if the key was missing.
+#[derive(Clone, Default)]
+pub struct PluginContext {
+    values: FxHashMap<FastStr, Arc<dyn Any + Send + Sync>>,
+}
+
+impl PluginContext {
+    pub fn insert<T: Any + Send + Sync>(&mut self, key: impl Into<FastStr>, value: T) {
+        self.values.insert(key.into(), Arc::new(value));
+    }
+
+    pub fn get<T: Any>(&self, key: &str) -> Option<&T> {
+        self.values.get(key).and_then(|v| v.downcast_ref())
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+}
+
+impl fmt::Debug for PluginContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.values.keys()).finish()
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::HashSet, ops::DerefMut, sync::Arc};
+use std::{collections::HashSet, ops::DerefMut, path::Path, sync::Arc};
 
 use faststr::FastStr;
 use itertools::Itertools;
@@ -13,11 +13,21 @@ use crate::{
     ty::{self, Ty, Visitor},
 };
 
+mod canonical_eq;
+mod context;
+mod pb_codec;
 mod serde;
+mod sqlx;
 mod workspace;
 
-pub use self::serde::SerdePlugin;
+pub use self::{
+    canonical_eq::CanonicalEqPlugin, context::PluginContext, pb_codec::PbCodecPlugin,
+    serde::SerdePlugin, sqlx::SqlxPlugin,
+};
 
+/// Every hook gets the [`Context`], through which plugins can read the
+/// values given to [`Builder::plugin_context`](crate::Builder::plugin_context)
+/// and write companion files with [`Context::emit_file`].
 pub trait Plugin: Sync + Send {
     fn on_codegen_uint(&mut self, cx: &Context, items: &[DefId]) {
         walk_codegen_uint(self, cx, items)
@@ -36,6 +46,10 @@ pub trait Plugin: Sync + Send {
     }
 
     fn on_emit(&mut self, _cx: &Context) {}
+
+    /// Called with the syntax tree of every generated rust file right before
+    /// it is formatted, for rewrites that can not be expressed on the IR.
+    fn on_file(&mut self, _cx: &Context, _path: &Path, _file: &mut syn::File) {}
 }
 
 pub trait ClonePlugin: Plugin {
@@ -72,6 +86,10 @@ impl Plugin for BoxClonePlugin {
     fn on_emit(&mut self, cx: &Context) {
         self.0.on_emit(cx)
     }
+
+    fn on_file(&mut self, cx: &Context, path: &Path, file: &mut syn::File) {
+        self.0.on_file(cx, path, file)
+    }
 }
 
 impl<T> ClonePlugin for T
@@ -87,6 +105,10 @@ impl<T> Plugin for &mut T
 where
     T: Plugin,
 {
+    fn on_codegen_uint(&mut self, cx: &Context, items: &[DefId]) {
+        (*self).on_codegen_uint(cx, items)
+    }
+
     fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
         (*self).on_item(cx, def_id, item)
     }
@@ -102,6 +124,10 @@ where
     fn on_emit(&mut self, cx: &Context) {
         (*self).on_emit(cx)
     }
+
+    fn on_file(&mut self, cx: &Context, path: &Path, file: &mut syn::File) {
+        (*self).on_file(cx, path, file)
+    }
 }
 
 #[allow(clippy::single_match)]
@@ -316,6 +342,10 @@ where
     fn on_emit(&mut self, cx: &Context) {
         self.deref_mut().on_emit(cx)
     }
+
+    fn on_file(&mut self, cx: &Context, path: &Path, file: &mut syn::File) {
+        self.deref_mut().on_file(cx, path, file)
+    }
 }
 
 pub struct WithAttrsPlugin(pub Arc<[FastStr]>);
@@ -379,6 +409,13 @@ impl Plugin for ImplDefaultPlugin {
                         fields.push_str("_field_mask: ::std::option::Option::None");
                     }
 
+                    if cx.has_cached_size(def_id, m) {
+                        if !fields.is_empty() {
+                            fields.push_str(",\n");
+                        }
+                        fields.push_str("_cached_size: ::std::default::Default::default()");
+                    }
+
                     cx.with_adjust_mut(def_id, |adj| {
                         adj.add_nested_item(
                             format!(
@@ -0,0 +1,390 @@
+use std::path::PathBuf;
+
+use itertools::Itertools;
+use protobuf::descriptor::{
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto,
+    field_descriptor_proto::{Label, Type},
+};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    db::RirDatabase,
+    rir::{FieldKind, Item, Message},
+    ty::{Ty, TyKind},
+};
+
+/// Generates `::pilota::pb::Message` impls for the thrift structs also
+/// defined in the given `.proto` files, for migrating a service from thrift
+/// to protobuf with a single rust type per message, which encodes and
+/// decodes both protocols.
+///
+/// A thrift struct and a proto message are the same type when they have the
+/// same name, and their fields when they have the same name. Every field of
+/// the message must have a thrift counterpart of a compatible type:
+///
+/// - `bool`, `i32`, `i64`, `double`, `string` and `binary` for `bool`,
+///   `int32`, `sint32` or `sfixed32`, `int64`, `sint64` or `sfixed64`,
+///   `double`, `string` and `bytes`.
+/// - An enum for an enum of the same name whose values it all has.
+/// - A struct for a message shared the same way.
+/// - A `list` for a `repeated` field and a `map` for a `map` field.
+///
+/// The build panics otherwise. The thrift fields missing in the message are
+/// left out of the protobuf encoding, so they keep their default when
+/// decoding protobuf.
+#[derive(Clone)]
+pub struct PbCodecPlugin {
+    files: Vec<PathBuf>,
+    include_dirs: Vec<PathBuf>,
+    protos: Option<std::sync::Arc<Protos>>,
+}
+
+impl PbCodecPlugin {
+    /// The `.proto` files are resolved against the directories given to
+    /// [`PbCodecPlugin::include_dirs`], or against their own directories.
+    pub fn new(files: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        Self {
+            files: files.into_iter().map(Into::into).collect(),
+            include_dirs: Vec::new(),
+            protos: None,
+        }
+    }
+
+    pub fn include_dirs(mut self, dirs: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        self.include_dirs.extend(dirs.into_iter().map(Into::into));
+        self
+    }
+
+    fn protos(&mut self) -> std::sync::Arc<Protos> {
+        self.protos
+            .get_or_insert_with(|| {
+                let include_dirs = if self.include_dirs.is_empty() {
+                    self.files
+                        .iter()
+                        .filter_map(|f| f.parent().map(|p| p.to_path_buf()))
+                        .unique()
+                        .collect()
+                } else {
+                    self.include_dirs.clone()
+                };
+                let parsed = protobuf_parse::Parser::new()
+                    .pure()
+                    .includes(&include_dirs)
+                    .inputs(&self.files)
+                    .parse_and_typecheck()
+                    .unwrap_or_else(|e| panic!("failed to parse {:?}: {e:?}", self.files));
+                self.files
+                    .iter()
+                    .for_each(|f| println!("cargo:rerun-if-changed={}", f.display()));
+
+                let mut protos = Protos::default();
+                parsed.file_descriptors.iter().for_each(|f| {
+                    let prefix = match f.package() {
+                        "" => String::new(),
+                        package => format!(".{package}"),
+                    };
+                    protos.index(
+                        &prefix,
+                        &f.message_type,
+                        &f.enum_type,
+                        f.syntax() == "proto3",
+                    );
+                });
+                std::sync::Arc::new(protos)
+            })
+            .clone()
+    }
+}
+
+#[derive(Default)]
+struct Protos {
+    // by full name, such as `.package.Message`, with whether they are proto3
+    messages: FxHashMap<String, (DescriptorProto, bool)>,
+    enums: FxHashMap<String, EnumDescriptorProto>,
+    // the full names of the messages by name
+    names: FxHashMap<String, Vec<String>>,
+}
+
+#[derive(Clone, Copy)]
+enum Codec {
+    Scalar(&'static str),
+    Repeated(&'static str),
+    Map(&'static str, &'static str),
+}
+
+impl Protos {
+    fn index(
+        &mut self,
+        prefix: &str,
+        messages: &[DescriptorProto],
+        enums: &[EnumDescriptorProto],
+        proto3: bool,
+    ) {
+        enums.iter().for_each(|e| {
+            self.enums
+                .insert(format!("{prefix}.{}", e.name()), e.clone());
+        });
+        messages.iter().for_each(|m| {
+            let full_name = format!("{prefix}.{}", m.name());
+            self.index(&full_name, &m.nested_type, &m.enum_type, proto3);
+            self.names
+                .entry(m.name().to_string())
+                .or_default()
+                .push(full_name.clone());
+            self.messages.insert(full_name, (m.clone(), proto3));
+        });
+    }
+
+    fn message(&self, name: &str) -> Option<&(DescriptorProto, bool)> {
+        let full_names = self.names.get(name)?;
+        if full_names.len() > 1 {
+            panic!("the thrift struct `{name}` matches several messages: {full_names:?}")
+        }
+        self.messages.get(&full_names[0])
+    }
+
+    fn simple_name(type_name: &str) -> &str {
+        type_name.rsplit('.').next().unwrap_or(type_name)
+    }
+
+    fn module(
+        &self,
+        cx: &crate::Context,
+        ty: &Ty,
+        f: &FieldDescriptorProto,
+    ) -> Option<&'static str> {
+        Some(match (&ty.kind, f.type_()) {
+            (TyKind::Bool, Type::TYPE_BOOL) => "bool",
+            (TyKind::I32, Type::TYPE_INT32) => "int32",
+            (TyKind::I32, Type::TYPE_SINT32) => "sint32",
+            (TyKind::I32, Type::TYPE_SFIXED32) => "sfixed32",
+            (TyKind::I64, Type::TYPE_INT64) => "int64",
+            (TyKind::I64, Type::TYPE_SINT64) => "sint64",
+            (TyKind::I64, Type::TYPE_SFIXED64) => "sfixed64",
+            (TyKind::F64, Type::TYPE_DOUBLE) => "double",
+            (TyKind::FastStr, Type::TYPE_STRING) => "faststr",
+            (TyKind::String, Type::TYPE_STRING) => "string",
+            (TyKind::Bytes, Type::TYPE_BYTES) => "bytes",
+            (TyKind::Path(p), Type::TYPE_ENUM) => {
+                let Item::Enum(e) = &*cx.item(p.did)? else {
+                    return None;
+                };
+                let proto = self.enums.get(f.type_name())?;
+                let compatible = e.repr.is_some()
+                    && e.name.raw_str() == proto.name()
+                    && proto.value.iter().all(|value| {
+                        e.variants.iter().any(|v| {
+                            v.name.raw_str() == value.name()
+                                && v.discr == Some(value.number() as i64)
+                        })
+                    });
+                if !compatible {
+                    return None;
+                }
+                "int32"
+            }
+            (TyKind::Path(p), Type::TYPE_MESSAGE) => {
+                let Item::Message(s) = &*cx.item(p.did)? else {
+                    return None;
+                };
+                if s.name.raw_str() != Self::simple_name(f.type_name()) {
+                    return None;
+                }
+                "message"
+            }
+            _ => return None,
+        })
+    }
+
+    fn codec(&self, cx: &crate::Context, ty: &Ty, f: &FieldDescriptorProto) -> Option<Codec> {
+        let entry = self
+            .messages
+            .get(f.type_name())
+            .filter(|(m, _)| m.options.map_entry());
+        if let Some((entry, _)) = entry {
+            let TyKind::Map(key, value) = &ty.kind else {
+                return None;
+            };
+            let [key_field, value_field] = &entry.field[..] else {
+                return None;
+            };
+            return Some(Codec::Map(
+                self.module(cx, key, key_field)?,
+                self.module(cx, value, value_field)?,
+            ));
+        }
+        if f.label() == Label::LABEL_REPEATED {
+            let TyKind::Vec(inner) = &ty.kind else {
+                return None;
+            };
+            return Some(Codec::Repeated(self.module(cx, inner, f)?));
+        }
+        Some(Codec::Scalar(self.module(cx, ty, f)?))
+    }
+
+    fn codegen(
+        &self,
+        cx: &crate::Context,
+        name: &str,
+        s: &Message,
+        proto: &DescriptorProto,
+        proto3: bool,
+    ) -> String {
+        let idl_name = s.name.raw_str();
+        let mut encoded_len = String::new();
+        let mut encode = String::new();
+        let mut merge = String::new();
+
+        for f in &proto.field {
+            if f.oneof_index.is_some() && !f.proto3_optional() {
+                panic!(
+                    "`{}.{}` is in a oneof, which has no thrift counterpart",
+                    proto.name(),
+                    f.name()
+                )
+            }
+            let Some(field) = s
+                .fields
+                .iter()
+                .find(|field| field.name.raw_str() == f.name())
+            else {
+                panic!(
+                    "`{}.{}` has no counterpart in the thrift struct `{idl_name}`",
+                    proto.name(),
+                    f.name()
+                )
+            };
+            let Some(codec) = self.codec(cx, &field.ty, f) else {
+                panic!(
+                    "`{}.{}` is incompatible with `{idl_name}.{}`",
+                    proto.name(),
+                    f.name(),
+                    field.name.raw_str()
+                )
+            };
+            let field_name = cx.rust_name(field.did);
+            let tag = f.number();
+            let packed = f.options.as_ref().and_then(|o| o.packed).unwrap_or(proto3);
+
+            let (len, enc, merge_fn) = match codec {
+                Codec::Scalar(m) => (
+                    format!("::pilota::pb::encoding::{m}::encoded_len(ctx, {tag}, value)"),
+                    format!("::pilota::pb::encoding::{m}::encode({tag}, value, buf);"),
+                    format!("::pilota::pb::encoding::{m}::merge(wire_type, value, buf, ctx)"),
+                ),
+                Codec::Repeated(m) => {
+                    let packable = !matches!(m, "faststr" | "string" | "bytes" | "message");
+                    let (len_fn, enc_fn) = match m {
+                        "int32" if packed => {
+                            ("encoded_len_packed_convert", "encode_packed_convert")
+                        }
+                        _ if packed && packable => ("encoded_len_packed", "encode_packed"),
+                        _ => ("encoded_len_repeated", "encode_repeated"),
+                    };
+                    (
+                        format!("::pilota::pb::encoding::{m}::{len_fn}(ctx, {tag}, value)"),
+                        format!("::pilota::pb::encoding::{m}::{enc_fn}({tag}, value, buf);"),
+                        format!(
+                            "::pilota::pb::encoding::{m}::merge_repeated(wire_type, value, buf, ctx)"
+                        ),
+                    )
+                }
+                Codec::Map(k, v) => (
+                    format!(
+                        "::pilota::pb::encoding::hash_map::encoded_len(ctx, ::pilota::pb::encoding::{k}::encoded_len, ::pilota::pb::encoding::{v}::encoded_len, {tag}, value)"
+                    ),
+                    format!(
+                        "::pilota::pb::encoding::hash_map::encode(::pilota::pb::encoding::{k}::encode, ::pilota::pb::encoding::{k}::encoded_len, ::pilota::pb::encoding::{v}::encode, ::pilota::pb::encoding::{v}::encoded_len, {tag}, value, buf);"
+                    ),
+                    format!(
+                        "::pilota::pb::encoding::hash_map::merge(::pilota::pb::encoding::{k}::merge, ::pilota::pb::encoding::{v}::merge, value, buf, ctx)"
+                    ),
+                ),
+            };
+
+            let (len, enc, value) = match field.kind {
+                FieldKind::Required => (
+                    format!("{{ let value = &self.{field_name}; {len} }}"),
+                    format!("{{ let value = &self.{field_name}; {enc} }}"),
+                    format!("&mut self.{field_name}"),
+                ),
+                FieldKind::Optional => (
+                    format!("self.{field_name}.as_ref().map_or(0, |value| {len})"),
+                    format!("if let Some(value) = &self.{field_name} {{ {enc} }}"),
+                    format!(
+                        "self.{field_name}.get_or_insert_with(::core::default::Default::default)"
+                    ),
+                ),
+            };
+            encoded_len.push_str(&format!("+ {len}"));
+            encode.push_str(&enc);
+            merge.push_str(&format!(
+                r#"{tag} => {{
+                    let value = {value};
+                    {merge_fn}.map_err(|mut error| {{
+                        error.push(STRUCT_NAME, stringify!({field_name}));
+                        error
+                    }})
+                }},"#
+            ));
+        }
+
+        let struct_name = if proto.field.is_empty() {
+            String::new()
+        } else {
+            format!("const STRUCT_NAME: &'static str = stringify!({name});")
+        };
+
+        format!(
+            r#"
+            impl ::pilota::pb::Message for {name} {{
+                #[inline]
+                fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {{
+                    0 {encoded_len}
+                }}
+
+                #[allow(unused_variables)]
+                fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {{
+                    {encode}
+                }}
+
+                #[allow(unused_variables)]
+                fn merge_field(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::pb::encoding::WireType,
+                    buf: &mut ::pilota::Bytes,
+                    ctx: &mut ::pilota::pb::encoding::DecodeContext,
+                    is_root: bool,
+                ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {{
+                    {struct_name}
+                    match tag {{
+                        {merge}
+                        _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }}
+                }}
+            }}
+            "#
+        )
+    }
+}
+
+impl crate::Plugin for PbCodecPlugin {
+    fn on_item(
+        &mut self,
+        cx: &crate::Context,
+        def_id: crate::DefId,
+        item: std::sync::Arc<crate::rir::Item>,
+    ) {
+        if let Item::Message(s) = &*item {
+            let protos = self.protos();
+            if let Some((proto, proto3)) = protos.message(&s.name.raw_str()) {
+                let name = cx.rust_name(def_id);
+                let code = protos.codegen(cx, &name, s, proto, *proto3);
+                cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(code.into()));
+            }
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
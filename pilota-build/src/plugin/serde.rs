@@ -1,4 +1,7 @@
-use crate::tags::SerdeAttribute;
+use crate::{
+    middle::ty::TyKind,
+    tags::{SerdeAttribute, SerdeI64AsString},
+};
 
 #[derive(Clone, Copy)]
 pub struct SerdePlugin;
@@ -34,8 +37,13 @@ impl crate::Plugin for SerdePlugin {
 
         if let crate::rir::Item::Enum(e) = &*item {
             if e.repr.is_some() {
+                let attr = if cx.config.with_unknown_enum_variant {
+                    "#[serde(from = \"i32\", into = \"i32\")]"
+                } else {
+                    "#[serde(transparent)]"
+                };
                 cx.with_adjust_mut(def_id, |adj| {
-                    adj.add_attrs(&["#[serde(transparent)]".into()]);
+                    adj.add_attrs(&[attr.into()]);
                 })
             }
         }
@@ -49,13 +57,33 @@ impl crate::Plugin for SerdePlugin {
         def_id: crate::DefId,
         f: std::sync::Arc<crate::rir::Field>,
     ) {
-        if let Some(attribute) = cx
-            .tags(f.tags_id)
+        let tags = cx.tags(f.tags_id);
+        if let Some(attribute) = tags
+            .as_ref()
             .and_then(|tags| tags.get::<SerdeAttribute>().cloned())
         {
             let attr = attribute.0.replace('\\', "");
             cx.with_adjust_mut(def_id, |adj| adj.add_attrs(&[attr.into()]))
         }
+
+        let i64_as_string = tags
+            .as_ref()
+            .and_then(|tags| tags.get::<SerdeI64AsString>())
+            .map_or(cx.config.serde_i64_as_string, |t| t.0);
+        if !i64_as_string {
+            return;
+        }
+        let is_i64 = |kind: &TyKind| matches!(kind, TyKind::I64 | TyKind::UInt64);
+        let path = match (&f.ty.kind, f.is_optional()) {
+            (kind, false) if is_i64(kind) => "::pilota::serde_str",
+            (kind, true) if is_i64(kind) => "::pilota::serde_str::option",
+            (TyKind::Vec(ty), false) if is_i64(&ty.kind) => "::pilota::serde_str::vec",
+            (TyKind::Vec(ty), true) if is_i64(&ty.kind) => "::pilota::serde_str::option_vec",
+            _ => return,
+        };
+        let default = if f.is_optional() { "default, " } else { "" };
+        let attr = format!("#[serde({default}with = \"{path}\")]");
+        cx.with_adjust_mut(def_id, |adj| adj.add_attrs(&[attr.into()]))
     }
 
     fn on_variant(
@@ -0,0 +1,189 @@
+use itertools::Itertools;
+
+use crate::tags::SqlxType;
+
+/// Generates `sqlx::Type`, `sqlx::Encode` and `sqlx::Decode` impls for
+/// types annotated with `pilota.sqlx`:
+///
+/// - `pilota.sqlx = "int"` on an enum stores it as its `i32` value.
+/// - `pilota.sqlx = "text"` on an enum stores it as its variant name.
+/// - `pilota.sqlx = "json"` on a struct or union stores it as JSON, which
+///   requires the serde impls from [`SerdePlugin`](crate::plugin::SerdePlugin).
+///
+/// The impls are generic over the database, so they are available for every
+/// driver whose `i32`, `String` or `Json` support they rely on. The generated
+/// crate must depend on `sqlx`.
+#[derive(Clone, Copy)]
+pub struct SqlxPlugin;
+
+impl crate::Plugin for SqlxPlugin {
+    fn on_item(
+        &mut self,
+        cx: &crate::Context,
+        def_id: crate::DefId,
+        item: std::sync::Arc<crate::rir::Item>,
+    ) {
+        let Some(sqlx_type) = cx
+            .node_tags(def_id)
+            .and_then(|tags| tags.get::<SqlxType>().copied())
+        else {
+            return crate::plugin::walk_item(self, cx, def_id, item);
+        };
+
+        let name = cx.rust_name(def_id);
+        let code = match (&*item, sqlx_type) {
+            (crate::rir::Item::Enum(e), SqlxType::Int) if e.repr.is_some() => {
+                let decode = if cx.config.with_unknown_enum_variant {
+                    "::std::result::Result::Ok(Self::from(value))".to_string()
+                } else {
+                    format!(
+                        r#"Self::try_from_i32(value).ok_or_else(|| ::std::format!("invalid value `{{value}}` for enum `{name}`").into())"#
+                    )
+                };
+                format!(
+                    r#"
+                    impl<DB: ::sqlx::Database> ::sqlx::Type<DB> for {name}
+                    where
+                        i32: ::sqlx::Type<DB>,
+                    {{
+                        fn type_info() -> DB::TypeInfo {{
+                            <i32 as ::sqlx::Type<DB>>::type_info()
+                        }}
+
+                        fn compatible(ty: &DB::TypeInfo) -> bool {{
+                            <i32 as ::sqlx::Type<DB>>::compatible(ty)
+                        }}
+                    }}
+
+                    impl<'q, DB: ::sqlx::Database> ::sqlx::Encode<'q, DB> for {name}
+                    where
+                        i32: ::sqlx::Encode<'q, DB>,
+                    {{
+                        fn encode_by_ref(
+                            &self,
+                            buf: &mut <DB as ::sqlx::Database>::ArgumentBuffer<'q>,
+                        ) -> ::std::result::Result<::sqlx::encode::IsNull, ::sqlx::error::BoxDynError> {{
+                            <i32 as ::sqlx::Encode<'q, DB>>::encode_by_ref(&self.inner(), buf)
+                        }}
+                    }}
+
+                    impl<'r, DB: ::sqlx::Database> ::sqlx::Decode<'r, DB> for {name}
+                    where
+                        i32: ::sqlx::Decode<'r, DB>,
+                    {{
+                        fn decode(
+                            value: <DB as ::sqlx::Database>::ValueRef<'r>,
+                        ) -> ::std::result::Result<Self, ::sqlx::error::BoxDynError> {{
+                            let value = <i32 as ::sqlx::Decode<'r, DB>>::decode(value)?;
+                            {decode}
+                        }}
+                    }}
+                    "#
+                )
+            }
+            (crate::rir::Item::Enum(e), SqlxType::Text) if e.repr.is_some() => {
+                let variants = e
+                    .variants
+                    .iter()
+                    .map(|v| {
+                        let variant_name = cx.rust_name(v.did);
+                        format!(
+                            r#""{}" => ::std::result::Result::Ok(Self::{variant_name}),"#,
+                            v.name
+                        )
+                    })
+                    .join("\n");
+                format!(
+                    r#"
+                    impl<DB: ::sqlx::Database> ::sqlx::Type<DB> for {name}
+                    where
+                        ::std::string::String: ::sqlx::Type<DB>,
+                    {{
+                        fn type_info() -> DB::TypeInfo {{
+                            <::std::string::String as ::sqlx::Type<DB>>::type_info()
+                        }}
+
+                        fn compatible(ty: &DB::TypeInfo) -> bool {{
+                            <::std::string::String as ::sqlx::Type<DB>>::compatible(ty)
+                        }}
+                    }}
+
+                    impl<'q, DB: ::sqlx::Database> ::sqlx::Encode<'q, DB> for {name}
+                    where
+                        ::std::string::String: ::sqlx::Encode<'q, DB>,
+                    {{
+                        fn encode_by_ref(
+                            &self,
+                            buf: &mut <DB as ::sqlx::Database>::ArgumentBuffer<'q>,
+                        ) -> ::std::result::Result<::sqlx::encode::IsNull, ::sqlx::error::BoxDynError> {{
+                            <::std::string::String as ::sqlx::Encode<'q, DB>>::encode(self.to_string(), buf)
+                        }}
+                    }}
+
+                    impl<'r, DB: ::sqlx::Database> ::sqlx::Decode<'r, DB> for {name}
+                    where
+                        ::std::string::String: ::sqlx::Decode<'r, DB>,
+                    {{
+                        fn decode(
+                            value: <DB as ::sqlx::Database>::ValueRef<'r>,
+                        ) -> ::std::result::Result<Self, ::sqlx::error::BoxDynError> {{
+                            let value = <::std::string::String as ::sqlx::Decode<'r, DB>>::decode(value)?;
+                            match value.as_str() {{
+                                {variants}
+                                _ => ::std::result::Result::Err(::std::format!("invalid value `{{value}}` for enum `{name}`").into()),
+                            }}
+                        }}
+                    }}
+                    "#
+                )
+            }
+            (crate::rir::Item::Message(_), SqlxType::Json)
+            | (crate::rir::Item::Enum(_), SqlxType::Json) => {
+                format!(
+                    r#"
+                    impl<DB: ::sqlx::Database> ::sqlx::Type<DB> for {name}
+                    where
+                        ::sqlx::types::Json<Self>: ::sqlx::Type<DB>,
+                    {{
+                        fn type_info() -> DB::TypeInfo {{
+                            <::sqlx::types::Json<Self> as ::sqlx::Type<DB>>::type_info()
+                        }}
+
+                        fn compatible(ty: &DB::TypeInfo) -> bool {{
+                            <::sqlx::types::Json<Self> as ::sqlx::Type<DB>>::compatible(ty)
+                        }}
+                    }}
+
+                    impl<'q, DB: ::sqlx::Database> ::sqlx::Encode<'q, DB> for {name}
+                    where
+                        for<'a> ::sqlx::types::Json<&'a Self>: ::sqlx::Encode<'q, DB>,
+                    {{
+                        fn encode_by_ref(
+                            &self,
+                            buf: &mut <DB as ::sqlx::Database>::ArgumentBuffer<'q>,
+                        ) -> ::std::result::Result<::sqlx::encode::IsNull, ::sqlx::error::BoxDynError> {{
+                            <::sqlx::types::Json<&Self> as ::sqlx::Encode<'q, DB>>::encode(::sqlx::types::Json(self), buf)
+                        }}
+                    }}
+
+                    impl<'r, DB: ::sqlx::Database> ::sqlx::Decode<'r, DB> for {name}
+                    where
+                        ::sqlx::types::Json<Self>: ::sqlx::Decode<'r, DB>,
+                    {{
+                        fn decode(
+                            value: <DB as ::sqlx::Database>::ValueRef<'r>,
+                        ) -> ::std::result::Result<Self, ::sqlx::error::BoxDynError> {{
+                            ::std::result::Result::Ok(<::sqlx::types::Json<Self> as ::sqlx::Decode<'r, DB>>::decode(value)?.0)
+                        }}
+                    }}
+                    "#
+                )
+            }
+            (_, sqlx_type) => panic!("`pilota.sqlx = {sqlx_type:?}` is not supported on `{name}`"),
+        };
+
+        cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(code.into()));
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
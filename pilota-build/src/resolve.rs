@@ -275,6 +275,8 @@ impl Resolver {
             .map(|f| (f.id, Arc::from(self.lower_file(f))))
             .collect::<FxHashMap<_, _>>();
 
+        self.check_service_extends();
+
         self.errors.abort_if_errors();
 
         ResolveResult {
@@ -287,6 +289,59 @@ impl Resolver {
         }
     }
 
+    fn service(&self, def_id: DefId) -> Option<&Service> {
+        match &self.nodes.get(&def_id)?.kind {
+            NodeKind::Item(item) => match &**item {
+                Item::Service(s) => Some(s),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Rejects services that extend themselves, or that redefine a method of
+    /// one of their base services.
+    fn check_service_extends(&mut self) {
+        let mut errors = Vec::new();
+        for def_id in self.nodes.keys().copied().sorted() {
+            let Some(s) = self.service(def_id) else {
+                continue;
+            };
+
+            let mut inherited = FxHashMap::default();
+            let mut visited = FxHashSet::default();
+            let mut bases = s.extend.iter().map(|p| p.did).collect_vec();
+            while let Some(base) = bases.pop() {
+                if base == def_id {
+                    errors.push(format!("service `{}` extends itself", s.name));
+                    break;
+                }
+                if !visited.insert(base) {
+                    continue;
+                }
+                let Some(b) = self.service(base) else {
+                    continue;
+                };
+                for m in &b.methods {
+                    inherited
+                        .entry(m.name.sym.clone())
+                        .or_insert(b.name.clone());
+                }
+                bases.extend(b.extend.iter().map(|p| p.did));
+            }
+
+            for m in &s.methods {
+                if let Some(base) = inherited.get(&m.name.sym) {
+                    errors.push(format!(
+                        "method `{}` of service `{}` is already defined in base service `{base}`",
+                        m.name, s.name
+                    ));
+                }
+            }
+        }
+        errors.into_iter().for_each(|e| self.errors.emit_error(e));
+    }
+
     fn modify_ty_by_tags(&mut self, mut ty: Ty, tags: &Tags) -> Ty {
         match ty.kind {
             ty::FastStr
@@ -767,6 +822,7 @@ impl Resolver {
                             .collect(),
                         ret: self.lower_type(&m.ret, true),
                         oneway: m.oneway,
+                        streaming: m.streaming,
                         exceptions: m
                             .exceptions
                             .as_ref()
@@ -109,7 +109,14 @@ macro_rules! new_type {
 }
 
 pub mod thrift {
+    use faststr::FastStr;
+
     pub struct EntryMessage;
+    /// A field declared neither `required` nor `optional`.
+    pub struct DefaultRequiredness;
+    /// The annotations of a method that are not read by pilota, in IDL
+    /// order.
+    pub struct MethodOptions(pub Vec<(FastStr, FastStr)>);
 }
 
 #[derive(Clone)]
@@ -182,6 +189,221 @@ impl Annotation for SerdeAttribute {
     const KEY: &'static str = "pilota.serde_attribute";
 }
 
+/// Extra names accepted when parsing an enum variant from a string, given as
+/// a comma separated list.
+#[derive(Clone, Debug)]
+pub struct EnumAlias(pub Vec<FastStr>);
+
+impl FromStr for EnumAlias {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(
+            s.split(',')
+                .map(|a| FastStr::new(a.trim()))
+                .filter(|a| !a.is_empty())
+                .collect(),
+        ))
+    }
+}
+
+impl Annotation for EnumAlias {
+    const KEY: &'static str = "pilota.alias";
+}
+
+/// How [`SqlxPlugin`](crate::plugin::SqlxPlugin) maps a type to SQL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SqlxType {
+    /// Enums stored as their integer value.
+    Int,
+    /// Enums stored as their variant name.
+    Text,
+    /// Structs stored as JSON through their serde implementation.
+    Json,
+}
+
+impl FromStr for SqlxType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "int" => Ok(Self::Int),
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "invalid pilota.sqlx value `{s}`, expected int, text or json"
+            )),
+        }
+    }
+}
+
+impl Annotation for SqlxType {
+    const KEY: &'static str = "pilota.sqlx";
+}
+
+/// Serializes an `i64` field, or a list of them, as strings in the serde
+/// impls generated by [`SerdePlugin`](crate::plugin::SerdePlugin).
+#[derive(Clone, Copy, Debug)]
+pub struct SerdeI64AsString(pub bool);
+
+impl FromStr for SerdeI64AsString {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s == "true"))
+    }
+}
+
+impl Annotation for SerdeI64AsString {
+    const KEY: &'static str = "pilota.serde_i64_as_string";
+}
+
+/// Lets a union be encoded with none of its fields set, which is refused
+/// otherwise.
+#[derive(Clone, Copy, Debug)]
+pub struct AllowEmptyUnion(pub bool);
+
+impl FromStr for AllowEmptyUnion {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s == "true"))
+    }
+}
+
+impl Annotation for AllowEmptyUnion {
+    const KEY: &'static str = "pilota.allow_empty_union";
+}
+
+/// Compresses a binary field on the wire with
+/// `::pilota::thrift::compress`, which needs the `zstd` feature of pilota.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compress {
+    Zstd,
+}
+
+impl FromStr for Compress {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "zstd" => Ok(Self::Zstd),
+            _ => Err(format!(
+                "invalid pilota.compress value `{s}`, expected zstd"
+            )),
+        }
+    }
+}
+
+impl Annotation for Compress {
+    const KEY: &'static str = "pilota.compress";
+}
+
+/// Appends a CRC32C of a binary field to its value on the wire and verifies
+/// it when decoding, with `::pilota::thrift::checksum`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Checksum {
+    Crc32c,
+}
+
+impl FromStr for Checksum {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "crc32c" => Ok(Self::Crc32c),
+            _ => Err(format!(
+                "invalid pilota.checksum value `{s}`, expected crc32c"
+            )),
+        }
+    }
+}
+
+impl Annotation for Checksum {
+    const KEY: &'static str = "pilota.checksum";
+}
+
+/// Generates a `#[repr(C)]` overlay of a struct of fixed-width fields, read
+/// and written in place with `::pilota::overlay::Overlay`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overlay {
+    LittleEndian,
+    BigEndian,
+}
+
+impl FromStr for Overlay {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "le" => Ok(Self::LittleEndian),
+            "be" => Ok(Self::BigEndian),
+            _ => Err(format!(
+                "invalid pilota.overlay value `{s}`, expected le or be"
+            )),
+        }
+    }
+}
+
+impl Annotation for Overlay {
+    const KEY: &'static str = "pilota.overlay";
+}
+
+/// How the request and response of a method are streamed.
+///
+/// Thrift methods declare it with the Kitex `streaming.mode` annotation,
+/// protobuf methods with the `stream` keyword.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StreamingMode {
+    #[default]
+    Unary,
+    /// The client sends a stream of requests and gets one response.
+    Client,
+    /// The client sends one request and gets a stream of responses.
+    Server,
+    /// Both requests and responses are streamed.
+    Bidirectional,
+}
+
+impl StreamingMode {
+    pub fn new(client_streaming: bool, server_streaming: bool) -> Self {
+        match (client_streaming, server_streaming) {
+            (false, false) => Self::Unary,
+            (true, false) => Self::Client,
+            (false, true) => Self::Server,
+            (true, true) => Self::Bidirectional,
+        }
+    }
+
+    pub fn is_client_streaming(self) -> bool {
+        matches!(self, Self::Client | Self::Bidirectional)
+    }
+
+    pub fn is_server_streaming(self) -> bool {
+        matches!(self, Self::Server | Self::Bidirectional)
+    }
+}
+
+impl FromStr for StreamingMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unary" => Ok(Self::Unary),
+            "client" => Ok(Self::Client),
+            "server" => Ok(Self::Server),
+            "bidirectional" => Ok(Self::Bidirectional),
+            _ => Err(format!(
+                "invalid streaming.mode value `{s}`, expected unary, client, server or bidirectional"
+            )),
+        }
+    }
+}
+
+impl Annotation for StreamingMode {
+    const KEY: &'static str = "streaming.mode";
+}
+
 #[derive(Debug)]
 pub struct KeepUnknownFields(pub bool);
 
@@ -215,5 +437,9 @@ pub mod protobuf {
         pub struct Deprecated(pub bool);
 
         pub struct OptionalRepeated(pub bool);
+
+        /// A proto2 `required` field, generated as an `Option` when
+        /// [`Builder::proto2_required`](crate::Builder::proto2_required) is set.
+        pub struct Proto2Required;
     }
 }
@@ -596,6 +596,307 @@ fn test_unknown_fields() {
     });
 }
 
+#[test]
+fn test_unknown_enum_variant() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("unknown_enum_variant.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .with_unknown_enum_variant(true)
+            .plugin(SerdePlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_sqlx_bridge() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("sqlx_bridge.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .plugin(SerdePlugin)
+            .plugin(crate::plugin::SqlxPlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_canonical_eq() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("canonical_eq.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .plugin(crate::plugin::CanonicalEqPlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_pb_codec() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
+    let file_path = test_data_dir.join("pb_codec.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .plugin(crate::plugin::PbCodecPlugin::new([
+                test_data_dir.join("pb_codec.proto")
+            ]))
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_serde_i64_as_string() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("serde_i64_as_string.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .serde_i64_as_string(true)
+            .plugin(SerdePlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_enum_key_policy() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("enum_key_policy.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .enum_key_policy(crate::EnumKeyPolicy::Error)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_lenient_numeric_decode() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("lenient_numeric.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .lenient_numeric_decode(true)
+            .duplicate_field_check(true)
+            .unknown_field_hook(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_typed_uuid() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("typed_uuid.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .typed_uuid(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_fixed_encoded_size() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("fixed_encoded_size.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .fixed_encoded_size(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_cached_size() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("cached_size.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .cached_size(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_field_names() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("field_names.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .with_field_names(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_union_policy() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("union_policy.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path.clone(), out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .union_policy(crate::UnionPolicy::KeepLast)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+
+    let mut out_path = file_path.clone();
+    out_path.set_file_name("union_policy_keep_first.rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .keep_unknown_fields([source.into()])
+            .union_policy(crate::UnionPolicy::KeepFirst)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_proto2_required() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("proto2_required.proto");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::pb()
+            .ignore_unused(false)
+            .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+            .proto2_required(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_default_field_encoding() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("default_field_encoding.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .default_field_encoding(crate::DefaultFieldEncoding::AlwaysWrite)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
 #[test]
 #[should_panic(expected = "duplicate ID `1` in struct `User`")]
 fn test_duplicate_field_id() {
@@ -607,13 +908,647 @@ fn test_duplicate_field_id() {
     out_path.set_extension("rs");
 
     crate::Builder::thrift()
-        .touch([(file_path.clone().into(), vec!["User"])])
+        .touch([(file_path.clone(), vec!["User"])])
         .compile_with_config(
             vec![IdlService::from_path(file_path)],
             crate::Output::File(out_path),
         );
 }
 
+#[test]
+#[should_panic]
+fn test_service_extends_cycle() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("service_extends_cycle.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(out_path),
+        );
+}
+
+#[test]
+fn test_dry_run_diff() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift")
+        .join("void.thrift");
+
+    let dir = tempdir().unwrap();
+    let out_path = dir.path().join("void.rs");
+    let compile = |diff: bool| {
+        let builder = crate::Builder::thrift().ignore_unused(false);
+        let services = vec![IdlService::from_path(file_path.clone())];
+        let out = crate::Output::File(out_path.clone());
+        if diff {
+            builder.diff_with_config(services, out)
+        } else {
+            builder.compile_with_config(services, out);
+            String::new()
+        }
+    };
+
+    let diff = compile(true);
+    assert!(diff.starts_with("--- /dev/null\n+++ b/void.rs\n"), "{diff}");
+    assert!(!out_path.exists());
+
+    compile(false);
+    assert_eq!(compile(true), "");
+
+    let generated = fs::read_to_string(&out_path).unwrap();
+    fs::write(
+        &out_path,
+        generated.replace("pub mod void", "pub mod stale"),
+    )
+    .unwrap();
+    let diff = compile(true);
+    assert!(diff.starts_with("--- a/void.rs\n+++ b/void.rs\n"), "{diff}");
+    assert!(diff.contains("-pub mod stale {\n+pub mod void {"), "{diff}");
+}
+
+#[test]
+fn test_compile_to_memory() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift")
+        .join("void.thrift");
+
+    let dir = tempdir().unwrap();
+    let out_path = dir.path().join("void.rs");
+    let files = crate::Builder::thrift()
+        .ignore_unused(false)
+        .compile_to_memory(
+            vec![IdlService::from_path(file_path.clone())],
+            crate::Output::File(out_path.clone()),
+        )
+        .unwrap();
+    assert!(!out_path.exists());
+    assert_eq!(files.len(), 1);
+
+    let on_disk = tempdir().unwrap();
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(on_disk.path().join("void.rs")),
+        );
+    let expected = fs::read_to_string(on_disk.path().join("void.rs")).unwrap();
+    assert_eq!(files.get("void.rs"), Some(expected.as_str()));
+    assert!(files.tokens("void.rs").unwrap().is_ok());
+    assert!(files.tokens("missing.rs").is_none());
+}
+
+#[test]
+fn test_reproducible_output() {
+    let dir = tempdir().unwrap();
+    let paths = ["a", "b", "c", "d"]
+        .iter()
+        .map(|name| {
+            let path = dir.path().join(format!("{name}.thrift"));
+            fs::write(
+                &path,
+                format!(
+                    "// file {name}\nnamespace rs shared\n\nstruct {} {{\n    1: required i64 id,\n}}\n",
+                    name.to_uppercase()
+                ),
+            )
+            .unwrap();
+            path
+        })
+        .collect::<Vec<_>>();
+
+    let compile = |i: usize| {
+        let out_dir = dir.path().join(i.to_string());
+        fs::create_dir(&out_dir).unwrap();
+        let out_path = out_dir.join("shared.rs");
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .with_comments(true)
+            .compile_with_config(
+                paths.iter().cloned().map(IdlService::from_path).collect(),
+                crate::Output::File(out_path.clone()),
+            );
+        fs::read_to_string(out_path).unwrap()
+    };
+
+    let first = compile(0);
+    for i in 1..8 {
+        assert_eq!(compile(i), first);
+    }
+}
+
+#[test]
+fn test_rustfmt_ignores_parent_config() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("rustfmt.toml"), "hard_tabs = true\n").unwrap();
+    let file = dir.path().join("a.rs");
+    fs::write(&file, "fn f() {\nlet x = 1;\n}\n").unwrap();
+
+    crate::fmt::fmt_file(&file);
+    assert_eq!(
+        fs::read_to_string(&file).unwrap(),
+        "fn f() {\n    let x = 1;\n}\n"
+    );
+}
+
+#[test]
+fn test_workspace_config() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("idl")).unwrap();
+    fs::create_dir_all(dir.path().join("crates/echo")).unwrap();
+    fs::write(
+        dir.path().join("idl/echo.thrift"),
+        "struct Request {\n    1: required string message,\n}\n\nstruct Unused {\n    1: optional i64 id,\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("pilota.toml"),
+        r#"
+[[target]]
+name = "echo"
+kind = "thrift"
+idls = ["idl/echo.thrift"]
+out = "echo.rs"
+plugins = ["serde"]
+
+[target.options]
+with_comments = true
+
+[[target.file]]
+path = "idl/echo.thrift"
+keep_unknown_fields = true
+touch = ["Unused"]
+"#,
+    )
+    .unwrap();
+
+    let config = crate::config::Config::find(dir.path().join("crates/echo")).unwrap();
+    let target = config.target("echo").unwrap();
+    assert!(config.target("other").is_none());
+    let out_dir = dir.path().join("out");
+    fs::create_dir(&out_dir).unwrap();
+    target.compile(&out_dir);
+
+    let idl = dir.path().join("idl/echo.thrift");
+    fs::create_dir(dir.path().join("expected")).unwrap();
+    let expected = dir.path().join("expected/echo.rs");
+    crate::Builder::thrift()
+        .plugin(SerdePlugin)
+        .with_comments(true)
+        .keep_unknown_fields([idl.clone()])
+        .touch([(idl.clone(), vec!["Unused"])])
+        .compile_with_config(
+            vec![IdlService::from_path(idl)],
+            crate::Output::File(expected.clone()),
+        );
+    diff_file(expected, out_dir.join("echo.rs"));
+
+    let err = crate::config::Config::parse(
+        "[[target]]\nname = \"x\"\nkind = \"pb\"\nidls = []\n",
+        dir.path(),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("exactly one of"), "{err}");
+    assert!(crate::config::Config::parse("[[target]]\nname = \"x\"\n", ".").is_err());
+}
+
+#[test]
+fn test_plugin_context_and_extra_files() {
+    #[derive(Default)]
+    struct RouteTable(Vec<String>);
+
+    impl crate::Plugin for RouteTable {
+        fn on_item(
+            &mut self,
+            cx: &crate::Context,
+            def_id: crate::DefId,
+            item: std::sync::Arc<crate::rir::Item>,
+        ) {
+            if let crate::rir::Item::Service(s) = &*item {
+                let prefix = cx.plugin_context::<String>("prefix").unwrap();
+                for m in &s.methods {
+                    self.0.push(format!("{prefix}/{}/{}", s.name, m.name));
+                }
+            }
+            crate::plugin::walk_item(self, cx, def_id, item)
+        }
+
+        fn on_emit(&mut self, cx: &crate::Context) {
+            assert!(cx.plugin_context::<u32>("prefix").is_none());
+            cx.emit_file("routes/table.txt", self.0.join("\n"));
+        }
+    }
+
+    let dir = tempdir().unwrap();
+    let idl = dir.path().join("route.thrift");
+    fs::write(
+        &idl,
+        "struct Req {}\n\nservice Echo {\n    Req ping(1: Req req),\n    Req pong(1: Req req),\n}\n",
+    )
+    .unwrap();
+
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .plugin(RouteTable::default())
+        .plugin_context("prefix", String::from("/api"))
+        .compile_with_config(
+            vec![IdlService::from_path(idl)],
+            crate::Output::File(dir.path().join("route.rs")),
+        );
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("routes/table.txt")).unwrap(),
+        "/api/Echo/ping\n/api/Echo/pong"
+    );
+}
+
+#[test]
+fn test_plugin_queries() {
+    struct Queries;
+
+    impl crate::Plugin for Queries {
+        fn on_emit(&mut self, cx: &crate::Context) {
+            let item = cx.resolve("shop.Item").unwrap();
+            let order = cx.resolve("shop.Order").unwrap();
+            let shop = cx.resolve("shop.Shop").unwrap();
+            assert_eq!(cx.resolve("Item"), None);
+            assert_eq!(cx.resolve("shop.Missing"), None);
+
+            assert_eq!(cx.who_references(item), [order]);
+            // and the result structs generated for `get`
+            assert!(cx.who_references(order).contains(&shop));
+            assert!(cx.who_references(shop).is_empty());
+
+            let audited = cx.methods_with_annotation("audit");
+            assert_eq!(audited.len(), 1);
+            let (service, method, value) = &audited[0];
+            assert_eq!(
+                (*service, &*method.name.sym.0, &**value),
+                (shop, "get", "true")
+            );
+            cx.emit_file("queried", "");
+        }
+    }
+
+    let dir = tempdir().unwrap();
+    let idl = dir.path().join("shop.thrift");
+    fs::write(
+        &idl,
+        r#"namespace rs shop
+
+struct Item {}
+
+struct Order {
+    1: required list<Item> items,
+}
+
+service Shop {
+    Order get(1: required i64 id) (audit = "true"),
+    void ping(),
+}
+"#,
+    )
+    .unwrap();
+
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .plugin(Queries)
+        .compile_with_config(
+            vec![IdlService::from_path(idl)],
+            crate::Output::File(dir.path().join("shop.rs")),
+        );
+    assert!(dir.path().join("queried").exists());
+}
+
+#[test]
+fn test_idl_metrics() {
+    let dir = tempdir().unwrap();
+    let idl = dir.path().join("metrics.thrift");
+    fs::write(
+        &idl,
+        r#"namespace rs shop
+
+enum Kind { A, B }
+
+struct Item {
+    1: required i64 id,
+    2: required string name,
+    3: optional Kind kind,
+}
+
+struct Order {
+    1: required list<Item> items,
+    2: optional Item gift,
+}
+
+service Shop {
+    Order get(1: required i64 id),
+    oneway void notify(1: required Order order),
+}
+"#,
+    )
+    .unwrap();
+
+    let metrics = crate::Builder::thrift()
+        .ignore_unused(false)
+        .metrics(vec![IdlService::from_path(idl)]);
+    assert_eq!(
+        (metrics.structs, metrics.enums, metrics.services),
+        (2, 1, 1)
+    );
+    assert_eq!(metrics.fields, 5);
+    assert_eq!(metrics.max_depth, 2);
+
+    let item = metrics
+        .messages
+        .iter()
+        .find(|m| m.name == "shop.Item")
+        .unwrap();
+    // stop + (header + i64) + (header + length)
+    assert_eq!(item.min_size, 1 + 11 + 7);
+    let get = metrics.methods.iter().find(|m| m.method == "get").unwrap();
+    assert_eq!(get.service, "shop.Shop");
+    assert_eq!(get.min_request_size, 1 + 11);
+    // stop + (header + Order { stop + (header + list header) })
+    assert_eq!(get.min_response_size, Some(1 + 3 + 1 + 3 + 5));
+    let notify = metrics
+        .methods
+        .iter()
+        .find(|m| m.method == "notify")
+        .unwrap();
+    assert_eq!(notify.min_response_size, None);
+
+    let text = metrics.to_prometheus();
+    assert!(text.contains("# TYPE pilota_idl_items gauge\n"));
+    assert!(text.contains("pilota_idl_items{kind=\"struct\"} 2\n"));
+    assert!(text.contains(
+        "pilota_idl_method_request_min_size_bytes{service=\"shop.Shop\",method=\"get\"} 12\n"
+    ));
+}
+
+#[test]
+fn test_dependency_graph() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("common.thrift"),
+        r#"namespace rs common
+
+struct Node {
+    1: required i64 id,
+    2: optional list<Tree> children,
+}
+
+struct Tree {
+    1: optional Node root,
+}
+"#,
+    )
+    .unwrap();
+    let idl = dir.path().join("shop.thrift");
+    fs::write(
+        &idl,
+        r#"include "common.thrift"
+
+namespace rs shop
+
+exception NotFound {
+    1: required string message,
+}
+
+struct Order {
+    1: required i64 id,
+    2: optional common.Tree tree,
+}
+
+service Shop {
+    Order get(1: required i64 id) throws (1: NotFound e),
+}
+"#,
+    )
+    .unwrap();
+
+    let graph = crate::Builder::thrift()
+        .ignore_unused(false)
+        .dependency_graph(vec![IdlService::from_path(idl)]);
+
+    let shop = graph
+        .files
+        .iter()
+        .find(|f| f.path.ends_with("shop.thrift"))
+        .unwrap();
+    assert_eq!(shop.package, "shop");
+    assert_eq!(shop.includes.len(), 1);
+    assert!(shop.includes[0].ends_with("common.thrift"));
+    assert_eq!(shop.cycle, None);
+
+    let ty = |name: &str| graph.types.iter().find(|t| t.name == name).unwrap();
+    let service = ty("shop.Shop");
+    assert_eq!(service.kind, crate::graph::TypeKind::Service);
+    assert_eq!(service.deps, ["shop.NotFound", "shop.Order"]);
+    assert_eq!(service.min_size, None);
+    let order = ty("shop.Order");
+    assert_eq!(order.deps, ["common.Tree"]);
+    assert_eq!(order.cycle, None);
+    // stop + (header + i64)
+    assert_eq!(order.min_size, Some(1 + 11));
+    assert!(order.file.ends_with("shop.thrift"));
+    let (node, tree) = (ty("common.Node"), ty("common.Tree"));
+    assert!(node.cycle.is_some());
+    assert_eq!(node.cycle, tree.cycle);
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph idl {\n"));
+    assert!(dot.contains("\"shop.Order\" [label=\"shop.Order\\nstruct, 12 B\"];\n"));
+    assert!(dot.contains("\"shop.Order\" -> \"common.Tree\";\n"));
+    assert!(dot.contains("\"common.Node\" -> \"common.Tree\" [color=red];\n"));
+
+    let json = graph.to_json();
+    assert!(json.contains("\"kind\": \"service\""));
+}
+
+#[test]
+fn test_wit() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("wit.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("wit");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        let wit = crate::Builder::thrift()
+            .ignore_unused(false)
+            .wit(
+                vec![IdlService::from_path(source.to_path_buf())],
+                "pilota:shop",
+                ["shop.Shop"],
+            )
+            .unwrap();
+        fs::write(target, wit).unwrap();
+    });
+}
+
+#[test]
+fn test_emit_backends() {
+    use crate::{
+        Context, GeneratedFiles,
+        backend::{Backend, DependencyGraphBackend, WitBackend},
+        db::RirDatabase,
+        rir::Item,
+    };
+
+    struct Names(&'static str);
+
+    impl Backend for Names {
+        fn name(&self) -> &str {
+            "names"
+        }
+
+        fn generate(&self, cx: &Context) -> anyhow::Result<GeneratedFiles> {
+            let mut names = cx
+                .cache
+                .codegen_items
+                .iter()
+                .filter_map(|def_id| match cx.db.item(*def_id).as_deref() {
+                    Some(Item::Message(m)) => Some(m.name.to_string()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            names.sort();
+            let mut files = GeneratedFiles::default();
+            files.insert(self.0, names.join("\n"));
+            Ok(files)
+        }
+    }
+
+    let idl = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("wit.thrift");
+    let wit = WitBackend::new("wit/shop.wit", "pilota:shop", ["shop.Shop"]);
+    let graph = DependencyGraphBackend::new("graph/shop");
+    let files = crate::Builder::thrift()
+        .ignore_unused(false)
+        .emit(
+            vec![IdlService::from_path(idl.clone())],
+            &[&Names("names.txt"), &wit, &graph],
+        )
+        .unwrap();
+    let paths = files
+        .iter()
+        .map(|(p, _)| p.to_path_buf())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        [
+            "graph/shop.dot",
+            "graph/shop.json",
+            "names.txt",
+            "wit/shop.wit"
+        ]
+        .map(std::path::PathBuf::from)
+    );
+    assert!(files.get("names.txt").unwrap().contains("Order"));
+    let expected = fs::read_to_string(idl.with_extension("wit")).unwrap();
+    assert_eq!(files.get("wit/shop.wit").unwrap(), expected);
+
+    let dir = tempdir().unwrap();
+    files.write_to(dir.path()).unwrap();
+    assert!(dir.path().join("graph/shop.dot").exists());
+
+    let err = crate::Builder::thrift()
+        .ignore_unused(false)
+        .emit(
+            vec![IdlService::from_path(idl)],
+            &[&Names("wit/shop.wit"), &wit],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("both generate"), "{err}");
+}
+
+#[test]
+fn test_wit_recursive() {
+    let dir = tempdir().unwrap();
+    let idl = dir.path().join("tree.thrift");
+    fs::write(
+        &idl,
+        r#"namespace rs tree
+
+struct Node {
+    1: required list<Node> children,
+}
+"#,
+    )
+    .unwrap();
+
+    let err = crate::Builder::thrift()
+        .ignore_unused(false)
+        .wit(
+            vec![IdlService::from_path(idl)],
+            "pilota:tree",
+            ["tree.Node"],
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("recursive"), "{err}");
+}
+
+#[test]
+fn test_plugin_rewrite_file() {
+    struct FeatureGate;
+
+    fn gate(items: &mut [syn::Item]) {
+        for item in items {
+            match item {
+                syn::Item::Mod(m) => {
+                    if let Some((_, items)) = &mut m.content {
+                        gate(items)
+                    }
+                }
+                syn::Item::Trait(t) if t.ident == "Echo" => {
+                    t.attrs.push(syn::parse_quote!(#[cfg(feature = "echo")]))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    impl crate::Plugin for FeatureGate {
+        fn on_file(&mut self, _cx: &crate::Context, path: &Path, file: &mut syn::File) {
+            assert_eq!(path.extension().unwrap(), "rs");
+            gate(&mut file.items)
+        }
+    }
+
+    let dir = tempdir().unwrap();
+    let idl = dir.path().join("gate.thrift");
+    fs::write(
+        &idl,
+        "struct Req {}\n\nservice Echo {\n    Req ping(1: Req req),\n}\n",
+    )
+    .unwrap();
+
+    for with_comments in [false, true] {
+        let out = dir.path().join(format!("gate_{with_comments}.rs"));
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .with_comments(with_comments)
+            .plugin(FeatureGate)
+            .compile_with_config(
+                vec![IdlService::from_path(idl.clone())],
+                crate::Output::File(out.clone()),
+            );
+
+        let generated = fs::read_to_string(out).unwrap();
+        assert!(generated.contains("#[cfg(feature = \"echo\")]\n"));
+        assert_eq!(generated.matches("cfg(feature").count(), 1);
+    }
+}
+
 mod tests {
 
     // use self::decode_error::decode_error::A;
@@ -0,0 +1,331 @@
+//! Experimental export of IDL types as [WIT] interfaces, see
+//! [`Builder::wit`](crate::Builder::wit).
+//!
+//! Structs become records, unions variants, enums WIT enums (their
+//! values are dropped) and typedefs type aliases. All of them are defined
+//! in one `types` interface, and each service gets an interface of its own
+//! with a function per method, exceptions turned into a `result` error.
+//!
+//! [WIT]: https://component-model.bytecodealliance.org/design/wit.html
+
+use std::{collections::BTreeSet, fmt::Write as _};
+
+use heck::ToKebabCase;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    Context, DefId,
+    db::RirDatabase,
+    middle::{
+        rir::{FieldKind, Item},
+        ty::{Ty, TyKind},
+    },
+    tags::StreamingMode,
+};
+
+const TYPES_INTERFACE: &str = "types";
+
+const KEYWORDS: &[&str] = &[
+    "as",
+    "bool",
+    "borrow",
+    "char",
+    "constructor",
+    "enum",
+    "export",
+    "f32",
+    "f64",
+    "flags",
+    "from",
+    "func",
+    "future",
+    "import",
+    "include",
+    "interface",
+    "list",
+    "option",
+    "own",
+    "package",
+    "record",
+    "resource",
+    "result",
+    "s16",
+    "s32",
+    "s64",
+    "s8",
+    "static",
+    "stream",
+    "string",
+    "tuple",
+    "type",
+    "u16",
+    "u32",
+    "u64",
+    "u8",
+    "use",
+    "variant",
+    "with",
+    "world",
+];
+
+/// Renders `items`, IDL paths such as `shop.Order`, and the types they
+/// reference as a WIT package named `package`.
+pub(crate) fn export(
+    cx: &Context,
+    package: &str,
+    items: impl IntoIterator<Item = impl AsRef<str>>,
+) -> anyhow::Result<String> {
+    let mut exporter = Exporter {
+        cx,
+        visiting: Default::default(),
+        names: Default::default(),
+        types: String::new(),
+        used: Default::default(),
+    };
+    let mut services = String::new();
+    for path in items {
+        let path = path.as_ref();
+        let Some(def_id) = cx.resolve(path) else {
+            anyhow::bail!("unknown item `{path}`");
+        };
+        let item = cx.db.item(def_id).unwrap();
+        match &*item {
+            Item::Message(_) | Item::Enum(_) | Item::NewType(_) => {
+                exporter.define(def_id)?;
+            }
+            Item::Service(s) => {
+                let name = ident(&s.name.sym.0);
+                if name == TYPES_INTERFACE {
+                    anyhow::bail!("service `{path}` clashes with the `types` interface");
+                }
+                let body = exporter.service(def_id)?;
+                let _ = writeln!(services, "\ninterface {name} {{");
+                let used = std::mem::take(&mut exporter.used);
+                if !used.is_empty() {
+                    let used = used.into_iter().collect::<Vec<_>>().join(", ");
+                    let _ = writeln!(services, "  use {TYPES_INTERFACE}.{{{used}}};\n");
+                }
+                services.push_str(&body);
+                services.push_str("}\n");
+            }
+            Item::Const(_) | Item::Mod(_) => {
+                anyhow::bail!("`{path}` is neither a type nor a service")
+            }
+        }
+    }
+
+    let mut out = format!("package {package};\n");
+    if !exporter.types.is_empty() {
+        let _ = write!(
+            out,
+            "\ninterface {TYPES_INTERFACE} {{\n{}}}\n",
+            exporter.types
+        );
+    }
+    out.push_str(&services);
+    Ok(out)
+}
+
+struct Exporter<'a> {
+    cx: &'a Context,
+    visiting: FxHashSet<DefId>,
+    /// The defined types by their WIT name, to catch two items of
+    /// different packages mapping to the same name.
+    names: FxHashMap<String, DefId>,
+    types: String,
+    /// The types referenced by the service being exported.
+    used: BTreeSet<String>,
+}
+
+impl Exporter<'_> {
+    /// Defines `def_id` after the types it references and returns its name.
+    fn define(&mut self, def_id: DefId) -> anyhow::Result<String> {
+        let item = self.cx.db.item(def_id).unwrap();
+        let raw = item.symbol_name().0;
+        let name = ident(&raw);
+        match self.names.get(&name) {
+            Some(did) if *did == def_id => {
+                if self.visiting.contains(&def_id) {
+                    anyhow::bail!("`{raw}` is recursive, which WIT can't express");
+                }
+                return Ok(name);
+            }
+            Some(_) => anyhow::bail!("two items are exported as `{name}`"),
+            None => {}
+        }
+        self.names.insert(name.clone(), def_id);
+        self.visiting.insert(def_id);
+
+        let mut def = String::new();
+        match &*item {
+            Item::Message(m) => {
+                if m.fields.is_empty() {
+                    anyhow::bail!("`{raw}` has no fields, which WIT records can't express");
+                }
+                let _ = writeln!(def, "  record {name} {{");
+                for f in &m.fields {
+                    let ty = self.ty(&f.ty)?;
+                    let ty = match f.kind {
+                        FieldKind::Optional => format!("option<{ty}>"),
+                        FieldKind::Required => ty,
+                    };
+                    let _ = writeln!(def, "    {}: {ty},", ident(&f.name.sym.0));
+                }
+            }
+            Item::Enum(e) if e.variants.is_empty() => {
+                anyhow::bail!("`{raw}` has no variants, which WIT can't express")
+            }
+            Item::Enum(e) if e.repr.is_some() => {
+                let _ = writeln!(def, "  enum {name} {{");
+                for v in &e.variants {
+                    let _ = writeln!(def, "    {},", ident(&v.name.sym.0));
+                }
+            }
+            Item::Enum(e) => {
+                let _ = writeln!(def, "  variant {name} {{");
+                for v in &e.variants {
+                    let case = ident(&v.name.sym.0);
+                    match &*v.fields {
+                        [] => {
+                            let _ = writeln!(def, "    {case},");
+                        }
+                        [ty] => {
+                            let ty = self.ty(ty)?;
+                            let _ = writeln!(def, "    {case}({ty}),");
+                        }
+                        tys => {
+                            let tys = tys
+                                .iter()
+                                .map(|ty| self.ty(ty))
+                                .collect::<anyhow::Result<Vec<_>>>()?;
+                            let _ = writeln!(def, "    {case}(tuple<{}>),", tys.join(", "));
+                        }
+                    }
+                }
+            }
+            Item::NewType(t) => {
+                let ty = self.ty(&t.ty)?;
+                let _ = writeln!(def, "  type {name} = {ty};");
+            }
+            Item::Service(_) | Item::Const(_) | Item::Mod(_) => {
+                anyhow::bail!("`{raw}` is not a type")
+            }
+        }
+        if !matches!(&*item, Item::NewType(_)) {
+            def.push_str("  }\n");
+        }
+
+        self.visiting.remove(&def_id);
+        if !self.types.is_empty() {
+            self.types.push('\n');
+        }
+        self.types.push_str(&def);
+        Ok(name)
+    }
+
+    fn service(&mut self, def_id: DefId) -> anyhow::Result<String> {
+        let mut out = String::new();
+        for m in self.cx.db.service_methods(def_id).iter() {
+            let name = m.name.sym.0.as_str();
+            if m.streaming != StreamingMode::Unary {
+                anyhow::bail!("`{name}` is a streaming method, which isn't supported");
+            }
+            let args = m
+                .args
+                .iter()
+                .map(|a| {
+                    let ty = self.used_ty(&a.ty)?;
+                    let ty = match a.kind {
+                        FieldKind::Optional => format!("option<{ty}>"),
+                        FieldKind::Required => ty,
+                    };
+                    Ok(format!("{}: {ty}", ident(&a.name.sym.0)))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            let ret = match &m.ret.kind {
+                TyKind::Void => None,
+                _ => Some(self.used_ty(&m.ret)?),
+            };
+            let ret = match (&m.exceptions, m.oneway) {
+                (Some(e), false) => {
+                    let err = self.define(e.did)?;
+                    self.used.insert(err.clone());
+                    let ok = ret.as_deref().unwrap_or("_");
+                    Some(format!("result<{ok}, {err}>"))
+                }
+                _ => ret,
+            };
+            let _ = write!(out, "  {}: func({})", ident(name), args.join(", "));
+            if let Some(ret) = ret {
+                let _ = write!(out, " -> {ret}");
+            }
+            out.push_str(";\n");
+        }
+        Ok(out)
+    }
+
+    /// Like [`Self::ty`], also recording the named types for the `use` of
+    /// the service interface.
+    fn used_ty(&mut self, ty: &Ty) -> anyhow::Result<String> {
+        let mut paths = Vec::new();
+        collect_paths(ty, &mut paths);
+        for did in paths {
+            let name = self.define(did)?;
+            self.used.insert(name);
+        }
+        self.ty(ty)
+    }
+
+    fn ty(&mut self, ty: &Ty) -> anyhow::Result<String> {
+        Ok(match &ty.kind {
+            TyKind::String | TyKind::FastStr => "string".into(),
+            TyKind::Void => anyhow::bail!("`void` is only supported as a return type"),
+            TyKind::U8 => "u8".into(),
+            TyKind::Bool => "bool".into(),
+            TyKind::BytesVec | TyKind::Bytes => "list<u8>".into(),
+            TyKind::I8 => "s8".into(),
+            TyKind::I16 => "s16".into(),
+            TyKind::I32 => "s32".into(),
+            TyKind::I64 => "s64".into(),
+            TyKind::UInt32 => "u32".into(),
+            TyKind::UInt64 => "u64".into(),
+            TyKind::F32 => "f32".into(),
+            TyKind::F64 | TyKind::OrderedF64 => "f64".into(),
+            // the high and low halves
+            TyKind::Uuid => "tuple<u64, u64>".into(),
+            TyKind::Vec(ty) | TyKind::Set(ty) | TyKind::BTreeSet(ty) => {
+                format!("list<{}>", self.ty(ty)?)
+            }
+            TyKind::Map(k, v) | TyKind::BTreeMap(k, v) => {
+                format!("list<tuple<{}, {}>>", self.ty(k)?, self.ty(v)?)
+            }
+            TyKind::Arc(ty) => self.ty(ty)?,
+            TyKind::Path(p) => self.define(p.did)?,
+        })
+    }
+}
+
+fn collect_paths(ty: &Ty, paths: &mut Vec<DefId>) {
+    match &ty.kind {
+        TyKind::Vec(ty) | TyKind::Set(ty) | TyKind::BTreeSet(ty) | TyKind::Arc(ty) => {
+            collect_paths(ty, paths)
+        }
+        TyKind::Map(k, v) | TyKind::BTreeMap(k, v) => {
+            collect_paths(k, paths);
+            collect_paths(v, paths);
+        }
+        TyKind::Path(p) => paths.push(p.did),
+        _ => {}
+    }
+}
+
+/// The kebab-case form of an IDL name, escaped with `%` if it is a WIT
+/// keyword.
+fn ident(name: &str) -> String {
+    let name = name.trim_start_matches('_').to_kebab_case();
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("%{name}")
+    } else {
+        name
+    }
+}
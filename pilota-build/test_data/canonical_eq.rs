@@ -0,0 +1,2380 @@
+pub mod canonical_eq {
+    #![allow(warnings, clippy::all)]
+    pub mod canonical_eq {
+        impl ::pilota::canonical::CanonicalEq for Status {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                self == other
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Status(i32);
+        impl Status {
+            pub const ACTIVE: Self = Self(1);
+            pub const INACTIVE: Self = Self(2);
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
+                match self {
+                    Self(1) => ::std::option::Option::Some("ACTIVE"),
+                    Self(2) => ::std::option::Option::Some("INACTIVE"),
+                    Self(_) => ::std::option::Option::None,
+                }
+            }
+            pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
+                match value {
+                    1 => Some(Self::ACTIVE),
+                    2 => Some(Self::INACTIVE),
+                    _ => None,
+                }
+            }
+        }
+        impl ::std::convert::From<i32> for Status {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+        impl ::std::convert::From<Status> for i32 {
+            fn from(value: Status) -> i32 {
+                value.0
+            }
+        }
+        impl ::std::fmt::Display for Status {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => {
+                        ::std::fmt::Display::fmt(&self.inner(), f)
+                    }
+                }
+            }
+        }
+        impl ::std::str::FromStr for Status {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "ACTIVE" => ::std::result::Result::Ok(Self::ACTIVE),
+                    "INACTIVE" => ::std::result::Result::Ok(Self::INACTIVE),
+                    _ => {
+                        s.parse::<i32>()
+                            .map(Self::from)
+                            .map_err(|_| ::pilota::EnumConvertError::InvalidNum(
+                                s.to_string(),
+                                "Status",
+                            ))
+                    }
+                }
+            }
+        }
+        impl ::pilota::thrift::Message for Status {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_i32(self.inner())?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let value = __protocol.read_i32()?;
+                ::std::result::Result::Ok(
+                    ::std::convert::TryFrom::try_from(value)
+                        .map_err(|err| ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            format!("invalid enum value for Status, value: {}", value),
+                        ))?,
+                )
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let value = __protocol.read_i32().await?;
+                    ::std::result::Result::Ok(
+                        ::std::convert::TryFrom::try_from(value)
+                            .map_err(|err| ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("invalid enum value for Status, value: {}", value),
+                            ))?,
+                    )
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.i32_len(self.inner())
+            }
+        }
+        impl ::pilota::canonical::CanonicalEq for Score {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                ::pilota::canonical::CanonicalEq::canonical_eq(&self.0, &other.0)
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct Score(pub f64);
+        impl ::std::ops::Deref for Score {
+            type Target = f64;
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl From<f64> for Score {
+            fn from(v: f64) -> Self {
+                Self(v)
+            }
+        }
+        impl ::pilota::thrift::Message for Score {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_double(*(&**self))?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                ::std::result::Result::Ok(Score(__protocol.read_double()?))
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    ::std::result::Result::Ok(Score(__protocol.read_double().await?))
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.double_len(*&**self)
+            }
+        }
+        impl ::pilota::canonical::CanonicalEq for Point {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                ::pilota::canonical::CanonicalEq::canonical_eq(&self.x, &other.x)
+                    && ::pilota::canonical::CanonicalEq::canonical_eq(&self.y, &other.y)
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct Point {
+            pub x: f64,
+            pub y: f64,
+        }
+        impl ::pilota::thrift::Message for Point {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "Point",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_double_field(1, *&self.x)?;
+                __protocol.write_double_field(2, *&self.y)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Double => {
+                                var_1 = Some(__protocol.read_double()?);
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Double => {
+                                var_2 = Some(__protocol.read_double()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `Point` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field x is required".to_string(),
+                        ),
+                    )
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field y is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { x: var_1, y: var_2 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Double => {
+                                    var_1 = Some(__protocol.read_double().await?);
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Double => {
+                                    var_2 = Some(__protocol.read_double().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `Point` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field x is required".to_string(),
+                            ),
+                        )
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field y is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { x: var_1, y: var_2 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Point",
+                        },
+                    ) + __protocol.double_field_len(Some(1), *&self.x)
+                    + __protocol.double_field_len(Some(2), *&self.y)
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Shape {
+            fn default() -> Self {
+                Shape::Point(::std::default::Default::default())
+            }
+        }
+        impl ::pilota::canonical::CanonicalEq for Shape {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    (Self::Point(a0), Self::Point(b0)) => {
+                        ::pilota::canonical::CanonicalEq::canonical_eq(a0, b0)
+                    }
+                    (Self::Polygon(a0), Self::Polygon(b0)) => {
+                        ::pilota::canonical::CanonicalEq::canonical_eq(a0, b0)
+                    }
+                    _ => false,
+                }
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Debug)]
+        #[derive(Clone, PartialEq)]
+        pub enum Shape {
+            Point(Point),
+            Polygon(::std::vec::Vec<Point>),
+        }
+        impl ::pilota::thrift::Message for Shape {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Shape",
+                        },
+                    )?;
+                match self {
+                    Shape::Point(value) => {
+                        __protocol
+                            .write_struct_field(
+                                1,
+                                value,
+                                ::pilota::thrift::TType::Struct,
+                            )?;
+                    }
+                    Shape::Polygon(value) => {
+                        __protocol
+                            .write_list_field(
+                                2,
+                                ::pilota::thrift::TType::Struct,
+                                &value,
+                                |__protocol, val| {
+                                    __protocol.write_struct(val)?;
+                                    ::std::result::Result::Ok(())
+                                },
+                            )?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol
+                            .field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(
+                                    __protocol,
+                                )?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(Shape::Point(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(2) => {
+                            if ret.is_none() {
+                                let field_ident = {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(
+                                        list_ident.size,
+                                    );
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(__protocol)?);
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                };
+                                __protocol
+                                    .list_len(
+                                        ::pilota::thrift::TType::Struct,
+                                        &field_ident,
+                                        |__protocol, el| { __protocol.struct_len(el) },
+                                    );
+                                ret = Some(Shape::Polygon(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ),
+                    )
+                }
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {}
+                        match field_ident.id {
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident = <Point as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+                                    ret = Some(Shape::Point(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            Some(2) => {
+                                if ret.is_none() {
+                                    let field_ident = {
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val = ::std::vec::Vec::with_capacity(
+                                            list_ident.size,
+                                        );
+                                        for _ in 0..list_ident.size {
+                                            val.push(
+                                                <Point as ::pilota::thrift::Message>::decode_async(
+                                                        __protocol,
+                                                    )
+                                                    .await?,
+                                            );
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    };
+                                    ret = Some(Shape::Polygon(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ),
+                        )
+                    }
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Shape",
+                        },
+                    )
+                    + match self {
+                        Shape::Point(value) => {
+                            __protocol.struct_field_len(Some(1), value)
+                        }
+                        Shape::Polygon(value) => {
+                            __protocol
+                                .list_field_len(
+                                    Some(2),
+                                    ::pilota::thrift::TType::Struct,
+                                    value,
+                                    |__protocol, el| { __protocol.struct_len(el) },
+                                )
+                        }
+                    } + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Reading {
+            fn default() -> Self {
+                Reading {
+                    sensor: ::std::default::Default::default(),
+                    retries: Some(3i32),
+                    unit: Some(::pilota::FastStr::from_static_str("celsius")),
+                    status: ::std::default::Default::default(),
+                    scores: ::std::default::Default::default(),
+                    samples: ::std::default::Default::default(),
+                    tags: ::std::default::Default::default(),
+                    shape: ::std::default::Default::default(),
+                }
+            }
+        }
+        impl ::pilota::canonical::CanonicalEq for Reading {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                ::pilota::canonical::CanonicalEq::canonical_eq(
+                    &self.sensor,
+                    &other.sensor,
+                )
+                    && ::pilota::canonical::optional_eq(
+                        &self.retries,
+                        &other.retries,
+                        || 3i32,
+                    )
+                    && ::pilota::canonical::optional_eq(
+                        &self.unit,
+                        &other.unit,
+                        || ::pilota::FastStr::from_static_str("celsius"),
+                    )
+                    && ::pilota::canonical::CanonicalEq::canonical_eq(
+                        &self.status,
+                        &other.status,
+                    )
+                    && ::pilota::canonical::CanonicalEq::canonical_eq(
+                        &self.scores,
+                        &other.scores,
+                    )
+                    && ::pilota::canonical::CanonicalEq::canonical_eq(
+                        &self.samples,
+                        &other.samples,
+                    )
+                    && ::pilota::canonical::CanonicalEq::canonical_eq(
+                        &self.tags,
+                        &other.tags,
+                    )
+                    && ::pilota::canonical::CanonicalEq::canonical_eq(
+                        &self.shape,
+                        &other.shape,
+                    )
+            }
+        }
+        #[derive(Debug)]
+        #[derive(Clone, PartialEq)]
+        pub struct Reading {
+            pub sensor: ::pilota::FastStr,
+            pub retries: ::std::option::Option<i32>,
+            pub unit: ::std::option::Option<::pilota::FastStr>,
+            pub status: ::std::option::Option<Status>,
+            pub scores: ::std::option::Option<
+                ::pilota::AHashMap<::pilota::FastStr, Score>,
+            >,
+            pub samples: ::std::option::Option<::std::vec::Vec<f64>>,
+            pub tags: ::std::option::Option<::pilota::AHashSet<::pilota::FastStr>>,
+            pub shape: ::std::option::Option<Shape>,
+        }
+        impl ::pilota::thrift::Message for Reading {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "Reading",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.sensor).clone())?;
+                if let Some(value) = self.retries.as_ref() {
+                    __protocol.write_i32_field(2, *value)?;
+                }
+                if let Some(value) = self.unit.as_ref() {
+                    __protocol.write_faststr_field(3, (value).clone())?;
+                }
+                if let Some(value) = self.status.as_ref() {
+                    __protocol.write_i32_field(4, (value).inner())?;
+                }
+                if let Some(value) = self.scores.as_ref() {
+                    __protocol
+                        .write_map_field(
+                            5,
+                            ::pilota::thrift::TType::Binary,
+                            ::pilota::thrift::TType::Double,
+                            &value,
+                            |__protocol, key| {
+                                __protocol.write_faststr((key).clone())?;
+                                ::std::result::Result::Ok(())
+                            },
+                            |__protocol, val| {
+                                __protocol.write_struct(val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                }
+                if let Some(value) = self.samples.as_ref() {
+                    __protocol
+                        .write_list_field(
+                            6,
+                            ::pilota::thrift::TType::Double,
+                            &value,
+                            |__protocol, val| {
+                                __protocol.write_double(*val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                }
+                if let Some(value) = self.tags.as_ref() {
+                    __protocol
+                        .write_set_field(
+                            7,
+                            ::pilota::thrift::TType::Binary,
+                            &value,
+                            |__protocol, val| {
+                                __protocol.write_faststr((val).clone())?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                }
+                if let Some(value) = self.shape.as_ref() {
+                    __protocol
+                        .write_struct_field(8, value, ::pilota::thrift::TType::Struct)?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = Some(3i32);
+                let mut var_3 = Some(::pilota::FastStr::from_static_str("celsius"));
+                let mut var_4 = None;
+                let mut var_5 = None;
+                let mut var_6 = None;
+                let mut var_7 = None;
+                let mut var_8 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Binary => {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                var_2 = Some(__protocol.read_i32()?);
+                            }
+                            Some(
+                                3,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Binary => {
+                                var_3 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(
+                                4,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                var_4 = Some(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                            }
+                            Some(
+                                5,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Map => {
+                                var_5 = Some({
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::pilota::AHashMap::with_capacity(
+                                        map_ident.size,
+                                    );
+                                    for _ in 0..map_ident.size {
+                                        val.insert(
+                                            __protocol.read_faststr()?,
+                                            ::pilota::thrift::Message::decode(__protocol)?,
+                                        );
+                                    }
+                                    __protocol.read_map_end()?;
+                                    val
+                                });
+                            }
+                            Some(
+                                6,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::List => {
+                                var_6 = Some({
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(
+                                        list_ident.size,
+                                    );
+                                    for _ in 0..list_ident.size {
+                                        val.push(__protocol.read_double()?);
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(
+                                7,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Set => {
+                                var_7 = Some({
+                                    let list_ident = __protocol.read_set_begin()?;
+                                    let mut val = ::pilota::AHashSet::with_capacity(
+                                        list_ident.size,
+                                    );
+                                    for _ in 0..list_ident.size {
+                                        val.insert(__protocol.read_faststr()?);
+                                    }
+                                    __protocol.read_set_end()?;
+                                    val
+                                });
+                            }
+                            Some(
+                                8,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Struct => {
+                                var_8 = Some(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `Reading` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field sensor is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self {
+                    sensor: var_1,
+                    retries: var_2,
+                    unit: var_3,
+                    status: var_4,
+                    scores: var_5,
+                    samples: var_6,
+                    tags: var_7,
+                    shape: var_8,
+                };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = Some(3i32);
+                    let mut var_3 = Some(::pilota::FastStr::from_static_str("celsius"));
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+                    let mut var_6 = None;
+                    let mut var_7 = None;
+                    let mut var_8 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Binary => {
+                                    var_1 = Some(__protocol.read_faststr().await?);
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    var_2 = Some(__protocol.read_i32().await?);
+                                }
+                                Some(
+                                    3,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Binary => {
+                                    var_3 = Some(__protocol.read_faststr().await?);
+                                }
+                                Some(
+                                    4,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    var_4 = Some(
+                                        <Status as ::pilota::thrift::Message>::decode_async(
+                                                __protocol,
+                                            )
+                                            .await?,
+                                    );
+                                }
+                                Some(
+                                    5,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Map => {
+                                    var_5 = Some({
+                                        let map_ident = __protocol.read_map_begin().await?;
+                                        let mut val = ::pilota::AHashMap::with_capacity(
+                                            map_ident.size,
+                                        );
+                                        for _ in 0..map_ident.size {
+                                            val.insert(
+                                                __protocol.read_faststr().await?,
+                                                <Score as ::pilota::thrift::Message>::decode_async(
+                                                        __protocol,
+                                                    )
+                                                    .await?,
+                                            );
+                                        }
+                                        __protocol.read_map_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(
+                                    6,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::List => {
+                                    var_6 = Some({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val = ::std::vec::Vec::with_capacity(
+                                            list_ident.size,
+                                        );
+                                        for _ in 0..list_ident.size {
+                                            val.push(__protocol.read_double().await?);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(
+                                    7,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Set => {
+                                    var_7 = Some({
+                                        let list_ident = __protocol.read_set_begin().await?;
+                                        let mut val = ::pilota::AHashSet::with_capacity(
+                                            list_ident.size,
+                                        );
+                                        for _ in 0..list_ident.size {
+                                            val.insert(__protocol.read_faststr().await?);
+                                        }
+                                        __protocol.read_set_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(
+                                    8,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Struct => {
+                                    var_8 = Some(
+                                        <Shape as ::pilota::thrift::Message>::decode_async(
+                                                __protocol,
+                                            )
+                                            .await?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `Reading` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field sensor is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self {
+                        sensor: var_1,
+                        retries: var_2,
+                        unit: var_3,
+                        status: var_4,
+                        scores: var_5,
+                        samples: var_6,
+                        tags: var_7,
+                        shape: var_8,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Reading",
+                        },
+                    ) + __protocol.faststr_field_len(Some(1), &self.sensor)
+                    + self
+                        .retries
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i32_field_len(Some(2), *value))
+                    + self
+                        .unit
+                        .as_ref()
+                        .map_or(0, |value| __protocol.faststr_field_len(Some(3), value))
+                    + self
+                        .status
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| __protocol.i32_field_len(Some(4), (value).inner()),
+                        )
+                    + self
+                        .scores
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol
+                                    .map_field_len(
+                                        Some(5),
+                                        ::pilota::thrift::TType::Binary,
+                                        ::pilota::thrift::TType::Double,
+                                        value,
+                                        |__protocol, key| { __protocol.faststr_len(key) },
+                                        |__protocol, val| { __protocol.struct_len(val) },
+                                    )
+                            },
+                        )
+                    + self
+                        .samples
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol
+                                    .list_field_len(
+                                        Some(6),
+                                        ::pilota::thrift::TType::Double,
+                                        value,
+                                        |__protocol, el| { __protocol.double_len(*el) },
+                                    )
+                            },
+                        )
+                    + self
+                        .tags
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol
+                                    .set_field_len(
+                                        Some(7),
+                                        ::pilota::thrift::TType::Binary,
+                                        value,
+                                        |__protocol, el| { __protocol.faststr_len(el) },
+                                    )
+                            },
+                        )
+                    + self
+                        .shape
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(8), value))
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::pilota::canonical::CanonicalEq for Empty {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                let _ = other;
+                true
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct Empty {}
+        impl ::pilota::thrift::Message for Empty {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_empty_struct(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Empty",
+                        },
+                    )?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                __protocol.read_empty_struct()?;
+                ::std::result::Result::Ok(Self {})
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    __protocol.read_empty_struct().await?;
+                    ::std::result::Result::Ok(Self {})
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .empty_struct_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Empty",
+                        },
+                    )
+            }
+        }
+        impl ::pilota::canonical::CanonicalEq for ReadingError {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                ::pilota::canonical::CanonicalEq::canonical_eq(
+                    &self.message,
+                    &other.message,
+                )
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct ReadingError {
+            pub message: ::pilota::FastStr,
+        }
+        impl ::pilota::thrift::Message for ReadingError {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "ReadingError",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.message).clone())?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Binary => {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `ReadingError` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field message is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { message: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Binary => {
+                                    var_1 = Some(__protocol.read_faststr().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `ReadingError` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field message is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { message: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "ReadingError",
+                        },
+                    ) + __protocol.faststr_field_len(Some(1), &self.message)
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for ReadingServiceGetResultRecv {
+            fn default() -> Self {
+                ReadingServiceGetResultRecv::Ok(::std::default::Default::default())
+            }
+        }
+        impl ::pilota::canonical::CanonicalEq for ReadingServiceGetResultRecv {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    (Self::Ok(a0), Self::Ok(b0)) => {
+                        ::pilota::canonical::CanonicalEq::canonical_eq(a0, b0)
+                    }
+                    (Self::Err(a0), Self::Err(b0)) => {
+                        ::pilota::canonical::CanonicalEq::canonical_eq(a0, b0)
+                    }
+                    _ => false,
+                }
+            }
+        }
+        #[derive(Debug)]
+        #[derive(Clone, PartialEq)]
+        pub enum ReadingServiceGetResultRecv {
+            Ok(Reading),
+            Err(ReadingError),
+        }
+        impl ::pilota::thrift::Message for ReadingServiceGetResultRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "ReadingServiceGetResultRecv",
+                        },
+                    )?;
+                match self {
+                    ReadingServiceGetResultRecv::Ok(value) => {
+                        __protocol
+                            .write_struct_field(
+                                0,
+                                value,
+                                ::pilota::thrift::TType::Struct,
+                            )?;
+                    }
+                    ReadingServiceGetResultRecv::Err(value) => {
+                        __protocol
+                            .write_struct_field(
+                                1,
+                                value,
+                                ::pilota::thrift::TType::Struct,
+                            )?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol
+                            .field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(
+                                    __protocol,
+                                )?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ReadingServiceGetResultRecv::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(
+                                    __protocol,
+                                )?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ReadingServiceGetResultRecv::Err(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ),
+                    )
+                }
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {}
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident = <Reading as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+                                    ret = Some(ReadingServiceGetResultRecv::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident = <ReadingError as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+                                    ret = Some(ReadingServiceGetResultRecv::Err(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ),
+                        )
+                    }
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "ReadingServiceGetResultRecv",
+                        },
+                    )
+                    + match self {
+                        ReadingServiceGetResultRecv::Ok(value) => {
+                            __protocol.struct_field_len(Some(0), value)
+                        }
+                        ReadingServiceGetResultRecv::Err(value) => {
+                            __protocol.struct_field_len(Some(1), value)
+                        }
+                    } + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for ReadingServiceGetResultSend {
+            fn default() -> Self {
+                ReadingServiceGetResultSend::Ok(::std::default::Default::default())
+            }
+        }
+        impl ::pilota::canonical::CanonicalEq for ReadingServiceGetResultSend {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    (Self::Ok(a0), Self::Ok(b0)) => {
+                        ::pilota::canonical::CanonicalEq::canonical_eq(a0, b0)
+                    }
+                    (Self::Err(a0), Self::Err(b0)) => {
+                        ::pilota::canonical::CanonicalEq::canonical_eq(a0, b0)
+                    }
+                    _ => false,
+                }
+            }
+        }
+        #[derive(Debug)]
+        #[derive(Clone, PartialEq)]
+        pub enum ReadingServiceGetResultSend {
+            Ok(Reading),
+            Err(ReadingError),
+        }
+        impl ::pilota::thrift::Message for ReadingServiceGetResultSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "ReadingServiceGetResultSend",
+                        },
+                    )?;
+                match self {
+                    ReadingServiceGetResultSend::Ok(value) => {
+                        __protocol
+                            .write_struct_field(
+                                0,
+                                value,
+                                ::pilota::thrift::TType::Struct,
+                            )?;
+                    }
+                    ReadingServiceGetResultSend::Err(value) => {
+                        __protocol
+                            .write_struct_field(
+                                1,
+                                value,
+                                ::pilota::thrift::TType::Struct,
+                            )?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol
+                            .field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(
+                                    __protocol,
+                                )?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ReadingServiceGetResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(
+                                    __protocol,
+                                )?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ReadingServiceGetResultSend::Err(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ),
+                    )
+                }
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {}
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident = <Reading as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+                                    ret = Some(ReadingServiceGetResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident = <ReadingError as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+                                    ret = Some(ReadingServiceGetResultSend::Err(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ),
+                        )
+                    }
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "ReadingServiceGetResultSend",
+                        },
+                    )
+                    + match self {
+                        ReadingServiceGetResultSend::Ok(value) => {
+                            __protocol.struct_field_len(Some(0), value)
+                        }
+                        ReadingServiceGetResultSend::Err(value) => {
+                            __protocol.struct_field_len(Some(1), value)
+                        }
+                    } + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for ReadingServiceGetException {
+            fn default() -> Self {
+                ReadingServiceGetException::Err(::std::default::Default::default())
+            }
+        }
+        impl ::pilota::canonical::CanonicalEq for ReadingServiceGetException {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    (Self::Err(a0), Self::Err(b0)) => {
+                        ::pilota::canonical::CanonicalEq::canonical_eq(a0, b0)
+                    }
+                }
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Clone, PartialEq)]
+        pub enum ReadingServiceGetException {
+            Err(ReadingError),
+        }
+        impl ::pilota::thrift::Message for ReadingServiceGetException {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "ReadingServiceGetException",
+                        },
+                    )?;
+                match self {
+                    ReadingServiceGetException::Err(value) => {
+                        __protocol
+                            .write_struct_field(
+                                1,
+                                value,
+                                ::pilota::thrift::TType::Struct,
+                            )?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol
+                            .field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(
+                                    __protocol,
+                                )?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ReadingServiceGetException::Err(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ),
+                    )
+                }
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {}
+                        match field_ident.id {
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident = <ReadingError as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+                                    ret = Some(ReadingServiceGetException::Err(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ),
+                        )
+                    }
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "ReadingServiceGetException",
+                        },
+                    )
+                    + match self {
+                        ReadingServiceGetException::Err(value) => {
+                            __protocol.struct_field_len(Some(1), value)
+                        }
+                    } + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::pilota::canonical::CanonicalEq for ReadingServiceGetArgsSend {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                ::pilota::canonical::CanonicalEq::canonical_eq(
+                    &self.sensor,
+                    &other.sensor,
+                )
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct ReadingServiceGetArgsSend {
+            pub sensor: ::pilota::FastStr,
+        }
+        impl ::pilota::thrift::Message for ReadingServiceGetArgsSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "ReadingServiceGetArgsSend",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.sensor).clone())?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Binary => {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `ReadingServiceGetArgsSend` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field sensor is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { sensor: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Binary => {
+                                    var_1 = Some(__protocol.read_faststr().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `ReadingServiceGetArgsSend` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field sensor is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { sensor: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "ReadingServiceGetArgsSend",
+                        },
+                    ) + __protocol.faststr_field_len(Some(1), &self.sensor)
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::pilota::canonical::CanonicalEq for ReadingServiceGetArgsRecv {
+            fn canonical_eq(&self, other: &Self) -> bool {
+                ::pilota::canonical::CanonicalEq::canonical_eq(
+                    &self.sensor,
+                    &other.sensor,
+                )
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct ReadingServiceGetArgsRecv {
+            pub sensor: ::pilota::FastStr,
+        }
+        impl ::pilota::thrift::Message for ReadingServiceGetArgsRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "ReadingServiceGetArgsRecv",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.sensor).clone())?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Binary => {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `ReadingServiceGetArgsRecv` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field sensor is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { sensor: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Binary => {
+                                    var_1 = Some(__protocol.read_faststr().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `ReadingServiceGetArgsRecv` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field sensor is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { sensor: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "ReadingServiceGetArgsRecv",
+                        },
+                    ) + __protocol.faststr_field_len(Some(1), &self.sensor)
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        pub trait ReadingService {}
+    }
+}
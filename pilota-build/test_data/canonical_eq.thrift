@@ -0,0 +1,39 @@
+namespace rs canonical_eq
+
+enum Status {
+    ACTIVE = 1,
+    INACTIVE = 2,
+}
+
+typedef double Score
+
+struct Point {
+    1: required double x,
+    2: required double y,
+}
+
+union Shape {
+    1: Point point,
+    2: list<Point> polygon,
+}
+
+struct Reading {
+    1: required string sensor,
+    2: optional i32 retries = 3,
+    3: optional string unit = "celsius",
+    4: optional Status status,
+    5: map<string, Score> scores,
+    6: list<double> samples,
+    7: set<string> tags,
+    8: optional Shape shape,
+}
+
+struct Empty {}
+
+exception ReadingError {
+    1: required string message,
+}
+
+service ReadingService {
+    Reading get(1: string sensor) throws (1: ReadingError err),
+}
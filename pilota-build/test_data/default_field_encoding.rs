@@ -0,0 +1,324 @@
+pub mod default_field_encoding {
+    #![allow(warnings, clippy::all)]
+    pub mod default_field_encoding {
+        impl ::std::default::Default for Node {
+            fn default() -> Self {
+                Node {
+                    id: ::std::default::Default::default(),
+                    name: Some(::pilota::FastStr::from_static_str("root")),
+                    weight: ::std::default::Default::default(),
+                    leaf: ::std::default::Default::default(),
+                    parent: ::std::default::Default::default(),
+                }
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Clone, PartialEq)]
+        pub struct Node {
+            pub id: ::std::option::Option<i32>,
+            pub name: ::std::option::Option<::pilota::FastStr>,
+            pub weight: ::std::option::Option<i64>,
+            pub leaf: bool,
+            pub parent: ::std::option::Option<::std::boxed::Box<Node>>,
+        }
+        impl ::pilota::thrift::Message for Node {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "Node",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                {
+                    let __pilota_default;
+                    let value = match self.id.as_ref() {
+                        Some(value) => value,
+                        None => {
+                            __pilota_default = ::std::default::Default::default();
+                            &__pilota_default
+                        }
+                    };
+                    __protocol.write_i32_field(1, *value)?;
+                }
+                {
+                    let __pilota_default;
+                    let value = match self.name.as_ref() {
+                        Some(value) => value,
+                        None => {
+                            __pilota_default = ::pilota::FastStr::from_static_str(
+                                "root",
+                            );
+                            &__pilota_default
+                        }
+                    };
+                    __protocol.write_faststr_field(2, (value).clone())?;
+                }
+                if let Some(value) = self.weight.as_ref() {
+                    __protocol.write_i64_field(3, *value)?;
+                }
+                __protocol.write_bool_field(4, *&self.leaf)?;
+                if let Some(value) = self.parent.as_ref() {
+                    __protocol
+                        .write_struct_field(5, value, ::pilota::thrift::TType::Struct)?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = Some(::pilota::FastStr::from_static_str("root"));
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut var_5 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                var_1 = Some(__protocol.read_i32()?);
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Binary => {
+                                var_2 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(
+                                3,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I64 => {
+                                var_3 = Some(__protocol.read_i64()?);
+                            }
+                            Some(
+                                4,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Bool => {
+                                var_4 = Some(__protocol.read_bool()?);
+                            }
+                            Some(
+                                5,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Struct => {
+                                var_5 = Some(
+                                    ::std::boxed::Box::new(
+                                        ::pilota::thrift::Message::decode(__protocol)?,
+                                    ),
+                                );
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `Node` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_4) = var_4 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field leaf is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self {
+                    id: var_1,
+                    name: var_2,
+                    weight: var_3,
+                    leaf: var_4,
+                    parent: var_5,
+                };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = Some(::pilota::FastStr::from_static_str("root"));
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    var_1 = Some(__protocol.read_i32().await?);
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Binary => {
+                                    var_2 = Some(__protocol.read_faststr().await?);
+                                }
+                                Some(
+                                    3,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I64 => {
+                                    var_3 = Some(__protocol.read_i64().await?);
+                                }
+                                Some(
+                                    4,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Bool => {
+                                    var_4 = Some(__protocol.read_bool().await?);
+                                }
+                                Some(
+                                    5,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Struct => {
+                                    var_5 = Some(
+                                        ::std::boxed::Box::new(
+                                            <Node as ::pilota::thrift::Message>::decode_async(
+                                                    __protocol,
+                                                )
+                                                .await?,
+                                        ),
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `Node` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_4) = var_4 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field leaf is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self {
+                        id: var_1,
+                        name: var_2,
+                        weight: var_3,
+                        leaf: var_4,
+                        parent: var_5,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Node",
+                        },
+                    )
+                    + {
+                        let __pilota_default;
+                        let value = match self.id.as_ref() {
+                            Some(value) => value,
+                            None => {
+                                __pilota_default = ::std::default::Default::default();
+                                &__pilota_default
+                            }
+                        };
+                        __protocol.i32_field_len(Some(1), *value)
+                    }
+                    + {
+                        let __pilota_default;
+                        let value = match self.name.as_ref() {
+                            Some(value) => value,
+                            None => {
+                                __pilota_default = ::pilota::FastStr::from_static_str(
+                                    "root",
+                                );
+                                &__pilota_default
+                            }
+                        };
+                        __protocol.faststr_field_len(Some(2), value)
+                    }
+                    + self
+                        .weight
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i64_field_len(Some(3), *value))
+                    + __protocol.bool_field_len(Some(4), *&self.leaf)
+                    + self
+                        .parent
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(5), value))
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+namespace rs default_field_encoding
+
+struct Node {
+    1: i32 id,
+    2: string name = "root",
+    3: optional i64 weight,
+    4: required bool leaf,
+    5: Node parent,
+}
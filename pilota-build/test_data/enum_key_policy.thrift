@@ -0,0 +1,13 @@
+enum Index {
+    A = 0,
+    B = 1,
+}
+
+struct A {
+    1: map<Index, string> names,
+    2: optional map<Index, list<Index>> groups,
+}
+
+service TestService {
+    A test(1: A req),
+}
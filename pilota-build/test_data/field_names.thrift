@@ -0,0 +1,23 @@
+struct Point {
+    1: required i32 x,
+    2: required i32 y,
+}
+
+union Shape {
+    1: Point point,
+    2: list<Point> polygon,
+}
+
+struct Drawing {
+    1: required string title,
+    2: optional list<Shape> shapes,
+    3: map<string, Point> anchors,
+}
+
+exception DrawingError {
+    1: string message,
+}
+
+service DrawingService {
+    Drawing Get(1: string title) throws (1: DrawingError err),
+}
@@ -0,0 +1,28 @@
+enum Level {
+    LOW = 1,
+    HIGH = 2,
+}
+
+typedef i64 Timestamp
+
+struct Header {
+    1: required i32 version,
+    2: required Level level,
+}
+
+struct Tick {
+    1: required Header header,
+    2: required Timestamp at,
+    3: required double value,
+    4: required bool valid,
+}
+
+struct Labeled {
+    1: required Header header,
+    2: required string label,
+}
+
+struct Partial {
+    1: required i64 id,
+    2: optional i64 parent,
+}
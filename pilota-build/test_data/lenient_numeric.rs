@@ -0,0 +1,497 @@
+pub mod lenient_numeric {
+    #![allow(warnings, clippy::all)]
+    pub mod lenient_numeric {
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct Narrow {
+            pub count: i32,
+            pub total: ::std::option::Option<i64>,
+        }
+        impl ::pilota::thrift::Message for Narrow {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "Narrow",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i32_field(1, *&self.count)?;
+                if let Some(value) = self.total.as_ref() {
+                    __protocol.write_i64_field(2, *value)?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut __pilota_seen_fields = ::pilota::thrift::duplicate::SeenFields::new(
+                    __protocol.rejects_duplicate_fields(),
+                );
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                __pilota_seen_fields.insert(0, 1)?;
+                                var_1 = Some(__protocol.read_i32()?);
+                            }
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I64 => {
+                                __pilota_seen_fields.insert(0, 1)?;
+                                var_1 = Some(
+                                    ::pilota::thrift::coerce::narrow(__protocol.read_i64()?)?,
+                                );
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I64 => {
+                                __pilota_seen_fields.insert(1, 2)?;
+                                var_2 = Some(__protocol.read_i64()?);
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                __pilota_seen_fields.insert(1, 2)?;
+                                var_2 = Some(
+                                    ::pilota::thrift::coerce::widen(__protocol.read_i32()?),
+                                );
+                            }
+                            _ => {
+                                __protocol
+                                    .on_unknown_field(
+                                        "Narrow",
+                                        &field_ident,
+                                        matches!(field_ident.id, Some(1 | 2)),
+                                    );
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `Narrow` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field count is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { count: var_1, total: var_2 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut __pilota_seen_fields = ::pilota::thrift::duplicate::SeenFields::new(
+                        __protocol.rejects_duplicate_fields(),
+                    );
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    __pilota_seen_fields.insert(0, 1)?;
+                                    var_1 = Some(__protocol.read_i32().await?);
+                                }
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I64 => {
+                                    __pilota_seen_fields.insert(0, 1)?;
+                                    var_1 = Some(
+                                        ::pilota::thrift::coerce::narrow(
+                                            __protocol.read_i64().await?,
+                                        )?,
+                                    );
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I64 => {
+                                    __pilota_seen_fields.insert(1, 2)?;
+                                    var_2 = Some(__protocol.read_i64().await?);
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    __pilota_seen_fields.insert(1, 2)?;
+                                    var_2 = Some(
+                                        ::pilota::thrift::coerce::widen(
+                                            __protocol.read_i32().await?,
+                                        ),
+                                    );
+                                }
+                                _ => {
+                                    __protocol
+                                        .on_unknown_field(
+                                            "Narrow",
+                                            &field_ident,
+                                            matches!(field_ident.id, Some(1 | 2)),
+                                        );
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `Narrow` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field count is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { count: var_1, total: var_2 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Narrow",
+                        },
+                    ) + __protocol.i32_field_len(Some(1), *&self.count)
+                    + self
+                        .total
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i64_field_len(Some(2), *value))
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct Wide {
+            pub count: i64,
+            pub total: ::std::option::Option<i32>,
+        }
+        impl ::pilota::thrift::Message for Wide {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "Wide",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.count)?;
+                if let Some(value) = self.total.as_ref() {
+                    __protocol.write_i32_field(2, *value)?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut __pilota_seen_fields = ::pilota::thrift::duplicate::SeenFields::new(
+                    __protocol.rejects_duplicate_fields(),
+                );
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I64 => {
+                                __pilota_seen_fields.insert(0, 1)?;
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                __pilota_seen_fields.insert(0, 1)?;
+                                var_1 = Some(
+                                    ::pilota::thrift::coerce::widen(__protocol.read_i32()?),
+                                );
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                __pilota_seen_fields.insert(1, 2)?;
+                                var_2 = Some(__protocol.read_i32()?);
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I64 => {
+                                __pilota_seen_fields.insert(1, 2)?;
+                                var_2 = Some(
+                                    ::pilota::thrift::coerce::narrow(__protocol.read_i64()?)?,
+                                );
+                            }
+                            _ => {
+                                __protocol
+                                    .on_unknown_field(
+                                        "Wide",
+                                        &field_ident,
+                                        matches!(field_ident.id, Some(1 | 2)),
+                                    );
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `Wide` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field count is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { count: var_1, total: var_2 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut __pilota_seen_fields = ::pilota::thrift::duplicate::SeenFields::new(
+                        __protocol.rejects_duplicate_fields(),
+                    );
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I64 => {
+                                    __pilota_seen_fields.insert(0, 1)?;
+                                    var_1 = Some(__protocol.read_i64().await?);
+                                }
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    __pilota_seen_fields.insert(0, 1)?;
+                                    var_1 = Some(
+                                        ::pilota::thrift::coerce::widen(
+                                            __protocol.read_i32().await?,
+                                        ),
+                                    );
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    __pilota_seen_fields.insert(1, 2)?;
+                                    var_2 = Some(__protocol.read_i32().await?);
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I64 => {
+                                    __pilota_seen_fields.insert(1, 2)?;
+                                    var_2 = Some(
+                                        ::pilota::thrift::coerce::narrow(
+                                            __protocol.read_i64().await?,
+                                        )?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol
+                                        .on_unknown_field(
+                                            "Wide",
+                                            &field_ident,
+                                            matches!(field_ident.id, Some(1 | 2)),
+                                        );
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `Wide` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field count is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { count: var_1, total: var_2 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Wide",
+                        },
+                    ) + __protocol.i64_field_len(Some(1), *&self.count)
+                    + self
+                        .total
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i32_field_len(Some(2), *value))
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+    }
+}
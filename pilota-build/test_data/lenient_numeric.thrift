@@ -0,0 +1,9 @@
+struct Narrow {
+    1: required i32 count,
+    2: optional i64 total,
+}
+
+struct Wide {
+    1: required i64 count,
+    2: optional i32 total,
+}
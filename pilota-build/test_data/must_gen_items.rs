@@ -1,9 +1,11 @@
 pub mod must_gen_items {
     #![allow(warnings, clippy::all)]
-
     pub mod must_gen_items {
-
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
         pub struct A {
             pub a: ::std::option::Option<i32>,
         }
@@ -14,8 +16,9 @@ pub mod must_gen_items {
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "A" };
-
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "A",
+                };
                 __protocol.write_struct_begin(&struct_ident)?;
                 if let Some(value) = self.a.as_ref() {
                     __protocol.write_i32_field(1, *value)?;
@@ -24,17 +27,13 @@ pub mod must_gen_items {
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
             }
-
             fn decode<T: ::pilota::thrift::TInputProtocol>(
                 __protocol: &mut T,
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
                 let mut var_1 = None;
-
                 let mut __pilota_decoding_field_id = None;
-
                 __protocol.read_struct_begin()?;
                 if let ::std::result::Result::Err(mut err) = (|| {
                     loop {
@@ -43,103 +42,113 @@ pub mod must_gen_items {
                             __protocol.field_stop_len();
                             break;
                         } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
-                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
                                 var_1 = Some(__protocol.read_i32()?);
                             }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
                             }
                         }
-
                         __protocol.read_field_end()?;
                         __protocol.field_end_len();
-                    }
+                    };
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `A` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `A` field(#{}) failed, caused by: ", field_id
+                            ),
+                        );
                     }
                     return ::std::result::Result::Err(err);
-                };
+                }
                 __protocol.read_struct_end()?;
-
                 let data = Self { a: var_1 };
                 ::std::result::Result::Ok(data)
             }
-
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
                 __protocol: &'a mut T,
             ) -> ::std::pin::Pin<
                 ::std::boxed::Box<
                     dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
                     let mut var_1 = None;
-
                     let mut __pilota_decoding_field_id = None;
-
                     __protocol.read_struct_begin().await?;
                     if let ::std::result::Result::Err(mut err) = async {
                         loop {
                             let field_ident = __protocol.read_field_begin().await?;
                             if field_ident.field_type == ::pilota::thrift::TType::Stop {
                                 break;
-                            } else {
-                            }
+                            } else {}
                             __pilota_decoding_field_id = field_ident.id;
                             match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
-                                {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
                                     var_1 = Some(__protocol.read_i32().await?);
                                 }
                                 _ => {
                                     __protocol.skip(field_ident.field_type).await?;
                                 }
                             }
-
                             __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
                     }
-                    .await
+                        .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `A` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `A` field(#{}) failed, caused by: ", field_id
+                                ),
+                            );
                         }
                         return ::std::result::Result::Err(err);
-                    };
+                    }
                     __protocol.read_struct_end().await?;
-
                     let data = Self { a: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "A" })
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "A",
+                        },
+                    )
                     + self
                         .a
                         .as_ref()
                         .map_or(0, |value| __protocol.i32_field_len(Some(1), *value))
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
             }
         }
     }
@@ -0,0 +1,1243 @@
+pub mod pb_codec {
+    #![allow(warnings, clippy::all)]
+    pub mod pb_codec {
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Status(i32);
+        impl Status {
+            pub const UNKNOWN: Self = Self(0);
+            pub const ACTIVE: Self = Self(1);
+            pub const INACTIVE: Self = Self(2);
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
+                match self {
+                    Self(0) => ::std::option::Option::Some("UNKNOWN"),
+                    Self(1) => ::std::option::Option::Some("ACTIVE"),
+                    Self(2) => ::std::option::Option::Some("INACTIVE"),
+                    Self(_) => ::std::option::Option::None,
+                }
+            }
+            pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
+                match value {
+                    0 => Some(Self::UNKNOWN),
+                    1 => Some(Self::ACTIVE),
+                    2 => Some(Self::INACTIVE),
+                    _ => None,
+                }
+            }
+        }
+        impl ::std::convert::From<i32> for Status {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+        impl ::std::convert::From<Status> for i32 {
+            fn from(value: Status) -> i32 {
+                value.0
+            }
+        }
+        impl ::std::fmt::Display for Status {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => {
+                        ::std::fmt::Display::fmt(&self.inner(), f)
+                    }
+                }
+            }
+        }
+        impl ::std::str::FromStr for Status {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "UNKNOWN" => ::std::result::Result::Ok(Self::UNKNOWN),
+                    "ACTIVE" => ::std::result::Result::Ok(Self::ACTIVE),
+                    "INACTIVE" => ::std::result::Result::Ok(Self::INACTIVE),
+                    _ => {
+                        s.parse::<i32>()
+                            .map(Self::from)
+                            .map_err(|_| ::pilota::EnumConvertError::InvalidNum(
+                                s.to_string(),
+                                "Status",
+                            ))
+                    }
+                }
+            }
+        }
+        impl ::pilota::thrift::Message for Status {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_i32(self.inner())?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let value = __protocol.read_i32()?;
+                ::std::result::Result::Ok(
+                    ::std::convert::TryFrom::try_from(value)
+                        .map_err(|err| ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            format!("invalid enum value for Status, value: {}", value),
+                        ))?,
+                )
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let value = __protocol.read_i32().await?;
+                    ::std::result::Result::Ok(
+                        ::std::convert::TryFrom::try_from(value)
+                            .map_err(|err| ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("invalid enum value for Status, value: {}", value),
+                            ))?,
+                    )
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.i32_len(self.inner())
+            }
+        }
+        impl ::pilota::pb::Message for Point {
+            #[inline]
+            fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
+                0
+                    + {
+                        let value = &self.x;
+                        ::pilota::pb::encoding::double::encoded_len(ctx, 1, value)
+                    }
+                    + {
+                        let value = &self.y;
+                        ::pilota::pb::encoding::double::encoded_len(ctx, 2, value)
+                    }
+            }
+            #[allow(unused_variables)]
+            fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
+                {
+                    let value = &self.x;
+                    ::pilota::pb::encoding::double::encode(1, value, buf);
+                }
+                {
+                    let value = &self.y;
+                    ::pilota::pb::encoding::double::encode(2, value, buf);
+                }
+            }
+            #[allow(unused_variables)]
+            fn merge_field(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::pb::encoding::WireType,
+                buf: &mut ::pilota::Bytes,
+                ctx: &mut ::pilota::pb::encoding::DecodeContext,
+                is_root: bool,
+            ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
+                const STRUCT_NAME: &'static str = stringify!(Point);
+                match tag {
+                    1 => {
+                        let value = &mut self.x;
+                        ::pilota::pb::encoding::double::merge(wire_type, value, buf, ctx)
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(x));
+                                error
+                            })
+                    }
+                    2 => {
+                        let value = &mut self.y;
+                        ::pilota::pb::encoding::double::merge(wire_type, value, buf, ctx)
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(y));
+                                error
+                            })
+                    }
+                    _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct Point {
+            pub x: f64,
+            pub y: f64,
+        }
+        impl ::pilota::thrift::Message for Point {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "Point",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_double_field(1, *&self.x)?;
+                __protocol.write_double_field(2, *&self.y)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Double => {
+                                var_1 = Some(__protocol.read_double()?);
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Double => {
+                                var_2 = Some(__protocol.read_double()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `Point` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field x is required".to_string(),
+                        ),
+                    )
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field y is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { x: var_1, y: var_2 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Double => {
+                                    var_1 = Some(__protocol.read_double().await?);
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Double => {
+                                    var_2 = Some(__protocol.read_double().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `Point` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field x is required".to_string(),
+                            ),
+                        )
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field y is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { x: var_1, y: var_2 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Point",
+                        },
+                    ) + __protocol.double_field_len(Some(1), *&self.x)
+                    + __protocol.double_field_len(Some(2), *&self.y)
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::pilota::pb::Message for User {
+            #[inline]
+            fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
+                0
+                    + {
+                        let value = &self.id;
+                        ::pilota::pb::encoding::int64::encoded_len(ctx, 1, value)
+                    }
+                    + {
+                        let value = &self.name;
+                        ::pilota::pb::encoding::faststr::encoded_len(ctx, 2, value)
+                    }
+                    + self
+                        .status
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| ::pilota::pb::encoding::int32::encoded_len(
+                                ctx,
+                                3,
+                                value,
+                            ),
+                        )
+                    + self
+                        .path
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| ::pilota::pb::encoding::message::encoded_len_repeated(
+                                ctx,
+                                4,
+                                value,
+                            ),
+                        )
+                    + self
+                        .scores
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| ::pilota::pb::encoding::sint32::encoded_len_packed(
+                                ctx,
+                                5,
+                                value,
+                            ),
+                        )
+                    + self
+                        .places
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| ::pilota::pb::encoding::hash_map::encoded_len(
+                                ctx,
+                                ::pilota::pb::encoding::faststr::encoded_len,
+                                ::pilota::pb::encoding::message::encoded_len,
+                                6,
+                                value,
+                            ),
+                        )
+                    + self
+                        .avatar
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| ::pilota::pb::encoding::bytes::encoded_len(
+                                ctx,
+                                7,
+                                value,
+                            ),
+                        )
+            }
+            #[allow(unused_variables)]
+            fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
+                {
+                    let value = &self.id;
+                    ::pilota::pb::encoding::int64::encode(1, value, buf);
+                }
+                {
+                    let value = &self.name;
+                    ::pilota::pb::encoding::faststr::encode(2, value, buf);
+                }
+                if let Some(value) = &self.status {
+                    ::pilota::pb::encoding::int32::encode(3, value, buf);
+                }
+                if let Some(value) = &self.path {
+                    ::pilota::pb::encoding::message::encode_repeated(4, value, buf);
+                }
+                if let Some(value) = &self.scores {
+                    ::pilota::pb::encoding::sint32::encode_packed(5, value, buf);
+                }
+                if let Some(value) = &self.places {
+                    ::pilota::pb::encoding::hash_map::encode(
+                        ::pilota::pb::encoding::faststr::encode,
+                        ::pilota::pb::encoding::faststr::encoded_len,
+                        ::pilota::pb::encoding::message::encode,
+                        ::pilota::pb::encoding::message::encoded_len,
+                        6,
+                        value,
+                        buf,
+                    );
+                }
+                if let Some(value) = &self.avatar {
+                    ::pilota::pb::encoding::bytes::encode(7, value, buf);
+                }
+            }
+            #[allow(unused_variables)]
+            fn merge_field(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::pb::encoding::WireType,
+                buf: &mut ::pilota::Bytes,
+                ctx: &mut ::pilota::pb::encoding::DecodeContext,
+                is_root: bool,
+            ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
+                const STRUCT_NAME: &'static str = stringify!(User);
+                match tag {
+                    1 => {
+                        let value = &mut self.id;
+                        ::pilota::pb::encoding::int64::merge(wire_type, value, buf, ctx)
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(id));
+                                error
+                            })
+                    }
+                    2 => {
+                        let value = &mut self.name;
+                        ::pilota::pb::encoding::faststr::merge(
+                                wire_type,
+                                value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(name));
+                                error
+                            })
+                    }
+                    3 => {
+                        let value = self
+                            .status
+                            .get_or_insert_with(::core::default::Default::default);
+                        ::pilota::pb::encoding::int32::merge(wire_type, value, buf, ctx)
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(status));
+                                error
+                            })
+                    }
+                    4 => {
+                        let value = self
+                            .path
+                            .get_or_insert_with(::core::default::Default::default);
+                        ::pilota::pb::encoding::message::merge_repeated(
+                                wire_type,
+                                value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(path));
+                                error
+                            })
+                    }
+                    5 => {
+                        let value = self
+                            .scores
+                            .get_or_insert_with(::core::default::Default::default);
+                        ::pilota::pb::encoding::sint32::merge_repeated(
+                                wire_type,
+                                value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(scores));
+                                error
+                            })
+                    }
+                    6 => {
+                        let value = self
+                            .places
+                            .get_or_insert_with(::core::default::Default::default);
+                        ::pilota::pb::encoding::hash_map::merge(
+                                ::pilota::pb::encoding::faststr::merge,
+                                ::pilota::pb::encoding::message::merge,
+                                value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(places));
+                                error
+                            })
+                    }
+                    7 => {
+                        let value = self
+                            .avatar
+                            .get_or_insert_with(::core::default::Default::default);
+                        ::pilota::pb::encoding::bytes::merge(wire_type, value, buf, ctx)
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(avatar));
+                                error
+                            })
+                    }
+                    _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct User {
+            pub id: i64,
+            pub name: ::pilota::FastStr,
+            pub status: ::std::option::Option<Status>,
+            pub path: ::std::option::Option<::std::vec::Vec<Point>>,
+            pub scores: ::std::option::Option<::std::vec::Vec<i32>>,
+            pub places: ::std::option::Option<
+                ::pilota::AHashMap<::pilota::FastStr, Point>,
+            >,
+            pub avatar: ::std::option::Option<::pilota::Bytes>,
+            pub legacy_token: ::std::option::Option<::pilota::FastStr>,
+        }
+        impl ::pilota::thrift::Message for User {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "User",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.id)?;
+                __protocol.write_faststr_field(2, (&self.name).clone())?;
+                if let Some(value) = self.status.as_ref() {
+                    __protocol.write_i32_field(3, (value).inner())?;
+                }
+                if let Some(value) = self.path.as_ref() {
+                    __protocol
+                        .write_list_field(
+                            4,
+                            ::pilota::thrift::TType::Struct,
+                            &value,
+                            |__protocol, val| {
+                                __protocol.write_struct(val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                }
+                if let Some(value) = self.scores.as_ref() {
+                    __protocol
+                        .write_list_field(
+                            5,
+                            ::pilota::thrift::TType::I32,
+                            &value,
+                            |__protocol, val| {
+                                __protocol.write_i32(*val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                }
+                if let Some(value) = self.places.as_ref() {
+                    __protocol
+                        .write_map_field(
+                            6,
+                            ::pilota::thrift::TType::Binary,
+                            ::pilota::thrift::TType::Struct,
+                            &value,
+                            |__protocol, key| {
+                                __protocol.write_faststr((key).clone())?;
+                                ::std::result::Result::Ok(())
+                            },
+                            |__protocol, val| {
+                                __protocol.write_struct(val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                }
+                if let Some(value) = self.avatar.as_ref() {
+                    __protocol.write_bytes_field(7, (value).clone())?;
+                }
+                if let Some(value) = self.legacy_token.as_ref() {
+                    __protocol.write_faststr_field(8, (value).clone())?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut var_5 = None;
+                let mut var_6 = None;
+                let mut var_7 = None;
+                let mut var_8 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I64 => {
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Binary => {
+                                var_2 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(
+                                3,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                var_3 = Some(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                            }
+                            Some(
+                                4,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::List => {
+                                var_4 = Some({
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(
+                                        list_ident.size,
+                                    );
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(__protocol)?);
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(
+                                5,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::List => {
+                                var_5 = Some({
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(
+                                        list_ident.size,
+                                    );
+                                    for _ in 0..list_ident.size {
+                                        val.push(__protocol.read_i32()?);
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(
+                                6,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Map => {
+                                var_6 = Some({
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::pilota::AHashMap::with_capacity(
+                                        map_ident.size,
+                                    );
+                                    for _ in 0..map_ident.size {
+                                        val.insert(
+                                            __protocol.read_faststr()?,
+                                            ::pilota::thrift::Message::decode(__protocol)?,
+                                        );
+                                    }
+                                    __protocol.read_map_end()?;
+                                    val
+                                });
+                            }
+                            Some(
+                                7,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Binary => {
+                                var_7 = Some(__protocol.read_bytes()?);
+                            }
+                            Some(
+                                8,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Binary => {
+                                var_8 = Some(__protocol.read_faststr()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `User` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    )
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self {
+                    id: var_1,
+                    name: var_2,
+                    status: var_3,
+                    path: var_4,
+                    scores: var_5,
+                    places: var_6,
+                    avatar: var_7,
+                    legacy_token: var_8,
+                };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+                    let mut var_6 = None;
+                    let mut var_7 = None;
+                    let mut var_8 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I64 => {
+                                    var_1 = Some(__protocol.read_i64().await?);
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Binary => {
+                                    var_2 = Some(__protocol.read_faststr().await?);
+                                }
+                                Some(
+                                    3,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    var_3 = Some(
+                                        <Status as ::pilota::thrift::Message>::decode_async(
+                                                __protocol,
+                                            )
+                                            .await?,
+                                    );
+                                }
+                                Some(
+                                    4,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::List => {
+                                    var_4 = Some({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val = ::std::vec::Vec::with_capacity(
+                                            list_ident.size,
+                                        );
+                                        for _ in 0..list_ident.size {
+                                            val.push(
+                                                <Point as ::pilota::thrift::Message>::decode_async(
+                                                        __protocol,
+                                                    )
+                                                    .await?,
+                                            );
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(
+                                    5,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::List => {
+                                    var_5 = Some({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val = ::std::vec::Vec::with_capacity(
+                                            list_ident.size,
+                                        );
+                                        for _ in 0..list_ident.size {
+                                            val.push(__protocol.read_i32().await?);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(
+                                    6,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Map => {
+                                    var_6 = Some({
+                                        let map_ident = __protocol.read_map_begin().await?;
+                                        let mut val = ::pilota::AHashMap::with_capacity(
+                                            map_ident.size,
+                                        );
+                                        for _ in 0..map_ident.size {
+                                            val.insert(
+                                                __protocol.read_faststr().await?,
+                                                <Point as ::pilota::thrift::Message>::decode_async(
+                                                        __protocol,
+                                                    )
+                                                    .await?,
+                                            );
+                                        }
+                                        __protocol.read_map_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(
+                                    7,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Binary => {
+                                    var_7 = Some(__protocol.read_bytes().await?);
+                                }
+                                Some(
+                                    8,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Binary => {
+                                    var_8 = Some(__protocol.read_faststr().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `User` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        )
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self {
+                        id: var_1,
+                        name: var_2,
+                        status: var_3,
+                        path: var_4,
+                        scores: var_5,
+                        places: var_6,
+                        avatar: var_7,
+                        legacy_token: var_8,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "User",
+                        },
+                    ) + __protocol.i64_field_len(Some(1), *&self.id)
+                    + __protocol.faststr_field_len(Some(2), &self.name)
+                    + self
+                        .status
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| __protocol.i32_field_len(Some(3), (value).inner()),
+                        )
+                    + self
+                        .path
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol
+                                    .list_field_len(
+                                        Some(4),
+                                        ::pilota::thrift::TType::Struct,
+                                        value,
+                                        |__protocol, el| { __protocol.struct_len(el) },
+                                    )
+                            },
+                        )
+                    + self
+                        .scores
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol
+                                    .list_field_len(
+                                        Some(5),
+                                        ::pilota::thrift::TType::I32,
+                                        value,
+                                        |__protocol, el| { __protocol.i32_len(*el) },
+                                    )
+                            },
+                        )
+                    + self
+                        .places
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol
+                                    .map_field_len(
+                                        Some(6),
+                                        ::pilota::thrift::TType::Binary,
+                                        ::pilota::thrift::TType::Struct,
+                                        value,
+                                        |__protocol, key| { __protocol.faststr_len(key) },
+                                        |__protocol, val| { __protocol.struct_len(val) },
+                                    )
+                            },
+                        )
+                    + self
+                        .avatar
+                        .as_ref()
+                        .map_or(0, |value| __protocol.bytes_field_len(Some(7), value))
+                    + self
+                        .legacy_token
+                        .as_ref()
+                        .map_or(0, |value| __protocol.faststr_field_len(Some(8), value))
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct ThriftOnly {
+            pub value: i32,
+        }
+        impl ::pilota::thrift::Message for ThriftOnly {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "ThriftOnly",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i32_field(1, *&self.value)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                var_1 = Some(__protocol.read_i32()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `ThriftOnly` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field value is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { value: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    var_1 = Some(__protocol.read_i32().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `ThriftOnly` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field value is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { value: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "ThriftOnly",
+                        },
+                    ) + __protocol.i32_field_len(Some(1), *&self.value)
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+namespace rs pb_codec
+
+enum Status {
+    UNKNOWN = 0,
+    ACTIVE = 1,
+    INACTIVE = 2,
+}
+
+struct Point {
+    1: required double x,
+    2: required double y,
+}
+
+struct User {
+    1: required i64 id,
+    2: required string name,
+    3: optional Status status,
+    4: list<Point> path,
+    5: list<i32> scores,
+    6: map<string, Point> places,
+    7: optional binary avatar,
+    8: optional string legacy_token,
+}
+
+struct ThriftOnly {
+    1: required i32 value,
+}
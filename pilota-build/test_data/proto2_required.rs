@@ -0,0 +1,264 @@
+pub mod proto2_required {
+    #![allow(warnings, clippy::all)]
+    use ::pilota::{Buf as _, BufMut as _};
+    pub mod proto2_required {
+        use ::pilota::{Buf as _, BufMut as _};
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct Item {
+            pub sku: ::std::option::Option<::pilota::FastStr>,
+            pub count: ::std::option::Option<i32>,
+        }
+        impl ::pilota::pb::Message for Item {
+            #[inline]
+            fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
+                0
+                    + self
+                        .sku
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| ::pilota::pb::encoding::faststr::encoded_len(
+                                ctx,
+                                1,
+                                value,
+                            ),
+                        )
+                    + self
+                        .count
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| ::pilota::pb::encoding::int32::encoded_len(
+                                ctx,
+                                2,
+                                value,
+                            ),
+                        )
+            }
+            #[allow(unused_variables)]
+            fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
+                if let Some(_pilota_inner_value) = self.sku.as_ref() {
+                    ::pilota::pb::encoding::faststr::encode(1, _pilota_inner_value, buf);
+                }
+                if let Some(_pilota_inner_value) = self.count.as_ref() {
+                    ::pilota::pb::encoding::int32::encode(2, _pilota_inner_value, buf);
+                }
+            }
+            #[allow(unused_variables)]
+            fn merge_field(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::pb::encoding::WireType,
+                buf: &mut ::pilota::Bytes,
+                ctx: &mut ::pilota::pb::encoding::DecodeContext,
+                is_root: bool,
+            ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
+                const STRUCT_NAME: &'static str = stringify!(Item);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.sku;
+                        ::pilota::pb::encoding::faststr::merge(
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(sku));
+                                error
+                            })
+                    }
+                    2 => {
+                        let mut _inner_pilota_value = &mut self.count;
+                        ::pilota::pb::encoding::int32::merge(
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(count));
+                                error
+                            })
+                    }
+                    _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+            fn missing_required_fields(
+                &self,
+                missing: &mut ::std::vec::Vec<&'static str>,
+            ) {
+                if self.sku.is_none() {
+                    missing.push("Item.sku");
+                }
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct Order {
+            pub id: ::std::option::Option<i64>,
+            pub item: ::std::option::Option<Item>,
+            pub extras: ::std::vec::Vec<Item>,
+            pub note: ::std::option::Option<::pilota::FastStr>,
+        }
+        impl ::pilota::pb::Message for Order {
+            #[inline]
+            fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
+                0
+                    + self
+                        .id
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| ::pilota::pb::encoding::int64::encoded_len(
+                                ctx,
+                                1,
+                                value,
+                            ),
+                        )
+                    + self
+                        .item
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |msg| ::pilota::pb::encoding::message::encoded_len(
+                                ctx,
+                                2,
+                                msg,
+                            ),
+                        )
+                    + ::pilota::pb::encoding::message::encoded_len_repeated(
+                        ctx,
+                        3,
+                        &self.extras,
+                    )
+                    + self
+                        .note
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| ::pilota::pb::encoding::faststr::encoded_len(
+                                ctx,
+                                4,
+                                value,
+                            ),
+                        )
+            }
+            #[allow(unused_variables)]
+            fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
+                if let Some(_pilota_inner_value) = self.id.as_ref() {
+                    ::pilota::pb::encoding::int64::encode(1, _pilota_inner_value, buf);
+                }
+                if let Some(_pilota_inner_value) = self.item.as_ref() {
+                    ::pilota::pb::encoding::message::encode(2, _pilota_inner_value, buf);
+                }
+                for msg in &self.extras {
+                    ::pilota::pb::encoding::message::encode(3, msg, buf);
+                }
+                if let Some(_pilota_inner_value) = self.note.as_ref() {
+                    ::pilota::pb::encoding::faststr::encode(4, _pilota_inner_value, buf);
+                }
+            }
+            #[allow(unused_variables)]
+            fn merge_field(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::pb::encoding::WireType,
+                buf: &mut ::pilota::Bytes,
+                ctx: &mut ::pilota::pb::encoding::DecodeContext,
+                is_root: bool,
+            ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
+                const STRUCT_NAME: &'static str = stringify!(Order);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.id;
+                        ::pilota::pb::encoding::int64::merge(
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(id));
+                                error
+                            })
+                    }
+                    2 => {
+                        let mut _inner_pilota_value = &mut self.item;
+                        ::pilota::pb::encoding::message::merge(
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(item));
+                                error
+                            })
+                    }
+                    3 => {
+                        let mut _inner_pilota_value = &mut self.extras;
+                        ::pilota::pb::encoding::message::merge_repeated(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(extras));
+                                error
+                            })
+                    }
+                    4 => {
+                        let mut _inner_pilota_value = &mut self.note;
+                        ::pilota::pb::encoding::faststr::merge(
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(note));
+                                error
+                            })
+                    }
+                    _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+            fn missing_required_fields(
+                &self,
+                missing: &mut ::std::vec::Vec<&'static str>,
+            ) {
+                if self.id.is_none() {
+                    missing.push("Order.id");
+                }
+                match &self.item {
+                    Some(value) => {
+                        ::pilota::pb::Message::missing_required_fields(value, missing);
+                    }
+                    None => {
+                        missing.push("Order.item");
+                    }
+                }
+                {
+                    let value = &self.extras;
+                    for value in value {
+                        ::pilota::pb::Message::missing_required_fields(value, missing);
+                    }
+                }
+            }
+        }
+    }
+}
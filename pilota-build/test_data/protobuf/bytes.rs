@@ -1,25 +1,32 @@
 pub mod bytes {
     #![allow(warnings, clippy::all)]
     use ::pilota::{Buf as _, BufMut as _};
-    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+    #[derive(PartialOrd)]
+    #[derive(Hash, Eq, Ord)]
+    #[derive(Debug)]
+    #[derive(Default)]
+    #[derive(Clone, PartialEq)]
     pub struct A {
         pub a: ::std::option::Option<::pilota::Bytes>,
     }
     impl ::pilota::pb::Message for A {
         #[inline]
         fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
-            0 + self.a.as_ref().map_or(0, |value| {
-                ::pilota::pb::encoding::bytes::encoded_len(ctx, 1, value)
-            })
+            0
+                + self
+                    .a
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::bytes::encoded_len(ctx, 1, value),
+                    )
         }
-
         #[allow(unused_variables)]
         fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
             if let Some(_pilota_inner_value) = self.a.as_ref() {
                 ::pilota::pb::encoding::bytes::encode(1, _pilota_inner_value, buf);
-            };
+            }
         }
-
         #[allow(unused_variables)]
         fn merge_field(
             &mut self,
@@ -30,20 +37,20 @@ pub mod bytes {
             is_root: bool,
         ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
             const STRUCT_NAME: &'static str = stringify!(A);
-
             match tag {
                 1 => {
                     let mut _inner_pilota_value = &mut self.a;
                     ::pilota::pb::encoding::bytes::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(a));
-                        error
-                    })
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(a));
+                            error
+                        })
                 }
                 _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
             }
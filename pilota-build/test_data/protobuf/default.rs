@@ -1,17 +1,19 @@
 pub mod default {
     #![allow(warnings, clippy::all)]
     use ::pilota::{Buf as _, BufMut as _};
-    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+    #[derive(PartialOrd)]
+    #[derive(Hash, Eq, Ord)]
+    #[derive(Debug)]
+    #[derive(Default)]
+    #[derive(Clone, PartialEq)]
     pub struct Outer {}
     impl ::pilota::pb::Message for Outer {
         #[inline]
         fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
             0
         }
-
         #[allow(unused_variables)]
         fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {}
-
         #[allow(unused_variables)]
         fn merge_field(
             &mut self,
@@ -26,50 +28,6 @@ pub mod default {
             }
         }
     }
-    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
-    #[repr(transparent)]
-    pub struct Color(i32);
-
-    impl Color {
-        pub const RED: Self = Self(0);
-        pub const GREEN: Self = Self(1);
-        pub const BLUE: Self = Self(2);
-
-        pub fn inner(&self) -> i32 {
-            self.0
-        }
-
-        pub fn to_string(&self) -> ::std::string::String {
-            match self {
-                Self(0) => ::std::string::String::from("RED"),
-                Self(1) => ::std::string::String::from("GREEN"),
-                Self(2) => ::std::string::String::from("BLUE"),
-                Self(val) => val.to_string(),
-            }
-        }
-
-        pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
-            match value {
-                0 => Some(Self::RED),
-                1 => Some(Self::GREEN),
-                2 => Some(Self::BLUE),
-                _ => None,
-            }
-        }
-    }
-
-    impl ::std::convert::From<i32> for Color {
-        fn from(value: i32) -> Self {
-            Self(value)
-        }
-    }
-
-    impl ::std::convert::From<Color> for i32 {
-        fn from(value: Color) -> i32 {
-            value.0
-        }
-    }
-
     impl ::std::default::Default for Defaults {
         fn default() -> Self {
             Defaults {
@@ -86,86 +44,132 @@ pub mod default {
             }
         }
     }
-    #[derive(PartialOrd, Debug, Clone, PartialEq)]
+    #[derive(PartialOrd)]
+    #[derive(Debug)]
+    #[derive(Clone, PartialEq)]
     pub struct Defaults {
         pub a: ::std::option::Option<i32>,
-
         pub a64: ::std::option::Option<i64>,
-
         pub b: ::std::option::Option<f32>,
-
         pub c: ::std::option::Option<f64>,
-
         pub d: ::std::option::Option<bool>,
-
         pub e: ::std::option::Option<::pilota::FastStr>,
-
         pub color: ::std::option::Option<Color>,
-
         pub inner: outer::Inner,
-
         pub zero: ::std::option::Option<i32>,
-
         pub dzero: ::std::option::Option<f64>,
     }
     impl ::pilota::pb::Message for Defaults {
         #[inline]
         fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
-            0 + self.a.as_ref().map_or(0, |value| {
-                ::pilota::pb::encoding::int32::encoded_len(ctx, 1, value)
-            }) + self.a64.as_ref().map_or(0, |value| {
-                ::pilota::pb::encoding::int64::encoded_len(ctx, 2, value)
-            }) + self.b.as_ref().map_or(0, |value| {
-                ::pilota::pb::encoding::float::encoded_len(ctx, 3, value)
-            }) + self.c.as_ref().map_or(0, |value| {
-                ::pilota::pb::encoding::double::encoded_len(ctx, 4, value)
-            }) + self.d.as_ref().map_or(0, |value| {
-                ::pilota::pb::encoding::bool::encoded_len(ctx, 5, value)
-            }) + self.e.as_ref().map_or(0, |value| {
-                ::pilota::pb::encoding::faststr::encoded_len(ctx, 6, value)
-            }) + self.color.as_ref().map_or(0, |value| {
-                ::pilota::pb::encoding::int32::encoded_len(ctx, 7, value)
-            }) + ::pilota::pb::encoding::message::encoded_len(ctx, 8, &self.inner)
-                + self.zero.as_ref().map_or(0, |value| {
-                    ::pilota::pb::encoding::int32::encoded_len(ctx, 9, value)
-                })
-                + self.dzero.as_ref().map_or(0, |value| {
-                    ::pilota::pb::encoding::double::encoded_len(ctx, 10, value)
-                })
+            0
+                + self
+                    .a
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::int32::encoded_len(ctx, 1, value),
+                    )
+                + self
+                    .a64
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::int64::encoded_len(ctx, 2, value),
+                    )
+                + self
+                    .b
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::float::encoded_len(ctx, 3, value),
+                    )
+                + self
+                    .c
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::double::encoded_len(
+                            ctx,
+                            4,
+                            value,
+                        ),
+                    )
+                + self
+                    .d
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::bool::encoded_len(ctx, 5, value),
+                    )
+                + self
+                    .e
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::faststr::encoded_len(
+                            ctx,
+                            6,
+                            value,
+                        ),
+                    )
+                + self
+                    .color
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::int32::encoded_len(ctx, 7, value),
+                    ) + ::pilota::pb::encoding::message::encoded_len(ctx, 8, &self.inner)
+                + self
+                    .zero
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::int32::encoded_len(ctx, 9, value),
+                    )
+                + self
+                    .dzero
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::double::encoded_len(
+                            ctx,
+                            10,
+                            value,
+                        ),
+                    )
         }
-
         #[allow(unused_variables)]
         fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
             if let Some(_pilota_inner_value) = self.a.as_ref() {
                 ::pilota::pb::encoding::int32::encode(1, _pilota_inner_value, buf);
-            };
+            }
             if let Some(_pilota_inner_value) = self.a64.as_ref() {
                 ::pilota::pb::encoding::int64::encode(2, _pilota_inner_value, buf);
-            };
+            }
             if let Some(_pilota_inner_value) = self.b.as_ref() {
                 ::pilota::pb::encoding::float::encode(3, _pilota_inner_value, buf);
-            };
+            }
             if let Some(_pilota_inner_value) = self.c.as_ref() {
                 ::pilota::pb::encoding::double::encode(4, _pilota_inner_value, buf);
-            };
+            }
             if let Some(_pilota_inner_value) = self.d.as_ref() {
                 ::pilota::pb::encoding::bool::encode(5, _pilota_inner_value, buf);
-            };
+            }
             if let Some(_pilota_inner_value) = self.e.as_ref() {
                 ::pilota::pb::encoding::faststr::encode(6, _pilota_inner_value, buf);
-            };
+            }
             if let Some(_pilota_inner_value) = self.color.as_ref() {
                 ::pilota::pb::encoding::int32::encode(7, _pilota_inner_value, buf);
-            };
+            }
             ::pilota::pb::encoding::message::encode(8, (&self.inner), buf);
             if let Some(_pilota_inner_value) = self.zero.as_ref() {
                 ::pilota::pb::encoding::int32::encode(9, _pilota_inner_value, buf);
-            };
+            }
             if let Some(_pilota_inner_value) = self.dzero.as_ref() {
                 ::pilota::pb::encoding::double::encode(10, _pilota_inner_value, buf);
-            };
+            }
         }
-
         #[allow(unused_variables)]
         fn merge_field(
             &mut self,
@@ -176,102 +180,113 @@ pub mod default {
             is_root: bool,
         ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
             const STRUCT_NAME: &'static str = stringify!(Defaults);
-
             match tag {
                 1 => {
                     let mut _inner_pilota_value = &mut self.a;
                     ::pilota::pb::encoding::int32::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(a));
-                        error
-                    })
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(a));
+                            error
+                        })
                 }
                 2 => {
                     let mut _inner_pilota_value = &mut self.a64;
                     ::pilota::pb::encoding::int64::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(a64));
-                        error
-                    })
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(a64));
+                            error
+                        })
                 }
                 3 => {
                     let mut _inner_pilota_value = &mut self.b;
                     ::pilota::pb::encoding::float::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(b));
-                        error
-                    })
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(b));
+                            error
+                        })
                 }
                 4 => {
                     let mut _inner_pilota_value = &mut self.c;
                     ::pilota::pb::encoding::double::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(c));
-                        error
-                    })
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(c));
+                            error
+                        })
                 }
                 5 => {
                     let mut _inner_pilota_value = &mut self.d;
                     ::pilota::pb::encoding::bool::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(d));
-                        error
-                    })
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(d));
+                            error
+                        })
                 }
                 6 => {
                     let mut _inner_pilota_value = &mut self.e;
                     ::pilota::pb::encoding::faststr::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(e));
-                        error
-                    })
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(e));
+                            error
+                        })
                 }
                 7 => {
                     let mut _inner_pilota_value = &mut self.color;
                     ::pilota::pb::encoding::int32::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(color));
-                        error
-                    })
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(color));
+                            error
+                        })
                 }
                 8 => {
                     let mut _inner_pilota_value = &mut self.inner;
-                    ::pilota::pb::encoding::message::merge(wire_type, _inner_pilota_value, buf, ctx)
+                    ::pilota::pb::encoding::message::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
                         .map_err(|mut error| {
                             error.push(STRUCT_NAME, stringify!(inner));
                             error
@@ -280,63 +295,138 @@ pub mod default {
                 9 => {
                     let mut _inner_pilota_value = &mut self.zero;
                     ::pilota::pb::encoding::int32::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(zero));
-                        error
-                    })
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(zero));
+                            error
+                        })
                 }
                 10 => {
                     let mut _inner_pilota_value = &mut self.dzero;
                     ::pilota::pb::encoding::double::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(dzero));
-                        error
-                    })
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(dzero));
+                            error
+                        })
                 }
                 _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
             }
         }
     }
-
+    #[derive(PartialOrd)]
+    #[derive(Hash, Eq, Ord)]
+    #[derive(Debug)]
+    #[derive(Default)]
+    #[derive(Clone, PartialEq, Copy)]
+    #[repr(transparent)]
+    pub struct Color(i32);
+    impl Color {
+        pub const RED: Self = Self(0);
+        pub const GREEN: Self = Self(1);
+        pub const BLUE: Self = Self(2);
+        pub fn inner(&self) -> i32 {
+            self.0
+        }
+        pub fn as_str(&self) -> ::std::option::Option<&'static str> {
+            match self {
+                Self(0) => ::std::option::Option::Some("RED"),
+                Self(1) => ::std::option::Option::Some("GREEN"),
+                Self(2) => ::std::option::Option::Some("BLUE"),
+                Self(_) => ::std::option::Option::None,
+            }
+        }
+        pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
+            match value {
+                0 => Some(Self::RED),
+                1 => Some(Self::GREEN),
+                2 => Some(Self::BLUE),
+                _ => None,
+            }
+        }
+    }
+    impl ::std::convert::From<i32> for Color {
+        fn from(value: i32) -> Self {
+            Self(value)
+        }
+    }
+    impl ::std::convert::From<Color> for i32 {
+        fn from(value: Color) -> i32 {
+            value.0
+        }
+    }
+    impl ::std::fmt::Display for Color {
+        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            match self.as_str() {
+                ::std::option::Option::Some(s) => f.write_str(s),
+                ::std::option::Option::None => ::std::fmt::Display::fmt(&self.inner(), f),
+            }
+        }
+    }
+    impl ::std::str::FromStr for Color {
+        type Err = ::pilota::EnumConvertError<::std::string::String>;
+        fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+            match s {
+                "RED" => ::std::result::Result::Ok(Self::RED),
+                "GREEN" => ::std::result::Result::Ok(Self::GREEN),
+                "BLUE" => ::std::result::Result::Ok(Self::BLUE),
+                _ => {
+                    s.parse::<i32>()
+                        .map(Self::from)
+                        .map_err(|_| ::pilota::EnumConvertError::InvalidNum(
+                            s.to_string(),
+                            "Color",
+                        ))
+                }
+            }
+        }
+    }
     pub mod outer {
         use ::pilota::{Buf as _, BufMut as _};
-
         impl ::std::default::Default for Inner {
             fn default() -> Self {
-                Inner {
-                    st: Some(Status::OK),
-                }
+                Inner { st: Some(Status::OK) }
             }
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Clone, PartialEq)]
         pub struct Inner {
             pub st: ::std::option::Option<Status>,
         }
         impl ::pilota::pb::Message for Inner {
             #[inline]
             fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
-                0 + self.st.as_ref().map_or(0, |value| {
-                    ::pilota::pb::encoding::int32::encoded_len(ctx, 1, value)
-                })
+                0
+                    + self
+                        .st
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| ::pilota::pb::encoding::int32::encoded_len(
+                                ctx,
+                                1,
+                                value,
+                            ),
+                        )
             }
-
             #[allow(unused_variables)]
             fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
                 if let Some(_pilota_inner_value) = self.st.as_ref() {
                     ::pilota::pb::encoding::int32::encode(1, _pilota_inner_value, buf);
-                };
+                }
             }
-
             #[allow(unused_variables)]
             fn merge_field(
                 &mut self,
@@ -347,46 +437,45 @@ pub mod default {
                 is_root: bool,
             ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
                 const STRUCT_NAME: &'static str = stringify!(Inner);
-
                 match tag {
                     1 => {
                         let mut _inner_pilota_value = &mut self.st;
                         ::pilota::pb::encoding::int32::merge(
-                            wire_type,
-                            _inner_pilota_value
-                                .get_or_insert_with(::core::default::Default::default),
-                            buf,
-                            ctx,
-                        )
-                        .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(st));
-                            error
-                        })
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(st));
+                                error
+                            })
                     }
                     _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
                 }
             }
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq, Copy)]
         #[repr(transparent)]
         pub struct Status(i32);
-
         impl Status {
             pub const UNKNOWN: Self = Self(0);
             pub const OK: Self = Self(1);
-
             pub fn inner(&self) -> i32 {
                 self.0
             }
-
-            pub fn to_string(&self) -> ::std::string::String {
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
                 match self {
-                    Self(0) => ::std::string::String::from("UNKNOWN"),
-                    Self(1) => ::std::string::String::from("OK"),
-                    Self(val) => val.to_string(),
+                    Self(0) => ::std::option::Option::Some("UNKNOWN"),
+                    Self(1) => ::std::option::Option::Some("OK"),
+                    Self(_) => ::std::option::Option::None,
                 }
             }
-
             pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
                 match value {
                     0 => Some(Self::UNKNOWN),
@@ -395,17 +484,42 @@ pub mod default {
                 }
             }
         }
-
         impl ::std::convert::From<i32> for Status {
             fn from(value: i32) -> Self {
                 Self(value)
             }
         }
-
         impl ::std::convert::From<Status> for i32 {
             fn from(value: Status) -> i32 {
                 value.0
             }
         }
+        impl ::std::fmt::Display for Status {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => {
+                        ::std::fmt::Display::fmt(&self.inner(), f)
+                    }
+                }
+            }
+        }
+        impl ::std::str::FromStr for Status {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "UNKNOWN" => ::std::result::Result::Ok(Self::UNKNOWN),
+                    "OK" => ::std::result::Result::Ok(Self::OK),
+                    _ => {
+                        s.parse::<i32>()
+                            .map(Self::from)
+                            .map_err(|_| ::pilota::EnumConvertError::InvalidNum(
+                                s.to_string(),
+                                "Status",
+                            ))
+                    }
+                }
+            }
+        }
     }
 }
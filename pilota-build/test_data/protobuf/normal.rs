@@ -1,7 +1,11 @@
 pub mod normal {
     #![allow(warnings, clippy::all)]
     use ::pilota::{Buf as _, BufMut as _};
-    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+    #[derive(PartialOrd)]
+    #[derive(Hash, Eq, Ord)]
+    #[derive(Debug)]
+    #[derive(Default)]
+    #[derive(Clone, PartialEq)]
     pub struct A {
         pub a: i32,
     }
@@ -10,12 +14,10 @@ pub mod normal {
         fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
             0 + ::pilota::pb::encoding::int32::encoded_len(ctx, 1, &self.a)
         }
-
         #[allow(unused_variables)]
         fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
             ::pilota::pb::encoding::int32::encode(1, &self.a, buf);
         }
-
         #[allow(unused_variables)]
         fn merge_field(
             &mut self,
@@ -26,11 +28,15 @@ pub mod normal {
             is_root: bool,
         ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
             const STRUCT_NAME: &'static str = stringify!(A);
-
             match tag {
                 1 => {
                     let mut _inner_pilota_value = &mut self.a;
-                    ::pilota::pb::encoding::int32::merge(wire_type, _inner_pilota_value, buf, ctx)
+                    ::pilota::pb::encoding::int32::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
                         .map_err(|mut error| {
                             error.push(STRUCT_NAME, stringify!(a));
                             error
@@ -40,25 +46,32 @@ pub mod normal {
             }
         }
     }
-    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-    pub struct SubMessage {
-        pub value: ::std::option::Option<::pilota::FastStr>,
+    #[derive(PartialOrd)]
+    #[derive(Hash, Eq, Ord)]
+    #[derive(Debug)]
+    #[derive(Default)]
+    #[derive(Clone, PartialEq)]
+    pub struct B {
+        pub a: ::std::option::Option<A>,
     }
-    impl ::pilota::pb::Message for SubMessage {
+    impl ::pilota::pb::Message for B {
         #[inline]
         fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
-            0 + self.value.as_ref().map_or(0, |value| {
-                ::pilota::pb::encoding::faststr::encoded_len(ctx, 2, value)
-            })
+            0
+                + self
+                    .a
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |msg| ::pilota::pb::encoding::message::encoded_len(ctx, 2, msg),
+                    )
         }
-
         #[allow(unused_variables)]
         fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
-            if let Some(_pilota_inner_value) = self.value.as_ref() {
-                ::pilota::pb::encoding::faststr::encode(2, _pilota_inner_value, buf);
-            };
+            if let Some(_pilota_inner_value) = self.a.as_ref() {
+                ::pilota::pb::encoding::message::encode(2, _pilota_inner_value, buf);
+            }
         }
-
         #[allow(unused_variables)]
         fn merge_field(
             &mut self,
@@ -68,76 +81,56 @@ pub mod normal {
             ctx: &mut ::pilota::pb::encoding::DecodeContext,
             is_root: bool,
         ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
-            const STRUCT_NAME: &'static str = stringify!(SubMessage);
-
+            const STRUCT_NAME: &'static str = stringify!(B);
             match tag {
                 2 => {
-                    let mut _inner_pilota_value = &mut self.value;
-                    ::pilota::pb::encoding::faststr::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(value));
-                        error
-                    })
+                    let mut _inner_pilota_value = &mut self.a;
+                    ::pilota::pb::encoding::message::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(a));
+                            error
+                        })
                 }
                 _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
             }
         }
     }
-
-    pub trait TestService {}
-    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-    pub struct ObjReq {
-        pub msg: ::std::option::Option<Message>,
-
-        pub msg_map: ::std::vec::Vec<obj_req::MsgMapEntry>,
-
-        pub sub_msgs: ::std::vec::Vec<SubMessage>,
-
-        pub msg_set: ::std::vec::Vec<Message>,
-
-        pub flag_msg: ::pilota::FastStr,
-
-        pub mock_cost: ::std::option::Option<::pilota::FastStr>,
+    #[derive(PartialOrd)]
+    #[derive(Hash, Eq, Ord)]
+    #[derive(Debug)]
+    #[derive(Default)]
+    #[derive(Clone, PartialEq)]
+    pub struct SubMessage {
+        pub value: ::std::option::Option<::pilota::FastStr>,
     }
-    impl ::pilota::pb::Message for ObjReq {
+    impl ::pilota::pb::Message for SubMessage {
         #[inline]
         fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
-            0 + self.msg.as_ref().map_or(0, |msg| {
-                ::pilota::pb::encoding::message::encoded_len(ctx, 1, msg)
-            }) + ::pilota::pb::encoding::message::encoded_len_repeated(ctx, 2, &self.msg_map)
-                + ::pilota::pb::encoding::message::encoded_len_repeated(ctx, 3, &self.sub_msgs)
-                + ::pilota::pb::encoding::message::encoded_len_repeated(ctx, 4, &self.msg_set)
-                + ::pilota::pb::encoding::faststr::encoded_len(ctx, 5, &self.flag_msg)
-                + self.mock_cost.as_ref().map_or(0, |value| {
-                    ::pilota::pb::encoding::faststr::encoded_len(ctx, 6, value)
-                })
+            0
+                + self
+                    .value
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::faststr::encoded_len(
+                            ctx,
+                            2,
+                            value,
+                        ),
+                    )
         }
-
         #[allow(unused_variables)]
         fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
-            if let Some(_pilota_inner_value) = self.msg.as_ref() {
-                ::pilota::pb::encoding::message::encode(1, _pilota_inner_value, buf);
-            }
-            for msg in &self.msg_map {
-                ::pilota::pb::encoding::message::encode(2, msg, buf);
-            }
-            for msg in &self.sub_msgs {
-                ::pilota::pb::encoding::message::encode(3, msg, buf);
-            }
-            for msg in &self.msg_set {
-                ::pilota::pb::encoding::message::encode(4, msg, buf);
+            if let Some(_pilota_inner_value) = self.value.as_ref() {
+                ::pilota::pb::encoding::faststr::encode(2, _pilota_inner_value, buf);
             }
-            ::pilota::pb::encoding::faststr::encode(5, &self.flag_msg, buf);
-            if let Some(_pilota_inner_value) = self.mock_cost.as_ref() {
-                ::pilota::pb::encoding::faststr::encode(6, _pilota_inner_value, buf);
-            };
         }
-
         #[allow(unused_variables)]
         fn merge_field(
             &mut self,
@@ -147,105 +140,67 @@ pub mod normal {
             ctx: &mut ::pilota::pb::encoding::DecodeContext,
             is_root: bool,
         ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
-            const STRUCT_NAME: &'static str = stringify!(ObjReq);
-
+            const STRUCT_NAME: &'static str = stringify!(SubMessage);
             match tag {
-                1 => {
-                    let mut _inner_pilota_value = &mut self.msg;
-                    ::pilota::pb::encoding::message::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(msg));
-                        error
-                    })
-                }
                 2 => {
-                    let mut _inner_pilota_value = &mut self.msg_map;
-                    ::pilota::pb::encoding::message::merge_repeated(
-                        wire_type,
-                        _inner_pilota_value,
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(msg_map));
-                        error
-                    })
-                }
-                3 => {
-                    let mut _inner_pilota_value = &mut self.sub_msgs;
-                    ::pilota::pb::encoding::message::merge_repeated(
-                        wire_type,
-                        _inner_pilota_value,
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(sub_msgs));
-                        error
-                    })
-                }
-                4 => {
-                    let mut _inner_pilota_value = &mut self.msg_set;
-                    ::pilota::pb::encoding::message::merge_repeated(
-                        wire_type,
-                        _inner_pilota_value,
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(msg_set));
-                        error
-                    })
-                }
-                5 => {
-                    let mut _inner_pilota_value = &mut self.flag_msg;
-                    ::pilota::pb::encoding::faststr::merge(wire_type, _inner_pilota_value, buf, ctx)
+                    let mut _inner_pilota_value = &mut self.value;
+                    ::pilota::pb::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
                         .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(flag_msg));
+                            error.push(STRUCT_NAME, stringify!(value));
                             error
                         })
                 }
-                6 => {
-                    let mut _inner_pilota_value = &mut self.mock_cost;
-                    ::pilota::pb::encoding::faststr::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(mock_cost));
-                        error
-                    })
-                }
                 _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
             }
         }
     }
-    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-    pub struct B {
-        pub a: ::std::option::Option<A>,
+    #[derive(PartialOrd)]
+    #[derive(Hash, Eq, Ord)]
+    #[derive(Debug)]
+    #[derive(Default)]
+    #[derive(Clone, PartialEq)]
+    pub struct Message {
+        pub uid: ::pilota::FastStr,
+        pub value: ::std::option::Option<::pilota::FastStr>,
+        pub sub_messages: ::std::vec::Vec<SubMessage>,
     }
-    impl ::pilota::pb::Message for B {
+    impl ::pilota::pb::Message for Message {
         #[inline]
         fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
-            0 + self.a.as_ref().map_or(0, |msg| {
-                ::pilota::pb::encoding::message::encoded_len(ctx, 2, msg)
-            })
+            0 + ::pilota::pb::encoding::faststr::encoded_len(ctx, 1, &self.uid)
+                + self
+                    .value
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::faststr::encoded_len(
+                            ctx,
+                            2,
+                            value,
+                        ),
+                    )
+                + ::pilota::pb::encoding::message::encoded_len_repeated(
+                    ctx,
+                    3,
+                    &self.sub_messages,
+                )
         }
-
         #[allow(unused_variables)]
         fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
-            if let Some(_pilota_inner_value) = self.a.as_ref() {
-                ::pilota::pb::encoding::message::encode(2, _pilota_inner_value, buf);
+            ::pilota::pb::encoding::faststr::encode(1, &self.uid, buf);
+            if let Some(_pilota_inner_value) = self.value.as_ref() {
+                ::pilota::pb::encoding::faststr::encode(2, _pilota_inner_value, buf);
+            }
+            for msg in &self.sub_messages {
+                ::pilota::pb::encoding::message::encode(3, msg, buf);
             }
         }
-
         #[allow(unused_variables)]
         fn merge_field(
             &mut self,
@@ -255,55 +210,122 @@ pub mod normal {
             ctx: &mut ::pilota::pb::encoding::DecodeContext,
             is_root: bool,
         ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
-            const STRUCT_NAME: &'static str = stringify!(B);
-
+            const STRUCT_NAME: &'static str = stringify!(Message);
             match tag {
+                1 => {
+                    let mut _inner_pilota_value = &mut self.uid;
+                    ::pilota::pb::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(uid));
+                            error
+                        })
+                }
                 2 => {
-                    let mut _inner_pilota_value = &mut self.a;
-                    ::pilota::pb::encoding::message::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(a));
-                        error
-                    })
+                    let mut _inner_pilota_value = &mut self.value;
+                    ::pilota::pb::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(value));
+                            error
+                        })
+                }
+                3 => {
+                    let mut _inner_pilota_value = &mut self.sub_messages;
+                    ::pilota::pb::encoding::message::merge_repeated(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(sub_messages));
+                            error
+                        })
                 }
                 _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
             }
         }
     }
-    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-    pub struct Message {
-        pub uid: ::pilota::FastStr,
-
-        pub value: ::std::option::Option<::pilota::FastStr>,
-
-        pub sub_messages: ::std::vec::Vec<SubMessage>,
+    #[derive(PartialOrd)]
+    #[derive(Hash, Eq, Ord)]
+    #[derive(Debug)]
+    #[derive(Default)]
+    #[derive(Clone, PartialEq)]
+    pub struct ObjReq {
+        pub msg: ::std::option::Option<Message>,
+        pub msg_map: ::std::vec::Vec<obj_req::MsgMapEntry>,
+        pub sub_msgs: ::std::vec::Vec<SubMessage>,
+        pub msg_set: ::std::vec::Vec<Message>,
+        pub flag_msg: ::pilota::FastStr,
+        pub mock_cost: ::std::option::Option<::pilota::FastStr>,
     }
-    impl ::pilota::pb::Message for Message {
+    impl ::pilota::pb::Message for ObjReq {
         #[inline]
         fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
-            0 + ::pilota::pb::encoding::faststr::encoded_len(ctx, 1, &self.uid)
-                + self.value.as_ref().map_or(0, |value| {
-                    ::pilota::pb::encoding::faststr::encoded_len(ctx, 2, value)
-                })
-                + ::pilota::pb::encoding::message::encoded_len_repeated(ctx, 3, &self.sub_messages)
+            0
+                + self
+                    .msg
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |msg| ::pilota::pb::encoding::message::encoded_len(ctx, 1, msg),
+                    )
+                + ::pilota::pb::encoding::message::encoded_len_repeated(
+                    ctx,
+                    2,
+                    &self.msg_map,
+                )
+                + ::pilota::pb::encoding::message::encoded_len_repeated(
+                    ctx,
+                    3,
+                    &self.sub_msgs,
+                )
+                + ::pilota::pb::encoding::message::encoded_len_repeated(
+                    ctx,
+                    4,
+                    &self.msg_set,
+                ) + ::pilota::pb::encoding::faststr::encoded_len(ctx, 5, &self.flag_msg)
+                + self
+                    .mock_cost
+                    .as_ref()
+                    .map_or(
+                        0,
+                        |value| ::pilota::pb::encoding::faststr::encoded_len(
+                            ctx,
+                            6,
+                            value,
+                        ),
+                    )
         }
-
         #[allow(unused_variables)]
         fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
-            ::pilota::pb::encoding::faststr::encode(1, &self.uid, buf);
-            if let Some(_pilota_inner_value) = self.value.as_ref() {
-                ::pilota::pb::encoding::faststr::encode(2, _pilota_inner_value, buf);
-            };
-            for msg in &self.sub_messages {
+            if let Some(_pilota_inner_value) = self.msg.as_ref() {
+                ::pilota::pb::encoding::message::encode(1, _pilota_inner_value, buf);
+            }
+            for msg in &self.msg_map {
+                ::pilota::pb::encoding::message::encode(2, msg, buf);
+            }
+            for msg in &self.sub_msgs {
                 ::pilota::pb::encoding::message::encode(3, msg, buf);
             }
+            for msg in &self.msg_set {
+                ::pilota::pb::encoding::message::encode(4, msg, buf);
+            }
+            ::pilota::pb::encoding::faststr::encode(5, &self.flag_msg, buf);
+            if let Some(_pilota_inner_value) = self.mock_cost.as_ref() {
+                ::pilota::pb::encoding::faststr::encode(6, _pilota_inner_value, buf);
+            }
         }
-
         #[allow(unused_variables)]
         fn merge_field(
             &mut self,
@@ -313,66 +335,131 @@ pub mod normal {
             ctx: &mut ::pilota::pb::encoding::DecodeContext,
             is_root: bool,
         ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
-            const STRUCT_NAME: &'static str = stringify!(Message);
-
+            const STRUCT_NAME: &'static str = stringify!(ObjReq);
             match tag {
                 1 => {
-                    let mut _inner_pilota_value = &mut self.uid;
-                    ::pilota::pb::encoding::faststr::merge(wire_type, _inner_pilota_value, buf, ctx)
+                    let mut _inner_pilota_value = &mut self.msg;
+                    ::pilota::pb::encoding::message::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
                         .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(uid));
+                            error.push(STRUCT_NAME, stringify!(msg));
                             error
                         })
                 }
                 2 => {
-                    let mut _inner_pilota_value = &mut self.value;
-                    ::pilota::pb::encoding::faststr::merge(
-                        wire_type,
-                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(value));
-                        error
-                    })
+                    let mut _inner_pilota_value = &mut self.msg_map;
+                    ::pilota::pb::encoding::message::merge_repeated(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(msg_map));
+                            error
+                        })
                 }
                 3 => {
-                    let mut _inner_pilota_value = &mut self.sub_messages;
+                    let mut _inner_pilota_value = &mut self.sub_msgs;
                     ::pilota::pb::encoding::message::merge_repeated(
-                        wire_type,
-                        _inner_pilota_value,
-                        buf,
-                        ctx,
-                    )
-                    .map_err(|mut error| {
-                        error.push(STRUCT_NAME, stringify!(sub_messages));
-                        error
-                    })
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(sub_msgs));
+                            error
+                        })
+                }
+                4 => {
+                    let mut _inner_pilota_value = &mut self.msg_set;
+                    ::pilota::pb::encoding::message::merge_repeated(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(msg_set));
+                            error
+                        })
+                }
+                5 => {
+                    let mut _inner_pilota_value = &mut self.flag_msg;
+                    ::pilota::pb::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(flag_msg));
+                            error
+                        })
+                }
+                6 => {
+                    let mut _inner_pilota_value = &mut self.mock_cost;
+                    ::pilota::pb::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(mock_cost));
+                            error
+                        })
                 }
                 _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
             }
         }
     }
-
+    pub trait TestService {}
     pub mod obj_req {
         use ::pilota::{Buf as _, BufMut as _};
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
         pub struct MsgMapEntry {
             pub key: ::std::option::Option<super::Message>,
-
             pub value: ::std::option::Option<super::SubMessage>,
         }
         impl ::pilota::pb::Message for MsgMapEntry {
             #[inline]
             fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
-                0 + self.key.as_ref().map_or(0, |msg| {
-                    ::pilota::pb::encoding::message::encoded_len(ctx, 1, msg)
-                }) + self.value.as_ref().map_or(0, |msg| {
-                    ::pilota::pb::encoding::message::encoded_len(ctx, 2, msg)
-                })
+                0
+                    + self
+                        .key
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |msg| ::pilota::pb::encoding::message::encoded_len(
+                                ctx,
+                                1,
+                                msg,
+                            ),
+                        )
+                    + self
+                        .value
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |msg| ::pilota::pb::encoding::message::encoded_len(
+                                ctx,
+                                2,
+                                msg,
+                            ),
+                        )
             }
-
             #[allow(unused_variables)]
             fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
                 if let Some(_pilota_inner_value) = self.key.as_ref() {
@@ -382,7 +469,6 @@ pub mod normal {
                     ::pilota::pb::encoding::message::encode(2, _pilota_inner_value, buf);
                 }
             }
-
             #[allow(unused_variables)]
             fn merge_field(
                 &mut self,
@@ -393,35 +479,34 @@ pub mod normal {
                 is_root: bool,
             ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
                 const STRUCT_NAME: &'static str = stringify!(MsgMapEntry);
-
                 match tag {
                     1 => {
                         let mut _inner_pilota_value = &mut self.key;
                         ::pilota::pb::encoding::message::merge(
-                            wire_type,
-                            _inner_pilota_value
-                                .get_or_insert_with(::core::default::Default::default),
-                            buf,
-                            ctx,
-                        )
-                        .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(key));
-                            error
-                        })
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(key));
+                                error
+                            })
                     }
                     2 => {
                         let mut _inner_pilota_value = &mut self.value;
                         ::pilota::pb::encoding::message::merge(
-                            wire_type,
-                            _inner_pilota_value
-                                .get_or_insert_with(::core::default::Default::default),
-                            buf,
-                            ctx,
-                        )
-                        .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(value));
-                            error
-                        })
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(value));
+                                error
+                            })
                     }
                     _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
                 }
@@ -3,7 +3,11 @@ pub mod service {
     use ::pilota::{Buf as _, BufMut as _};
     pub mod service {
         use ::pilota::{Buf as _, BufMut as _};
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
         pub struct EchoRequest {
             pub message: ::pilota::FastStr,
         }
@@ -12,12 +16,10 @@ pub mod service {
             fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
                 0 + ::pilota::pb::encoding::faststr::encoded_len(ctx, 1, &self.message)
             }
-
             #[allow(unused_variables)]
             fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
                 ::pilota::pb::encoding::faststr::encode(1, &self.message, buf);
             }
-
             #[allow(unused_variables)]
             fn merge_field(
                 &mut self,
@@ -28,28 +30,29 @@ pub mod service {
                 is_root: bool,
             ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
                 const STRUCT_NAME: &'static str = stringify!(EchoRequest);
-
                 match tag {
                     1 => {
                         let mut _inner_pilota_value = &mut self.message;
                         ::pilota::pb::encoding::faststr::merge(
-                            wire_type,
-                            _inner_pilota_value,
-                            buf,
-                            ctx,
-                        )
-                        .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(message));
-                            error
-                        })
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(message));
+                                error
+                            })
                     }
                     _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
                 }
             }
         }
-
-        pub trait Echo {}
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
         pub struct EchoResponse {
             pub message: ::pilota::FastStr,
         }
@@ -58,12 +61,10 @@ pub mod service {
             fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
                 0 + ::pilota::pb::encoding::faststr::encoded_len(ctx, 1, &self.message)
             }
-
             #[allow(unused_variables)]
             fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
                 ::pilota::pb::encoding::faststr::encode(1, &self.message, buf);
             }
-
             #[allow(unused_variables)]
             fn merge_field(
                 &mut self,
@@ -74,24 +75,24 @@ pub mod service {
                 is_root: bool,
             ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
                 const STRUCT_NAME: &'static str = stringify!(EchoResponse);
-
                 match tag {
                     1 => {
                         let mut _inner_pilota_value = &mut self.message;
                         ::pilota::pb::encoding::faststr::merge(
-                            wire_type,
-                            _inner_pilota_value,
-                            buf,
-                            ctx,
-                        )
-                        .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(message));
-                            error
-                        })
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(message));
+                                error
+                            })
                     }
                     _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
                 }
             }
         }
+        pub trait Echo {}
     }
 }
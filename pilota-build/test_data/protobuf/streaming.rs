@@ -0,0 +1,78 @@
+pub mod streaming {
+    #![allow(warnings, clippy::all)]
+    use ::pilota::{Buf as _, BufMut as _};
+    pub mod streaming {
+        use ::pilota::{Buf as _, BufMut as _};
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct Item {
+            pub name: ::pilota::FastStr,
+        }
+        impl ::pilota::pb::Message for Item {
+            #[inline]
+            fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
+                0 + ::pilota::pb::encoding::faststr::encoded_len(ctx, 1, &self.name)
+            }
+            #[allow(unused_variables)]
+            fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
+                ::pilota::pb::encoding::faststr::encode(1, &self.name, buf);
+            }
+            #[allow(unused_variables)]
+            fn merge_field(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::pb::encoding::WireType,
+                buf: &mut ::pilota::Bytes,
+                ctx: &mut ::pilota::pb::encoding::DecodeContext,
+                is_root: bool,
+            ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
+                const STRUCT_NAME: &'static str = stringify!(Item);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.name;
+                        ::pilota::pb::encoding::faststr::merge(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(name));
+                                error
+                            })
+                    }
+                    _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+        pub trait Catalog {
+            fn upload(
+                &self,
+                req: ::pilota::stream::Streaming<Item>,
+            ) -> impl ::std::future::Future<
+                Output = ::std::result::Result<Item, ::pilota::stream::BoxError>,
+            > + Send;
+            fn list(
+                &self,
+                req: Item,
+            ) -> impl ::std::future::Future<
+                Output = ::std::result::Result<
+                    ::pilota::stream::Streaming<Item>,
+                    ::pilota::stream::BoxError,
+                >,
+            > + Send;
+            fn sync(
+                &self,
+                req: ::pilota::stream::Streaming<Item>,
+            ) -> impl ::std::future::Future<
+                Output = ::std::result::Result<
+                    ::pilota::stream::Streaming<Item>,
+                    ::pilota::stream::BoxError,
+                >,
+            > + Send;
+        }
+    }
+}
@@ -3,8 +3,9 @@ pub mod message {
     use ::pilota::{Buf as _, BufMut as _, pb::descriptor_getter::*};
     pub mod example {
         use ::pilota::{Buf as _, BufMut as _, pb::descriptor_getter::*};
-
-        static FILE_DESCRIPTOR_BYTES_MESSAGE: ::pilota::Bytes = ::pilota::Bytes::from_static(b"\n\rmessage.proto\x12\x07example\".\n\x06Person\x12\x12\n\x04name\x18\x01 \x01(\tR\x04name\x12\x10\n\x03age\x18\x02 \x01(\x05R\x03age\"L\n\x07Company\x12\x12\n\x04name\x18\x01 \x01(\tR\x04name\x12-\n\temployees\x18\x02 \x03(\x0b2\x0f.example.PersonR\temployees\"\x06\n\x04Self*/\n\x06Status\x12\x0b\n\x07UNKNOWN\x10\0\x12\n\n\x06ACTIVE\x10\x01\x12\x0c\n\x08INACTIVE\x10\x02b\x06proto3");
+        static FILE_DESCRIPTOR_BYTES_MESSAGE: ::pilota::Bytes = ::pilota::Bytes::from_static(
+            b"\n\rmessage.proto\x12\x07example\".\n\x06Person\x12\x12\n\x04name\x18\x01 \x01(\tR\x04name\x12\x10\n\x03age\x18\x02 \x01(\x05R\x03age\"L\n\x07Company\x12\x12\n\x04name\x18\x01 \x01(\tR\x04name\x12-\n\temployees\x18\x02 \x03(\x0b2\x0f.example.PersonR\temployees\"\x06\n\x04Self*/\n\x06Status\x12\x0b\n\x07UNKNOWN\x10\0\x12\n\n\x06ACTIVE\x10\x01\x12\x0c\n\x08INACTIVE\x10\x02b\x06proto3",
+        );
         static FILE_DESCRIPTOR_PROTO_MESSAGE: ::std::sync::LazyLock<
             ::pilota::pb::descriptor::FileDescriptorProto,
         > = ::std::sync::LazyLock::new(|| {
@@ -12,53 +13,50 @@ pub mod message {
             ::pilota::pb::PbMessage::parse_from_bytes(data)
                 .expect("Failed to decode file descriptor")
         });
-        pub fn file_descriptor_proto_message()
-        -> &'static ::pilota::pb::descriptor::FileDescriptorProto {
+        pub fn file_descriptor_proto_message() -> &'static ::pilota::pb::descriptor::FileDescriptorProto {
             &*FILE_DESCRIPTOR_PROTO_MESSAGE
         }
-
         static FILE_DESCRIPTOR_MESSAGE: ::std::sync::LazyLock<
             ::pilota::pb::reflect::FileDescriptor,
         > = ::std::sync::LazyLock::new(|| {
             let mut deps = ::std::vec::Vec::new();
-
             ::pilota::pb::reflect::FileDescriptor::new_dynamic(
-                file_descriptor_proto_message().clone(),
-                &deps,
-            )
-            .expect("Failed to build dynamic FileDescriptor")
+                    file_descriptor_proto_message().clone(),
+                    &deps,
+                )
+                .expect("Failed to build dynamic FileDescriptor")
         });
-
         pub fn file_descriptor_message() -> &'static ::pilota::pb::reflect::FileDescriptor {
             &*FILE_DESCRIPTOR_MESSAGE
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
         pub struct Person {
             pub name: ::pilota::FastStr,
-
             pub age: i32,
         }
         impl Person {
-            pub fn get_descriptor_proto()
-            -> Option<&'static ::pilota::pb::descriptor::DescriptorProto> {
+            pub fn get_descriptor_proto() -> Option<
+                &'static ::pilota::pb::descriptor::DescriptorProto,
+            > {
                 let file_descriptor = file_descriptor_proto_message();
                 file_descriptor.get_message_descriptor_proto("Person")
             }
         }
-
         impl ::pilota::pb::Message for Person {
             #[inline]
             fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
                 0 + ::pilota::pb::encoding::faststr::encoded_len(ctx, 1, &self.name)
                     + ::pilota::pb::encoding::int32::encoded_len(ctx, 2, &self.age)
             }
-
             #[allow(unused_variables)]
             fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
                 ::pilota::pb::encoding::faststr::encode(1, &self.name, buf);
                 ::pilota::pb::encoding::int32::encode(2, &self.age, buf);
             }
-
             #[allow(unused_variables)]
             fn merge_field(
                 &mut self,
@@ -69,59 +67,64 @@ pub mod message {
                 is_root: bool,
             ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
                 const STRUCT_NAME: &'static str = stringify!(Person);
-
                 match tag {
                     1 => {
                         let mut _inner_pilota_value = &mut self.name;
                         ::pilota::pb::encoding::faststr::merge(
-                            wire_type,
-                            _inner_pilota_value,
-                            buf,
-                            ctx,
-                        )
-                        .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(name));
-                            error
-                        })
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(name));
+                                error
+                            })
                     }
                     2 => {
                         let mut _inner_pilota_value = &mut self.age;
                         ::pilota::pb::encoding::int32::merge(
-                            wire_type,
-                            _inner_pilota_value,
-                            buf,
-                            ctx,
-                        )
-                        .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(age));
-                            error
-                        })
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(age));
+                                error
+                            })
                     }
                     _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
                 }
             }
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
         pub struct Company {
             pub name: ::pilota::FastStr,
-
             pub employees: ::std::vec::Vec<Person>,
         }
         impl Company {
-            pub fn get_descriptor_proto()
-            -> Option<&'static ::pilota::pb::descriptor::DescriptorProto> {
+            pub fn get_descriptor_proto() -> Option<
+                &'static ::pilota::pb::descriptor::DescriptorProto,
+            > {
                 let file_descriptor = file_descriptor_proto_message();
                 file_descriptor.get_message_descriptor_proto("Company")
             }
         }
-
         impl ::pilota::pb::Message for Company {
             #[inline]
             fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
                 0 + ::pilota::pb::encoding::faststr::encoded_len(ctx, 1, &self.name)
-                    + ::pilota::pb::encoding::message::encoded_len_repeated(ctx, 2, &self.employees)
+                    + ::pilota::pb::encoding::message::encoded_len_repeated(
+                        ctx,
+                        2,
+                        &self.employees,
+                    )
             }
-
             #[allow(unused_variables)]
             fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
                 ::pilota::pb::encoding::faststr::encode(1, &self.name, buf);
@@ -129,7 +132,6 @@ pub mod message {
                     ::pilota::pb::encoding::message::encode(2, msg, buf);
                 }
             }
-
             #[allow(unused_variables)]
             fn merge_field(
                 &mut self,
@@ -140,57 +142,58 @@ pub mod message {
                 is_root: bool,
             ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
                 const STRUCT_NAME: &'static str = stringify!(Company);
-
                 match tag {
                     1 => {
                         let mut _inner_pilota_value = &mut self.name;
                         ::pilota::pb::encoding::faststr::merge(
-                            wire_type,
-                            _inner_pilota_value,
-                            buf,
-                            ctx,
-                        )
-                        .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(name));
-                            error
-                        })
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(name));
+                                error
+                            })
                     }
                     2 => {
                         let mut _inner_pilota_value = &mut self.employees;
                         ::pilota::pb::encoding::message::merge_repeated(
-                            wire_type,
-                            _inner_pilota_value,
-                            buf,
-                            ctx,
-                        )
-                        .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(employees));
-                            error
-                        })
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(employees));
+                                error
+                            })
                     }
                     _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
                 }
             }
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
         pub struct Self_ {}
         impl Self_ {
-            pub fn get_descriptor_proto()
-            -> Option<&'static ::pilota::pb::descriptor::DescriptorProto> {
+            pub fn get_descriptor_proto() -> Option<
+                &'static ::pilota::pb::descriptor::DescriptorProto,
+            > {
                 let file_descriptor = file_descriptor_proto_message();
                 file_descriptor.get_message_descriptor_proto("Self")
             }
         }
-
         impl ::pilota::pb::Message for Self_ {
             #[inline]
             fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
                 0
             }
-
             #[allow(unused_variables)]
             fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {}
-
             #[allow(unused_variables)]
             fn merge_field(
                 &mut self,
@@ -205,28 +208,28 @@ pub mod message {
                 }
             }
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq, Copy)]
         #[repr(transparent)]
         pub struct Status(i32);
-
         impl Status {
             pub const UNKNOWN: Self = Self(0);
             pub const ACTIVE: Self = Self(1);
             pub const INACTIVE: Self = Self(2);
-
             pub fn inner(&self) -> i32 {
                 self.0
             }
-
-            pub fn to_string(&self) -> ::std::string::String {
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
                 match self {
-                    Self(0) => ::std::string::String::from("UNKNOWN"),
-                    Self(1) => ::std::string::String::from("ACTIVE"),
-                    Self(2) => ::std::string::String::from("INACTIVE"),
-                    Self(val) => val.to_string(),
+                    Self(0) => ::std::option::Option::Some("UNKNOWN"),
+                    Self(1) => ::std::option::Option::Some("ACTIVE"),
+                    Self(2) => ::std::option::Option::Some("INACTIVE"),
+                    Self(_) => ::std::option::Option::None,
                 }
             }
-
             pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
                 match value {
                     0 => Some(Self::UNKNOWN),
@@ -236,36 +239,59 @@ pub mod message {
                 }
             }
         }
-
         impl ::pilota::pb::EnumMessage for Status {
             fn inner(&self) -> i32 {
                 self.inner()
             }
-
             fn to_string(&self) -> ::std::string::String {
-                self.to_string()
+                ::std::string::ToString::to_string(self)
             }
-
             fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
                 Status::try_from_i32(value)
             }
         }
-
         impl ::std::convert::From<i32> for Status {
             fn from(value: i32) -> Self {
                 Self(value)
             }
         }
-
         impl ::std::convert::From<Status> for i32 {
             fn from(value: Status) -> i32 {
                 value.0
             }
         }
-
+        impl ::std::fmt::Display for Status {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => {
+                        ::std::fmt::Display::fmt(&self.inner(), f)
+                    }
+                }
+            }
+        }
+        impl ::std::str::FromStr for Status {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "UNKNOWN" => ::std::result::Result::Ok(Self::UNKNOWN),
+                    "ACTIVE" => ::std::result::Result::Ok(Self::ACTIVE),
+                    "INACTIVE" => ::std::result::Result::Ok(Self::INACTIVE),
+                    _ => {
+                        s.parse::<i32>()
+                            .map(Self::from)
+                            .map_err(|_| ::pilota::EnumConvertError::InvalidNum(
+                                s.to_string(),
+                                "Status",
+                            ))
+                    }
+                }
+            }
+        }
         impl Status {
-            pub fn get_descriptor_proto()
-            -> Option<&'static ::pilota::pb::descriptor::EnumDescriptorProto> {
+            pub fn get_descriptor_proto() -> Option<
+                &'static ::pilota::pb::descriptor::EnumDescriptorProto,
+            > {
                 let file_descriptor = file_descriptor_proto_message();
                 file_descriptor.get_enum_descriptor_proto("Status")
             }
@@ -3,8 +3,9 @@ pub mod oneof {
     use ::pilota::{Buf as _, BufMut as _, pb::descriptor_getter::*};
     pub mod example {
         use ::pilota::{Buf as _, BufMut as _, pb::descriptor_getter::*};
-
-        static FILE_DESCRIPTOR_BYTES_ONEOF: ::pilota::Bytes = ::pilota::Bytes::from_static(b"\n\x0boneof.proto\x12\x07example\"l\n\x0bUserContact\x12\x12\n\x04name\x18\x01 \x01(\tR\x04name\x1aI\n\x07Contact\x12\x16\n\x05email\x18\x02 \x01(\tH\0R\x05email\x12\x16\n\x05phone\x18\x03 \x01(\tH\0R\x05phoneB\x0e\n\x0ccontact_infob\x06proto3");
+        static FILE_DESCRIPTOR_BYTES_ONEOF: ::pilota::Bytes = ::pilota::Bytes::from_static(
+            b"\n\x0boneof.proto\x12\x07example\"l\n\x0bUserContact\x12\x12\n\x04name\x18\x01 \x01(\tR\x04name\x1aI\n\x07Contact\x12\x16\n\x05email\x18\x02 \x01(\tH\0R\x05email\x12\x16\n\x05phone\x18\x03 \x01(\tH\0R\x05phoneB\x0e\n\x0ccontact_infob\x06proto3",
+        );
         static FILE_DESCRIPTOR_PROTO_ONEOF: ::std::sync::LazyLock<
             ::pilota::pb::descriptor::FileDescriptorProto,
         > = ::std::sync::LazyLock::new(|| {
@@ -12,48 +13,47 @@ pub mod oneof {
             ::pilota::pb::PbMessage::parse_from_bytes(data)
                 .expect("Failed to decode file descriptor")
         });
-        pub fn file_descriptor_proto_oneof()
-        -> &'static ::pilota::pb::descriptor::FileDescriptorProto {
+        pub fn file_descriptor_proto_oneof() -> &'static ::pilota::pb::descriptor::FileDescriptorProto {
             &*FILE_DESCRIPTOR_PROTO_ONEOF
         }
-
-        static FILE_DESCRIPTOR_ONEOF: ::std::sync::LazyLock<::pilota::pb::reflect::FileDescriptor> =
-            ::std::sync::LazyLock::new(|| {
-                let mut deps = ::std::vec::Vec::new();
-
-                ::pilota::pb::reflect::FileDescriptor::new_dynamic(
+        static FILE_DESCRIPTOR_ONEOF: ::std::sync::LazyLock<
+            ::pilota::pb::reflect::FileDescriptor,
+        > = ::std::sync::LazyLock::new(|| {
+            let mut deps = ::std::vec::Vec::new();
+            ::pilota::pb::reflect::FileDescriptor::new_dynamic(
                     file_descriptor_proto_oneof().clone(),
                     &deps,
                 )
                 .expect("Failed to build dynamic FileDescriptor")
-            });
-
+        });
         pub fn file_descriptor_oneof() -> &'static ::pilota::pb::reflect::FileDescriptor {
             &*FILE_DESCRIPTOR_ONEOF
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
         pub struct UserContact {
             pub name: ::pilota::FastStr,
         }
         impl UserContact {
-            pub fn get_descriptor_proto()
-            -> Option<&'static ::pilota::pb::descriptor::DescriptorProto> {
+            pub fn get_descriptor_proto() -> Option<
+                &'static ::pilota::pb::descriptor::DescriptorProto,
+            > {
                 let file_descriptor = file_descriptor_proto_oneof();
                 file_descriptor.get_message_descriptor_proto("UserContact")
             }
         }
-
         impl ::pilota::pb::Message for UserContact {
             #[inline]
             fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
                 0 + ::pilota::pb::encoding::faststr::encoded_len(ctx, 1, &self.name)
             }
-
             #[allow(unused_variables)]
             fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
                 ::pilota::pb::encoding::faststr::encode(1, &self.name, buf);
             }
-
             #[allow(unused_variables)]
             fn merge_field(
                 &mut self,
@@ -64,56 +64,56 @@ pub mod oneof {
                 is_root: bool,
             ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
                 const STRUCT_NAME: &'static str = stringify!(UserContact);
-
                 match tag {
                     1 => {
                         let mut _inner_pilota_value = &mut self.name;
                         ::pilota::pb::encoding::faststr::merge(
-                            wire_type,
-                            _inner_pilota_value,
-                            buf,
-                            ctx,
-                        )
-                        .map_err(|mut error| {
-                            error.push(STRUCT_NAME, stringify!(name));
-                            error
-                        })
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(name));
+                                error
+                            })
                     }
                     _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
                 }
             }
         }
-
         pub mod user_contact {
             use ::pilota::{Buf as _, BufMut as _, pb::descriptor_getter::*};
-            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            #[derive(PartialOrd)]
+            #[derive(Hash, Eq, Ord)]
+            #[derive(Debug)]
+            #[derive(Default)]
+            #[derive(Clone, PartialEq)]
             pub struct Contact {
                 pub contact_info: ::std::option::Option<contact::ContactInfo>,
             }
             impl Contact {
-                pub fn get_descriptor_proto()
-                -> Option<&'static ::pilota::pb::descriptor::DescriptorProto> {
+                pub fn get_descriptor_proto() -> Option<
+                    &'static ::pilota::pb::descriptor::DescriptorProto,
+                > {
                     let message_descriptor = super::UserContact::get_descriptor_proto()?;
                     message_descriptor.get_message_descriptor_proto("Contact")
                 }
             }
-
             impl ::pilota::pb::Message for Contact {
                 #[inline]
-                fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
-                    0 + self
-                        .contact_info
-                        .as_ref()
-                        .map_or(0, |msg| msg.encoded_len(ctx))
+                fn encoded_len(
+                    &self,
+                    ctx: &mut ::pilota::pb::EncodeLengthContext,
+                ) -> usize {
+                    0 + self.contact_info.as_ref().map_or(0, |msg| msg.encoded_len(ctx))
                 }
-
                 #[allow(unused_variables)]
                 fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
                     if let Some(_pilota_inner_value) = self.contact_info.as_ref() {
                         _pilota_inner_value.encode(buf);
                     }
                 }
-
                 #[allow(unused_variables)]
                 fn merge_field(
                     &mut self,
@@ -124,51 +124,48 @@ pub mod oneof {
                     is_root: bool,
                 ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
                     const STRUCT_NAME: &'static str = stringify!(Contact);
-
                     match tag {
                         2 | 3 => {
                             let mut _inner_pilota_value = &mut self.contact_info;
                             contact::ContactInfo::merge(
-                                _inner_pilota_value,
-                                tag,
-                                wire_type,
-                                buf,
-                                ctx,
-                            )
-                            .map_err(|mut error| {
-                                error.push(STRUCT_NAME, stringify!(contact_info));
-                                error
-                            })
+                                    _inner_pilota_value,
+                                    tag,
+                                    wire_type,
+                                    buf,
+                                    ctx,
+                                )
+                                .map_err(|mut error| {
+                                    error.push(STRUCT_NAME, stringify!(contact_info));
+                                    error
+                                })
                         }
                         _ => ::pilota::pb::encoding::skip_field(wire_type, tag, buf, ctx),
                     }
                 }
             }
-
             pub mod contact {
                 use ::pilota::{Buf as _, BufMut as _, pb::descriptor_getter::*};
-
                 impl ::std::default::Default for ContactInfo {
                     fn default() -> Self {
                         ContactInfo::Email(::std::default::Default::default())
                     }
                 }
-                #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+                #[derive(PartialOrd)]
+                #[derive(Hash, Eq, Ord)]
+                #[derive(Debug)]
+                #[derive(Clone, PartialEq)]
                 pub enum ContactInfo {
                     Email(::pilota::FastStr),
-
                     Phone(::pilota::FastStr),
                 }
-
                 impl ContactInfo {
-                    pub fn get_descriptor_proto()
-                    -> Option<&'static ::pilota::pb::descriptor::OneofDescriptorProto>
-                    {
+                    pub fn get_descriptor_proto() -> Option<
+                        &'static ::pilota::pb::descriptor::OneofDescriptorProto,
+                    > {
                         let message_descriptor = super::Contact::get_descriptor_proto()?;
                         message_descriptor.get_oneof_descriptor_proto("contact_info")
                     }
                 }
-
                 impl ContactInfo {
                     pub fn encode(&self, buf: &mut ::pilota::LinkedBytes) {
                         match self {
@@ -180,7 +177,6 @@ pub mod oneof {
                             }
                         }
                     }
-
                     #[inline]
                     pub fn encoded_len(
                         &self,
@@ -188,14 +184,21 @@ pub mod oneof {
                     ) -> usize {
                         match self {
                             ContactInfo::Email(value) => {
-                                ::pilota::pb::encoding::faststr::encoded_len(ctx, 2, &*value)
+                                ::pilota::pb::encoding::faststr::encoded_len(
+                                    ctx,
+                                    2,
+                                    &*value,
+                                )
                             }
                             ContactInfo::Phone(value) => {
-                                ::pilota::pb::encoding::faststr::encoded_len(ctx, 3, &*value)
+                                ::pilota::pb::encoding::faststr::encoded_len(
+                                    ctx,
+                                    3,
+                                    &*value,
+                                )
                             }
                         }
                     }
-
                     #[inline]
                     pub fn merge(
                         field: &mut ::core::option::Option<Self>,
@@ -205,44 +208,62 @@ pub mod oneof {
                         ctx: &mut ::pilota::pb::encoding::DecodeContext,
                     ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
                         match tag {
-                            2 => match field {
-                                ::core::option::Option::Some(ContactInfo::Email(value)) => {
-                                    ::pilota::pb::encoding::faststr::merge(
-                                        wire_type, value, buf, ctx,
-                                    )?;
+                            2 => {
+                                match field {
+                                    ::core::option::Option::Some(ContactInfo::Email(value)) => {
+                                        ::pilota::pb::encoding::faststr::merge(
+                                            wire_type,
+                                            value,
+                                            buf,
+                                            ctx,
+                                        )?;
+                                    }
+                                    _ => {
+                                        let mut owned_value = ::core::default::Default::default();
+                                        let value = &mut owned_value;
+                                        ::pilota::pb::encoding::faststr::merge(
+                                            wire_type,
+                                            value,
+                                            buf,
+                                            ctx,
+                                        )?;
+                                        *field = ::core::option::Option::Some(
+                                            ContactInfo::Email(owned_value),
+                                        );
+                                    }
                                 }
-                                _ => {
-                                    let mut owned_value = ::core::default::Default::default();
-                                    let value = &mut owned_value;
-                                    ::pilota::pb::encoding::faststr::merge(
-                                        wire_type, value, buf, ctx,
-                                    )?;
-                                    *field = ::core::option::Option::Some(ContactInfo::Email(
-                                        owned_value,
-                                    ));
-                                }
-                            },
-                            3 => match field {
-                                ::core::option::Option::Some(ContactInfo::Phone(value)) => {
-                                    ::pilota::pb::encoding::faststr::merge(
-                                        wire_type, value, buf, ctx,
-                                    )?;
-                                }
-                                _ => {
-                                    let mut owned_value = ::core::default::Default::default();
-                                    let value = &mut owned_value;
-                                    ::pilota::pb::encoding::faststr::merge(
-                                        wire_type, value, buf, ctx,
-                                    )?;
-                                    *field = ::core::option::Option::Some(ContactInfo::Phone(
-                                        owned_value,
-                                    ));
+                            }
+                            3 => {
+                                match field {
+                                    ::core::option::Option::Some(ContactInfo::Phone(value)) => {
+                                        ::pilota::pb::encoding::faststr::merge(
+                                            wire_type,
+                                            value,
+                                            buf,
+                                            ctx,
+                                        )?;
+                                    }
+                                    _ => {
+                                        let mut owned_value = ::core::default::Default::default();
+                                        let value = &mut owned_value;
+                                        ::pilota::pb::encoding::faststr::merge(
+                                            wire_type,
+                                            value,
+                                            buf,
+                                            ctx,
+                                        )?;
+                                        *field = ::core::option::Option::Some(
+                                            ContactInfo::Phone(owned_value),
+                                        );
+                                    }
                                 }
-                            },
-                            _ => unreachable!(
-                                concat!("invalid ", stringify!(ContactInfo), " tag: {}"),
-                                tag
-                            ),
+                            }
+                            _ => {
+                                unreachable!(
+                                    concat!("invalid ", stringify!(ContactInfo), " tag: {}"),
+                                    tag
+                                )
+                            }
                         };
                         ::core::result::Result::Ok(())
                     }
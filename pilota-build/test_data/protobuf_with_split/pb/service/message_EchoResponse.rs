@@ -1,4 +1,8 @@
-#[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+#[derive(PartialOrd)]
+#[derive(Hash, Eq, Ord)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Clone, PartialEq)]
 pub struct EchoResponse {
     pub message: ::pilota::FastStr,
 }
@@ -7,12 +11,10 @@ impl ::pilota::pb::Message for EchoResponse {
     fn encoded_len(&self, ctx: &mut ::pilota::pb::EncodeLengthContext) -> usize {
         0 + ::pilota::pb::encoding::faststr::encoded_len(ctx, 1, &self.message)
     }
-
     #[allow(unused_variables)]
     fn encode_raw(&self, buf: &mut ::pilota::LinkedBytes) {
         ::pilota::pb::encoding::faststr::encode(1, &self.message, buf);
     }
-
     #[allow(unused_variables)]
     fn merge_field(
         &mut self,
@@ -23,11 +25,15 @@ impl ::pilota::pb::Message for EchoResponse {
         is_root: bool,
     ) -> ::core::result::Result<(), ::pilota::pb::DecodeError> {
         const STRUCT_NAME: &'static str = stringify!(EchoResponse);
-
         match tag {
             1 => {
                 let mut _inner_pilota_value = &mut self.message;
-                ::pilota::pb::encoding::faststr::merge(wire_type, _inner_pilota_value, buf, ctx)
+                ::pilota::pb::encoding::faststr::merge(
+                        wire_type,
+                        _inner_pilota_value,
+                        buf,
+                        ctx,
+                    )
                     .map_err(|mut error| {
                         error.push(STRUCT_NAME, stringify!(message));
                         error
@@ -1,3 +1,3 @@
 include!("message_EchoRequest.rs");
-include!("service_Echo.rs");
 include!("message_EchoResponse.rs");
+include!("service_Echo.rs");
@@ -1,2 +1 @@
-
 pub trait Echo {}
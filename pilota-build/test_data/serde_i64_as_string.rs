@@ -0,0 +1,403 @@
+pub mod serde_i64_as_string {
+    #![allow(warnings, clippy::all)]
+    pub mod serde_i64_as_string {
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(::pilota::serde::Serialize, ::pilota::serde::Deserialize)]
+        #[derive(Clone, PartialEq)]
+        pub struct Snowflake {
+            #[serde(with = "::pilota::serde_str")]
+            pub id: i64,
+            #[serde(default, with = "::pilota::serde_str::option")]
+            pub parent_id: ::std::option::Option<i64>,
+            #[serde(with = "::pilota::serde_str::vec")]
+            pub children: ::std::vec::Vec<i64>,
+            #[serde(default, with = "::pilota::serde_str::option_vec")]
+            pub tags: ::std::option::Option<::std::vec::Vec<i64>>,
+            pub count: i64,
+            pub small: i32,
+        }
+        impl ::pilota::thrift::Message for Snowflake {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "Snowflake",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.id)?;
+                if let Some(value) = self.parent_id.as_ref() {
+                    __protocol.write_i64_field(2, *value)?;
+                }
+                __protocol
+                    .write_list_field(
+                        3,
+                        ::pilota::thrift::TType::I64,
+                        &&self.children,
+                        |__protocol, val| {
+                            __protocol.write_i64(*val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                if let Some(value) = self.tags.as_ref() {
+                    __protocol
+                        .write_list_field(
+                            4,
+                            ::pilota::thrift::TType::I64,
+                            &value,
+                            |__protocol, val| {
+                                __protocol.write_i64(*val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                }
+                __protocol.write_i64_field(5, *&self.count)?;
+                __protocol.write_i32_field(6, *&self.small)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut var_5 = None;
+                let mut var_6 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I64 => {
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I64 => {
+                                var_2 = Some(__protocol.read_i64()?);
+                            }
+                            Some(
+                                3,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::List => {
+                                var_3 = Some({
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(
+                                        list_ident.size,
+                                    );
+                                    for _ in 0..list_ident.size {
+                                        val.push(__protocol.read_i64()?);
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(
+                                4,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::List => {
+                                var_4 = Some({
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(
+                                        list_ident.size,
+                                    );
+                                    for _ in 0..list_ident.size {
+                                        val.push(__protocol.read_i64()?);
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(
+                                5,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I64 => {
+                                var_5 = Some(__protocol.read_i64()?);
+                            }
+                            Some(
+                                6,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                var_6 = Some(__protocol.read_i32()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `Snowflake` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    )
+                };
+                let Some(var_3) = var_3 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field children is required".to_string(),
+                        ),
+                    )
+                };
+                let Some(var_5) = var_5 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field count is required".to_string(),
+                        ),
+                    )
+                };
+                let Some(var_6) = var_6 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field small is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self {
+                    id: var_1,
+                    parent_id: var_2,
+                    children: var_3,
+                    tags: var_4,
+                    count: var_5,
+                    small: var_6,
+                };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+                    let mut var_6 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I64 => {
+                                    var_1 = Some(__protocol.read_i64().await?);
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I64 => {
+                                    var_2 = Some(__protocol.read_i64().await?);
+                                }
+                                Some(
+                                    3,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::List => {
+                                    var_3 = Some({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val = ::std::vec::Vec::with_capacity(
+                                            list_ident.size,
+                                        );
+                                        for _ in 0..list_ident.size {
+                                            val.push(__protocol.read_i64().await?);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(
+                                    4,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::List => {
+                                    var_4 = Some({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val = ::std::vec::Vec::with_capacity(
+                                            list_ident.size,
+                                        );
+                                        for _ in 0..list_ident.size {
+                                            val.push(__protocol.read_i64().await?);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(
+                                    5,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I64 => {
+                                    var_5 = Some(__protocol.read_i64().await?);
+                                }
+                                Some(
+                                    6,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    var_6 = Some(__protocol.read_i32().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `Snowflake` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        )
+                    };
+                    let Some(var_3) = var_3 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field children is required".to_string(),
+                            ),
+                        )
+                    };
+                    let Some(var_5) = var_5 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field count is required".to_string(),
+                            ),
+                        )
+                    };
+                    let Some(var_6) = var_6 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field small is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self {
+                        id: var_1,
+                        parent_id: var_2,
+                        children: var_3,
+                        tags: var_4,
+                        count: var_5,
+                        small: var_6,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Snowflake",
+                        },
+                    ) + __protocol.i64_field_len(Some(1), *&self.id)
+                    + self
+                        .parent_id
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i64_field_len(Some(2), *value))
+                    + __protocol
+                        .list_field_len(
+                            Some(3),
+                            ::pilota::thrift::TType::I64,
+                            &self.children,
+                            |__protocol, el| { __protocol.i64_len(*el) },
+                        )
+                    + self
+                        .tags
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol
+                                    .list_field_len(
+                                        Some(4),
+                                        ::pilota::thrift::TType::I64,
+                                        value,
+                                        |__protocol, el| { __protocol.i64_len(*el) },
+                                    )
+                            },
+                        ) + __protocol.i64_field_len(Some(5), *&self.count)
+                    + __protocol.i32_field_len(Some(6), *&self.small)
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+    }
+}
@@ -0,0 +1,8 @@
+struct Snowflake {
+    1: required i64 id,
+    2: optional i64 parent_id,
+    3: required list<i64> children,
+    4: optional list<i64> tags,
+    5: required i64 count (pilota.serde_i64_as_string = "false"),
+    6: required i32 small,
+}
@@ -0,0 +1,7 @@
+service A extends B {
+    void a(),
+}
+
+service B extends A {
+    void b(),
+}
@@ -0,0 +1,903 @@
+pub mod sqlx_bridge {
+    #![allow(warnings, clippy::all)]
+    pub mod sqlx_bridge {
+        impl<DB: ::sqlx::Database> ::sqlx::Type<DB> for Role
+        where
+            i32: ::sqlx::Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <i32 as ::sqlx::Type<DB>>::type_info()
+            }
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <i32 as ::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+        impl<'q, DB: ::sqlx::Database> ::sqlx::Encode<'q, DB> for Role
+        where
+            i32: ::sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as ::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> ::std::result::Result<
+                ::sqlx::encode::IsNull,
+                ::sqlx::error::BoxDynError,
+            > {
+                <i32 as ::sqlx::Encode<'q, DB>>::encode_by_ref(&self.inner(), buf)
+            }
+        }
+        impl<'r, DB: ::sqlx::Database> ::sqlx::Decode<'r, DB> for Role
+        where
+            i32: ::sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: <DB as ::sqlx::Database>::ValueRef<'r>,
+            ) -> ::std::result::Result<Self, ::sqlx::error::BoxDynError> {
+                let value = <i32 as ::sqlx::Decode<'r, DB>>::decode(value)?;
+                Self::try_from_i32(value)
+                    .ok_or_else(|| {
+                        ::std::format!("invalid value `{value}` for enum `Role`").into()
+                    })
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(::pilota::serde::Serialize, ::pilota::serde::Deserialize)]
+        #[serde(transparent)]
+        #[derive(Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Role(i32);
+        impl Role {
+            pub const ADMIN: Self = Self(1);
+            pub const MEMBER: Self = Self(2);
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
+                match self {
+                    Self(1) => ::std::option::Option::Some("ADMIN"),
+                    Self(2) => ::std::option::Option::Some("MEMBER"),
+                    Self(_) => ::std::option::Option::None,
+                }
+            }
+            pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
+                match value {
+                    1 => Some(Self::ADMIN),
+                    2 => Some(Self::MEMBER),
+                    _ => None,
+                }
+            }
+        }
+        impl ::std::convert::From<i32> for Role {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+        impl ::std::convert::From<Role> for i32 {
+            fn from(value: Role) -> i32 {
+                value.0
+            }
+        }
+        impl ::std::fmt::Display for Role {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => {
+                        ::std::fmt::Display::fmt(&self.inner(), f)
+                    }
+                }
+            }
+        }
+        impl ::std::str::FromStr for Role {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "ADMIN" => ::std::result::Result::Ok(Self::ADMIN),
+                    "MEMBER" => ::std::result::Result::Ok(Self::MEMBER),
+                    _ => {
+                        s.parse::<i32>()
+                            .map(Self::from)
+                            .map_err(|_| ::pilota::EnumConvertError::InvalidNum(
+                                s.to_string(),
+                                "Role",
+                            ))
+                    }
+                }
+            }
+        }
+        impl ::pilota::thrift::Message for Role {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_i32(self.inner())?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let value = __protocol.read_i32()?;
+                ::std::result::Result::Ok(
+                    ::std::convert::TryFrom::try_from(value)
+                        .map_err(|err| ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            format!("invalid enum value for Role, value: {}", value),
+                        ))?,
+                )
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let value = __protocol.read_i32().await?;
+                    ::std::result::Result::Ok(
+                        ::std::convert::TryFrom::try_from(value)
+                            .map_err(|err| ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("invalid enum value for Role, value: {}", value),
+                            ))?,
+                    )
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.i32_len(self.inner())
+            }
+        }
+        impl<DB: ::sqlx::Database> ::sqlx::Type<DB> for Plan
+        where
+            ::std::string::String: ::sqlx::Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <::std::string::String as ::sqlx::Type<DB>>::type_info()
+            }
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <::std::string::String as ::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+        impl<'q, DB: ::sqlx::Database> ::sqlx::Encode<'q, DB> for Plan
+        where
+            ::std::string::String: ::sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as ::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> ::std::result::Result<
+                ::sqlx::encode::IsNull,
+                ::sqlx::error::BoxDynError,
+            > {
+                <::std::string::String as ::sqlx::Encode<
+                    'q,
+                    DB,
+                >>::encode(self.to_string(), buf)
+            }
+        }
+        impl<'r, DB: ::sqlx::Database> ::sqlx::Decode<'r, DB> for Plan
+        where
+            ::std::string::String: ::sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: <DB as ::sqlx::Database>::ValueRef<'r>,
+            ) -> ::std::result::Result<Self, ::sqlx::error::BoxDynError> {
+                let value = <::std::string::String as ::sqlx::Decode<
+                    'r,
+                    DB,
+                >>::decode(value)?;
+                match value.as_str() {
+                    "FREE" => ::std::result::Result::Ok(Self::FREE),
+                    "PRO" => ::std::result::Result::Ok(Self::PRO),
+                    _ => {
+                        ::std::result::Result::Err(
+                            ::std::format!("invalid value `{value}` for enum `Plan`")
+                                .into(),
+                        )
+                    }
+                }
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(::pilota::serde::Serialize, ::pilota::serde::Deserialize)]
+        #[serde(transparent)]
+        #[derive(Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Plan(i32);
+        impl Plan {
+            pub const FREE: Self = Self(0);
+            pub const PRO: Self = Self(1);
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
+                match self {
+                    Self(0) => ::std::option::Option::Some("FREE"),
+                    Self(1) => ::std::option::Option::Some("PRO"),
+                    Self(_) => ::std::option::Option::None,
+                }
+            }
+            pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
+                match value {
+                    0 => Some(Self::FREE),
+                    1 => Some(Self::PRO),
+                    _ => None,
+                }
+            }
+        }
+        impl ::std::convert::From<i32> for Plan {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+        impl ::std::convert::From<Plan> for i32 {
+            fn from(value: Plan) -> i32 {
+                value.0
+            }
+        }
+        impl ::std::fmt::Display for Plan {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => {
+                        ::std::fmt::Display::fmt(&self.inner(), f)
+                    }
+                }
+            }
+        }
+        impl ::std::str::FromStr for Plan {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "FREE" => ::std::result::Result::Ok(Self::FREE),
+                    "PRO" => ::std::result::Result::Ok(Self::PRO),
+                    _ => {
+                        s.parse::<i32>()
+                            .map(Self::from)
+                            .map_err(|_| ::pilota::EnumConvertError::InvalidNum(
+                                s.to_string(),
+                                "Plan",
+                            ))
+                    }
+                }
+            }
+        }
+        impl ::pilota::thrift::Message for Plan {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_i32(self.inner())?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let value = __protocol.read_i32()?;
+                ::std::result::Result::Ok(
+                    ::std::convert::TryFrom::try_from(value)
+                        .map_err(|err| ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            format!("invalid enum value for Plan, value: {}", value),
+                        ))?,
+                )
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let value = __protocol.read_i32().await?;
+                    ::std::result::Result::Ok(
+                        ::std::convert::TryFrom::try_from(value)
+                            .map_err(|err| ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("invalid enum value for Plan, value: {}", value),
+                            ))?,
+                    )
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.i32_len(self.inner())
+            }
+        }
+        impl<DB: ::sqlx::Database> ::sqlx::Type<DB> for Profile
+        where
+            ::sqlx::types::Json<Self>: ::sqlx::Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <::sqlx::types::Json<Self> as ::sqlx::Type<DB>>::type_info()
+            }
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <::sqlx::types::Json<Self> as ::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+        impl<'q, DB: ::sqlx::Database> ::sqlx::Encode<'q, DB> for Profile
+        where
+            for<'a> ::sqlx::types::Json<&'a Self>: ::sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as ::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> ::std::result::Result<
+                ::sqlx::encode::IsNull,
+                ::sqlx::error::BoxDynError,
+            > {
+                <::sqlx::types::Json<
+                    &Self,
+                > as ::sqlx::Encode<'q, DB>>::encode(::sqlx::types::Json(self), buf)
+            }
+        }
+        impl<'r, DB: ::sqlx::Database> ::sqlx::Decode<'r, DB> for Profile
+        where
+            ::sqlx::types::Json<Self>: ::sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: <DB as ::sqlx::Database>::ValueRef<'r>,
+            ) -> ::std::result::Result<Self, ::sqlx::error::BoxDynError> {
+                ::std::result::Result::Ok(
+                    <::sqlx::types::Json<Self> as ::sqlx::Decode<'r, DB>>::decode(value)?
+                        .0,
+                )
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(::pilota::serde::Serialize, ::pilota::serde::Deserialize)]
+        #[derive(Clone, PartialEq)]
+        pub struct Profile {
+            pub name: ::pilota::FastStr,
+            pub tags: ::std::option::Option<::std::vec::Vec<::pilota::FastStr>>,
+        }
+        impl ::pilota::thrift::Message for Profile {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "Profile",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.name).clone())?;
+                if let Some(value) = self.tags.as_ref() {
+                    __protocol
+                        .write_list_field(
+                            2,
+                            ::pilota::thrift::TType::Binary,
+                            &value,
+                            |__protocol, val| {
+                                __protocol.write_faststr((val).clone())?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Binary => {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::List => {
+                                var_2 = Some({
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(
+                                        list_ident.size,
+                                    );
+                                    for _ in 0..list_ident.size {
+                                        val.push(__protocol.read_faststr()?);
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `Profile` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field name is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { name: var_1, tags: var_2 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Binary => {
+                                    var_1 = Some(__protocol.read_faststr().await?);
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::List => {
+                                    var_2 = Some({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val = ::std::vec::Vec::with_capacity(
+                                            list_ident.size,
+                                        );
+                                        for _ in 0..list_ident.size {
+                                            val.push(__protocol.read_faststr().await?);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    });
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `Profile` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { name: var_1, tags: var_2 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Profile",
+                        },
+                    ) + __protocol.faststr_field_len(Some(1), &self.name)
+                    + self
+                        .tags
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol
+                                    .list_field_len(
+                                        Some(2),
+                                        ::pilota::thrift::TType::Binary,
+                                        value,
+                                        |__protocol, el| { __protocol.faststr_len(el) },
+                                    )
+                            },
+                        ) + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(::pilota::serde::Serialize, ::pilota::serde::Deserialize)]
+        #[derive(Clone, PartialEq)]
+        pub struct User {
+            pub id: i64,
+            pub role: Role,
+            pub plan: Plan,
+            pub profile: Profile,
+        }
+        impl ::pilota::thrift::Message for User {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "User",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.id)?;
+                __protocol.write_i32_field(2, (&self.role).inner())?;
+                __protocol.write_i32_field(3, (&self.plan).inner())?;
+                __protocol
+                    .write_struct_field(
+                        4,
+                        &self.profile,
+                        ::pilota::thrift::TType::Struct,
+                    )?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I64 => {
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                var_2 = Some(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                            }
+                            Some(
+                                3,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                var_3 = Some(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                            }
+                            Some(
+                                4,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Struct => {
+                                var_4 = Some(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `User` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    )
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field role is required".to_string(),
+                        ),
+                    )
+                };
+                let Some(var_3) = var_3 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field plan is required".to_string(),
+                        ),
+                    )
+                };
+                let Some(var_4) = var_4 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field profile is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self {
+                    id: var_1,
+                    role: var_2,
+                    plan: var_3,
+                    profile: var_4,
+                };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I64 => {
+                                    var_1 = Some(__protocol.read_i64().await?);
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    var_2 = Some(
+                                        <Role as ::pilota::thrift::Message>::decode_async(
+                                                __protocol,
+                                            )
+                                            .await?,
+                                    );
+                                }
+                                Some(
+                                    3,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    var_3 = Some(
+                                        <Plan as ::pilota::thrift::Message>::decode_async(
+                                                __protocol,
+                                            )
+                                            .await?,
+                                    );
+                                }
+                                Some(
+                                    4,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Struct => {
+                                    var_4 = Some(
+                                        <Profile as ::pilota::thrift::Message>::decode_async(
+                                                __protocol,
+                                            )
+                                            .await?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `User` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        )
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field role is required".to_string(),
+                            ),
+                        )
+                    };
+                    let Some(var_3) = var_3 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field plan is required".to_string(),
+                            ),
+                        )
+                    };
+                    let Some(var_4) = var_4 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field profile is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self {
+                        id: var_1,
+                        role: var_2,
+                        plan: var_3,
+                        profile: var_4,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "User",
+                        },
+                    ) + __protocol.i64_field_len(Some(1), *&self.id)
+                    + __protocol.i32_field_len(Some(2), (&self.role).inner())
+                    + __protocol.i32_field_len(Some(3), (&self.plan).inner())
+                    + __protocol.struct_field_len(Some(4), &self.profile)
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+    }
+}
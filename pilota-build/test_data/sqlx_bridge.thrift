@@ -0,0 +1,21 @@
+enum Role {
+    ADMIN = 1,
+    MEMBER = 2,
+} (pilota.sqlx = "int")
+
+enum Plan {
+    FREE = 0,
+    PRO = 1,
+} (pilota.sqlx = "text")
+
+struct Profile {
+    1: required string name,
+    2: optional list<string> tags,
+} (pilota.sqlx = "json")
+
+struct User {
+    1: required i64 id,
+    2: required Role role,
+    3: required Plan plan,
+    4: required Profile profile,
+}
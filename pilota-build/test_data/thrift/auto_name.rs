@@ -156,172 +156,27 @@ pub mod auto_name {
                     + __protocol.struct_end_len()
             }
         }
+        pub const ip: &'static str = "ip";
+        pub const IP: &'static str = "IP";
 
-        pub trait service {}
-
-        impl ::std::default::Default for serviceTest2ResultRecv {
-            fn default() -> Self {
-                serviceTest2ResultRecv::Ok(::std::default::Default::default())
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum serviceTest2ResultRecv {
-            Ok(Test),
-        }
-
-        impl ::pilota::thrift::Message for serviceTest2ResultRecv {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTest2ResultRecv",
-                })?;
-                match self {
-                    serviceTest2ResultRecv::Ok(value) => {
-                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
-                    }
-                }
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
-
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let mut ret = None;
-                __protocol.read_struct_begin()?;
-                loop {
-                    let field_ident = __protocol.read_field_begin()?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        __protocol.field_stop_len();
-                        break;
-                    } else {
-                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                    }
-                    match field_ident.id {
-                        Some(0) => {
-                            if ret.is_none() {
-                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(serviceTest2ResultRecv::Ok(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
-                            }
-                        }
-                        _ => {
-                            __protocol.skip(field_ident.field_type)?;
-                        }
-                    }
-                }
-                __protocol.read_field_end()?;
-                __protocol.read_struct_end()?;
-                if let Some(ret) = ret {
-                    ::std::result::Result::Ok(ret)
-                } else {
-                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "received empty union from remote Message",
-                    ))
-                }
-            }
-
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    let mut ret = None;
-                    __protocol.read_struct_begin().await?;
-                    loop {
-                        let field_ident = __protocol.read_field_begin().await?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            break;
-                        } else {
-                        }
-                        match field_ident.id {
-                            Some(0) => {
-                                if ret.is_none() {
-                                    let field_ident =
-                                        <Test as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?;
-
-                                    ret = Some(serviceTest2ResultRecv::Ok(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
-                                    );
-                                }
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
-                            }
-                        }
-                    }
-                    __protocol.read_field_end().await?;
-                    __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
-                    } else {
-                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            "received empty union from remote Message",
-                        ))
-                    }
-                })
-            }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTest2ResultRecv",
-                }) + match self {
-                    serviceTest2ResultRecv::Ok(value) => {
-                        __protocol.struct_field_len(Some(0), value)
-                    }
-                } + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct servicetestArgsSend {
-            pub req: TEST,
+        pub struct Test {
+            pub ID: ::pilota::FastStr,
+
+            pub Id: ::pilota::FastStr,
         }
-        impl ::pilota::thrift::Message for servicetestArgsSend {
+        impl ::pilota::thrift::Message for Test {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "servicetestArgsSend",
-                };
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Test" };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_faststr_field(1, (&self.ID).clone())?;
+                __protocol.write_faststr_field(2, (&self.Id).clone())?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -334,6 +189,7 @@ pub mod auto_name {
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
                 let mut var_1 = None;
+                let mut var_2 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -350,9 +206,14 @@ pub mod auto_name {
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
                             Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
                             {
-                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(2)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_2 = Some(__protocol.read_faststr()?);
                             }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
@@ -366,7 +227,7 @@ pub mod auto_name {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `servicetestArgsSend` field(#{}) failed, caused by: ",
+                            "decode struct `Test` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -377,11 +238,20 @@ pub mod auto_name {
                 let Some(var_1) = var_1 else {
                     return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
+                        "field ID is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field Id is required".to_string(),
                     ));
                 };
 
-                let data = Self { req: var_1 };
+                let data = Self {
+                    ID: var_1,
+                    Id: var_2,
+                };
                 ::std::result::Result::Ok(data)
             }
 
@@ -397,6 +267,7 @@ pub mod auto_name {
             > {
                 ::std::boxed::Box::pin(async move {
                     let mut var_1 = None;
+                    let mut var_2 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
@@ -412,14 +283,15 @@ pub mod auto_name {
                             match field_ident.id {
                                 Some(1)
                                     if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
+                                        == ::pilota::thrift::TType::Binary =>
                                 {
-                                    var_1 = Some(
-                                        <TEST as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    );
+                                    var_1 = Some(__protocol.read_faststr().await?);
+                                }
+                                Some(2)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_2 = Some(__protocol.read_faststr().await?);
                                 }
                                 _ => {
                                     __protocol.skip(field_ident.field_type).await?;
@@ -433,7 +305,10 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `servicetestArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_msg(&format!(
+                                "decode struct `Test` field(#{}) failed, caused by: ",
+                                field_id
+                            ));
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -443,12 +318,23 @@ pub mod auto_name {
                         return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
+                                "field ID is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field Id is required".to_string(),
                             ),
                         );
                     };
 
-                    let data = Self { req: var_1 };
+                    let data = Self {
+                        ID: var_1,
+                        Id: var_2,
+                    };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -456,32 +342,87 @@ pub mod auto_name {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "servicetestArgsSend",
-                }) + __protocol.struct_field_len(Some(1), &self.req)
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Test" })
+                    + __protocol.faststr_field_len(Some(1), &self.ID)
+                    + __protocol.faststr_field_len(Some(2), &self.Id)
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
 
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct TestException {}
-        impl ::pilota::thrift::Message for TestException {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "TestException",
-                };
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Index(i32);
 
-                __protocol.write_struct_begin(&struct_ident)?;
+        impl Index {
+            pub const A: Self = Self(0);
+            pub const a: Self = Self(1);
 
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
+                match self {
+                    Self(0) => ::std::option::Option::Some("A"),
+                    Self(1) => ::std::option::Option::Some("a"),
+                    Self(_) => ::std::option::Option::None,
+                }
+            }
+
+            pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
+                match value {
+                    0 => Some(Self::A),
+                    1 => Some(Self::a),
+                    _ => None,
+                }
+            }
+        }
+
+        impl ::std::convert::From<i32> for Index {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::std::convert::From<Index> for i32 {
+            fn from(value: Index) -> i32 {
+                value.0
+            }
+        }
+
+        impl ::std::fmt::Display for Index {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => ::std::fmt::Display::fmt(&self.inner(), f),
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for Index {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "A" => ::std::result::Result::Ok(Self::A),
+                    "a" => ::std::result::Result::Ok(Self::a),
+                    _ => s.parse::<i32>().map(Self::from).map_err(|_| {
+                        ::pilota::EnumConvertError::InvalidNum(s.to_string(), "Index")
+                    }),
+                }
+            }
+        }
+
+        impl ::pilota::thrift::Message for Index {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_i32(self.inner())?;
+                ::std::result::Result::Ok(())
             }
 
             fn decode<T: ::pilota::thrift::TInputProtocol>(
@@ -489,43 +430,15 @@ pub mod auto_name {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `TestException` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let data = Self {};
-                ::std::result::Result::Ok(data)
+                let value = __protocol.read_i32()?;
+                ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                    |err| {
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            format!("invalid enum value for Index, value: {}", value),
+                        )
+                    },
+                )?)
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -539,73 +452,37 @@ pub mod auto_name {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut __pilota_decoding_field_id = None;
-
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
-
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `TestException` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
-
-                    let data = Self {};
-                    ::std::result::Result::Ok(data)
+                    let value = __protocol.read_i32().await?;
+                    ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                        |err| {
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("invalid enum value for Index, value: {}", value),
+                            )
+                        },
+                    )?)
                 })
             }
 
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestException",
-                }) + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
+                __protocol.i32_len(self.inner())
             }
         }
-        pub const ip: &'static str = "ip";
+
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct servicetestArgsRecv {
-            pub req: TEST,
-        }
-        impl ::pilota::thrift::Message for servicetestArgsRecv {
+        pub struct TestException {}
+        impl ::pilota::thrift::Message for TestException {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "servicetestArgsRecv",
-                };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
+                __protocol.write_empty_struct(&::pilota::thrift::TStructIdentifier {
+                    name: "TestException",
+                })?;
                 ::std::result::Result::Ok(())
             }
 
@@ -614,57 +491,8 @@ pub mod auto_name {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut var_1 = None;
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
-                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `servicetestArgsRecv` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let Some(var_1) = var_1 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
-                    ));
-                };
-
-                let data = Self { req: var_1 };
-                ::std::result::Result::Ok(data)
+                __protocol.read_empty_struct()?;
+                ::std::result::Result::Ok(Self {})
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -678,86 +506,32 @@ pub mod auto_name {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
+                    __protocol.read_empty_struct().await?;
+                    ::std::result::Result::Ok(Self {})
+                })
+            }
 
-                    let mut __pilota_decoding_field_id = None;
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.empty_struct_len(&::pilota::thrift::TStructIdentifier {
+                    name: "TestException",
+                })
+            }
+        }
+        impl ::std::default::Default for ServicetestResultRecv {
+            fn default() -> Self {
+                ServicetestResultRecv::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum ServicetestResultRecv {
+            Ok(::std::sync::Arc<Test>),
 
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_1 = Some(
-                                        <TEST as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    );
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
+            E(TestException),
+        }
 
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `servicetestArgsRecv` field(#{}) failed, caused by: ", field_id));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
-
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self { req: var_1 };
-                    ::std::result::Result::Ok(data)
-                })
-            }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "servicetestArgsRecv",
-                }) + __protocol.struct_field_len(Some(1), &self.req)
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
-        impl ::std::default::Default for servicetestResultSend {
-            fn default() -> Self {
-                servicetestResultSend::Ok(::std::default::Default::default())
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum servicetestResultSend {
-            Ok(Test),
-
-            E(TestException),
-        }
-
-        impl ::pilota::thrift::Message for servicetestResultSend {
+        impl ::pilota::thrift::Message for ServicetestResultRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -765,13 +539,13 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "servicetestResultSend",
+                    name: "ServicetestResultRecv",
                 })?;
                 match self {
-                    servicetestResultSend::Ok(value) => {
+                    ServicetestResultRecv::Ok(value) => {
                         __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
                     }
-                    servicetestResultSend::E(value) => {
+                    ServicetestResultRecv::E(value) => {
                         __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
                     }
                 }
@@ -798,9 +572,11 @@ pub mod auto_name {
                     match field_ident.id {
                         Some(0) => {
                             if ret.is_none() {
-                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                let field_ident = ::std::sync::Arc::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(servicetestResultSend::Ok(field_ident));
+                                ret = Some(ServicetestResultRecv::Ok(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -814,7 +590,7 @@ pub mod auto_name {
                             if ret.is_none() {
                                 let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(servicetestResultSend::E(field_ident));
+                                ret = Some(ServicetestResultRecv::E(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -863,13 +639,14 @@ pub mod auto_name {
                         match field_ident.id {
                             Some(0) => {
                                 if ret.is_none() {
-                                    let field_ident =
+                                    let field_ident = ::std::sync::Arc::new(
                                         <Test as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
-                                        .await?;
+                                        .await?,
+                                    );
 
-                                    ret = Some(servicetestResultSend::Ok(field_ident));
+                                    ret = Some(ServicetestResultRecv::Ok(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -887,7 +664,7 @@ pub mod auto_name {
                                         )
                                         .await?;
 
-                                    ret = Some(servicetestResultSend::E(field_ident));
+                                    ret = Some(ServicetestResultRecv::E(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -919,31 +696,44 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "servicetestResultSend",
+                    name: "ServicetestResultRecv",
                 }) + match self {
-                    servicetestResultSend::Ok(value) => __protocol.struct_field_len(Some(0), value),
-                    servicetestResultSend::E(value) => __protocol.struct_field_len(Some(1), value),
+                    ServicetestResultRecv::Ok(value) => __protocol.struct_field_len(Some(0), value),
+                    ServicetestResultRecv::E(value) => __protocol.struct_field_len(Some(1), value),
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct ServiceTest2ArgsSend {
-            pub r#type: ::std::sync::Arc<TEST>,
+        impl ::std::default::Default for ServicetestResultSend {
+            fn default() -> Self {
+                ServicetestResultSend::Ok(::std::default::Default::default())
+            }
         }
-        impl ::pilota::thrift::Message for ServiceTest2ArgsSend {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum ServicetestResultSend {
+            Ok(::std::sync::Arc<Test>),
+
+            E(TestException),
+        }
+
+        impl ::pilota::thrift::Message for ServicetestResultSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTest2ArgsSend",
-                };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.r#type, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "ServicetestResultSend",
+                })?;
+                match self {
+                    ServicetestResultSend::Ok(value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                    ServicetestResultSend::E(value) => {
+                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -954,59 +744,62 @@ pub mod auto_name {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut var_1 = None;
-
-                let mut __pilota_decoding_field_id = None;
-
+                let mut ret = None;
                 __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
-                                var_1 = Some(::std::sync::Arc::new(
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::std::sync::Arc::new(
                                     ::pilota::thrift::Message::decode(__protocol)?,
-                                ));
+                                );
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ServicetestResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
                             }
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
+                        }
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ServicetestResultSend::E(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
                             }
                         }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ServiceTest2ArgsSend` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
                     }
-                    return ::std::result::Result::Err(err);
-                };
+                }
+                __protocol.read_field_end()?;
                 __protocol.read_struct_end()?;
-
-                let Some(var_1) = var_1 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field r#type is required".to_string(),
-                    ));
-                };
-
-                let data = Self { r#type: var_1 };
-                ::std::result::Result::Ok(data)
+                        "received empty union from remote Message",
+                    ))
+                }
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -1020,220 +813,67 @@ pub mod auto_name {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
-
-                    let mut __pilota_decoding_field_id = None;
-
+                    let mut ret = None;
                     __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_1 = Some(::std::sync::Arc::new(
-                                        <TEST as ::pilota::thrift::Message>::decode_async(
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident = ::std::sync::Arc::new(
+                                        <Test as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
                                         .await?,
-                                    ));
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
+                                    );
+
+                                    ret = Some(ServicetestResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
                                 }
                             }
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <TestException as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
 
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ServiceTest2ArgsSend` field(#{}) failed, caused by: ", field_id));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
-
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field r#type is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self { r#type: var_1 };
-                    ::std::result::Result::Ok(data)
-                })
-            }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTest2ArgsSend",
-                }) + __protocol.struct_field_len(Some(1), &self.r#type)
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct ServiceTestArgsSend {
-            pub req: ::std::sync::Arc<TEST>,
-        }
-        impl ::pilota::thrift::Message for ServiceTestArgsSend {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTestArgsSend",
-                };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
-
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut var_1 = None;
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
-                                var_1 = Some(::std::sync::Arc::new(
-                                    ::pilota::thrift::Message::decode(__protocol)?,
-                                ));
+                                    ret = Some(ServicetestResultSend::E(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
                             }
                             _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ServiceTestArgsSend` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let Some(var_1) = var_1 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
-                    ));
-                };
-
-                let data = Self { req: var_1 };
-                ::std::result::Result::Ok(data)
-            }
-
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
-
-                    let mut __pilota_decoding_field_id = None;
-
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_1 = Some(::std::sync::Arc::new(
-                                        <TEST as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    ));
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
+                                __protocol.skip(field_ident.field_type).await?;
                             }
-
-                            __protocol.read_field_end().await?;
                         }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ServiceTestArgsSend` field(#{}) failed, caused by: ", field_id));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
+                    __protocol.read_field_end().await?;
                     __protocol.read_struct_end().await?;
-
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self { req: var_1 };
-                    ::std::result::Result::Ok(data)
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
                 })
             }
 
@@ -1241,9 +881,11 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTestArgsSend",
-                }) + __protocol.struct_field_len(Some(1), &self.req)
-                    + __protocol.field_stop_len()
+                    name: "ServicetestResultSend",
+                }) + match self {
+                    ServicetestResultSend::Ok(value) => __protocol.struct_field_len(Some(0), value),
+                    ServicetestResultSend::E(value) => __protocol.struct_field_len(Some(1), value),
+                } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
@@ -1391,36 +1033,26 @@ pub mod auto_name {
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for ServicetestResultRecv {
-            fn default() -> Self {
-                ServicetestResultRecv::Ok(::std::default::Default::default())
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum ServicetestResultRecv {
-            Ok(::std::sync::Arc<Test>),
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct ServicetestArgsSend {
+            pub req: ::std::sync::Arc<TEST>,
 
-            E(TestException),
+            pub Req: ::std::sync::Arc<TEST>,
         }
-
-        impl ::pilota::thrift::Message for ServicetestResultRecv {
+        impl ::pilota::thrift::Message for ServicetestArgsSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "ServicetestResultRecv",
-                })?;
-                match self {
-                    ServicetestResultRecv::Ok(value) => {
-                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
-                    }
-                    ServicetestResultRecv::E(value) => {
-                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
-                    }
-                }
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "ServicetestArgsSend",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_struct_field(2, &self.Req, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -1431,62 +1063,76 @@ pub mod auto_name {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let mut ret = None;
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
                 __protocol.read_struct_begin()?;
-                loop {
-                    let field_ident = __protocol.read_field_begin()?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        __protocol.field_stop_len();
-                        break;
-                    } else {
-                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                    }
-                    match field_ident.id {
-                        Some(0) => {
-                            if ret.is_none() {
-                                let field_ident = ::std::sync::Arc::new(
-                                    ::pilota::thrift::Message::decode(__protocol)?,
-                                );
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(ServicetestResultRecv::Ok(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
-                            }
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
                         }
-                        Some(1) => {
-                            if ret.is_none() {
-                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(ServicetestResultRecv::E(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_1 = Some(::std::sync::Arc::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                ));
+                            }
+                            Some(2)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_2 = Some(::std::sync::Arc::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                ));
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
                             }
                         }
-                        _ => {
-                            __protocol.skip(field_ident.field_type)?;
-                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
                     }
-                }
-                __protocol.read_field_end()?;
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(&format!(
+                            "decode struct `ServicetestArgsSend` field(#{}) failed, caused by: ",
+                            field_id
+                        ));
+                    }
+                    return ::std::result::Result::Err(err);
+                };
                 __protocol.read_struct_end()?;
-                if let Some(ret) = ret {
-                    ::std::result::Result::Ok(ret)
-                } else {
-                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "received empty union from remote Message",
-                    ))
-                }
+                        "field req is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field Req is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    req: var_1,
+                    Req: var_2,
+                };
+                ::std::result::Result::Ok(data)
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -1500,67 +1146,83 @@ pub mod auto_name {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut ret = None;
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
                     __protocol.read_struct_begin().await?;
-                    loop {
-                        let field_ident = __protocol.read_field_begin().await?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            break;
-                        } else {
-                        }
-                        match field_ident.id {
-                            Some(0) => {
-                                if ret.is_none() {
-                                    let field_ident = ::std::sync::Arc::new(
-                                        <Test as ::pilota::thrift::Message>::decode_async(
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(::std::sync::Arc::new(
+                                        <TEST as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
                                         .await?,
-                                    );
-
-                                    ret = Some(ServicetestResultRecv::Ok(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
-                                    );
+                                    ));
                                 }
-                            }
-                            Some(1) => {
-                                if ret.is_none() {
-                                    let field_ident =
-                                        <TestException as ::pilota::thrift::Message>::decode_async(
+                                Some(2)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_2 = Some(::std::sync::Arc::new(
+                                        <TEST as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
-                                        .await?;
-
-                                    ret = Some(ServicetestResultRecv::E(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
-                                    );
+                                        .await?,
+                                    ));
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
                                 }
                             }
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
-                            }
+
+                            __protocol.read_field_end().await?;
                         }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     }
-                    __protocol.read_field_end().await?;
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!("decode struct `ServicetestArgsSend` field(#{}) failed, caused by: ", field_id));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
                     __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
-                    } else {
-                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            "received empty union from remote Message",
-                        ))
-                    }
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field req is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field Req is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        req: var_1,
+                        Req: var_2,
+                    };
+                    ::std::result::Result::Ok(data)
                 })
             }
 
@@ -1568,40 +1230,33 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServicetestResultRecv",
-                }) + match self {
-                    ServicetestResultRecv::Ok(value) => __protocol.struct_field_len(Some(0), value),
-                    ServicetestResultRecv::E(value) => __protocol.struct_field_len(Some(1), value),
-                } + __protocol.field_stop_len()
+                    name: "ServicetestArgsSend",
+                }) + __protocol.struct_field_len(Some(1), &self.req)
+                    + __protocol.struct_field_len(Some(2), &self.Req)
+                    + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        pub const IP: &'static str = "IP";
-        impl ::std::default::Default for serviceTest2ResultSend {
-            fn default() -> Self {
-                serviceTest2ResultSend::Ok(::std::default::Default::default())
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum serviceTest2ResultSend {
-            Ok(Test),
-        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct ServicetestArgsRecv {
+            pub req: ::std::sync::Arc<TEST>,
 
-        impl ::pilota::thrift::Message for serviceTest2ResultSend {
+            pub Req: ::std::sync::Arc<TEST>,
+        }
+        impl ::pilota::thrift::Message for ServicetestArgsRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTest2ResultSend",
-                })?;
-                match self {
-                    serviceTest2ResultSend::Ok(value) => {
-                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
-                    }
-                }
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "ServicetestArgsRecv",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_struct_field(2, &self.Req, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -1612,46 +1267,76 @@ pub mod auto_name {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let mut ret = None;
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
                 __protocol.read_struct_begin()?;
-                loop {
-                    let field_ident = __protocol.read_field_begin()?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        __protocol.field_stop_len();
-                        break;
-                    } else {
-                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                    }
-                    match field_ident.id {
-                        Some(0) => {
-                            if ret.is_none() {
-                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(serviceTest2ResultSend::Ok(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
-                            }
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
                         }
-                        _ => {
-                            __protocol.skip(field_ident.field_type)?;
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_1 = Some(::std::sync::Arc::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                ));
+                            }
+                            Some(2)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_2 = Some(::std::sync::Arc::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                ));
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
                         }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
                     }
-                }
-                __protocol.read_field_end()?;
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(&format!(
+                            "decode struct `ServicetestArgsRecv` field(#{}) failed, caused by: ",
+                            field_id
+                        ));
+                    }
+                    return ::std::result::Result::Err(err);
+                };
                 __protocol.read_struct_end()?;
-                if let Some(ret) = ret {
-                    ::std::result::Result::Ok(ret)
-                } else {
-                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "received empty union from remote Message",
-                    ))
-                }
+                        "field req is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field Req is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    req: var_1,
+                    Req: var_2,
+                };
+                ::std::result::Result::Ok(data)
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -1665,48 +1350,83 @@ pub mod auto_name {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut ret = None;
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
                     __protocol.read_struct_begin().await?;
-                    loop {
-                        let field_ident = __protocol.read_field_begin().await?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            break;
-                        } else {
-                        }
-                        match field_ident.id {
-                            Some(0) => {
-                                if ret.is_none() {
-                                    let field_ident =
-                                        <Test as ::pilota::thrift::Message>::decode_async(
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(::std::sync::Arc::new(
+                                        <TEST as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
-                                        .await?;
-
-                                    ret = Some(serviceTest2ResultSend::Ok(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
-                                    );
+                                        .await?,
+                                    ));
+                                }
+                                Some(2)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_2 = Some(::std::sync::Arc::new(
+                                        <TEST as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    ));
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
                                 }
                             }
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
-                            }
+
+                            __protocol.read_field_end().await?;
                         }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     }
-                    __protocol.read_field_end().await?;
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!("decode struct `ServicetestArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
                     __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
-                    } else {
-                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            "received empty union from remote Message",
-                        ))
-                    }
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field req is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field Req is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        req: var_1,
+                        Req: var_2,
+                    };
+                    ::std::result::Result::Ok(data)
                 })
             }
 
@@ -1714,26 +1434,26 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTest2ResultSend",
-                }) + match self {
-                    serviceTest2ResultSend::Ok(value) => {
-                        __protocol.struct_field_len(Some(0), value)
-                    }
-                } + __protocol.field_stop_len()
+                    name: "ServicetestArgsRecv",
+                }) + __protocol.struct_field_len(Some(1), &self.req)
+                    + __protocol.struct_field_len(Some(2), &self.Req)
+                    + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for serviceTestException {
+        impl ::std::default::Default for ServiceTestResultRecv {
             fn default() -> Self {
-                serviceTestException::E(::std::default::Default::default())
+                ServiceTestResultRecv::Ok(::std::default::Default::default())
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum serviceTestException {
+        pub enum ServiceTestResultRecv {
+            Ok(::std::sync::Arc<Test>),
+
             E(TestException),
         }
 
-        impl ::pilota::thrift::Message for serviceTestException {
+        impl ::pilota::thrift::Message for ServiceTestResultRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -1741,10 +1461,13 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTestException",
+                    name: "ServiceTestResultRecv",
                 })?;
                 match self {
-                    serviceTestException::E(value) => {
+                    ServiceTestResultRecv::Ok(value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                    ServiceTestResultRecv::E(value) => {
                         __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
                     }
                 }
@@ -1769,11 +1492,27 @@ pub mod auto_name {
                         __protocol.field_begin_len(field_ident.field_type, field_ident.id);
                     }
                     match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::std::sync::Arc::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ServiceTestResultRecv::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
                         Some(1) => {
                             if ret.is_none() {
                                 let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(serviceTestException::E(field_ident));
+                                ret = Some(ServiceTestResultRecv::E(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -1820,15 +1559,16 @@ pub mod auto_name {
                         } else {
                         }
                         match field_ident.id {
-                            Some(1) => {
+                            Some(0) => {
                                 if ret.is_none() {
-                                    let field_ident =
-                                        <TestException as ::pilota::thrift::Message>::decode_async(
+                                    let field_ident = ::std::sync::Arc::new(
+                                        <Test as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
-                                        .await?;
+                                        .await?,
+                                    );
 
-                                    ret = Some(serviceTestException::E(field_ident));
+                                    ret = Some(ServiceTestResultRecv::Ok(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -1838,15 +1578,33 @@ pub mod auto_name {
                                     );
                                 }
                             }
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
-                            }
-                        }
-                    }
-                    __protocol.read_field_end().await?;
-                    __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <TestException as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(ServiceTestResultRecv::E(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
                     } else {
                         ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                             ::pilota::thrift::ProtocolExceptionKind::InvalidData,
@@ -1860,26 +1618,27 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTestException",
+                    name: "ServiceTestResultRecv",
                 }) + match self {
-                    serviceTestException::E(value) => __protocol.struct_field_len(Some(1), value),
+                    ServiceTestResultRecv::Ok(value) => __protocol.struct_field_len(Some(0), value),
+                    ServiceTestResultRecv::E(value) => __protocol.struct_field_len(Some(1), value),
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for serviceTestResultRecv {
+        impl ::std::default::Default for ServiceTestResultSend {
             fn default() -> Self {
-                serviceTestResultRecv::Ok(::std::default::Default::default())
+                ServiceTestResultSend::Ok(::std::default::Default::default())
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum serviceTestResultRecv {
-            Ok(Test),
+        pub enum ServiceTestResultSend {
+            Ok(::std::sync::Arc<Test>),
 
             E(TestException),
         }
 
-        impl ::pilota::thrift::Message for serviceTestResultRecv {
+        impl ::pilota::thrift::Message for ServiceTestResultSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -1887,13 +1646,13 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTestResultRecv",
+                    name: "ServiceTestResultSend",
                 })?;
                 match self {
-                    serviceTestResultRecv::Ok(value) => {
+                    ServiceTestResultSend::Ok(value) => {
                         __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
                     }
-                    serviceTestResultRecv::E(value) => {
+                    ServiceTestResultSend::E(value) => {
                         __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
                     }
                 }
@@ -1920,9 +1679,11 @@ pub mod auto_name {
                     match field_ident.id {
                         Some(0) => {
                             if ret.is_none() {
-                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                let field_ident = ::std::sync::Arc::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(serviceTestResultRecv::Ok(field_ident));
+                                ret = Some(ServiceTestResultSend::Ok(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -1936,7 +1697,7 @@ pub mod auto_name {
                             if ret.is_none() {
                                 let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(serviceTestResultRecv::E(field_ident));
+                                ret = Some(ServiceTestResultSend::E(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -1985,13 +1746,32 @@ pub mod auto_name {
                         match field_ident.id {
                             Some(0) => {
                                 if ret.is_none() {
-                                    let field_ident =
+                                    let field_ident = ::std::sync::Arc::new(
                                         <Test as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
+                                        .await?,
+                                    );
+
+                                    ret = Some(ServiceTestResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <TestException as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
                                         .await?;
 
-                                    ret = Some(serviceTestResultRecv::Ok(field_ident));
+                                    ret = Some(ServiceTestResultSend::E(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -2001,6 +1781,133 @@ pub mod auto_name {
                                     );
                                 }
                             }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "ServiceTestResultSend",
+                }) + match self {
+                    ServiceTestResultSend::Ok(value) => __protocol.struct_field_len(Some(0), value),
+                    ServiceTestResultSend::E(value) => __protocol.struct_field_len(Some(1), value),
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for ServiceTestException {
+            fn default() -> Self {
+                ServiceTestException::E(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum ServiceTestException {
+            E(TestException),
+        }
+
+        impl ::pilota::thrift::Message for ServiceTestException {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "ServiceTestException",
+                })?;
+                match self {
+                    ServiceTestException::E(value) => {
+                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ServiceTestException::E(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
                             Some(1) => {
                                 if ret.is_none() {
                                     let field_ident =
@@ -2009,7 +1916,7 @@ pub mod auto_name {
                                         )
                                         .await?;
 
-                                    ret = Some(serviceTestResultRecv::E(field_ident));
+                                    ret = Some(ServiceTestException::E(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -2041,19 +1948,18 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTestResultRecv",
+                    name: "ServiceTestException",
                 }) + match self {
-                    serviceTestResultRecv::Ok(value) => __protocol.struct_field_len(Some(0), value),
-                    serviceTestResultRecv::E(value) => __protocol.struct_field_len(Some(1), value),
+                    ServiceTestException::E(value) => __protocol.struct_field_len(Some(1), value),
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct ServiceTest2ArgsRecv {
-            pub r#type: ::std::sync::Arc<TEST>,
+        pub struct ServiceTestArgsSend {
+            pub req: ::std::sync::Arc<TEST>,
         }
-        impl ::pilota::thrift::Message for ServiceTest2ArgsRecv {
+        impl ::pilota::thrift::Message for ServiceTestArgsSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -2061,11 +1967,11 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTest2ArgsRecv",
+                    name: "ServiceTestArgsSend",
                 };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.r#type, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -2112,7 +2018,7 @@ pub mod auto_name {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `ServiceTest2ArgsRecv` field(#{}) failed, caused by: ",
+                            "decode struct `ServiceTestArgsSend` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -2123,11 +2029,11 @@ pub mod auto_name {
                 let Some(var_1) = var_1 else {
                     return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field r#type is required".to_string(),
+                        "field req is required".to_string(),
                     ));
                 };
 
-                let data = Self { r#type: var_1 };
+                let data = Self { req: var_1 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -2179,7 +2085,7 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ServiceTest2ArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_msg(&format!("decode struct `ServiceTestArgsSend` field(#{}) failed, caused by: ", field_id));
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -2189,12 +2095,12 @@ pub mod auto_name {
                         return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field r#type is required".to_string(),
+                                "field req is required".to_string(),
                             ),
                         );
                     };
 
-                    let data = Self { r#type: var_1 };
+                    let data = Self { req: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -2203,8 +2109,8 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTest2ArgsRecv",
-                }) + __protocol.struct_field_len(Some(1), &self.r#type)
+                    name: "ServiceTestArgsSend",
+                }) + __protocol.struct_field_len(Some(1), &self.req)
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
@@ -2369,19 +2275,17 @@ pub mod auto_name {
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for ServiceTestResultSend {
+        impl ::std::default::Default for ServiceTest2ResultRecv {
             fn default() -> Self {
-                ServiceTestResultSend::Ok(::std::default::Default::default())
+                ServiceTest2ResultRecv::Ok(::std::default::Default::default())
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum ServiceTestResultSend {
+        pub enum ServiceTest2ResultRecv {
             Ok(::std::sync::Arc<Test>),
-
-            E(TestException),
         }
 
-        impl ::pilota::thrift::Message for ServiceTestResultSend {
+        impl ::pilota::thrift::Message for ServiceTest2ResultRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -2389,15 +2293,12 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTestResultSend",
+                    name: "ServiceTest2ResultRecv",
                 })?;
                 match self {
-                    ServiceTestResultSend::Ok(value) => {
+                    ServiceTest2ResultRecv::Ok(value) => {
                         __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
                     }
-                    ServiceTestResultSend::E(value) => {
-                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
-                    }
                 }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
@@ -2426,21 +2327,7 @@ pub mod auto_name {
                                     ::pilota::thrift::Message::decode(__protocol)?,
                                 );
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(ServiceTestResultSend::Ok(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
-                            }
-                        }
-                        Some(1) => {
-                            if ret.is_none() {
-                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(ServiceTestResultSend::E(field_ident));
+                                ret = Some(ServiceTest2ResultRecv::Ok(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -2496,7 +2383,7 @@ pub mod auto_name {
                                         .await?,
                                     );
 
-                                    ret = Some(ServiceTestResultSend::Ok(field_ident));
+                                    ret = Some(ServiceTest2ResultRecv::Ok(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -2506,15 +2393,146 @@ pub mod auto_name {
                                     );
                                 }
                             }
-                            Some(1) => {
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "ServiceTest2ResultRecv",
+                }) + match self {
+                    ServiceTest2ResultRecv::Ok(value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for ServiceTest2ResultSend {
+            fn default() -> Self {
+                ServiceTest2ResultSend::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum ServiceTest2ResultSend {
+            Ok(::std::sync::Arc<Test>),
+        }
+
+        impl ::pilota::thrift::Message for ServiceTest2ResultSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "ServiceTest2ResultSend",
+                })?;
+                match self {
+                    ServiceTest2ResultSend::Ok(value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::std::sync::Arc::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ServiceTest2ResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
                                 if ret.is_none() {
-                                    let field_ident =
-                                        <TestException as ::pilota::thrift::Message>::decode_async(
+                                    let field_ident = ::std::sync::Arc::new(
+                                        <Test as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
-                                        .await?;
+                                        .await?,
+                                    );
 
-                                    ret = Some(ServiceTestResultSend::E(field_ident));
+                                    ret = Some(ServiceTest2ResultSend::Ok(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -2546,33 +2564,32 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTestResultSend",
+                    name: "ServiceTest2ResultSend",
                 }) + match self {
-                    ServiceTestResultSend::Ok(value) => __protocol.struct_field_len(Some(0), value),
-                    ServiceTestResultSend::E(value) => __protocol.struct_field_len(Some(1), value),
+                    ServiceTest2ResultSend::Ok(value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct Test {
-            pub ID: ::pilota::FastStr,
-
-            pub Id: ::pilota::FastStr,
+        pub struct ServiceTest2ArgsSend {
+            pub r#type: ::std::sync::Arc<TEST>,
         }
-        impl ::pilota::thrift::Message for Test {
+        impl ::pilota::thrift::Message for ServiceTest2ArgsSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Test" };
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "ServiceTest2ArgsSend",
+                };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_faststr_field(1, (&self.ID).clone())?;
-                __protocol.write_faststr_field(2, (&self.Id).clone())?;
+                __protocol.write_struct_field(1, &self.r#type, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -2585,7 +2602,6 @@ pub mod auto_name {
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
                 let mut var_1 = None;
-                let mut var_2 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -2602,14 +2618,11 @@ pub mod auto_name {
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
                             Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
-                            {
-                                var_1 = Some(__protocol.read_faststr()?);
-                            }
-                            Some(2)
-                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
                             {
-                                var_2 = Some(__protocol.read_faststr()?);
+                                var_1 = Some(::std::sync::Arc::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                ));
                             }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
@@ -2623,7 +2636,7 @@ pub mod auto_name {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `Test` field(#{}) failed, caused by: ",
+                            "decode struct `ServiceTest2ArgsSend` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -2634,20 +2647,11 @@ pub mod auto_name {
                 let Some(var_1) = var_1 else {
                     return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field ID is required".to_string(),
-                    ));
-                };
-                let Some(var_2) = var_2 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field Id is required".to_string(),
+                        "field r#type is required".to_string(),
                     ));
                 };
 
-                let data = Self {
-                    ID: var_1,
-                    Id: var_2,
-                };
+                let data = Self { r#type: var_1 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -2663,7 +2667,6 @@ pub mod auto_name {
             > {
                 ::std::boxed::Box::pin(async move {
                     let mut var_1 = None;
-                    let mut var_2 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
@@ -2679,15 +2682,14 @@ pub mod auto_name {
                             match field_ident.id {
                                 Some(1)
                                     if field_ident.field_type
-                                        == ::pilota::thrift::TType::Binary =>
-                                {
-                                    var_1 = Some(__protocol.read_faststr().await?);
-                                }
-                                Some(2)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Binary =>
+                                        == ::pilota::thrift::TType::Struct =>
                                 {
-                                    var_2 = Some(__protocol.read_faststr().await?);
+                                    var_1 = Some(::std::sync::Arc::new(
+                                        <TEST as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    ));
                                 }
                                 _ => {
                                     __protocol.skip(field_ident.field_type).await?;
@@ -2701,10 +2703,7 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Test` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_msg(&format!("decode struct `ServiceTest2ArgsSend` field(#{}) failed, caused by: ", field_id));
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -2714,23 +2713,12 @@ pub mod auto_name {
                         return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field ID is required".to_string(),
-                            ),
-                        );
-                    };
-                    let Some(var_2) = var_2 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field Id is required".to_string(),
+                                "field r#type is required".to_string(),
                             ),
                         );
                     };
 
-                    let data = Self {
-                        ID: var_1,
-                        Id: var_2,
-                    };
+                    let data = Self { r#type: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -2738,172 +2726,18 @@ pub mod auto_name {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Test" })
-                    + __protocol.faststr_field_len(Some(1), &self.ID)
-                    + __protocol.faststr_field_len(Some(2), &self.Id)
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "ServiceTest2ArgsSend",
+                }) + __protocol.struct_field_len(Some(1), &self.r#type)
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-
-        pub trait Service {}
-
-        impl ::std::default::Default for ServiceTest2ResultRecv {
-            fn default() -> Self {
-                ServiceTest2ResultRecv::Ok(::std::default::Default::default())
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum ServiceTest2ResultRecv {
-            Ok(::std::sync::Arc<Test>),
-        }
-
-        impl ::pilota::thrift::Message for ServiceTest2ResultRecv {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTest2ResultRecv",
-                })?;
-                match self {
-                    ServiceTest2ResultRecv::Ok(value) => {
-                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
-                    }
-                }
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
-
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let mut ret = None;
-                __protocol.read_struct_begin()?;
-                loop {
-                    let field_ident = __protocol.read_field_begin()?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        __protocol.field_stop_len();
-                        break;
-                    } else {
-                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                    }
-                    match field_ident.id {
-                        Some(0) => {
-                            if ret.is_none() {
-                                let field_ident = ::std::sync::Arc::new(
-                                    ::pilota::thrift::Message::decode(__protocol)?,
-                                );
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(ServiceTest2ResultRecv::Ok(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
-                            }
-                        }
-                        _ => {
-                            __protocol.skip(field_ident.field_type)?;
-                        }
-                    }
-                }
-                __protocol.read_field_end()?;
-                __protocol.read_struct_end()?;
-                if let Some(ret) = ret {
-                    ::std::result::Result::Ok(ret)
-                } else {
-                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "received empty union from remote Message",
-                    ))
-                }
-            }
-
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    let mut ret = None;
-                    __protocol.read_struct_begin().await?;
-                    loop {
-                        let field_ident = __protocol.read_field_begin().await?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            break;
-                        } else {
-                        }
-                        match field_ident.id {
-                            Some(0) => {
-                                if ret.is_none() {
-                                    let field_ident = ::std::sync::Arc::new(
-                                        <Test as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    );
-
-                                    ret = Some(ServiceTest2ResultRecv::Ok(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
-                                    );
-                                }
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
-                            }
-                        }
-                    }
-                    __protocol.read_field_end().await?;
-                    __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
-                    } else {
-                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            "received empty union from remote Message",
-                        ))
-                    }
-                })
-            }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTest2ResultRecv",
-                }) + match self {
-                    ServiceTest2ResultRecv::Ok(value) => {
-                        __protocol.struct_field_len(Some(0), value)
-                    }
-                } + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct ServicetestArgsSend {
-            pub req: ::std::sync::Arc<TEST>,
-
-            pub Req: ::std::sync::Arc<TEST>,
+        pub struct ServiceTest2ArgsRecv {
+            pub r#type: ::std::sync::Arc<TEST>,
         }
-        impl ::pilota::thrift::Message for ServicetestArgsSend {
+        impl ::pilota::thrift::Message for ServiceTest2ArgsRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -2911,12 +2745,11 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "ServicetestArgsSend",
+                    name: "ServiceTest2ArgsRecv",
                 };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
-                __protocol.write_struct_field(2, &self.Req, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_struct_field(1, &self.r#type, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -2929,7 +2762,6 @@ pub mod auto_name {
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
                 let mut var_1 = None;
-                let mut var_2 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -2952,13 +2784,6 @@ pub mod auto_name {
                                     ::pilota::thrift::Message::decode(__protocol)?,
                                 ));
                             }
-                            Some(2)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
-                                var_2 = Some(::std::sync::Arc::new(
-                                    ::pilota::thrift::Message::decode(__protocol)?,
-                                ));
-                            }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
                             }
@@ -2971,7 +2796,7 @@ pub mod auto_name {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `ServicetestArgsSend` field(#{}) failed, caused by: ",
+                            "decode struct `ServiceTest2ArgsRecv` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -2982,20 +2807,11 @@ pub mod auto_name {
                 let Some(var_1) = var_1 else {
                     return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
-                    ));
-                };
-                let Some(var_2) = var_2 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field Req is required".to_string(),
+                        "field r#type is required".to_string(),
                     ));
                 };
 
-                let data = Self {
-                    req: var_1,
-                    Req: var_2,
-                };
+                let data = Self { r#type: var_1 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -3011,7 +2827,6 @@ pub mod auto_name {
             > {
                 ::std::boxed::Box::pin(async move {
                     let mut var_1 = None;
-                    let mut var_2 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
@@ -3036,17 +2851,6 @@ pub mod auto_name {
                                         .await?,
                                     ));
                                 }
-                                Some(2)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_2 = Some(::std::sync::Arc::new(
-                                        <TEST as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    ));
-                                }
                                 _ => {
                                     __protocol.skip(field_ident.field_type).await?;
                                 }
@@ -3059,7 +2863,7 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ServicetestArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_msg(&format!("decode struct `ServiceTest2ArgsRecv` field(#{}) failed, caused by: ", field_id));
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -3069,23 +2873,12 @@ pub mod auto_name {
                         return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
-                            ),
-                        );
-                    };
-                    let Some(var_2) = var_2 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field Req is required".to_string(),
+                                "field r#type is required".to_string(),
                             ),
                         );
                     };
 
-                    let data = Self {
-                        req: var_1,
-                        Req: var_2,
-                    };
+                    let data = Self { r#type: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -3094,63 +2887,229 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServicetestArgsSend",
-                }) + __protocol.struct_field_len(Some(1), &self.req)
-                    + __protocol.struct_field_len(Some(2), &self.Req)
+                    name: "ServiceTest2ArgsRecv",
+                }) + __protocol.struct_field_len(Some(1), &self.r#type)
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
 
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
-        #[repr(transparent)]
-        pub struct Index(i32);
-
-        impl Index {
-            pub const A: Self = Self(0);
-            pub const a: Self = Self(1);
+        pub trait Service {}
 
-            pub fn inner(&self) -> i32 {
-                self.0
+        impl ::std::default::Default for servicetestResultRecv {
+            fn default() -> Self {
+                servicetestResultRecv::Ok(::std::default::Default::default())
             }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum servicetestResultRecv {
+            Ok(Test),
+
+            E(TestException),
+        }
 
-            pub fn to_string(&self) -> ::std::string::String {
+        impl ::pilota::thrift::Message for servicetestResultRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "servicetestResultRecv",
+                })?;
                 match self {
-                    Self(0) => ::std::string::String::from("A"),
-                    Self(1) => ::std::string::String::from("a"),
-                    Self(val) => val.to_string(),
+                    servicetestResultRecv::Ok(value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                    servicetestResultRecv::E(value) => {
+                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
+                    }
                 }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
             }
 
-            pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
-                match value {
-                    0 => Some(Self::A),
-                    1 => Some(Self::a),
-                    _ => None,
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(servicetestResultRecv::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(servicetestResultRecv::E(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
                 }
             }
-        }
 
-        impl ::std::convert::From<i32> for Index {
-            fn from(value: i32) -> Self {
-                Self(value)
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Test as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(servicetestResultRecv::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <TestException as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(servicetestResultRecv::E(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
             }
-        }
 
-        impl ::std::convert::From<Index> for i32 {
-            fn from(value: Index) -> i32 {
-                value.0
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "servicetestResultRecv",
+                }) + match self {
+                    servicetestResultRecv::Ok(value) => __protocol.struct_field_len(Some(0), value),
+                    servicetestResultRecv::E(value) => __protocol.struct_field_len(Some(1), value),
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
             }
         }
+        impl ::std::default::Default for servicetestResultSend {
+            fn default() -> Self {
+                servicetestResultSend::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum servicetestResultSend {
+            Ok(Test),
 
-        impl ::pilota::thrift::Message for Index {
+            E(TestException),
+        }
+
+        impl ::pilota::thrift::Message for servicetestResultSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_i32(self.inner())?;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "servicetestResultSend",
+                })?;
+                match self {
+                    servicetestResultSend::Ok(value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                    servicetestResultSend::E(value) => {
+                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
             }
 
@@ -3159,15 +3118,223 @@ pub mod auto_name {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let value = __protocol.read_i32()?;
-                ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
-                    |err| {
-                        ::pilota::thrift::new_protocol_exception(
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(servicetestResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(servicetestResultSend::E(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Test as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(servicetestResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <TestException as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(servicetestResultSend::E(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                             ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            format!("invalid enum value for Index, value: {}", value),
-                        )
-                    },
-                )?)
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "servicetestResultSend",
+                }) + match self {
+                    servicetestResultSend::Ok(value) => __protocol.struct_field_len(Some(0), value),
+                    servicetestResultSend::E(value) => __protocol.struct_field_len(Some(1), value),
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for servicetestException {
+            fn default() -> Self {
+                servicetestException::E(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum servicetestException {
+            E(TestException),
+        }
+
+        impl ::pilota::thrift::Message for servicetestException {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "servicetestException",
+                })?;
+                match self {
+                    servicetestException::E(value) => {
+                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(servicetestException::E(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -3181,29 +3348,67 @@ pub mod auto_name {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let value = __protocol.read_i32().await?;
-                    ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
-                        |err| {
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                format!("invalid enum value for Index, value: {}", value),
-                            )
-                        },
-                    )?)
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <TestException as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(servicetestException::E(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
                 })
             }
 
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.i32_len(self.inner())
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "servicetestException",
+                }) + match self {
+                    servicetestException::E(value) => __protocol.struct_field_len(Some(1), value),
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct serviceTest2ArgsSend {
-            pub self_: TEST,
+        pub struct servicetestArgsSend {
+            pub req: TEST,
         }
-        impl ::pilota::thrift::Message for serviceTest2ArgsSend {
+        impl ::pilota::thrift::Message for servicetestArgsSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -3211,11 +3416,11 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "serviceTest2ArgsSend",
+                    name: "servicetestArgsSend",
                 };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.self_, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -3260,7 +3465,7 @@ pub mod auto_name {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `serviceTest2ArgsSend` field(#{}) failed, caused by: ",
+                            "decode struct `servicetestArgsSend` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -3271,11 +3476,11 @@ pub mod auto_name {
                 let Some(var_1) = var_1 else {
                     return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field self_ is required".to_string(),
+                        "field req is required".to_string(),
                     ));
                 };
 
-                let data = Self { self_: var_1 };
+                let data = Self { req: var_1 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -3327,7 +3532,7 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `serviceTest2ArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_msg(&format!("decode struct `servicetestArgsSend` field(#{}) failed, caused by: ", field_id));
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -3337,12 +3542,12 @@ pub mod auto_name {
                         return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field self_ is required".to_string(),
+                                "field req is required".to_string(),
                             ),
                         );
                     };
 
-                    let data = Self { self_: var_1 };
+                    let data = Self { req: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -3351,17 +3556,17 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTest2ArgsSend",
-                }) + __protocol.struct_field_len(Some(1), &self.self_)
+                    name: "servicetestArgsSend",
+                }) + __protocol.struct_field_len(Some(1), &self.req)
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct serviceTestArgsSend {
+        pub struct servicetestArgsRecv {
             pub req: TEST,
         }
-        impl ::pilota::thrift::Message for serviceTestArgsSend {
+        impl ::pilota::thrift::Message for servicetestArgsRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -3369,7 +3574,7 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "serviceTestArgsSend",
+                    name: "servicetestArgsRecv",
                 };
 
                 __protocol.write_struct_begin(&struct_ident)?;
@@ -3418,7 +3623,7 @@ pub mod auto_name {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `serviceTestArgsSend` field(#{}) failed, caused by: ",
+                            "decode struct `servicetestArgsRecv` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -3477,174 +3682,31 @@ pub mod auto_name {
                                     __protocol.skip(field_ident.field_type).await?;
                                 }
                             }
-
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `serviceTestArgsSend` field(#{}) failed, caused by: ", field_id));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
-
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self { req: var_1 };
-                    ::std::result::Result::Ok(data)
-                })
-            }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTestArgsSend",
-                }) + __protocol.struct_field_len(Some(1), &self.req)
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
-        impl ::std::default::Default for servicetestException {
-            fn default() -> Self {
-                servicetestException::E(::std::default::Default::default())
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum servicetestException {
-            E(TestException),
-        }
-
-        impl ::pilota::thrift::Message for servicetestException {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "servicetestException",
-                })?;
-                match self {
-                    servicetestException::E(value) => {
-                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
-                    }
-                }
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
-
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let mut ret = None;
-                __protocol.read_struct_begin()?;
-                loop {
-                    let field_ident = __protocol.read_field_begin()?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        __protocol.field_stop_len();
-                        break;
-                    } else {
-                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                    }
-                    match field_ident.id {
-                        Some(1) => {
-                            if ret.is_none() {
-                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(servicetestException::E(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
-                            }
-                        }
-                        _ => {
-                            __protocol.skip(field_ident.field_type)?;
-                        }
-                    }
-                }
-                __protocol.read_field_end()?;
-                __protocol.read_struct_end()?;
-                if let Some(ret) = ret {
-                    ::std::result::Result::Ok(ret)
-                } else {
-                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "received empty union from remote Message",
-                    ))
-                }
-            }
-
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    let mut ret = None;
-                    __protocol.read_struct_begin().await?;
-                    loop {
-                        let field_ident = __protocol.read_field_begin().await?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            break;
-                        } else {
-                        }
-                        match field_ident.id {
-                            Some(1) => {
-                                if ret.is_none() {
-                                    let field_ident =
-                                        <TestException as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?;
-
-                                    ret = Some(servicetestException::E(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
-                                    );
-                                }
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
-                            }
+
+                            __protocol.read_field_end().await?;
                         }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     }
-                    __protocol.read_field_end().await?;
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!("decode struct `servicetestArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
                     __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
-                    } else {
-                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            "received empty union from remote Message",
-                        ))
-                    }
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field req is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { req: var_1 };
+                    ::std::result::Result::Ok(data)
                 })
             }
 
@@ -3652,26 +3714,25 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "servicetestException",
-                }) + match self {
-                    servicetestException::E(value) => __protocol.struct_field_len(Some(1), value),
-                } + __protocol.field_stop_len()
+                    name: "servicetestArgsRecv",
+                }) + __protocol.struct_field_len(Some(1), &self.req)
+                    + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for servicetestResultRecv {
+        impl ::std::default::Default for serviceTestResultRecv {
             fn default() -> Self {
-                servicetestResultRecv::Ok(::std::default::Default::default())
+                serviceTestResultRecv::Ok(::std::default::Default::default())
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum servicetestResultRecv {
+        pub enum serviceTestResultRecv {
             Ok(Test),
 
             E(TestException),
         }
 
-        impl ::pilota::thrift::Message for servicetestResultRecv {
+        impl ::pilota::thrift::Message for serviceTestResultRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -3679,13 +3740,13 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "servicetestResultRecv",
+                    name: "serviceTestResultRecv",
                 })?;
                 match self {
-                    servicetestResultRecv::Ok(value) => {
+                    serviceTestResultRecv::Ok(value) => {
                         __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
                     }
-                    servicetestResultRecv::E(value) => {
+                    serviceTestResultRecv::E(value) => {
                         __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
                     }
                 }
@@ -3714,7 +3775,7 @@ pub mod auto_name {
                             if ret.is_none() {
                                 let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(servicetestResultRecv::Ok(field_ident));
+                                ret = Some(serviceTestResultRecv::Ok(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -3728,7 +3789,7 @@ pub mod auto_name {
                             if ret.is_none() {
                                 let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(servicetestResultRecv::E(field_ident));
+                                ret = Some(serviceTestResultRecv::E(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -3783,7 +3844,7 @@ pub mod auto_name {
                                         )
                                         .await?;
 
-                                    ret = Some(servicetestResultRecv::Ok(field_ident));
+                                    ret = Some(serviceTestResultRecv::Ok(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -3801,7 +3862,7 @@ pub mod auto_name {
                                         )
                                         .await?;
 
-                                    ret = Some(servicetestResultRecv::E(field_ident));
+                                    ret = Some(serviceTestResultRecv::E(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -3811,226 +3872,21 @@ pub mod auto_name {
                                     );
                                 }
                             }
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
-                            }
-                        }
-                    }
-                    __protocol.read_field_end().await?;
-                    __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
-                    } else {
-                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            "received empty union from remote Message",
-                        ))
-                    }
-                })
-            }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "servicetestResultRecv",
-                }) + match self {
-                    servicetestResultRecv::Ok(value) => __protocol.struct_field_len(Some(0), value),
-                    servicetestResultRecv::E(value) => __protocol.struct_field_len(Some(1), value),
-                } + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct ServicetestArgsRecv {
-            pub req: ::std::sync::Arc<TEST>,
-
-            pub Req: ::std::sync::Arc<TEST>,
-        }
-        impl ::pilota::thrift::Message for ServicetestArgsRecv {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "ServicetestArgsRecv",
-                };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
-                __protocol.write_struct_field(2, &self.Req, ::pilota::thrift::TType::Struct)?;
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
-
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut var_1 = None;
-                let mut var_2 = None;
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
-                                var_1 = Some(::std::sync::Arc::new(
-                                    ::pilota::thrift::Message::decode(__protocol)?,
-                                ));
-                            }
-                            Some(2)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
-                                var_2 = Some(::std::sync::Arc::new(
-                                    ::pilota::thrift::Message::decode(__protocol)?,
-                                ));
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ServicetestArgsRecv` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let Some(var_1) = var_1 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
-                    ));
-                };
-                let Some(var_2) = var_2 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field Req is required".to_string(),
-                    ));
-                };
-
-                let data = Self {
-                    req: var_1,
-                    Req: var_2,
-                };
-                ::std::result::Result::Ok(data)
-            }
-
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
-                    let mut var_2 = None;
-
-                    let mut __pilota_decoding_field_id = None;
-
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_1 = Some(::std::sync::Arc::new(
-                                        <TEST as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    ));
-                                }
-                                Some(2)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_2 = Some(::std::sync::Arc::new(
-                                        <TEST as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    ));
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
-
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ServicetestArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
                         }
-                        return ::std::result::Result::Err(err);
-                    };
+                    }
+                    __protocol.read_field_end().await?;
                     __protocol.read_struct_end().await?;
-
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
-                            ),
-                        );
-                    };
-                    let Some(var_2) = var_2 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field Req is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self {
-                        req: var_1,
-                        Req: var_2,
-                    };
-                    ::std::result::Result::Ok(data)
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
                 })
             }
 
@@ -4038,26 +3894,27 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServicetestArgsRecv",
-                }) + __protocol.struct_field_len(Some(1), &self.req)
-                    + __protocol.struct_field_len(Some(2), &self.Req)
-                    + __protocol.field_stop_len()
+                    name: "serviceTestResultRecv",
+                }) + match self {
+                    serviceTestResultRecv::Ok(value) => __protocol.struct_field_len(Some(0), value),
+                    serviceTestResultRecv::E(value) => __protocol.struct_field_len(Some(1), value),
+                } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for ServicetestResultSend {
+        impl ::std::default::Default for serviceTestResultSend {
             fn default() -> Self {
-                ServicetestResultSend::Ok(::std::default::Default::default())
+                serviceTestResultSend::Ok(::std::default::Default::default())
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum ServicetestResultSend {
-            Ok(::std::sync::Arc<Test>),
+        pub enum serviceTestResultSend {
+            Ok(Test),
 
             E(TestException),
         }
 
-        impl ::pilota::thrift::Message for ServicetestResultSend {
+        impl ::pilota::thrift::Message for serviceTestResultSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -4065,13 +3922,13 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "ServicetestResultSend",
+                    name: "serviceTestResultSend",
                 })?;
                 match self {
-                    ServicetestResultSend::Ok(value) => {
+                    serviceTestResultSend::Ok(value) => {
                         __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
                     }
-                    ServicetestResultSend::E(value) => {
+                    serviceTestResultSend::E(value) => {
                         __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
                     }
                 }
@@ -4098,11 +3955,9 @@ pub mod auto_name {
                     match field_ident.id {
                         Some(0) => {
                             if ret.is_none() {
-                                let field_ident = ::std::sync::Arc::new(
-                                    ::pilota::thrift::Message::decode(__protocol)?,
-                                );
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(ServicetestResultSend::Ok(field_ident));
+                                ret = Some(serviceTestResultSend::Ok(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -4116,7 +3971,7 @@ pub mod auto_name {
                             if ret.is_none() {
                                 let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(ServicetestResultSend::E(field_ident));
+                                ret = Some(serviceTestResultSend::E(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -4165,14 +4020,31 @@ pub mod auto_name {
                         match field_ident.id {
                             Some(0) => {
                                 if ret.is_none() {
-                                    let field_ident = ::std::sync::Arc::new(
+                                    let field_ident =
                                         <Test as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
-                                        .await?,
+                                        .await?;
+
+                                    ret = Some(serviceTestResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
                                     );
+                                }
+                            }
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <TestException as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
 
-                                    ret = Some(ServicetestResultSend::Ok(field_ident));
+                                    ret = Some(serviceTestResultSend::E(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -4182,6 +4054,133 @@ pub mod auto_name {
                                     );
                                 }
                             }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "serviceTestResultSend",
+                }) + match self {
+                    serviceTestResultSend::Ok(value) => __protocol.struct_field_len(Some(0), value),
+                    serviceTestResultSend::E(value) => __protocol.struct_field_len(Some(1), value),
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for serviceTestException {
+            fn default() -> Self {
+                serviceTestException::E(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum serviceTestException {
+            E(TestException),
+        }
+
+        impl ::pilota::thrift::Message for serviceTestException {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "serviceTestException",
+                })?;
+                match self {
+                    serviceTestException::E(value) => {
+                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(serviceTestException::E(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
                             Some(1) => {
                                 if ret.is_none() {
                                     let field_ident =
@@ -4190,7 +4189,7 @@ pub mod auto_name {
                                         )
                                         .await?;
 
-                                    ret = Some(ServicetestResultSend::E(field_ident));
+                                    ret = Some(serviceTestException::E(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -4222,19 +4221,18 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServicetestResultSend",
+                    name: "serviceTestException",
                 }) + match self {
-                    ServicetestResultSend::Ok(value) => __protocol.struct_field_len(Some(0), value),
-                    ServicetestResultSend::E(value) => __protocol.struct_field_len(Some(1), value),
+                    serviceTestException::E(value) => __protocol.struct_field_len(Some(1), value),
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct serviceTest2ArgsRecv {
-            pub self_: TEST,
+        pub struct serviceTestArgsSend {
+            pub req: TEST,
         }
-        impl ::pilota::thrift::Message for serviceTest2ArgsRecv {
+        impl ::pilota::thrift::Message for serviceTestArgsSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -4242,11 +4240,11 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "serviceTest2ArgsRecv",
+                    name: "serviceTestArgsSend",
                 };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.self_, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -4291,7 +4289,7 @@ pub mod auto_name {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `serviceTest2ArgsRecv` field(#{}) failed, caused by: ",
+                            "decode struct `serviceTestArgsSend` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -4302,11 +4300,11 @@ pub mod auto_name {
                 let Some(var_1) = var_1 else {
                     return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field self_ is required".to_string(),
+                        "field req is required".to_string(),
                     ));
                 };
 
-                let data = Self { self_: var_1 };
+                let data = Self { req: var_1 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -4358,7 +4356,7 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `serviceTest2ArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_msg(&format!("decode struct `serviceTestArgsSend` field(#{}) failed, caused by: ", field_id));
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -4368,12 +4366,12 @@ pub mod auto_name {
                         return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field self_ is required".to_string(),
+                                "field req is required".to_string(),
                             ),
                         );
                     };
 
-                    let data = Self { self_: var_1 };
+                    let data = Self { req: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -4382,8 +4380,8 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTest2ArgsRecv",
-                }) + __protocol.struct_field_len(Some(1), &self.self_)
+                    name: "serviceTestArgsSend",
+                }) + __protocol.struct_field_len(Some(1), &self.req)
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
@@ -4546,19 +4544,17 @@ pub mod auto_name {
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for serviceTestResultSend {
+        impl ::std::default::Default for serviceTest2ResultRecv {
             fn default() -> Self {
-                serviceTestResultSend::Ok(::std::default::Default::default())
+                serviceTest2ResultRecv::Ok(::std::default::Default::default())
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum serviceTestResultSend {
+        pub enum serviceTest2ResultRecv {
             Ok(Test),
-
-            E(TestException),
         }
 
-        impl ::pilota::thrift::Message for serviceTestResultSend {
+        impl ::pilota::thrift::Message for serviceTest2ResultRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -4566,15 +4562,12 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTestResultSend",
+                    name: "serviceTest2ResultRecv",
                 })?;
                 match self {
-                    serviceTestResultSend::Ok(value) => {
+                    serviceTest2ResultRecv::Ok(value) => {
                         __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
                     }
-                    serviceTestResultSend::E(value) => {
-                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
-                    }
                 }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
@@ -4601,21 +4594,7 @@ pub mod auto_name {
                             if ret.is_none() {
                                 let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(serviceTestResultSend::Ok(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
-                            }
-                        }
-                        Some(1) => {
-                            if ret.is_none() {
-                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(serviceTestResultSend::E(field_ident));
+                                ret = Some(serviceTest2ResultRecv::Ok(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -4670,25 +4649,7 @@ pub mod auto_name {
                                         )
                                         .await?;
 
-                                    ret = Some(serviceTestResultSend::Ok(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
-                                    );
-                                }
-                            }
-                            Some(1) => {
-                                if ret.is_none() {
-                                    let field_ident =
-                                        <TestException as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?;
-
-                                    ret = Some(serviceTestResultSend::E(field_ident));
+                                    ret = Some(serviceTest2ResultRecv::Ok(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -4720,25 +4681,26 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "serviceTestResultSend",
+                    name: "serviceTest2ResultRecv",
                 }) + match self {
-                    serviceTestResultSend::Ok(value) => __protocol.struct_field_len(Some(0), value),
-                    serviceTestResultSend::E(value) => __protocol.struct_field_len(Some(1), value),
+                    serviceTest2ResultRecv::Ok(value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for ServiceTest2ResultSend {
+        impl ::std::default::Default for serviceTest2ResultSend {
             fn default() -> Self {
-                ServiceTest2ResultSend::Ok(::std::default::Default::default())
+                serviceTest2ResultSend::Ok(::std::default::Default::default())
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum ServiceTest2ResultSend {
-            Ok(::std::sync::Arc<Test>),
+        pub enum serviceTest2ResultSend {
+            Ok(Test),
         }
 
-        impl ::pilota::thrift::Message for ServiceTest2ResultSend {
+        impl ::pilota::thrift::Message for serviceTest2ResultSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -4746,10 +4708,10 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTest2ResultSend",
+                    name: "serviceTest2ResultSend",
                 })?;
                 match self {
-                    ServiceTest2ResultSend::Ok(value) => {
+                    serviceTest2ResultSend::Ok(value) => {
                         __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
                     }
                 }
@@ -4776,11 +4738,9 @@ pub mod auto_name {
                     match field_ident.id {
                         Some(0) => {
                             if ret.is_none() {
-                                let field_ident = ::std::sync::Arc::new(
-                                    ::pilota::thrift::Message::decode(__protocol)?,
-                                );
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(ServiceTest2ResultSend::Ok(field_ident));
+                                ret = Some(serviceTest2ResultSend::Ok(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -4829,14 +4789,13 @@ pub mod auto_name {
                         match field_ident.id {
                             Some(0) => {
                                 if ret.is_none() {
-                                    let field_ident = ::std::sync::Arc::new(
+                                    let field_ident =
                                         <Test as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
-                                        .await?,
-                                    );
+                                        .await?;
 
-                                    ret = Some(ServiceTest2ResultSend::Ok(field_ident));
+                                    ret = Some(serviceTest2ResultSend::Ok(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -4868,40 +4827,32 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTest2ResultSend",
+                    name: "serviceTest2ResultSend",
                 }) + match self {
-                    ServiceTest2ResultSend::Ok(value) => {
+                    serviceTest2ResultSend::Ok(value) => {
                         __protocol.struct_field_len(Some(0), value)
                     }
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for ServiceTestException {
-            fn default() -> Self {
-                ServiceTestException::E(::std::default::Default::default())
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum ServiceTestException {
-            E(TestException),
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct serviceTest2ArgsSend {
+            pub self_: TEST,
         }
-
-        impl ::pilota::thrift::Message for ServiceTestException {
+        impl ::pilota::thrift::Message for serviceTest2ArgsSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTestException",
-                })?;
-                match self {
-                    ServiceTestException::E(value) => {
-                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
-                    }
-                }
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "serviceTest2ArgsSend",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_struct_field(1, &self.self_, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -4912,46 +4863,57 @@ pub mod auto_name {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let mut ret = None;
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
                 __protocol.read_struct_begin()?;
-                loop {
-                    let field_ident = __protocol.read_field_begin()?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        __protocol.field_stop_len();
-                        break;
-                    } else {
-                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                    }
-                    match field_ident.id {
-                        Some(1) => {
-                            if ret.is_none() {
-                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(ServiceTestException::E(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
-                            }
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
                         }
-                        _ => {
-                            __protocol.skip(field_ident.field_type)?;
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
                         }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
                     }
-                }
-                __protocol.read_field_end()?;
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(&format!(
+                            "decode struct `serviceTest2ArgsSend` field(#{}) failed, caused by: ",
+                            field_id
+                        ));
+                    }
+                    return ::std::result::Result::Err(err);
+                };
                 __protocol.read_struct_end()?;
-                if let Some(ret) = ret {
-                    ::std::result::Result::Ok(ret)
-                } else {
-                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "received empty union from remote Message",
-                    ))
-                }
+                        "field self_ is required".to_string(),
+                    ));
+                };
+
+                let data = Self { self_: var_1 };
+                ::std::result::Result::Ok(data)
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -4965,48 +4927,60 @@ pub mod auto_name {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut ret = None;
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
                     __protocol.read_struct_begin().await?;
-                    loop {
-                        let field_ident = __protocol.read_field_begin().await?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            break;
-                        } else {
-                        }
-                        match field_ident.id {
-                            Some(1) => {
-                                if ret.is_none() {
-                                    let field_ident =
-                                        <TestException as ::pilota::thrift::Message>::decode_async(
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(
+                                        <TEST as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
-                                        .await?;
-
-                                    ret = Some(ServiceTestException::E(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
+                                        .await?,
                                     );
                                 }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
                             }
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
-                            }
+
+                            __protocol.read_field_end().await?;
                         }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     }
-                    __protocol.read_field_end().await?;
-                    __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
-                    } else {
-                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            "received empty union from remote Message",
-                        ))
-                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!("decode struct `serviceTest2ArgsSend` field(#{}) failed, caused by: ", field_id));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field self_ is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { self_: var_1 };
+                    ::std::result::Result::Ok(data)
                 })
             }
 
@@ -5014,43 +4988,29 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTestException",
-                }) + match self {
-                    ServiceTestException::E(value) => __protocol.struct_field_len(Some(1), value),
-                } + __protocol.field_stop_len()
+                    name: "serviceTest2ArgsSend",
+                }) + __protocol.struct_field_len(Some(1), &self.self_)
+                    + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for ServiceTestResultRecv {
-            fn default() -> Self {
-                ServiceTestResultRecv::Ok(::std::default::Default::default())
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum ServiceTestResultRecv {
-            Ok(::std::sync::Arc<Test>),
-
-            E(TestException),
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct serviceTest2ArgsRecv {
+            pub self_: TEST,
         }
-
-        impl ::pilota::thrift::Message for ServiceTestResultRecv {
+        impl ::pilota::thrift::Message for serviceTest2ArgsRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTestResultRecv",
-                })?;
-                match self {
-                    ServiceTestResultRecv::Ok(value) => {
-                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
-                    }
-                    ServiceTestResultRecv::E(value) => {
-                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
-                    }
-                }
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "serviceTest2ArgsRecv",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_struct_field(1, &self.self_, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -5061,62 +5021,57 @@ pub mod auto_name {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let mut ret = None;
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
                 __protocol.read_struct_begin()?;
-                loop {
-                    let field_ident = __protocol.read_field_begin()?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        __protocol.field_stop_len();
-                        break;
-                    } else {
-                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                    }
-                    match field_ident.id {
-                        Some(0) => {
-                            if ret.is_none() {
-                                let field_ident = ::std::sync::Arc::new(
-                                    ::pilota::thrift::Message::decode(__protocol)?,
-                                );
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(ServiceTestResultRecv::Ok(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
-                            }
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
                         }
-                        Some(1) => {
-                            if ret.is_none() {
-                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(ServiceTestResultRecv::E(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
                             }
                         }
-                        _ => {
-                            __protocol.skip(field_ident.field_type)?;
-                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
                     }
-                }
-                __protocol.read_field_end()?;
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(&format!(
+                            "decode struct `serviceTest2ArgsRecv` field(#{}) failed, caused by: ",
+                            field_id
+                        ));
+                    }
+                    return ::std::result::Result::Err(err);
+                };
                 __protocol.read_struct_end()?;
-                if let Some(ret) = ret {
-                    ::std::result::Result::Ok(ret)
-                } else {
-                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "received empty union from remote Message",
-                    ))
-                }
+                        "field self_ is required".to_string(),
+                    ));
+                };
+
+                let data = Self { self_: var_1 };
+                ::std::result::Result::Ok(data)
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -5130,67 +5085,60 @@ pub mod auto_name {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut ret = None;
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
                     __protocol.read_struct_begin().await?;
-                    loop {
-                        let field_ident = __protocol.read_field_begin().await?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            break;
-                        } else {
-                        }
-                        match field_ident.id {
-                            Some(0) => {
-                                if ret.is_none() {
-                                    let field_ident = ::std::sync::Arc::new(
-                                        <Test as ::pilota::thrift::Message>::decode_async(
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(
+                                        <TEST as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
                                         .await?,
                                     );
-
-                                    ret = Some(ServiceTestResultRecv::Ok(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
-                                    );
                                 }
-                            }
-                            Some(1) => {
-                                if ret.is_none() {
-                                    let field_ident =
-                                        <TestException as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?;
-
-                                    ret = Some(ServiceTestResultRecv::E(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
-                                    );
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
                                 }
                             }
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
-                            }
+
+                            __protocol.read_field_end().await?;
                         }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     }
-                    __protocol.read_field_end().await?;
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!("decode struct `serviceTest2ArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
                     __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
-                    } else {
-                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            "received empty union from remote Message",
-                        ))
-                    }
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field self_ is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { self_: var_1 };
+                    ::std::result::Result::Ok(data)
                 })
             }
 
@@ -5198,13 +5146,13 @@ pub mod auto_name {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "ServiceTestResultRecv",
-                }) + match self {
-                    ServiceTestResultRecv::Ok(value) => __protocol.struct_field_len(Some(0), value),
-                    ServiceTestResultRecv::E(value) => __protocol.struct_field_len(Some(1), value),
-                } + __protocol.field_stop_len()
+                    name: "serviceTest2ArgsRecv",
+                }) + __protocol.struct_field_len(Some(1), &self.self_)
+                    + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
+
+        pub trait service {}
     }
 }
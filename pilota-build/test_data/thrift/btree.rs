@@ -12,12 +12,8 @@ pub mod btree {
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "A" };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
+                __protocol
+                    .write_empty_struct(&::pilota::thrift::TStructIdentifier { name: "A" })?;
                 ::std::result::Result::Ok(())
             }
 
@@ -26,43 +22,8 @@ pub mod btree {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `A` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let data = Self {};
-                ::std::result::Result::Ok(data)
+                __protocol.read_empty_struct()?;
+                ::std::result::Result::Ok(Self {})
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -76,187 +37,15 @@ pub mod btree {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut __pilota_decoding_field_id = None;
-
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
-
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `A` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
-
-                    let data = Self {};
-                    ::std::result::Result::Ok(data)
+                    __protocol.read_empty_struct().await?;
+                    ::std::result::Result::Ok(Self {})
                 })
             }
 
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "A" })
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct TypeA(
-            pub ::std::collections::BTreeMap<::std::collections::BTreeSet<i32>, ::pilota::FastStr>,
-        );
-
-        impl ::std::ops::Deref for TypeA {
-            type Target =
-                ::std::collections::BTreeMap<::std::collections::BTreeSet<i32>, ::pilota::FastStr>;
-
-            fn deref(&self) -> &Self::Target {
-                &self.0
-            }
-        }
-
-        impl
-            From<::std::collections::BTreeMap<::std::collections::BTreeSet<i32>, ::pilota::FastStr>>
-            for TypeA
-        {
-            fn from(
-                v: ::std::collections::BTreeMap<
-                    ::std::collections::BTreeSet<i32>,
-                    ::pilota::FastStr,
-                >,
-            ) -> Self {
-                Self(v)
-            }
-        }
-
-        impl ::pilota::thrift::Message for TypeA {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_btree_map(
-                    ::pilota::thrift::TType::Set,
-                    ::pilota::thrift::TType::Binary,
-                    &(&**self),
-                    |__protocol, key| {
-                        __protocol.write_btree_set(
-                            ::pilota::thrift::TType::I32,
-                            &key,
-                            |__protocol, val| {
-                                __protocol.write_i32(*val)?;
-                                ::std::result::Result::Ok(())
-                            },
-                        )?;
-                        ::std::result::Result::Ok(())
-                    },
-                    |__protocol, val| {
-                        __protocol.write_faststr((val).clone())?;
-                        ::std::result::Result::Ok(())
-                    },
-                )?;
-                ::std::result::Result::Ok(())
-            }
-
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                ::std::result::Result::Ok(TypeA({
-                    let map_ident = __protocol.read_map_begin()?;
-                    let mut val = ::std::collections::BTreeMap::new();
-                    for _ in 0..map_ident.size {
-                        val.insert(
-                            {
-                                let list_ident = __protocol.read_set_begin()?;
-                                let mut val = ::std::collections::BTreeSet::new();
-                                for _ in 0..list_ident.size {
-                                    val.insert(__protocol.read_i32()?);
-                                }
-                                __protocol.read_set_end()?;
-                                val
-                            },
-                            __protocol.read_faststr()?,
-                        );
-                    }
-                    __protocol.read_map_end()?;
-                    val
-                }))
-            }
-
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    ::std::result::Result::Ok(TypeA({
-                        let map_ident = __protocol.read_map_begin().await?;
-                        let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert(
-                                {
-                                    let list_ident = __protocol.read_set_begin().await?;
-                                    let mut val = ::std::collections::BTreeSet::new();
-                                    for _ in 0..list_ident.size {
-                                        val.insert(__protocol.read_i32().await?);
-                                    }
-                                    __protocol.read_set_end().await?;
-                                    val
-                                },
-                                __protocol.read_faststr().await?,
-                            );
-                        }
-                        __protocol.read_map_end().await?;
-                        val
-                    }))
-                })
-            }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.btree_map_len(
-                    ::pilota::thrift::TType::Set,
-                    ::pilota::thrift::TType::Binary,
-                    &**self,
-                    |__protocol, key| {
-                        __protocol.btree_set_len(
-                            ::pilota::thrift::TType::I32,
-                            key,
-                            |__protocol, el| __protocol.i32_len(*el),
-                        )
-                    },
-                    |__protocol, val| __protocol.faststr_len(val),
-                )
+                __protocol.empty_struct_len(&::pilota::thrift::TStructIdentifier { name: "A" })
             }
         }
 
@@ -394,20 +183,15 @@ pub mod btree {
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::std::collections::BTreeMap::new();
                                     for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, unsafe {
+                                        val.insert(__protocol.read_i32()?, {
                                             let list_ident = __protocol.read_list_begin()?;
-                                            let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
+                                            let mut val =
                                                 ::std::vec::Vec::with_capacity(list_ident.size);
-                                            for i in 0..list_ident.size {
-                                                val.as_mut_ptr().offset(i as isize).write(
-                                                    ::std::sync::Arc::new(
-                                                        ::pilota::thrift::Message::decode(
-                                                            __protocol,
-                                                        )?,
-                                                    ),
-                                                );
+                                            for _ in 0..list_ident.size {
+                                                val.push(::std::sync::Arc::new(
+                                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                                ));
                                             }
-                                            val.set_len(list_ident.size);
                                             __protocol.read_list_end()?;
                                             val
                                         });
@@ -433,16 +217,12 @@ pub mod btree {
                                     let mut val = ::std::collections::BTreeMap::new();
                                     for _ in 0..map_ident.size {
                                         val.insert(
-                                            unsafe {
+                                            {
                                                 let list_ident = __protocol.read_list_begin()?;
-                                                let mut val: ::std::vec::Vec<
-                                                    ::std::collections::BTreeMap<
-                                                        ::std::collections::BTreeSet<i32>,
-                                                        i32,
-                                                    >,
-                                                > = ::std::vec::Vec::with_capacity(list_ident.size);
-                                                for i in 0..list_ident.size {
-                                                    val.as_mut_ptr().offset(i as isize).write({
+                                                let mut val =
+                                                    ::std::vec::Vec::with_capacity(list_ident.size);
+                                                for _ in 0..list_ident.size {
+                                                    val.push({
                         let map_ident = __protocol.read_map_begin()?;
                         let mut val = ::std::collections::BTreeMap::new();
                         for _ in 0..map_ident.size {
@@ -458,7 +238,6 @@ pub mod btree {
                         val
                     });
                                                 }
-                                                val.set_len(list_ident.size);
                                                 __protocol.read_list_end()?;
                                                 val
                                             },
@@ -556,25 +335,27 @@ pub mod btree {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+
                     var_1 = Some({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::std::collections::BTreeMap::new();
                         for _ in 0..map_ident.size {
                             val.insert(__protocol.read_i32().await?, {
-                            let list_ident = __protocol.read_list_begin().await?;
-                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
-                                val.push(::std::sync::Arc::new(<A as ::pilota::thrift::Message>::decode_async(__protocol).await?));
-                            };
-                            __protocol.read_list_end().await?;
-                            val
-                        });
+                        let list_ident = __protocol.read_list_begin().await?;
+                        let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                        for _ in 0..list_ident.size {
+                            val.push(::std::sync::Arc::new(<A as ::pilota::thrift::Message>::decode_async(__protocol).await?));
+                        };
+                        __protocol.read_list_end().await?;
+                        val
+                    });
                         }
                         __protocol.read_map_end().await?;
                         val
                     });
 
                 },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Set  => {
+
                     var_2 = Some({let list_ident = __protocol.read_set_begin().await?;
                     let mut val = ::std::collections::BTreeSet::new();
                     for _ in 0..list_ident.size {
@@ -584,15 +365,16 @@ pub mod btree {
                     val});
 
                 },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+
                     var_3 = Some({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::std::collections::BTreeMap::new();
                         for _ in 0..map_ident.size {
                             val.insert({
-                            let list_ident = __protocol.read_list_begin().await?;
-                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
-                                val.push({
+                        let list_ident = __protocol.read_list_begin().await?;
+                        let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                        for _ in 0..list_ident.size {
+                            val.push({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::std::collections::BTreeMap::new();
                         for _ in 0..map_ident.size {
@@ -607,10 +389,10 @@ pub mod btree {
                         __protocol.read_map_end().await?;
                         val
                     });
-                            };
-                            __protocol.read_list_end().await?;
-                            val
-                        }, {let list_ident = __protocol.read_set_begin().await?;
+                        };
+                        __protocol.read_list_end().await?;
+                        val
+                    }, {let list_ident = __protocol.read_set_begin().await?;
                     let mut val = ::std::collections::BTreeSet::new();
                     for _ in 0..list_ident.size {
                         val.insert(__protocol.read_i32().await?);
@@ -624,6 +406,7 @@ pub mod btree {
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -738,126 +521,6 @@ pub mod btree {
                     + __protocol.struct_end_len()
             }
         }
-
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct Vec {}
-        impl ::pilota::thrift::Message for Vec {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Vec" };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
-
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Vec` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let data = Self {};
-                ::std::result::Result::Ok(data)
-            }
-
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    let mut __pilota_decoding_field_id = None;
-
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
-
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Vec` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
-
-                    let data = Self {};
-                    ::std::result::Result::Ok(data)
-                })
-            }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Vec" })
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
         pub static TEST_MAP_LIST: ::std::sync::LazyLock<
             ::std::collections::BTreeMap<i32, ::std::vec::Vec<&'static str>>,
         > = ::std::sync::LazyLock::new(|| {
@@ -866,15 +529,6 @@ pub mod btree {
             map
         });
 
-        pub static TEST_MAP: ::std::sync::LazyLock<
-            ::std::collections::BTreeMap<Index, &'static str>,
-        > = ::std::sync::LazyLock::new(|| {
-            let mut map = ::std::collections::BTreeMap::new();
-            map.insert(Index::A, "hello");
-            map.insert(Index::B, "world");
-            map
-        });
-
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
         #[repr(transparent)]
         pub struct Index(i32);
@@ -887,11 +541,11 @@ pub mod btree {
                 self.0
             }
 
-            pub fn to_string(&self) -> ::std::string::String {
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
                 match self {
-                    Self(0) => ::std::string::String::from("A"),
-                    Self(1) => ::std::string::String::from("B"),
-                    Self(val) => val.to_string(),
+                    Self(0) => ::std::option::Option::Some("A"),
+                    Self(1) => ::std::option::Option::Some("B"),
+                    Self(_) => ::std::option::Option::None,
                 }
             }
 
@@ -916,6 +570,29 @@ pub mod btree {
             }
         }
 
+        impl ::std::fmt::Display for Index {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => ::std::fmt::Display::fmt(&self.inner(), f),
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for Index {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "A" => ::std::result::Result::Ok(Self::A),
+                    "B" => ::std::result::Result::Ok(Self::B),
+                    _ => s.parse::<i32>().map(Self::from).map_err(|_| {
+                        ::pilota::EnumConvertError::InvalidNum(s.to_string(), "Index")
+                    }),
+                }
+            }
+        }
+
         impl ::pilota::thrift::Message for Index {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
@@ -972,5 +649,196 @@ pub mod btree {
                 __protocol.i32_len(self.inner())
             }
         }
+        pub static TEST_MAP: ::std::sync::LazyLock<
+            ::std::collections::BTreeMap<Index, &'static str>,
+        > = ::std::sync::LazyLock::new(|| {
+            let mut map = ::std::collections::BTreeMap::new();
+            map.insert(Index::A, "hello");
+            map.insert(Index::B, "world");
+            map
+        });
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct TypeA(
+            pub ::std::collections::BTreeMap<::std::collections::BTreeSet<i32>, ::pilota::FastStr>,
+        );
+
+        impl ::std::ops::Deref for TypeA {
+            type Target =
+                ::std::collections::BTreeMap<::std::collections::BTreeSet<i32>, ::pilota::FastStr>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl
+            From<::std::collections::BTreeMap<::std::collections::BTreeSet<i32>, ::pilota::FastStr>>
+            for TypeA
+        {
+            fn from(
+                v: ::std::collections::BTreeMap<
+                    ::std::collections::BTreeSet<i32>,
+                    ::pilota::FastStr,
+                >,
+            ) -> Self {
+                Self(v)
+            }
+        }
+
+        impl ::pilota::thrift::Message for TypeA {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_btree_map(
+                    ::pilota::thrift::TType::Set,
+                    ::pilota::thrift::TType::Binary,
+                    &(&**self),
+                    |__protocol, key| {
+                        __protocol.write_btree_set(
+                            ::pilota::thrift::TType::I32,
+                            &key,
+                            |__protocol, val| {
+                                __protocol.write_i32(*val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                        ::std::result::Result::Ok(())
+                    },
+                    |__protocol, val| {
+                        __protocol.write_faststr((val).clone())?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                ::std::result::Result::Ok(TypeA({
+                    let map_ident = __protocol.read_map_begin()?;
+                    let mut val = ::std::collections::BTreeMap::new();
+                    for _ in 0..map_ident.size {
+                        val.insert(
+                            {
+                                let list_ident = __protocol.read_set_begin()?;
+                                let mut val = ::std::collections::BTreeSet::new();
+                                for _ in 0..list_ident.size {
+                                    val.insert(__protocol.read_i32()?);
+                                }
+                                __protocol.read_set_end()?;
+                                val
+                            },
+                            __protocol.read_faststr()?,
+                        );
+                    }
+                    __protocol.read_map_end()?;
+                    val
+                }))
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    ::std::result::Result::Ok(TypeA({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::std::collections::BTreeMap::new();
+                        for _ in 0..map_ident.size {
+                            val.insert(
+                                {
+                                    let list_ident = __protocol.read_set_begin().await?;
+                                    let mut val = ::std::collections::BTreeSet::new();
+                                    for _ in 0..list_ident.size {
+                                        val.insert(__protocol.read_i32().await?);
+                                    }
+                                    __protocol.read_set_end().await?;
+                                    val
+                                },
+                                __protocol.read_faststr().await?,
+                            );
+                        }
+                        __protocol.read_map_end().await?;
+                        val
+                    }))
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.btree_map_len(
+                    ::pilota::thrift::TType::Set,
+                    ::pilota::thrift::TType::Binary,
+                    &**self,
+                    |__protocol, key| {
+                        __protocol.btree_set_len(
+                            ::pilota::thrift::TType::I32,
+                            key,
+                            |__protocol, el| __protocol.i32_len(*el),
+                        )
+                    },
+                    |__protocol, val| __protocol.faststr_len(val),
+                )
+            }
+        }
+
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Vec {}
+        impl ::pilota::thrift::Message for Vec {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_empty_struct(&::pilota::thrift::TStructIdentifier { name: "Vec" })?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                __protocol.read_empty_struct()?;
+                ::std::result::Result::Ok(Self {})
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    __protocol.read_empty_struct().await?;
+                    ::std::result::Result::Ok(Self {})
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.empty_struct_len(&::pilota::thrift::TStructIdentifier { name: "Vec" })
+            }
+        }
     }
 }
@@ -0,0 +1,220 @@
+pub mod checksum {
+    #![allow(warnings, clippy::all)]
+
+    pub mod checksum {
+
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Upload {
+            pub file: ::pilota::Bytes,
+
+            pub archive: ::std::option::Option<::pilota::Bytes>,
+        }
+        impl ::pilota::thrift::Message for Upload {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Upload" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol
+                    .write_bytes_field(1, ::pilota::thrift::checksum::append_crc32c(&self.file))?;
+                if let Some(value) = self.archive.as_ref() {
+                    __protocol.write_bytes_field(
+                        2,
+                        ::pilota::thrift::checksum::append_crc32c(
+                            &::pilota::thrift::compress::compress(
+                                ::pilota::thrift::compress::Compression::Zstd,
+                                value,
+                            )?,
+                        ),
+                    )?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(::pilota::thrift::checksum::verify_crc32c(
+                                    __protocol.read_bytes()?,
+                                )?);
+                            }
+                            Some(2)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_2 = Some(::pilota::thrift::compress::decompress(
+                                    ::pilota::thrift::checksum::verify_crc32c(
+                                        __protocol.read_bytes()?,
+                                    )?,
+                                )?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(&format!(
+                            "decode struct `Upload` field(#{}) failed, caused by: ",
+                            field_id
+                        ));
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field file is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    file: var_1,
+                    archive: var_2,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(::pilota::thrift::checksum::verify_crc32c(
+                                        __protocol.read_bytes().await?,
+                                    )?);
+                                }
+                                Some(2)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_2 = Some(::pilota::thrift::compress::decompress(
+                                        ::pilota::thrift::checksum::verify_crc32c(
+                                            __protocol.read_bytes().await?,
+                                        )?,
+                                    )?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!(
+                                "decode struct `Upload` field(#{}) failed, caused by: ",
+                                field_id
+                            ));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field file is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        file: var_1,
+                        archive: var_2,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Upload" })
+                    + __protocol.bytes_field_len(
+                        Some(1),
+                        &::pilota::thrift::checksum::append_crc32c(&self.file),
+                    )
+                    + self.archive.as_ref().map_or(0, |value| {
+                        __protocol.bytes_field_len(
+                            Some(2),
+                            &::pilota::thrift::checksum::append_crc32c(
+                                &::pilota::thrift::compress::compress(
+                                    ::pilota::thrift::compress::Compression::Zstd,
+                                    value,
+                                )
+                                .unwrap_or_default(),
+                            ),
+                        )
+                    })
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+    }
+}
@@ -225,15 +225,19 @@ pub mod comments {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+
                     var_1 = Some(__protocol.read_i64().await?);
 
                 },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
                     var_2 = Some(__protocol.read_faststr().await?);
 
                 },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
                     var_3 = Some(__protocol.read_faststr().await?);
 
                 },Some(10) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+
                     var_10 = Some({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
@@ -246,6 +250,7 @@ pub mod comments {
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -324,12 +329,145 @@ pub mod comments {
                             + __protocol.struct_end_len()
                     }
                 }
-                // method to get an item
+                // Status enum represents the status of an operation
+
+                #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+                #[repr(transparent)]
+                pub struct Status(i32);
+
+                impl Status {
+                    pub const SUCCESS: Self = Self(0);
+                    pub const ERROR: Self = Self(1);
+
+                    pub fn inner(&self) -> i32 {
+                        self.0
+                    }
+
+                    pub fn as_str(&self) -> ::std::option::Option<&'static str> {
+                        match self {
+                            Self(0) => ::std::option::Option::Some("SUCCESS"),
+                            Self(1) => ::std::option::Option::Some("ERROR"),
+                            Self(_) => ::std::option::Option::None,
+                        }
+                    }
+
+                    pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
+                        match value {
+                            0 => Some(Self::SUCCESS),
+                            1 => Some(Self::ERROR),
+                            _ => None,
+                        }
+                    }
+                }
+
+                impl ::std::convert::From<i32> for Status {
+                    fn from(value: i32) -> Self {
+                        Self(value)
+                    }
+                }
+
+                impl ::std::convert::From<Status> for i32 {
+                    fn from(value: Status) -> i32 {
+                        value.0
+                    }
+                }
+
+                impl ::std::fmt::Display for Status {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        match self.as_str() {
+                            ::std::option::Option::Some(s) => f.write_str(s),
+                            ::std::option::Option::None => {
+                                ::std::fmt::Display::fmt(&self.inner(), f)
+                            }
+                        }
+                    }
+                }
+
+                impl ::std::str::FromStr for Status {
+                    type Err = ::pilota::EnumConvertError<::std::string::String>;
+
+                    fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                        match s {
+                            "SUCCESS" => ::std::result::Result::Ok(Self::SUCCESS),
+                            "ERROR" => ::std::result::Result::Ok(Self::ERROR),
+                            _ => s.parse::<i32>().map(Self::from).map_err(|_| {
+                                ::pilota::EnumConvertError::InvalidNum(s.to_string(), "Status")
+                            }),
+                        }
+                    }
+                }
+
+                impl ::pilota::thrift::Message for Status {
+                    fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                        &self,
+                        __protocol: &mut T,
+                    ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException>
+                    {
+                        #[allow(unused_imports)]
+                        use ::pilota::thrift::TOutputProtocolExt;
+                        __protocol.write_i32(self.inner())?;
+                        ::std::result::Result::Ok(())
+                    }
+
+                    fn decode<T: ::pilota::thrift::TInputProtocol>(
+                        __protocol: &mut T,
+                    ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
+                    {
+                        #[allow(unused_imports)]
+                        use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                        let value = __protocol.read_i32()?;
+                        ::std::result::Result::Ok(
+                            ::std::convert::TryFrom::try_from(value).map_err(|err| {
+                                ::pilota::thrift::new_protocol_exception(
+                                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                    format!("invalid enum value for Status, value: {}", value),
+                                )
+                            })?,
+                        )
+                    }
+
+                    fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                        __protocol: &'a mut T,
+                    ) -> ::std::pin::Pin<
+                        ::std::boxed::Box<
+                            dyn ::std::future::Future<
+                                    Output = ::std::result::Result<
+                                        Self,
+                                        ::pilota::thrift::ThriftException,
+                                    >,
+                                > + Send
+                                + 'a,
+                        >,
+                    > {
+                        ::std::boxed::Box::pin(async move {
+                            let value = __protocol.read_i32().await?;
+                            ::std::result::Result::Ok(
+                                ::std::convert::TryFrom::try_from(value).map_err(|err| {
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        format!("invalid enum value for Status, value: {}", value),
+                                    )
+                                })?,
+                            )
+                        })
+                    }
+
+                    fn size<T: ::pilota::thrift::TLengthProtocol>(
+                        &self,
+                        __protocol: &mut T,
+                    ) -> usize {
+                        #[allow(unused_imports)]
+                        use ::pilota::thrift::TLengthProtocolExt;
+                        __protocol.i32_len(self.inner())
+                    }
+                }
+                // GetItemRequest struct represents the request for getting an item
+
                 #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-                pub struct TestServiceGetItemArgsRecv {
-                    pub req: GetItemRequest,
+                pub struct GetItemRequest {
+                    pub id: i64,
                 }
-                impl ::pilota::thrift::Message for TestServiceGetItemArgsRecv {
+                impl ::pilota::thrift::Message for GetItemRequest {
                     fn encode<T: ::pilota::thrift::TOutputProtocol>(
                         &self,
                         __protocol: &mut T,
@@ -338,15 +476,11 @@ pub mod comments {
                         #[allow(unused_imports)]
                         use ::pilota::thrift::TOutputProtocolExt;
                         let struct_ident = ::pilota::thrift::TStructIdentifier {
-                            name: "TestServiceGetItemArgsRecv",
+                            name: "GetItemRequest",
                         };
 
                         __protocol.write_struct_begin(&struct_ident)?;
-                        __protocol.write_struct_field(
-                            1,
-                            &self.req,
-                            ::pilota::thrift::TType::Struct,
-                        )?;
+                        __protocol.write_i64_field(1, *&self.id)?;
                         __protocol.write_field_stop()?;
                         __protocol.write_struct_end()?;
                         ::std::result::Result::Ok(())
@@ -378,10 +512,9 @@ pub mod comments {
                                 match field_ident.id {
                                     Some(1)
                                         if field_ident.field_type
-                                            == ::pilota::thrift::TType::Struct =>
+                                            == ::pilota::thrift::TType::I64 =>
                                     {
-                                        var_1 =
-                                            Some(::pilota::thrift::Message::decode(__protocol)?);
+                                        var_1 = Some(__protocol.read_i64()?);
                                     }
                                     _ => {
                                         __protocol.skip(field_ident.field_type)?;
@@ -394,7 +527,10 @@ pub mod comments {
                             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                         })() {
                             if let Some(field_id) = __pilota_decoding_field_id {
-                                err.prepend_msg(&format!("decode struct `TestServiceGetItemArgsRecv` field(#{}) failed, caused by: ", field_id));
+                                err.prepend_msg(&format!(
+                                    "decode struct `GetItemRequest` field(#{}) failed, caused by: ",
+                                    field_id
+                                ));
                             }
                             return ::std::result::Result::Err(err);
                         };
@@ -404,12 +540,12 @@ pub mod comments {
                             return ::std::result::Result::Err(
                                 ::pilota::thrift::new_protocol_exception(
                                     ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                    "field req is required".to_string(),
+                                    "field id is required".to_string(),
                                 ),
                             );
                         };
 
-                        let data = Self { req: var_1 };
+                        let data = Self { id: var_1 };
                         ::std::result::Result::Ok(data)
                     }
 
@@ -445,11 +581,13 @@ pub mod comments {
                 }
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
-                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
-                    var_1 = Some(<GetItemRequest as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+
+                    var_1 = Some(__protocol.read_i64().await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -462,7 +600,7 @@ pub mod comments {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `TestServiceGetItemArgsRecv` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_msg(&format!("decode struct `GetItemRequest` field(#{}) failed, caused by: ", field_id));
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -472,12 +610,12 @@ pub mod comments {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
                                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "field req is required".to_string(),
+                                        "field id is required".to_string(),
                                     ),
                                 );
                             };
 
-                            let data = Self { req: var_1 };
+                            let data = Self { id: var_1 };
                             ::std::result::Result::Ok(data)
                         })
                     }
@@ -489,26 +627,21 @@ pub mod comments {
                         #[allow(unused_imports)]
                         use ::pilota::thrift::TLengthProtocolExt;
                         __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                            name: "TestServiceGetItemArgsRecv",
-                        }) + __protocol.struct_field_len(Some(1), &self.req)
+                            name: "GetItemRequest",
+                        }) + __protocol.i64_field_len(Some(1), *&self.id)
                             + __protocol.field_stop_len()
                             + __protocol.struct_end_len()
                     }
                 }
-                // method to get an item
+                // GetItemResponse struct represents the response for getting an item
 
-                impl ::std::default::Default for TestServiceGetItemResultSend {
-                    fn default() -> Self {
-                        TestServiceGetItemResultSend::Ok(::std::default::Default::default())
-                    }
-                }
-                #[derive(Debug, Clone, PartialEq)]
-                pub enum TestServiceGetItemResultSend {
-                    // method to get an item
-                    Ok(GetItemResponse),
-                }
+                #[derive(Debug, Default, Clone, PartialEq)]
+                pub struct GetItemResponse {
+                    pub item: Item,
 
-                impl ::pilota::thrift::Message for TestServiceGetItemResultSend {
+                    pub status: Status,
+                }
+                impl ::pilota::thrift::Message for GetItemResponse {
                     fn encode<T: ::pilota::thrift::TOutputProtocol>(
                         &self,
                         __protocol: &mut T,
@@ -516,18 +649,17 @@ pub mod comments {
                     {
                         #[allow(unused_imports)]
                         use ::pilota::thrift::TOutputProtocolExt;
-                        __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                            name: "TestServiceGetItemResultSend",
-                        })?;
-                        match self {
-                            TestServiceGetItemResultSend::Ok(value) => {
-                                __protocol.write_struct_field(
-                                    0,
-                                    value,
-                                    ::pilota::thrift::TType::Struct,
-                                )?;
-                            }
-                        }
+                        let struct_ident = ::pilota::thrift::TStructIdentifier {
+                            name: "GetItemResponse",
+                        };
+
+                        __protocol.write_struct_begin(&struct_ident)?;
+                        __protocol.write_struct_field(
+                            1,
+                            &self.item,
+                            ::pilota::thrift::TType::Struct,
+                        )?;
+                        __protocol.write_i32_field(2, (&self.status).inner())?;
                         __protocol.write_field_stop()?;
                         __protocol.write_struct_end()?;
                         ::std::result::Result::Ok(())
@@ -539,173 +671,22 @@ pub mod comments {
                     {
                         #[allow(unused_imports)]
                         use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                        let mut ret = None;
+
+                        let mut var_1 = None;
+                        let mut var_2 = None;
+
+                        let mut __pilota_decoding_field_id = None;
+
                         __protocol.read_struct_begin()?;
-                        loop {
-                            let field_ident = __protocol.read_field_begin()?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                __protocol.field_stop_len();
-                                break;
-                            } else {
-                                __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                            }
-                            match field_ident.id {
-                                Some(0) => {
-                                    if ret.is_none() {
-                                        let field_ident =
-                                            ::pilota::thrift::Message::decode(__protocol)?;
-                                        __protocol.struct_len(&field_ident);
-                                        ret = Some(TestServiceGetItemResultSend::Ok(field_ident));
-                                    } else {
-                                        return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message"
-                                        ));
-                                    }
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type)?;
-                                }
-                            }
-                        }
-                        __protocol.read_field_end()?;
-                        __protocol.read_struct_end()?;
-                        if let Some(ret) = ret {
-                            ::std::result::Result::Ok(ret)
-                        } else {
-                            ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "received empty union from remote Message",
-                            ))
-                        }
-                    }
-
-                    fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                        __protocol: &'a mut T,
-                    ) -> ::std::pin::Pin<
-                        ::std::boxed::Box<
-                            dyn ::std::future::Future<
-                                    Output = ::std::result::Result<
-                                        Self,
-                                        ::pilota::thrift::ThriftException,
-                                    >,
-                                > + Send
-                                + 'a,
-                        >,
-                    > {
-                        ::std::boxed::Box::pin(async move {
-                            let mut ret = None;
-                            __protocol.read_struct_begin().await?;
-                            loop {
-                                let field_ident = __protocol.read_field_begin().await?;
-                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                    break;
-                                } else {
-                                }
-                                match field_ident.id {
-                                    Some(0) => {
-                                        if ret.is_none() {
-                                            let field_ident = <GetItemResponse as ::pilota::thrift::Message>::decode_async(__protocol).await?;
-
-                                            ret =
-                                                Some(TestServiceGetItemResultSend::Ok(field_ident));
-                                        } else {
-                                            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message"
-                                        ));
-                                        }
-                                    }
-                                    _ => {
-                                        __protocol.skip(field_ident.field_type).await?;
-                                    }
-                                }
-                            }
-                            __protocol.read_field_end().await?;
-                            __protocol.read_struct_end().await?;
-                            if let Some(ret) = ret {
-                                ::std::result::Result::Ok(ret)
-                            } else {
-                                ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received empty union from remote Message",
-                                    ),
-                                )
-                            }
-                        })
-                    }
-
-                    fn size<T: ::pilota::thrift::TLengthProtocol>(
-                        &self,
-                        __protocol: &mut T,
-                    ) -> usize {
-                        #[allow(unused_imports)]
-                        use ::pilota::thrift::TLengthProtocolExt;
-                        __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                            name: "TestServiceGetItemResultSend",
-                        }) + match self {
-                            TestServiceGetItemResultSend::Ok(value) => {
-                                __protocol.struct_field_len(Some(0), value)
-                            }
-                        } + __protocol.field_stop_len()
-                            + __protocol.struct_end_len()
-                    }
-                }
-                // GetItemResponse struct represents the response for getting an item
-
-                #[derive(Debug, Default, Clone, PartialEq)]
-                pub struct GetItemResponse {
-                    pub item: Item,
-
-                    pub status: Status,
-                }
-                impl ::pilota::thrift::Message for GetItemResponse {
-                    fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                        &self,
-                        __protocol: &mut T,
-                    ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException>
-                    {
-                        #[allow(unused_imports)]
-                        use ::pilota::thrift::TOutputProtocolExt;
-                        let struct_ident = ::pilota::thrift::TStructIdentifier {
-                            name: "GetItemResponse",
-                        };
-
-                        __protocol.write_struct_begin(&struct_ident)?;
-                        __protocol.write_struct_field(
-                            1,
-                            &self.item,
-                            ::pilota::thrift::TType::Struct,
-                        )?;
-                        __protocol.write_i32_field(2, (&self.status).inner())?;
-                        __protocol.write_field_stop()?;
-                        __protocol.write_struct_end()?;
-                        ::std::result::Result::Ok(())
-                    }
-
-                    fn decode<T: ::pilota::thrift::TInputProtocol>(
-                        __protocol: &mut T,
-                    ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
-                    {
-                        #[allow(unused_imports)]
-                        use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                        let mut var_1 = None;
-                        let mut var_2 = None;
-
-                        let mut __pilota_decoding_field_id = None;
-
-                        __protocol.read_struct_begin()?;
-                        if let ::std::result::Result::Err(mut err) = (|| {
-                            loop {
-                                let field_ident = __protocol.read_field_begin()?;
-                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                    __protocol.field_stop_len();
-                                    break;
-                                } else {
-                                    __protocol
-                                        .field_begin_len(field_ident.field_type, field_ident.id);
+                        if let ::std::result::Result::Err(mut err) = (|| {
+                            loop {
+                                let field_ident = __protocol.read_field_begin()?;
+                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                    __protocol.field_stop_len();
+                                    break;
+                                } else {
+                                    __protocol
+                                        .field_begin_len(field_ident.field_type, field_ident.id);
                                 }
                                 __pilota_decoding_field_id = field_ident.id;
                                 match field_ident.id {
@@ -798,13 +779,16 @@ pub mod comments {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_1 = Some(<Item as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },Some(2) if field_ident.field_type == ::pilota::thrift::TType::I32  => {
+
                     var_2 = Some(<Status as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -863,11 +847,19 @@ pub mod comments {
                     }
                 }
                 // method to get an item
-                #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-                pub struct TestServiceGetItemArgsSend {
-                    pub req: GetItemRequest,
+
+                impl ::std::default::Default for TestServiceGetItemResultRecv {
+                    fn default() -> Self {
+                        TestServiceGetItemResultRecv::Ok(::std::default::Default::default())
+                    }
                 }
-                impl ::pilota::thrift::Message for TestServiceGetItemArgsSend {
+                #[derive(Debug, Clone, PartialEq)]
+                pub enum TestServiceGetItemResultRecv {
+                    // method to get an item
+                    Ok(GetItemResponse),
+                }
+
+                impl ::pilota::thrift::Message for TestServiceGetItemResultRecv {
                     fn encode<T: ::pilota::thrift::TOutputProtocol>(
                         &self,
                         __protocol: &mut T,
@@ -875,16 +867,18 @@ pub mod comments {
                     {
                         #[allow(unused_imports)]
                         use ::pilota::thrift::TOutputProtocolExt;
-                        let struct_ident = ::pilota::thrift::TStructIdentifier {
-                            name: "TestServiceGetItemArgsSend",
-                        };
-
-                        __protocol.write_struct_begin(&struct_ident)?;
-                        __protocol.write_struct_field(
-                            1,
-                            &self.req,
-                            ::pilota::thrift::TType::Struct,
-                        )?;
+                        __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                            name: "TestServiceGetItemResultRecv",
+                        })?;
+                        match self {
+                            TestServiceGetItemResultRecv::Ok(value) => {
+                                __protocol.write_struct_field(
+                                    0,
+                                    value,
+                                    ::pilota::thrift::TType::Struct,
+                                )?;
+                            }
+                        }
                         __protocol.write_field_stop()?;
                         __protocol.write_struct_end()?;
                         ::std::result::Result::Ok(())
@@ -896,59 +890,45 @@ pub mod comments {
                     {
                         #[allow(unused_imports)]
                         use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                        let mut var_1 = None;
-
-                        let mut __pilota_decoding_field_id = None;
-
+                        let mut ret = None;
                         __protocol.read_struct_begin()?;
-                        if let ::std::result::Result::Err(mut err) = (|| {
-                            loop {
-                                let field_ident = __protocol.read_field_begin()?;
-                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                    __protocol.field_stop_len();
-                                    break;
-                                } else {
-                                    __protocol
-                                        .field_begin_len(field_ident.field_type, field_ident.id);
-                                }
-                                __pilota_decoding_field_id = field_ident.id;
-                                match field_ident.id {
-                                    Some(1)
-                                        if field_ident.field_type
-                                            == ::pilota::thrift::TType::Struct =>
-                                    {
-                                        var_1 =
-                                            Some(::pilota::thrift::Message::decode(__protocol)?);
-                                    }
-                                    _ => {
-                                        __protocol.skip(field_ident.field_type)?;
+                        loop {
+                            let field_ident = __protocol.read_field_begin()?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                __protocol.field_stop_len();
+                                break;
+                            } else {
+                                __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                            }
+                            match field_ident.id {
+                                Some(0) => {
+                                    if ret.is_none() {
+                                        let field_ident =
+                                            ::pilota::thrift::Message::decode(__protocol)?;
+                                        __protocol.struct_len(&field_ident);
+                                        ret = Some(TestServiceGetItemResultRecv::Ok(field_ident));
+                                    } else {
+                                        return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
                                     }
                                 }
-
-                                __protocol.read_field_end()?;
-                                __protocol.field_end_len();
-                            }
-                            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                        })() {
-                            if let Some(field_id) = __pilota_decoding_field_id {
-                                err.prepend_msg(&format!("decode struct `TestServiceGetItemArgsSend` field(#{}) failed, caused by: ", field_id));
+                                _ => {
+                                    __protocol.skip(field_ident.field_type)?;
+                                }
                             }
-                            return ::std::result::Result::Err(err);
-                        };
+                        }
+                        __protocol.read_field_end()?;
                         __protocol.read_struct_end()?;
-
-                        let Some(var_1) = var_1 else {
-                            return ::std::result::Result::Err(
-                                ::pilota::thrift::new_protocol_exception(
-                                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                    "field req is required".to_string(),
-                                ),
-                            );
-                        };
-
-                        let data = Self { req: var_1 };
-                        ::std::result::Result::Ok(data)
+                        if let Some(ret) = ret {
+                            ::std::result::Result::Ok(ret)
+                        } else {
+                            ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ))
+                        }
                     }
 
                     fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -965,58 +945,202 @@ pub mod comments {
                         >,
                     > {
                         ::std::boxed::Box::pin(async move {
-                            let mut var_1 = None;
-
-                            let mut __pilota_decoding_field_id = None;
-
+                            let mut ret = None;
                             __protocol.read_struct_begin().await?;
-                            if let ::std::result::Result::Err(mut err) = async {
-                    loop {
-
-
-                let field_ident = __protocol.read_field_begin().await?;
-                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            loop {
+                                let field_ident = __protocol.read_field_begin().await?;
+                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                    break;
+                                } else {
+                                }
+                                match field_ident.id {
+                                    Some(0) => {
+                                        if ret.is_none() {
+                                            let field_ident = <GetItemResponse as ::pilota::thrift::Message>::decode_async(__protocol).await?;
 
-                    break;
-                } else {
+                                            ret =
+                                                Some(TestServiceGetItemResultRecv::Ok(field_ident));
+                                        } else {
+                                            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                        }
+                                    }
+                                    _ => {
+                                        __protocol.skip(field_ident.field_type).await?;
+                                    }
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                            __protocol.read_struct_end().await?;
+                            if let Some(ret) = ret {
+                                ::std::result::Result::Ok(ret)
+                            } else {
+                                ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received empty union from remote Message",
+                                    ),
+                                )
+                            }
+                        })
+                    }
 
+                    fn size<T: ::pilota::thrift::TLengthProtocol>(
+                        &self,
+                        __protocol: &mut T,
+                    ) -> usize {
+                        #[allow(unused_imports)]
+                        use ::pilota::thrift::TLengthProtocolExt;
+                        __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                            name: "TestServiceGetItemResultRecv",
+                        }) + match self {
+                            TestServiceGetItemResultRecv::Ok(value) => {
+                                __protocol.struct_field_len(Some(0), value)
+                            }
+                        } + __protocol.field_stop_len()
+                            + __protocol.struct_end_len()
+                    }
                 }
-                __pilota_decoding_field_id = field_ident.id;
-                match field_ident.id {
-                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
-                    var_1 = Some(<GetItemRequest as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-
-                },
-                    _ => {
-                        __protocol.skip(field_ident.field_type).await?;
+                // method to get an item
 
-                    },
+                impl ::std::default::Default for TestServiceGetItemResultSend {
+                    fn default() -> Self {
+                        TestServiceGetItemResultSend::Ok(::std::default::Default::default())
+                    }
+                }
+                #[derive(Debug, Clone, PartialEq)]
+                pub enum TestServiceGetItemResultSend {
+                    // method to get an item
+                    Ok(GetItemResponse),
                 }
 
-                __protocol.read_field_end().await?;
+                impl ::pilota::thrift::Message for TestServiceGetItemResultSend {
+                    fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                        &self,
+                        __protocol: &mut T,
+                    ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException>
+                    {
+                        #[allow(unused_imports)]
+                        use ::pilota::thrift::TOutputProtocolExt;
+                        __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                            name: "TestServiceGetItemResultSend",
+                        })?;
+                        match self {
+                            TestServiceGetItemResultSend::Ok(value) => {
+                                __protocol.write_struct_field(
+                                    0,
+                                    value,
+                                    ::pilota::thrift::TType::Struct,
+                                )?;
+                            }
+                        }
+                        __protocol.write_field_stop()?;
+                        __protocol.write_struct_end()?;
+                        ::std::result::Result::Ok(())
+                    }
+
+                    fn decode<T: ::pilota::thrift::TInputProtocol>(
+                        __protocol: &mut T,
+                    ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
+                    {
+                        #[allow(unused_imports)]
+                        use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                        let mut ret = None;
+                        __protocol.read_struct_begin()?;
+                        loop {
+                            let field_ident = __protocol.read_field_begin()?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                __protocol.field_stop_len();
+                                break;
+                            } else {
+                                __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                            }
+                            match field_ident.id {
+                                Some(0) => {
+                                    if ret.is_none() {
+                                        let field_ident =
+                                            ::pilota::thrift::Message::decode(__protocol)?;
+                                        __protocol.struct_len(&field_ident);
+                                        ret = Some(TestServiceGetItemResultSend::Ok(field_ident));
+                                    } else {
+                                        return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                    }
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type)?;
+                                }
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.read_struct_end()?;
+                        if let Some(ret) = ret {
+                            ::std::result::Result::Ok(ret)
+                        } else {
+                            ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ))
+                        }
+                    }
 
+                    fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                        __protocol: &'a mut T,
+                    ) -> ::std::pin::Pin<
+                        ::std::boxed::Box<
+                            dyn ::std::future::Future<
+                                    Output = ::std::result::Result<
+                                        Self,
+                                        ::pilota::thrift::ThriftException,
+                                    >,
+                                > + Send
+                                + 'a,
+                        >,
+                    > {
+                        ::std::boxed::Box::pin(async move {
+                            let mut ret = None;
+                            __protocol.read_struct_begin().await?;
+                            loop {
+                                let field_ident = __protocol.read_field_begin().await?;
+                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                    break;
+                                } else {
+                                }
+                                match field_ident.id {
+                                    Some(0) => {
+                                        if ret.is_none() {
+                                            let field_ident = <GetItemResponse as ::pilota::thrift::Message>::decode_async(__protocol).await?;
 
-            };
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                }.await {
-                if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `TestServiceGetItemArgsSend` field(#{}) failed, caused by: ", field_id));
-                }
-                return ::std::result::Result::Err(err);
-            };
+                                            ret =
+                                                Some(TestServiceGetItemResultSend::Ok(field_ident));
+                                        } else {
+                                            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                        }
+                                    }
+                                    _ => {
+                                        __protocol.skip(field_ident.field_type).await?;
+                                    }
+                                }
+                            }
+                            __protocol.read_field_end().await?;
                             __protocol.read_struct_end().await?;
-
-                            let Some(var_1) = var_1 else {
-                                return ::std::result::Result::Err(
+                            if let Some(ret) = ret {
+                                ::std::result::Result::Ok(ret)
+                            } else {
+                                ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
                                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "field req is required".to_string(),
+                                        "received empty union from remote Message",
                                     ),
-                                );
-                            };
-
-                            let data = Self { req: var_1 };
-                            ::std::result::Result::Ok(data)
+                                )
+                            }
                         })
                     }
 
@@ -1027,19 +1151,21 @@ pub mod comments {
                         #[allow(unused_imports)]
                         use ::pilota::thrift::TLengthProtocolExt;
                         __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                            name: "TestServiceGetItemArgsSend",
-                        }) + __protocol.struct_field_len(Some(1), &self.req)
-                            + __protocol.field_stop_len()
+                            name: "TestServiceGetItemResultSend",
+                        }) + match self {
+                            TestServiceGetItemResultSend::Ok(value) => {
+                                __protocol.struct_field_len(Some(0), value)
+                            }
+                        } + __protocol.field_stop_len()
                             + __protocol.struct_end_len()
                     }
                 }
-                // GetItemRequest struct represents the request for getting an item
-
+                // method to get an item
                 #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-                pub struct GetItemRequest {
-                    pub id: i64,
+                pub struct TestServiceGetItemArgsSend {
+                    pub req: GetItemRequest,
                 }
-                impl ::pilota::thrift::Message for GetItemRequest {
+                impl ::pilota::thrift::Message for TestServiceGetItemArgsSend {
                     fn encode<T: ::pilota::thrift::TOutputProtocol>(
                         &self,
                         __protocol: &mut T,
@@ -1048,11 +1174,15 @@ pub mod comments {
                         #[allow(unused_imports)]
                         use ::pilota::thrift::TOutputProtocolExt;
                         let struct_ident = ::pilota::thrift::TStructIdentifier {
-                            name: "GetItemRequest",
+                            name: "TestServiceGetItemArgsSend",
                         };
 
                         __protocol.write_struct_begin(&struct_ident)?;
-                        __protocol.write_i64_field(1, *&self.id)?;
+                        __protocol.write_struct_field(
+                            1,
+                            &self.req,
+                            ::pilota::thrift::TType::Struct,
+                        )?;
                         __protocol.write_field_stop()?;
                         __protocol.write_struct_end()?;
                         ::std::result::Result::Ok(())
@@ -1084,9 +1214,10 @@ pub mod comments {
                                 match field_ident.id {
                                     Some(1)
                                         if field_ident.field_type
-                                            == ::pilota::thrift::TType::I64 =>
+                                            == ::pilota::thrift::TType::Struct =>
                                     {
-                                        var_1 = Some(__protocol.read_i64()?);
+                                        var_1 =
+                                            Some(::pilota::thrift::Message::decode(__protocol)?);
                                     }
                                     _ => {
                                         __protocol.skip(field_ident.field_type)?;
@@ -1099,10 +1230,7 @@ pub mod comments {
                             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                         })() {
                             if let Some(field_id) = __pilota_decoding_field_id {
-                                err.prepend_msg(&format!(
-                                    "decode struct `GetItemRequest` field(#{}) failed, caused by: ",
-                                    field_id
-                                ));
+                                err.prepend_msg(&format!("decode struct `TestServiceGetItemArgsSend` field(#{}) failed, caused by: ", field_id));
                             }
                             return ::std::result::Result::Err(err);
                         };
@@ -1112,12 +1240,12 @@ pub mod comments {
                             return ::std::result::Result::Err(
                                 ::pilota::thrift::new_protocol_exception(
                                     ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                    "field id is required".to_string(),
+                                    "field req is required".to_string(),
                                 ),
                             );
                         };
 
-                        let data = Self { id: var_1 };
+                        let data = Self { req: var_1 };
                         ::std::result::Result::Ok(data)
                     }
 
@@ -1153,11 +1281,13 @@ pub mod comments {
                 }
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
-                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
-                    var_1 = Some(__protocol.read_i64().await?);
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
+                    var_1 = Some(<GetItemRequest as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -1170,7 +1300,7 @@ pub mod comments {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `GetItemRequest` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_msg(&format!("decode struct `TestServiceGetItemArgsSend` field(#{}) failed, caused by: ", field_id));
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -1180,12 +1310,12 @@ pub mod comments {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
                                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "field id is required".to_string(),
+                                        "field req is required".to_string(),
                                     ),
                                 );
                             };
 
-                            let data = Self { id: var_1 };
+                            let data = Self { req: var_1 };
                             ::std::result::Result::Ok(data)
                         })
                     }
@@ -1197,56 +1327,18 @@ pub mod comments {
                         #[allow(unused_imports)]
                         use ::pilota::thrift::TLengthProtocolExt;
                         __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                            name: "GetItemRequest",
-                        }) + __protocol.i64_field_len(Some(1), *&self.id)
+                            name: "TestServiceGetItemArgsSend",
+                        }) + __protocol.struct_field_len(Some(1), &self.req)
                             + __protocol.field_stop_len()
                             + __protocol.struct_end_len()
                     }
                 }
-                // Status enum represents the status of an operation
-
-                #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
-                #[repr(transparent)]
-                pub struct Status(i32);
-
-                impl Status {
-                    pub const SUCCESS: Self = Self(0);
-                    pub const ERROR: Self = Self(1);
-
-                    pub fn inner(&self) -> i32 {
-                        self.0
-                    }
-
-                    pub fn to_string(&self) -> ::std::string::String {
-                        match self {
-                            Self(0) => ::std::string::String::from("SUCCESS"),
-                            Self(1) => ::std::string::String::from("ERROR"),
-                            Self(val) => val.to_string(),
-                        }
-                    }
-
-                    pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
-                        match value {
-                            0 => Some(Self::SUCCESS),
-                            1 => Some(Self::ERROR),
-                            _ => None,
-                        }
-                    }
-                }
-
-                impl ::std::convert::From<i32> for Status {
-                    fn from(value: i32) -> Self {
-                        Self(value)
-                    }
-                }
-
-                impl ::std::convert::From<Status> for i32 {
-                    fn from(value: Status) -> i32 {
-                        value.0
-                    }
+                // method to get an item
+                #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+                pub struct TestServiceGetItemArgsRecv {
+                    pub req: GetItemRequest,
                 }
-
-                impl ::pilota::thrift::Message for Status {
+                impl ::pilota::thrift::Message for TestServiceGetItemArgsRecv {
                     fn encode<T: ::pilota::thrift::TOutputProtocol>(
                         &self,
                         __protocol: &mut T,
@@ -1254,7 +1346,18 @@ pub mod comments {
                     {
                         #[allow(unused_imports)]
                         use ::pilota::thrift::TOutputProtocolExt;
-                        __protocol.write_i32(self.inner())?;
+                        let struct_ident = ::pilota::thrift::TStructIdentifier {
+                            name: "TestServiceGetItemArgsRecv",
+                        };
+
+                        __protocol.write_struct_begin(&struct_ident)?;
+                        __protocol.write_struct_field(
+                            1,
+                            &self.req,
+                            ::pilota::thrift::TType::Struct,
+                        )?;
+                        __protocol.write_field_stop()?;
+                        __protocol.write_struct_end()?;
                         ::std::result::Result::Ok(())
                     }
 
@@ -1264,15 +1367,59 @@ pub mod comments {
                     {
                         #[allow(unused_imports)]
                         use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                        let value = __protocol.read_i32()?;
-                        ::std::result::Result::Ok(
-                            ::std::convert::TryFrom::try_from(value).map_err(|err| {
+
+                        let mut var_1 = None;
+
+                        let mut __pilota_decoding_field_id = None;
+
+                        __protocol.read_struct_begin()?;
+                        if let ::std::result::Result::Err(mut err) = (|| {
+                            loop {
+                                let field_ident = __protocol.read_field_begin()?;
+                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                    __protocol.field_stop_len();
+                                    break;
+                                } else {
+                                    __protocol
+                                        .field_begin_len(field_ident.field_type, field_ident.id);
+                                }
+                                __pilota_decoding_field_id = field_ident.id;
+                                match field_ident.id {
+                                    Some(1)
+                                        if field_ident.field_type
+                                            == ::pilota::thrift::TType::Struct =>
+                                    {
+                                        var_1 =
+                                            Some(::pilota::thrift::Message::decode(__protocol)?);
+                                    }
+                                    _ => {
+                                        __protocol.skip(field_ident.field_type)?;
+                                    }
+                                }
+
+                                __protocol.read_field_end()?;
+                                __protocol.field_end_len();
+                            }
+                            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                        })() {
+                            if let Some(field_id) = __pilota_decoding_field_id {
+                                err.prepend_msg(&format!("decode struct `TestServiceGetItemArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            }
+                            return ::std::result::Result::Err(err);
+                        };
+                        __protocol.read_struct_end()?;
+
+                        let Some(var_1) = var_1 else {
+                            return ::std::result::Result::Err(
                                 ::pilota::thrift::new_protocol_exception(
                                     ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                    format!("invalid enum value for Status, value: {}", value),
-                                )
-                            })?,
-                        )
+                                    "field req is required".to_string(),
+                                ),
+                            );
+                        };
+
+                        let data = Self { req: var_1 };
+                        ::std::result::Result::Ok(data)
                     }
 
                     fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -1289,171 +1436,60 @@ pub mod comments {
                         >,
                     > {
                         ::std::boxed::Box::pin(async move {
-                            let value = __protocol.read_i32().await?;
-                            ::std::result::Result::Ok(
-                                ::std::convert::TryFrom::try_from(value).map_err(|err| {
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        format!("invalid enum value for Status, value: {}", value),
-                                    )
-                                })?,
-                            )
-                        })
-                    }
+                            let mut var_1 = None;
 
-                    fn size<T: ::pilota::thrift::TLengthProtocol>(
-                        &self,
-                        __protocol: &mut T,
-                    ) -> usize {
-                        #[allow(unused_imports)]
-                        use ::pilota::thrift::TLengthProtocolExt;
-                        __protocol.i32_len(self.inner())
-                    }
-                }
-                // Test Service
+                            let mut __pilota_decoding_field_id = None;
 
-                // This is a comment for the TestService
+                            __protocol.read_struct_begin().await?;
+                            if let ::std::result::Result::Err(mut err) = async {
+                    loop {
 
-                pub trait TestService {}
 
-                // method to get an item
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
 
-                impl ::std::default::Default for TestServiceGetItemResultRecv {
-                    fn default() -> Self {
-                        TestServiceGetItemResultRecv::Ok(::std::default::Default::default())
-                    }
-                }
-                #[derive(Debug, Clone, PartialEq)]
-                pub enum TestServiceGetItemResultRecv {
-                    // method to get an item
-                    Ok(GetItemResponse),
                 }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
 
-                impl ::pilota::thrift::Message for TestServiceGetItemResultRecv {
-                    fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                        &self,
-                        __protocol: &mut T,
-                    ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException>
-                    {
-                        #[allow(unused_imports)]
-                        use ::pilota::thrift::TOutputProtocolExt;
-                        __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                            name: "TestServiceGetItemResultRecv",
-                        })?;
-                        match self {
-                            TestServiceGetItemResultRecv::Ok(value) => {
-                                __protocol.write_struct_field(
-                                    0,
-                                    value,
-                                    ::pilota::thrift::TType::Struct,
-                                )?;
-                            }
-                        }
-                        __protocol.write_field_stop()?;
-                        __protocol.write_struct_end()?;
-                        ::std::result::Result::Ok(())
-                    }
+                    var_1 = Some(<GetItemRequest as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
-                    fn decode<T: ::pilota::thrift::TInputProtocol>(
-                        __protocol: &mut T,
-                    ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
-                    {
-                        #[allow(unused_imports)]
-                        use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                        let mut ret = None;
-                        __protocol.read_struct_begin()?;
-                        loop {
-                            let field_ident = __protocol.read_field_begin()?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                __protocol.field_stop_len();
-                                break;
-                            } else {
-                                __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                            }
-                            match field_ident.id {
-                                Some(0) => {
-                                    if ret.is_none() {
-                                        let field_ident =
-                                            ::pilota::thrift::Message::decode(__protocol)?;
-                                        __protocol.struct_len(&field_ident);
-                                        ret = Some(TestServiceGetItemResultRecv::Ok(field_ident));
-                                    } else {
-                                        return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message"
-                                        ));
-                                    }
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type)?;
-                                }
-                            }
-                        }
-                        __protocol.read_field_end()?;
-                        __protocol.read_struct_end()?;
-                        if let Some(ret) = ret {
-                            ::std::result::Result::Ok(ret)
-                        } else {
-                            ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "received empty union from remote Message",
-                            ))
-                        }
-                    }
+                },
+                    _ => {
 
-                    fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                        __protocol: &'a mut T,
-                    ) -> ::std::pin::Pin<
-                        ::std::boxed::Box<
-                            dyn ::std::future::Future<
-                                    Output = ::std::result::Result<
-                                        Self,
-                                        ::pilota::thrift::ThriftException,
-                                    >,
-                                > + Send
-                                + 'a,
-                        >,
-                    > {
-                        ::std::boxed::Box::pin(async move {
-                            let mut ret = None;
-                            __protocol.read_struct_begin().await?;
-                            loop {
-                                let field_ident = __protocol.read_field_begin().await?;
-                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                    break;
-                                } else {
-                                }
-                                match field_ident.id {
-                                    Some(0) => {
-                                        if ret.is_none() {
-                                            let field_ident = <GetItemResponse as ::pilota::thrift::Message>::decode_async(__protocol).await?;
+                        __protocol.skip(field_ident.field_type).await?;
 
-                                            ret =
-                                                Some(TestServiceGetItemResultRecv::Ok(field_ident));
-                                        } else {
-                                            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message"
-                                        ));
-                                        }
-                                    }
-                                    _ => {
-                                        __protocol.skip(field_ident.field_type).await?;
-                                    }
-                                }
-                            }
-                            __protocol.read_field_end().await?;
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_msg(&format!("decode struct `TestServiceGetItemArgsRecv` field(#{}) failed, caused by: ", field_id));
+                }
+                return ::std::result::Result::Err(err);
+            };
                             __protocol.read_struct_end().await?;
-                            if let Some(ret) = ret {
-                                ::std::result::Result::Ok(ret)
-                            } else {
-                                ::std::result::Result::Err(
+
+                            let Some(var_1) = var_1 else {
+                                return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
                                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received empty union from remote Message",
+                                        "field req is required".to_string(),
                                     ),
-                                )
-                            }
+                                );
+                            };
+
+                            let data = Self { req: var_1 };
+                            ::std::result::Result::Ok(data)
                         })
                     }
 
@@ -1464,15 +1500,17 @@ pub mod comments {
                         #[allow(unused_imports)]
                         use ::pilota::thrift::TLengthProtocolExt;
                         __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                            name: "TestServiceGetItemResultRecv",
-                        }) + match self {
-                            TestServiceGetItemResultRecv::Ok(value) => {
-                                __protocol.struct_field_len(Some(0), value)
-                            }
-                        } + __protocol.field_stop_len()
+                            name: "TestServiceGetItemArgsRecv",
+                        }) + __protocol.struct_field_len(Some(1), &self.req)
+                            + __protocol.field_stop_len()
                             + __protocol.struct_end_len()
                     }
                 }
+                // Test Service
+
+                // This is a comment for the TestService
+
+                pub trait TestService {}
             }
         }
     }
@@ -0,0 +1,5 @@
+struct Snapshot {
+    1: required string id,
+    2: required binary payload (pilota.compress = "zstd"),
+    3: optional binary thumbnail (pilota.compress = "zstd"),
+}
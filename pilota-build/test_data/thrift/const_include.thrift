@@ -0,0 +1,16 @@
+include "const_limits.thrift"
+include "const_val.thrift"
+
+const i32 LIMIT = const_limits.LIMIT
+const i64 WIDE_LIMIT = const_limits.LIMIT
+const double RATIO = const_limits.RETRIES
+const list<i64> LIMITS = [const_limits.RETRIES, const_limits.LIMIT]
+const list<string> LIST = const_val.TEST_LIST
+const i32 INDEX = const_val.Index.B
+const const_val.Index DEFAULT_INDEX = const_val.Index.A
+
+struct Limits {
+    1: optional i64 max = const_limits.LIMIT,
+    2: optional i32 retries = const_limits.RETRIES,
+    3: optional const_val.Index index = const_val.Index.B,
+}
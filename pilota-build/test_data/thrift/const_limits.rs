@@ -0,0 +1,9 @@
+pub mod const_limits {
+    #![allow(warnings, clippy::all)]
+
+    pub mod const_limits {
+
+        pub const RETRIES: i16 = 3i16;
+        pub const LIMIT: i32 = 100i32;
+    }
+}
@@ -0,0 +1,2 @@
+const i16 RETRIES = 3
+const i32 LIMIT = 100
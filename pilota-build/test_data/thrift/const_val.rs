@@ -15,11 +15,11 @@ pub mod const_val {
                 self.0
             }
 
-            pub fn to_string(&self) -> ::std::string::String {
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
                 match self {
-                    Self(0) => ::std::string::String::from("A"),
-                    Self(1) => ::std::string::String::from("B"),
-                    Self(val) => val.to_string(),
+                    Self(0) => ::std::option::Option::Some("A"),
+                    Self(1) => ::std::option::Option::Some("B"),
+                    Self(_) => ::std::option::Option::None,
                 }
             }
 
@@ -44,6 +44,29 @@ pub mod const_val {
             }
         }
 
+        impl ::std::fmt::Display for Index {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => ::std::fmt::Display::fmt(&self.inner(), f),
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for Index {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "A" => ::std::result::Result::Ok(Self::A),
+                    "B" => ::std::result::Result::Ok(Self::B),
+                    _ => s.parse::<i32>().map(Self::from).map_err(|_| {
+                        ::pilota::EnumConvertError::InvalidNum(s.to_string(), "Index")
+                    }),
+                }
+            }
+        }
+
         impl ::pilota::thrift::Message for Index {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
@@ -100,22 +123,6 @@ pub mod const_val {
                 __protocol.i32_len(self.inner())
             }
         }
-        pub const TEST_STRUCT: Test = Test { name: None };
-        pub static TEST_MAP: ::std::sync::LazyLock<::pilota::AHashMap<Index, &'static str>> =
-            ::std::sync::LazyLock::new(|| {
-                let mut map = ::pilota::AHashMap::with_capacity(2);
-                map.insert(Index::A, "hello");
-                map.insert(Index::B, "world");
-                map
-            });
-        pub const TEST_LIST: [&'static str; 2] = ["hello", "world"];
-        pub static TEST_MAP_LIST: ::std::sync::LazyLock<
-            ::pilota::AHashMap<i32, ::std::vec::Vec<&'static str>>,
-        > = ::std::sync::LazyLock::new(|| {
-            let mut map = ::pilota::AHashMap::with_capacity(1);
-            map.insert(1i32, ::std::vec!["hello"]);
-            map
-        });
 
         #[derive(Debug, Default, Clone, PartialEq)]
         pub struct Test {
@@ -299,5 +306,21 @@ pub mod const_val {
                     + __protocol.struct_end_len()
             }
         }
+        pub static TEST_MAP: ::std::sync::LazyLock<::pilota::AHashMap<Index, &'static str>> =
+            ::std::sync::LazyLock::new(|| {
+                let mut map = ::pilota::AHashMap::with_capacity(2);
+                map.insert(Index::A, "hello");
+                map.insert(Index::B, "world");
+                map
+            });
+        pub const TEST_LIST: [&'static str; 2] = ["hello", "world"];
+        pub static TEST_MAP_LIST: ::std::sync::LazyLock<
+            ::pilota::AHashMap<i32, ::std::vec::Vec<&'static str>>,
+        > = ::std::sync::LazyLock::new(|| {
+            let mut map = ::pilota::AHashMap::with_capacity(1);
+            map.insert(1i32, ::std::vec!["hello"]);
+            map
+        });
+        pub const TEST_STRUCT: Test = Test { name: None };
     }
 }
@@ -161,20 +161,20 @@ pub mod decode_error {
         }
 
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct C {
-            pub a: ::pilota::FastStr,
+        pub struct B {
+            pub c: C,
         }
-        impl ::pilota::thrift::Message for C {
+        impl ::pilota::thrift::Message for B {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "C" };
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "B" };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_faststr_field(3, (&self.a).clone())?;
+                __protocol.write_struct_field(2, &self.c, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -186,7 +186,7 @@ pub mod decode_error {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
-                let mut var_3 = None;
+                let mut var_2 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -202,10 +202,10 @@ pub mod decode_error {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
-                            Some(3)
-                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            Some(2)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
                             {
-                                var_3 = Some(__protocol.read_faststr()?);
+                                var_2 = Some(::pilota::thrift::Message::decode(__protocol)?);
                             }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
@@ -219,7 +219,7 @@ pub mod decode_error {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `C` field(#{}) failed, caused by: ",
+                            "decode struct `B` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -227,14 +227,14 @@ pub mod decode_error {
                 };
                 __protocol.read_struct_end()?;
 
-                let Some(var_3) = var_3 else {
+                let Some(var_2) = var_2 else {
                     return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field a is required".to_string(),
+                        "field c is required".to_string(),
                     ));
                 };
 
-                let data = Self { a: var_3 };
+                let data = Self { c: var_2 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -249,7 +249,7 @@ pub mod decode_error {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut var_3 = None;
+                    let mut var_2 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
@@ -263,11 +263,14 @@ pub mod decode_error {
                             }
                             __pilota_decoding_field_id = field_ident.id;
                             match field_ident.id {
-                                Some(3)
+                                Some(2)
                                     if field_ident.field_type
-                                        == ::pilota::thrift::TType::Binary =>
+                                        == ::pilota::thrift::TType::Struct =>
                                 {
-                                    var_3 = Some(__protocol.read_faststr().await?);
+                                    var_2 = Some(
+                                        <C as ::pilota::thrift::Message>::decode_async(__protocol)
+                                            .await?,
+                                    );
                                 }
                                 _ => {
                                     __protocol.skip(field_ident.field_type).await?;
@@ -282,7 +285,7 @@ pub mod decode_error {
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
                             err.prepend_msg(&format!(
-                                "decode struct `C` field(#{}) failed, caused by: ",
+                                "decode struct `B` field(#{}) failed, caused by: ",
                                 field_id
                             ));
                         }
@@ -290,16 +293,16 @@ pub mod decode_error {
                     };
                     __protocol.read_struct_end().await?;
 
-                    let Some(var_3) = var_3 else {
+                    let Some(var_2) = var_2 else {
                         return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field a is required".to_string(),
+                                "field c is required".to_string(),
                             ),
                         );
                     };
 
-                    let data = Self { a: var_3 };
+                    let data = Self { c: var_2 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -307,28 +310,28 @@ pub mod decode_error {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "C" })
-                    + __protocol.faststr_field_len(Some(3), &self.a)
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "B" })
+                    + __protocol.struct_field_len(Some(2), &self.c)
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
 
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct B {
-            pub c: C,
+        pub struct C {
+            pub a: ::pilota::FastStr,
         }
-        impl ::pilota::thrift::Message for B {
+        impl ::pilota::thrift::Message for C {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "B" };
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "C" };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(2, &self.c, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_faststr_field(3, (&self.a).clone())?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -340,7 +343,7 @@ pub mod decode_error {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
-                let mut var_2 = None;
+                let mut var_3 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -356,10 +359,10 @@ pub mod decode_error {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
-                            Some(2)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            Some(3)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
                             {
-                                var_2 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                                var_3 = Some(__protocol.read_faststr()?);
                             }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
@@ -373,7 +376,7 @@ pub mod decode_error {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `B` field(#{}) failed, caused by: ",
+                            "decode struct `C` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -381,14 +384,14 @@ pub mod decode_error {
                 };
                 __protocol.read_struct_end()?;
 
-                let Some(var_2) = var_2 else {
+                let Some(var_3) = var_3 else {
                     return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field c is required".to_string(),
+                        "field a is required".to_string(),
                     ));
                 };
 
-                let data = Self { c: var_2 };
+                let data = Self { a: var_3 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -403,7 +406,7 @@ pub mod decode_error {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut var_2 = None;
+                    let mut var_3 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
@@ -417,14 +420,11 @@ pub mod decode_error {
                             }
                             __pilota_decoding_field_id = field_ident.id;
                             match field_ident.id {
-                                Some(2)
+                                Some(3)
                                     if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
+                                        == ::pilota::thrift::TType::Binary =>
                                 {
-                                    var_2 = Some(
-                                        <C as ::pilota::thrift::Message>::decode_async(__protocol)
-                                            .await?,
-                                    );
+                                    var_3 = Some(__protocol.read_faststr().await?);
                                 }
                                 _ => {
                                     __protocol.skip(field_ident.field_type).await?;
@@ -439,7 +439,7 @@ pub mod decode_error {
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
                             err.prepend_msg(&format!(
-                                "decode struct `B` field(#{}) failed, caused by: ",
+                                "decode struct `C` field(#{}) failed, caused by: ",
                                 field_id
                             ));
                         }
@@ -447,16 +447,16 @@ pub mod decode_error {
                     };
                     __protocol.read_struct_end().await?;
 
-                    let Some(var_2) = var_2 else {
+                    let Some(var_3) = var_3 else {
                         return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field c is required".to_string(),
+                                "field a is required".to_string(),
                             ),
                         );
                     };
 
-                    let data = Self { c: var_2 };
+                    let data = Self { a: var_3 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -464,8 +464,8 @@ pub mod decode_error {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "B" })
-                    + __protocol.struct_field_len(Some(2), &self.c)
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "C" })
+                    + __protocol.faststr_field_len(Some(3), &self.a)
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
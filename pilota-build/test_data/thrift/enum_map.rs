@@ -60,17 +60,8 @@ pub mod enum_map {
                 __protocol.i32_len(*&**self)
             }
         }
-        pub const TYPE_A2: TypeA = TypeA(::pilota::FastStr::from_static_str("a2"));
-        pub const TYPE_B2: TypeB = TypeB(2i32);
-        pub const TYPE_A1: TypeA = TypeA(::pilota::FastStr::from_static_str("a1"));
         pub const TYPE_B1: TypeB = TypeB(1i32);
-        pub static TYPE_A_MAP: ::std::sync::LazyLock<::pilota::AHashMap<TypeB, TypeA>> =
-            ::std::sync::LazyLock::new(|| {
-                let mut map = ::pilota::AHashMap::with_capacity(2);
-                map.insert(TYPE_B1, TYPE_A1);
-                map.insert(TYPE_B2, TYPE_A2);
-                map
-            });
+        pub const TYPE_B2: TypeB = TypeB(2i32);
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
         pub struct TypeA(pub ::pilota::FastStr);
 
@@ -128,5 +119,14 @@ pub mod enum_map {
                 __protocol.faststr_len(&**self)
             }
         }
+        pub const TYPE_A1: TypeA = TypeA(::pilota::FastStr::from_static_str("a1"));
+        pub const TYPE_A2: TypeA = TypeA(::pilota::FastStr::from_static_str("a2"));
+        pub static TYPE_A_MAP: ::std::sync::LazyLock<::pilota::AHashMap<TypeB, TypeA>> =
+            ::std::sync::LazyLock::new(|| {
+                let mut map = ::pilota::AHashMap::with_capacity(2);
+                map.insert(TYPE_B1, TYPE_A1);
+                map.insert(TYPE_B2, TYPE_A2);
+                map
+            });
     }
 }
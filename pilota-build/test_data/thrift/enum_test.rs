@@ -15,11 +15,11 @@ pub mod enum_test {
                 self.0
             }
 
-            pub fn to_string(&self) -> ::std::string::String {
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
                 match self {
-                    Self(1) => ::std::string::String::from("A"),
-                    Self(16) => ::std::string::String::from("B"),
-                    Self(val) => val.to_string(),
+                    Self(1) => ::std::option::Option::Some("A"),
+                    Self(16) => ::std::option::Option::Some("B"),
+                    Self(_) => ::std::option::Option::None,
                 }
             }
 
@@ -44,6 +44,29 @@ pub mod enum_test {
             }
         }
 
+        impl ::std::fmt::Display for Index {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => ::std::fmt::Display::fmt(&self.inner(), f),
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for Index {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "A" | "a" | "first" => ::std::result::Result::Ok(Self::A),
+                    "B" => ::std::result::Result::Ok(Self::B),
+                    _ => s.parse::<i32>().map(Self::from).map_err(|_| {
+                        ::pilota::EnumConvertError::InvalidNum(s.to_string(), "Index")
+                    }),
+                }
+            }
+        }
+
         impl ::pilota::thrift::Message for Index {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
@@ -100,25 +123,71 @@ pub mod enum_test {
                 __protocol.i32_len(self.inner())
             }
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct TestTestEnumVarTypeNameConflictArgsSend {
-            pub req: Request,
+
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Err(i32);
+
+        impl Err {
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
+                match self {
+                    Self(_) => ::std::option::Option::None,
+                }
+            }
+
+            pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
+                match value {
+                    _ => None,
+                }
+            }
         }
-        impl ::pilota::thrift::Message for TestTestEnumVarTypeNameConflictArgsSend {
+
+        impl ::std::convert::From<i32> for Err {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::std::convert::From<Err> for i32 {
+            fn from(value: Err) -> i32 {
+                value.0
+            }
+        }
+
+        impl ::std::fmt::Display for Err {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => ::std::fmt::Display::fmt(&self.inner(), f),
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for Err {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    _ => s
+                        .parse::<i32>()
+                        .map(Self::from)
+                        .map_err(|_| ::pilota::EnumConvertError::InvalidNum(s.to_string(), "Err")),
+                }
+            }
+        }
+
+        impl ::pilota::thrift::Message for Err {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumVarTypeNameConflictArgsSend",
-                };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
+                __protocol.write_i32(self.inner())?;
                 ::std::result::Result::Ok(())
             }
 
@@ -127,54 +196,15 @@ pub mod enum_test {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut var_1 = None;
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
-                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsSend` field(#{}) failed, caused by: ", field_id));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let Some(var_1) = var_1 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
-                    ));
-                };
-
-                let data = Self { req: var_1 };
-                ::std::result::Result::Ok(data)
+                let value = __protocol.read_i32()?;
+                ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                    |err| {
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            format!("invalid enum value for Err, value: {}", value),
+                        )
+                    },
+                )?)
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -188,92 +218,89 @@ pub mod enum_test {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
-
-                    let mut __pilota_decoding_field_id = None;
-
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_1 = Some(
-                                        <Request as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    );
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
-
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsSend` field(#{}) failed, caused by: ", field_id));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
-
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
+                    let value = __protocol.read_i32().await?;
+                    ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                        |err| {
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self { req: var_1 };
-                    ::std::result::Result::Ok(data)
+                                format!("invalid enum value for Err, value: {}", value),
+                            )
+                        },
+                    )?)
                 })
             }
 
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumVarTypeNameConflictArgsSend",
-                }) + __protocol.struct_field_len(Some(1), &self.req)
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
+                __protocol.i32_len(self.inner())
             }
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct TestTestEnumArgsSend {
-            pub req: Ok,
+
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Ok(i32);
+
+        impl Ok {
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
+                match self {
+                    Self(_) => ::std::option::Option::None,
+                }
+            }
+
+            pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
+                match value {
+                    _ => None,
+                }
+            }
         }
-        impl ::pilota::thrift::Message for TestTestEnumArgsSend {
+
+        impl ::std::convert::From<i32> for Ok {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::std::convert::From<Ok> for i32 {
+            fn from(value: Ok) -> i32 {
+                value.0
+            }
+        }
+
+        impl ::std::fmt::Display for Ok {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => ::std::fmt::Display::fmt(&self.inner(), f),
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for Ok {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    _ => s
+                        .parse::<i32>()
+                        .map(Self::from)
+                        .map_err(|_| ::pilota::EnumConvertError::InvalidNum(s.to_string(), "Ok")),
+                }
+            }
+        }
+
+        impl ::pilota::thrift::Message for Ok {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumArgsSend",
-                };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_i32_field(1, (&self.req).inner())?;
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
+                __protocol.write_i32(self.inner())?;
                 ::std::result::Result::Ok(())
             }
 
@@ -282,56 +309,16 @@ pub mod enum_test {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut var_1 = None;
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
-                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `TestTestEnumArgsSend` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let Some(var_1) = var_1 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
-                    ));
-                };
-
-                let data = Self { req: var_1 };
-                ::std::result::Result::Ok(data)
-            }
+                let value = __protocol.read_i32()?;
+                ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                    |err| {
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            format!("invalid enum value for Ok, value: {}", value),
+                        )
+                    },
+                )?)
+            }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
                 __protocol: &'a mut T,
@@ -344,106 +331,90 @@ pub mod enum_test {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
-
-                    let mut __pilota_decoding_field_id = None;
-
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
-                                {
-                                    var_1 = Some(
-                                        <Ok as ::pilota::thrift::Message>::decode_async(__protocol)
-                                            .await?,
-                                    );
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
-
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTestEnumArgsSend` field(#{}) failed, caused by: ", field_id));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
-
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
+                    let value = __protocol.read_i32().await?;
+                    ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                        |err| {
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self { req: var_1 };
-                    ::std::result::Result::Ok(data)
+                                format!("invalid enum value for Ok, value: {}", value),
+                            )
+                        },
+                    )?)
                 })
             }
 
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumArgsSend",
-                }) + __protocol.i32_field_len(Some(1), (&self.req).inner())
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
+                __protocol.i32_len(self.inner())
             }
         }
 
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq, Copy)]
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
         #[repr(transparent)]
-        pub struct Ok(i32);
+        pub struct _Enum(i32);
+
+        impl _Enum {
+            pub const _1: Self = Self(1);
+            pub const _2: Self = Self(2);
 
-        impl Ok {
             pub fn inner(&self) -> i32 {
                 self.0
             }
 
-            pub fn to_string(&self) -> ::std::string::String {
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
                 match self {
-                    Self(val) => val.to_string(),
+                    Self(1) => ::std::option::Option::Some("_1"),
+                    Self(2) => ::std::option::Option::Some("_2"),
+                    Self(_) => ::std::option::Option::None,
                 }
             }
 
             pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
                 match value {
+                    1 => Some(Self::_1),
+                    2 => Some(Self::_2),
                     _ => None,
                 }
             }
         }
 
-        impl ::std::convert::From<i32> for Ok {
+        impl ::std::convert::From<i32> for _Enum {
             fn from(value: i32) -> Self {
                 Self(value)
             }
         }
 
-        impl ::std::convert::From<Ok> for i32 {
-            fn from(value: Ok) -> i32 {
+        impl ::std::convert::From<_Enum> for i32 {
+            fn from(value: _Enum) -> i32 {
                 value.0
             }
         }
 
-        impl ::pilota::thrift::Message for Ok {
+        impl ::std::fmt::Display for _Enum {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => ::std::fmt::Display::fmt(&self.inner(), f),
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for _Enum {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "_1" => ::std::result::Result::Ok(Self::_1),
+                    "_2" => ::std::result::Result::Ok(Self::_2),
+                    _ => s.parse::<i32>().map(Self::from).map_err(|_| {
+                        ::pilota::EnumConvertError::InvalidNum(s.to_string(), "_Enum")
+                    }),
+                }
+            }
+        }
+
+        impl ::pilota::thrift::Message for _Enum {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -464,7 +435,7 @@ pub mod enum_test {
                     |err| {
                         ::pilota::thrift::new_protocol_exception(
                             ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            format!("invalid enum value for Ok, value: {}", value),
+                            format!("invalid enum value for _Enum, value: {}", value),
                         )
                     },
                 )?)
@@ -486,7 +457,7 @@ pub mod enum_test {
                         |err| {
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                format!("invalid enum value for Ok, value: {}", value),
+                                format!("invalid enum value for _Enum, value: {}", value),
                             )
                         },
                     )?)
@@ -499,23 +470,32 @@ pub mod enum_test {
                 __protocol.i32_len(self.inner())
             }
         }
+
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct TestTestEnumVarTypeNameConflictArgsRecv {
-            pub req: Request,
+        pub struct Request {
+            pub Index: Index,
+
+            pub index: ::std::option::Option<Index>,
+
+            pub _enum: ::std::option::Option<_Enum>,
         }
-        impl ::pilota::thrift::Message for TestTestEnumVarTypeNameConflictArgsRecv {
+        impl ::pilota::thrift::Message for Request {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumVarTypeNameConflictArgsRecv",
-                };
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Request" };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_i32_field(1, (&self.Index).inner())?;
+                if let Some(value) = self.index.as_ref() {
+                    __protocol.write_i32_field(2, (value).inner())?;
+                }
+                if let Some(value) = self._enum.as_ref() {
+                    __protocol.write_i32_field(3, (value).inner())?;
+                }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -528,6 +508,8 @@ pub mod enum_test {
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
                 let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -543,11 +525,15 @@ pub mod enum_test {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
                                 var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
                             }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_2 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_3 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
                             }
@@ -559,7 +545,10 @@ pub mod enum_test {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_msg(&format!(
+                            "decode struct `Request` field(#{}) failed, caused by: ",
+                            field_id
+                        ));
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -568,11 +557,15 @@ pub mod enum_test {
                 let Some(var_1) = var_1 else {
                     return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
+                        "field Index is required".to_string(),
                     ));
                 };
 
-                let data = Self { req: var_1 };
+                let data = Self {
+                    Index: var_1,
+                    index: var_2,
+                    _enum: var_3,
+                };
                 ::std::result::Result::Ok(data)
             }
 
@@ -588,6 +581,8 @@ pub mod enum_test {
             > {
                 ::std::boxed::Box::pin(async move {
                     let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
@@ -602,11 +597,30 @@ pub mod enum_test {
                             __pilota_decoding_field_id = field_ident.id;
                             match field_ident.id {
                                 Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
                                 {
                                     var_1 = Some(
-                                        <Request as ::pilota::thrift::Message>::decode_async(
+                                        <Index as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    );
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_2 = Some(
+                                        <Index as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    );
+                                }
+                                Some(3)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_3 = Some(
+                                        <_Enum as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
                                         .await?,
@@ -624,7 +638,10 @@ pub mod enum_test {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_msg(&format!(
+                                "decode struct `Request` field(#{}) failed, caused by: ",
+                                field_id
+                            ));
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -634,12 +651,16 @@ pub mod enum_test {
                         return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
+                                "field Index is required".to_string(),
                             ),
                         );
                     };
 
-                    let data = Self { req: var_1 };
+                    let data = Self {
+                        Index: var_1,
+                        index: var_2,
+                        _enum: var_3,
+                    };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -647,24 +668,30 @@ pub mod enum_test {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumVarTypeNameConflictArgsRecv",
-                }) + __protocol.struct_field_len(Some(1), &self.req)
+                __protocol
+                    .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Request" })
+                    + __protocol.i32_field_len(Some(1), (&self.Index).inner())
+                    + self.index.as_ref().map_or(0, |value| {
+                        __protocol.i32_field_len(Some(2), (value).inner())
+                    })
+                    + self._enum.as_ref().map_or(0, |value| {
+                        __protocol.i32_field_len(Some(3), (value).inner())
+                    })
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for TestTestEnumVarTypeNameConflictResultSend {
+        impl ::std::default::Default for TestTestEnumResultRecv {
             fn default() -> Self {
-                TestTestEnumVarTypeNameConflictResultSend::Ok(::std::default::Default::default())
+                TestTestEnumResultRecv::Ok(::std::default::Default::default())
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum TestTestEnumVarTypeNameConflictResultSend {
+        pub enum TestTestEnumResultRecv {
             Ok(Err),
         }
 
-        impl ::pilota::thrift::Message for TestTestEnumVarTypeNameConflictResultSend {
+        impl ::pilota::thrift::Message for TestTestEnumResultRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -672,10 +699,10 @@ pub mod enum_test {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumVarTypeNameConflictResultSend",
+                    name: "TestTestEnumResultRecv",
                 })?;
                 match self {
-                    TestTestEnumVarTypeNameConflictResultSend::Ok(value) => {
+                    TestTestEnumResultRecv::Ok(value) => {
                         __protocol.write_i32_field(0, (value).inner())?;
                     }
                 }
@@ -704,9 +731,7 @@ pub mod enum_test {
                             if ret.is_none() {
                                 let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(TestTestEnumVarTypeNameConflictResultSend::Ok(
-                                    field_ident,
-                                ));
+                                ret = Some(TestTestEnumResultRecv::Ok(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -761,9 +786,7 @@ pub mod enum_test {
                                         )
                                         .await?;
 
-                                    ret = Some(TestTestEnumVarTypeNameConflictResultSend::Ok(
-                                        field_ident,
-                                    ));
+                                    ret = Some(TestTestEnumResultRecv::Ok(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -795,168 +818,15 @@ pub mod enum_test {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumVarTypeNameConflictResultSend",
+                    name: "TestTestEnumResultRecv",
                 }) + match self {
-                    TestTestEnumVarTypeNameConflictResultSend::Ok(value) => {
+                    TestTestEnumResultRecv::Ok(value) => {
                         __protocol.i32_field_len(Some(0), (value).inner())
                     }
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct TestTestEnumArgsRecv {
-            pub req: Ok,
-        }
-        impl ::pilota::thrift::Message for TestTestEnumArgsRecv {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumArgsRecv",
-                };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_i32_field(1, (&self.req).inner())?;
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
-
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut var_1 = None;
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
-                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `TestTestEnumArgsRecv` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let Some(var_1) = var_1 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
-                    ));
-                };
-
-                let data = Self { req: var_1 };
-                ::std::result::Result::Ok(data)
-            }
-
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
-
-                    let mut __pilota_decoding_field_id = None;
-
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
-                                {
-                                    var_1 = Some(
-                                        <Ok as ::pilota::thrift::Message>::decode_async(__protocol)
-                                            .await?,
-                                    );
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
-
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTestEnumArgsRecv` field(#{}) failed, caused by: ", field_id));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
-
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self { req: var_1 };
-                    ::std::result::Result::Ok(data)
-                })
-            }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumArgsRecv",
-                }) + __protocol.i32_field_len(Some(1), (&self.req).inner())
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
         impl ::std::default::Default for TestTestEnumResultSend {
             fn default() -> Self {
                 TestTestEnumResultSend::Ok(::std::default::Default::default())
@@ -1103,32 +973,23 @@ pub mod enum_test {
                     + __protocol.struct_end_len()
             }
         }
-
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct Request {
-            pub Index: Index,
-
-            pub index: ::std::option::Option<Index>,
-
-            pub _enum: ::std::option::Option<_Enum>,
+        pub struct TestTestEnumArgsSend {
+            pub req: Ok,
         }
-        impl ::pilota::thrift::Message for Request {
+        impl ::pilota::thrift::Message for TestTestEnumArgsSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Request" };
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "TestTestEnumArgsSend",
+                };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_i32_field(1, (&self.Index).inner())?;
-                if let Some(value) = self.index.as_ref() {
-                    __protocol.write_i32_field(2, (value).inner())?;
-                }
-                if let Some(value) = self._enum.as_ref() {
-                    __protocol.write_i32_field(3, (value).inner())?;
-                }
+                __protocol.write_i32_field(1, (&self.req).inner())?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -1141,8 +1002,6 @@ pub mod enum_test {
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
                 let mut var_1 = None;
-                let mut var_2 = None;
-                let mut var_3 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -1161,12 +1020,6 @@ pub mod enum_test {
                             Some(1) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
                                 var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
                             }
-                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
-                                var_2 = Some(::pilota::thrift::Message::decode(__protocol)?);
-                            }
-                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
-                                var_3 = Some(::pilota::thrift::Message::decode(__protocol)?);
-                            }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
                             }
@@ -1179,7 +1032,7 @@ pub mod enum_test {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `Request` field(#{}) failed, caused by: ",
+                            "decode struct `TestTestEnumArgsSend` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -1190,15 +1043,11 @@ pub mod enum_test {
                 let Some(var_1) = var_1 else {
                     return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field Index is required".to_string(),
+                        "field req is required".to_string(),
                     ));
                 };
 
-                let data = Self {
-                    Index: var_1,
-                    index: var_2,
-                    _enum: var_3,
-                };
+                let data = Self { req: var_1 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -1214,8 +1063,6 @@ pub mod enum_test {
             > {
                 ::std::boxed::Box::pin(async move {
                     let mut var_1 = None;
-                    let mut var_2 = None;
-                    let mut var_3 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
@@ -1233,30 +1080,8 @@ pub mod enum_test {
                                     if field_ident.field_type == ::pilota::thrift::TType::I32 =>
                                 {
                                     var_1 = Some(
-                                        <Index as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    );
-                                }
-                                Some(2)
-                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
-                                {
-                                    var_2 = Some(
-                                        <Index as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    );
-                                }
-                                Some(3)
-                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
-                                {
-                                    var_3 = Some(
-                                        <_Enum as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
+                                        <Ok as ::pilota::thrift::Message>::decode_async(__protocol)
+                                            .await?,
                                     );
                                 }
                                 _ => {
@@ -1271,10 +1096,7 @@ pub mod enum_test {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Request` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_msg(&format!("decode struct `TestTestEnumArgsSend` field(#{}) failed, caused by: ", field_id));
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -1284,16 +1106,12 @@ pub mod enum_test {
                         return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field Index is required".to_string(),
+                                "field req is required".to_string(),
                             ),
                         );
                     };
 
-                    let data = Self {
-                        Index: var_1,
-                        index: var_2,
-                        _enum: var_3,
-                    };
+                    let data = Self { req: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -1301,69 +1119,32 @@ pub mod enum_test {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol
-                    .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Request" })
-                    + __protocol.i32_field_len(Some(1), (&self.Index).inner())
-                    + self.index.as_ref().map_or(0, |value| {
-                        __protocol.i32_field_len(Some(2), (value).inner())
-                    })
-                    + self._enum.as_ref().map_or(0, |value| {
-                        __protocol.i32_field_len(Some(3), (value).inner())
-                    })
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTestEnumArgsSend",
+                }) + __protocol.i32_field_len(Some(1), (&self.req).inner())
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
-        #[repr(transparent)]
-        pub struct _Enum(i32);
-
-        impl _Enum {
-            pub const _1: Self = Self(1);
-            pub const _2: Self = Self(2);
-
-            pub fn inner(&self) -> i32 {
-                self.0
-            }
-
-            pub fn to_string(&self) -> ::std::string::String {
-                match self {
-                    Self(1) => ::std::string::String::from("_1"),
-                    Self(2) => ::std::string::String::from("_2"),
-                    Self(val) => val.to_string(),
-                }
-            }
-
-            pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
-                match value {
-                    1 => Some(Self::_1),
-                    2 => Some(Self::_2),
-                    _ => None,
-                }
-            }
-        }
-
-        impl ::std::convert::From<i32> for _Enum {
-            fn from(value: i32) -> Self {
-                Self(value)
-            }
-        }
-
-        impl ::std::convert::From<_Enum> for i32 {
-            fn from(value: _Enum) -> i32 {
-                value.0
-            }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct TestTestEnumArgsRecv {
+            pub req: Ok,
         }
-
-        impl ::pilota::thrift::Message for _Enum {
+        impl ::pilota::thrift::Message for TestTestEnumArgsRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_i32(self.inner())?;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "TestTestEnumArgsRecv",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i32_field(1, (&self.req).inner())?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
             }
 
@@ -1372,15 +1153,55 @@ pub mod enum_test {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let value = __protocol.read_i32()?;
-                ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
-                    |err| {
-                        ::pilota::thrift::new_protocol_exception(
-                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            format!("invalid enum value for _Enum, value: {}", value),
-                        )
-                    },
-                )?)
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(&format!(
+                            "decode struct `TestTestEnumArgsRecv` field(#{}) failed, caused by: ",
+                            field_id
+                        ));
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field req is required".to_string(),
+                    ));
+                };
+
+                let data = Self { req: var_1 };
+                ::std::result::Result::Ok(data)
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -1394,27 +1215,70 @@ pub mod enum_test {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let value = __protocol.read_i32().await?;
-                    ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
-                        |err| {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_1 = Some(
+                                        <Ok as ::pilota::thrift::Message>::decode_async(__protocol)
+                                            .await?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!("decode struct `TestTestEnumArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                format!("invalid enum value for _Enum, value: {}", value),
-                            )
-                        },
-                    )?)
+                                "field req is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { req: var_1 };
+                    ::std::result::Result::Ok(data)
                 })
             }
 
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.i32_len(self.inner())
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTestEnumArgsRecv",
+                }) + __protocol.i32_field_len(Some(1), (&self.req).inner())
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
             }
         }
-
-        pub trait Test {}
-
         impl ::std::default::Default for TestTestEnumVarTypeNameConflictResultRecv {
             fn default() -> Self {
                 TestTestEnumVarTypeNameConflictResultRecv::Ok(::std::default::Default::default())
@@ -1565,17 +1429,17 @@ pub mod enum_test {
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for TestTestEnumResultRecv {
+        impl ::std::default::Default for TestTestEnumVarTypeNameConflictResultSend {
             fn default() -> Self {
-                TestTestEnumResultRecv::Ok(::std::default::Default::default())
+                TestTestEnumVarTypeNameConflictResultSend::Ok(::std::default::Default::default())
             }
         }
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum TestTestEnumResultRecv {
+        pub enum TestTestEnumVarTypeNameConflictResultSend {
             Ok(Err),
         }
 
-        impl ::pilota::thrift::Message for TestTestEnumResultRecv {
+        impl ::pilota::thrift::Message for TestTestEnumVarTypeNameConflictResultSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -1583,10 +1447,10 @@ pub mod enum_test {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumResultRecv",
+                    name: "TestTestEnumVarTypeNameConflictResultSend",
                 })?;
                 match self {
-                    TestTestEnumResultRecv::Ok(value) => {
+                    TestTestEnumVarTypeNameConflictResultSend::Ok(value) => {
                         __protocol.write_i32_field(0, (value).inner())?;
                     }
                 }
@@ -1615,7 +1479,9 @@ pub mod enum_test {
                             if ret.is_none() {
                                 let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(TestTestEnumResultRecv::Ok(field_ident));
+                                ret = Some(TestTestEnumVarTypeNameConflictResultSend::Ok(
+                                    field_ident,
+                                ));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -1670,7 +1536,9 @@ pub mod enum_test {
                                         )
                                         .await?;
 
-                                    ret = Some(TestTestEnumResultRecv::Ok(field_ident));
+                                    ret = Some(TestTestEnumVarTypeNameConflictResultSend::Ok(
+                                        field_ident,
+                                    ));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -1702,58 +1570,189 @@ pub mod enum_test {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestEnumResultRecv",
+                    name: "TestTestEnumVarTypeNameConflictResultSend",
                 }) + match self {
-                    TestTestEnumResultRecv::Ok(value) => {
+                    TestTestEnumVarTypeNameConflictResultSend::Ok(value) => {
                         __protocol.i32_field_len(Some(0), (value).inner())
                     }
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct TestTestEnumVarTypeNameConflictArgsSend {
+            pub req: Request,
+        }
+        impl ::pilota::thrift::Message for TestTestEnumVarTypeNameConflictArgsSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "TestTestEnumVarTypeNameConflictArgsSend",
+                };
 
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq, Copy)]
-        #[repr(transparent)]
-        pub struct Err(i32);
-
-        impl Err {
-            pub fn inner(&self) -> i32 {
-                self.0
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
             }
 
-            pub fn to_string(&self) -> ::std::string::String {
-                match self {
-                    Self(val) => val.to_string(),
-                }
-            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
-            pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
-                match value {
-                    _ => None,
-                }
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsSend` field(#{}) failed, caused by: ", field_id));
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field req is required".to_string(),
+                    ));
+                };
+
+                let data = Self { req: var_1 };
+                ::std::result::Result::Ok(data)
             }
-        }
 
-        impl ::std::convert::From<i32> for Err {
-            fn from(value: i32) -> Self {
-                Self(value)
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(
+                                        <Request as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsSend` field(#{}) failed, caused by: ", field_id));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field req is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { req: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
             }
-        }
 
-        impl ::std::convert::From<Err> for i32 {
-            fn from(value: Err) -> i32 {
-                value.0
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTestEnumVarTypeNameConflictArgsSend",
+                }) + __protocol.struct_field_len(Some(1), &self.req)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
             }
         }
-
-        impl ::pilota::thrift::Message for Err {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct TestTestEnumVarTypeNameConflictArgsRecv {
+            pub req: Request,
+        }
+        impl ::pilota::thrift::Message for TestTestEnumVarTypeNameConflictArgsRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_i32(self.inner())?;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "TestTestEnumVarTypeNameConflictArgsRecv",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
             }
 
@@ -1762,15 +1761,54 @@ pub mod enum_test {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let value = __protocol.read_i32()?;
-                ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
-                    |err| {
-                        ::pilota::thrift::new_protocol_exception(
-                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            format!("invalid enum value for Err, value: {}", value),
-                        )
-                    },
-                )?)
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsRecv` field(#{}) failed, caused by: ", field_id));
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field req is required".to_string(),
+                    ));
+                };
+
+                let data = Self { req: var_1 };
+                ::std::result::Result::Ok(data)
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -1784,23 +1822,74 @@ pub mod enum_test {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let value = __protocol.read_i32().await?;
-                    ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
-                        |err| {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(
+                                        <Request as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                format!("invalid enum value for Err, value: {}", value),
-                            )
-                        },
-                    )?)
+                                "field req is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { req: var_1 };
+                    ::std::result::Result::Ok(data)
                 })
             }
 
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.i32_len(self.inner())
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTestEnumVarTypeNameConflictArgsRecv",
+                }) + __protocol.struct_field_len(Some(1), &self.req)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
             }
         }
+
+        pub trait Test {}
     }
 }
@@ -1,5 +1,5 @@
 enum Index {
-    A = 0x01,
+    A = 0x01 (pilota.alias = "a, first"),
     B = 0x10,
 }
 
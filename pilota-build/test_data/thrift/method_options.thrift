@@ -0,0 +1,13 @@
+struct Req {
+    1: required string message,
+}
+
+struct Resp {
+    1: required string message,
+}
+
+service Echo {
+    Resp Ping(1: Req req) (timeout.ms = "500", retry.max = "2", idempotent = "true"),
+    Resp Post(1: Req req) (api.post = "/echo", pilota.name = "post_message"),
+    Resp Plain(1: Req req),
+}
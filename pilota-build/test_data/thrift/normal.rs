@@ -143,22 +143,23 @@ pub mod normal {
             }
         }
 
-        pub trait Test {}
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct TestTest123ArgsSend {}
-        impl ::pilota::thrift::Message for TestTest123ArgsSend {
+        pub struct B {
+            pub a: ::std::option::Option<A>,
+        }
+        impl ::pilota::thrift::Message for B {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "TestTest123ArgsSend",
-                };
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "B" };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-
+                if let Some(value) = self.a.as_ref() {
+                    __protocol.write_struct_field(2, value, ::pilota::thrift::TType::Struct)?;
+                }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -170,6 +171,8 @@ pub mod normal {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
+                let mut var_2 = None;
+
                 let mut __pilota_decoding_field_id = None;
 
                 __protocol.read_struct_begin()?;
@@ -184,6 +187,11 @@ pub mod normal {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
+                            Some(2)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_2 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
                             }
@@ -196,7 +204,7 @@ pub mod normal {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `TestTest123ArgsSend` field(#{}) failed, caused by: ",
+                            "decode struct `B` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -204,7 +212,7 @@ pub mod normal {
                 };
                 __protocol.read_struct_end()?;
 
-                let data = Self {};
+                let data = Self { a: var_2 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -219,6 +227,8 @@ pub mod normal {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
+                    let mut var_2 = None;
+
                     let mut __pilota_decoding_field_id = None;
 
                     __protocol.read_struct_begin().await?;
@@ -231,6 +241,15 @@ pub mod normal {
                             }
                             __pilota_decoding_field_id = field_ident.id;
                             match field_ident.id {
+                                Some(2)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_2 = Some(
+                                        <A as ::pilota::thrift::Message>::decode_async(__protocol)
+                                            .await?,
+                                    );
+                                }
                                 _ => {
                                     __protocol.skip(field_ident.field_type).await?;
                                 }
@@ -243,13 +262,16 @@ pub mod normal {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTest123ArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_msg(&format!(
+                                "decode struct `B` field(#{}) failed, caused by: ",
+                                field_id
+                            ));
                         }
                         return ::std::result::Result::Err(err);
                     };
                     __protocol.read_struct_end().await?;
 
-                    let data = Self {};
+                    let data = Self { a: var_2 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -257,75 +279,32 @@ pub mod normal {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTest123ArgsSend",
-                }) + __protocol.field_stop_len()
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "B" })
+                    + self
+                        .a
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(2), value))
+                    + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
 
-        #[derive(Debug, Default, Clone, PartialEq)]
-        pub struct ObjReq {
-            pub msg: Message,
-
-            pub msg_map: ::pilota::AHashMap<Message, SubMessage>,
-
-            pub sub_msgs: ::std::vec::Vec<SubMessage>,
-
-            pub msg_set: ::std::option::Option<::pilota::AHashSet<Message>>,
-
-            pub flag_msg: ::pilota::FastStr,
-
-            pub mock_cost: ::std::option::Option<::pilota::FastStr>,
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct SubMessage {
+            pub value: ::std::option::Option<::pilota::FastStr>,
         }
-        impl ::pilota::thrift::Message for ObjReq {
+        impl ::pilota::thrift::Message for SubMessage {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "ObjReq" };
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "SubMessage" };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.msg, ::pilota::thrift::TType::Struct)?;
-                __protocol.write_map_field(
-                    2,
-                    ::pilota::thrift::TType::Struct,
-                    ::pilota::thrift::TType::Struct,
-                    &&self.msg_map,
-                    |__protocol, key| {
-                        __protocol.write_struct(key)?;
-                        ::std::result::Result::Ok(())
-                    },
-                    |__protocol, val| {
-                        __protocol.write_struct(val)?;
-                        ::std::result::Result::Ok(())
-                    },
-                )?;
-                __protocol.write_list_field(
-                    3,
-                    ::pilota::thrift::TType::Struct,
-                    &&self.sub_msgs,
-                    |__protocol, val| {
-                        __protocol.write_struct(val)?;
-                        ::std::result::Result::Ok(())
-                    },
-                )?;
-                if let Some(value) = self.msg_set.as_ref() {
-                    __protocol.write_set_field(
-                        4,
-                        ::pilota::thrift::TType::Struct,
-                        &value,
-                        |__protocol, val| {
-                            __protocol.write_struct(val)?;
-                            ::std::result::Result::Ok(())
-                        },
-                    )?;
-                }
-                __protocol.write_faststr_field(5, (&self.flag_msg).clone())?;
-                if let Some(value) = self.mock_cost.as_ref() {
-                    __protocol.write_faststr_field(6, (value).clone())?;
+                if let Some(value) = self.value.as_ref() {
+                    __protocol.write_faststr_field(2, (value).clone())?;
                 }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
@@ -338,12 +317,7 @@ pub mod normal {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
-                let mut var_1 = None;
                 let mut var_2 = None;
-                let mut var_3 = None;
-                let mut var_4 = None;
-                let mut var_5 = None;
-                let mut var_6 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -359,61 +333,10 @@ pub mod normal {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
-                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
-                            }
-                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::Map => {
-                                var_2 = Some({
-                                    let map_ident = __protocol.read_map_begin()?;
-                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            ::pilota::thrift::Message::decode(__protocol)?,
-                                            ::pilota::thrift::Message::decode(__protocol)?,
-                                        );
-                                    }
-                                    __protocol.read_map_end()?;
-                                    val
-                                });
-                            }
-                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::List => {
-                                var_3 = Some(unsafe {
-                                    let list_ident = __protocol.read_list_begin()?;
-                                    let mut val: ::std::vec::Vec<SubMessage> =
-                                        ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(::pilota::thrift::Message::decode(__protocol)?);
-                                    }
-                                    val.set_len(list_ident.size);
-                                    __protocol.read_list_end()?;
-                                    val
-                                });
-                            }
-                            Some(4) if field_ident.field_type == ::pilota::thrift::TType::Set => {
-                                var_4 = Some({
-                                    let list_ident = __protocol.read_set_begin()?;
-                                    let mut val =
-                                        ::pilota::AHashSet::with_capacity(list_ident.size);
-                                    for _ in 0..list_ident.size {
-                                        val.insert(::pilota::thrift::Message::decode(__protocol)?);
-                                    }
-                                    __protocol.read_set_end()?;
-                                    val
-                                });
-                            }
-                            Some(5)
-                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
-                            {
-                                var_5 = Some(__protocol.read_faststr()?);
-                            }
-                            Some(6)
+                            Some(2)
                                 if field_ident.field_type == ::pilota::thrift::TType::Binary =>
                             {
-                                var_6 = Some(__protocol.read_faststr()?);
+                                var_2 = Some(__protocol.read_faststr()?);
                             }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
@@ -427,7 +350,7 @@ pub mod normal {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `ObjReq` field(#{}) failed, caused by: ",
+                            "decode struct `SubMessage` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -435,39 +358,7 @@ pub mod normal {
                 };
                 __protocol.read_struct_end()?;
 
-                let Some(var_1) = var_1 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field msg is required".to_string(),
-                    ));
-                };
-                let Some(var_2) = var_2 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field msg_map is required".to_string(),
-                    ));
-                };
-                let Some(var_3) = var_3 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field sub_msgs is required".to_string(),
-                    ));
-                };
-                let Some(var_5) = var_5 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field flag_msg is required".to_string(),
-                    ));
-                };
-
-                let data = Self {
-                    msg: var_1,
-                    msg_map: var_2,
-                    sub_msgs: var_3,
-                    msg_set: var_4,
-                    flag_msg: var_5,
-                    mock_cost: var_6,
-                };
+                let data = Self { value: var_2 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -482,187 +373,100 @@ pub mod normal {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
                     let mut var_2 = None;
-                    let mut var_3 = None;
-                    let mut var_4 = None;
-                    let mut var_5 = None;
-                    let mut var_6 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
                     __protocol.read_struct_begin().await?;
                     if let ::std::result::Result::Err(mut err) = async {
-                    loop {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(2)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_2 = Some(__protocol.read_faststr().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
 
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!(
+                                "decode struct `SubMessage` field(#{}) failed, caused by: ",
+                                field_id
+                            ));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
 
-                let field_ident = __protocol.read_field_begin().await?;
-                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                    let data = Self { value: var_2 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
 
-                    break;
-                } else {
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "SubMessage" })
+                    + self
+                        .value
+                        .as_ref()
+                        .map_or(0, |value| __protocol.faststr_field_len(Some(2), value))
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
 
-                }
-                __pilota_decoding_field_id = field_ident.id;
-                match field_ident.id {
-                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
-                    var_1 = Some(<Message as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-
-                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
-                    var_2 = Some({
-                        let map_ident = __protocol.read_map_begin().await?;
-                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                        for _ in 0..map_ident.size {
-                            val.insert(<Message as ::pilota::thrift::Message>::decode_async(__protocol).await?, <SubMessage as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                        }
-                        __protocol.read_map_end().await?;
-                        val
-                    });
-
-                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::List  => {
-                    var_3 = Some({
-                            let list_ident = __protocol.read_list_begin().await?;
-                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
-                                val.push(<SubMessage as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                            };
-                            __protocol.read_list_end().await?;
-                            val
-                        });
-
-                },Some(4) if field_ident.field_type == ::pilota::thrift::TType::Set  => {
-                    var_4 = Some({let list_ident = __protocol.read_set_begin().await?;
-                    let mut val = ::pilota::AHashSet::with_capacity(list_ident.size);
-                    for _ in 0..list_ident.size {
-                        val.insert(<Message as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                    };
-                    __protocol.read_set_end().await?;
-                    val});
-
-                },Some(5) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
-                    var_5 = Some(__protocol.read_faststr().await?);
-
-                },Some(6) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
-                    var_6 = Some(__protocol.read_faststr().await?);
-
-                },
-                    _ => {
-                        __protocol.skip(field_ident.field_type).await?;
-
-                    },
-                }
-
-                __protocol.read_field_end().await?;
-
-
-            };
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                }.await {
-                if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `ObjReq` field(#{}) failed, caused by: ", field_id));
-                }
-                return ::std::result::Result::Err(err);
-            };
-                    __protocol.read_struct_end().await?;
-
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field msg is required".to_string(),
-                            ),
-                        );
-                    };
-                    let Some(var_2) = var_2 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field msg_map is required".to_string(),
-                            ),
-                        );
-                    };
-                    let Some(var_3) = var_3 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field sub_msgs is required".to_string(),
-                            ),
-                        );
-                    };
-                    let Some(var_5) = var_5 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field flag_msg is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self {
-                        msg: var_1,
-                        msg_map: var_2,
-                        sub_msgs: var_3,
-                        msg_set: var_4,
-                        flag_msg: var_5,
-                        mock_cost: var_6,
-                    };
-                    ::std::result::Result::Ok(data)
-                })
-            }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Message {
+            pub uid: ::std::option::Option<[u8; 16]>,
 
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "ObjReq" })
-                    + __protocol.struct_field_len(Some(1), &self.msg)
-                    + __protocol.map_field_len(
-                        Some(2),
-                        ::pilota::thrift::TType::Struct,
-                        ::pilota::thrift::TType::Struct,
-                        &self.msg_map,
-                        |__protocol, key| __protocol.struct_len(key),
-                        |__protocol, val| __protocol.struct_len(val),
-                    )
-                    + __protocol.list_field_len(
-                        Some(3),
-                        ::pilota::thrift::TType::Struct,
-                        &self.sub_msgs,
-                        |__protocol, el| __protocol.struct_len(el),
-                    )
-                    + self.msg_set.as_ref().map_or(0, |value| {
-                        __protocol.set_field_len(
-                            Some(4),
-                            ::pilota::thrift::TType::Struct,
-                            value,
-                            |__protocol, el| __protocol.struct_len(el),
-                        )
-                    })
-                    + __protocol.faststr_field_len(Some(5), &self.flag_msg)
-                    + self
-                        .mock_cost
-                        .as_ref()
-                        .map_or(0, |value| __protocol.faststr_field_len(Some(6), value))
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
+            pub value: ::std::option::Option<::pilota::FastStr>,
 
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct B {
-            pub a: ::std::option::Option<A>,
+            pub sub_messages: ::std::option::Option<::std::vec::Vec<SubMessage>>,
         }
-        impl ::pilota::thrift::Message for B {
+        impl ::pilota::thrift::Message for Message {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "B" };
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Message" };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                if let Some(value) = self.a.as_ref() {
-                    __protocol.write_struct_field(2, value, ::pilota::thrift::TType::Struct)?;
+                if let Some(value) = self.uid.as_ref() {
+                    __protocol.write_uuid_field(1, *value)?;
+                }
+                if let Some(value) = self.value.as_ref() {
+                    __protocol.write_faststr_field(2, (value).clone())?;
+                }
+                if let Some(value) = self.sub_messages.as_ref() {
+                    __protocol.write_list_field(
+                        3,
+                        ::pilota::thrift::TType::Struct,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_struct(val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
                 }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
@@ -675,7 +479,9 @@ pub mod normal {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
+                let mut var_1 = None;
                 let mut var_2 = None;
+                let mut var_3 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -691,10 +497,24 @@ pub mod normal {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::Uuid => {
+                                var_1 = Some(__protocol.read_uuid()?);
+                            }
                             Some(2)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
                             {
-                                var_2 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                                var_2 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_3 = Some({
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(__protocol)?);
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
                             }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
@@ -708,7 +528,7 @@ pub mod normal {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `B` field(#{}) failed, caused by: ",
+                            "decode struct `Message` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -716,7 +536,11 @@ pub mod normal {
                 };
                 __protocol.read_struct_end()?;
 
-                let data = Self { a: var_2 };
+                let data = Self {
+                    uid: var_1,
+                    value: var_2,
+                    sub_messages: var_3,
+                };
                 ::std::result::Result::Ok(data)
             }
 
@@ -731,84 +555,165 @@ pub mod normal {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
                     let mut var_2 = None;
+                    let mut var_3 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
                     __protocol.read_struct_begin().await?;
                     if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(2)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_2 = Some(
-                                        <A as ::pilota::thrift::Message>::decode_async(__protocol)
-                                            .await?,
-                                    );
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
+                    loop {
 
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `B` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
 
-                    let data = Self { a: var_2 };
-                    ::std::result::Result::Ok(data)
-                })
-            }
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
 
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "B" })
-                    + self
-                        .a
-                        .as_ref()
-                        .map_or(0, |value| __protocol.struct_field_len(Some(2), value))
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
-        #[derive(Debug, Default, Clone, PartialEq)]
-        pub struct TestTestExceptionArgsSend {
-            pub req: ObjReq,
-        }
-        impl ::pilota::thrift::Message for TestTestExceptionArgsSend {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                    break;
+                } else {
+
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Uuid  => {
+
+                    var_1 = Some(__protocol.read_uuid().await?);
+
+                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
+                    var_2 = Some(__protocol.read_faststr().await?);
+
+                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+
+                    var_3 = Some({
+                        let list_ident = __protocol.read_list_begin().await?;
+                        let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                        for _ in 0..list_ident.size {
+                            val.push(<SubMessage as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                        };
+                        __protocol.read_list_end().await?;
+                        val
+                    });
+
+                },
+                    _ => {
+
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_msg(&format!("decode struct `Message` field(#{}) failed, caused by: ", field_id));
+                }
+                return ::std::result::Result::Err(err);
+            };
+                    __protocol.read_struct_end().await?;
+
+                    let data = Self {
+                        uid: var_1,
+                        value: var_2,
+                        sub_messages: var_3,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Message" })
+                    + self
+                        .uid
+                        .as_ref()
+                        .map_or(0, |value| __protocol.uuid_field_len(Some(1), *value))
+                    + self
+                        .value
+                        .as_ref()
+                        .map_or(0, |value| __protocol.faststr_field_len(Some(2), value))
+                    + self.sub_messages.as_ref().map_or(0, |value| {
+                        __protocol.list_field_len(
+                            Some(3),
+                            ::pilota::thrift::TType::Struct,
+                            value,
+                            |__protocol, el| __protocol.struct_len(el),
+                        )
+                    })
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct ObjReq {
+            pub msg: Message,
+
+            pub msg_map: ::pilota::AHashMap<Message, SubMessage>,
+
+            pub sub_msgs: ::std::vec::Vec<SubMessage>,
+
+            pub msg_set: ::std::option::Option<::pilota::AHashSet<Message>>,
+
+            pub flag_msg: ::pilota::FastStr,
+
+            pub mock_cost: ::std::option::Option<::pilota::FastStr>,
+        }
+        impl ::pilota::thrift::Message for ObjReq {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "TestTestExceptionArgsSend",
-                };
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "ObjReq" };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_struct_field(1, &self.msg, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_map_field(
+                    2,
+                    ::pilota::thrift::TType::Struct,
+                    ::pilota::thrift::TType::Struct,
+                    &&self.msg_map,
+                    |__protocol, key| {
+                        __protocol.write_struct(key)?;
+                        ::std::result::Result::Ok(())
+                    },
+                    |__protocol, val| {
+                        __protocol.write_struct(val)?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                __protocol.write_list_field(
+                    3,
+                    ::pilota::thrift::TType::Struct,
+                    &&self.sub_msgs,
+                    |__protocol, val| {
+                        __protocol.write_struct(val)?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                if let Some(value) = self.msg_set.as_ref() {
+                    __protocol.write_set_field(
+                        4,
+                        ::pilota::thrift::TType::Struct,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_struct(val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                __protocol.write_faststr_field(5, (&self.flag_msg).clone())?;
+                if let Some(value) = self.mock_cost.as_ref() {
+                    __protocol.write_faststr_field(6, (value).clone())?;
+                }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -821,6 +726,11 @@ pub mod normal {
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
                 let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut var_5 = None;
+                let mut var_6 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -841,6 +751,53 @@ pub mod normal {
                             {
                                 var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
                             }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::Map => {
+                                var_2 = Some({
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        val.insert(
+                                            ::pilota::thrift::Message::decode(__protocol)?,
+                                            ::pilota::thrift::Message::decode(__protocol)?,
+                                        );
+                                    }
+                                    __protocol.read_map_end()?;
+                                    val
+                                });
+                            }
+                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_3 = Some({
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(__protocol)?);
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(4) if field_ident.field_type == ::pilota::thrift::TType::Set => {
+                                var_4 = Some({
+                                    let list_ident = __protocol.read_set_begin()?;
+                                    let mut val =
+                                        ::pilota::AHashSet::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.insert(::pilota::thrift::Message::decode(__protocol)?);
+                                    }
+                                    __protocol.read_set_end()?;
+                                    val
+                                });
+                            }
+                            Some(5)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_5 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(6)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_6 = Some(__protocol.read_faststr()?);
+                            }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
                             }
@@ -852,7 +809,10 @@ pub mod normal {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `TestTestExceptionArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_msg(&format!(
+                            "decode struct `ObjReq` field(#{}) failed, caused by: ",
+                            field_id
+                        ));
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -861,11 +821,36 @@ pub mod normal {
                 let Some(var_1) = var_1 else {
                     return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
+                        "field msg is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field msg_map is required".to_string(),
+                    ));
+                };
+                let Some(var_3) = var_3 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field sub_msgs is required".to_string(),
+                    ));
+                };
+                let Some(var_5) = var_5 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field flag_msg is required".to_string(),
                     ));
                 };
 
-                let data = Self { req: var_1 };
+                let data = Self {
+                    msg: var_1,
+                    msg_map: var_2,
+                    sub_msgs: var_3,
+                    msg_set: var_4,
+                    flag_msg: var_5,
+                    mock_cost: var_6,
+                };
                 ::std::result::Result::Ok(data)
             }
 
@@ -881,423 +866,136 @@ pub mod normal {
             > {
                 ::std::boxed::Box::pin(async move {
                     let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+                    let mut var_6 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
                     __protocol.read_struct_begin().await?;
                     if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_1 = Some(
-                                        <ObjReq as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    );
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
+                    loop {
 
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTestExceptionArgsSend` field(#{}) failed, caused by: ", field_id));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
 
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self { req: var_1 };
-                    ::std::result::Result::Ok(data)
-                })
-            }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestExceptionArgsSend",
-                }) + __protocol.struct_field_len(Some(1), &self.req)
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct TestTest123ArgsRecv {}
-        impl ::pilota::thrift::Message for TestTest123ArgsRecv {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "TestTest123ArgsRecv",
-                };
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
 
-                __protocol.write_struct_begin(&struct_ident)?;
+                    break;
+                } else {
 
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
 
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                    var_1 = Some(<Message as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
-                let mut __pilota_decoding_field_id = None;
+                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
 
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
+                    var_2 = Some({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        for _ in 0..map_ident.size {
+                            val.insert(<Message as ::pilota::thrift::Message>::decode_async(__protocol).await?, <SubMessage as ::pilota::thrift::Message>::decode_async(__protocol).await?);
                         }
+                        __protocol.read_map_end().await?;
+                        val
+                    });
 
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `TestTest123ArgsRecv` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let data = Self {};
-                ::std::result::Result::Ok(data)
-            }
+                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::List  => {
 
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    let mut __pilota_decoding_field_id = None;
+                    var_3 = Some({
+                        let list_ident = __protocol.read_list_begin().await?;
+                        let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                        for _ in 0..list_ident.size {
+                            val.push(<SubMessage as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                        };
+                        __protocol.read_list_end().await?;
+                        val
+                    });
 
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
+                },Some(4) if field_ident.field_type == ::pilota::thrift::TType::Set  => {
 
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTest123ArgsRecv` field(#{}) failed, caused by: ", field_id));
-                        }
-                        return ::std::result::Result::Err(err);
+                    var_4 = Some({let list_ident = __protocol.read_set_begin().await?;
+                    let mut val = ::pilota::AHashSet::with_capacity(list_ident.size);
+                    for _ in 0..list_ident.size {
+                        val.insert(<Message as ::pilota::thrift::Message>::decode_async(__protocol).await?);
                     };
-                    __protocol.read_struct_end().await?;
+                    __protocol.read_set_end().await?;
+                    val});
 
-                    let data = Self {};
-                    ::std::result::Result::Ok(data)
-                })
-            }
+                },Some(5) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
 
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTest123ArgsRecv",
-                }) + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
-        impl ::std::default::Default for TestTest123ResultSend {
-            fn default() -> Self {
-                TestTest123ResultSend::Ok(::std::default::Default::default())
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum TestTest123ResultSend {
-            Ok(()),
-        }
+                    var_5 = Some(__protocol.read_faststr().await?);
 
-        impl ::pilota::thrift::Message for TestTest123ResultSend {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTest123ResultSend",
-                })?;
-                match self {
-                    TestTest123ResultSend::Ok(value) => {}
-                }
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
+                },Some(6) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
 
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let mut ret = None;
-                __protocol.read_struct_begin()?;
-                loop {
-                    let field_ident = __protocol.read_field_begin()?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        __protocol.field_stop_len();
-                        break;
-                    } else {
-                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                    }
-                    match field_ident.id {
-                        _ => {
-                            __protocol.skip(field_ident.field_type)?;
-                        }
-                    }
-                }
-                __protocol.read_field_end()?;
-                __protocol.read_struct_end()?;
-                if let Some(ret) = ret {
-                    ::std::result::Result::Ok(ret)
-                } else {
-                    ::std::result::Result::Ok(TestTest123ResultSend::Ok(()))
-                }
-            }
+                    var_6 = Some(__protocol.read_faststr().await?);
 
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    let mut ret = None;
-                    __protocol.read_struct_begin().await?;
-                    loop {
-                        let field_ident = __protocol.read_field_begin().await?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            break;
-                        } else {
-                        }
-                        match field_ident.id {
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
-                            }
-                        }
-                    }
-                    __protocol.read_field_end().await?;
-                    __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
-                    } else {
-                        ::std::result::Result::Ok(TestTest123ResultSend::Ok(()))
-                    }
-                })
-            }
+                },
+                    _ => {
 
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTest123ResultSend",
-                }) + match self {
-                    TestTest123ResultSend::Ok(value) => 0,
-                } + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct StException {
-            pub message: ::std::option::Option<::pilota::FastStr>,
-        }
-        impl ::pilota::thrift::Message for StException {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "StException",
-                };
+                        __protocol.skip(field_ident.field_type).await?;
 
-                __protocol.write_struct_begin(&struct_ident)?;
-                if let Some(value) = self.message.as_ref() {
-                    __protocol.write_faststr_field(1, (value).clone())?;
+                    },
                 }
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
 
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut var_1 = None;
+                __protocol.read_field_end().await?;
 
-                let mut __pilota_decoding_field_id = None;
 
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
-                            {
-                                var_1 = Some(__protocol.read_faststr()?);
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
+            };
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `StException` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let data = Self { message: var_1 };
-                ::std::result::Result::Ok(data)
-            }
-
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
-
-                    let mut __pilota_decoding_field_id = None;
-
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Binary =>
-                                {
-                                    var_1 = Some(__protocol.read_faststr().await?);
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_msg(&format!("decode struct `ObjReq` field(#{}) failed, caused by: ", field_id));
+                }
+                return ::std::result::Result::Err(err);
+            };
+                    __protocol.read_struct_end().await?;
 
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `StException` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
-                        }
-                        return ::std::result::Result::Err(err);
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field msg is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field msg_map is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_3) = var_3 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field sub_msgs is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_5) = var_5 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field flag_msg is required".to_string(),
+                            ),
+                        );
                     };
-                    __protocol.read_struct_end().await?;
 
-                    let data = Self { message: var_1 };
+                    let data = Self {
+                        msg: var_1,
+                        msg_map: var_2,
+                        sub_msgs: var_3,
+                        msg_set: var_4,
+                        flag_msg: var_5,
+                        mock_cost: var_6,
+                    };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -1305,33 +1003,57 @@ pub mod normal {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "StException",
-                }) + self
-                    .message
-                    .as_ref()
-                    .map_or(0, |value| __protocol.faststr_field_len(Some(1), value))
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "ObjReq" })
+                    + __protocol.struct_field_len(Some(1), &self.msg)
+                    + __protocol.map_field_len(
+                        Some(2),
+                        ::pilota::thrift::TType::Struct,
+                        ::pilota::thrift::TType::Struct,
+                        &self.msg_map,
+                        |__protocol, key| __protocol.struct_len(key),
+                        |__protocol, val| __protocol.struct_len(val),
+                    )
+                    + __protocol.list_field_len(
+                        Some(3),
+                        ::pilota::thrift::TType::Struct,
+                        &self.sub_msgs,
+                        |__protocol, el| __protocol.struct_len(el),
+                    )
+                    + self.msg_set.as_ref().map_or(0, |value| {
+                        __protocol.set_field_len(
+                            Some(4),
+                            ::pilota::thrift::TType::Struct,
+                            value,
+                            |__protocol, el| __protocol.struct_len(el),
+                        )
+                    })
+                    + __protocol.faststr_field_len(Some(5), &self.flag_msg)
+                    + self
+                        .mock_cost
+                        .as_ref()
+                        .map_or(0, |value| __protocol.faststr_field_len(Some(6), value))
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct SubMessage {
-            pub value: ::std::option::Option<::pilota::FastStr>,
+        pub struct StException {
+            pub message: ::std::option::Option<::pilota::FastStr>,
         }
-        impl ::pilota::thrift::Message for SubMessage {
+        impl ::pilota::thrift::Message for StException {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "SubMessage" };
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "StException",
+                };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                if let Some(value) = self.value.as_ref() {
-                    __protocol.write_faststr_field(2, (value).clone())?;
+                if let Some(value) = self.message.as_ref() {
+                    __protocol.write_faststr_field(1, (value).clone())?;
                 }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
@@ -1344,7 +1066,7 @@ pub mod normal {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
-                let mut var_2 = None;
+                let mut var_1 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -1360,10 +1082,10 @@ pub mod normal {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
-                            Some(2)
+                            Some(1)
                                 if field_ident.field_type == ::pilota::thrift::TType::Binary =>
                             {
-                                var_2 = Some(__protocol.read_faststr()?);
+                                var_1 = Some(__protocol.read_faststr()?);
                             }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
@@ -1377,7 +1099,7 @@ pub mod normal {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `SubMessage` field(#{}) failed, caused by: ",
+                            "decode struct `StException` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -1385,7 +1107,7 @@ pub mod normal {
                 };
                 __protocol.read_struct_end()?;
 
-                let data = Self { value: var_2 };
+                let data = Self { message: var_1 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -1400,7 +1122,7 @@ pub mod normal {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut var_2 = None;
+                    let mut var_1 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
@@ -1414,11 +1136,11 @@ pub mod normal {
                             }
                             __pilota_decoding_field_id = field_ident.id;
                             match field_ident.id {
-                                Some(2)
+                                Some(1)
                                     if field_ident.field_type
                                         == ::pilota::thrift::TType::Binary =>
                                 {
-                                    var_2 = Some(__protocol.read_faststr().await?);
+                                    var_1 = Some(__protocol.read_faststr().await?);
                                 }
                                 _ => {
                                     __protocol.skip(field_ident.field_type).await?;
@@ -1433,7 +1155,7 @@ pub mod normal {
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
                             err.prepend_msg(&format!(
-                                "decode struct `SubMessage` field(#{}) failed, caused by: ",
+                                "decode struct `StException` field(#{}) failed, caused by: ",
                                 field_id
                             ));
                         }
@@ -1441,7 +1163,7 @@ pub mod normal {
                     };
                     __protocol.read_struct_end().await?;
 
-                    let data = Self { value: var_2 };
+                    let data = Self { message: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -1449,35 +1171,36 @@ pub mod normal {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol
-                    .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "SubMessage" })
-                    + self
-                        .value
-                        .as_ref()
-                        .map_or(0, |value| __protocol.faststr_field_len(Some(2), value))
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "StException",
+                }) + self
+                    .message
+                    .as_ref()
+                    .map_or(0, |value| __protocol.faststr_field_len(Some(1), value))
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        #[derive(Debug, Default, Clone, PartialEq)]
-        pub struct TestTestExceptionArgsRecv {
-            pub req: ObjReq,
+        impl ::std::default::Default for TestTest123ResultRecv {
+            fn default() -> Self {
+                TestTest123ResultRecv::Ok(::std::default::Default::default())
+            }
         }
-        impl ::pilota::thrift::Message for TestTestExceptionArgsRecv {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum TestTest123ResultRecv {
+            Ok(()),
+        }
+
+        impl ::pilota::thrift::Message for TestTest123ResultRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "TestTestExceptionArgsRecv",
-                };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
+                __protocol.write_empty_struct(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTest123ResultRecv",
+                })?;
                 ::std::result::Result::Ok(())
             }
 
@@ -1486,54 +1209,64 @@ pub mod normal {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                __protocol.read_empty_struct()?;
+                ::std::result::Result::Ok(TestTest123ResultRecv::Ok(()))
+            }
 
-                let mut var_1 = None;
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
-                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    __protocol.read_empty_struct().await?;
+                    ::std::result::Result::Ok(TestTest123ResultRecv::Ok(()))
+                })
+            }
 
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `TestTestExceptionArgsRecv` field(#{}) failed, caused by: ", field_id));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.empty_struct_len(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTest123ResultRecv",
+                })
+            }
+        }
+        impl ::std::default::Default for TestTest123ResultSend {
+            fn default() -> Self {
+                TestTest123ResultSend::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum TestTest123ResultSend {
+            Ok(()),
+        }
 
-                let Some(var_1) = var_1 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
-                    ));
-                };
+        impl ::pilota::thrift::Message for TestTest123ResultSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_empty_struct(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTest123ResultSend",
+                })?;
+                ::std::result::Result::Ok(())
+            }
 
-                let data = Self { req: var_1 };
-                ::std::result::Result::Ok(data)
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                __protocol.read_empty_struct()?;
+                ::std::result::Result::Ok(TestTest123ResultSend::Ok(()))
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -1547,84 +1280,128 @@ pub mod normal {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
+                    __protocol.read_empty_struct().await?;
+                    ::std::result::Result::Ok(TestTest123ResultSend::Ok(()))
+                })
+            }
 
-                    let mut __pilota_decoding_field_id = None;
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.empty_struct_len(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTest123ResultSend",
+                })
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct TestTest123ArgsSend {}
+        impl ::pilota::thrift::Message for TestTest123ArgsSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_empty_struct(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTest123ArgsSend",
+                })?;
+                ::std::result::Result::Ok(())
+            }
 
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_1 = Some(
-                                        <ObjReq as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    );
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                __protocol.read_empty_struct()?;
+                ::std::result::Result::Ok(Self {})
+            }
 
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTestExceptionArgsRecv` field(#{}) failed, caused by: ", field_id));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    __protocol.read_empty_struct().await?;
+                    ::std::result::Result::Ok(Self {})
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.empty_struct_len(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTest123ArgsSend",
+                })
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct TestTest123ArgsRecv {}
+        impl ::pilota::thrift::Message for TestTest123ArgsRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_empty_struct(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTest123ArgsRecv",
+                })?;
+                ::std::result::Result::Ok(())
+            }
 
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
-                            ),
-                        );
-                    };
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                __protocol.read_empty_struct()?;
+                ::std::result::Result::Ok(Self {})
+            }
 
-                    let data = Self { req: var_1 };
-                    ::std::result::Result::Ok(data)
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    __protocol.read_empty_struct().await?;
+                    ::std::result::Result::Ok(Self {})
                 })
             }
 
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestExceptionArgsRecv",
-                }) + __protocol.struct_field_len(Some(1), &self.req)
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
+                __protocol.empty_struct_len(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTest123ArgsRecv",
+                })
             }
         }
-        impl ::std::default::Default for TestTestExceptionException {
+        impl ::std::default::Default for TestTestExceptionResultRecv {
             fn default() -> Self {
-                TestTestExceptionException::StException(::std::default::Default::default())
+                TestTestExceptionResultRecv::Ok(::std::default::Default::default())
             }
         }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum TestTestExceptionException {
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum TestTestExceptionResultRecv {
+            Ok(ObjReq),
+
             StException(StException),
         }
 
-        impl ::pilota::thrift::Message for TestTestExceptionException {
+        impl ::pilota::thrift::Message for TestTestExceptionResultRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -1632,10 +1409,13 @@ pub mod normal {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestExceptionException",
+                    name: "TestTestExceptionResultRecv",
                 })?;
                 match self {
-                    TestTestExceptionException::StException(value) => {
+                    TestTestExceptionResultRecv::Ok(value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                    TestTestExceptionResultRecv::StException(value) => {
                         __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
                     }
                 }
@@ -1660,11 +1440,25 @@ pub mod normal {
                         __protocol.field_begin_len(field_ident.field_type, field_ident.id);
                     }
                     match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(TestTestExceptionResultRecv::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
                         Some(1) => {
                             if ret.is_none() {
                                 let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(TestTestExceptionException::StException(field_ident));
+                                ret = Some(TestTestExceptionResultRecv::StException(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -1711,6 +1505,24 @@ pub mod normal {
                         } else {
                         }
                         match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <ObjReq as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(TestTestExceptionResultRecv::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
                             Some(1) => {
                                 if ret.is_none() {
                                     let field_ident =
@@ -1720,7 +1532,7 @@ pub mod normal {
                                         .await?;
 
                                     ret =
-                                        Some(TestTestExceptionException::StException(field_ident));
+                                        Some(TestTestExceptionResultRecv::StException(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -1752,9 +1564,12 @@ pub mod normal {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestExceptionException",
+                    name: "TestTestExceptionResultRecv",
                 }) + match self {
-                    TestTestExceptionException::StException(value) => {
+                    TestTestExceptionResultRecv::Ok(value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                    TestTestExceptionResultRecv::StException(value) => {
                         __protocol.struct_field_len(Some(1), value)
                     }
                 } + __protocol.field_stop_len()
@@ -1948,19 +1763,17 @@ pub mod normal {
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for TestTestExceptionResultRecv {
+        impl ::std::default::Default for TestTestExceptionException {
             fn default() -> Self {
-                TestTestExceptionResultRecv::Ok(::std::default::Default::default())
+                TestTestExceptionException::StException(::std::default::Default::default())
             }
         }
-        #[derive(Debug, Clone, PartialEq)]
-        pub enum TestTestExceptionResultRecv {
-            Ok(ObjReq),
-
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum TestTestExceptionException {
             StException(StException),
         }
 
-        impl ::pilota::thrift::Message for TestTestExceptionResultRecv {
+        impl ::pilota::thrift::Message for TestTestExceptionException {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -1968,13 +1781,10 @@ pub mod normal {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestExceptionResultRecv",
+                    name: "TestTestExceptionException",
                 })?;
                 match self {
-                    TestTestExceptionResultRecv::Ok(value) => {
-                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
-                    }
-                    TestTestExceptionResultRecv::StException(value) => {
+                    TestTestExceptionException::StException(value) => {
                         __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
                     }
                 }
@@ -1999,25 +1809,11 @@ pub mod normal {
                         __protocol.field_begin_len(field_ident.field_type, field_ident.id);
                     }
                     match field_ident.id {
-                        Some(0) => {
-                            if ret.is_none() {
-                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(TestTestExceptionResultRecv::Ok(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
-                            }
-                        }
                         Some(1) => {
                             if ret.is_none() {
                                 let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(TestTestExceptionResultRecv::StException(field_ident));
+                                ret = Some(TestTestExceptionException::StException(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -2064,24 +1860,6 @@ pub mod normal {
                         } else {
                         }
                         match field_ident.id {
-                            Some(0) => {
-                                if ret.is_none() {
-                                    let field_ident =
-                                        <ObjReq as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?;
-
-                                    ret = Some(TestTestExceptionResultRecv::Ok(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
-                                    );
-                                }
-                            }
                             Some(1) => {
                                 if ret.is_none() {
                                     let field_ident =
@@ -2091,7 +1869,7 @@ pub mod normal {
                                         .await?;
 
                                     ret =
-                                        Some(TestTestExceptionResultRecv::StException(field_ident));
+                                        Some(TestTestExceptionException::StException(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -2123,41 +1901,32 @@ pub mod normal {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTestExceptionResultRecv",
+                    name: "TestTestExceptionException",
                 }) + match self {
-                    TestTestExceptionResultRecv::Ok(value) => {
-                        __protocol.struct_field_len(Some(0), value)
-                    }
-                    TestTestExceptionResultRecv::StException(value) => {
+                    TestTestExceptionException::StException(value) => {
                         __protocol.struct_field_len(Some(1), value)
                     }
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for TestTest123ResultRecv {
-            fn default() -> Self {
-                TestTest123ResultRecv::Ok(::std::default::Default::default())
-            }
-        }
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
-        pub enum TestTest123ResultRecv {
-            Ok(()),
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct TestTestExceptionArgsSend {
+            pub req: ObjReq,
         }
-
-        impl ::pilota::thrift::Message for TestTest123ResultRecv {
+        impl ::pilota::thrift::Message for TestTestExceptionArgsSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTest123ResultRecv",
-                })?;
-                match self {
-                    TestTest123ResultRecv::Ok(value) => {}
-                }
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "TestTestExceptionArgsSend",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -2168,29 +1937,54 @@ pub mod normal {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let mut ret = None;
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
                 __protocol.read_struct_begin()?;
-                loop {
-                    let field_ident = __protocol.read_field_begin()?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        __protocol.field_stop_len();
-                        break;
-                    } else {
-                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                    }
-                    match field_ident.id {
-                        _ => {
-                            __protocol.skip(field_ident.field_type)?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
                         }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
                     }
-                }
-                __protocol.read_field_end()?;
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(&format!("decode struct `TestTestExceptionArgsSend` field(#{}) failed, caused by: ", field_id));
+                    }
+                    return ::std::result::Result::Err(err);
+                };
                 __protocol.read_struct_end()?;
-                if let Some(ret) = ret {
-                    ::std::result::Result::Ok(ret)
-                } else {
-                    ::std::result::Result::Ok(TestTest123ResultRecv::Ok(()))
-                }
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field req is required".to_string(),
+                    ));
+                };
+
+                let data = Self { req: var_1 };
+                ::std::result::Result::Ok(data)
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -2204,27 +1998,60 @@ pub mod normal {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut ret = None;
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
                     __protocol.read_struct_begin().await?;
-                    loop {
-                        let field_ident = __protocol.read_field_begin().await?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            break;
-                        } else {
-                        }
-                        match field_ident.id {
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(
+                                        <ObjReq as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
                             }
+
+                            __protocol.read_field_end().await?;
                         }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     }
-                    __protocol.read_field_end().await?;
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!("decode struct `TestTestExceptionArgsSend` field(#{}) failed, caused by: ", field_id));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
                     __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
-                    } else {
-                        ::std::result::Result::Ok(TestTest123ResultRecv::Ok(()))
-                    }
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field req is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { req: var_1 };
+                    ::std::result::Result::Ok(data)
                 })
             }
 
@@ -2232,49 +2059,29 @@ pub mod normal {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestTest123ResultRecv",
-                }) + match self {
-                    TestTest123ResultRecv::Ok(value) => 0,
-                } + __protocol.field_stop_len()
+                    name: "TestTestExceptionArgsSend",
+                }) + __protocol.struct_field_len(Some(1), &self.req)
+                    + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct Message {
-            pub uid: ::std::option::Option<[u8; 16]>,
-
-            pub value: ::std::option::Option<::pilota::FastStr>,
-
-            pub sub_messages: ::std::option::Option<::std::vec::Vec<SubMessage>>,
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct TestTestExceptionArgsRecv {
+            pub req: ObjReq,
         }
-        impl ::pilota::thrift::Message for Message {
+        impl ::pilota::thrift::Message for TestTestExceptionArgsRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Message" };
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "TestTestExceptionArgsRecv",
+                };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                if let Some(value) = self.uid.as_ref() {
-                    __protocol.write_uuid_field(1, *value)?;
-                }
-                if let Some(value) = self.value.as_ref() {
-                    __protocol.write_faststr_field(2, (value).clone())?;
-                }
-                if let Some(value) = self.sub_messages.as_ref() {
-                    __protocol.write_list_field(
-                        3,
-                        ::pilota::thrift::TType::Struct,
-                        &value,
-                        |__protocol, val| {
-                            __protocol.write_struct(val)?;
-                            ::std::result::Result::Ok(())
-                        },
-                    )?;
-                }
+                __protocol.write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -2287,8 +2094,6 @@ pub mod normal {
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
                 let mut var_1 = None;
-                let mut var_2 = None;
-                let mut var_3 = None;
 
                 let mut __pilota_decoding_field_id = None;
 
@@ -2304,28 +2109,10 @@ pub mod normal {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
-                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::Uuid => {
-                                var_1 = Some(__protocol.read_uuid()?);
-                            }
-                            Some(2)
-                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
                             {
-                                var_2 = Some(__protocol.read_faststr()?);
-                            }
-                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::List => {
-                                var_3 = Some(unsafe {
-                                    let list_ident = __protocol.read_list_begin()?;
-                                    let mut val: ::std::vec::Vec<SubMessage> =
-                                        ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(::pilota::thrift::Message::decode(__protocol)?);
-                                    }
-                                    val.set_len(list_ident.size);
-                                    __protocol.read_list_end()?;
-                                    val
-                                });
+                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
                             }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
@@ -2338,20 +2125,20 @@ pub mod normal {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Message` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_msg(&format!("decode struct `TestTestExceptionArgsRecv` field(#{}) failed, caused by: ", field_id));
                     }
                     return ::std::result::Result::Err(err);
                 };
                 __protocol.read_struct_end()?;
 
-                let data = Self {
-                    uid: var_1,
-                    value: var_2,
-                    sub_messages: var_3,
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field req is required".to_string(),
+                    ));
                 };
+
+                let data = Self { req: var_1 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -2367,67 +2154,58 @@ pub mod normal {
             > {
                 ::std::boxed::Box::pin(async move {
                     let mut var_1 = None;
-                    let mut var_2 = None;
-                    let mut var_3 = None;
 
                     let mut __pilota_decoding_field_id = None;
 
                     __protocol.read_struct_begin().await?;
                     if let ::std::result::Result::Err(mut err) = async {
-                    loop {
-
-
-                let field_ident = __protocol.read_field_begin().await?;
-                if field_ident.field_type == ::pilota::thrift::TType::Stop {
-
-                    break;
-                } else {
-
-                }
-                __pilota_decoding_field_id = field_ident.id;
-                match field_ident.id {
-                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Uuid  => {
-                    var_1 = Some(__protocol.read_uuid().await?);
-
-                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
-                    var_2 = Some(__protocol.read_faststr().await?);
-
-                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::List  => {
-                    var_3 = Some({
-                            let list_ident = __protocol.read_list_begin().await?;
-                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
-                                val.push(<SubMessage as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                            };
-                            __protocol.read_list_end().await?;
-                            val
-                        });
-
-                },
-                    _ => {
-                        __protocol.skip(field_ident.field_type).await?;
-
-                    },
-                }
-
-                __protocol.read_field_end().await?;
-
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(
+                                        <ObjReq as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
 
-            };
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                }.await {
-                if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `Message` field(#{}) failed, caused by: ", field_id));
-                }
-                return ::std::result::Result::Err(err);
-            };
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!("decode struct `TestTestExceptionArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
                     __protocol.read_struct_end().await?;
 
-                    let data = Self {
-                        uid: var_1,
-                        value: var_2,
-                        sub_messages: var_3,
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field req is required".to_string(),
+                            ),
+                        );
                     };
+
+                    let data = Self { req: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -2435,27 +2213,14 @@ pub mod normal {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol
-                    .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Message" })
-                    + self
-                        .uid
-                        .as_ref()
-                        .map_or(0, |value| __protocol.uuid_field_len(Some(1), *value))
-                    + self
-                        .value
-                        .as_ref()
-                        .map_or(0, |value| __protocol.faststr_field_len(Some(2), value))
-                    + self.sub_messages.as_ref().map_or(0, |value| {
-                        __protocol.list_field_len(
-                            Some(3),
-                            ::pilota::thrift::TType::Struct,
-                            value,
-                            |__protocol, el| __protocol.struct_len(el),
-                        )
-                    })
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "TestTestExceptionArgsRecv",
+                }) + __protocol.struct_field_len(Some(1), &self.req)
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
+
+        pub trait Test {}
     }
 }
@@ -0,0 +1,635 @@
+pub mod overlay {
+    #![allow(warnings, clippy::all)]
+
+    pub mod overlay {
+
+        #[derive(PartialOrd, Debug, Default, Clone, PartialEq)]
+        pub struct Tick {
+            pub timestamp: i64,
+
+            pub price: f64,
+
+            pub volume: i32,
+
+            pub venue: i16,
+
+            pub side: i8,
+
+            pub halted: bool,
+        }
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct TickOverlay {
+            timestamp: [u8; 8],
+            price: [u8; 8],
+            volume: [u8; 4],
+            venue: [u8; 2],
+            side: [u8; 1],
+            halted: [u8; 1],
+        }
+
+        const _: () = ::std::assert!(::std::mem::align_of::<TickOverlay>() == 1);
+
+        // SAFETY: only byte arrays, so no padding and an alignment of 1
+        unsafe impl ::pilota::overlay::Overlay for TickOverlay {}
+
+        impl TickOverlay {
+            pub fn timestamp(&self) -> i64 {
+                i64::from_le_bytes(self.timestamp)
+            }
+
+            pub fn set_timestamp(&mut self, value: i64) {
+                self.timestamp = value.to_le_bytes();
+            }
+            pub fn price(&self) -> f64 {
+                f64::from_le_bytes(self.price)
+            }
+
+            pub fn set_price(&mut self, value: f64) {
+                self.price = value.to_le_bytes();
+            }
+            pub fn volume(&self) -> i32 {
+                i32::from_le_bytes(self.volume)
+            }
+
+            pub fn set_volume(&mut self, value: i32) {
+                self.volume = value.to_le_bytes();
+            }
+            pub fn venue(&self) -> i16 {
+                i16::from_le_bytes(self.venue)
+            }
+
+            pub fn set_venue(&mut self, value: i16) {
+                self.venue = value.to_le_bytes();
+            }
+            pub fn side(&self) -> i8 {
+                i8::from_le_bytes(self.side)
+            }
+
+            pub fn set_side(&mut self, value: i8) {
+                self.side = value.to_le_bytes();
+            }
+            pub fn halted(&self) -> bool {
+                self.halted[0] != 0
+            }
+
+            pub fn set_halted(&mut self, value: bool) {
+                self.halted = [value as u8];
+            }
+        }
+
+        impl ::std::convert::From<&Tick> for TickOverlay {
+            fn from(value: &Tick) -> Self {
+                let mut overlay = Self::default();
+                overlay.set_timestamp(value.timestamp);
+                overlay.set_price(value.price);
+                overlay.set_volume(value.volume);
+                overlay.set_venue(value.venue);
+                overlay.set_side(value.side);
+                overlay.set_halted(value.halted);
+
+                overlay
+            }
+        }
+
+        impl ::std::convert::From<&TickOverlay> for Tick {
+            fn from(value: &TickOverlay) -> Self {
+                Self {
+                    timestamp: value.timestamp(),
+                    price: value.price(),
+                    volume: value.volume(),
+                    venue: value.venue(),
+                    side: value.side(),
+                    halted: value.halted(),
+                }
+            }
+        }
+
+        impl ::pilota::thrift::Message for Tick {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Tick" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.timestamp)?;
+                __protocol.write_double_field(2, *&self.price)?;
+                __protocol.write_i32_field(3, *&self.volume)?;
+                __protocol.write_i16_field(4, *&self.venue)?;
+                __protocol.write_i8_field(5, *&self.side)?;
+                __protocol.write_bool_field(6, *&self.halted)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut var_5 = None;
+                let mut var_6 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            Some(2)
+                                if field_ident.field_type == ::pilota::thrift::TType::Double =>
+                            {
+                                var_2 = Some(__protocol.read_double()?);
+                            }
+                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_3 = Some(__protocol.read_i32()?);
+                            }
+                            Some(4) if field_ident.field_type == ::pilota::thrift::TType::I16 => {
+                                var_4 = Some(__protocol.read_i16()?);
+                            }
+                            Some(5) if field_ident.field_type == ::pilota::thrift::TType::I8 => {
+                                var_5 = Some(__protocol.read_i8()?);
+                            }
+                            Some(6) if field_ident.field_type == ::pilota::thrift::TType::Bool => {
+                                var_6 = Some(__protocol.read_bool()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(&format!(
+                            "decode struct `Tick` field(#{}) failed, caused by: ",
+                            field_id
+                        ));
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field timestamp is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field price is required".to_string(),
+                    ));
+                };
+                let Some(var_3) = var_3 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field volume is required".to_string(),
+                    ));
+                };
+                let Some(var_4) = var_4 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field venue is required".to_string(),
+                    ));
+                };
+                let Some(var_5) = var_5 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field side is required".to_string(),
+                    ));
+                };
+                let Some(var_6) = var_6 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field halted is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    timestamp: var_1,
+                    price: var_2,
+                    volume: var_3,
+                    venue: var_4,
+                    side: var_5,
+                    halted: var_6,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+                    let mut var_6 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I64 =>
+                                {
+                                    var_1 = Some(__protocol.read_i64().await?);
+                                }
+                                Some(2)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Double =>
+                                {
+                                    var_2 = Some(__protocol.read_double().await?);
+                                }
+                                Some(3)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_3 = Some(__protocol.read_i32().await?);
+                                }
+                                Some(4)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I16 =>
+                                {
+                                    var_4 = Some(__protocol.read_i16().await?);
+                                }
+                                Some(5)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I8 =>
+                                {
+                                    var_5 = Some(__protocol.read_i8().await?);
+                                }
+                                Some(6)
+                                    if field_ident.field_type == ::pilota::thrift::TType::Bool =>
+                                {
+                                    var_6 = Some(__protocol.read_bool().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!(
+                                "decode struct `Tick` field(#{}) failed, caused by: ",
+                                field_id
+                            ));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field timestamp is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field price is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_3) = var_3 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field volume is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_4) = var_4 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field venue is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_5) = var_5 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field side is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_6) = var_6 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field halted is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        timestamp: var_1,
+                        price: var_2,
+                        volume: var_3,
+                        venue: var_4,
+                        side: var_5,
+                        halted: var_6,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Tick" })
+                    + __protocol.i64_field_len(Some(1), *&self.timestamp)
+                    + __protocol.double_field_len(Some(2), *&self.price)
+                    + __protocol.i32_field_len(Some(3), *&self.volume)
+                    + __protocol.i16_field_len(Some(4), *&self.venue)
+                    + __protocol.i8_field_len(Some(5), *&self.side)
+                    + __protocol.bool_field_len(Some(6), *&self.halted)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Header {
+            pub magic: i32,
+
+            pub version: i16,
+        }
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct HeaderOverlay {
+            magic: [u8; 4],
+            version: [u8; 2],
+        }
+
+        const _: () = ::std::assert!(::std::mem::align_of::<HeaderOverlay>() == 1);
+
+        // SAFETY: only byte arrays, so no padding and an alignment of 1
+        unsafe impl ::pilota::overlay::Overlay for HeaderOverlay {}
+
+        impl HeaderOverlay {
+            pub fn magic(&self) -> i32 {
+                i32::from_be_bytes(self.magic)
+            }
+
+            pub fn set_magic(&mut self, value: i32) {
+                self.magic = value.to_be_bytes();
+            }
+            pub fn version(&self) -> i16 {
+                i16::from_be_bytes(self.version)
+            }
+
+            pub fn set_version(&mut self, value: i16) {
+                self.version = value.to_be_bytes();
+            }
+        }
+
+        impl ::std::convert::From<&Header> for HeaderOverlay {
+            fn from(value: &Header) -> Self {
+                let mut overlay = Self::default();
+                overlay.set_magic(value.magic);
+                overlay.set_version(value.version);
+
+                overlay
+            }
+        }
+
+        impl ::std::convert::From<&HeaderOverlay> for Header {
+            fn from(value: &HeaderOverlay) -> Self {
+                Self {
+                    magic: value.magic(),
+                    version: value.version(),
+                }
+            }
+        }
+
+        impl ::pilota::thrift::Message for Header {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Header" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i32_field(1, *&self.magic)?;
+                __protocol.write_i16_field(2, *&self.version)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_1 = Some(__protocol.read_i32()?);
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::I16 => {
+                                var_2 = Some(__protocol.read_i16()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(&format!(
+                            "decode struct `Header` field(#{}) failed, caused by: ",
+                            field_id
+                        ));
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field magic is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field version is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    magic: var_1,
+                    version: var_2,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_1 = Some(__protocol.read_i32().await?);
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I16 =>
+                                {
+                                    var_2 = Some(__protocol.read_i16().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!(
+                                "decode struct `Header` field(#{}) failed, caused by: ",
+                                field_id
+                            ));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field magic is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field version is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        magic: var_1,
+                        version: var_2,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Header" })
+                    + __protocol.i32_field_len(Some(1), *&self.magic)
+                    + __protocol.i16_field_len(Some(2), *&self.version)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+    }
+}
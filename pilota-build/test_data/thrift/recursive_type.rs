@@ -184,30 +184,22 @@ pub mod recursive_type {
             }
         }
 
-        #[derive(Debug, Default, Clone, PartialEq)]
-        pub struct C {
-            pub c: ::std::option::Option<::pilota::AHashSet<::pilota::FastStr>>,
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct B {
+            pub b_a: ::std::option::Option<::std::boxed::Box<A>>,
         }
-        impl ::pilota::thrift::Message for C {
+        impl ::pilota::thrift::Message for B {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "C" };
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "B" };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                if let Some(value) = self.c.as_ref() {
-                    __protocol.write_set_field(
-                        1,
-                        ::pilota::thrift::TType::Binary,
-                        &value,
-                        |__protocol, val| {
-                            __protocol.write_faststr((val).clone())?;
-                            ::std::result::Result::Ok(())
-                        },
-                    )?;
+                if let Some(value) = self.b_a.as_ref() {
+                    __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
                 }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
@@ -236,17 +228,12 @@ pub mod recursive_type {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
-                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::Set => {
-                                var_1 = Some({
-                                    let list_ident = __protocol.read_set_begin()?;
-                                    let mut val =
-                                        ::pilota::AHashSet::with_capacity(list_ident.size);
-                                    for _ in 0..list_ident.size {
-                                        val.insert(__protocol.read_faststr()?);
-                                    }
-                                    __protocol.read_set_end()?;
-                                    val
-                                });
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_1 = Some(::std::boxed::Box::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                ));
                             }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
@@ -260,7 +247,7 @@ pub mod recursive_type {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `C` field(#{}) failed, caused by: ",
+                            "decode struct `B` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -268,7 +255,7 @@ pub mod recursive_type {
                 };
                 __protocol.read_struct_end()?;
 
-                let data = Self { c: var_1 };
+                let data = Self { b_a: var_1 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -298,18 +285,13 @@ pub mod recursive_type {
                             __pilota_decoding_field_id = field_ident.id;
                             match field_ident.id {
                                 Some(1)
-                                    if field_ident.field_type == ::pilota::thrift::TType::Set =>
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
                                 {
-                                    var_1 = Some({
-                                        let list_ident = __protocol.read_set_begin().await?;
-                                        let mut val =
-                                            ::pilota::AHashSet::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.insert(__protocol.read_faststr().await?);
-                                        }
-                                        __protocol.read_set_end().await?;
-                                        val
-                                    });
+                                    var_1 = Some(::std::boxed::Box::new(
+                                        <A as ::pilota::thrift::Message>::decode_async(__protocol)
+                                            .await?,
+                                    ));
                                 }
                                 _ => {
                                     __protocol.skip(field_ident.field_type).await?;
@@ -324,7 +306,7 @@ pub mod recursive_type {
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
                             err.prepend_msg(&format!(
-                                "decode struct `C` field(#{}) failed, caused by: ",
+                                "decode struct `B` field(#{}) failed, caused by: ",
                                 field_id
                             ));
                         }
@@ -332,7 +314,7 @@ pub mod recursive_type {
                     };
                     __protocol.read_struct_end().await?;
 
-                    let data = Self { c: var_1 };
+                    let data = Self { b_a: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -340,36 +322,40 @@ pub mod recursive_type {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "C" })
-                    + self.c.as_ref().map_or(0, |value| {
-                        __protocol.set_field_len(
-                            Some(1),
-                            ::pilota::thrift::TType::Binary,
-                            value,
-                            |__protocol, el| __protocol.faststr_len(el),
-                        )
-                    })
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "B" })
+                    + self
+                        .b_a
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(1), value))
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
 
-        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct B {
-            pub b_a: ::std::option::Option<::std::boxed::Box<A>>,
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct C {
+            pub c: ::std::option::Option<::pilota::AHashSet<::pilota::FastStr>>,
         }
-        impl ::pilota::thrift::Message for B {
+        impl ::pilota::thrift::Message for C {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "B" };
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "C" };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-                if let Some(value) = self.b_a.as_ref() {
-                    __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
+                if let Some(value) = self.c.as_ref() {
+                    __protocol.write_set_field(
+                        1,
+                        ::pilota::thrift::TType::Binary,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_faststr((val).clone())?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
                 }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
@@ -398,12 +384,17 @@ pub mod recursive_type {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
-                                var_1 = Some(::std::boxed::Box::new(
-                                    ::pilota::thrift::Message::decode(__protocol)?,
-                                ));
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::Set => {
+                                var_1 = Some({
+                                    let list_ident = __protocol.read_set_begin()?;
+                                    let mut val =
+                                        ::pilota::AHashSet::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.insert(__protocol.read_faststr()?);
+                                    }
+                                    __protocol.read_set_end()?;
+                                    val
+                                });
                             }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
@@ -417,7 +408,7 @@ pub mod recursive_type {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `B` field(#{}) failed, caused by: ",
+                            "decode struct `C` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -425,7 +416,7 @@ pub mod recursive_type {
                 };
                 __protocol.read_struct_end()?;
 
-                let data = Self { b_a: var_1 };
+                let data = Self { c: var_1 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -455,13 +446,18 @@ pub mod recursive_type {
                             __pilota_decoding_field_id = field_ident.id;
                             match field_ident.id {
                                 Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
+                                    if field_ident.field_type == ::pilota::thrift::TType::Set =>
                                 {
-                                    var_1 = Some(::std::boxed::Box::new(
-                                        <A as ::pilota::thrift::Message>::decode_async(__protocol)
-                                            .await?,
-                                    ));
+                                    var_1 = Some({
+                                        let list_ident = __protocol.read_set_begin().await?;
+                                        let mut val =
+                                            ::pilota::AHashSet::with_capacity(list_ident.size);
+                                        for _ in 0..list_ident.size {
+                                            val.insert(__protocol.read_faststr().await?);
+                                        }
+                                        __protocol.read_set_end().await?;
+                                        val
+                                    });
                                 }
                                 _ => {
                                     __protocol.skip(field_ident.field_type).await?;
@@ -476,7 +472,7 @@ pub mod recursive_type {
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
                             err.prepend_msg(&format!(
-                                "decode struct `B` field(#{}) failed, caused by: ",
+                                "decode struct `C` field(#{}) failed, caused by: ",
                                 field_id
                             ));
                         }
@@ -484,7 +480,7 @@ pub mod recursive_type {
                     };
                     __protocol.read_struct_end().await?;
 
-                    let data = Self { b_a: var_1 };
+                    let data = Self { c: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -492,11 +488,15 @@ pub mod recursive_type {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "B" })
-                    + self
-                        .b_a
-                        .as_ref()
-                        .map_or(0, |value| __protocol.struct_field_len(Some(1), value))
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "C" })
+                    + self.c.as_ref().map_or(0, |value| {
+                        __protocol.set_field_len(
+                            Some(1),
+                            ::pilota::thrift::TType::Binary,
+                            value,
+                            |__protocol, el| __protocol.faststr_len(el),
+                        )
+                    })
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
@@ -0,0 +1,21 @@
+exception BaseError {
+    1: required string message,
+}
+
+exception DerivedError {
+    1: required string message,
+}
+
+struct Item {
+    1: required string name,
+}
+
+service Base {
+    Item Get(1: Item req) throws (1: BaseError err),
+    Item Watch(1: Item req) (streaming.mode = "server"),
+}
+
+service Derived extends Base {
+    Item Put(1: Item req) throws (1: BaseError base_err, 2: DerivedError err),
+    Item Sync(1: Item req) (streaming.mode = "bidirectional"),
+}
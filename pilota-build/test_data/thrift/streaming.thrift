@@ -0,0 +1,10 @@
+struct Item {
+    1: required string name,
+}
+
+service Catalog {
+    Item Get(1: Item req),
+    Item Upload(1: Item req) (streaming.mode = "client"),
+    Item List(1: Item req) (streaming.mode = "server"),
+    Item Sync(1: Item req) (streaming.mode = "bidirectional"),
+}
@@ -178,18 +178,22 @@ pub mod union {
         }
 
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct Default {}
-        impl ::pilota::thrift::Message for Default {
+        pub struct A {
+            pub u: ::std::option::Option<Union>,
+        }
+        impl ::pilota::thrift::Message for A {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Default" };
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "A" };
 
                 __protocol.write_struct_begin(&struct_ident)?;
-
+                if let Some(value) = self.u.as_ref() {
+                    __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
+                }
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -201,6 +205,8 @@ pub mod union {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
+                let mut var_1 = None;
+
                 let mut __pilota_decoding_field_id = None;
 
                 __protocol.read_struct_begin()?;
@@ -215,6 +221,11 @@ pub mod union {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
                             }
@@ -227,7 +238,7 @@ pub mod union {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `Default` field(#{}) failed, caused by: ",
+                            "decode struct `A` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -235,7 +246,7 @@ pub mod union {
                 };
                 __protocol.read_struct_end()?;
 
-                let data = Self {};
+                let data = Self { u: var_1 };
                 ::std::result::Result::Ok(data)
             }
 
@@ -250,6 +261,8 @@ pub mod union {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
                     let mut __pilota_decoding_field_id = None;
 
                     __protocol.read_struct_begin().await?;
@@ -262,6 +275,17 @@ pub mod union {
                             }
                             __pilota_decoding_field_id = field_ident.id;
                             match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(
+                                        <Union as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    );
+                                }
                                 _ => {
                                     __protocol.skip(field_ident.field_type).await?;
                                 }
@@ -275,7 +299,7 @@ pub mod union {
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
                             err.prepend_msg(&format!(
-                                "decode struct `Default` field(#{}) failed, caused by: ",
+                                "decode struct `A` field(#{}) failed, caused by: ",
                                 field_id
                             ));
                         }
@@ -283,7 +307,7 @@ pub mod union {
                     };
                     __protocol.read_struct_end().await?;
 
-                    let data = Self {};
+                    let data = Self { u: var_1 };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -291,8 +315,11 @@ pub mod union {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol
-                    .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Default" })
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "A" })
+                    + self
+                        .u
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(1), value))
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
@@ -401,24 +428,16 @@ pub mod union {
         }
 
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
-        pub struct A {
-            pub u: ::std::option::Option<Union>,
-        }
-        impl ::pilota::thrift::Message for A {
+        pub struct Default {}
+        impl ::pilota::thrift::Message for Default {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "A" };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-                if let Some(value) = self.u.as_ref() {
-                    __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
-                }
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
+                __protocol
+                    .write_empty_struct(&::pilota::thrift::TStructIdentifier { name: "Default" })?;
                 ::std::result::Result::Ok(())
             }
 
@@ -427,50 +446,8 @@ pub mod union {
             ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut var_1 = None;
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
-                            {
-                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
-
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
-                    }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `A` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
-                    }
-                    return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let data = Self { u: var_1 };
-                ::std::result::Result::Ok(data)
+                __protocol.read_empty_struct()?;
+                ::std::result::Result::Ok(Self {})
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -484,67 +461,16 @@ pub mod union {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
-
-                    let mut __pilota_decoding_field_id = None;
-
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_1 = Some(
-                                        <Union as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    );
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
-
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `A` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
-
-                    let data = Self { u: var_1 };
-                    ::std::result::Result::Ok(data)
+                    __protocol.read_empty_struct().await?;
+                    ::std::result::Result::Ok(Self {})
                 })
             }
 
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "A" })
-                    + self
-                        .u
-                        .as_ref()
-                        .map_or(0, |value| __protocol.struct_field_len(Some(1), value))
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
+                __protocol
+                    .empty_struct_len(&::pilota::thrift::TStructIdentifier { name: "Default" })
             }
         }
     }
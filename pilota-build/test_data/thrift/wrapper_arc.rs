@@ -12,10 +12,99 @@ pub mod wrapper_arc {
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "A" };
+                __protocol
+                    .write_empty_struct(&::pilota::thrift::TStructIdentifier { name: "A" })?;
+                ::std::result::Result::Ok(())
+            }
 
-                __protocol.write_struct_begin(&struct_ident)?;
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                __protocol.read_empty_struct()?;
+                ::std::result::Result::Ok(Self {})
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    __protocol.read_empty_struct().await?;
+                    ::std::result::Result::Ok(Self {})
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.empty_struct_len(&::pilota::thrift::TStructIdentifier { name: "A" })
+            }
+        }
 
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct Test {
+            pub id: ::pilota::FastStr,
+
+            pub name2: ::std::vec::Vec<::std::vec::Vec<::std::sync::Arc<A>>>,
+
+            pub name3: ::pilota::AHashMap<i32, ::std::vec::Vec<::std::sync::Arc<A>>>,
+        }
+        impl ::pilota::thrift::Message for Test {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Test" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.id).clone())?;
+                __protocol.write_list_field(
+                    2,
+                    ::pilota::thrift::TType::List,
+                    &&self.name2,
+                    |__protocol, val| {
+                        __protocol.write_list(
+                            ::pilota::thrift::TType::Struct,
+                            &val,
+                            |__protocol, val| {
+                                __protocol.write_struct(val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                __protocol.write_map_field(
+                    3,
+                    ::pilota::thrift::TType::I32,
+                    ::pilota::thrift::TType::List,
+                    &&self.name3,
+                    |__protocol, key| {
+                        __protocol.write_i32(*key)?;
+                        ::std::result::Result::Ok(())
+                    },
+                    |__protocol, val| {
+                        __protocol.write_list(
+                            ::pilota::thrift::TType::Struct,
+                            &val,
+                            |__protocol, val| {
+                                __protocol.write_struct(val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
                 __protocol.write_field_stop()?;
                 __protocol.write_struct_end()?;
                 ::std::result::Result::Ok(())
@@ -27,6 +116,10 @@ pub mod wrapper_arc {
                 #[allow(unused_imports)]
                 use ::pilota::{Buf, thrift::TLengthProtocolExt};
 
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+
                 let mut __pilota_decoding_field_id = None;
 
                 __protocol.read_struct_begin()?;
@@ -41,6 +134,55 @@ pub mod wrapper_arc {
                         }
                         __pilota_decoding_field_id = field_ident.id;
                         match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_2 = Some({
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push({
+                                            let list_ident = __protocol.read_list_begin()?;
+                                            let mut val =
+                                                ::std::vec::Vec::with_capacity(list_ident.size);
+                                            for _ in 0..list_ident.size {
+                                                val.push(::std::sync::Arc::new(
+                                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                                ));
+                                            }
+                                            __protocol.read_list_end()?;
+                                            val
+                                        });
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::Map => {
+                                var_3 = Some({
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                                    for _ in 0..map_ident.size {
+                                        val.insert(__protocol.read_i32()?, {
+                                            let list_ident = __protocol.read_list_begin()?;
+                                            let mut val =
+                                                ::std::vec::Vec::with_capacity(list_ident.size);
+                                            for _ in 0..list_ident.size {
+                                                val.push(::std::sync::Arc::new(
+                                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                                ));
+                                            }
+                                            __protocol.read_list_end()?;
+                                            val
+                                        });
+                                    }
+                                    __protocol.read_map_end()?;
+                                    val
+                                });
+                            }
                             _ => {
                                 __protocol.skip(field_ident.field_type)?;
                             }
@@ -53,7 +195,7 @@ pub mod wrapper_arc {
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
                         err.prepend_msg(&format!(
-                            "decode struct `A` field(#{}) failed, caused by: ",
+                            "decode struct `Test` field(#{}) failed, caused by: ",
                             field_id
                         ));
                     }
@@ -61,7 +203,30 @@ pub mod wrapper_arc {
                 };
                 __protocol.read_struct_end()?;
 
-                let data = Self {};
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field id is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field name2 is required".to_string(),
+                    ));
+                };
+                let Some(var_3) = var_3 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field name3 is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    id: var_1,
+                    name2: var_2,
+                    name3: var_3,
+                };
                 ::std::result::Result::Ok(data)
             }
 
@@ -76,40 +241,121 @@ pub mod wrapper_arc {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+
                     let mut __pilota_decoding_field_id = None;
 
                     __protocol.read_struct_begin().await?;
                     if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
+                    loop {
 
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `A` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
+
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
+                    var_1 = Some(__protocol.read_faststr().await?);
+
+                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+
+                    var_2 = Some({
+                        let list_ident = __protocol.read_list_begin().await?;
+                        let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                        for _ in 0..list_ident.size {
+                            val.push({
+                        let list_ident = __protocol.read_list_begin().await?;
+                        let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                        for _ in 0..list_ident.size {
+                            val.push(::std::sync::Arc::new(<A as ::pilota::thrift::Message>::decode_async(__protocol).await?));
+                        };
+                        __protocol.read_list_end().await?;
+                        val
+                    });
+                        };
+                        __protocol.read_list_end().await?;
+                        val
+                    });
+
+                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+
+                    var_3 = Some({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        for _ in 0..map_ident.size {
+                            val.insert(__protocol.read_i32().await?, {
+                        let list_ident = __protocol.read_list_begin().await?;
+                        let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                        for _ in 0..list_ident.size {
+                            val.push(::std::sync::Arc::new(<A as ::pilota::thrift::Message>::decode_async(__protocol).await?));
+                        };
+                        __protocol.read_list_end().await?;
+                        val
+                    });
                         }
-                        return ::std::result::Result::Err(err);
-                    };
+                        __protocol.read_map_end().await?;
+                        val
+                    });
+
+                },
+                    _ => {
+
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_msg(&format!("decode struct `Test` field(#{}) failed, caused by: ", field_id));
+                }
+                return ::std::result::Result::Err(err);
+            };
                     __protocol.read_struct_end().await?;
 
-                    let data = Self {};
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name2 is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_3) = var_3 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name3 is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        id: var_1,
+                        name2: var_2,
+                        name3: var_3,
+                    };
                     ::std::result::Result::Ok(data)
                 })
             }
@@ -117,25 +363,49 @@ pub mod wrapper_arc {
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "A" })
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Test" })
+                    + __protocol.faststr_field_len(Some(1), &self.id)
+                    + __protocol.list_field_len(
+                        Some(2),
+                        ::pilota::thrift::TType::List,
+                        &self.name2,
+                        |__protocol, el| {
+                            __protocol.list_len(
+                                ::pilota::thrift::TType::Struct,
+                                el,
+                                |__protocol, el| __protocol.struct_len(el),
+                            )
+                        },
+                    )
+                    + __protocol.map_field_len(
+                        Some(3),
+                        ::pilota::thrift::TType::I32,
+                        ::pilota::thrift::TType::List,
+                        &self.name3,
+                        |__protocol, key| __protocol.i32_len(*key),
+                        |__protocol, val| {
+                            __protocol.list_len(
+                                ::pilota::thrift::TType::Struct,
+                                val,
+                                |__protocol, el| __protocol.struct_len(el),
+                            )
+                        },
+                    )
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-
-        pub trait TestService {}
-
-        impl ::std::default::Default for TestServiceTest2ResultRecv {
+        impl ::std::default::Default for TestServiceTestResultRecv {
             fn default() -> Self {
-                TestServiceTest2ResultRecv::Ok(::std::default::Default::default())
+                TestServiceTestResultRecv::Ok(::std::default::Default::default())
             }
         }
         #[derive(Debug, Clone, PartialEq)]
-        pub enum TestServiceTest2ResultRecv {
+        pub enum TestServiceTestResultRecv {
             Ok(::std::sync::Arc<Test>),
         }
 
-        impl ::pilota::thrift::Message for TestServiceTest2ResultRecv {
+        impl ::pilota::thrift::Message for TestServiceTestResultRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -143,10 +413,10 @@ pub mod wrapper_arc {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTest2ResultRecv",
+                    name: "TestServiceTestResultRecv",
                 })?;
                 match self {
-                    TestServiceTest2ResultRecv::Ok(value) => {
+                    TestServiceTestResultRecv::Ok(value) => {
                         __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
                     }
                 }
@@ -177,7 +447,7 @@ pub mod wrapper_arc {
                                     ::pilota::thrift::Message::decode(__protocol)?,
                                 );
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(TestServiceTest2ResultRecv::Ok(field_ident));
+                                ret = Some(TestServiceTestResultRecv::Ok(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -233,7 +503,7 @@ pub mod wrapper_arc {
                                         .await?,
                                     );
 
-                                    ret = Some(TestServiceTest2ResultRecv::Ok(field_ident));
+                                    ret = Some(TestServiceTestResultRecv::Ok(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -265,26 +535,26 @@ pub mod wrapper_arc {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTest2ResultRecv",
+                    name: "TestServiceTestResultRecv",
                 }) + match self {
-                    TestServiceTest2ResultRecv::Ok(value) => {
+                    TestServiceTestResultRecv::Ok(value) => {
                         __protocol.struct_field_len(Some(0), value)
                     }
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for TestServiceTestResultRecv {
+        impl ::std::default::Default for TestServiceTestResultSend {
             fn default() -> Self {
-                TestServiceTestResultRecv::Ok(::std::default::Default::default())
+                TestServiceTestResultSend::Ok(::std::default::Default::default())
             }
         }
         #[derive(Debug, Clone, PartialEq)]
-        pub enum TestServiceTestResultRecv {
+        pub enum TestServiceTestResultSend {
             Ok(::std::sync::Arc<Test>),
         }
 
-        impl ::pilota::thrift::Message for TestServiceTestResultRecv {
+        impl ::pilota::thrift::Message for TestServiceTestResultSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -292,10 +562,10 @@ pub mod wrapper_arc {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTestResultRecv",
+                    name: "TestServiceTestResultSend",
                 })?;
                 match self {
-                    TestServiceTestResultRecv::Ok(value) => {
+                    TestServiceTestResultSend::Ok(value) => {
                         __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
                     }
                 }
@@ -326,7 +596,7 @@ pub mod wrapper_arc {
                                     ::pilota::thrift::Message::decode(__protocol)?,
                                 );
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(TestServiceTestResultRecv::Ok(field_ident));
+                                ret = Some(TestServiceTestResultSend::Ok(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -382,7 +652,7 @@ pub mod wrapper_arc {
                                         .await?,
                                     );
 
-                                    ret = Some(TestServiceTestResultRecv::Ok(field_ident));
+                                    ret = Some(TestServiceTestResultSend::Ok(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -414,9 +684,9 @@ pub mod wrapper_arc {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTestResultRecv",
+                    name: "TestServiceTestResultSend",
                 }) + match self {
-                    TestServiceTestResultRecv::Ok(value) => {
+                    TestServiceTestResultSend::Ok(value) => {
                         __protocol.struct_field_len(Some(0), value)
                     }
                 } + __protocol.field_stop_len()
@@ -424,10 +694,10 @@ pub mod wrapper_arc {
             }
         }
         #[derive(Debug, Default, Clone, PartialEq)]
-        pub struct TestServiceTest2ArgsRecv {
+        pub struct TestServiceTestArgsSend {
             pub req: ::std::sync::Arc<Test>,
         }
-        impl ::pilota::thrift::Message for TestServiceTest2ArgsRecv {
+        impl ::pilota::thrift::Message for TestServiceTestArgsSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -435,7 +705,7 @@ pub mod wrapper_arc {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTest2ArgsRecv",
+                    name: "TestServiceTestArgsSend",
                 };
 
                 __protocol.write_struct_begin(&struct_ident)?;
@@ -485,178 +755,21 @@ pub mod wrapper_arc {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `TestServiceTest2ArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_msg(&format!("decode struct `TestServiceTestArgsSend` field(#{}) failed, caused by: ", field_id));
                     }
                     return ::std::result::Result::Err(err);
-                };
-                __protocol.read_struct_end()?;
-
-                let Some(var_1) = var_1 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field req is required".to_string(),
-                    ));
-                };
-
-                let data = Self { req: var_1 };
-                ::std::result::Result::Ok(data)
-            }
-
-            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
-                __protocol: &'a mut T,
-            ) -> ::std::pin::Pin<
-                ::std::boxed::Box<
-                    dyn ::std::future::Future<
-                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
-                        > + Send
-                        + 'a,
-                >,
-            > {
-                ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
-
-                    let mut __pilota_decoding_field_id = None;
-
-                    __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type
-                                        == ::pilota::thrift::TType::Struct =>
-                                {
-                                    var_1 = Some(::std::sync::Arc::new(
-                                        <Test as ::pilota::thrift::Message>::decode_async(
-                                            __protocol,
-                                        )
-                                        .await?,
-                                    ));
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
-                            }
-
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestServiceTest2ArgsRecv` field(#{}) failed, caused by: ", field_id));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
-                    __protocol.read_struct_end().await?;
-
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field req is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self { req: var_1 };
-                    ::std::result::Result::Ok(data)
-                })
-            }
-
-            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTest2ArgsRecv",
-                }) + __protocol.struct_field_len(Some(1), &self.req)
-                    + __protocol.field_stop_len()
-                    + __protocol.struct_end_len()
-            }
-        }
-        impl ::std::default::Default for TestServiceTest2ResultSend {
-            fn default() -> Self {
-                TestServiceTest2ResultSend::Ok(::std::default::Default::default())
-            }
-        }
-        #[derive(Debug, Clone, PartialEq)]
-        pub enum TestServiceTest2ResultSend {
-            Ok(::std::sync::Arc<Test>),
-        }
-
-        impl ::pilota::thrift::Message for TestServiceTest2ResultSend {
-            fn encode<T: ::pilota::thrift::TOutputProtocol>(
-                &self,
-                __protocol: &mut T,
-            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::thrift::TOutputProtocolExt;
-                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTest2ResultSend",
-                })?;
-                match self {
-                    TestServiceTest2ResultSend::Ok(value) => {
-                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
-                    }
-                }
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
-
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-                let mut ret = None;
-                __protocol.read_struct_begin()?;
-                loop {
-                    let field_ident = __protocol.read_field_begin()?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        __protocol.field_stop_len();
-                        break;
-                    } else {
-                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                    }
-                    match field_ident.id {
-                        Some(0) => {
-                            if ret.is_none() {
-                                let field_ident = ::std::sync::Arc::new(
-                                    ::pilota::thrift::Message::decode(__protocol)?,
-                                );
-                                __protocol.struct_len(&field_ident);
-                                ret = Some(TestServiceTest2ResultSend::Ok(field_ident));
-                            } else {
-                                return ::std::result::Result::Err(
-                                    ::pilota::thrift::new_protocol_exception(
-                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                        "received multiple fields for union from remote Message",
-                                    ),
-                                );
-                            }
-                        }
-                        _ => {
-                            __protocol.skip(field_ident.field_type)?;
-                        }
-                    }
-                }
-                __protocol.read_field_end()?;
+                };
                 __protocol.read_struct_end()?;
-                if let Some(ret) = ret {
-                    ::std::result::Result::Ok(ret)
-                } else {
-                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "received empty union from remote Message",
-                    ))
-                }
+                        "field req is required".to_string(),
+                    ));
+                };
+
+                let data = Self { req: var_1 };
+                ::std::result::Result::Ok(data)
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -670,49 +783,60 @@ pub mod wrapper_arc {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut ret = None;
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
                     __protocol.read_struct_begin().await?;
-                    loop {
-                        let field_ident = __protocol.read_field_begin().await?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            break;
-                        } else {
-                        }
-                        match field_ident.id {
-                            Some(0) => {
-                                if ret.is_none() {
-                                    let field_ident = ::std::sync::Arc::new(
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(::std::sync::Arc::new(
                                         <Test as ::pilota::thrift::Message>::decode_async(
                                             __protocol,
                                         )
                                         .await?,
-                                    );
-
-                                    ret = Some(TestServiceTest2ResultSend::Ok(field_ident));
-                                } else {
-                                    return ::std::result::Result::Err(
-                                        ::pilota::thrift::new_protocol_exception(
-                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                            "received multiple fields for union from remote Message",
-                                        ),
-                                    );
+                                    ));
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
                                 }
                             }
-                            _ => {
-                                __protocol.skip(field_ident.field_type).await?;
-                            }
+
+                            __protocol.read_field_end().await?;
                         }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     }
-                    __protocol.read_field_end().await?;
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(&format!("decode struct `TestServiceTestArgsSend` field(#{}) failed, caused by: ", field_id));
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
                     __protocol.read_struct_end().await?;
-                    if let Some(ret) = ret {
-                        ::std::result::Result::Ok(ret)
-                    } else {
-                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                            "received empty union from remote Message",
-                        ))
-                    }
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field req is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { req: var_1 };
+                    ::std::result::Result::Ok(data)
                 })
             }
 
@@ -720,12 +844,9 @@ pub mod wrapper_arc {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTest2ResultSend",
-                }) + match self {
-                    TestServiceTest2ResultSend::Ok(value) => {
-                        __protocol.struct_field_len(Some(0), value)
-                    }
-                } + __protocol.field_stop_len()
+                    name: "TestServiceTestArgsSend",
+                }) + __protocol.struct_field_len(Some(1), &self.req)
+                    + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
@@ -886,17 +1007,17 @@ pub mod wrapper_arc {
                     + __protocol.struct_end_len()
             }
         }
-        impl ::std::default::Default for TestServiceTestResultSend {
+        impl ::std::default::Default for TestServiceTest2ResultRecv {
             fn default() -> Self {
-                TestServiceTestResultSend::Ok(::std::default::Default::default())
+                TestServiceTest2ResultRecv::Ok(::std::default::Default::default())
             }
         }
         #[derive(Debug, Clone, PartialEq)]
-        pub enum TestServiceTestResultSend {
+        pub enum TestServiceTest2ResultRecv {
             Ok(::std::sync::Arc<Test>),
         }
 
-        impl ::pilota::thrift::Message for TestServiceTestResultSend {
+        impl ::pilota::thrift::Message for TestServiceTest2ResultRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -904,10 +1025,10 @@ pub mod wrapper_arc {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTestResultSend",
+                    name: "TestServiceTest2ResultRecv",
                 })?;
                 match self {
-                    TestServiceTestResultSend::Ok(value) => {
+                    TestServiceTest2ResultRecv::Ok(value) => {
                         __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
                     }
                 }
@@ -938,7 +1059,7 @@ pub mod wrapper_arc {
                                     ::pilota::thrift::Message::decode(__protocol)?,
                                 );
                                 __protocol.struct_len(&field_ident);
-                                ret = Some(TestServiceTestResultSend::Ok(field_ident));
+                                ret = Some(TestServiceTest2ResultRecv::Ok(field_ident));
                             } else {
                                 return ::std::result::Result::Err(
                                     ::pilota::thrift::new_protocol_exception(
@@ -994,7 +1115,7 @@ pub mod wrapper_arc {
                                         .await?,
                                     );
 
-                                    ret = Some(TestServiceTestResultSend::Ok(field_ident));
+                                    ret = Some(TestServiceTest2ResultRecv::Ok(field_ident));
                                 } else {
                                     return ::std::result::Result::Err(
                                         ::pilota::thrift::new_protocol_exception(
@@ -1026,208 +1147,92 @@ pub mod wrapper_arc {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTestResultSend",
+                    name: "TestServiceTest2ResultRecv",
                 }) + match self {
-                    TestServiceTestResultSend::Ok(value) => {
+                    TestServiceTest2ResultRecv::Ok(value) => {
                         __protocol.struct_field_len(Some(0), value)
                     }
                 } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
-
-        #[derive(Debug, Default, Clone, PartialEq)]
-        pub struct Test {
-            pub id: ::pilota::FastStr,
-
-            pub name2: ::std::vec::Vec<::std::vec::Vec<::std::sync::Arc<A>>>,
-
-            pub name3: ::pilota::AHashMap<i32, ::std::vec::Vec<::std::sync::Arc<A>>>,
+        impl ::std::default::Default for TestServiceTest2ResultSend {
+            fn default() -> Self {
+                TestServiceTest2ResultSend::Ok(::std::default::Default::default())
+            }
         }
-        impl ::pilota::thrift::Message for Test {
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum TestServiceTest2ResultSend {
+            Ok(::std::sync::Arc<Test>),
+        }
+
+        impl ::pilota::thrift::Message for TestServiceTest2ResultSend {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
             ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
-                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Test" };
-
-                __protocol.write_struct_begin(&struct_ident)?;
-                __protocol.write_faststr_field(1, (&self.id).clone())?;
-                __protocol.write_list_field(
-                    2,
-                    ::pilota::thrift::TType::List,
-                    &&self.name2,
-                    |__protocol, val| {
-                        __protocol.write_list(
-                            ::pilota::thrift::TType::Struct,
-                            &val,
-                            |__protocol, val| {
-                                __protocol.write_struct(val)?;
-                                ::std::result::Result::Ok(())
-                            },
-                        )?;
-                        ::std::result::Result::Ok(())
-                    },
-                )?;
-                __protocol.write_map_field(
-                    3,
-                    ::pilota::thrift::TType::I32,
-                    ::pilota::thrift::TType::List,
-                    &&self.name3,
-                    |__protocol, key| {
-                        __protocol.write_i32(*key)?;
-                        ::std::result::Result::Ok(())
-                    },
-                    |__protocol, val| {
-                        __protocol.write_list(
-                            ::pilota::thrift::TType::Struct,
-                            &val,
-                            |__protocol, val| {
-                                __protocol.write_struct(val)?;
-                                ::std::result::Result::Ok(())
-                            },
-                        )?;
-                        ::std::result::Result::Ok(())
-                    },
-                )?;
-                __protocol.write_field_stop()?;
-                __protocol.write_struct_end()?;
-                ::std::result::Result::Ok(())
-            }
-
-            fn decode<T: ::pilota::thrift::TInputProtocol>(
-                __protocol: &mut T,
-            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
-                #[allow(unused_imports)]
-                use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-                let mut var_1 = None;
-                let mut var_2 = None;
-                let mut var_3 = None;
-
-                let mut __pilota_decoding_field_id = None;
-
-                __protocol.read_struct_begin()?;
-                if let ::std::result::Result::Err(mut err) = (|| {
-                    loop {
-                        let field_ident = __protocol.read_field_begin()?;
-                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                            __protocol.field_stop_len();
-                            break;
-                        } else {
-                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                        }
-                        __pilota_decoding_field_id = field_ident.id;
-                        match field_ident.id {
-                            Some(1)
-                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
-                            {
-                                var_1 = Some(__protocol.read_faststr()?);
-                            }
-                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::List => {
-                                var_2 = Some(unsafe {
-                                    let list_ident = __protocol.read_list_begin()?;
-                                    let mut val: ::std::vec::Vec<
-                                        ::std::vec::Vec<::std::sync::Arc<A>>,
-                                    > = ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write(unsafe {
-                                            let list_ident = __protocol.read_list_begin()?;
-                                            let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
-                                                ::std::vec::Vec::with_capacity(list_ident.size);
-                                            for i in 0..list_ident.size {
-                                                val.as_mut_ptr().offset(i as isize).write(
-                                                    ::std::sync::Arc::new(
-                                                        ::pilota::thrift::Message::decode(
-                                                            __protocol,
-                                                        )?,
-                                                    ),
-                                                );
-                                            }
-                                            val.set_len(list_ident.size);
-                                            __protocol.read_list_end()?;
-                                            val
-                                        });
-                                    }
-                                    val.set_len(list_ident.size);
-                                    __protocol.read_list_end()?;
-                                    val
-                                });
-                            }
-                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::Map => {
-                                var_3 = Some({
-                                    let map_ident = __protocol.read_map_begin()?;
-                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, unsafe {
-                                            let list_ident = __protocol.read_list_begin()?;
-                                            let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
-                                                ::std::vec::Vec::with_capacity(list_ident.size);
-                                            for i in 0..list_ident.size {
-                                                val.as_mut_ptr().offset(i as isize).write(
-                                                    ::std::sync::Arc::new(
-                                                        ::pilota::thrift::Message::decode(
-                                                            __protocol,
-                                                        )?,
-                                                    ),
-                                                );
-                                            }
-                                            val.set_len(list_ident.size);
-                                            __protocol.read_list_end()?;
-                                            val
-                                        });
-                                    }
-                                    __protocol.read_map_end()?;
-                                    val
-                                });
-                            }
-                            _ => {
-                                __protocol.skip(field_ident.field_type)?;
-                            }
-                        }
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "TestServiceTest2ResultSend",
+                })?;
+                match self {
+                    TestServiceTest2ResultSend::Ok(value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
 
-                        __protocol.read_field_end()?;
-                        __protocol.field_end_len();
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{Buf, thrift::TLengthProtocolExt};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
                     }
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                })() {
-                    if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Test` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::std::sync::Arc::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(TestServiceTest2ResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
                     }
-                    return ::std::result::Result::Err(err);
-                };
+                }
+                __protocol.read_field_end()?;
                 __protocol.read_struct_end()?;
-
-                let Some(var_1) = var_1 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field id is required".to_string(),
-                    ));
-                };
-                let Some(var_2) = var_2 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
-                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field name2 is required".to_string(),
-                    ));
-                };
-                let Some(var_3) = var_3 else {
-                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                         ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                        "field name3 is required".to_string(),
-                    ));
-                };
-
-                let data = Self {
-                    id: var_1,
-                    name2: var_2,
-                    name3: var_3,
-                };
-                ::std::result::Result::Ok(data)
+                        "received empty union from remote Message",
+                    ))
+                }
             }
 
             fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -1241,153 +1246,62 @@ pub mod wrapper_arc {
                 >,
             > {
                 ::std::boxed::Box::pin(async move {
-                    let mut var_1 = None;
-                    let mut var_2 = None;
-                    let mut var_3 = None;
-
-                    let mut __pilota_decoding_field_id = None;
-
+                    let mut ret = None;
                     __protocol.read_struct_begin().await?;
-                    if let ::std::result::Result::Err(mut err) = async {
                     loop {
-
-
-                let field_ident = __protocol.read_field_begin().await?;
-                if field_ident.field_type == ::pilota::thrift::TType::Stop {
-
-                    break;
-                } else {
-
-                }
-                __pilota_decoding_field_id = field_ident.id;
-                match field_ident.id {
-                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
-                    var_1 = Some(__protocol.read_faststr().await?);
-
-                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::List  => {
-                    var_2 = Some({
-                            let list_ident = __protocol.read_list_begin().await?;
-                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
-                                val.push({
-                            let list_ident = __protocol.read_list_begin().await?;
-                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
-                                val.push(::std::sync::Arc::new(<A as ::pilota::thrift::Message>::decode_async(__protocol).await?));
-                            };
-                            __protocol.read_list_end().await?;
-                            val
-                        });
-                            };
-                            __protocol.read_list_end().await?;
-                            val
-                        });
-
-                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
-                    var_3 = Some({
-                        let map_ident = __protocol.read_map_begin().await?;
-                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                        for _ in 0..map_ident.size {
-                            val.insert(__protocol.read_i32().await?, {
-                            let list_ident = __protocol.read_list_begin().await?;
-                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
-                                val.push(::std::sync::Arc::new(<A as ::pilota::thrift::Message>::decode_async(__protocol).await?));
-                            };
-                            __protocol.read_list_end().await?;
-                            val
-                        });
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
                         }
-                        __protocol.read_map_end().await?;
-                        val
-                    });
-
-                },
-                    _ => {
-                        __protocol.skip(field_ident.field_type).await?;
-
-                    },
-                }
-
-                __protocol.read_field_end().await?;
-
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident = ::std::sync::Arc::new(
+                                        <Test as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    );
 
-            };
-                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                }.await {
-                if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `Test` field(#{}) failed, caused by: ", field_id));
-                }
-                return ::std::result::Result::Err(err);
-            };
+                                    ret = Some(TestServiceTest2ResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
                     __protocol.read_struct_end().await?;
-
-                    let Some(var_1) = var_1 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field id is required".to_string(),
-                            ),
-                        );
-                    };
-                    let Some(var_2) = var_2 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field name2 is required".to_string(),
-                            ),
-                        );
-                    };
-                    let Some(var_3) = var_3 else {
-                        return ::std::result::Result::Err(
-                            ::pilota::thrift::new_protocol_exception(
-                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
-                                "field name3 is required".to_string(),
-                            ),
-                        );
-                    };
-
-                    let data = Self {
-                        id: var_1,
-                        name2: var_2,
-                        name3: var_3,
-                    };
-                    ::std::result::Result::Ok(data)
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
                 })
             }
 
             fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
-                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Test" })
-                    + __protocol.faststr_field_len(Some(1), &self.id)
-                    + __protocol.list_field_len(
-                        Some(2),
-                        ::pilota::thrift::TType::List,
-                        &self.name2,
-                        |__protocol, el| {
-                            __protocol.list_len(
-                                ::pilota::thrift::TType::Struct,
-                                el,
-                                |__protocol, el| __protocol.struct_len(el),
-                            )
-                        },
-                    )
-                    + __protocol.map_field_len(
-                        Some(3),
-                        ::pilota::thrift::TType::I32,
-                        ::pilota::thrift::TType::List,
-                        &self.name3,
-                        |__protocol, key| __protocol.i32_len(*key),
-                        |__protocol, val| {
-                            __protocol.list_len(
-                                ::pilota::thrift::TType::Struct,
-                                val,
-                                |__protocol, el| __protocol.struct_len(el),
-                            )
-                        },
-                    )
-                    + __protocol.field_stop_len()
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "TestServiceTest2ResultSend",
+                }) + match self {
+                    TestServiceTest2ResultSend::Ok(value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
@@ -1549,10 +1463,10 @@ pub mod wrapper_arc {
             }
         }
         #[derive(Debug, Default, Clone, PartialEq)]
-        pub struct TestServiceTestArgsSend {
+        pub struct TestServiceTest2ArgsRecv {
             pub req: ::std::sync::Arc<Test>,
         }
-        impl ::pilota::thrift::Message for TestServiceTestArgsSend {
+        impl ::pilota::thrift::Message for TestServiceTest2ArgsRecv {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
                 __protocol: &mut T,
@@ -1560,7 +1474,7 @@ pub mod wrapper_arc {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TOutputProtocolExt;
                 let struct_ident = ::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTestArgsSend",
+                    name: "TestServiceTest2ArgsRecv",
                 };
 
                 __protocol.write_struct_begin(&struct_ident)?;
@@ -1610,7 +1524,7 @@ pub mod wrapper_arc {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `TestServiceTestArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_msg(&format!("decode struct `TestServiceTest2ArgsRecv` field(#{}) failed, caused by: ", field_id));
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -1675,7 +1589,7 @@ pub mod wrapper_arc {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestServiceTestArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_msg(&format!("decode struct `TestServiceTest2ArgsRecv` field(#{}) failed, caused by: ", field_id));
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -1699,11 +1613,13 @@ pub mod wrapper_arc {
                 #[allow(unused_imports)]
                 use ::pilota::thrift::TLengthProtocolExt;
                 __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
-                    name: "TestServiceTestArgsSend",
+                    name: "TestServiceTest2ArgsRecv",
                 }) + __protocol.struct_field_len(Some(1), &self.req)
                     + __protocol.field_stop_len()
                     + __protocol.struct_end_len()
             }
         }
+
+        pub trait TestService {}
     }
 }
@@ -7,12 +7,7 @@ impl ::pilota::thrift::Message for A {
     ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
         #[allow(unused_imports)]
         use ::pilota::thrift::TOutputProtocolExt;
-        let struct_ident = ::pilota::thrift::TStructIdentifier { name: "A" };
-
-        __protocol.write_struct_begin(&struct_ident)?;
-
-        __protocol.write_field_stop()?;
-        __protocol.write_struct_end()?;
+        __protocol.write_empty_struct(&::pilota::thrift::TStructIdentifier { name: "A" })?;
         ::std::result::Result::Ok(())
     }
 
@@ -21,43 +16,8 @@ impl ::pilota::thrift::Message for A {
     ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
         #[allow(unused_imports)]
         use ::pilota::{Buf, thrift::TLengthProtocolExt};
-
-        let mut __pilota_decoding_field_id = None;
-
-        __protocol.read_struct_begin()?;
-        if let ::std::result::Result::Err(mut err) = (|| {
-            loop {
-                let field_ident = __protocol.read_field_begin()?;
-                if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                    __protocol.field_stop_len();
-                    break;
-                } else {
-                    __protocol.field_begin_len(field_ident.field_type, field_ident.id);
-                }
-                __pilota_decoding_field_id = field_ident.id;
-                match field_ident.id {
-                    _ => {
-                        __protocol.skip(field_ident.field_type)?;
-                    }
-                }
-
-                __protocol.read_field_end()?;
-                __protocol.field_end_len();
-            }
-            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-        })() {
-            if let Some(field_id) = __pilota_decoding_field_id {
-                err.prepend_msg(&format!(
-                    "decode struct `A` field(#{}) failed, caused by: ",
-                    field_id
-                ));
-            }
-            return ::std::result::Result::Err(err);
-        };
-        __protocol.read_struct_end()?;
-
-        let data = Self {};
-        ::std::result::Result::Ok(data)
+        __protocol.read_empty_struct()?;
+        ::std::result::Result::Ok(Self {})
     }
 
     fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
@@ -71,49 +31,14 @@ impl ::pilota::thrift::Message for A {
         >,
     > {
         ::std::boxed::Box::pin(async move {
-            let mut __pilota_decoding_field_id = None;
-
-            __protocol.read_struct_begin().await?;
-            if let ::std::result::Result::Err(mut err) = async {
-                loop {
-                    let field_ident = __protocol.read_field_begin().await?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        break;
-                    } else {
-                    }
-                    __pilota_decoding_field_id = field_ident.id;
-                    match field_ident.id {
-                        _ => {
-                            __protocol.skip(field_ident.field_type).await?;
-                        }
-                    }
-
-                    __protocol.read_field_end().await?;
-                }
-                ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-            }
-            .await
-            {
-                if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!(
-                        "decode struct `A` field(#{}) failed, caused by: ",
-                        field_id
-                    ));
-                }
-                return ::std::result::Result::Err(err);
-            };
-            __protocol.read_struct_end().await?;
-
-            let data = Self {};
-            ::std::result::Result::Ok(data)
+            __protocol.read_empty_struct().await?;
+            ::std::result::Result::Ok(Self {})
         })
     }
 
     fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
         #[allow(unused_imports)]
         use ::pilota::thrift::TLengthProtocolExt;
-        __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "A" })
-            + __protocol.field_stop_len()
-            + __protocol.struct_end_len()
+        __protocol.empty_struct_len(&::pilota::thrift::TStructIdentifier { name: "A" })
     }
 }
@@ -87,28 +87,22 @@ impl ::pilota::thrift::Message for Test {
                         var_1 = Some(__protocol.read_faststr()?);
                     }
                     Some(2) if field_ident.field_type == ::pilota::thrift::TType::List => {
-                        var_2 = Some(unsafe {
+                        var_2 = Some({
                             let list_ident = __protocol.read_list_begin()?;
-                            let mut val: ::std::vec::Vec<::std::vec::Vec<::std::sync::Arc<A>>> =
-                                ::std::vec::Vec::with_capacity(list_ident.size);
-                            for i in 0..list_ident.size {
-                                val.as_mut_ptr().offset(i as isize).write(unsafe {
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            for _ in 0..list_ident.size {
+                                val.push({
                                     let list_ident = __protocol.read_list_begin()?;
-                                    let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
-                                        ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write(
-                                            ::std::sync::Arc::new(
-                                                ::pilota::thrift::Message::decode(__protocol)?,
-                                            ),
-                                        );
+                                    let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::std::sync::Arc::new(
+                                            ::pilota::thrift::Message::decode(__protocol)?,
+                                        ));
                                     }
-                                    val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
                                     val
                                 });
                             }
-                            val.set_len(list_ident.size);
                             __protocol.read_list_end()?;
                             val
                         });
@@ -118,18 +112,14 @@ impl ::pilota::thrift::Message for Test {
                             let map_ident = __protocol.read_map_begin()?;
                             let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
                             for _ in 0..map_ident.size {
-                                val.insert(__protocol.read_i32()?, unsafe {
+                                val.insert(__protocol.read_i32()?, {
                                     let list_ident = __protocol.read_list_begin()?;
-                                    let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
-                                        ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write(
-                                            ::std::sync::Arc::new(
-                                                ::pilota::thrift::Message::decode(__protocol)?,
-                                            ),
-                                        );
+                                    let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::std::sync::Arc::new(
+                                            ::pilota::thrift::Message::decode(__protocol)?,
+                                        ));
                                     }
-                                    val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
                                     val
                                 });
@@ -15,11 +15,11 @@ pub mod r#gen {
                 self.0
             }
 
-            pub fn to_string(&self) -> ::std::string::String {
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
                 match self {
-                    Self(0) => ::std::string::String::from("NORMAL"),
-                    Self(1) => ::std::string::String::from("DELETED"),
-                    Self(val) => val.to_string(),
+                    Self(0) => ::std::option::Option::Some("NORMAL"),
+                    Self(1) => ::std::option::Option::Some("DELETED"),
+                    Self(_) => ::std::option::Option::None,
                 }
             }
 
@@ -44,6 +44,29 @@ pub mod r#gen {
             }
         }
 
+        impl ::std::fmt::Display for Status {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => ::std::fmt::Display::fmt(&self.inner(), f),
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for Status {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "NORMAL" => ::std::result::Result::Ok(Self::NORMAL),
+                    "DELETED" => ::std::result::Result::Ok(Self::DELETED),
+                    _ => s.parse::<i32>().map(Self::from).map_err(|_| {
+                        ::pilota::EnumConvertError::InvalidNum(s.to_string(), "Status")
+                    }),
+                }
+            }
+        }
+
         impl ::pilota::thrift::Message for Status {
             fn encode<T: ::pilota::thrift::TOutputProtocol>(
                 &self,
@@ -207,10 +230,12 @@ pub mod r#gen {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_1 = Some(<GetArticleRequest as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -664,10 +689,12 @@ pub mod r#gen {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_1 = Some(<GetArticleRequest as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -1110,19 +1137,14 @@ pub mod r#gen {
                                 var_5 = Some(::pilota::thrift::Message::decode(__protocol)?);
                             }
                             Some(6) if field_ident.field_type == ::pilota::thrift::TType::List => {
-                                var_6 = Some(unsafe {
+                                var_6 = Some({
                                     let list_ident = __protocol.read_list_begin()?;
-                                    let mut val: ::std::vec::Vec<
-                                        ::std::sync::Arc<::common::article::image::Image>,
-                                    > = ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write(
-                                            ::std::sync::Arc::new(
-                                                ::pilota::thrift::Message::decode(__protocol)?,
-                                            ),
-                                        );
+                                    let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                                    for _ in 0..list_ident.size {
+                                        val.push(::std::sync::Arc::new(
+                                            ::pilota::thrift::Message::decode(__protocol)?,
+                                        ));
                                     }
-                                    val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
                                     val
                                 });
@@ -1243,36 +1265,44 @@ pub mod r#gen {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+
                     var_1 = Some(__protocol.read_i64().await?);
 
                 },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
                     var_2 = Some(__protocol.read_faststr().await?);
 
                 },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
                     var_3 = Some(__protocol.read_faststr().await?);
 
                 },Some(4) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_4 = Some(<::common::author::Author as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },Some(5) if field_ident.field_type == ::pilota::thrift::TType::I32  => {
+
                     var_5 = Some(<Status as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },Some(6) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+
                     var_6 = Some({
-                            let list_ident = __protocol.read_list_begin().await?;
-                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
-                                val.push(::std::sync::Arc::new(<::common::article::image::Image as ::pilota::thrift::Message>::decode_async(__protocol).await?));
-                            };
-                            __protocol.read_list_end().await?;
-                            val
-                        });
+                        let list_ident = __protocol.read_list_begin().await?;
+                        let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                        for _ in 0..list_ident.size {
+                            val.push(::std::sync::Arc::new(<::common::article::image::Image as ::pilota::thrift::Message>::decode_async(__protocol).await?));
+                        };
+                        __protocol.read_list_end().await?;
+                        val
+                    });
 
                 },Some(7) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_7 = Some(<::common::common::CommonData as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
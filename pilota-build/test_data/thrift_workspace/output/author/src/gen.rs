@@ -254,10 +254,12 @@ pub mod r#gen {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_1 = Some(<GetAuthorRequest as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -553,10 +555,12 @@ pub mod r#gen {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_1 = Some(<::common::author::Author as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -706,10 +710,12 @@ pub mod r#gen {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_1 = Some(<GetAuthorRequest as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
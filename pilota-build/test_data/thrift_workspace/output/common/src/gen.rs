@@ -185,19 +185,24 @@ pub mod r#gen {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+
                     var_1 = Some(__protocol.read_i64().await?);
 
                 },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
                     var_2 = Some(__protocol.read_faststr().await?);
 
                 },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_3 = Some(<cdn::Cdn as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },Some(4) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_4 = Some(<super::super::common::CommonData as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -438,16 +443,20 @@ pub mod r#gen {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+
                     var_1 = Some(__protocol.read_i64().await?);
 
                 },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
                     var_2 = Some(__protocol.read_faststr().await?);
 
                 },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_3 = Some(<super::super::super::common::CommonData as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -685,19 +694,24 @@ pub mod r#gen {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+
                     var_1 = Some(__protocol.read_i64().await?);
 
                 },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
                     var_2 = Some(__protocol.read_faststr().await?);
 
                 },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
                     var_3 = Some(__protocol.read_faststr().await?);
 
                 },Some(5) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_5 = Some(<super::common::CommonData as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
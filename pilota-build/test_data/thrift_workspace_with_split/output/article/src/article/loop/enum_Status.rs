@@ -10,11 +10,11 @@ impl Status {
         self.0
     }
 
-    pub fn to_string(&self) -> ::std::string::String {
+    pub fn as_str(&self) -> ::std::option::Option<&'static str> {
         match self {
-            Self(0) => ::std::string::String::from("NORMAL"),
-            Self(1) => ::std::string::String::from("DELETED"),
-            Self(val) => val.to_string(),
+            Self(0) => ::std::option::Option::Some("NORMAL"),
+            Self(1) => ::std::option::Option::Some("DELETED"),
+            Self(_) => ::std::option::Option::None,
         }
     }
 
@@ -39,6 +39,30 @@ impl ::std::convert::From<Status> for i32 {
     }
 }
 
+impl ::std::fmt::Display for Status {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self.as_str() {
+            ::std::option::Option::Some(s) => f.write_str(s),
+            ::std::option::Option::None => ::std::fmt::Display::fmt(&self.inner(), f),
+        }
+    }
+}
+
+impl ::std::str::FromStr for Status {
+    type Err = ::pilota::EnumConvertError<::std::string::String>;
+
+    fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+        match s {
+            "NORMAL" => ::std::result::Result::Ok(Self::NORMAL),
+            "DELETED" => ::std::result::Result::Ok(Self::DELETED),
+            _ => s
+                .parse::<i32>()
+                .map(Self::from)
+                .map_err(|_| ::pilota::EnumConvertError::InvalidNum(s.to_string(), "Status")),
+        }
+    }
+}
+
 impl ::pilota::thrift::Message for Status {
     fn encode<T: ::pilota::thrift::TOutputProtocol>(
         &self,
@@ -182,25 +182,32 @@ impl ::pilota::thrift::Message for Article {
                 __pilota_decoding_field_id = field_ident.id;
                 match field_ident.id {
                     Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+
                     var_1 = Some(__protocol.read_i64().await?);
 
                 },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
                     var_2 = Some(__protocol.read_faststr().await?);
 
                 },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+
                     var_3 = Some(__protocol.read_faststr().await?);
 
                 },Some(4) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_4 = Some(<::common::author::Author as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },Some(5) if field_ident.field_type == ::pilota::thrift::TType::I32  => {
+
                     var_5 = Some(<Status as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },Some(7) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+
                     var_7 = Some(<::common::common::CommonData as ::pilota::thrift::Message>::decode_async(__protocol).await?);
 
                 },
                     _ => {
+
                         __protocol.skip(field_ident.field_type).await?;
 
                     },
@@ -0,0 +1,983 @@
+pub mod typed_uuid {
+    #![allow(warnings, clippy::all)]
+    pub mod typed_uuid {
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct Session {
+            pub id: ::pilota::uuid::Uuid,
+            pub parent: ::std::option::Option<::pilota::uuid::Uuid>,
+            pub members: ::std::vec::Vec<::pilota::uuid::Uuid>,
+            pub owners: ::std::option::Option<
+                ::pilota::AHashMap<::pilota::FastStr, ::pilota::uuid::Uuid>,
+            >,
+        }
+        impl ::pilota::thrift::Message for Session {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "Session",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_uuid_field(1, (*&self.id).into_bytes())?;
+                if let Some(value) = self.parent.as_ref() {
+                    __protocol.write_uuid_field(2, (*value).into_bytes())?;
+                }
+                __protocol
+                    .write_list_field(
+                        3,
+                        ::pilota::thrift::TType::Uuid,
+                        &&self.members,
+                        |__protocol, val| {
+                            __protocol.write_uuid_typed(*val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                if let Some(value) = self.owners.as_ref() {
+                    __protocol
+                        .write_map_field(
+                            4,
+                            ::pilota::thrift::TType::Binary,
+                            ::pilota::thrift::TType::Uuid,
+                            &value,
+                            |__protocol, key| {
+                                __protocol.write_faststr((key).clone())?;
+                                ::std::result::Result::Ok(())
+                            },
+                            |__protocol, val| {
+                                __protocol.write_uuid_typed(*val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Uuid => {
+                                var_1 = Some(__protocol.read_uuid_typed()?);
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Uuid => {
+                                var_2 = Some(__protocol.read_uuid_typed()?);
+                            }
+                            Some(
+                                3,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::List => {
+                                var_3 = Some({
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(
+                                        list_ident.size,
+                                    );
+                                    for _ in 0..list_ident.size {
+                                        val.push(__protocol.read_uuid_typed()?);
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(
+                                4,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Map => {
+                                var_4 = Some({
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::pilota::AHashMap::with_capacity(
+                                        map_ident.size,
+                                    );
+                                    for _ in 0..map_ident.size {
+                                        val.insert(
+                                            __protocol.read_faststr()?,
+                                            __protocol.read_uuid_typed()?,
+                                        );
+                                    }
+                                    __protocol.read_map_end()?;
+                                    val
+                                });
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `Session` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    )
+                };
+                let Some(var_3) = var_3 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field members is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self {
+                    id: var_1,
+                    parent: var_2,
+                    members: var_3,
+                    owners: var_4,
+                };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Uuid => {
+                                    var_1 = Some(__protocol.read_uuid_typed().await?);
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Uuid => {
+                                    var_2 = Some(__protocol.read_uuid_typed().await?);
+                                }
+                                Some(
+                                    3,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::List => {
+                                    var_3 = Some({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val = ::std::vec::Vec::with_capacity(
+                                            list_ident.size,
+                                        );
+                                        for _ in 0..list_ident.size {
+                                            val.push(__protocol.read_uuid_typed().await?);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(
+                                    4,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Map => {
+                                    var_4 = Some({
+                                        let map_ident = __protocol.read_map_begin().await?;
+                                        let mut val = ::pilota::AHashMap::with_capacity(
+                                            map_ident.size,
+                                        );
+                                        for _ in 0..map_ident.size {
+                                            val.insert(
+                                                __protocol.read_faststr().await?,
+                                                __protocol.read_uuid_typed().await?,
+                                            );
+                                        }
+                                        __protocol.read_map_end().await?;
+                                        val
+                                    });
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `Session` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        )
+                    };
+                    let Some(var_3) = var_3 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field members is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self {
+                        id: var_1,
+                        parent: var_2,
+                        members: var_3,
+                        owners: var_4,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Session",
+                        },
+                    ) + __protocol.uuid_field_len(Some(1), (*&self.id).into_bytes())
+                    + self
+                        .parent
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol.uuid_field_len(Some(2), (*value).into_bytes())
+                            },
+                        )
+                    + __protocol
+                        .list_field_len(
+                            Some(3),
+                            ::pilota::thrift::TType::Uuid,
+                            &self.members,
+                            |__protocol, el| { __protocol.uuid_len((*el).into_bytes()) },
+                        )
+                    + self
+                        .owners
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol
+                                    .map_field_len(
+                                        Some(4),
+                                        ::pilota::thrift::TType::Binary,
+                                        ::pilota::thrift::TType::Uuid,
+                                        value,
+                                        |__protocol, key| { __protocol.faststr_len(key) },
+                                        |__protocol, val| {
+                                            __protocol.uuid_len((*val).into_bytes())
+                                        },
+                                    )
+                            },
+                        ) + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for SessionServiceLookupResultRecv {
+            fn default() -> Self {
+                SessionServiceLookupResultRecv::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Clone, PartialEq)]
+        pub enum SessionServiceLookupResultRecv {
+            Ok(::pilota::uuid::Uuid),
+        }
+        impl ::pilota::thrift::Message for SessionServiceLookupResultRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "SessionServiceLookupResultRecv",
+                        },
+                    )?;
+                match self {
+                    SessionServiceLookupResultRecv::Ok(value) => {
+                        __protocol.write_uuid_field(0, (*value).into_bytes())?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol
+                            .field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_uuid_typed()?;
+                                __protocol.uuid_len((*&field_ident).into_bytes());
+                                ret = Some(SessionServiceLookupResultRecv::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ),
+                    )
+                }
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {}
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_uuid_typed().await?;
+                                    ret = Some(SessionServiceLookupResultRecv::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ),
+                        )
+                    }
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "SessionServiceLookupResultRecv",
+                        },
+                    )
+                    + match self {
+                        SessionServiceLookupResultRecv::Ok(value) => {
+                            __protocol.uuid_field_len(Some(0), (*value).into_bytes())
+                        }
+                    } + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for SessionServiceLookupResultSend {
+            fn default() -> Self {
+                SessionServiceLookupResultSend::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Clone, PartialEq)]
+        pub enum SessionServiceLookupResultSend {
+            Ok(::pilota::uuid::Uuid),
+        }
+        impl ::pilota::thrift::Message for SessionServiceLookupResultSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "SessionServiceLookupResultSend",
+                        },
+                    )?;
+                match self {
+                    SessionServiceLookupResultSend::Ok(value) => {
+                        __protocol.write_uuid_field(0, (*value).into_bytes())?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol
+                            .field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_uuid_typed()?;
+                                __protocol.uuid_len((*&field_ident).into_bytes());
+                                ret = Some(SessionServiceLookupResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ),
+                    )
+                }
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {}
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_uuid_typed().await?;
+                                    ret = Some(SessionServiceLookupResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ),
+                        )
+                    }
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "SessionServiceLookupResultSend",
+                        },
+                    )
+                    + match self {
+                        SessionServiceLookupResultSend::Ok(value) => {
+                            __protocol.uuid_field_len(Some(0), (*value).into_bytes())
+                        }
+                    } + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct SessionServiceLookupArgsSend {
+            pub id: ::pilota::uuid::Uuid,
+        }
+        impl ::pilota::thrift::Message for SessionServiceLookupArgsSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "SessionServiceLookupArgsSend",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_uuid_field(1, (*&self.id).into_bytes())?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Uuid => {
+                                var_1 = Some(__protocol.read_uuid_typed()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `SessionServiceLookupArgsSend` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { id: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Uuid => {
+                                    var_1 = Some(__protocol.read_uuid_typed().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `SessionServiceLookupArgsSend` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { id: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "SessionServiceLookupArgsSend",
+                        },
+                    ) + __protocol.uuid_field_len(Some(1), (*&self.id).into_bytes())
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct SessionServiceLookupArgsRecv {
+            pub id: ::pilota::uuid::Uuid,
+        }
+        impl ::pilota::thrift::Message for SessionServiceLookupArgsRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "SessionServiceLookupArgsRecv",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_uuid_field(1, (*&self.id).into_bytes())?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Uuid => {
+                                var_1 = Some(__protocol.read_uuid_typed()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `SessionServiceLookupArgsRecv` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field id is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { id: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Uuid => {
+                                    var_1 = Some(__protocol.read_uuid_typed().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `SessionServiceLookupArgsRecv` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { id: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "SessionServiceLookupArgsRecv",
+                        },
+                    ) + __protocol.uuid_field_len(Some(1), (*&self.id).into_bytes())
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        pub trait SessionService {}
+    }
+}
@@ -0,0 +1,10 @@
+struct Session {
+    1: required uuid id,
+    2: optional uuid parent,
+    3: required list<uuid> members,
+    4: optional map<string, uuid> owners,
+}
+
+service SessionService {
+    uuid Lookup(1: uuid id),
+}
@@ -0,0 +1,517 @@
+pub mod union_policy {
+    #![allow(warnings, clippy::all)]
+    pub mod union_policy {
+        impl ::std::default::Default for Choice {
+            fn default() -> Self {
+                Choice::Name(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Clone, PartialEq)]
+        pub enum Choice {
+            Name(::pilota::FastStr),
+            Id(i64),
+        }
+        impl ::pilota::thrift::Message for Choice {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Choice",
+                        },
+                    )?;
+                match self {
+                    Choice::Name(value) => {
+                        __protocol.write_faststr_field(1, (value).clone())?;
+                    }
+                    Choice::Id(value) => {
+                        __protocol.write_i64_field(2, *value)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol
+                            .field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            let field_ident = __protocol.read_faststr()?;
+                            __protocol.faststr_len(&field_ident);
+                            ret = Some(Choice::Name(field_ident));
+                        }
+                        Some(2) => {
+                            let field_ident = __protocol.read_i64()?;
+                            __protocol.i64_len(*&field_ident);
+                            ret = Some(Choice::Id(field_ident));
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ),
+                    )
+                }
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {}
+                        match field_ident.id {
+                            Some(1) => {
+                                let field_ident = __protocol.read_faststr().await?;
+                                ret = Some(Choice::Name(field_ident));
+                            }
+                            Some(2) => {
+                                let field_ident = __protocol.read_i64().await?;
+                                ret = Some(Choice::Id(field_ident));
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ),
+                        )
+                    }
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Choice",
+                        },
+                    )
+                    + match self {
+                        Choice::Name(value) => {
+                            __protocol.faststr_field_len(Some(1), value)
+                        }
+                        Choice::Id(value) => __protocol.i64_field_len(Some(2), *value),
+                    } + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Optional {
+            fn default() -> Self {
+                Optional::Name(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Clone, PartialEq)]
+        pub enum Optional {
+            Name(::pilota::FastStr),
+            Id(i64),
+        }
+        impl ::pilota::thrift::Message for Optional {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Optional",
+                        },
+                    )?;
+                match self {
+                    Optional::Name(value) => {
+                        __protocol.write_faststr_field(1, (value).clone())?;
+                    }
+                    Optional::Id(value) => {
+                        __protocol.write_i64_field(2, *value)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol
+                            .field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            let field_ident = __protocol.read_faststr()?;
+                            __protocol.faststr_len(&field_ident);
+                            ret = Some(Optional::Name(field_ident));
+                        }
+                        Some(2) => {
+                            let field_ident = __protocol.read_i64()?;
+                            __protocol.i64_len(*&field_ident);
+                            ret = Some(Optional::Id(field_ident));
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ),
+                    )
+                }
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {}
+                        match field_ident.id {
+                            Some(1) => {
+                                let field_ident = __protocol.read_faststr().await?;
+                                ret = Some(Optional::Name(field_ident));
+                            }
+                            Some(2) => {
+                                let field_ident = __protocol.read_i64().await?;
+                                ret = Some(Optional::Id(field_ident));
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ),
+                        )
+                    }
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Optional",
+                        },
+                    )
+                    + match self {
+                        Optional::Name(value) => {
+                            __protocol.faststr_field_len(Some(1), value)
+                        }
+                        Optional::Id(value) => __protocol.i64_field_len(Some(2), *value),
+                    } + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(Clone, PartialEq)]
+        pub struct Holder {
+            pub choice: Choice,
+            pub optional: ::std::option::Option<Optional>,
+        }
+        impl ::pilota::thrift::Message for Holder {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "Holder",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol
+                    .write_struct_field(
+                        1,
+                        &self.choice,
+                        ::pilota::thrift::TType::Struct,
+                    )?;
+                if let Some(value) = self.optional.as_ref() {
+                    __protocol
+                        .write_struct_field(2, value, ::pilota::thrift::TType::Struct)?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Struct => {
+                                var_1 = Some(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Struct => {
+                                var_2 = Some(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `Holder` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field choice is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self {
+                    choice: var_1,
+                    optional: var_2,
+                };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Struct => {
+                                    var_1 = Some(
+                                        <Choice as ::pilota::thrift::Message>::decode_async(
+                                                __protocol,
+                                            )
+                                            .await?,
+                                    );
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Struct => {
+                                    var_2 = Some(
+                                        <Optional as ::pilota::thrift::Message>::decode_async(
+                                                __protocol,
+                                            )
+                                            .await?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `Holder` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field choice is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self {
+                        choice: var_1,
+                        optional: var_2,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "Holder",
+                        },
+                    ) + __protocol.struct_field_len(Some(1), &self.choice)
+                    + self
+                        .optional
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(2), value))
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+    }
+}
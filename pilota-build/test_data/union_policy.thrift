@@ -0,0 +1,14 @@
+union Choice {
+    1: string name,
+    2: i64 id,
+}
+
+union Optional {
+    1: string name,
+    2: i64 id,
+} (pilota.allow_empty_union = "true")
+
+struct Holder {
+    1: required Choice choice,
+    2: optional Optional optional,
+}
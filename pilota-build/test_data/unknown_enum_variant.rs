@@ -0,0 +1,1151 @@
+pub mod unknown_enum_variant {
+    #![allow(warnings, clippy::all)]
+    pub mod unknown_enum_variant {
+        #[derive(PartialOrd)]
+        #[derive(Hash, Eq, Ord)]
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(::pilota::serde::Serialize, ::pilota::serde::Deserialize)]
+        #[serde(from = "i32", into = "i32")]
+        #[derive(Clone, PartialEq, Copy)]
+        pub enum Index {
+            #[default]
+            A,
+            B,
+            C,
+            Unknown(i32),
+        }
+        impl Index {
+            pub fn inner(&self) -> i32 {
+                match self {
+                    Self::A => 0,
+                    Self::B => 1,
+                    Self::C => 16,
+                    Self::Unknown(val) => *val,
+                }
+            }
+            pub fn as_str(&self) -> ::std::option::Option<&'static str> {
+                match self {
+                    Self::A => ::std::option::Option::Some("A"),
+                    Self::B => ::std::option::Option::Some("B"),
+                    Self::C => ::std::option::Option::Some("C"),
+                    Self::Unknown(_) => ::std::option::Option::None,
+                }
+            }
+            pub fn try_from_i32(value: i32) -> ::std::option::Option<Self> {
+                match Self::from(value) {
+                    Self::Unknown(_) => None,
+                    val => Some(val),
+                }
+            }
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, Self::Unknown(_))
+            }
+        }
+        impl ::std::convert::From<i32> for Index {
+            fn from(value: i32) -> Self {
+                match value {
+                    0 => Self::A,
+                    1 => Self::B,
+                    16 => Self::C,
+                    _ => Self::Unknown(value),
+                }
+            }
+        }
+        impl ::std::convert::From<Index> for i32 {
+            fn from(value: Index) -> i32 {
+                value.inner()
+            }
+        }
+        impl ::std::fmt::Display for Index {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self.as_str() {
+                    ::std::option::Option::Some(s) => f.write_str(s),
+                    ::std::option::Option::None => {
+                        ::std::fmt::Display::fmt(&self.inner(), f)
+                    }
+                }
+            }
+        }
+        impl ::std::str::FromStr for Index {
+            type Err = ::pilota::EnumConvertError<::std::string::String>;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    "A" => ::std::result::Result::Ok(Self::A),
+                    "B" => ::std::result::Result::Ok(Self::B),
+                    "C" => ::std::result::Result::Ok(Self::C),
+                    _ => {
+                        s.parse::<i32>()
+                            .map(Self::from)
+                            .map_err(|_| ::pilota::EnumConvertError::InvalidNum(
+                                s.to_string(),
+                                "Index",
+                            ))
+                    }
+                }
+            }
+        }
+        impl ::pilota::thrift::Message for Index {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_i32(self.inner())?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let value = __protocol.read_i32()?;
+                ::std::result::Result::Ok(
+                    ::std::convert::TryFrom::try_from(value)
+                        .map_err(|err| ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            format!("invalid enum value for Index, value: {}", value),
+                        ))?,
+                )
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let value = __protocol.read_i32().await?;
+                    ::std::result::Result::Ok(
+                        ::std::convert::TryFrom::try_from(value)
+                            .map_err(|err| ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("invalid enum value for Index, value: {}", value),
+                            ))?,
+                    )
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.i32_len(self.inner())
+            }
+        }
+        impl ::std::default::Default for A {
+            fn default() -> Self {
+                A {
+                    index: Index::B,
+                    opt_index: ::std::default::Default::default(),
+                    indexes: ::std::default::Default::default(),
+                    names: ::std::default::Default::default(),
+                }
+            }
+        }
+        #[derive(Debug)]
+        #[derive(::pilota::serde::Serialize, ::pilota::serde::Deserialize)]
+        #[derive(Clone, PartialEq)]
+        pub struct A {
+            pub index: Index,
+            pub opt_index: ::std::option::Option<Index>,
+            pub indexes: ::std::option::Option<::std::vec::Vec<Index>>,
+            pub names: ::std::option::Option<
+                ::pilota::AHashMap<Index, ::pilota::FastStr>,
+            >,
+        }
+        impl ::pilota::thrift::Message for A {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "A",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i32_field(1, (&self.index).inner())?;
+                if let Some(value) = self.opt_index.as_ref() {
+                    __protocol.write_i32_field(2, (value).inner())?;
+                }
+                if let Some(value) = self.indexes.as_ref() {
+                    __protocol
+                        .write_list_field(
+                            3,
+                            ::pilota::thrift::TType::I32,
+                            &value,
+                            |__protocol, val| {
+                                __protocol.write_struct(val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                }
+                if let Some(value) = self.names.as_ref() {
+                    __protocol
+                        .write_map_field(
+                            4,
+                            ::pilota::thrift::TType::I32,
+                            ::pilota::thrift::TType::Binary,
+                            &value,
+                            |__protocol, key| {
+                                __protocol.write_struct(key)?;
+                                ::std::result::Result::Ok(())
+                            },
+                            |__protocol, val| {
+                                __protocol.write_faststr((val).clone())?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = Index::B;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                var_1 = ::pilota::thrift::Message::decode(__protocol)?;
+                            }
+                            Some(
+                                2,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::I32 => {
+                                var_2 = Some(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                            }
+                            Some(
+                                3,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::List => {
+                                var_3 = Some({
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val = ::std::vec::Vec::with_capacity(
+                                        list_ident.size,
+                                    );
+                                    for _ in 0..list_ident.size {
+                                        val.push(::pilota::thrift::Message::decode(__protocol)?);
+                                    }
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(
+                                4,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Map => {
+                                var_4 = Some({
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::pilota::AHashMap::with_capacity(
+                                        map_ident.size,
+                                    );
+                                    for _ in 0..map_ident.size {
+                                        val.insert(
+                                            ::pilota::thrift::Message::decode(__protocol)?,
+                                            __protocol.read_faststr()?,
+                                        );
+                                    }
+                                    __protocol.read_map_end()?;
+                                    val
+                                });
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `A` field(#{}) failed, caused by: ", field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let data = Self {
+                    index: var_1,
+                    opt_index: var_2,
+                    indexes: var_3,
+                    names: var_4,
+                };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = Index::B;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    var_1 = <Index as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+                                }
+                                Some(
+                                    2,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::I32 => {
+                                    var_2 = Some(
+                                        <Index as ::pilota::thrift::Message>::decode_async(
+                                                __protocol,
+                                            )
+                                            .await?,
+                                    );
+                                }
+                                Some(
+                                    3,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::List => {
+                                    var_3 = Some({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val = ::std::vec::Vec::with_capacity(
+                                            list_ident.size,
+                                        );
+                                        for _ in 0..list_ident.size {
+                                            val.push(
+                                                <Index as ::pilota::thrift::Message>::decode_async(
+                                                        __protocol,
+                                                    )
+                                                    .await?,
+                                            );
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(
+                                    4,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Map => {
+                                    var_4 = Some({
+                                        let map_ident = __protocol.read_map_begin().await?;
+                                        let mut val = ::pilota::AHashMap::with_capacity(
+                                            map_ident.size,
+                                        );
+                                        for _ in 0..map_ident.size {
+                                            val.insert(
+                                                <Index as ::pilota::thrift::Message>::decode_async(
+                                                        __protocol,
+                                                    )
+                                                    .await?,
+                                                __protocol.read_faststr().await?,
+                                            );
+                                        }
+                                        __protocol.read_map_end().await?;
+                                        val
+                                    });
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `A` field(#{}) failed, caused by: ", field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let data = Self {
+                        index: var_1,
+                        opt_index: var_2,
+                        indexes: var_3,
+                        names: var_4,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "A",
+                        },
+                    ) + __protocol.i32_field_len(Some(1), (&self.index).inner())
+                    + self
+                        .opt_index
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| __protocol.i32_field_len(Some(2), (value).inner()),
+                        )
+                    + self
+                        .indexes
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol
+                                    .list_field_len(
+                                        Some(3),
+                                        ::pilota::thrift::TType::I32,
+                                        value,
+                                        |__protocol, el| { __protocol.struct_len(el) },
+                                    )
+                            },
+                        )
+                    + self
+                        .names
+                        .as_ref()
+                        .map_or(
+                            0,
+                            |value| {
+                                __protocol
+                                    .map_field_len(
+                                        Some(4),
+                                        ::pilota::thrift::TType::I32,
+                                        ::pilota::thrift::TType::Binary,
+                                        value,
+                                        |__protocol, key| { __protocol.struct_len(key) },
+                                        |__protocol, val| { __protocol.faststr_len(val) },
+                                    )
+                            },
+                        ) + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for TestServiceTestResultRecv {
+            fn default() -> Self {
+                TestServiceTestResultRecv::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(Debug)]
+        #[derive(::pilota::serde::Serialize, ::pilota::serde::Deserialize)]
+        #[derive(Clone, PartialEq)]
+        pub enum TestServiceTestResultRecv {
+            Ok(A),
+        }
+        impl ::pilota::thrift::Message for TestServiceTestResultRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "TestServiceTestResultRecv",
+                        },
+                    )?;
+                match self {
+                    TestServiceTestResultRecv::Ok(value) => {
+                        __protocol
+                            .write_struct_field(
+                                0,
+                                value,
+                                ::pilota::thrift::TType::Struct,
+                            )?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol
+                            .field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(
+                                    __protocol,
+                                )?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(TestServiceTestResultRecv::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ),
+                    )
+                }
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {}
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident = <A as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+                                    ret = Some(TestServiceTestResultRecv::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ),
+                        )
+                    }
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "TestServiceTestResultRecv",
+                        },
+                    )
+                    + match self {
+                        TestServiceTestResultRecv::Ok(value) => {
+                            __protocol.struct_field_len(Some(0), value)
+                        }
+                    } + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for TestServiceTestResultSend {
+            fn default() -> Self {
+                TestServiceTestResultSend::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(Debug)]
+        #[derive(::pilota::serde::Serialize, ::pilota::serde::Deserialize)]
+        #[derive(Clone, PartialEq)]
+        pub enum TestServiceTestResultSend {
+            Ok(A),
+        }
+        impl ::pilota::thrift::Message for TestServiceTestResultSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "TestServiceTestResultSend",
+                        },
+                    )?;
+                match self {
+                    TestServiceTestResultSend::Ok(value) => {
+                        __protocol
+                            .write_struct_field(
+                                0,
+                                value,
+                                ::pilota::thrift::TType::Struct,
+                            )?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol
+                            .field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(
+                                    __protocol,
+                                )?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(TestServiceTestResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ),
+                    )
+                }
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {}
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident = <A as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+                                    ret = Some(TestServiceTestResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(
+                                        ::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message",
+                                        ),
+                                    );
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ),
+                        )
+                    }
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "TestServiceTestResultSend",
+                        },
+                    )
+                    + match self {
+                        TestServiceTestResultSend::Ok(value) => {
+                            __protocol.struct_field_len(Some(0), value)
+                        }
+                    } + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(::pilota::serde::Serialize, ::pilota::serde::Deserialize)]
+        #[derive(Clone, PartialEq)]
+        pub struct TestServiceTestArgsSend {
+            pub req: A,
+        }
+        impl ::pilota::thrift::Message for TestServiceTestArgsSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "TestServiceTestArgsSend",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol
+                    .write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Struct => {
+                                var_1 = Some(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `TestServiceTestArgsSend` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field req is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { req: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Struct => {
+                                    var_1 = Some(
+                                        <A as ::pilota::thrift::Message>::decode_async(__protocol)
+                                            .await?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `TestServiceTestArgsSend` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field req is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { req: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "TestServiceTestArgsSend",
+                        },
+                    ) + __protocol.struct_field_len(Some(1), &self.req)
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        #[derive(Debug)]
+        #[derive(Default)]
+        #[derive(::pilota::serde::Serialize, ::pilota::serde::Deserialize)]
+        #[derive(Clone, PartialEq)]
+        pub struct TestServiceTestArgsRecv {
+            pub req: A,
+        }
+        impl ::pilota::thrift::Message for TestServiceTestArgsRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "TestServiceTestArgsRecv",
+                };
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol
+                    .write_struct_field(1, &self.req, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut var_1 = None;
+                let mut __pilota_decoding_field_id = None;
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol
+                                .field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(
+                                1,
+                            ) if field_ident.field_type
+                                == ::pilota::thrift::TType::Struct => {
+                                var_1 = Some(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                );
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_msg(
+                            &format!(
+                                "decode struct `TestServiceTestArgsRecv` field(#{}) failed, caused by: ",
+                                field_id
+                            ),
+                        );
+                    }
+                    return ::std::result::Result::Err(err);
+                }
+                __protocol.read_struct_end()?;
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "field req is required".to_string(),
+                        ),
+                    )
+                };
+                let data = Self { req: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                        Output = ::std::result::Result<
+                            Self,
+                            ::pilota::thrift::ThriftException,
+                        >,
+                    > + Send + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut __pilota_decoding_field_id = None;
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {}
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(
+                                    1,
+                                ) if field_ident.field_type
+                                    == ::pilota::thrift::TType::Struct => {
+                                    var_1 = Some(
+                                        <A as ::pilota::thrift::Message>::decode_async(__protocol)
+                                            .await?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                            __protocol.read_field_end().await?;
+                        };
+                        ::std::result::Result::Ok::<
+                            _,
+                            ::pilota::thrift::ThriftException,
+                        >(())
+                    }
+                        .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_msg(
+                                &format!(
+                                    "decode struct `TestServiceTestArgsRecv` field(#{}) failed, caused by: ",
+                                    field_id
+                                ),
+                            );
+                        }
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_struct_end().await?;
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field req is required".to_string(),
+                            ),
+                        )
+                    };
+                    let data = Self { req: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+            fn size<T: ::pilota::thrift::TLengthProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(
+                        &::pilota::thrift::TStructIdentifier {
+                            name: "TestServiceTestArgsRecv",
+                        },
+                    ) + __protocol.struct_field_len(Some(1), &self.req)
+                    + __protocol.field_stop_len() + __protocol.struct_end_len()
+            }
+        }
+        pub trait TestService {}
+    }
+}
@@ -0,0 +1,16 @@
+enum Index {
+    A = 0,
+    B = 1,
+    C = 16,
+}
+
+struct A {
+    1: required Index index = Index.B,
+    2: optional Index opt_index,
+    3: list<Index> indexes,
+    4: map<Index, string> names,
+}
+
+service TestService {
+    A test(1: A req),
+}
@@ -0,0 +1,37 @@
+namespace rs shop
+
+enum Kind {
+    PHYSICAL = 1,
+    DIGITAL = 2,
+}
+
+typedef i64 ItemId
+
+struct Item {
+    1: required ItemId id,
+    2: required string name,
+    3: optional Kind kind,
+    4: optional map<string, double> prices,
+    5: optional binary thumbnail,
+}
+
+union Payment {
+    1: string card,
+    2: i64 voucher,
+}
+
+struct Order {
+    1: required list<Item> items,
+    2: optional Payment payment,
+    3: optional set<string> type,
+}
+
+exception NotFound {
+    1: required ItemId id,
+}
+
+service Shop {
+    Order get(1: required i64 id) throws (1: NotFound not_found),
+    list<Item> search(1: required string query, 2: optional Kind kind),
+    oneway void notify(1: required Order order),
+}
@@ -0,0 +1,286 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use lsp_types::{
+    Diagnostic, DiagnosticSeverity, Hover, HoverContents, Location, MarkupContent, MarkupKind,
+    Position, Range, Url,
+};
+use pilota_thrift_parser::{
+    Error, FileParser, FileSource,
+    index::{self, Definition, FileIndex, SymbolAt, SymbolIndex},
+};
+
+/// The state of the open documents, answering the requests of the server.
+///
+/// Documents are reparsed on every request, the files they include are read
+/// from the open documents first and from the disk otherwise.
+#[derive(Debug, Default)]
+pub struct Analysis {
+    docs: HashMap<PathBuf, String>,
+}
+
+impl Analysis {
+    pub fn open(&mut self, uri: &Url, text: String) {
+        if let Ok(path) = uri.to_file_path() {
+            self.docs.insert(path, text);
+        }
+    }
+
+    pub fn close(&mut self, uri: &Url) {
+        if let Ok(path) = uri.to_file_path() {
+            self.docs.remove(&path);
+        }
+    }
+
+    fn read(&self, path: &Path) -> std::io::Result<String> {
+        match self.docs.get(path) {
+            Some(text) => Ok(text.clone()),
+            None => std::fs::read_to_string(path),
+        }
+    }
+
+    fn parse(&self, uri: &Url) -> Option<(PathBuf, String, FileIndex)> {
+        let path = uri.to_file_path().ok()?;
+        let text = self.read(&path).ok()?;
+        let file = FileParser::new(FileSource::new(&text)).parse().ok()?;
+        let file_index = FileIndex::new(&path, &file, &text);
+        Some((path, text, file_index))
+    }
+
+    // indexes the includes of `file_index`, which comes first in the index,
+    // along with the errors of each include
+    fn index(&self, file_index: FileIndex) -> (SymbolIndex, Vec<(index::Location, String)>) {
+        let includes = file_index.includes.clone();
+        let mut index = SymbolIndex {
+            files: vec![file_index],
+            ..Default::default()
+        };
+        let mut errors = Vec::new();
+        for include in includes {
+            match SymbolIndex::build_with([PathBuf::from(&include.resolved)], |p| self.read(p)) {
+                Ok(included) => {
+                    for file in included.files {
+                        if index.file(&file.path).is_none() {
+                            index.files.push(file);
+                        }
+                    }
+                }
+                Err(e) => {
+                    let msg = match e {
+                        Error::FileNotFound(path) => format!("cannot find {}", path.display()),
+                        Error::Syntax { summary, .. } => summary.to_string(),
+                        Error::IO(e) => e.to_string(),
+                    };
+                    errors.push((include.location, msg));
+                }
+            }
+        }
+        (index, errors)
+    }
+
+    /// Syntax errors, broken includes, unresolved references and duplicate
+    /// definitions of the document.
+    pub fn diagnostics(&self, uri: &Url) -> Vec<Diagnostic> {
+        let Some(text) = uri.to_file_path().ok().and_then(|p| self.read(&p).ok()) else {
+            return Vec::new();
+        };
+        let Some((_, _, file_index)) = self.parse(uri) else {
+            return match FileParser::new(FileSource::new(&text)).parse() {
+                Err(Error::Syntax { errors, .. }) => errors
+                    .iter()
+                    .map(|e| {
+                        diagnostic(
+                            range(&text, e.span.start, e.span.end),
+                            e.message.to_string(),
+                        )
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+        };
+
+        let (index, errors) = self.index(file_index);
+        let mut diagnostics = errors
+            .into_iter()
+            .map(|(location, msg)| diagnostic(location_range(&text, location), msg))
+            .collect::<Vec<_>>();
+
+        let file_index = &index.files[0];
+        for r in &file_index.references {
+            // an include that could not be indexed is already reported
+            if index.file(&r.target.file).is_some() && index.definition(&r.target).is_none() {
+                diagnostics.push(diagnostic(
+                    location_range(&text, r.location),
+                    format!("cannot find `{}`", r.name),
+                ));
+            }
+        }
+        let mut defined = HashSet::new();
+        for d in &file_index.definitions {
+            if !defined.insert(&d.name) {
+                diagnostics.push(diagnostic(
+                    location_range(&text, d.location),
+                    format!("`{}` is defined more than once", d.name),
+                ));
+            }
+        }
+        diagnostics
+    }
+
+    pub fn goto_definition(&self, uri: &Url, position: Position) -> Option<Location> {
+        let (path, text, file_index) = self.parse(uri)?;
+        let offset = offset(&text, position);
+        if let Some(include) = file_index
+            .includes
+            .iter()
+            .find(|i| i.location.contains(offset))
+        {
+            return Some(Location::new(
+                Url::from_file_path(&include.resolved).ok()?,
+                Range::default(),
+            ));
+        }
+
+        let (index, _) = self.index(file_index);
+        let (file, def) = match index.files[0].symbol_at(offset)? {
+            SymbolAt::Reference(r) => (PathBuf::from(&r.target.file), index.definition(&r.target)?),
+            SymbolAt::Definition(d) => (path, d),
+        };
+        let target_text = self.read(&file).ok()?;
+        Some(Location::new(
+            Url::from_file_path(&file).ok()?,
+            location_range(&target_text, def.location),
+        ))
+    }
+
+    pub fn hover(&self, uri: &Url, position: Position) -> Option<Hover> {
+        let (_, text, file_index) = self.parse(uri)?;
+        let offset = offset(&text, position);
+        let (index, _) = self.index(file_index);
+        let (location, def) = match index.files[0].symbol_at(offset)? {
+            SymbolAt::Reference(r) => (r.location, index.definition(&r.target)?),
+            SymbolAt::Definition(d) => (d.location, d),
+        };
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: hover_text(def),
+            }),
+            range: Some(location_range(&text, location)),
+        })
+    }
+}
+
+fn hover_text(def: &Definition) -> String {
+    let kind = serde_json::to_value(def.kind)
+        .ok()
+        .and_then(|v| v.as_str().map(|s| s.replace('_', " ")))
+        .unwrap_or_default();
+    let mut text = format!("```thrift\n{kind} {}\n```", def.name);
+    if let Some(doc) = &def.doc {
+        text.push_str("\n\n");
+        text.push_str(doc);
+    }
+    text
+}
+
+fn diagnostic(range: Range, message: String) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("pilota".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+fn location_range(text: &str, location: index::Location) -> Range {
+    range(text, location.offset, location.offset + location.len)
+}
+
+fn range(text: &str, start: usize, end: usize) -> Range {
+    Range::new(position(text, start), position(text, end))
+}
+
+// lsp positions count lines from 0 and columns in utf-16 code units
+fn position(text: &str, offset: usize) -> Position {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position::new(
+        before.matches('\n').count() as u32,
+        before[line_start..].encode_utf16().count() as u32,
+    )
+}
+
+fn offset(text: &str, position: Position) -> usize {
+    let line_start = text
+        .split_inclusive('\n')
+        .take(position.line as usize)
+        .map(str::len)
+        .sum::<usize>();
+    let line = text[line_start..].split('\n').next().unwrap_or_default();
+    let mut units = 0;
+    for (i, c) in line.char_indices() {
+        if units >= position.character {
+            return line_start + i;
+        }
+        units += c.len_utf16() as u32;
+    }
+    line_start + line.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analysis() {
+        let dir = std::env::temp_dir().join(format!("pilota-lsp-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("common.thrift"),
+            "/** The base of all items */\nstruct Base {}\n",
+        )
+        .unwrap();
+
+        let mut analysis = Analysis::default();
+        let uri = Url::from_file_path(dir.join("shop.thrift")).unwrap();
+        analysis.open(
+            &uri,
+            "include \"common.thrift\"\n\nstruct Item {\n    1: common.Base base,\n    2: Missing missing,\n}\n"
+                .to_string(),
+        );
+
+        let diagnostics = analysis.diagnostics(&uri);
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+        assert_eq!(diagnostics[0].message, "cannot find `Missing`");
+        assert_eq!(diagnostics[0].range.start, Position::new(4, 7));
+
+        let def = analysis
+            .goto_definition(&uri, Position::new(3, 15))
+            .unwrap();
+        assert_eq!(
+            def.uri,
+            Url::from_file_path(dir.join("common.thrift")).unwrap()
+        );
+        assert_eq!(def.range.start, Position::new(1, 7));
+
+        let hover = analysis.hover(&uri, Position::new(3, 15)).unwrap();
+        let HoverContents::Markup(content) = hover.contents else {
+            panic!("hover is markdown")
+        };
+        assert_eq!(
+            content.value,
+            "```thrift\nstruct Base\n```\n\nThe base of all items"
+        );
+
+        analysis.open(&uri, "struct Item {\n    1: i32\n}\n".to_string());
+        let diagnostics = analysis.diagnostics(&uri);
+        assert!(!diagnostics.is_empty());
+        assert_eq!(diagnostics[0].range.start.line, 2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,133 @@
+#![doc(
+    html_logo_url = "https://github.com/cloudwego/pilota/raw/main/.github/assets/logo.png?sanitize=true"
+)]
+#![cfg_attr(not(doctest), doc = include_str!("../README.md"))]
+
+mod analysis;
+
+pub use analysis::Analysis;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    GotoDefinitionParams, GotoDefinitionResponse, HoverParams, HoverProviderCapability, OneOf,
+    PublishDiagnosticsParams, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _,
+        PublishDiagnostics,
+    },
+    request::{GotoDefinition, HoverRequest, Request as _},
+};
+
+pub fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        ..Default::default()
+    }
+}
+
+/// Serves `connection` until the client shuts the server down.
+pub fn run(connection: &Connection) -> anyhow::Result<()> {
+    connection.initialize(serde_json::to_value(capabilities())?)?;
+
+    let mut analysis = Analysis::default();
+    for msg in &connection.receiver {
+        match msg {
+            Message::Request(req) => {
+                if connection.handle_shutdown(&req)? {
+                    return Ok(());
+                }
+                let resp = handle_request(&analysis, req);
+                connection.sender.send(Message::Response(resp))?;
+            }
+            Message::Notification(not) => {
+                if let Some(params) = handle_notification(&mut analysis, not)? {
+                    connection
+                        .sender
+                        .send(Message::Notification(Notification::new(
+                            PublishDiagnostics::METHOD.to_string(),
+                            params,
+                        )))?;
+                }
+            }
+            Message::Response(_) => {}
+        }
+    }
+    Ok(())
+}
+
+fn handle_request(analysis: &Analysis, req: Request) -> Response {
+    let id = req.id.clone();
+    let result = match req.method.as_str() {
+        GotoDefinition::METHOD => req
+            .extract::<GotoDefinitionParams>(GotoDefinition::METHOD)
+            .map(|(_, params)| {
+                let pos = params.text_document_position_params;
+                let result = analysis
+                    .goto_definition(&pos.text_document.uri, pos.position)
+                    .map(GotoDefinitionResponse::Scalar);
+                serde_json::to_value(result)
+            }),
+        HoverRequest::METHOD => {
+            req.extract::<HoverParams>(HoverRequest::METHOD)
+                .map(|(_, params)| {
+                    let pos = params.text_document_position_params;
+                    serde_json::to_value(analysis.hover(&pos.text_document.uri, pos.position))
+                })
+        }
+        _ => {
+            return Response::new_err(
+                id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported request {}", req.method),
+            );
+        }
+    };
+    match result {
+        Ok(Ok(value)) => Response::new_ok(id, value),
+        Ok(Err(e)) => Response::new_err(id, ErrorCode::InternalError as i32, e.to_string()),
+        Err(e) => Response::new_err(id, ErrorCode::InvalidParams as i32, e.to_string()),
+    }
+}
+
+// returns the diagnostics to publish, if the notification changed them
+fn handle_notification(
+    analysis: &mut Analysis,
+    not: Notification,
+) -> anyhow::Result<Option<PublishDiagnosticsParams>> {
+    let (uri, version) = match not.method.as_str() {
+        DidOpenTextDocument::METHOD => {
+            let params: DidOpenTextDocumentParams = serde_json::from_value(not.params)?;
+            let doc = params.text_document;
+            analysis.open(&doc.uri, doc.text);
+            (doc.uri, Some(doc.version))
+        }
+        DidChangeTextDocument::METHOD => {
+            let mut params: DidChangeTextDocumentParams = serde_json::from_value(not.params)?;
+            // the sync is full, the last change holds the whole document
+            let Some(change) = params.content_changes.pop() else {
+                return Ok(None);
+            };
+            let doc = params.text_document;
+            analysis.open(&doc.uri, change.text);
+            (doc.uri, Some(doc.version))
+        }
+        DidCloseTextDocument::METHOD => {
+            let params: DidCloseTextDocumentParams = serde_json::from_value(not.params)?;
+            analysis.close(&params.text_document.uri);
+            return Ok(Some(PublishDiagnosticsParams::new(
+                params.text_document.uri,
+                Vec::new(),
+                None,
+            )));
+        }
+        _ => return Ok(None),
+    };
+    let diagnostics = analysis.diagnostics(&uri);
+    Ok(Some(PublishDiagnosticsParams::new(
+        uri,
+        diagnostics,
+        version,
+    )))
+}
@@ -0,0 +1,82 @@
+#![doc = include_str!("../README.md")]
+
+use std::path::PathBuf;
+
+use pilota_build::{Builder, IdlService, MakeBackend, Output, parser::Parser};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{LitStr, parse_macro_input};
+
+/// Expands to the code generated for a thrift file, see the crate docs.
+#[proc_macro]
+pub fn include_thrift(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    include_idl(path, Builder::thrift().ignore_unused(false))
+}
+
+/// Expands to the code generated for a protobuf file, see the crate docs.
+#[proc_macro]
+pub fn include_proto(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    include_idl(path, Builder::pb().ignore_unused(false))
+}
+
+fn include_idl<MkB, P>(lit: LitStr, builder: Builder<MkB, P>) -> TokenStream
+where
+    MkB: MakeBackend + Send,
+    MkB::Target: Send,
+    P: Parser,
+{
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let path = PathBuf::from(manifest_dir).join(lit.value());
+    if !path.exists() {
+        return compile_error(&lit, format!("{} does not exist", path.display()));
+    }
+    let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+        return compile_error(&lit, format!("{} has no file name", path.display()));
+    };
+    let out = format!("{stem}.rs");
+
+    // pilota-build moves proc-macro2 tokens across threads, which only its
+    // fallback implementation supports. A panic is how it reports an invalid
+    // IDL.
+    proc_macro2::fallback::force();
+    let source = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        builder
+            .compile_to_memory(
+                vec![IdlService::from_path(path.clone())],
+                Output::File(out.clone().into()),
+            )
+            .map(|files| files.get(&out).map(str::to_owned))
+    }));
+    proc_macro2::fallback::unforce();
+    let source = match source {
+        Ok(Ok(Some(source))) => source,
+        Ok(Ok(None)) => return compile_error(&lit, format!("no code generated for {stem}")),
+        Ok(Err(e)) => return compile_error(&lit, format!("failed to generate code: {e}")),
+        Err(panic) => {
+            let msg = panic
+                .downcast_ref::<String>()
+                .map(String::as_str)
+                .or_else(|| panic.downcast_ref::<&str>().copied())
+                .unwrap_or("pilota-build panicked");
+            return compile_error(&lit, format!("failed to generate code: {msg}"));
+        }
+    };
+    let generated = match syn::parse_str::<proc_macro2::TokenStream>(&source) {
+        Ok(tokens) => tokens,
+        Err(e) => return compile_error(&lit, format!("invalid generated code: {e}")),
+    };
+
+    // makes cargo rebuild the crate when the file changes
+    let path = path.display().to_string();
+    quote! {
+        const _: &[u8] = include_bytes!(#path);
+        #generated
+    }
+    .into()
+}
+
+fn compile_error(lit: &LitStr, msg: String) -> TokenStream {
+    syn::Error::new(lit.span(), msg).to_compile_error().into()
+}
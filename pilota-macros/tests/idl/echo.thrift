@@ -0,0 +1,12 @@
+struct Request {
+    1: required string message,
+    2: optional i32 times,
+}
+
+struct Response {
+    1: required list<string> messages,
+}
+
+service Echo {
+    Response echo(1: Request req),
+}
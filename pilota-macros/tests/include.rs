@@ -0,0 +1,22 @@
+use pilota::{
+    BytesMut,
+    thrift::{Message, binary::TBinaryProtocol},
+};
+
+pilota_macros::include_thrift!("tests/idl/echo.thrift");
+
+#[test]
+fn test_include_thrift() {
+    let req = echo::echo::Request {
+        message: "hi".into(),
+        times: Some(2),
+    };
+    let mut buf = BytesMut::new();
+    req.encode(&mut TBinaryProtocol::new(&mut buf, false))
+        .unwrap();
+    let mut bytes = buf.freeze();
+    let decoded =
+        echo::echo::Request::decode(&mut TBinaryProtocol::new(&mut bytes, false)).unwrap();
+    assert_eq!(decoded, req);
+    let _ = echo::echo::Response::default();
+}
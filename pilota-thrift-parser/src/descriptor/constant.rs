@@ -22,6 +22,7 @@ pub struct Constant {
     pub value: ConstValue,
     pub annotations: Annotations,
     pub leading_comments: FastStr,
+    pub doc: Option<FastStr>,
     pub trailing_comments: FastStr,
 }
 
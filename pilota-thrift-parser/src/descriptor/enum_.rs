@@ -8,6 +8,7 @@ pub struct EnumValue {
     pub value: Option<IntConstant>,
     pub annotations: Annotations,
     pub leading_comments: FastStr,
+    pub doc: Option<FastStr>,
     pub trailing_comments: FastStr,
 }
 
@@ -17,5 +18,6 @@ pub struct Enum {
     pub values: Vec<EnumValue>,
     pub annotations: Annotations,
     pub leading_comments: FastStr,
+    pub doc: Option<FastStr>,
     pub trailing_comments: FastStr,
 }
@@ -19,5 +19,6 @@ pub struct Field {
     pub default: Option<ConstValue>,
     pub annotations: Annotations,
     pub leading_comments: FastStr,
+    pub doc: Option<FastStr>,
     pub trailing_comments: FastStr,
 }
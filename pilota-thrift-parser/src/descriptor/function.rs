@@ -11,5 +11,6 @@ pub struct Function {
     pub throws: Vec<Field>, // throws as an exception
     pub annotations: Annotations,
     pub leading_comments: FastStr,
+    pub doc: Option<FastStr>,
     pub trailing_comments: FastStr,
 }
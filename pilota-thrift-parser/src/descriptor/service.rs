@@ -9,5 +9,6 @@ pub struct Service {
     pub functions: Vec<Function>,
     pub annotations: Annotations,
     pub leading_comments: FastStr,
+    pub doc: Option<FastStr>,
     pub trailing_comments: FastStr,
 }
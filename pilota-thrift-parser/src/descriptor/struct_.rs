@@ -8,6 +8,7 @@ use super::{Annotations, Field, Ident};
 pub struct Struct {
     pub struct_like: StructLike,
     pub leading_comments: FastStr,
+    pub doc: Option<FastStr>,
     pub trailing_comments: FastStr,
 }
 
@@ -34,6 +35,7 @@ struct_like!(Struct);
 pub struct Union {
     pub struct_like: StructLike,
     pub leading_comments: FastStr,
+    pub doc: Option<FastStr>,
     pub trailing_comments: FastStr,
 }
 
@@ -43,6 +45,7 @@ struct_like!(Union);
 pub struct Exception {
     pub struct_like: StructLike,
     pub leading_comments: FastStr,
+    pub doc: Option<FastStr>,
     pub trailing_comments: FastStr,
 }
 
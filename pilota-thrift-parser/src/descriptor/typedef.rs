@@ -8,5 +8,6 @@ pub struct Typedef {
     pub alias: Ident,
     pub annotations: Annotations,
     pub leading_comments: FastStr,
+    pub doc: Option<FastStr>,
     pub trailing_comments: FastStr,
 }
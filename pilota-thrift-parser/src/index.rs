@@ -0,0 +1,665 @@
+//! Symbol index of thrift files, for editors and language servers.
+//!
+//! The index records where every item is defined, where it is referenced and
+//! which files each file includes, as computed from the same parse results
+//! pilota-build works with. It serializes to JSON in the following format,
+//! versioned by [`INDEX_VERSION`]:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "files": [
+//!     {
+//!       "path": "idl/shop.thrift",
+//!       "includes": [
+//!         {
+//!           "path": "common.thrift",
+//!           "resolved": "idl/common.thrift",
+//!           "location": { "offset": 8, "len": 15, "line": 1, "column": 9 }
+//!         }
+//!       ],
+//!       "definitions": [
+//!         {
+//!           "name": "Item",
+//!           "kind": "struct",
+//!           "location": { "offset": 32, "len": 4, "line": 3, "column": 8 }
+//!         },
+//!         {
+//!           "name": "Item.base",
+//!           "kind": "field",
+//!           "location": { "offset": 67, "len": 4, "line": 4, "column": 29 }
+//!         }
+//!       ],
+//!       "references": [
+//!         {
+//!           "name": "common.Base",
+//!           "target": { "file": "idl/common.thrift", "name": "Base" },
+//!           "location": { "offset": 55, "len": 11, "line": 4, "column": 17 }
+//!         }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! - `offset` and `len` are in bytes, `line` and `column` start at 1 and the
+//!   column counts chars, like the positions of parse errors.
+//! - The location of an include is the one of its quoted path.
+//! - Members are named after their parent: fields and enum values
+//!   `Parent.member`, methods `Service.method`.
+//! - A reference targets the file defining the item, resolved through the
+//!   includes relative to the including file, and the name of the item in that
+//!   file. The target is not checked to exist.
+
+use std::{
+    collections::{BTreeSet, VecDeque},
+    path::{Path as FsPath, PathBuf},
+};
+
+use faststr::FastStr;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ConstValue, Field, File, FileParser, FileSource, Item, Path, StructLike, Ty, Type,
+    parser::error::Error,
+};
+
+/// The version of the JSON format, bumped on incompatible changes.
+pub const INDEX_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolIndex {
+    pub version: u32,
+    pub files: Vec<FileIndex>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileIndex {
+    pub path: String,
+    pub includes: Vec<IncludeEdge>,
+    pub definitions: Vec<Definition>,
+    pub references: Vec<Reference>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IncludeEdge {
+    /// The path as written in the include.
+    pub path: String,
+    pub resolved: String,
+    pub location: Location,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Definition {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub location: Location,
+    /// The doc comment of the item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    Struct,
+    Union,
+    Exception,
+    Enum,
+    EnumValue,
+    Typedef,
+    Const,
+    Service,
+    Method,
+    Field,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reference {
+    /// The path as written in the file.
+    pub name: String,
+    pub target: Target,
+    pub location: Location,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Target {
+    pub file: String,
+    pub name: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    pub offset: usize,
+    pub len: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// Whether `offset` falls in the located text.
+    pub fn contains(&self, offset: usize) -> bool {
+        (self.offset..=self.offset + self.len).contains(&offset)
+    }
+}
+
+impl Default for SymbolIndex {
+    fn default() -> Self {
+        Self {
+            version: INDEX_VERSION,
+            files: Vec::new(),
+        }
+    }
+}
+
+impl SymbolIndex {
+    /// Parses and indexes `entries` and all the files they include.
+    pub fn build(entries: impl IntoIterator<Item = PathBuf>) -> Result<Self, Error> {
+        Self::build_with(entries, |path| std::fs::read_to_string(path))
+    }
+
+    /// Like [`Self::build`], reading the files with `read`, e.g. to index the
+    /// unsaved buffers of an editor.
+    pub fn build_with(
+        entries: impl IntoIterator<Item = PathBuf>,
+        mut read: impl FnMut(&FsPath) -> std::io::Result<String>,
+    ) -> Result<Self, Error> {
+        let mut index = Self::default();
+        let mut seen = BTreeSet::new();
+        let mut queue = entries.into_iter().collect::<VecDeque<_>>();
+        while let Some(path) = queue.pop_front() {
+            if !seen.insert(path.clone()) {
+                continue;
+            }
+            let source = read(&path).map_err(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => Error::FileNotFound(path.clone()),
+                _ => Error::IO(e),
+            })?;
+            let file = FileParser::new(FileSource::new(&source)).parse()?;
+            let file_index = FileIndex::new(&path, &file, &source);
+            queue.extend(
+                file_index
+                    .includes
+                    .iter()
+                    .map(|i| PathBuf::from(&i.resolved)),
+            );
+            index.files.push(file_index);
+        }
+        Ok(index)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("the index is always serializable")
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn file(&self, path: &str) -> Option<&FileIndex> {
+        self.files.iter().find(|f| f.path == path)
+    }
+
+    /// The definition a reference points to.
+    pub fn definition(&self, target: &Target) -> Option<&Definition> {
+        self.file(&target.file)?
+            .definitions
+            .iter()
+            .find(|d| d.name == target.name)
+    }
+
+    /// All references to the item `name` defined in `file`, with the file
+    /// they are in.
+    pub fn references<'a>(
+        &'a self,
+        file: &'a str,
+        name: &'a str,
+    ) -> impl Iterator<Item = (&'a str, &'a Reference)> + 'a {
+        self.files.iter().flat_map(move |f| {
+            f.references
+                .iter()
+                .filter(move |r| r.target.file == file && r.target.name == name)
+                .map(move |r| (f.path.as_str(), r))
+        })
+    }
+}
+
+impl FileIndex {
+    /// Indexes `file`, parsed from `source`, found at `path`.
+    pub fn new(path: &FsPath, file: &File, source: &str) -> Self {
+        let mut indexer = Indexer {
+            path: path.display().to_string(),
+            dir: path.parent().map(FsPath::to_path_buf).unwrap_or_default(),
+            tokens: tokenize(source),
+            source,
+            cursor: 0,
+            index: FileIndex {
+                path: path.display().to_string(),
+                includes: Vec::new(),
+                definitions: Vec::new(),
+                references: Vec::new(),
+            },
+        };
+        indexer.file(file);
+        indexer.index
+    }
+
+    /// The definition or reference at `offset`.
+    pub fn symbol_at(&self, offset: usize) -> Option<SymbolAt<'_>> {
+        self.references
+            .iter()
+            .find(|r| r.location.contains(offset))
+            .map(SymbolAt::Reference)
+            .or_else(|| {
+                self.definitions
+                    .iter()
+                    .find(|d| d.location.contains(offset))
+                    .map(SymbolAt::Definition)
+            })
+    }
+}
+
+/// What is found at an offset by [`FileIndex::symbol_at`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SymbolAt<'a> {
+    Definition(&'a Definition),
+    Reference(&'a Reference),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum TokenKind {
+    Ident,
+    Str,
+}
+
+#[derive(Debug)]
+struct Token<'a> {
+    kind: TokenKind,
+    // the string literals without their quotes
+    text: &'a str,
+    offset: usize,
+    len: usize,
+}
+
+// Splits the source in identifiers, dotted ones included, and string
+// literals. Comments, numbers and punctuation are skipped.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !bytes[i..].starts_with(b"*/") {
+                    i += 1;
+                }
+                i += 2;
+            }
+            quote @ (b'"' | b'\'') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    i += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Str,
+                    text: &source[start + 1..i.min(bytes.len())],
+                    offset: start,
+                    len: (i + 1).min(bytes.len()) - start,
+                });
+                i += 1;
+            }
+            b if b.is_ascii_alphabetic() || b == b'_' => {
+                while i < bytes.len()
+                    && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || bytes[i] == b'.')
+                {
+                    i += 1;
+                }
+                let text = source[start..i].trim_end_matches('.');
+                tokens.push(Token {
+                    kind: TokenKind::Ident,
+                    text,
+                    offset: start,
+                    len: text.len(),
+                });
+            }
+            b if b.is_ascii_digit() => {
+                while i < bytes.len()
+                    && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || bytes[i] == b'.')
+                {
+                    i += 1;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    tokens
+}
+
+// Walks the parsed file in source order and locates every name in the tokens
+// after the previously located one.
+struct Indexer<'a> {
+    path: String,
+    dir: PathBuf,
+    tokens: Vec<Token<'a>>,
+    source: &'a str,
+    cursor: usize,
+    index: FileIndex,
+}
+
+impl Indexer<'_> {
+    fn locate(&mut self, kind: TokenKind, text: &str) -> Option<Location> {
+        let found = self.tokens[self.cursor..]
+            .iter()
+            .position(|t| t.kind == kind && t.text == text)?;
+        self.cursor += found + 1;
+        let token = &self.tokens[self.cursor - 1];
+        let (line, column) = line_col(self.source, token.offset);
+        Some(Location {
+            offset: token.offset,
+            len: token.len,
+            line,
+            column,
+        })
+    }
+
+    fn define(&mut self, name: String, kind: SymbolKind, doc: &Option<FastStr>) {
+        let local = name.rsplit('.').next().unwrap_or_default().to_string();
+        if let Some(location) = self.locate(TokenKind::Ident, &local) {
+            self.index.definitions.push(Definition {
+                name,
+                kind,
+                location,
+                doc: doc.as_ref().map(|d| d.to_string()),
+            });
+        }
+    }
+
+    // skips a name that is neither a definition nor a reference
+    fn skip(&mut self, name: &str) {
+        self.locate(TokenKind::Ident, name);
+    }
+
+    fn reference(&mut self, path: &Path) {
+        let name = path
+            .segments
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>()
+            .join(".");
+        let Some(location) = self.locate(TokenKind::Ident, &name) else {
+            return;
+        };
+        let target = match &*path.segments {
+            [scope, rest @ ..] if !rest.is_empty() => self
+                .index
+                .includes
+                .iter()
+                .find(|i| {
+                    FsPath::new(&i.path).file_stem().and_then(|s| s.to_str())
+                        == Some(scope.as_str())
+                })
+                .map(|i| Target {
+                    file: i.resolved.clone(),
+                    name: rest
+                        .iter()
+                        .map(|s| s.as_str())
+                        .collect::<Vec<_>>()
+                        .join("."),
+                }),
+            _ => None,
+        };
+        let target = target.unwrap_or_else(|| Target {
+            file: self.path.clone(),
+            name: name.clone(),
+        });
+        self.index.references.push(Reference {
+            name,
+            target,
+            location,
+        });
+    }
+
+    fn file(&mut self, file: &File) {
+        for item in &file.items {
+            match item {
+                Item::Include(include) => {
+                    if let Some(location) = self.locate(TokenKind::Str, &include.path) {
+                        self.index.includes.push(IncludeEdge {
+                            path: include.path.0.clone(),
+                            resolved: self.dir.join(&*include.path).display().to_string(),
+                            location,
+                        });
+                    }
+                }
+                Item::CppInclude(_) | Item::Namespace(_) => {}
+                Item::Typedef(t) => {
+                    self.ty(&t.r#type);
+                    self.define(t.alias.to_string(), SymbolKind::Typedef, &t.doc);
+                }
+                Item::Constant(c) => {
+                    self.ty(&c.r#type);
+                    self.define(c.name.to_string(), SymbolKind::Const, &c.doc);
+                    self.const_value(&c.value);
+                }
+                Item::Enum(e) => {
+                    self.define(e.name.to_string(), SymbolKind::Enum, &e.doc);
+                    for value in &e.values {
+                        self.define(
+                            format!("{}.{}", e.name.as_str(), value.name.as_str()),
+                            SymbolKind::EnumValue,
+                            &value.doc,
+                        );
+                    }
+                }
+                Item::Struct(s) => self.struct_like(s, SymbolKind::Struct, &s.doc),
+                Item::Union(u) => self.struct_like(u, SymbolKind::Union, &u.doc),
+                Item::Exception(e) => self.struct_like(e, SymbolKind::Exception, &e.doc),
+                Item::Service(s) => {
+                    self.define(s.name.to_string(), SymbolKind::Service, &s.doc);
+                    if let Some(extends) = &s.extends {
+                        self.reference(extends);
+                    }
+                    for f in &s.functions {
+                        self.ty(&f.result_type);
+                        self.define(
+                            format!("{}.{}", s.name.as_str(), f.name.as_str()),
+                            SymbolKind::Method,
+                            &f.doc,
+                        );
+                        for arg in f.arguments.iter().chain(&f.throws) {
+                            self.field(arg, None);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn struct_like(&mut self, s: &StructLike, kind: SymbolKind, doc: &Option<FastStr>) {
+        self.define(s.name.to_string(), kind, doc);
+        for field in &s.fields {
+            self.field(field, Some(&s.name));
+        }
+    }
+
+    fn field(&mut self, field: &Field, parent: Option<&str>) {
+        self.ty(&field.ty);
+        match parent {
+            Some(parent) => self.define(
+                format!("{parent}.{}", field.name.as_str()),
+                SymbolKind::Field,
+                &field.doc,
+            ),
+            None => self.skip(&field.name),
+        }
+        if let Some(default) = &field.default {
+            self.const_value(default);
+        }
+    }
+
+    fn ty(&mut self, ty: &Type) {
+        match &ty.0 {
+            Ty::List { value, .. } | Ty::Set { value, .. } => self.ty(value),
+            Ty::Map { key, value, .. } => {
+                self.ty(key);
+                self.ty(value);
+            }
+            Ty::Path(path) => self.reference(path),
+            _ => {}
+        }
+    }
+
+    fn const_value(&mut self, value: &ConstValue) {
+        match value {
+            ConstValue::Path(path) => self.reference(path),
+            ConstValue::List(values) => values.iter().for_each(|v| self.const_value(v)),
+            ConstValue::Map(entries) => {
+                for (k, v) in entries {
+                    self.const_value(k);
+                    self.const_value(v);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn line_col(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before[before.rfind('\n').map_or(0, |i| i + 1)..]
+        .chars()
+        .count()
+        + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(path: &str, source: &str) -> FileIndex {
+        let file = FileParser::new(FileSource::new(source)).parse().unwrap();
+        FileIndex::new(FsPath::new(path), &file, source)
+    }
+
+    #[test]
+    fn test_file_index() {
+        let source = r#"include "common.thrift"
+
+/** struct Item is documented here */
+struct Item {
+    1: required common.Base base,
+    2: optional list<Kind> kinds = [Kind.A],
+}
+
+enum Kind {
+    A = 1,
+    B = 2,
+}
+
+service Shop extends common.BaseService {
+    Item get(1: string Item) throws (1: common.Error err),
+}
+"#;
+        let index = index("idl/shop.thrift", source);
+
+        assert_eq!(index.includes.len(), 1);
+        assert_eq!(index.includes[0].resolved, "idl/common.thrift");
+        assert_eq!(index.includes[0].location.line, 1);
+        assert_eq!(index.includes[0].location.column, 9);
+
+        let defs = index
+            .definitions
+            .iter()
+            .map(|d| (d.name.as_str(), d.kind, d.location.line, d.location.column))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            defs,
+            [
+                ("Item", SymbolKind::Struct, 4, 8),
+                ("Item.base", SymbolKind::Field, 5, 29),
+                ("Item.kinds", SymbolKind::Field, 6, 28),
+                ("Kind", SymbolKind::Enum, 9, 6),
+                ("Kind.A", SymbolKind::EnumValue, 10, 5),
+                ("Kind.B", SymbolKind::EnumValue, 11, 5),
+                ("Shop", SymbolKind::Service, 14, 9),
+                ("Shop.get", SymbolKind::Method, 15, 10),
+            ]
+        );
+
+        let refs = index
+            .references
+            .iter()
+            .map(|r| {
+                (
+                    r.name.as_str(),
+                    r.target.file.as_str(),
+                    r.target.name.as_str(),
+                    r.location.line,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            refs,
+            [
+                ("common.Base", "idl/common.thrift", "Base", 5),
+                ("Kind", "idl/shop.thrift", "Kind", 6),
+                ("Kind.A", "idl/shop.thrift", "Kind.A", 6),
+                ("common.BaseService", "idl/common.thrift", "BaseService", 14),
+                ("Item", "idl/shop.thrift", "Item", 15),
+                ("common.Error", "idl/common.thrift", "Error", 15),
+            ]
+        );
+
+        assert_eq!(
+            index.definitions[0].doc.as_deref(),
+            Some("struct Item is documented here")
+        );
+
+        let base = index.references[0].location;
+        assert!(matches!(
+            index.symbol_at(base.offset + 2),
+            Some(SymbolAt::Reference(r)) if r.name == "common.Base"
+        ));
+    }
+
+    #[test]
+    fn test_symbol_index() {
+        let dir = std::env::temp_dir().join(format!("pilota-index-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("common.thrift"), "struct Base {}\n").unwrap();
+        std::fs::write(
+            dir.join("shop.thrift"),
+            "include \"common.thrift\"\nstruct Item { 1: common.Base base }\n",
+        )
+        .unwrap();
+
+        let index = SymbolIndex::build([dir.join("shop.thrift")]).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(index.files.len(), 2);
+        let common = dir.join("common.thrift").display().to_string();
+        let reference = &index.files[0].references[0];
+        assert_eq!(reference.target.file, common);
+        assert_eq!(
+            index.definition(&reference.target).unwrap().kind,
+            SymbolKind::Struct
+        );
+        assert_eq!(index.references(&common, "Base").count(), 1);
+
+        assert_eq!(SymbolIndex::from_json(&index.to_json()).unwrap(), index);
+    }
+}
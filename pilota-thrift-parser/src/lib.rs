@@ -4,10 +4,12 @@
 #![cfg_attr(not(doctest), doc = include_str!("../README.md"))]
 
 pub mod descriptor;
+#[cfg(feature = "index")]
+pub mod index;
 pub mod parser;
 
 pub use descriptor::*;
 pub use parser::{
-    error::Error,
+    error::{Error, SyntaxError},
     thrift::{FileParser, FileSource},
 };
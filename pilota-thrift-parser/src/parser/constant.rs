@@ -75,6 +75,7 @@ impl Constant {
             .map(
                 |(((((comments, r#type), name), value), annotations), trailing_comments)| {
                     Constant {
+                        doc: Components::doc(&comments),
                         leading_comments: FastStr::from(comments.join("\n\n")),
                         name: Ident(name.into()),
                         r#type,
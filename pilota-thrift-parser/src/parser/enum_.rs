@@ -28,6 +28,7 @@ impl EnumValue {
             .then_ignore(Components::blank().or_not())
             .map(
                 |((((comments, name), value), annotations), trailing_comments)| EnumValue {
+                    doc: Components::doc(&comments),
                     leading_comments: FastStr::from(comments.join("\n\n")),
                     name: Ident(name.into()),
                     value,
@@ -62,6 +63,7 @@ impl Enum {
                     (((((leading, name), name_comments), values), comments), annotations),
                     trailing,
                 )| Enum {
+                    doc: Components::doc(&leading),
                     leading_comments: FastStr::from(format!(
                         "{}\n\n{}\n\n{}",
                         leading.join("\n\n"),
@@ -9,11 +9,20 @@ pub enum Error {
     #[error("Syntax error: {source}")]
     Syntax {
         summary: FastStr,
+        errors: Vec<SyntaxError>,
         #[source]
         source: anyhow::Error,
     },
 }
 
+/// A single error of [`Error::Syntax`].
+#[derive(Debug, Clone)]
+pub struct SyntaxError {
+    /// The byte range of the source the error is about.
+    pub span: std::ops::Range<usize>,
+    pub message: FastStr,
+}
+
 // for unwrap
 impl std::fmt::Debug for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -42,6 +42,7 @@ impl Field {
                     ((((((comments, id), attribute), r#type), name), value), annotations),
                     trailing_comments,
                 )| Field {
+                    doc: Components::doc(&comments),
                     leading_comments: FastStr::from(comments.join("\n\n")),
                     id: id.parse().unwrap(),
                     attribute: attribute.unwrap_or_default(),
@@ -55,6 +55,7 @@ impl Function {
                         }
                     });
                     Function {
+                        doc: Components::doc(&comments),
                         leading_comments: FastStr::from(comments.join("\n\n")),
                         name: Ident(name.into()),
                         oneway: ow,
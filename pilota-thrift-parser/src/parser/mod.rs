@@ -73,19 +73,51 @@ impl Components {
                 )
                 .padded_by(Components::blank().or_not())
                 .map(|(_, content)| FastStr::from(format!("//{}", content))),
+            Components::block_comment()
+                .padded_by(Components::blank().or_not())
+                .map(FastStr::new),
+        ))
+    }
+
+    /// A `/* */` comment, which may contain nested block comments.
+    pub fn block_comment<'a>() -> impl Parser<'a, &'a str, &'a str, extra::Err<Rich<'a, char>>> {
+        recursive(|block| {
             just("/*")
                 .then(
-                    any()
-                        .and_is(just("*/").not())
-                        .repeated()
-                        .collect::<String>(),
+                    choice((
+                        block.ignored(),
+                        any()
+                            .and_is(just("*/").not())
+                            .and_is(just("/*").not())
+                            .ignored(),
+                    ))
+                    .repeated(),
                 )
                 .then(just("*/"))
-                .padded_by(Components::blank().or_not())
-                .map(|((start, content), end)| {
-                    FastStr::from(format!("{}{}{}", start, content, end))
-                }),
-        ))
+                .to_slice()
+        })
+    }
+
+    /// Returns the text of the doc comment of a definition, the `/** */`
+    /// block closest to it among its leading `comments`, without the comment
+    /// markers and the leading `*` of each line.
+    pub fn doc(comments: &[FastStr]) -> Option<FastStr> {
+        let doc = comments.last()?.strip_prefix("/**")?.strip_suffix("*/")?;
+        if doc.starts_with('*') || doc.starts_with('/') {
+            // `/***` is a separator line and `/**/` an empty block comment
+            return None;
+        }
+        let lines = doc
+            .lines()
+            .map(|line| {
+                let line = line.trim_start();
+                let line = line.strip_prefix('*').unwrap_or(line);
+                line.strip_prefix(' ').unwrap_or(line).trim_end()
+            })
+            .skip_while(|line| line.is_empty())
+            .collect::<Vec<_>>();
+        let end = lines.iter().rposition(|line| !line.is_empty())? + 1;
+        Some(FastStr::from(lines[..end].join("\n")))
     }
 
     pub fn trailing_comment<'a>() -> impl Parser<'a, &'a str, FastStr, extra::Err<Rich<'a, char>>> {
@@ -111,18 +143,9 @@ impl Components {
                     )
                     .then_ignore(Components::blank().or_not())
                     .map(|(_, content)| FastStr::from(format!("//{}", content))),
-                just("/*")
-                    .then(
-                        any()
-                            .and_is(just("*/").not())
-                            .repeated()
-                            .collect::<String>(),
-                    )
-                    .then(just("*/"))
+                Components::block_comment()
                     .then_ignore(Components::blank().or_not())
-                    .map(|((start, content), end)| {
-                        FastStr::from(format!("{}{}{}", start, content, end))
-                    }),
+                    .map(FastStr::new),
             )))
             .map(|(_, c)| c)
     }
@@ -135,10 +158,7 @@ impl Components {
             just("#")
                 .then(any().and_is(just('\n').not()).repeated())
                 .ignored(),
-            just("/*")
-                .then(any().and_is(just("*/").not()).repeated())
-                .then(just("*/"))
-                .ignored(),
+            Components::block_comment().ignored(),
             one_of(" \t\r\n").ignored(),
         ))
         .repeated()
@@ -163,7 +183,7 @@ mod tests {
 
     #[test]
     fn test_blank() {
-        let _ = Components::blank().parse(" \t\r\n").unwrap();
+        Components::blank().parse(" \t\r\n").unwrap();
     }
 
     #[test]
@@ -181,31 +201,59 @@ mod tests {
 
     #[test]
     fn test_comment() {
-        let _ = Components::comment().parse("// foo").unwrap();
-        let _ = Components::comment()
+        Components::comment().parse("// foo").unwrap();
+        Components::comment()
             .parse("# From 133120 ~ 134143\n")
             .unwrap();
-        let _ = Components::comment().parse("/* foo */").unwrap();
+        Components::comment().parse("/* foo */").unwrap();
+        assert_eq!(
+            Components::comment()
+                .parse("/* foo /* bar */ baz */")
+                .unwrap(),
+            "/* foo /* bar */ baz */"
+        );
+        assert!(Components::comment().parse("/* foo /* bar */").has_errors());
+    }
+
+    #[test]
+    fn test_doc() {
+        let doc = |comments: &[&str]| {
+            Components::doc(
+                &comments
+                    .iter()
+                    .map(|c| FastStr::new(*c))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(
+            doc(&["// foo", "/**\n * Greets.\n *\n * Twice.\n */"]).as_deref(),
+            Some("Greets.\n\nTwice.")
+        );
+        assert_eq!(doc(&["/** inline */"]).as_deref(), Some("inline"));
+        assert_eq!(doc(&["/** doc */", "// code();"]), None);
+        assert_eq!(doc(&["/* plain */"]), None);
+        assert_eq!(doc(&["/**/"]), None);
+        assert_eq!(doc(&["/*********/"]), None);
     }
 
     #[test]
     fn test_trailing_comment() {
-        let _ = Components::trailing_comment().parse(" // foo").unwrap();
-        let _ = Components::trailing_comment().parse(" # foo").unwrap();
-        let _ = Components::trailing_comment().parse(" /* foo */").unwrap();
+        Components::trailing_comment().parse(" // foo").unwrap();
+        Components::trailing_comment().parse(" # foo").unwrap();
+        Components::trailing_comment().parse(" /* foo */").unwrap();
     }
 
     #[test]
     fn test_blank_with_comments() {
-        let _ = Components::blank_with_comments().parse(" // foo").unwrap();
-        let _ = Components::blank_with_comments().parse(" # foo").unwrap();
-        let _ = Components::blank_with_comments()
+        Components::blank_with_comments().parse(" // foo").unwrap();
+        Components::blank_with_comments().parse(" # foo").unwrap();
+        Components::blank_with_comments()
             .parse(" /* foo */")
             .unwrap();
     }
 
     #[test]
     fn test_blank_without_newline() {
-        let _ = Components::blank_without_newline().parse(" \t\r").unwrap();
+        Components::blank_without_newline().parse(" \t\r").unwrap();
     }
 }
@@ -43,6 +43,7 @@ impl Service {
                     trailing,
                 )| {
                     Service {
+                        doc: Components::doc(&leading),
                         leading_comments: FastStr::from(format!(
                             "{}\n\n{}\n\n{}",
                             leading.join("\n\n"),
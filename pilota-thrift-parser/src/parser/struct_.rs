@@ -25,6 +25,7 @@ impl Struct {
                     struct_like.comments
                 ));
                 Struct {
+                    doc: Components::doc(&comments),
                     leading_comments,
                     struct_like,
                     trailing_comments: trailing_comments.unwrap_or_default(),
@@ -45,6 +46,7 @@ impl Union {
             .then(Components::trailing_comment().or_not())
             .then_ignore(Components::blank().or_not())
             .map(|((comments, struct_like), trailing_comments)| Union {
+                doc: Components::doc(&comments),
                 leading_comments: FastStr::from(comments.join("\n\n")),
                 struct_like,
                 trailing_comments: trailing_comments.unwrap_or_default(),
@@ -64,6 +66,7 @@ impl Exception {
             .then(Components::trailing_comment().or_not())
             .then_ignore(Components::blank().or_not())
             .map(|((comments, struct_like), trailing_comments)| Exception {
+                doc: Components::doc(&comments),
                 leading_comments: FastStr::from(comments.join("\n\n")),
                 struct_like,
                 trailing_comments: trailing_comments.unwrap_or_default(),
@@ -159,4 +162,23 @@ mod tests {
         }"#;
         let _ = Struct::get_parser().parse(input).unwrap();
     }
+    #[test]
+    fn test_struct_doc() {
+        let input = r#"
+        /**
+         * A user.
+         */
+        struct User {
+            /** The id. */
+            1: required i64 id,
+            // 2: required string name,
+            /* 3: optional /* nested */ string email, */
+            4: optional string phone,
+        }"#;
+        let s = Struct::get_parser().parse(input).unwrap();
+        assert_eq!(s.doc.as_deref(), Some("A user."));
+        assert_eq!(s.fields.len(), 2);
+        assert_eq!(s.fields[0].doc.as_deref(), Some("The id."));
+        assert_eq!(s.fields[1].doc, None);
+    }
 }
@@ -114,9 +114,17 @@ impl<'a> FileParser<'a> {
             let report = report_strings.join("\n").into();
             let summary = create_error_summary(&errs, path_str, self.source.content).into();
             let custom_error = CustomSyntaxError { report };
+            let errors = errs
+                .iter()
+                .map(|e| error::SyntaxError {
+                    span: e.span().into_range(),
+                    message: e.reason().to_string().into(),
+                })
+                .collect();
 
             return Err(error::Error::Syntax {
                 summary,
+                errors,
                 source: anyhow::anyhow!(custom_error),
             });
         }
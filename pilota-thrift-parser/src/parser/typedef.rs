@@ -21,6 +21,7 @@ impl Typedef {
             .then_ignore(Components::blank().or_not())
             .map(
                 |((((comments, r#type), alias), annotations), trailing_comments)| Typedef {
+                    doc: Components::doc(&comments),
                     leading_comments: FastStr::from(comments.join("\n\n")),
                     r#type,
                     alias: Ident(alias.into()),
@@ -0,0 +1,346 @@
+use bytes::{Bytes, BytesMut};
+use pilota::{
+    FastStr,
+    thrift::{
+        Message, ProtocolExceptionKind, TInputProtocol, TOutputProtocol, TType, ThriftException,
+        VOID_IDENT, binary::TBinaryProtocol, new_protocol_exception,
+    },
+};
+
+use crate::thrift_reflection::{FieldDescriptor, StructDescriptor, TypeDescriptor};
+
+/// How a matched string or binary value is rewritten.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Action {
+    /// Replace the value with the hex encoded 64-bit FNV-1a hash of it, so
+    /// equal values are still equal after anonymization.
+    #[default]
+    Hash,
+    /// Replace every byte of the value with zero, keeping its length.
+    Zero,
+}
+
+impl Action {
+    fn apply(&self, value: &[u8]) -> Bytes {
+        match self {
+            Action::Hash => {
+                let hash = value.iter().fold(0xcbf29ce484222325u64, |hash, b| {
+                    (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+                });
+                Bytes::from(format!("{hash:016x}"))
+            }
+            Action::Zero => Bytes::from(vec![0; value.len()]),
+        }
+    }
+}
+
+/// Selects the string and binary fields rewritten by [`anonymize`].
+///
+/// A field matches if it carries one of the `annotations` in the IDL, or if
+/// its path from the root struct (field names joined by `.`, e.g.
+/// `user.email`) is one of the `paths`. Containers are transparent: every
+/// string inside a matched `list<string>` field is rewritten.
+#[derive(Clone, Debug, Default)]
+pub struct Policy {
+    pub annotations: Vec<FastStr>,
+    pub paths: Vec<FastStr>,
+    pub action: Action,
+}
+
+impl Policy {
+    pub fn new(action: Action) -> Self {
+        Self {
+            action,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_annotation(mut self, key: impl Into<FastStr>) -> Self {
+        self.annotations.push(key.into());
+        self
+    }
+
+    pub fn with_path(mut self, path: impl Into<FastStr>) -> Self {
+        self.paths.push(path.into());
+        self
+    }
+
+    fn matches(&self, path: &str, field: &FieldDescriptor) -> bool {
+        self.paths.iter().any(|p| p == path)
+            || self
+                .annotations
+                .iter()
+                .any(|a| field.annotations.contains_key(a))
+    }
+}
+
+/// Returns a copy of `msg` with the fields selected by `policy` anonymized.
+pub fn anonymize<M: Message>(
+    msg: &M,
+    desc: &StructDescriptor,
+    policy: &Policy,
+) -> Result<M, ThriftException> {
+    let mut buf = BytesMut::new();
+    msg.encode(&mut TBinaryProtocol::new(&mut buf, false))?;
+    let mut data = anonymize_bytes(buf.freeze(), desc, policy)?;
+    M::decode(&mut TBinaryProtocol::new(&mut data, false))
+}
+
+/// Anonymizes a binary protocol encoded struct described by `desc`.
+///
+/// Fields unknown to the descriptor are copied unchanged.
+pub fn anonymize_bytes(
+    mut data: Bytes,
+    desc: &StructDescriptor,
+    policy: &Policy,
+) -> Result<Bytes, ThriftException> {
+    let mut out = BytesMut::with_capacity(data.len());
+    Anonymizer {
+        input: TBinaryProtocol::new(&mut data, false),
+        output: TBinaryProtocol::new(&mut out, false),
+        policy,
+    }
+    .anonymize_struct(Some(desc), "")?;
+    Ok(out.freeze())
+}
+
+struct Anonymizer<'a> {
+    input: TBinaryProtocol<&'a mut Bytes>,
+    output: TBinaryProtocol<&'a mut BytesMut>,
+    policy: &'a Policy,
+}
+
+impl Anonymizer<'_> {
+    fn anonymize_struct(
+        &mut self,
+        desc: Option<&StructDescriptor>,
+        path: &str,
+    ) -> Result<(), ThriftException> {
+        self.input.read_struct_begin()?;
+        self.output.write_struct_begin(&VOID_IDENT)?;
+        loop {
+            let field_ident = self.input.read_field_begin()?;
+            if field_ident.field_type == TType::Stop {
+                break;
+            }
+            let id = field_ident.id.unwrap_or_default();
+            let field = desc.and_then(|desc| desc.find_field_by_id(id as i32));
+            let field_path = match field {
+                Some(field) if path.is_empty() => field.name.to_string(),
+                Some(field) => format!("{path}.{}", field.name),
+                None => format!("{path}.{id}"),
+            };
+            let matched = field.is_some_and(|field| self.policy.matches(&field_path, field));
+
+            self.output.write_field_begin(field_ident.field_type, id)?;
+            self.anonymize_value(
+                field_ident.field_type,
+                field.map(|field| &field.r#type),
+                &field_path,
+                matched,
+            )?;
+            self.input.read_field_end()?;
+            self.output.write_field_end()?;
+        }
+        self.input.read_struct_end()?;
+        self.output.write_field_stop()?;
+        self.output.write_struct_end()
+    }
+
+    fn anonymize_value(
+        &mut self,
+        ttype: TType,
+        ty: Option<&TypeDescriptor>,
+        path: &str,
+        matched: bool,
+    ) -> Result<(), ThriftException> {
+        match ttype {
+            TType::Bool => {
+                let v = self.input.read_bool()?;
+                self.output.write_bool(v)
+            }
+            TType::I8 => {
+                let v = self.input.read_i8()?;
+                self.output.write_i8(v)
+            }
+            TType::I16 => {
+                let v = self.input.read_i16()?;
+                self.output.write_i16(v)
+            }
+            TType::I32 => {
+                let v = self.input.read_i32()?;
+                self.output.write_i32(v)
+            }
+            TType::I64 => {
+                let v = self.input.read_i64()?;
+                self.output.write_i64(v)
+            }
+            TType::Double => {
+                let v = self.input.read_double()?;
+                self.output.write_double(v)
+            }
+            TType::Uuid => {
+                let v = self.input.read_uuid()?;
+                self.output.write_uuid(v)
+            }
+            TType::Binary => {
+                let v = self.input.read_bytes()?;
+                if matched {
+                    self.output.write_bytes(self.policy.action.apply(&v))
+                } else {
+                    self.output.write_bytes(v)
+                }
+            }
+            TType::Struct => {
+                let desc = ty.and_then(|ty| ty.get_struct_desc());
+                self.anonymize_struct(desc.as_ref(), path)
+            }
+            TType::List => {
+                let list_ident = self.input.read_list_begin()?;
+                self.output.write_list_begin(list_ident)?;
+                let el_ty = ty.and_then(|ty| ty.value_type.as_deref());
+                for _ in 0..list_ident.size {
+                    self.anonymize_value(list_ident.element_type, el_ty, path, matched)?;
+                }
+                self.input.read_list_end()?;
+                self.output.write_list_end()
+            }
+            TType::Set => {
+                let set_ident = self.input.read_set_begin()?;
+                self.output.write_set_begin(set_ident)?;
+                let el_ty = ty.and_then(|ty| ty.value_type.as_deref());
+                for _ in 0..set_ident.size {
+                    self.anonymize_value(set_ident.element_type, el_ty, path, matched)?;
+                }
+                self.input.read_set_end()?;
+                self.output.write_set_end()
+            }
+            TType::Map => {
+                let map_ident = self.input.read_map_begin()?;
+                self.output.write_map_begin(map_ident)?;
+                let key_ty = ty.and_then(|ty| ty.key_type.as_deref());
+                let val_ty = ty.and_then(|ty| ty.value_type.as_deref());
+                for _ in 0..map_ident.size {
+                    self.anonymize_value(map_ident.key_type, key_ty, path, matched)?;
+                    self.anonymize_value(map_ident.value_type, val_ty, path, matched)?;
+                }
+                self.input.read_map_end()?;
+                self.output.write_map_end()
+            }
+            TType::Stop | TType::Void => Err(new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                format!("unexpected ttype {ttype:?} at {path}"),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use pilota::FastStr;
+
+    use super::{Action, Policy, anonymize};
+    use crate::{service::Register, thrift_reflection::*};
+
+    fn descriptor_file() -> FileDescriptor {
+        let idl_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("idl/descriptor.thrift");
+        let content = std::fs::read_to_string(&idl_path).unwrap();
+        let mut parsed_file = pilota_thrift_parser::parser::thrift::FileParser::new(
+            pilota_thrift_parser::FileSource::new_with_path(idl_path.clone(), &content).unwrap(),
+        )
+        .parse()
+        .unwrap();
+        parsed_file.path = idl_path.into();
+
+        let descriptor: FileDescriptor = (&parsed_file).into();
+        Register::register(descriptor.filepath.clone(), descriptor.clone());
+        descriptor
+    }
+
+    fn type_descriptor() -> TypeDescriptor {
+        TypeDescriptor {
+            filepath: "a.thrift".into(),
+            name: "map".into(),
+            key_type: Some(Box::new(TypeDescriptor {
+                filepath: "a.thrift".into(),
+                name: "string".into(),
+                ..Default::default()
+            })),
+            value_type: None,
+            extra: Some(
+                [(FastStr::from_static_str("k"), FastStr::from_static_str("v"))]
+                    .into_iter()
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_anonymize_by_path() {
+        let file = descriptor_file();
+        let desc = file.find_struct_by_name("TypeDescriptor").unwrap();
+        let policy = Policy::new(Action::Hash)
+            .with_path("filepath")
+            .with_path("key_type.name")
+            .with_path("extra");
+
+        let original = type_descriptor();
+        let anonymized = anonymize(&original, desc, &policy).unwrap();
+
+        assert_ne!(anonymized.filepath, original.filepath);
+        assert_eq!(anonymized.filepath.len(), 16);
+        assert_eq!(anonymized.name, original.name);
+
+        let key_type = anonymized.key_type.as_ref().unwrap();
+        assert_eq!(
+            key_type.filepath,
+            original.key_type.as_ref().unwrap().filepath
+        );
+        // hashing is deterministic
+        assert_eq!(
+            key_type.name,
+            anonymize(&original, desc, &policy)
+                .unwrap()
+                .key_type
+                .unwrap()
+                .name
+        );
+
+        let (k, v) = anonymized.extra.as_ref().unwrap().iter().next().unwrap();
+        assert_ne!(k, "k");
+        assert_ne!(v, "v");
+    }
+
+    #[test]
+    fn test_anonymize_zero() {
+        let file = descriptor_file();
+        let desc = file.find_struct_by_name("TypeDescriptor").unwrap();
+        let policy = Policy::new(Action::Zero).with_path("name");
+
+        let original = type_descriptor();
+        let anonymized = anonymize(&original, desc, &policy).unwrap();
+        assert_eq!(anonymized.name, "\0\0\0");
+        assert_eq!(anonymized.filepath, original.filepath);
+        assert_eq!(anonymized.key_type, original.key_type);
+    }
+
+    #[test]
+    fn test_anonymize_by_annotation() {
+        let file = descriptor_file();
+        let mut desc = file.find_struct_by_name("TypeDescriptor").unwrap().clone();
+        desc.fields
+            .iter_mut()
+            .find(|f| f.name == "filepath")
+            .unwrap()
+            .annotations
+            .insert("pii".into(), vec!["true".into()]);
+        let policy = Policy::new(Action::Zero).with_annotation("pii");
+
+        let original = type_descriptor();
+        let anonymized = anonymize(&original, &desc, &policy).unwrap();
+        assert_eq!(anonymized.filepath, "\0".repeat(original.filepath.len()));
+        assert_eq!(anonymized.name, original.name);
+    }
+}
@@ -0,0 +1,106 @@
+//! Generates a reproducible corpus of binary protocol encoded payloads.
+//!
+//! ```text
+//! pilota-corpus gen <idl> <struct> [--seed N] [--count N] [--out DIR]
+//!     [--max-string-len N] [--max-container-len N] [--max-depth N]
+//!     [--optional-probability P]
+//! ```
+//!
+//! Payloads are written to `DIR/<struct>-<index>.bin`.
+
+use std::{path::PathBuf, process::ExitCode, str::FromStr};
+
+use pilota_thrift_reflect::corpus::{CorpusConfig, Generator, load_idl};
+
+const USAGE: &str = "usage: pilota-corpus gen <idl> <struct> [--seed N] [--count N] [--out DIR] \
+                     [--max-string-len N] [--max-container-len N] [--max-depth N] \
+                     [--optional-probability P]";
+
+struct Args {
+    idl: PathBuf,
+    name: String,
+    seed: u64,
+    count: usize,
+    out: PathBuf,
+    config: CorpusConfig,
+}
+
+fn parse<T: FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("missing value for {flag}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {flag}: {value}"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    match args.next().as_deref() {
+        Some("gen") => {}
+        Some(cmd) => return Err(format!("unknown subcommand: {cmd}")),
+        None => return Err("missing subcommand".into()),
+    }
+
+    let mut positional = Vec::new();
+    let mut seed = 0;
+    let mut count = 100;
+    let mut out = PathBuf::from("corpus");
+    let mut config = CorpusConfig::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = parse(&arg, args.next())?,
+            "--count" => count = parse(&arg, args.next())?,
+            "--out" => out = parse(&arg, args.next())?,
+            "--max-string-len" => config.max_string_len = parse(&arg, args.next())?,
+            "--max-container-len" => config.max_container_len = parse(&arg, args.next())?,
+            "--max-depth" => config.max_depth = parse(&arg, args.next())?,
+            "--optional-probability" => config.optional_probability = parse(&arg, args.next())?,
+            _ if arg.starts_with("--") => return Err(format!("unknown flag: {arg}")),
+            _ => positional.push(arg),
+        }
+    }
+
+    let [idl, name] = <[String; 2]>::try_from(positional)
+        .map_err(|_| "expected <idl> and <struct> arguments".to_string())?;
+    Ok(Args {
+        idl: idl.into(),
+        name,
+        seed,
+        count,
+        out,
+        config,
+    })
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let file = load_idl(&args.idl).map_err(|e| e.to_string())?;
+    let desc = file
+        .find_struct_by_name(&args.name)
+        .or_else(|| file.find_exception_by_name(&args.name))
+        .or_else(|| file.unions.iter().find(|u| u.name == args.name))
+        .ok_or_else(|| format!("struct {} not found in {}", args.name, args.idl.display()))?;
+
+    std::fs::create_dir_all(&args.out).map_err(|e| e.to_string())?;
+    let mut generator = Generator::new(args.seed, args.config);
+    for i in 0..args.count {
+        let payload = generator.generate(desc).map_err(|e| e.to_string())?;
+        let path = args.out.join(format!("{}-{i:05}.bin", args.name));
+        std::fs::write(&path, payload).map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use std::path::{Path, PathBuf};
+
+use bytes::{Bytes, BytesMut};
+use pilota::{
+    FastStr,
+    thrift::{
+        TListIdentifier, TMapIdentifier, TOutputProtocol, TSetIdentifier, TType, ThriftException,
+        VOID_IDENT, binary::TBinaryProtocol,
+    },
+};
+
+use crate::{
+    ThriftType,
+    error::ReflectorError,
+    service::{IncludePath, Register},
+    thrift_reflection::{EnumDescriptor, FileDescriptor, StructDescriptor, TypeDescriptor},
+};
+
+/// Size distribution of the generated payloads.
+///
+/// Lengths are drawn uniformly from `0..=max`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CorpusConfig {
+    pub max_string_len: usize,
+    pub max_container_len: usize,
+    /// Probability that an optional field is present.
+    pub optional_probability: f64,
+    /// Below this depth only required fields are generated and containers are
+    /// left empty, which bounds recursive types.
+    pub max_depth: usize,
+}
+
+impl Default for CorpusConfig {
+    fn default() -> Self {
+        Self {
+            max_string_len: 32,
+            max_container_len: 8,
+            optional_probability: 0.5,
+            max_depth: 8,
+        }
+    }
+}
+
+/// Generates binary protocol encoded payloads for thrift structs from their
+/// descriptors.
+///
+/// The output only depends on the seed, the config and the descriptors, so a
+/// corpus can be regenerated byte for byte across pilota versions.
+pub struct Generator {
+    rng: SplitMix64,
+    config: CorpusConfig,
+}
+
+impl Generator {
+    pub fn new(seed: u64, config: CorpusConfig) -> Self {
+        Self {
+            rng: SplitMix64(seed),
+            config,
+        }
+    }
+
+    /// Generates the next payload of `desc`.
+    ///
+    /// All files the struct refers to must be registered in [`Register`], see
+    /// [`load_idl`].
+    pub fn generate(&mut self, desc: &StructDescriptor) -> Result<Bytes, ThriftException> {
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, false);
+        self.write_struct(&mut protocol, desc, false, 0)?;
+        Ok(buf.freeze())
+    }
+
+    fn write_struct<P: TOutputProtocol>(
+        &mut self,
+        protocol: &mut P,
+        desc: &StructDescriptor,
+        is_union: bool,
+        depth: usize,
+    ) -> Result<(), ThriftException> {
+        protocol.write_struct_begin(&VOID_IDENT)?;
+        if is_union {
+            if !desc.fields.is_empty() {
+                let field = &desc.fields[self.rng.below(desc.fields.len())];
+                protocol.write_field_begin(self.ttype(&field.r#type), field.id as i16)?;
+                self.write_value(protocol, &field.r#type, depth + 1)?;
+                protocol.write_field_end()?;
+            }
+        } else {
+            for field in desc.fields.iter() {
+                let present = match field.requiredness.as_str() {
+                    "required" => true,
+                    _ if depth >= self.config.max_depth => false,
+                    "optional" => self.rng.chance(self.config.optional_probability),
+                    _ => true,
+                };
+                if !present {
+                    continue;
+                }
+                protocol.write_field_begin(self.ttype(&field.r#type), field.id as i16)?;
+                self.write_value(protocol, &field.r#type, depth + 1)?;
+                protocol.write_field_end()?;
+            }
+        }
+        protocol.write_field_stop()?;
+        protocol.write_struct_end()
+    }
+
+    fn write_value<P: TOutputProtocol>(
+        &mut self,
+        protocol: &mut P,
+        ty: &TypeDescriptor,
+        depth: usize,
+    ) -> Result<(), ThriftException> {
+        match ThriftType::from(ty.name.as_str()) {
+            ThriftType::Bool => protocol.write_bool(self.rng.next() & 1 == 1),
+            ThriftType::Byte | ThriftType::I8 => protocol.write_i8(self.rng.next() as i8),
+            ThriftType::I16 => protocol.write_i16(self.rng.next() as i16),
+            ThriftType::I32 => protocol.write_i32(self.rng.next() as i32),
+            ThriftType::I64 => protocol.write_i64(self.rng.next() as i64),
+            ThriftType::Double => protocol.write_double(self.rng.unit() * 1e6),
+            ThriftType::Uuid => {
+                let mut uuid = [0; 16];
+                uuid[..8].copy_from_slice(&self.rng.next().to_le_bytes());
+                uuid[8..].copy_from_slice(&self.rng.next().to_le_bytes());
+                protocol.write_uuid(uuid)
+            }
+            ThriftType::String => {
+                let len = self.rng.below(self.config.max_string_len + 1);
+                let s: String = (0..len)
+                    .map(|_| (b'a' + self.rng.below(26) as u8) as char)
+                    .collect();
+                protocol.write_faststr(s.into())
+            }
+            ThriftType::Binary => {
+                let len = self.rng.below(self.config.max_string_len + 1);
+                let b: Vec<u8> = (0..len).map(|_| self.rng.next() as u8).collect();
+                protocol.write_bytes(b.into())
+            }
+            ThriftType::List => {
+                let el = ty.value_type.as_deref().expect("list without element type");
+                let len = self.container_len(depth);
+                protocol.write_list_begin(TListIdentifier::new(self.ttype(el), len))?;
+                for _ in 0..len {
+                    self.write_value(protocol, el, depth + 1)?;
+                }
+                protocol.write_list_end()
+            }
+            ThriftType::Set => {
+                let el = ty.value_type.as_deref().expect("set without element type");
+                let len = self.container_len(depth);
+                protocol.write_set_begin(TSetIdentifier::new(self.ttype(el), len))?;
+                for _ in 0..len {
+                    self.write_value(protocol, el, depth + 1)?;
+                }
+                protocol.write_set_end()
+            }
+            ThriftType::Map => {
+                let key = ty.key_type.as_deref().expect("map without key type");
+                let val = ty.value_type.as_deref().expect("map without value type");
+                let len = self.container_len(depth);
+                protocol.write_map_begin(TMapIdentifier::new(
+                    self.ttype(key),
+                    self.ttype(val),
+                    len,
+                ))?;
+                for _ in 0..len {
+                    self.write_value(protocol, key, depth + 1)?;
+                    self.write_value(protocol, val, depth + 1)?;
+                }
+                protocol.write_map_end()
+            }
+            ThriftType::Void => Ok(()),
+            ThriftType::Path(_) => match resolve(ty) {
+                Resolved::Struct(desc) => self.write_struct(protocol, &desc, false, depth),
+                Resolved::Union(desc) => self.write_struct(protocol, &desc, true, depth),
+                Resolved::Enum(desc) => {
+                    let value = if desc.values.is_empty() {
+                        0
+                    } else {
+                        desc.values[self.rng.below(desc.values.len())].value as i32
+                    };
+                    protocol.write_i32(value)
+                }
+                Resolved::Typedef(ty) => self.write_value(protocol, &ty, depth),
+            },
+        }
+    }
+
+    fn container_len(&mut self, depth: usize) -> usize {
+        if depth >= self.config.max_depth {
+            0
+        } else {
+            self.rng.below(self.config.max_container_len + 1)
+        }
+    }
+
+    fn ttype(&self, ty: &TypeDescriptor) -> TType {
+        match ThriftType::from(ty.name.as_str()) {
+            ThriftType::String | ThriftType::Binary => TType::Binary,
+            ThriftType::Bool => TType::Bool,
+            ThriftType::Byte | ThriftType::I8 => TType::I8,
+            ThriftType::I16 => TType::I16,
+            ThriftType::I32 => TType::I32,
+            ThriftType::I64 => TType::I64,
+            ThriftType::Double => TType::Double,
+            ThriftType::Uuid => TType::Uuid,
+            ThriftType::List => TType::List,
+            ThriftType::Set => TType::Set,
+            ThriftType::Map => TType::Map,
+            ThriftType::Void => TType::Void,
+            ThriftType::Path(_) => match resolve(ty) {
+                Resolved::Struct(_) | Resolved::Union(_) => TType::Struct,
+                Resolved::Enum(_) => TType::I32,
+                Resolved::Typedef(ty) => self.ttype(&ty),
+            },
+        }
+    }
+}
+
+/// Parses `path` and every file it includes into [`Register`], returning the
+/// descriptor of `path`.
+pub fn load_idl(path: impl AsRef<Path>) -> Result<FileDescriptor, ReflectorError> {
+    let path = crate::normalize_path(
+        &std::path::absolute(path.as_ref())
+            .map_err(|e| ReflectorError::IdlError(format!("{}: {e}", path.as_ref().display())))?,
+    );
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| ReflectorError::IdlError(format!("{}: {e}", path.display())))?;
+    let mut file = pilota_thrift_parser::parser::thrift::FileParser::new(
+        pilota_thrift_parser::FileSource::new_with_path(path.clone(), &content)
+            .map_err(|e| ReflectorError::IdlError(e.to_string()))?,
+    )
+    .parse()
+    .map_err(|e| ReflectorError::IdlError(e.to_string()))?;
+    file.path = path.into();
+
+    let descriptor: FileDescriptor = (&file).into();
+    Register::register(descriptor.filepath.clone(), descriptor.clone());
+    for include in descriptor.includes.values() {
+        if !Register::contains(include) {
+            load_idl(PathBuf::from(include.as_str()))?;
+        }
+    }
+    Ok(descriptor)
+}
+
+enum Resolved {
+    Struct(StructDescriptor),
+    Union(StructDescriptor),
+    Enum(EnumDescriptor),
+    Typedef(TypeDescriptor),
+}
+
+fn resolve(ty: &TypeDescriptor) -> Resolved {
+    let path = IncludePath::try_from(ty.name.as_str()).unwrap();
+    let cur_file = Register::get(ty.filepath.as_str())
+        .unwrap_or_else(|| panic!("file not registered: {}", ty.filepath));
+    let file_path: FastStr = if path.prefix.is_empty() {
+        ty.filepath.clone()
+    } else {
+        cur_file
+            .includes
+            .get(path.prefix.as_str())
+            .unwrap_or_else(|| panic!("include path not found: {}", path.prefix))
+            .clone()
+    };
+    drop(cur_file);
+    let file = Register::get(file_path.as_str())
+        .unwrap_or_else(|| panic!("file not registered: {file_path}"));
+
+    let name = path.name.as_str();
+    if let Some(s) = file.find_struct_by_name(name) {
+        Resolved::Struct(s.clone())
+    } else if let Some(s) = file.find_exception_by_name(name) {
+        Resolved::Struct(s.clone())
+    } else if let Some(u) = file.unions.iter().find(|u| u.name == name) {
+        Resolved::Union(u.clone())
+    } else if let Some(e) = file.find_enum_by_name(name) {
+        Resolved::Enum(e.clone())
+    } else if let Some(t) = file.typedefs.iter().find(|t| t.alias == name) {
+        Resolved::Typedef(t.r#type.clone())
+    } else {
+        panic!("type not found: {}", ty.name)
+    }
+}
+
+/// SplitMix64, chosen over `rand` generators because its output is fixed by
+/// definition and never changes between releases.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            0
+        } else {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    fn chance(&mut self, p: f64) -> bool {
+        self.unit() < p
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use pilota::thrift::{Message as _, binary::TBinaryProtocol};
+
+    use super::{CorpusConfig, Generator, load_idl};
+    use crate::thrift_reflection::FileDescriptor;
+
+    #[test]
+    fn test_generate_is_reproducible() {
+        let idl_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("idl/descriptor.thrift");
+        let file = load_idl(idl_path).unwrap();
+        let desc = file.find_struct_by_name("FileDescriptor").unwrap();
+
+        let generate = |seed| {
+            let mut generator = Generator::new(seed, CorpusConfig::default());
+            (0..8)
+                .map(|_| generator.generate(desc).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        let corpus = generate(42);
+        assert_eq!(corpus, generate(42));
+        assert_ne!(corpus, generate(43));
+
+        for payload in corpus {
+            let mut payload = payload;
+            FileDescriptor::decode(&mut TBinaryProtocol::new(&mut payload, false)).unwrap();
+            assert!(payload.is_empty());
+        }
+    }
+}
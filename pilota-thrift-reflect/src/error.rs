@@ -6,4 +6,6 @@ pub enum ReflectorError {
     DeserializationError(String),
     #[error("Include path error: {0}")]
     IncludePathError(String),
+    #[error("IDL error: {0}")]
+    IdlError(String),
 }
@@ -24,6 +24,8 @@ fn normalize_path(path: &std::path::Path) -> PathBuf {
 include!("descriptor.rs");
 pub use descriptor::*;
 
+pub mod anonymize;
+pub mod corpus;
 pub mod error;
 pub mod service;
 
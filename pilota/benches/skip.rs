@@ -1,47 +1,55 @@
+#![allow(clippy::drop_non_drop)]
+// every case skips with binary_unsafe, left out under safe-only
+#![cfg_attr(feature = "safe-only", allow(dead_code, unused_imports, unused_mut))]
+
 use std::hint::black_box;
 
 use ahash::{AHashMap, AHashSet};
 use bytes::{Bytes, BytesMut};
 use criterion::{Criterion, criterion_group, criterion_main};
 use faststr::FastStr;
+#[cfg(not(feature = "safe-only"))]
+use pilota::thrift::binary_unsafe::TBinaryUnsafeInputProtocol;
 use pilota::thrift::{
     TInputProtocol, TOutputProtocol, TOutputProtocolExt, TStructIdentifier, TType, ThriftException,
-    binary_unsafe::TBinaryUnsafeInputProtocol,
 };
 use rand::Rng;
 
 fn skip_bench(c: &mut Criterion) {
     let mut group = c.benchmark_group("Thrift Binary Skip Bench");
 
-    let buf = generate_list_i32();
-    group.bench_function("binary_unsafe skip list<i32>", |b| {
-        b.iter(|| {
-            {
-                let b = buf.clone();
-                let b_len = b.len();
-                match black_box(skip_binary_unsafe(b, TType::List)) {
-                    Ok(size) => assert_eq!(size, b_len),
-                    Err(_) => panic!("skip decode error"),
-                }
-            };
-            black_box(());
+    #[cfg(not(feature = "safe-only"))]
+    {
+        let buf = generate_list_i32();
+        group.bench_function("binary_unsafe skip list<i32>", |b| {
+            b.iter(|| {
+                {
+                    let b = buf.clone();
+                    let b_len = b.len();
+                    match black_box(skip_binary_unsafe(b, TType::List)) {
+                        Ok(size) => assert_eq!(size, b_len),
+                        Err(_) => panic!("skip decode error"),
+                    }
+                };
+                black_box(());
+            });
         });
-    });
 
-    let buf = generate_struct();
-    group.bench_function("binary_unsafe skip struct", |b| {
-        b.iter(|| {
-            {
-                let b = buf.clone();
-                let b_len = b.len();
-                match black_box(skip_binary_unsafe(b, TType::Struct)) {
-                    Ok(size) => assert_eq!(size, b_len),
-                    Err(_) => panic!("skip decode error"),
-                }
-            };
-            black_box(());
+        let buf = generate_struct();
+        group.bench_function("binary_unsafe skip struct", |b| {
+            b.iter(|| {
+                {
+                    let b = buf.clone();
+                    let b_len = b.len();
+                    match black_box(skip_binary_unsafe(b, TType::Struct)) {
+                        Ok(size) => assert_eq!(size, b_len),
+                        Err(_) => panic!("skip decode error"),
+                    }
+                };
+                black_box(());
+            });
         });
-    });
+    }
 
     group.finish();
 }
@@ -127,6 +135,7 @@ fn generate_struct() -> Bytes {
     buf.freeze()
 }
 
+#[cfg(not(feature = "safe-only"))]
 #[inline(never)]
 fn skip_binary_unsafe(mut b: Bytes, ttype: TType) -> Result<usize, ThriftException> {
     unsafe {
@@ -1,6 +1,6 @@
-#![allow(clippy::redundant_clone)]
+#![allow(clippy::redundant_clone, clippy::unit_arg, clippy::drop_non_drop)]
 
-use std::{hint::black_box, ptr};
+use std::hint::black_box;
 
 use bytes::{Bytes, BytesMut};
 use criterion::{criterion_group, criterion_main};
@@ -29,7 +29,7 @@ fn binary_bench(c: &mut criterion::Criterion) {
 
     let mut buf_le = BytesMut::new();
 
-    let mut p = pilota::thrift::binary_le::TBinaryProtocol::new(&mut buf_le, true);
+    let mut p = pilota::thrift::binary_le::TBinaryProtocol::with_byte_order(&mut buf_le, true);
     for i in &v {
         p.write_i64(*i).unwrap();
     }
@@ -37,9 +37,12 @@ fn binary_bench(c: &mut criterion::Criterion) {
     let buf_le = buf_le.freeze();
     assert_eq!(buf_le.len(), 8 * size);
 
-    let b = buf.clone();
-    let v2 = read_be_unsafe_vec(b, size);
-    assert_eq!(v, v2);
+    #[cfg(not(feature = "safe-only"))]
+    {
+        let b = buf.clone();
+        let v2 = read_be_unsafe_vec(b, size);
+        assert_eq!(v, v2);
+    }
 
     group.bench_function("big endian decode vec i64", |b| {
         b.iter(|| {
@@ -50,6 +53,7 @@ fn binary_bench(c: &mut criterion::Criterion) {
         })
     });
 
+    #[cfg(not(feature = "safe-only"))]
     group.bench_function("big endian decode vec i64 unsafe", |b| {
         b.iter(|| {
             black_box({
@@ -59,6 +63,7 @@ fn binary_bench(c: &mut criterion::Criterion) {
         })
     });
 
+    #[cfg(not(feature = "safe-only"))]
     group.bench_function("big endian decode vec i64 unsafe vec", |b| {
         b.iter(|| {
             black_box({
@@ -87,6 +92,7 @@ fn binary_bench(c: &mut criterion::Criterion) {
         })
     });
 
+    #[cfg(not(feature = "safe-only"))]
     group.bench_function("big endian encode vec i64 unsafe", |b| {
         b.iter(|| {
             black_box({
@@ -125,7 +131,7 @@ fn binary_bench(c: &mut criterion::Criterion) {
     group.bench_function("alloc vec", |b| {
         b.iter(|| {
             let mut b = buf_le.clone();
-            let _p = pilota::thrift::binary_le::TBinaryProtocol::new(&mut b, true);
+            let _p = pilota::thrift::binary_le::TBinaryProtocol::with_byte_order(&mut b, true);
             let _: Vec<i64> = black_box(Vec::with_capacity(size));
         })
     });
@@ -143,6 +149,7 @@ fn read_be(mut b: Bytes, size: usize) -> Vec<i64> {
     v
 }
 
+#[cfg(not(feature = "safe-only"))]
 #[inline(never)]
 fn read_be_unsafe(mut b: Bytes, size: usize) -> Vec<i64> {
     unsafe {
@@ -155,6 +162,7 @@ fn read_be_unsafe(mut b: Bytes, size: usize) -> Vec<i64> {
     }
 }
 
+#[cfg(not(feature = "safe-only"))]
 #[inline(never)]
 fn read_be_unsafe_vec(mut b: Bytes, size: usize) -> Vec<i64> {
     unsafe {
@@ -199,6 +207,7 @@ fn write_be(b: &mut BytesMut, v: &Vec<i64>, _size: usize) {
     }
 }
 
+#[cfg(not(feature = "safe-only"))]
 #[inline(never)]
 fn write_be_unsafe(b: &mut BytesMut, v: &Vec<i64>, size: usize) {
     unsafe {
@@ -213,7 +222,7 @@ fn write_be_unsafe(b: &mut BytesMut, v: &Vec<i64>, size: usize) {
 
 #[inline(never)]
 fn read_le(mut b: Bytes, size: usize) -> Vec<i64> {
-    let mut p = pilota::thrift::binary_le::TBinaryProtocol::new(&mut b, true);
+    let mut p = pilota::thrift::binary_le::TBinaryProtocol::with_byte_order(&mut b, true);
 
     let mut v = Vec::with_capacity(size);
     for _ in 0..size {
@@ -248,7 +257,7 @@ fn read_le_unsafe_optimized(b: Bytes, size: usize) -> Vec<i64> {
 
 #[inline(never)]
 fn read_le_optimized(mut b: Bytes, size: usize) -> Vec<i64> {
-    let _p = pilota::thrift::binary_le::TBinaryProtocol::new(&mut b, true);
+    let _p = pilota::thrift::binary_le::TBinaryProtocol::with_byte_order(&mut b, true);
     let mut v: Vec<i64> = Vec::with_capacity(size);
     {
         {
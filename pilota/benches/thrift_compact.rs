@@ -0,0 +1,81 @@
+#![allow(clippy::ptr_arg)]
+
+use std::hint::black_box;
+
+#[cfg(not(feature = "safe-only"))]
+use bytes::BufMut;
+use bytes::BytesMut;
+use criterion::{criterion_group, criterion_main};
+use pilota::thrift::{TLengthProtocol, TOutputProtocol};
+use rand::{self, Rng};
+
+fn compact_bench(c: &mut criterion::Criterion) {
+    let size = std::env::var("SIZE")
+        .unwrap_or("10000".to_string())
+        .parse()
+        .unwrap();
+    let mut group = c.benchmark_group("Bench Thrift Compact");
+    let mut v: Vec<i64> = Vec::with_capacity(size);
+    for _ in 0..size {
+        v.push(rand::rng().random());
+    }
+
+    #[cfg(not(feature = "safe-only"))]
+    {
+        let mut safe = BytesMut::new();
+        write_compact(&mut safe, &v);
+        let mut unsafe_ = BytesMut::new();
+        write_compact_unsafe(&mut unsafe_, &v);
+        assert_eq!(safe, unsafe_);
+    }
+
+    group.bench_function("compact encode vec i64", |b| {
+        b.iter(|| {
+            let mut buf = BytesMut::new();
+            write_compact(&mut buf, black_box(&v));
+            black_box(buf);
+        })
+    });
+
+    #[cfg(not(feature = "safe-only"))]
+    group.bench_function("compact encode vec i64 unsafe", |b| {
+        b.iter(|| {
+            let mut buf = BytesMut::new();
+            write_compact_unsafe(&mut buf, black_box(&v));
+            black_box(buf);
+        })
+    });
+}
+
+#[inline(never)]
+fn write_compact(b: &mut BytesMut, v: &Vec<i64>) {
+    let mut p = pilota::thrift::compact::TCompactOutputProtocol::new(&mut *b, true);
+    let size = v.iter().map(|el| p.i64_len(*el)).sum();
+    p.buf_mut().reserve(size);
+    for el in v {
+        p.write_i64(*el).unwrap();
+    }
+}
+
+#[cfg(not(feature = "safe-only"))]
+#[inline(never)]
+fn write_compact_unsafe(b: &mut BytesMut, v: &Vec<i64>) {
+    unsafe {
+        let mut p =
+            pilota::thrift::compact_unsafe::TCompactUnsafeOutputProtocol::new((), &mut [], true);
+        let size = v.iter().map(|el| p.i64_len(*el)).sum();
+        b.reserve(size);
+        let spare = b.spare_capacity_mut();
+        let s = std::slice::from_raw_parts_mut(spare.as_mut_ptr().cast(), spare.len());
+        let mut p =
+            pilota::thrift::compact_unsafe::TCompactUnsafeOutputProtocol::new(&mut *b, s, true);
+        for el in v {
+            p.write_i64(*el).unwrap();
+        }
+        let len = p.index();
+        b.advance_mut(len);
+    }
+}
+
+criterion_group!(benches, compact_bench);
+criterion_main!(benches);
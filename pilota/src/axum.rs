@@ -0,0 +1,115 @@
+//! [`axum`](::axum) extractors and responders for generated types.
+//!
+//! [`Thrift`] and [`Protobuf`] decode the request body according to its
+//! content type and encode the wrapped message as the response body:
+//!
+//! ```ignore
+//! async fn handler(Thrift(req): Thrift<GetUserRequest>) -> Thrift<GetUserResponse> {
+//!     ...
+//! }
+//! ```
+
+use ::axum::{
+    extract::{FromRequest, Request},
+    http::{StatusCode, header::CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    http::{
+        BodyError, PROTOBUF_CONTENT_TYPE, THRIFT_CONTENT_TYPE, decode_pb, decode_thrift,
+        pb_response, thrift_response,
+    },
+    pb, thrift,
+};
+
+/// A thrift message in binary protocol, sent as `application/x-thrift`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Thrift<T>(pub T);
+
+/// A protobuf message, sent as `application/grpc+proto` or
+/// `application/x-protobuf`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Protobuf<T>(pub T);
+
+/// Rejection used by the [`Thrift`] and [`Protobuf`] extractors.
+#[derive(Debug, thiserror::Error)]
+pub enum Rejection {
+    #[error("unsupported content type, expected {expected}")]
+    UnsupportedContentType { expected: &'static str },
+    #[error("failed to decode request body: {0}")]
+    Decode(String),
+}
+
+impl IntoResponse for Rejection {
+    fn into_response(self) -> Response {
+        let status = match self {
+            Rejection::UnsupportedContentType { .. } => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Rejection::Decode(_) => StatusCode::BAD_REQUEST,
+        };
+        (status, self.to_string()).into_response()
+    }
+}
+
+impl<E: std::fmt::Display> From<BodyError<E>> for Rejection {
+    fn from(e: BodyError<E>) -> Self {
+        Rejection::Decode(e.to_string())
+    }
+}
+
+fn has_content_type(req: &Request, expected: &[&str]) -> bool {
+    req.headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(';').next())
+        .is_some_and(|v| expected.iter().any(|e| v.trim().eq_ignore_ascii_case(e)))
+}
+
+impl<T, S> FromRequest<S> for Thrift<T>
+where
+    T: thrift::Message,
+    S: Send + Sync,
+{
+    type Rejection = Rejection;
+
+    async fn from_request(req: Request, _state: &S) -> Result<Self, Rejection> {
+        if !has_content_type(&req, &[THRIFT_CONTENT_TYPE]) {
+            return Err(Rejection::UnsupportedContentType {
+                expected: THRIFT_CONTENT_TYPE,
+            });
+        }
+        Ok(Thrift(decode_thrift(req.into_body()).await?))
+    }
+}
+
+impl<T: thrift::Message> IntoResponse for Thrift<T> {
+    fn into_response(self) -> Response {
+        match thrift_response(&self.0) {
+            Ok(resp) => resp.into_response(),
+            Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        }
+    }
+}
+
+impl<T, S> FromRequest<S> for Protobuf<T>
+where
+    T: pb::Message + Default,
+    S: Send + Sync,
+{
+    type Rejection = Rejection;
+
+    async fn from_request(req: Request, _state: &S) -> Result<Self, Rejection> {
+        if !has_content_type(&req, &[PROTOBUF_CONTENT_TYPE, "application/x-protobuf"]) {
+            return Err(Rejection::UnsupportedContentType {
+                expected: PROTOBUF_CONTENT_TYPE,
+            });
+        }
+        Ok(Protobuf(decode_pb(req.into_body()).await?))
+    }
+}
+
+impl<T: pb::Message> IntoResponse for Protobuf<T> {
+    fn into_response(self) -> Response {
+        pb_response(&self.0).into_response()
+    }
+}
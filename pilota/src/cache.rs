@@ -0,0 +1,199 @@
+//! Versioned values for external caches such as redis or memcached.
+//!
+//! A cached value is laid out as
+//!
+//! ```text
+//! +---------+-------------------+--------------------------+
+//! | version | fingerprint (u64) | compact protocol payload |
+//! +---------+-------------------+--------------------------+
+//! ```
+//!
+//! The fingerprint identifies the schema the value was written with, so a
+//! reader built from a different schema rejects the value with
+//! [`CacheError::FingerprintMismatch`] and can treat it as a miss instead of
+//! decoding garbage.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::thrift::{
+    Message, ThriftException,
+    compact::{TCompactInputProtocol, TCompactOutputProtocol},
+};
+
+/// The version of the value layout.
+pub const VERSION: u8 = 1;
+
+const HEADER_LEN: usize = 1 + 8;
+
+/// A type with a stable schema fingerprint.
+///
+/// The fingerprint must change whenever the encoded form of the type changes
+/// incompatibly, the simplest way is to derive it from a schema revision with
+/// [`fingerprint`]:
+///
+/// ```
+/// # use pilota::cache::{Fingerprint, fingerprint};
+/// # struct User;
+/// impl Fingerprint for User {
+///     const FINGERPRINT: u64 = fingerprint("user.thrift#User@3");
+/// }
+/// ```
+pub trait Fingerprint {
+    const FINGERPRINT: u64;
+}
+
+/// Computes the 64-bit FNV-1a hash of `schema`, usable in const context.
+pub const fn fingerprint(schema: &str) -> u64 {
+    let bytes = schema.as_bytes();
+    let mut hash = 0xcbf29ce484222325u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u64).wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+    hash
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CacheError {
+    #[error("cached value is truncated")]
+    Truncated,
+    #[error("unsupported cached value version {0}")]
+    UnsupportedVersion(u8),
+    #[error("schema fingerprint mismatch: expected {expected:#018x}, found {found:#018x}")]
+    FingerprintMismatch { expected: u64, found: u64 },
+    #[error(transparent)]
+    Thrift(#[from] ThriftException),
+}
+
+/// Encodes `msg` as a cached value of the schema `fingerprint`.
+pub fn encode_with<M: Message>(msg: &M, fingerprint: u64) -> Result<Bytes, CacheError> {
+    let mut buf = BytesMut::new();
+    buf.put_u8(VERSION);
+    buf.put_u64(fingerprint);
+    msg.encode(&mut TCompactOutputProtocol::new(&mut buf, false))?;
+    Ok(buf.freeze())
+}
+
+/// Decodes a cached value, checking it was written with the schema
+/// `fingerprint`.
+pub fn decode_with<M: Message>(mut data: Bytes, fingerprint: u64) -> Result<M, CacheError> {
+    if data.len() < HEADER_LEN {
+        return Err(CacheError::Truncated);
+    }
+    let version = data.get_u8();
+    if version != VERSION {
+        return Err(CacheError::UnsupportedVersion(version));
+    }
+    let found = data.get_u64();
+    if found != fingerprint {
+        return Err(CacheError::FingerprintMismatch {
+            expected: fingerprint,
+            found,
+        });
+    }
+    Ok(M::decode(&mut TCompactInputProtocol::new(&mut data))?)
+}
+
+/// Encodes `msg` as a cached value of its [`Fingerprint`].
+#[inline]
+pub fn encode<M: Message + Fingerprint>(msg: &M) -> Result<Bytes, CacheError> {
+    encode_with(msg, M::FINGERPRINT)
+}
+
+/// Decodes a cached value written by [`encode`].
+#[inline]
+pub fn decode<M: Message + Fingerprint>(data: Bytes) -> Result<M, CacheError> {
+    decode_with(data, M::FINGERPRINT)
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+
+    use super::{CacheError, Fingerprint, decode, decode_with, encode, fingerprint};
+    use crate::thrift::{
+        Message, TAsyncInputProtocol, TInputProtocol, TLengthProtocol, TOutputProtocol, TType,
+        ThriftException, VOID_IDENT,
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct Counter {
+        value: i64,
+    }
+
+    impl Fingerprint for Counter {
+        const FINGERPRINT: u64 = fingerprint("Counter@1");
+    }
+
+    impl Message for Counter {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_struct_begin(&VOID_IDENT)?;
+            protocol.write_field_begin(TType::I64, 1)?;
+            protocol.write_i64(self.value)?;
+            protocol.write_field_end()?;
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()
+        }
+
+        fn decode<T: TInputProtocol>(protocol: &mut T) -> Result<Self, ThriftException> {
+            let mut value = 0;
+            protocol.read_struct_begin()?;
+            loop {
+                let field_ident = protocol.read_field_begin()?;
+                match (field_ident.field_type, field_ident.id) {
+                    (TType::Stop, _) => break,
+                    (TType::I64, Some(1)) => value = protocol.read_i64()?,
+                    (ttype, _) => {
+                        protocol.skip(ttype)?;
+                    }
+                }
+                protocol.read_field_end()?;
+            }
+            protocol.read_struct_end()?;
+            Ok(Counter { value })
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(
+            _protocol: &mut T,
+        ) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+            protocol.struct_begin_len(&VOID_IDENT)
+                + protocol.field_begin_len(TType::I64, Some(1))
+                + protocol.i64_len(self.value)
+                + protocol.field_end_len()
+                + protocol.field_stop_len()
+                + protocol.struct_end_len()
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let data = encode(&Counter { value: 42 }).unwrap();
+        assert_eq!(decode::<Counter>(data).unwrap(), Counter { value: 42 });
+    }
+
+    #[test]
+    fn test_reject() {
+        let data = encode(&Counter { value: 42 }).unwrap();
+        assert!(matches!(
+            decode_with::<Counter>(data.clone(), fingerprint("Counter@2")),
+            Err(CacheError::FingerprintMismatch { .. })
+        ));
+
+        let mut bumped = data.to_vec();
+        bumped[0] = 2;
+        assert!(matches!(
+            decode::<Counter>(bumped.into()),
+            Err(CacheError::UnsupportedVersion(2))
+        ));
+
+        assert!(matches!(
+            decode::<Counter>(Bytes::from_static(&[1, 0])),
+            Err(CacheError::Truncated)
+        ));
+    }
+}
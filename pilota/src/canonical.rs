@@ -0,0 +1,251 @@
+//! Semantic comparison of decoded values.
+//!
+//! The derived `PartialEq` reports two decodes of the same payload by
+//! different peers as different when they only differ in ways the wire
+//! format doesn't carry, such as a NaN or an optional field left out instead
+//! of set to its default. [`CanonicalEq`] compares what the values mean:
+//!
+//! - NaN equals NaN.
+//! - Maps compare their entries whatever their order, sets their elements.
+//! - An optional field with a default in the IDL equals that default when
+//!   absent.
+//! - Unknown fields compare their bytes, however they are chunked.
+//!
+//! The impls of the generated types come from
+//! `pilota_build::plugin::CanonicalEqPlugin`.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    hash::{BuildHasher, Hash},
+    sync::Arc,
+};
+
+use bytes::Bytes;
+use faststr::FastStr;
+use ordered_float::OrderedFloat;
+
+use crate::{AHashMap, AHashSet, BytesVec};
+
+pub trait CanonicalEq {
+    fn canonical_eq(&self, other: &Self) -> bool;
+}
+
+/// Compares two optional fields with a default in the IDL, an absent one
+/// being equal to `default()`.
+#[inline]
+pub fn optional_eq<T: CanonicalEq>(
+    a: &Option<T>,
+    b: &Option<T>,
+    default: impl FnOnce() -> T,
+) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.canonical_eq(b),
+        (None, None) => true,
+        (Some(v), None) | (None, Some(v)) => v.canonical_eq(&default()),
+    }
+}
+
+macro_rules! impl_partial_eq {
+    ($($ty:ty),*) => {
+        $(
+            impl CanonicalEq for $ty {
+                #[inline]
+                fn canonical_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_partial_eq!(
+    (),
+    bool,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u32,
+    u64,
+    String,
+    FastStr,
+    Bytes,
+    [u8; 16],
+    OrderedFloat<f32>,
+    OrderedFloat<f64>
+);
+
+#[cfg(feature = "uuid")]
+impl_partial_eq!(uuid::Uuid);
+
+macro_rules! impl_float {
+    ($($ty:ty),*) => {
+        $(
+            impl CanonicalEq for $ty {
+                #[inline]
+                fn canonical_eq(&self, other: &Self) -> bool {
+                    self == other || (self.is_nan() && other.is_nan())
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+impl<T: CanonicalEq + ?Sized> CanonicalEq for Box<T> {
+    #[inline]
+    fn canonical_eq(&self, other: &Self) -> bool {
+        (**self).canonical_eq(other)
+    }
+}
+
+impl<T: CanonicalEq + ?Sized> CanonicalEq for Arc<T> {
+    #[inline]
+    fn canonical_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(self, other) || (**self).canonical_eq(other)
+    }
+}
+
+impl<T: CanonicalEq> CanonicalEq for Option<T> {
+    #[inline]
+    fn canonical_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.canonical_eq(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: CanonicalEq> CanonicalEq for [T] {
+    fn canonical_eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.canonical_eq(b))
+    }
+}
+
+impl<T: CanonicalEq> CanonicalEq for Vec<T> {
+    #[inline]
+    fn canonical_eq(&self, other: &Self) -> bool {
+        self[..].canonical_eq(&other[..])
+    }
+}
+
+impl<K: Eq + Hash, V: CanonicalEq, S: BuildHasher> CanonicalEq for HashMap<K, V, S> {
+    fn canonical_eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(k, v)| other.get(k).is_some_and(|o| v.canonical_eq(o)))
+    }
+}
+
+impl<K: Ord, V: CanonicalEq> CanonicalEq for BTreeMap<K, V> {
+    fn canonical_eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(k, v)| other.get(k).is_some_and(|o| v.canonical_eq(o)))
+    }
+}
+
+// the elements being hashed or ordered, they hold neither floats nor maps,
+// so their `Eq` is already canonical
+impl<T: Eq + Hash, S: BuildHasher> CanonicalEq for HashSet<T, S> {
+    #[inline]
+    fn canonical_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<T: Ord> CanonicalEq for BTreeSet<T> {
+    #[inline]
+    fn canonical_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<K: Eq + Hash, V: CanonicalEq> CanonicalEq for AHashMap<K, V> {
+    #[inline]
+    fn canonical_eq(&self, other: &Self) -> bool {
+        (**self).canonical_eq(other)
+    }
+}
+
+impl<T: Eq + Hash> CanonicalEq for AHashSet<T> {
+    #[inline]
+    fn canonical_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl CanonicalEq for BytesVec {
+    fn canonical_eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self
+                .list
+                .iter()
+                .flat_map(|b| b.iter())
+                .eq(other.list.iter().flat_map(|b| b.iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use bytes::Bytes;
+
+    use super::{CanonicalEq, optional_eq};
+    use crate::{AHashMap, BytesVec};
+
+    #[test]
+    fn test_nan() {
+        assert!(f64::NAN.canonical_eq(&f64::NAN));
+        assert!(!f64::NAN.canonical_eq(&1.0));
+        assert!(vec![1.0, f64::NAN].canonical_eq(&vec![1.0, f64::NAN]));
+        assert!(!vec![f64::NAN, 1.0].canonical_eq(&vec![1.0, f64::NAN]));
+    }
+
+    #[test]
+    fn test_maps() {
+        let a = (0..64)
+            .map(|i| (i, vec![f64::NAN, i as f64]))
+            .collect::<AHashMap<_, _>>();
+        let b = (0..64)
+            .rev()
+            .map(|i| (i, vec![f64::NAN, i as f64]))
+            .collect::<AHashMap<_, _>>();
+        assert!(a.canonical_eq(&b));
+        let mut c = b.clone();
+        c.insert(0, vec![0.0]);
+        assert!(!a.canonical_eq(&c));
+        c.remove(&0);
+        assert!(!a.canonical_eq(&c));
+
+        let a = BTreeMap::from([(1, f64::NAN)]);
+        assert!(a.canonical_eq(&a.clone()));
+    }
+
+    #[test]
+    fn test_optional_eq() {
+        assert!(optional_eq(&None, &Some(5), || 5));
+        assert!(optional_eq(&Some(5), &None, || 5));
+        assert!(!optional_eq(&None, &Some(4), || 5));
+        assert!(optional_eq::<i32>(&None, &None, || 5));
+    }
+
+    #[test]
+    fn test_bytes_vec() {
+        let mut a = BytesVec::new();
+        a.push_back(Bytes::from_static(b"ab"));
+        a.push_back(Bytes::from_static(b"c"));
+        let mut b = BytesVec::new();
+        b.push_back(Bytes::from_static(b"a"));
+        b.push_back(Bytes::from_static(b"bc"));
+        assert!(a.canonical_eq(&b));
+        b.push_back(Bytes::from_static(b"d"));
+        assert!(!a.canonical_eq(&b));
+    }
+}
@@ -0,0 +1,186 @@
+//! Loading generated types from YAML, TOML or JSON config files.
+//!
+//! The types must be generated with the serde plugin. Fields missing from
+//! the file are taken from the type's `Default` impl, which carries the
+//! default values declared in the IDL.
+
+use std::path::Path;
+
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl Format {
+    /// Guesses the format from the extension of `path`.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "yaml" | "yml" => Some(Format::Yaml),
+            "toml" => Some(Format::Toml),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("failed to read config: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("unsupported config format: {0}")]
+    UnsupportedFormat(String),
+    #[error("failed to parse config: {0}")]
+    Parse(String),
+    #[error("invalid config: {0}")]
+    Invalid(String),
+}
+
+/// Checks a loaded config beyond what its type can express.
+pub trait Validate {
+    fn validate(&self) -> Result<(), String>;
+}
+
+/// Loads `T` from the file at `path`, picking the format from its extension.
+pub fn load<T>(path: impl AsRef<Path>) -> Result<T, ConfigError>
+where
+    T: Default + Serialize + DeserializeOwned,
+{
+    let path = path.as_ref();
+    let format = Format::from_path(path)
+        .ok_or_else(|| ConfigError::UnsupportedFormat(path.display().to_string()))?;
+    from_str(&std::fs::read_to_string(path)?, format)
+}
+
+/// Like [`load`], then runs the [`Validate`] checks of `T`.
+pub fn load_validated<T>(path: impl AsRef<Path>) -> Result<T, ConfigError>
+where
+    T: Default + Serialize + DeserializeOwned + Validate,
+{
+    let config: T = load(path)?;
+    config.validate().map_err(ConfigError::Invalid)?;
+    Ok(config)
+}
+
+/// Parses `T` from `content` in the given format.
+pub fn from_str<T>(content: &str, format: Format) -> Result<T, ConfigError>
+where
+    T: Default + Serialize + DeserializeOwned,
+{
+    let parse_err = |e: &dyn std::fmt::Display| ConfigError::Parse(e.to_string());
+    let value: Value = match format {
+        Format::Yaml => serde_yaml::from_str(content).map_err(|e| parse_err(&e))?,
+        Format::Toml => toml::from_str(content).map_err(|e| parse_err(&e))?,
+        Format::Json => serde_json::from_str(content).map_err(|e| parse_err(&e))?,
+    };
+
+    let mut merged = serde_json::to_value(T::default()).map_err(|e| parse_err(&e))?;
+    merge(&mut merged, value);
+    serde_json::from_value(merged).map_err(|e| parse_err(&e))
+}
+
+/// Overlays `value` onto `base`, recursing into objects so nested structs
+/// keep their defaults too.
+fn merge(base: &mut Value, value: Value) {
+    match (base, value) {
+        (Value::Object(base), Value::Object(value)) => {
+            for (k, v) in value {
+                match base.get_mut(&k) {
+                    Some(b) => merge(b, v),
+                    None => {
+                        base.insert(k, v);
+                    }
+                }
+            }
+        }
+        (base, value) => *base = value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::{ConfigError, Format, Validate, from_str};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Server {
+        host: String,
+        port: i32,
+        limits: Limits,
+        tags: Option<Vec<String>>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Limits {
+        max_conns: i32,
+        timeout_ms: i64,
+    }
+
+    impl Default for Server {
+        fn default() -> Self {
+            Self {
+                host: "127.0.0.1".into(),
+                port: 8080,
+                limits: Limits {
+                    max_conns: 100,
+                    timeout_ms: 1000,
+                },
+                tags: None,
+            }
+        }
+    }
+
+    impl Validate for Server {
+        fn validate(&self) -> Result<(), String> {
+            if self.port <= 0 {
+                return Err(format!("port must be positive, got {}", self.port));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_defaults_applied() {
+        let expected = Server {
+            host: "0.0.0.0".into(),
+            limits: Limits {
+                max_conns: 10,
+                timeout_ms: 1000,
+            },
+            ..Default::default()
+        };
+
+        let yaml = "host: 0.0.0.0\nlimits:\n  max_conns: 10\n";
+        assert_eq!(from_str::<Server>(yaml, Format::Yaml).unwrap(), expected);
+
+        let toml = "host = \"0.0.0.0\"\n[limits]\nmax_conns = 10\n";
+        assert_eq!(from_str::<Server>(toml, Format::Toml).unwrap(), expected);
+
+        let json = r#"{"host": "0.0.0.0", "limits": {"max_conns": 10}}"#;
+        assert_eq!(from_str::<Server>(json, Format::Json).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_validate() {
+        let dir = std::env::temp_dir().join(format!("pilota-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("server.toml");
+        std::fs::write(&path, "port = -1\n").unwrap();
+
+        assert_eq!(super::load::<Server>(&path).unwrap().port, -1);
+        assert!(matches!(
+            super::load_validated::<Server>(&path),
+            Err(ConfigError::Invalid(_))
+        ));
+        assert!(matches!(
+            super::load::<Server>(dir.join("server.ini")),
+            Err(ConfigError::UnsupportedFormat(_))
+        ));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,84 @@
+//! Helpers for carrying messages in HTTP bodies.
+//!
+//! Thrift messages are encoded with the binary protocol and sent as
+//! [`THRIFT_CONTENT_TYPE`], protobuf messages as [`PROTOBUF_CONTENT_TYPE`].
+
+use ::http::{HeaderValue, Response, header::CONTENT_TYPE};
+use bytes::Bytes;
+use http_body::Body;
+use http_body_util::{BodyExt, Full};
+
+use crate::{
+    pb::{self, DecodeError, EncodeLengthContext},
+    thrift::{self, ThriftException, binary::TBinaryProtocol},
+};
+
+pub const THRIFT_CONTENT_TYPE: &str = "application/x-thrift";
+pub const PROTOBUF_CONTENT_TYPE: &str = "application/grpc+proto";
+
+/// Error returned when decoding a message from an HTTP body.
+#[derive(Debug, thiserror::Error)]
+pub enum BodyError<E> {
+    #[error("failed to read body: {0}")]
+    Body(E),
+    #[error(transparent)]
+    Thrift(#[from] ThriftException),
+    #[error(transparent)]
+    Protobuf(#[from] DecodeError),
+}
+
+/// Encodes a thrift message into a body.
+pub fn encode_thrift<M: thrift::Message>(msg: &M) -> Result<Full<Bytes>, ThriftException> {
+    let mut buf = bytes::BytesMut::new();
+    msg.encode(&mut TBinaryProtocol::new(&mut buf, false))?;
+    Ok(Full::new(buf.freeze()))
+}
+
+/// Encodes a protobuf message into a body.
+pub fn encode_pb<M: pb::Message>(msg: &M) -> Full<Bytes> {
+    Full::new(
+        msg.encode_to_vec(&mut EncodeLengthContext::default())
+            .into(),
+    )
+}
+
+/// Builds a `200 OK` response carrying a thrift message.
+pub fn thrift_response<M: thrift::Message>(
+    msg: &M,
+) -> Result<Response<Full<Bytes>>, ThriftException> {
+    Ok(with_content_type(
+        Response::new(encode_thrift(msg)?),
+        THRIFT_CONTENT_TYPE,
+    ))
+}
+
+/// Builds a `200 OK` response carrying a protobuf message.
+pub fn pb_response<M: pb::Message>(msg: &M) -> Response<Full<Bytes>> {
+    with_content_type(Response::new(encode_pb(msg)), PROTOBUF_CONTENT_TYPE)
+}
+
+fn with_content_type<B>(mut resp: Response<B>, content_type: &'static str) -> Response<B> {
+    resp.headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    resp
+}
+
+/// Collects `body` and decodes it as a thrift message.
+pub async fn decode_thrift<M, B>(body: B) -> Result<M, BodyError<B::Error>>
+where
+    M: thrift::Message,
+    B: Body,
+{
+    let mut data = body.collect().await.map_err(BodyError::Body)?.to_bytes();
+    Ok(M::decode(&mut TBinaryProtocol::new(&mut data, true))?)
+}
+
+/// Collects `body` and decodes it as a protobuf message.
+pub async fn decode_pb<M, B>(body: B) -> Result<M, BodyError<B::Error>>
+where
+    M: pb::Message + Default,
+    B: Body,
+{
+    let data = body.collect().await.map_err(BodyError::Body)?.to_bytes();
+    Ok(M::decode(data)?)
+}
@@ -3,7 +3,22 @@
 )]
 #![cfg_attr(not(doctest), doc = include_str!("../README.md"))]
 
+#[cfg(feature = "axum")]
+pub mod axum;
+pub mod cache;
+pub mod canonical;
+#[cfg(feature = "config")]
+pub mod config;
+#[cfg(feature = "http")]
+pub mod http;
+pub mod metadata;
+#[cfg(not(feature = "safe-only"))]
+pub mod overlay;
 pub mod pb;
+pub mod serde_str;
+pub mod simd;
+pub mod storage;
+pub mod stream;
 pub mod thrift;
 
 // reexport
@@ -17,6 +32,8 @@ pub use ordered_float::OrderedFloat;
 pub use serde;
 pub use thiserror::Error as ThisError;
 pub use tokio::io::AsyncRead;
+#[cfg(feature = "uuid")]
+pub use uuid;
 
 pub use crate::thrift::unknown::BytesVec;
 
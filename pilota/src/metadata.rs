@@ -0,0 +1,99 @@
+//! Typed request metadata carried next to a message.
+//!
+//! A [`Metadata`] type names the THeader info key and the HTTP header (gRPC
+//! metadata) it travels under, so a retry layer can read the same
+//! [`RequestId`] or [`IdempotencyKey`] whichever transport delivered it. See
+//! [`InfoHeaders`](crate::thrift::header::InfoHeaders) for THeader.
+
+use faststr::FastStr;
+
+/// A string value carried under a well-known key.
+pub trait Metadata: Sized {
+    /// The key of the THeader info record.
+    const THEADER_KEY: &'static str;
+    /// The name of the HTTP header, lowercase as gRPC metadata requires.
+    const HTTP_HEADER: &'static str;
+
+    fn from_value(value: FastStr) -> Self;
+
+    fn into_value(self) -> FastStr;
+}
+
+/// Identifies one request for tracing, the same on every retry of it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RequestId(pub FastStr);
+
+/// Identifies one logical operation, so a server can drop the duplicates a
+/// retry produces.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IdempotencyKey(pub FastStr);
+
+macro_rules! impl_metadata {
+    ($ty:ident, $theader_key:literal, $http_header:literal) => {
+        impl $ty {
+            pub fn new(value: impl Into<FastStr>) -> Self {
+                Self(value.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Metadata for $ty {
+            const THEADER_KEY: &'static str = $theader_key;
+            const HTTP_HEADER: &'static str = $http_header;
+
+            fn from_value(value: FastStr) -> Self {
+                Self(value)
+            }
+
+            fn into_value(self) -> FastStr {
+                self.0
+            }
+        }
+    };
+}
+
+impl_metadata!(RequestId, "pilota.request_id", "x-request-id");
+impl_metadata!(IdempotencyKey, "pilota.idempotency_key", "idempotency-key");
+
+/// Reads the value of type `M` from HTTP headers, `None` if it is missing or
+/// not visible ASCII.
+#[cfg(feature = "http")]
+pub fn from_header_map<M: Metadata>(headers: &::http::HeaderMap) -> Option<M> {
+    let value = headers.get(M::HTTP_HEADER)?.to_str().ok()?;
+    Some(M::from_value(FastStr::new(value)))
+}
+
+/// Sets the value of type `M` in HTTP headers, replacing the previous one.
+///
+/// Fails if the value is not a valid header value.
+#[cfg(feature = "http")]
+pub fn insert_header_map<M: Metadata>(
+    headers: &mut ::http::HeaderMap,
+    metadata: M,
+) -> Result<(), ::http::header::InvalidHeaderValue> {
+    let value = ::http::HeaderValue::from_str(&metadata.into_value())?;
+    headers.insert(M::HTTP_HEADER, value);
+    Ok(())
+}
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_map() {
+        let mut headers = ::http::HeaderMap::new();
+        assert_eq!(from_header_map::<RequestId>(&headers), None);
+        insert_header_map(&mut headers, RequestId::new("req-1")).unwrap();
+        insert_header_map(&mut headers, IdempotencyKey::new("order-7")).unwrap();
+        assert_eq!(headers["x-request-id"], "req-1");
+        assert_eq!(
+            from_header_map::<IdempotencyKey>(&headers),
+            Some(IdempotencyKey::new("order-7"))
+        );
+        assert!(insert_header_map(&mut headers, RequestId::new("a\nb")).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+//! Raw views of fixed-layout structs.
+//!
+//! Structs annotated with `(pilota.overlay = "le")` or
+//! `(pilota.overlay = "be")` whose fields are all fixed-width get a
+//! `{Name}Overlay` companion: a `#[repr(C)]` struct of byte arrays in the
+//! given byte order. Reading one from a buffer or writing it out is a plain
+//! reinterpretation of the bytes, its accessors do the byte order conversion.
+
+/// A type that can be viewed as bytes and from bytes without copying.
+///
+/// # Safety
+///
+/// The type must be `#[repr(C)]` with an alignment of 1, no padding, and
+/// every bit pattern must be a valid value. Structs made only of byte arrays
+/// satisfy all three.
+pub unsafe trait Overlay: Copy + 'static {
+    /// The size of the type in bytes.
+    const SIZE: usize = std::mem::size_of::<Self>();
+
+    /// Views the first [`Self::SIZE`] bytes of `bytes` as `Self`, `None` if
+    /// there are fewer.
+    fn ref_from_prefix(bytes: &[u8]) -> Option<&Self> {
+        if bytes.len() < Self::SIZE {
+            return None;
+        }
+        debug_assert_eq!(std::mem::align_of::<Self>(), 1);
+        // SAFETY: the length is checked above, and the implementor
+        // guarantees the alignment of 1 and that any bytes are a valid value.
+        Some(unsafe { &*(bytes.as_ptr() as *const Self) })
+    }
+
+    /// Views `bytes` as `Self`, `None` unless its length is exactly
+    /// [`Self::SIZE`].
+    fn ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+        if bytes.len() != Self::SIZE {
+            return None;
+        }
+        Self::ref_from_prefix(bytes)
+    }
+
+    /// Views `bytes` as a slice of `Self`, `None` unless its length is a
+    /// multiple of [`Self::SIZE`].
+    fn slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        if Self::SIZE == 0 || bytes.len() % Self::SIZE != 0 {
+            return None;
+        }
+        // SAFETY: as in `ref_from_prefix`, for each of the elements.
+        Some(unsafe {
+            std::slice::from_raw_parts(bytes.as_ptr() as *const Self, bytes.len() / Self::SIZE)
+        })
+    }
+
+    /// The bytes of the value.
+    fn as_bytes(&self) -> &[u8] {
+        // SAFETY: the implementor guarantees there is no padding, so all the
+        // bytes are initialized.
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const u8, Self::SIZE) }
+    }
+
+    /// The bytes of a slice of values.
+    fn slice_as_bytes(values: &[Self]) -> &[u8] {
+        // SAFETY: as in `as_bytes`, for each of the elements.
+        unsafe {
+            std::slice::from_raw_parts(values.as_ptr() as *const u8, std::mem::size_of_val(values))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Tick {
+        price: [u8; 8],
+        volume: [u8; 4],
+    }
+
+    unsafe impl Overlay for Tick {}
+
+    #[test]
+    fn test_overlay() {
+        let tick = Tick {
+            price: 1.5f64.to_le_bytes(),
+            volume: 7u32.to_le_bytes(),
+        };
+        assert_eq!(Tick::SIZE, 12);
+        let bytes = tick.as_bytes();
+        assert_eq!(&bytes[8..], &[7, 0, 0, 0]);
+        assert_eq!(Tick::ref_from_bytes(bytes), Some(&tick));
+        assert_eq!(Tick::ref_from_bytes(&bytes[1..]), None);
+        assert_eq!(Tick::ref_from_prefix(&[bytes, &[0]].concat()), Some(&tick));
+
+        let ticks = [tick, tick];
+        let bytes = Tick::slice_as_bytes(&ticks);
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(Tick::slice_from_bytes(bytes), Some(&ticks[..]));
+        assert_eq!(Tick::slice_from_bytes(&bytes[1..]), None);
+    }
+}
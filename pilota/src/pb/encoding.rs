@@ -6,7 +6,7 @@
 extern crate alloc;
 
 use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
-use core::{cmp::min, convert::TryFrom, mem, str};
+use core::{cmp::min, convert::TryFrom, str};
 
 use ::bytes::{Buf, BufMut, Bytes};
 use ahash::AHashSet;
@@ -45,7 +45,8 @@ pub fn decode_varint(buf: &mut Bytes) -> Result<u64, DecodeError> {
         buf.advance(1);
         Ok(u64::from(byte))
     } else if len > 10 || bytes[len - 1] < 0x80 {
-        let (value, advance) = decode_varint_slice(bytes)?;
+        let (value, advance) =
+            crate::simd::decode_varint(bytes).ok_or_else(|| DecodeError::new("invalid varint"))?;
         buf.advance(advance);
         Ok(value)
     } else {
@@ -53,100 +54,6 @@ pub fn decode_varint(buf: &mut Bytes) -> Result<u64, DecodeError> {
     }
 }
 
-/// Decodes a LEB128-encoded variable length integer from the slice, returning
-/// the value and the number of bytes read.
-///
-/// Based loosely on [`ReadVarint64FromArray`][1] with a varint overflow check
-/// from [`ConsumeVarint`][2].
-///
-/// ## Safety
-///
-/// The caller must ensure that `bytes` is non-empty and either `bytes.len() >=
-/// 10` or the last element in bytes is < `0x80`.
-///
-/// [1]: https://github.com/google/protobuf/blob/3.3.x/src/google/protobuf/io/coded_stream.cc#L365-L406
-/// [2]: https://github.com/protocolbuffers/protobuf-go/blob/v1.27.1/encoding/protowire/wire.go#L358
-#[inline]
-fn decode_varint_slice(bytes: &[u8]) -> Result<(u64, usize), DecodeError> {
-    // Fully unrolled varint decoding loop. Splitting into 32-bit pieces gives
-    // better performance.
-
-    // Use assertions to ensure memory safety, but it should always be optimized
-    // after inline.
-    assert!(!bytes.is_empty());
-    assert!(bytes.len() > 10 || bytes[bytes.len() - 1] < 0x80);
-
-    let mut b: u8 = unsafe { *bytes.get_unchecked(0) };
-    let mut part0: u32 = u32::from(b);
-    if b < 0x80 {
-        return Ok((u64::from(part0), 1));
-    };
-    part0 -= 0x80;
-    b = unsafe { *bytes.get_unchecked(1) };
-    part0 += u32::from(b) << 7;
-    if b < 0x80 {
-        return Ok((u64::from(part0), 2));
-    };
-    part0 -= 0x80 << 7;
-    b = unsafe { *bytes.get_unchecked(2) };
-    part0 += u32::from(b) << 14;
-    if b < 0x80 {
-        return Ok((u64::from(part0), 3));
-    };
-    part0 -= 0x80 << 14;
-    b = unsafe { *bytes.get_unchecked(3) };
-    part0 += u32::from(b) << 21;
-    if b < 0x80 {
-        return Ok((u64::from(part0), 4));
-    };
-    part0 -= 0x80 << 21;
-    let value = u64::from(part0);
-
-    b = unsafe { *bytes.get_unchecked(4) };
-    let mut part1: u32 = u32::from(b);
-    if b < 0x80 {
-        return Ok((value + (u64::from(part1) << 28), 5));
-    };
-    part1 -= 0x80;
-    b = unsafe { *bytes.get_unchecked(5) };
-    part1 += u32::from(b) << 7;
-    if b < 0x80 {
-        return Ok((value + (u64::from(part1) << 28), 6));
-    };
-    part1 -= 0x80 << 7;
-    b = unsafe { *bytes.get_unchecked(6) };
-    part1 += u32::from(b) << 14;
-    if b < 0x80 {
-        return Ok((value + (u64::from(part1) << 28), 7));
-    };
-    part1 -= 0x80 << 14;
-    b = unsafe { *bytes.get_unchecked(7) };
-    part1 += u32::from(b) << 21;
-    if b < 0x80 {
-        return Ok((value + (u64::from(part1) << 28), 8));
-    };
-    part1 -= 0x80 << 21;
-    let value = value + ((u64::from(part1)) << 28);
-
-    b = unsafe { *bytes.get_unchecked(8) };
-    let mut part2: u32 = u32::from(b);
-    if b < 0x80 {
-        return Ok((value + (u64::from(part2) << 56), 9));
-    };
-    part2 -= 0x80;
-    b = unsafe { *bytes.get_unchecked(9) };
-    part2 += u32::from(b) << 7;
-    // Check for u64::MAX overflow. See [`ConsumeVarint`][1] for details.
-    // [1]: https://github.com/protocolbuffers/protobuf-go/blob/v1.27.1/encoding/protowire/wire.go#L358
-    if b < 0x02 {
-        return Ok((value + (u64::from(part2) << 56), 10));
-    };
-
-    // We have overrun the maximum size of a varint (10 bytes) or the final byte
-    // caused an overflow. Assume the data is corrupt.
-    Err(DecodeError::new("invalid varint"))
-}
-
 /// Decodes a LEB128-encoded variable length integer from the buffer, advancing
 /// the buffer as necessary.
 ///
@@ -1077,6 +984,24 @@ pub mod string {
         // leaked into the backing `String`. To enforce this, even in the event
         // of a panic in `bytes::merge` or in the buf implementation, a drop
         // guard is used.
+        //
+        // With the `safe-only` feature the bytes are decoded into a plain
+        // `Vec<u8>` and validated by `String::from_utf8` instead.
+        #[cfg(feature = "safe-only")]
+        {
+            let mut bytes = Vec::new();
+            bytes::merge(wire_type, &mut bytes, buf, ctx)?;
+            match String::from_utf8(bytes) {
+                Ok(s) => {
+                    *value = S::from(s);
+                    Ok(())
+                }
+                Err(_) => Err(DecodeError::new(
+                    "invalid string value: data is not UTF-8 encoded",
+                )),
+            }
+        }
+        #[cfg(not(feature = "safe-only"))]
         unsafe {
             struct DropGuard<'a>(&'a mut Vec<u8>);
             impl<'a> Drop for DropGuard<'a> {
@@ -1090,16 +1015,15 @@ pub mod string {
 
             let drop_guard = DropGuard(empty.as_mut_vec());
             bytes::merge(wire_type, drop_guard.0, buf, ctx)?;
-            match str::from_utf8(drop_guard.0) {
-                Ok(_) => {
-                    // Success; do not clear the bytes.
-                    mem::forget(drop_guard);
-                    *value = S::from(empty);
-                    Ok(())
-                }
-                Err(_) => Err(DecodeError::new(
+            if crate::simd::validate_utf8(drop_guard.0) {
+                // Success; do not clear the bytes.
+                core::mem::forget(drop_guard);
+                *value = S::from(empty);
+                Ok(())
+            } else {
+                Err(DecodeError::new(
                     "invalid string value: data is not UTF-8 encoded",
-                )),
+                ))
             }
         }
     }
@@ -1211,7 +1135,21 @@ pub mod faststr {
         let mut bytes = Bytes::new();
 
         bytes::merge(wire_type, &mut bytes, buf, ctx)?;
-        *value = unsafe { FastStr::from_bytes_unchecked(bytes) };
+        #[cfg(feature = "safe-only")]
+        {
+            *value = FastStr::from_bytes(bytes)
+                .map_err(|_| DecodeError::new("invalid string value: data is not UTF-8 encoded"))?;
+        }
+        #[cfg(not(feature = "safe-only"))]
+        {
+            #[cfg(feature = "validate-utf8")]
+            if !crate::simd::validate_utf8(&bytes) {
+                return Err(DecodeError::new(
+                    "invalid string value: data is not UTF-8 encoded",
+                ));
+            }
+            *value = unsafe { FastStr::from_bytes_unchecked(bytes) };
+        }
         Ok(())
     }
 
@@ -1985,9 +1923,10 @@ pub struct EncodeLengthContext {
 }
 
 #[cfg(test)]
+#[allow(unexpected_cfgs)]
 mod test {
     use alloc::string::ToString;
-    use core::{fmt::Debug, u64};
+    use core::fmt::Debug;
 
     use ::bytes::Bytes;
     use proptest::{prelude::*, test_runner::TestCaseResult};
@@ -2406,14 +2345,14 @@ mod test {
         let e = ::faststr::FastStr::from("");
         faststr::encode(1, &e, &mut buf);
         if cfg!(feature = "pb-encode-default-value") {
-            assert!(buf.len() > 0);
+            assert!(!buf.is_empty());
         } else {
             assert_eq!(buf.len(), 0);
         }
         let mut buf2 = LinkedBytes::new();
         let n = ::faststr::FastStr::from("x");
         faststr::encode(1, &n, &mut buf2);
-        assert!(buf2.len() > 0);
+        assert!(!buf2.is_empty());
     }
 
     #[test]
@@ -2437,14 +2376,14 @@ mod test {
         let e = Bytes::new();
         bytes::encode(1, &e, &mut buf);
         if cfg!(feature = "pb-encode-default-value") {
-            assert!(buf.len() > 0);
+            assert!(!buf.is_empty());
         } else {
             assert_eq!(buf.len(), 0);
         }
         let mut buf2 = LinkedBytes::new();
         let n = Bytes::from_static(b"x");
         bytes::encode(1, &n, &mut buf2);
-        assert!(buf2.len() > 0);
+        assert!(!buf2.is_empty());
     }
 
     #[test]
@@ -2452,13 +2391,13 @@ mod test {
         let mut buf = LinkedBytes::new();
         int32::encode(1, &0i32, &mut buf);
         if cfg!(feature = "pb-encode-default-value") {
-            assert!(buf.len() > 0);
+            assert!(!buf.is_empty());
         } else {
             assert_eq!(buf.len(), 0);
         }
         let mut buf2 = LinkedBytes::new();
         int32::encode(1, &1i32, &mut buf2);
-        assert!(buf2.len() > 0);
+        assert!(!buf2.is_empty());
     }
 
     macro_rules! gen_skip_default_numeric_tests {
@@ -2482,13 +2421,13 @@ mod test {
                     let mut buf = LinkedBytes::new();
                     super::super::$mod::encode(1, &$zero, &mut buf);
                     if cfg!(feature = "pb-encode-default-value") {
-                        assert!(buf.len() > 0);
+                        assert!(!buf.is_empty());
                     } else {
                         assert_eq!(buf.len(), 0);
                     }
                     let mut buf2 = LinkedBytes::new();
                     super::super::$mod::encode(1, &$nonzero, &mut buf2);
-                    assert!(buf2.len() > 0);
+                    assert!(!buf2.is_empty());
                 }
             }
         };
@@ -92,25 +92,92 @@ impl From<DecodeError> for std::io::Error {
 
 /// A Protobuf message encoding error.
 ///
-/// `EncodeError` always indicates that a message failed to encode because the
-/// provided buffer had insufficient capacity. Message encoding is otherwise
-/// infallible.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// `EncodeError` indicates that a message failed to encode, the
+/// [`EncodeErrorKind`] telling whether trying again with another buffer may
+/// succeed.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct EncodeError {
+    kind: EncodeErrorKind,
+    description: Cow<'static, str>,
     required: usize,
     remaining: usize,
+    missing_required_fields: Vec<&'static str>,
+}
+
+/// The categories of [`EncodeError`].
+///
+/// This list may grow, and it is not recommended to match against it
+/// exhaustively.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EncodeErrorKind {
+    /// The message is longer than the 2 GiB Protobuf allows.
+    TooLarge,
+    /// A message or field name to encode isn't valid UTF-8.
+    InvalidUtf8InName,
+    /// A value of a type the encoder can't represent.
+    UnsupportedType,
+    /// The buffer has insufficient capacity for the message, see
+    /// [`EncodeError::required_capacity`].
+    BufferOverflow,
+    /// Writing the encoded message failed.
+    Io,
+    /// Proto2 `required` fields are not set in a message generated with
+    /// `proto2_required`, see [`EncodeError::missing_required`].
+    MissingRequired,
 }
 
 impl EncodeError {
-    /// Creates a new `EncodeError`.
-    pub(crate) fn new(required: usize, remaining: usize) -> EncodeError {
+    /// Creates a new `EncodeError` of `kind` with a root cause description.
+    ///
+    /// Meant to be used only by `Message` implementations.
+    #[doc(hidden)]
+    #[cold]
+    pub fn new(kind: EncodeErrorKind, description: impl Into<Cow<'static, str>>) -> EncodeError {
+        EncodeError {
+            kind,
+            description: description.into(),
+            required: 0,
+            remaining: 0,
+            missing_required_fields: Vec::new(),
+        }
+    }
+
+    #[cold]
+    pub(crate) fn buffer_overflow(required: usize, remaining: usize) -> EncodeError {
         EncodeError {
             required,
             remaining,
+            ..EncodeError::new(
+                EncodeErrorKind::BufferOverflow,
+                "insufficient buffer capacity",
+            )
         }
     }
 
-    /// Returns the required buffer capacity to encode the message.
+    #[cold]
+    pub(crate) fn too_large(len: usize) -> EncodeError {
+        EncodeError::new(
+            EncodeErrorKind::TooLarge,
+            format!("encoded length {len} exceeds the 2 GiB limit"),
+        )
+    }
+
+    #[cold]
+    pub(crate) fn missing_required_fields(fields: Vec<&'static str>) -> EncodeError {
+        EncodeError {
+            missing_required_fields: fields,
+            ..EncodeError::new(EncodeErrorKind::MissingRequired, "missing required fields")
+        }
+    }
+
+    /// Returns the category of the error.
+    pub fn kind(&self) -> EncodeErrorKind {
+        self.kind
+    }
+
+    /// Returns the required buffer capacity to encode the message, zero
+    /// unless the kind is [`EncodeErrorKind::BufferOverflow`].
     pub fn required_capacity(&self) -> usize {
         self.required
     }
@@ -120,15 +187,28 @@ impl EncodeError {
     pub fn remaining(&self) -> usize {
         self.remaining
     }
+
+    /// Returns the `required` fields not set, as `Message.field`, empty
+    /// unless the kind is [`EncodeErrorKind::MissingRequired`].
+    pub fn missing_required(&self) -> &[&'static str] {
+        &self.missing_required_fields
+    }
 }
 
 impl fmt::Display for EncodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "failed to encode Protobuf message; insufficient buffer capacity (required: {}, remaining: {})",
-            self.required, self.remaining
-        )
+        write!(f, "failed to encode Protobuf message; {}", self.description)?;
+        match self.kind {
+            EncodeErrorKind::MissingRequired => {
+                write!(f, ": {}", self.missing_required_fields.join(", "))
+            }
+            EncodeErrorKind::BufferOverflow => write!(
+                f,
+                " (required: {}, remaining: {})",
+                self.required, self.remaining
+            ),
+            _ => Ok(()),
+        }
     }
 }
 
@@ -139,3 +219,9 @@ impl From<EncodeError> for std::io::Error {
         std::io::Error::new(std::io::ErrorKind::InvalidInput, error)
     }
 }
+
+impl From<std::io::Error> for EncodeError {
+    fn from(error: std::io::Error) -> EncodeError {
+        EncodeError::new(EncodeErrorKind::Io, error.to_string())
+    }
+}
@@ -43,6 +43,13 @@ pub trait Message: Debug + Send + Sync {
     /// Returns the encoded length of the message without a length delimiter.
     fn encoded_len(&self, ctx: &mut EncodeLengthContext) -> usize;
 
+    /// Pushes the proto2 `required` fields not set, as `Message.field`, of
+    /// this message and of the messages nested in it.
+    ///
+    /// Only messages generated with `proto2_required` have such fields.
+    #[inline]
+    fn missing_required_fields(&self, _missing: &mut Vec<&'static str>) {}
+
     /// Encodes the message to a buffer.
     ///
     /// An error will be returned if the buffer does not have sufficient
@@ -51,13 +58,15 @@ pub trait Message: Debug + Send + Sync {
     where
         Self: Sized,
     {
+        check_required_on_encode(self)?;
         let mut ctx = EncodeLengthContext::default();
         let len = self.encoded_len(&mut ctx);
+        check_len_on_encode(len)?;
         let required = len - ctx.zero_copy_len;
 
         let remaining = buf.remaining_mut();
         if required > remaining {
-            return Err(EncodeError::new(required, remaining));
+            return Err(EncodeError::buffer_overflow(required, remaining));
         }
 
         self.encode_raw(buf);
@@ -87,12 +96,14 @@ pub trait Message: Debug + Send + Sync {
     where
         Self: Sized,
     {
+        check_required_on_encode(self)?;
         let (len, total) = self.encoded_len_length_delimited(ctx);
+        check_len_on_encode(len)?;
         let required = total - ctx.zero_copy_len;
 
         let remaining = buf.remaining_mut();
         if required > remaining {
-            return Err(EncodeError::new(required, remaining));
+            return Err(EncodeError::buffer_overflow(required, remaining));
         }
 
         encode_varint(len as u64, buf);
@@ -169,7 +180,7 @@ pub trait Message: Debug + Send + Sync {
             let (tag, wire_type) = decode_key(&mut buf)?;
             self.merge_field(tag, wire_type, &mut buf, &mut ctx, true)?;
         }
-        Ok(())
+        check_required_on_decode(self)
     }
 
     /// Decodes a length-delimited instance of the message from buffer, and
@@ -179,7 +190,45 @@ pub trait Message: Debug + Send + Sync {
         Self: Sized,
     {
         let mut ctx = DecodeContext::new(buf.clone());
-        message::merge(WireType::LengthDelimited, self, &mut buf, &mut ctx)
+        message::merge(WireType::LengthDelimited, self, &mut buf, &mut ctx)?;
+        check_required_on_decode(self)
+    }
+}
+
+// the required fields are checked once for the whole message, so the error
+// lists all of them
+fn check_required_on_decode<M: Message>(msg: &M) -> Result<(), DecodeError> {
+    let mut missing = Vec::new();
+    msg.missing_required_fields(&mut missing);
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(DecodeError::new(format!(
+            "missing required fields: {}",
+            missing.join(", ")
+        )))
+    }
+}
+
+fn check_required_on_encode<M: Message>(msg: &M) -> Result<(), EncodeError> {
+    let mut missing = Vec::new();
+    msg.missing_required_fields(&mut missing);
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(EncodeError::missing_required_fields(missing))
+    }
+}
+
+// the largest message Protobuf allows, lengths being read as signed 32-bit
+// integers by most implementations
+const MAX_MESSAGE_LEN: usize = i32::MAX as usize;
+
+fn check_len_on_encode(len: usize) -> Result<(), EncodeError> {
+    if len > MAX_MESSAGE_LEN {
+        Err(EncodeError::too_large(len))
+    } else {
+        Ok(())
     }
 }
 
@@ -203,6 +252,9 @@ where
     fn encoded_len(&self, ctx: &mut EncodeLengthContext) -> usize {
         (**self).encoded_len(ctx)
     }
+    fn missing_required_fields(&self, missing: &mut Vec<&'static str>) {
+        (**self).missing_required_fields(missing)
+    }
 }
 
 trait ArcMessage<M>
@@ -272,6 +324,10 @@ where
         <Arc<M> as ArcMessage<M>>::encoded_len(self, ctx)
     }
 
+    fn missing_required_fields(&self, missing: &mut Vec<&'static str>) {
+        (**self).missing_required_fields(missing)
+    }
+
     fn decode(buf: Bytes) -> Result<Arc<M>, DecodeError>
     where
         M: Default + Clone,
@@ -314,6 +370,7 @@ pub trait EnumMessage: Sized {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::pb::EncodeErrorKind;
     use crate::pb::encoding::{DecodeContext, EncodeLengthContext, WireType, encode_varint};
 
     const _MESSAGE_IS_OBJECT_SAFE: Option<&dyn Message> = None;
@@ -332,7 +389,7 @@ mod tests {
     impl Message for TestMessage {
         fn encode_raw(&self, buf: &mut LinkedBytes) {
             // tag=1, wire_type=0 (varint), value
-            encode_varint((1 << 3) | 0, buf); // tag=1, wire_type=0
+            encode_varint(1 << 3, buf); // tag=1, wire_type=0
             encode_varint(self.value as u64, buf);
         }
 
@@ -432,6 +489,53 @@ mod tests {
         assert!(result.is_ok() || result.is_err());
     }
 
+    #[test]
+    fn test_message_encode_error_kinds() {
+        // claims a length past the limit, never encoded
+        #[derive(Debug, Default)]
+        struct Huge;
+
+        impl Message for Huge {
+            fn encode_raw(&self, _buf: &mut LinkedBytes) {
+                unreachable!()
+            }
+
+            fn merge_field(
+                &mut self,
+                tag: u32,
+                wire_type: WireType,
+                buf: &mut Bytes,
+                ctx: &mut DecodeContext,
+                _is_root: bool,
+            ) -> Result<(), DecodeError> {
+                crate::pb::encoding::skip_field(wire_type, tag, buf, ctx)
+            }
+
+            fn encoded_len(&self, _ctx: &mut EncodeLengthContext) -> usize {
+                MAX_MESSAGE_LEN + 1
+            }
+        }
+
+        let mut buf = LinkedBytes::new();
+        let err = Huge.encode(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), EncodeErrorKind::TooLarge);
+        let err = Huge
+            .encode_length_delimited(&mut EncodeLengthContext::default(), &mut buf)
+            .unwrap_err();
+        assert_eq!(err.kind(), EncodeErrorKind::TooLarge);
+        assert!(buf.is_empty());
+
+        let err = EncodeError::buffer_overflow(8, 4);
+        assert_eq!(err.kind(), EncodeErrorKind::BufferOverflow);
+        assert_eq!(
+            err.to_string(),
+            "failed to encode Protobuf message; insufficient buffer capacity (required: 8, remaining: 4)"
+        );
+
+        let err = EncodeError::from(std::io::Error::other("closed"));
+        assert_eq!(err.kind(), EncodeErrorKind::Io);
+    }
+
     #[test]
     fn test_box_message() {
         let original = Box::new(TestMessage::new(789));
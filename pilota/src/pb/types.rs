@@ -338,6 +338,7 @@ impl Message for () {
 }
 
 #[cfg(test)]
+#[allow(clippy::approx_constant)]
 mod tests {
     use ::bytes::Bytes;
     use linkedbytes::LinkedBytes;
@@ -0,0 +1,195 @@
+//! Serde helpers that serialize numbers as strings, for
+//! `#[serde(with = "::pilota::serde_str")]`.
+//!
+//! JavaScript numbers lose precision above 2^53, so proto3 JSON writes 64-bit
+//! integers as strings. Deserialization accepts both strings and numbers.
+
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+use serde::{
+    Deserializer, Serializer,
+    de::{self, Visitor},
+};
+
+pub fn serialize<T: fmt::Display, S: Serializer>(v: &T, s: S) -> Result<S::Ok, S::Error> {
+    s.collect_str(v)
+}
+
+pub fn deserialize<'de, T, D>(d: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+    D: Deserializer<'de>,
+{
+    d.deserialize_any(StrVisitor(PhantomData))
+}
+
+struct StrVisitor<T>(PhantomData<T>);
+
+impl<T> Visitor<'_> for StrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number or a string containing a number")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        self.visit_str(&v.to_string())
+    }
+}
+
+struct Str<T>(T);
+
+impl<'de, T> de::Deserialize<'de> for Str<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        deserialize(d).map(Str)
+    }
+}
+
+/// For `Option<T>` fields, to be combined with `#[serde(default)]`.
+pub mod option {
+    use std::{fmt, str::FromStr};
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Str;
+
+    pub fn serialize<T: fmt::Display, S: Serializer>(
+        v: &Option<T>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        match v {
+            Some(v) => s.collect_str(v),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(d: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<Str<T>>::deserialize(d)?.map(|v| v.0))
+    }
+}
+
+/// For `Vec<T>` fields.
+pub mod vec {
+    use std::{fmt, str::FromStr};
+
+    use serde::{Deserialize, Deserializer, Serializer, ser::SerializeSeq};
+
+    use super::Str;
+
+    pub fn serialize<T: fmt::Display, S: Serializer>(v: &[T], s: S) -> Result<S::Ok, S::Error> {
+        let mut seq = s.serialize_seq(Some(v.len()))?;
+        for v in v {
+            seq.serialize_element(&v.to_string())?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, T, D>(d: D) -> Result<Vec<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        Ok(Vec::<Str<T>>::deserialize(d)?
+            .into_iter()
+            .map(|v| v.0)
+            .collect())
+    }
+}
+
+/// For `Option<Vec<T>>` fields, to be combined with `#[serde(default)]`.
+pub mod option_vec {
+    use std::{fmt, str::FromStr};
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Str;
+
+    pub fn serialize<T: fmt::Display, S: Serializer>(
+        v: &Option<Vec<T>>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        match v {
+            Some(v) => super::vec::serialize(v, s),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(d: D) -> Result<Option<Vec<T>>, D::Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<Vec<Str<T>>>::deserialize(d)?.map(|v| v.into_iter().map(|v| v.0).collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Ids {
+        #[serde(with = "super")]
+        id: i64,
+        #[serde(default, with = "super::option")]
+        parent: Option<u64>,
+        #[serde(with = "super::vec")]
+        children: Vec<i64>,
+        #[serde(default, with = "super::option_vec")]
+        tags: Option<Vec<i64>>,
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let ids = Ids {
+            id: i64::MAX,
+            parent: Some(u64::MAX),
+            children: vec![-1, 2],
+            tags: None,
+        };
+        let json = serde_json::to_string(&ids).unwrap();
+        assert_eq!(
+            json,
+            r#"{"id":"9223372036854775807","parent":"18446744073709551615","children":["-1","2"],"tags":null}"#
+        );
+        assert_eq!(serde_json::from_str::<Ids>(&json).unwrap(), ids);
+    }
+
+    #[test]
+    fn test_accepts_numbers() {
+        let ids: Ids = serde_json::from_str(r#"{"id":1,"children":[3,"4"],"tags":[5]}"#).unwrap();
+        assert_eq!(
+            ids,
+            Ids {
+                id: 1,
+                parent: None,
+                children: vec![3, 4],
+                tags: Some(vec![5]),
+            }
+        );
+    }
+}
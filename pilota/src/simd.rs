@@ -0,0 +1,493 @@
+//! Dispatch of the hot-path routines to their CPU-specific versions.
+//!
+//! The CPU features are detected once, on the first call of a routine, and
+//! the routines are resolved to function pointers along with them, so every
+//! caller sees the same choice and none detects the features on its own:
+//!
+//! - [`crc32c`] uses the SSE4.2 `crc32` instruction.
+//! - [`validate_utf8`] skips ASCII 32 bytes at a time with AVX2.
+//! - [`find_byte`] compares 32 bytes at a time with AVX2, 16 with SSE2.
+//! - The protobuf varints are decoded with the BMI2 `pext` instruction.
+//!
+//! The other CPUs, and every CPU with the `safe-only` feature, get the
+//! portable versions.
+
+use std::sync::OnceLock;
+
+/// The CPU features the routines were resolved with, see [`features`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Features {
+    pub sse42: bool,
+    pub avx2: bool,
+    pub bmi2: bool,
+}
+
+impl Features {
+    fn detect() -> Self {
+        #[cfg(all(target_arch = "x86_64", not(feature = "safe-only")))]
+        {
+            Self {
+                sse42: std::arch::is_x86_feature_detected!("sse4.2"),
+                avx2: std::arch::is_x86_feature_detected!("avx2"),
+                bmi2: std::arch::is_x86_feature_detected!("bmi2"),
+            }
+        }
+        #[cfg(not(all(target_arch = "x86_64", not(feature = "safe-only"))))]
+        {
+            Self::default()
+        }
+    }
+}
+
+struct Dispatch {
+    features: Features,
+    crc32c: fn(&[u8]) -> u32,
+    validate_utf8: fn(&[u8]) -> bool,
+    find_byte: fn(u8, &[u8]) -> Option<usize>,
+    decode_varint: fn(&[u8]) -> Option<(u64, usize)>,
+}
+
+impl Dispatch {
+    const PORTABLE: Self = Self {
+        features: Features {
+            sse42: false,
+            avx2: false,
+            bmi2: false,
+        },
+        crc32c: portable::crc32c,
+        validate_utf8: portable::validate_utf8,
+        find_byte: portable::find_byte,
+        decode_varint: portable::decode_varint,
+    };
+
+    #[allow(unused_mut)]
+    fn resolve(features: Features) -> Self {
+        let mut dispatch = Self {
+            features,
+            ..Self::PORTABLE
+        };
+        #[cfg(all(target_arch = "x86_64", not(feature = "safe-only")))]
+        {
+            dispatch.find_byte = x86::find_byte_sse2;
+            if features.sse42 {
+                dispatch.crc32c = x86::crc32c_sse42;
+            }
+            if features.avx2 {
+                dispatch.validate_utf8 = x86::validate_utf8_avx2;
+                dispatch.find_byte = x86::find_byte_avx2;
+            }
+            if features.bmi2 {
+                dispatch.decode_varint = x86::decode_varint_bmi2;
+            }
+        }
+        dispatch
+    }
+}
+
+#[inline]
+fn dispatch() -> &'static Dispatch {
+    static DISPATCH: OnceLock<Dispatch> = OnceLock::new();
+    DISPATCH.get_or_init(|| Dispatch::resolve(Features::detect()))
+}
+
+/// The CPU features detected, which the routines use.
+pub fn features() -> Features {
+    dispatch().features
+}
+
+/// The CRC32C (Castagnoli) of `data`.
+#[inline]
+pub fn crc32c(data: &[u8]) -> u32 {
+    (dispatch().crc32c)(data)
+}
+
+/// Whether `data` is valid UTF-8, same as `std::str::from_utf8(data).is_ok()`.
+#[inline]
+pub fn validate_utf8(data: &[u8]) -> bool {
+    (dispatch().validate_utf8)(data)
+}
+
+/// The index of the first `needle` in `haystack`.
+#[inline]
+pub fn find_byte(needle: u8, haystack: &[u8]) -> Option<usize> {
+    (dispatch().find_byte)(needle, haystack)
+}
+
+/// Decodes the LEB128 varint `bytes` start with, returning its value and
+/// length, or `None` if it overflows a `u64`.
+///
+/// # Panics
+///
+/// If `bytes` is empty or neither holds 10 bytes nor ends with the last byte
+/// of a varint.
+#[inline]
+pub(crate) fn decode_varint(bytes: &[u8]) -> Option<(u64, usize)> {
+    (dispatch().decode_varint)(bytes)
+}
+
+mod portable {
+    // reflected Castagnoli polynomial
+    const POLY: u32 = 0x82f6_3b78;
+
+    static TABLE: [u32; 256] = {
+        let mut table = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ POLY
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    };
+
+    pub(super) fn crc32c(data: &[u8]) -> u32 {
+        !data.iter().fold(!0, |crc, b| {
+            TABLE[((crc ^ *b as u32) & 0xff) as usize] ^ (crc >> 8)
+        })
+    }
+
+    pub(super) fn validate_utf8(data: &[u8]) -> bool {
+        std::str::from_utf8(data).is_ok()
+    }
+
+    pub(super) fn find_byte(needle: u8, haystack: &[u8]) -> Option<usize> {
+        haystack.iter().position(|b| *b == needle)
+    }
+
+    #[cfg(not(feature = "safe-only"))]
+    #[inline(always)]
+    fn byte_at(bytes: &[u8], i: usize) -> u8 {
+        unsafe { *bytes.get_unchecked(i) }
+    }
+
+    #[cfg(feature = "safe-only")]
+    #[inline(always)]
+    fn byte_at(bytes: &[u8], i: usize) -> u8 {
+        bytes[i]
+    }
+
+    /// Based loosely on [`ReadVarint64FromArray`][1] with a varint overflow
+    /// check from [`ConsumeVarint`][2].
+    ///
+    /// [1]: https://github.com/google/protobuf/blob/3.3.x/src/google/protobuf/io/coded_stream.cc#L365-L406
+    /// [2]: https://github.com/protocolbuffers/protobuf-go/blob/v1.27.1/encoding/protowire/wire.go#L358
+    #[inline]
+    pub(super) fn decode_varint(bytes: &[u8]) -> Option<(u64, usize)> {
+        // Fully unrolled varint decoding loop. Splitting into 32-bit pieces gives
+        // better performance.
+
+        // Use assertions to ensure memory safety, but it should always be optimized
+        // after inline.
+        assert!(!bytes.is_empty());
+        assert!(bytes.len() > 10 || bytes[bytes.len() - 1] < 0x80);
+
+        let mut b: u8 = byte_at(bytes, 0);
+        let mut part0: u32 = u32::from(b);
+        if b < 0x80 {
+            return Some((u64::from(part0), 1));
+        };
+        part0 -= 0x80;
+        b = byte_at(bytes, 1);
+        part0 += u32::from(b) << 7;
+        if b < 0x80 {
+            return Some((u64::from(part0), 2));
+        };
+        part0 -= 0x80 << 7;
+        b = byte_at(bytes, 2);
+        part0 += u32::from(b) << 14;
+        if b < 0x80 {
+            return Some((u64::from(part0), 3));
+        };
+        part0 -= 0x80 << 14;
+        b = byte_at(bytes, 3);
+        part0 += u32::from(b) << 21;
+        if b < 0x80 {
+            return Some((u64::from(part0), 4));
+        };
+        part0 -= 0x80 << 21;
+        let value = u64::from(part0);
+
+        b = byte_at(bytes, 4);
+        let mut part1: u32 = u32::from(b);
+        if b < 0x80 {
+            return Some((value + (u64::from(part1) << 28), 5));
+        };
+        part1 -= 0x80;
+        b = byte_at(bytes, 5);
+        part1 += u32::from(b) << 7;
+        if b < 0x80 {
+            return Some((value + (u64::from(part1) << 28), 6));
+        };
+        part1 -= 0x80 << 7;
+        b = byte_at(bytes, 6);
+        part1 += u32::from(b) << 14;
+        if b < 0x80 {
+            return Some((value + (u64::from(part1) << 28), 7));
+        };
+        part1 -= 0x80 << 14;
+        b = byte_at(bytes, 7);
+        part1 += u32::from(b) << 21;
+        if b < 0x80 {
+            return Some((value + (u64::from(part1) << 28), 8));
+        };
+        part1 -= 0x80 << 21;
+        let value = value + ((u64::from(part1)) << 28);
+
+        b = byte_at(bytes, 8);
+        let mut part2: u32 = u32::from(b);
+        if b < 0x80 {
+            return Some((value + (u64::from(part2) << 56), 9));
+        };
+        part2 -= 0x80;
+        b = byte_at(bytes, 9);
+        part2 += u32::from(b) << 7;
+        // Check for u64::MAX overflow. See [`ConsumeVarint`][1] for details.
+        // [1]: https://github.com/protocolbuffers/protobuf-go/blob/v1.27.1/encoding/protowire/wire.go#L358
+        if b < 0x02 {
+            return Some((value + (u64::from(part2) << 56), 10));
+        };
+
+        // We have overrun the maximum size of a varint (10 bytes) or the final byte
+        // caused an overflow. Assume the data is corrupt.
+        None
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "safe-only")))]
+mod x86 {
+    use std::arch::x86_64::*;
+
+    use super::portable;
+
+    pub(super) fn crc32c_sse42(data: &[u8]) -> u32 {
+        // SAFETY: only resolved when SSE4.2 is detected
+        unsafe { crc32c_sse42_impl(data) }
+    }
+
+    #[target_feature(enable = "sse4.2")]
+    unsafe fn crc32c_sse42_impl(data: &[u8]) -> u32 {
+        let mut chunks = data.chunks_exact(8);
+        let mut crc = !0u64;
+        for chunk in &mut chunks {
+            crc = _mm_crc32_u64(crc, u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        let mut crc = crc as u32;
+        for b in chunks.remainder() {
+            crc = _mm_crc32_u8(crc, *b);
+        }
+        !crc
+    }
+
+    pub(super) fn validate_utf8_avx2(data: &[u8]) -> bool {
+        // SAFETY: only resolved when AVX2 is detected
+        let ascii = unsafe { ascii_prefix_avx2(data) };
+        // the prefix being ASCII, the rest starts at a char boundary
+        portable::validate_utf8(&data[ascii..])
+    }
+
+    /// The length of the 32-byte blocks of ASCII `data` starts with.
+    #[target_feature(enable = "avx2")]
+    unsafe fn ascii_prefix_avx2(data: &[u8]) -> usize {
+        let mut i = 0;
+        while i + 32 <= data.len() {
+            // SAFETY: the 32 bytes from `i` are in `data`
+            let block = unsafe { _mm256_loadu_si256(data.as_ptr().add(i) as *const __m256i) };
+            if _mm256_movemask_epi8(block) != 0 {
+                break;
+            }
+            i += 32;
+        }
+        i
+    }
+
+    pub(super) fn find_byte_sse2(needle: u8, haystack: &[u8]) -> Option<usize> {
+        // SAFETY: SSE2 is part of the x86_64 baseline
+        unsafe { find_byte_sse2_impl(needle, haystack) }
+    }
+
+    #[target_feature(enable = "sse2")]
+    unsafe fn find_byte_sse2_impl(needle: u8, haystack: &[u8]) -> Option<usize> {
+        let n = _mm_set1_epi8(needle as i8);
+        let mut i = 0;
+        while i + 16 <= haystack.len() {
+            // SAFETY: the 16 bytes from `i` are in `haystack`
+            let block = unsafe { _mm_loadu_si128(haystack.as_ptr().add(i) as *const __m128i) };
+            let mask = _mm_movemask_epi8(_mm_cmpeq_epi8(block, n)) as u32;
+            if mask != 0 {
+                return Some(i + mask.trailing_zeros() as usize);
+            }
+            i += 16;
+        }
+        portable::find_byte(needle, &haystack[i..]).map(|pos| i + pos)
+    }
+
+    pub(super) fn find_byte_avx2(needle: u8, haystack: &[u8]) -> Option<usize> {
+        // SAFETY: only resolved when AVX2 is detected
+        unsafe { find_byte_avx2_impl(needle, haystack) }
+    }
+
+    #[target_feature(enable = "avx2")]
+    unsafe fn find_byte_avx2_impl(needle: u8, haystack: &[u8]) -> Option<usize> {
+        let n = _mm256_set1_epi8(needle as i8);
+        let mut i = 0;
+        while i + 32 <= haystack.len() {
+            // SAFETY: the 32 bytes from `i` are in `haystack`
+            let block = unsafe { _mm256_loadu_si256(haystack.as_ptr().add(i) as *const __m256i) };
+            let mask = _mm256_movemask_epi8(_mm256_cmpeq_epi8(block, n)) as u32;
+            if mask != 0 {
+                return Some(i + mask.trailing_zeros() as usize);
+            }
+            i += 32;
+        }
+        find_byte_sse2(needle, &haystack[i..]).map(|pos| i + pos)
+    }
+
+    pub(super) fn decode_varint_bmi2(bytes: &[u8]) -> Option<(u64, usize)> {
+        // SAFETY: only resolved when BMI2 is detected
+        unsafe { decode_varint_bmi2_impl(bytes) }
+    }
+
+    #[target_feature(enable = "bmi2")]
+    unsafe fn decode_varint_bmi2_impl(bytes: &[u8]) -> Option<(u64, usize)> {
+        // the varints of up to 8 bytes, in one word
+        if let Some(word) = bytes.get(..8) {
+            let word = u64::from_le_bytes(word.try_into().unwrap());
+            let ends = !word & 0x8080_8080_8080_8080;
+            if ends != 0 {
+                let len = ends.trailing_zeros() as usize / 8 + 1;
+                let word = if len == 8 {
+                    word
+                } else {
+                    word & ((1 << (len * 8)) - 1)
+                };
+                return Some((_pext_u64(word, 0x7f7f_7f7f_7f7f_7f7f), len));
+            }
+        }
+        portable::decode_varint(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dispatch, Features, portable};
+
+    fn resolved() -> Vec<Dispatch> {
+        let detected = Features::detect();
+        let mut dispatches = vec![Dispatch::PORTABLE, Dispatch::resolve(Features::default())];
+        for features in [
+            Features {
+                sse42: true,
+                ..Default::default()
+            },
+            Features {
+                avx2: true,
+                ..Default::default()
+            },
+            Features {
+                bmi2: true,
+                ..Default::default()
+            },
+        ] {
+            // only the routines this CPU can run
+            if (!features.sse42 || detected.sse42)
+                && (!features.avx2 || detected.avx2)
+                && (!features.bmi2 || detected.bmi2)
+            {
+                dispatches.push(Dispatch::resolve(features));
+            }
+        }
+        dispatches
+    }
+
+    #[test]
+    fn test_crc32c() {
+        let data = (0..=255u8).cycle().take(1000).collect::<Vec<_>>();
+        for dispatch in resolved() {
+            assert_eq!((dispatch.crc32c)(b"123456789"), 0xe306_9283);
+            assert_eq!((dispatch.crc32c)(&[]), 0);
+            for len in [0, 1, 7, 8, 9, 63, 1000] {
+                assert_eq!(
+                    (dispatch.crc32c)(&data[..len]),
+                    portable::crc32c(&data[..len])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_utf8() {
+        let ascii = "a".repeat(100);
+        let cases = [
+            Vec::new(),
+            ascii.clone().into_bytes(),
+            "é".repeat(40).into_bytes(),
+            [ascii.as_bytes(), "日本".as_bytes()].concat(),
+            [ascii.as_bytes(), &[0xff]].concat(),
+            [ascii.as_bytes(), &"€".as_bytes()[..2]].concat(),
+        ];
+        for dispatch in resolved() {
+            for case in &cases {
+                assert_eq!(
+                    (dispatch.validate_utf8)(case),
+                    std::str::from_utf8(case).is_ok()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_byte() {
+        let mut haystack = vec![1u8; 100];
+        for dispatch in resolved() {
+            assert_eq!((dispatch.find_byte)(0, &haystack), None);
+            assert_eq!((dispatch.find_byte)(0, &[]), None);
+            for pos in [0, 15, 16, 31, 32, 70, 99] {
+                haystack[pos] = 0;
+                haystack[99] = 0;
+                assert_eq!((dispatch.find_byte)(0, &haystack), Some(pos));
+                haystack.fill(1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_varint() {
+        let encode = |mut value: u64| {
+            let mut bytes = Vec::new();
+            while value >= 0x80 {
+                bytes.push(value as u8 | 0x80);
+                value >>= 7;
+            }
+            bytes.push(value as u8);
+            bytes
+        };
+        for dispatch in resolved() {
+            for shift in 0..64 {
+                for value in [1u64 << shift, (1u64 << shift) - 1, u64::MAX >> shift] {
+                    let mut bytes = encode(value);
+                    let len = bytes.len();
+                    assert_eq!((dispatch.decode_varint)(&bytes), Some((value, len)));
+                    // followed by more bytes
+                    bytes.extend([0xff; 10]);
+                    assert_eq!((dispatch.decode_varint)(&bytes), Some((value, len)));
+                }
+            }
+            assert_eq!((dispatch.decode_varint)(&[0xff; 11]), None);
+            assert_eq!(
+                (dispatch.decode_varint)(&[
+                    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02
+                ]),
+                None
+            );
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! Value and key encodings for embedded key-value stores such as RocksDB or
+//! sled.
+//!
+//! Values written by [`AsStorageValue`] are prefixed with the
+//! [`Fingerprint`] of their type and the protocol they are encoded with:
+//!
+//! ```text
+//! +-------------------+---------------+---------+
+//! | fingerprint (u64) | protocol (u8) | payload |
+//! +-------------------+---------------+---------+
+//! ```
+//!
+//! Keys built with [`KeyEncoder`] compare bytewise in the same order as
+//! their components, so range scans over them follow field order.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+pub use crate::cache::{Fingerprint, fingerprint};
+use crate::thrift::{
+    Message, ThriftException,
+    binary::TBinaryProtocol,
+    compact::{TCompactInputProtocol, TCompactOutputProtocol},
+};
+
+const HEADER_LEN: usize = 8 + 1;
+
+/// The protocol a stored value is encoded with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Protocol {
+    Binary = 1,
+    #[default]
+    Compact = 2,
+}
+
+impl TryFrom<u8> for Protocol {
+    type Error = StorageError;
+
+    fn try_from(value: u8) -> Result<Self, StorageError> {
+        match value {
+            1 => Ok(Protocol::Binary),
+            2 => Ok(Protocol::Compact),
+            _ => Err(StorageError::UnknownProtocol(value)),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StorageError {
+    #[error("stored data is truncated")]
+    Truncated,
+    #[error("unknown protocol {0} in stored value")]
+    UnknownProtocol(u8),
+    #[error("type fingerprint mismatch: expected {expected:#018x}, found {found:#018x}")]
+    FingerprintMismatch { expected: u64, found: u64 },
+    #[error("invalid escape sequence in stored key")]
+    InvalidKey,
+    #[error(transparent)]
+    Thrift(#[from] ThriftException),
+}
+
+/// Serializes a message into a self-describing stored value.
+///
+/// Implemented for every [`Message`] with a [`Fingerprint`].
+pub trait AsStorageValue: Message + Fingerprint {
+    fn to_storage_value(&self, protocol: Protocol) -> Result<Bytes, StorageError> {
+        let mut buf = BytesMut::new();
+        buf.put_u64(Self::FINGERPRINT);
+        buf.put_u8(protocol as u8);
+        match protocol {
+            Protocol::Binary => self.encode(&mut TBinaryProtocol::new(&mut buf, false))?,
+            Protocol::Compact => self.encode(&mut TCompactOutputProtocol::new(&mut buf, false))?,
+        }
+        Ok(buf.freeze())
+    }
+
+    /// Decodes a value written by [`to_storage_value`], checking that it was
+    /// written for this type.
+    ///
+    /// [`to_storage_value`]: AsStorageValue::to_storage_value
+    fn from_storage_value(mut data: Bytes) -> Result<Self, StorageError> {
+        if data.len() < HEADER_LEN {
+            return Err(StorageError::Truncated);
+        }
+        let found = data.get_u64();
+        if found != Self::FINGERPRINT {
+            return Err(StorageError::FingerprintMismatch {
+                expected: Self::FINGERPRINT,
+                found,
+            });
+        }
+        Ok(match Protocol::try_from(data.get_u8())? {
+            Protocol::Binary => Self::decode(&mut TBinaryProtocol::new(&mut data, true))?,
+            Protocol::Compact => Self::decode(&mut TCompactInputProtocol::new(&mut data))?,
+        })
+    }
+}
+
+impl<M: Message + Fingerprint> AsStorageValue for M {}
+
+/// Builds an order-preserving composite key.
+///
+/// Integers are written big-endian, with the sign bit flipped for signed
+/// integers. Strings and bytes are terminated by `0x00 0x00`, with `0x00`
+/// inside them escaped as `0x00 0xff`, so a shorter string sorts before any
+/// of its extensions.
+#[derive(Clone, Debug, Default)]
+pub struct KeyEncoder {
+    buf: BytesMut,
+}
+
+impl KeyEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn u32(mut self, v: u32) -> Self {
+        self.buf.put_u32(v);
+        self
+    }
+
+    pub fn u64(mut self, v: u64) -> Self {
+        self.buf.put_u64(v);
+        self
+    }
+
+    pub fn i32(self, v: i32) -> Self {
+        self.u32((v as u32) ^ (1 << 31))
+    }
+
+    pub fn i64(self, v: i64) -> Self {
+        self.u64((v as u64) ^ (1 << 63))
+    }
+
+    pub fn str(self, v: &str) -> Self {
+        self.bytes(v.as_bytes())
+    }
+
+    pub fn bytes(mut self, v: &[u8]) -> Self {
+        for chunk in v.split_inclusive(|b| *b == 0) {
+            self.buf.put_slice(chunk);
+            if chunk.last() == Some(&0) {
+                self.buf.put_u8(0xff);
+            }
+        }
+        self.buf.put_slice(&[0, 0]);
+        self
+    }
+
+    pub fn finish(self) -> Bytes {
+        self.buf.freeze()
+    }
+}
+
+/// Reads back the components of a key built by [`KeyEncoder`], in the order
+/// they were written.
+#[derive(Clone, Debug)]
+pub struct KeyDecoder<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> KeyDecoder<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    pub fn u32(&mut self) -> Result<u32, StorageError> {
+        if self.buf.remaining() < 4 {
+            return Err(StorageError::Truncated);
+        }
+        Ok(self.buf.get_u32())
+    }
+
+    pub fn u64(&mut self) -> Result<u64, StorageError> {
+        if self.buf.remaining() < 8 {
+            return Err(StorageError::Truncated);
+        }
+        Ok(self.buf.get_u64())
+    }
+
+    pub fn i32(&mut self) -> Result<i32, StorageError> {
+        Ok((self.u32()? ^ (1 << 31)) as i32)
+    }
+
+    pub fn i64(&mut self) -> Result<i64, StorageError> {
+        Ok((self.u64()? ^ (1 << 63)) as i64)
+    }
+
+    pub fn str(&mut self) -> Result<String, StorageError> {
+        String::from_utf8(self.bytes()?).map_err(|e| ThriftException::from(e).into())
+    }
+
+    pub fn bytes(&mut self) -> Result<Vec<u8>, StorageError> {
+        let mut out = Vec::new();
+        loop {
+            let Some(pos) = self.buf.iter().position(|b| *b == 0) else {
+                return Err(StorageError::Truncated);
+            };
+            out.extend_from_slice(&self.buf[..pos]);
+            match self.buf.get(pos + 1) {
+                Some(0) => {
+                    self.buf = &self.buf[pos + 2..];
+                    return Ok(out);
+                }
+                Some(0xff) => {
+                    out.push(0);
+                    self.buf = &self.buf[pos + 2..];
+                }
+                Some(_) => return Err(StorageError::InvalidKey),
+                None => return Err(StorageError::Truncated),
+            }
+        }
+    }
+
+    /// Returns the bytes not read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsStorageValue, Fingerprint, KeyDecoder, KeyEncoder, Protocol, StorageError};
+    use crate::thrift::{ApplicationException, ApplicationExceptionKind};
+
+    impl Fingerprint for ApplicationException {
+        const FINGERPRINT: u64 = super::fingerprint("ApplicationException");
+    }
+
+    #[test]
+    fn test_storage_value() {
+        let e = ApplicationException::new(ApplicationExceptionKind::INTERNAL_ERROR, "oops");
+        for protocol in [Protocol::Binary, Protocol::Compact] {
+            let data = e.to_storage_value(protocol).unwrap();
+            assert_eq!(data[8], protocol as u8);
+            assert_eq!(ApplicationException::from_storage_value(data).unwrap(), e);
+        }
+
+        let mut data = e.to_storage_value(Protocol::Compact).unwrap().to_vec();
+        data[0] ^= 1;
+        assert!(matches!(
+            ApplicationException::from_storage_value(data.into()),
+            Err(StorageError::FingerprintMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_key_order() {
+        let key = |id: i64, name: &str| KeyEncoder::new().i64(id).str(name).finish();
+        let mut keys = [
+            key(1, "b"),
+            key(-1, "a"),
+            key(1, "a\0"),
+            key(i64::MIN, ""),
+            key(1, "a"),
+            key(0, "z"),
+        ];
+        keys.sort();
+        let decoded: Vec<_> = keys
+            .iter()
+            .map(|k| {
+                let mut decoder = KeyDecoder::new(k);
+                let key = (decoder.i64().unwrap(), decoder.str().unwrap());
+                assert!(decoder.remaining().is_empty());
+                key
+            })
+            .collect();
+        assert_eq!(
+            decoded,
+            vec![
+                (i64::MIN, "".to_string()),
+                (-1, "a".to_string()),
+                (0, "z".to_string()),
+                (1, "a".to_string()),
+                (1, "a\0".to_string()),
+                (1, "b".to_string()),
+            ]
+        );
+    }
+}
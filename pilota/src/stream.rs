@@ -0,0 +1,274 @@
+//! Support for streaming methods.
+//!
+//! Generated streaming methods take and return [`Streaming`] values. On the
+//! wire every message of a stream is sent as a gRPC length-prefixed frame:
+//!
+//! ```text
+//! +-----------------+----------------------+---------+
+//! | compressed (u8) | payload length (u32) | payload |
+//! +-----------------+----------------------+---------+
+//! ```
+//!
+//! Protobuf payloads are the encoded message, thrift payloads are the message
+//! encoded with the binary protocol, as in Kitex thrift streaming.
+
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+pub use futures_core::Stream;
+
+use crate::{
+    pb::{self, DecodeError, EncodeLengthContext},
+    thrift::{self, ThriftException, binary::TBinaryProtocol},
+};
+
+/// The error carried by a [`Streaming`].
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A stream of messages, as taken and returned by streaming methods.
+pub type Streaming<T> = Pin<Box<dyn Stream<Item = Result<T, BoxError>> + Send>>;
+
+pub const FRAME_HEADER_LEN: usize = 5;
+
+/// The default maximum payload size, the same as gRPC's.
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 4 * 1024 * 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub enum FrameError {
+    #[error("compressed frames are not supported")]
+    Compressed,
+    #[error("frame of {size} bytes exceeds the limit of {max} bytes")]
+    TooLarge { size: usize, max: usize },
+    #[error("stream ended in the middle of a frame")]
+    Truncated,
+    #[error(transparent)]
+    Thrift(#[from] ThriftException),
+    #[error(transparent)]
+    Protobuf(#[from] DecodeError),
+}
+
+/// Appends `payload` to `buf` as an uncompressed frame.
+pub fn encode_frame(payload: &[u8], buf: &mut BytesMut) {
+    buf.reserve(FRAME_HEADER_LEN + payload.len());
+    buf.put_u8(0);
+    buf.put_u32(payload.len() as u32);
+    buf.put_slice(payload);
+}
+
+/// Splits the payload of the first frame off `buf`, or returns `None` if the
+/// frame is not complete yet.
+pub fn decode_frame(
+    buf: &mut BytesMut,
+    max_frame_size: usize,
+) -> Result<Option<Bytes>, FrameError> {
+    if buf.len() < FRAME_HEADER_LEN {
+        return Ok(None);
+    }
+    if buf[0] != 0 {
+        return Err(FrameError::Compressed);
+    }
+    let size = u32::from_be_bytes([buf[1], buf[2], buf[3], buf[4]]) as usize;
+    if size > max_frame_size {
+        return Err(FrameError::TooLarge {
+            size,
+            max: max_frame_size,
+        });
+    }
+    if buf.len() < FRAME_HEADER_LEN + size {
+        buf.reserve(FRAME_HEADER_LEN + size - buf.len());
+        return Ok(None);
+    }
+    buf.advance(FRAME_HEADER_LEN);
+    Ok(Some(buf.split_to(size).freeze()))
+}
+
+/// Appends a protobuf message to `buf` as a frame.
+pub fn encode_pb_frame<M: pb::Message>(msg: &M, buf: &mut BytesMut) {
+    encode_frame(&msg.encode_to_vec(&mut EncodeLengthContext::default()), buf)
+}
+
+/// Appends a thrift message to `buf` as a frame.
+pub fn encode_thrift_frame<M: thrift::Message>(
+    msg: &M,
+    buf: &mut BytesMut,
+) -> Result<(), ThriftException> {
+    let mut payload = BytesMut::new();
+    msg.encode(&mut TBinaryProtocol::new(&mut payload, false))?;
+    encode_frame(&payload, buf);
+    Ok(())
+}
+
+/// How a frame payload is decoded into a message.
+pub trait PayloadDecoder {
+    type Item;
+
+    fn decode(payload: Bytes) -> Result<Self::Item, FrameError>;
+}
+
+/// Decodes payloads as protobuf messages.
+pub struct Pb<M>(PhantomData<fn() -> M>);
+
+impl<M: pb::Message + Default> PayloadDecoder for Pb<M> {
+    type Item = M;
+
+    fn decode(payload: Bytes) -> Result<M, FrameError> {
+        Ok(M::decode(payload)?)
+    }
+}
+
+/// Decodes payloads as thrift messages encoded with the binary protocol.
+pub struct Thrift<M>(PhantomData<fn() -> M>);
+
+impl<M: thrift::Message> PayloadDecoder for Thrift<M> {
+    type Item = M;
+
+    fn decode(mut payload: Bytes) -> Result<M, FrameError> {
+        Ok(M::decode(&mut TBinaryProtocol::new(&mut payload, true))?)
+    }
+}
+
+/// Turns a stream of byte chunks, such as an HTTP/2 body, into a stream of
+/// messages.
+pub struct DecodeStream<S, D> {
+    inner: S,
+    buf: BytesMut,
+    max_frame_size: usize,
+    done: bool,
+    _decoder: PhantomData<fn() -> D>,
+}
+
+/// A [`DecodeStream`] of protobuf messages.
+pub type PbStream<S, M> = DecodeStream<S, Pb<M>>;
+
+/// A [`DecodeStream`] of thrift messages.
+pub type ThriftStream<S, M> = DecodeStream<S, Thrift<M>>;
+
+impl<S, D> DecodeStream<S, D> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            buf: BytesMut::new(),
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            done: false,
+            _decoder: PhantomData,
+        }
+    }
+
+    pub fn with_max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.max_frame_size = max_frame_size;
+        self
+    }
+}
+
+impl<S, E, D> Stream for DecodeStream<S, D>
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+    E: Into<BoxError>,
+    D: PayloadDecoder,
+{
+    type Item = Result<D::Item, BoxError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(payload) = decode_frame(&mut this.buf, this.max_frame_size)? {
+                return Poll::Ready(Some(D::decode(payload).map_err(Into::into)));
+            }
+            if this.done {
+                if this.buf.is_empty() {
+                    return Poll::Ready(None);
+                }
+                this.buf.clear();
+                return Poll::Ready(Some(Err(FrameError::Truncated.into())));
+            }
+            match ready!(Pin::new(&mut this.inner).poll_next(cx)) {
+                Some(Ok(chunk)) => this.buf.extend_from_slice(&chunk),
+                Some(Err(e)) => return Poll::Ready(Some(Err(e.into()))),
+                None => this.done = true,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::VecDeque,
+        pin::Pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use bytes::{Bytes, BytesMut};
+
+    use super::{BoxError, FrameError, Stream, ThriftStream, decode_frame, encode_thrift_frame};
+    use crate::thrift::{ApplicationException, ApplicationExceptionKind};
+
+    struct Chunks(VecDeque<Bytes>);
+
+    impl Stream for Chunks {
+        type Item = Result<Bytes, BoxError>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.0.pop_front().map(Ok))
+        }
+    }
+
+    fn poll<S: Stream + Unpin>(s: &mut S) -> Poll<Option<S::Item>> {
+        Pin::new(s).poll_next(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn test_decode_stream() {
+        let msgs = [
+            ApplicationException::new(ApplicationExceptionKind::UNKNOWN, "first"),
+            ApplicationException::new(ApplicationExceptionKind::INTERNAL_ERROR, "second"),
+        ];
+        let mut buf = BytesMut::new();
+        for msg in &msgs {
+            encode_thrift_frame(msg, &mut buf).unwrap();
+        }
+        let data = buf.freeze();
+        // Split the frames at odd places, the first chunk is shorter than a
+        // frame header.
+        let chunks = [data.slice(..3), data.slice(3..11), data.slice(11..)];
+
+        let mut stream = ThriftStream::<_, ApplicationException>::new(Chunks(chunks.into()));
+        for msg in &msgs {
+            match poll(&mut stream) {
+                Poll::Ready(Some(Ok(decoded))) => assert_eq!(&decoded, msg),
+                _ => panic!("expected a message"),
+            }
+        }
+        assert!(matches!(poll(&mut stream), Poll::Ready(None)));
+
+        let mut stream = ThriftStream::<_, ApplicationException>::new(Chunks(
+            [data.slice(..data.len() - 1)].into(),
+        ));
+        assert!(matches!(poll(&mut stream), Poll::Ready(Some(Ok(_)))));
+        match poll(&mut stream) {
+            Poll::Ready(Some(Err(e))) => {
+                assert!(matches!(e.downcast_ref(), Some(FrameError::Truncated)))
+            }
+            _ => panic!("expected an error"),
+        }
+    }
+
+    #[test]
+    fn test_frame_limits() {
+        let mut buf = BytesMut::from(&[1, 0, 0, 0, 0][..]);
+        assert!(matches!(
+            decode_frame(&mut buf, 16),
+            Err(FrameError::Compressed)
+        ));
+
+        let mut buf = BytesMut::from(&[0, 0, 0, 0, 17][..]);
+        assert!(matches!(
+            decode_frame(&mut buf, 16),
+            Err(FrameError::TooLarge { size: 17, max: 16 })
+        ));
+    }
+}
@@ -0,0 +1,233 @@
+use super::{TType, ThriftException, error::ProtocolExceptionKind, new_protocol_exception};
+
+/// Sums what decoding a message allocates for its strings, binaries and
+/// containers, so a crafted message can't make the decoder allocate more than
+/// `max` bytes in total.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AllocationTracker {
+    max: usize,
+    allocated: usize,
+}
+
+impl AllocationTracker {
+    #[inline]
+    pub(crate) fn new(max: usize) -> Self {
+        Self { max, allocated: 0 }
+    }
+}
+
+/// The fewest bytes an element of `ttype` takes once decoded, a `String`,
+/// `Vec` or map taking three words however few elements it holds.
+#[inline]
+pub(crate) fn element_size(ttype: TType) -> usize {
+    match ttype {
+        TType::I16 => 2,
+        TType::I32 => 4,
+        TType::I64 | TType::Double => 8,
+        TType::Uuid => 16,
+        TType::Binary | TType::List | TType::Set | TType::Map => 3 * size_of::<usize>(),
+        _ => 1,
+    }
+}
+
+/// Charges `len` bytes, failing with [`ProtocolExceptionKind::BudgetExceeded`]
+/// once more than the maximum is allocated.
+#[inline]
+pub(crate) fn charge(
+    tracker: &mut Option<AllocationTracker>,
+    len: usize,
+) -> Result<(), ThriftException> {
+    let Some(tracker) = tracker else {
+        return Ok(());
+    };
+    let allocated = tracker.allocated.saturating_add(len);
+    if allocated > tracker.max {
+        return Err(new_protocol_exception(
+            ProtocolExceptionKind::BudgetExceeded,
+            format!(
+                "allocating {len} more bytes after {} exceeds the allocation budget of {} bytes",
+                tracker.allocated, tracker.max
+            ),
+        ));
+    }
+    tracker.allocated = allocated;
+    Ok(())
+}
+
+/// Charges the elements of a container of `size` elements taking
+/// `element_size` bytes each.
+#[inline]
+pub(crate) fn charge_elements(
+    tracker: &mut Option<AllocationTracker>,
+    size: usize,
+    element_size: usize,
+) -> Result<(), ThriftException> {
+    charge(tracker, size.saturating_mul(element_size))
+}
+
+/// Restarts at nothing allocated, used when a new message begins on the same
+/// protocol.
+#[inline]
+pub(crate) fn reset(tracker: &mut Option<AllocationTracker>) {
+    if let Some(tracker) = tracker {
+        tracker.allocated = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use bytes::{Bytes, BytesMut};
+
+    use crate::thrift::{
+        ProtocolExceptionKind, TAsyncInputProtocol, TInputProtocol, TListIdentifier,
+        TOutputProtocol, TType, ThriftException,
+        binary::{TAsyncBinaryProtocol, TBinaryProtocol},
+        compact::{TCompactInputProtocol, TCompactOutputProtocol},
+    };
+
+    // a 10 byte string then a list of 10 i64, 90 bytes in all
+    fn write_values<P: TOutputProtocol>(p: &mut P) {
+        p.write_string("0123456789").unwrap();
+        p.write_list_begin(TListIdentifier::new(TType::I64, 10))
+            .unwrap();
+        for i in 0..10 {
+            p.write_i64(i).unwrap();
+        }
+        p.write_list_end().unwrap();
+    }
+
+    fn read_values<P: TInputProtocol>(p: &mut P) -> Result<usize, ThriftException> {
+        p.read_string()?;
+        p.skip(TType::List)
+    }
+
+    fn assert_budget_exceeded<T: std::fmt::Debug>(r: Result<T, ThriftException>) {
+        match r {
+            Err(ThriftException::Protocol(e)) => {
+                assert_eq!(e.kind(), ProtocolExceptionKind::BudgetExceeded)
+            }
+            r => panic!("expected an exceeded allocation budget, got {r:?}"),
+        }
+    }
+
+    #[test]
+    fn test_binary_allocation() {
+        let mut buf = BytesMut::new();
+        write_values(&mut TBinaryProtocol::new(&mut buf, false));
+        let encoded = buf.freeze();
+
+        let mut bytes = encoded.clone();
+        let mut protocol = TBinaryProtocol::new(&mut bytes, false);
+        protocol.set_max_allocation(89);
+        assert_budget_exceeded(read_values(&mut protocol));
+
+        // the next message starts with nothing allocated
+        let mut bytes = encoded.clone();
+        protocol.reset_with(&mut bytes);
+        protocol.set_max_allocation(90);
+        read_values(&mut protocol).unwrap();
+        let mut bytes = encoded.clone();
+        protocol.reset_with(&mut bytes);
+        read_values(&mut protocol).unwrap();
+    }
+
+    #[test]
+    fn test_compact_allocation() {
+        let mut buf = BytesMut::new();
+        write_values(&mut TCompactOutputProtocol::new(&mut buf, false));
+        let encoded = buf.freeze();
+
+        let mut bytes = encoded.clone();
+        let mut protocol = TCompactInputProtocol::new(&mut bytes);
+        protocol.set_max_allocation(89);
+        assert_budget_exceeded(read_values(&mut protocol));
+
+        let mut bytes = encoded;
+        let mut protocol = TCompactInputProtocol::new(&mut bytes);
+        protocol.set_max_allocation(90);
+        read_values(&mut protocol).unwrap();
+    }
+
+    #[test]
+    fn test_binary_le_allocation() {
+        use crate::thrift::binary_le;
+
+        let mut buf = BytesMut::new();
+        write_values(&mut binary_le::TBinaryProtocol::with_byte_order(
+            &mut buf, false,
+        ));
+        let encoded = buf.freeze();
+
+        let mut bytes = encoded.clone();
+        let mut protocol = binary_le::TBinaryProtocol::with_byte_order(&mut bytes, false);
+        protocol.set_max_allocation(89);
+        assert_budget_exceeded(read_values(&mut protocol));
+
+        let mut bytes = encoded;
+        let mut protocol = binary_le::TBinaryProtocol::with_byte_order(&mut bytes, false);
+        protocol.set_max_allocation(90);
+        read_values(&mut protocol).unwrap();
+    }
+
+    // the unsafe protocols only skip right after a field header
+    #[cfg(not(feature = "safe-only"))]
+    fn read_list_header<P: TInputProtocol>(p: &mut P) -> Result<usize, ThriftException> {
+        p.read_string()?;
+        Ok(p.read_list_begin()?.size)
+    }
+
+    #[cfg(not(feature = "safe-only"))]
+    #[test]
+    fn test_unsafe_allocation() {
+        use crate::thrift::{
+            binary_unsafe::TBinaryUnsafeInputProtocol, compact_unsafe::TCompactUnsafeInputProtocol,
+        };
+
+        let mut buf = BytesMut::new();
+        write_values(&mut TBinaryProtocol::new(&mut buf, false));
+        let encoded = buf.split().freeze();
+        let mut bytes = encoded.clone();
+        let mut protocol = unsafe { TBinaryUnsafeInputProtocol::new(&mut bytes) };
+        protocol.set_max_allocation(89);
+        assert_budget_exceeded(read_list_header(&mut protocol));
+        let mut bytes = encoded;
+        let mut protocol = unsafe { TBinaryUnsafeInputProtocol::new(&mut bytes) };
+        protocol.set_max_allocation(90);
+        assert_eq!(read_list_header(&mut protocol).unwrap(), 10);
+
+        write_values(&mut TCompactOutputProtocol::new(&mut buf, false));
+        let encoded = buf.freeze();
+        let mut bytes = encoded.clone();
+        let mut protocol = unsafe { TCompactUnsafeInputProtocol::new(&mut bytes) };
+        protocol.set_max_allocation(89);
+        assert_budget_exceeded(read_list_header(&mut protocol));
+        let mut bytes = encoded;
+        let mut protocol = unsafe { TCompactUnsafeInputProtocol::new(&mut bytes) };
+        protocol.set_max_allocation(90);
+        assert_eq!(read_list_header(&mut protocol).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_async_allocation() {
+        let mut buf = BytesMut::new();
+        write_values(&mut TBinaryProtocol::new(&mut buf, false));
+        let encoded: Bytes = buf.freeze();
+
+        let mut protocol = TAsyncBinaryProtocol::new(&encoded[..]);
+        protocol.set_max_allocation(89);
+        let read = async {
+            protocol.read_string().await?;
+            protocol.skip(TType::List).await
+        };
+        match pin!(read).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(r) => assert_budget_exceeded(r),
+            Poll::Pending => panic!("reading a slice is ready"),
+        }
+    }
+}
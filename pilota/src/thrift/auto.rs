@@ -0,0 +1,85 @@
+//! Decoding with the strategy suited to the size of the message.
+//!
+//! Sharing the binaries and strings of a message with its input saves
+//! copying them, but costs a reference count per field and keeps the whole
+//! input alive as long as any of them is. [`Message::decode_auto`] copies
+//! them out of messages smaller than the [`threshold`] and shares them with
+//! larger ones. The threshold is process-wide, see [`set_threshold`].
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bytes::Bytes;
+
+use super::{Message, ThriftException, ZERO_COPY_THRESHOLD, binary::TBinaryProtocol};
+
+static THRESHOLD: AtomicUsize = AtomicUsize::new(ZERO_COPY_THRESHOLD);
+
+/// The size from which [`Message::decode_auto`] shares the input, the same as
+/// the zero-copy threshold of the output protocols unless set.
+pub fn threshold() -> usize {
+    THRESHOLD.load(Ordering::Relaxed)
+}
+
+pub fn set_threshold(threshold: usize) {
+    THRESHOLD.store(threshold, Ordering::Relaxed);
+}
+
+#[inline]
+pub(super) fn decode<T: Message>(mut bytes: Bytes) -> Result<T, ThriftException> {
+    if bytes.len() < threshold() {
+        T::decode(&mut TBinaryProtocol::new(&bytes[..], false))
+    } else {
+        T::decode(&mut TBinaryProtocol::new(&mut bytes, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Bytes, BytesMut};
+
+    use crate::thrift::{
+        Message, TAsyncInputProtocol, TInputProtocol, TLengthProtocol, TOutputProtocol,
+        ThriftException, binary::TBinaryProtocol,
+    };
+
+    struct Blob(Bytes);
+
+    impl Message for Blob {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_bytes(self.0.clone())
+        }
+
+        fn decode<T: TInputProtocol>(protocol: &mut T) -> Result<Self, ThriftException> {
+            protocol.read_bytes().map(Blob)
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(
+            _protocol: &mut T,
+        ) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+            protocol.bytes_len(&self.0)
+        }
+    }
+
+    #[test]
+    fn test_decode_auto() {
+        let shares = |len: usize| {
+            let mut buf = BytesMut::new();
+            Blob(Bytes::from(vec![7; len]))
+                .encode(&mut TBinaryProtocol::new(&mut buf, false))
+                .unwrap();
+            let input = buf.freeze();
+            let blob = Blob::decode_auto(input.clone()).unwrap();
+            assert_eq!(blob.0, vec![7; len]);
+            input.as_ptr_range().contains(&blob.0.as_ptr())
+        };
+        let threshold = super::threshold();
+        assert!(!shares(16));
+        assert!(!shares(threshold - 5));
+        assert!(shares(threshold - 4));
+        assert!(shares(threshold * 2));
+    }
+}
@@ -1,27 +1,89 @@
-use std::{convert::TryInto, str};
+use std::{convert::TryInto, str, sync::Arc};
 
-use bytes::{Bytes, BytesMut};
+use bytes::{Buf, BufMut, Bytes};
 use faststr::FastStr;
 use linkedbytes::LinkedBytes;
 use tokio::io::{AsyncRead, AsyncReadExt};
 
 use super::{
-    ProtocolException, TAsyncInputProtocol, TFieldIdentifier, TInputProtocol, TLengthProtocol,
-    TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType, TOutputProtocol,
-    TSetIdentifier, TStructIdentifier, TType, ThriftException, ZERO_COPY_THRESHOLD,
+    MAXIMUM_SKIP_DEPTH, ProtocolException, TAsyncInputProtocol, TFieldIdentifier, TInputProtocol,
+    TLengthProtocol, TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType,
+    TOutputProtocol, TSetIdentifier, TStructIdentifier, TType, ThriftException,
+    ZERO_COPY_THRESHOLD,
+    allocation::{self, AllocationTracker, element_size},
+    budget::{Budget, BudgetTracker},
+    canonicalize_nan,
+    capture::CaptureReader,
+    depth::{self, DepthTracker},
     error::ProtocolExceptionKind,
+    mark::{InputMark, mark_not_implemented},
     new_protocol_exception,
-    rw_ext::{ReadExt, WriteExt},
+    recovery::{Recovery, TTypeRecovery},
+    rw_ext::{IOError, ReadExt, WriteExt, faststr_from_bytes, string_from_utf8},
+    unknown::{self, UnknownField, UnknownFieldHook},
 };
+use crate::assert_remaining;
 
-static VERSION_1: u32 = 0x80010000;
-static VERSION_MASK: u32 = 0xffff0000;
+const VERSION_1: u32 = 0x80010000;
+// the version of the little-endian variant
+const VERSION_LE: u32 = 0x88880000;
+pub(crate) const VERSION_MASK: u32 = 0xffff0000;
 
-pub struct TBinaryProtocol<T> {
+pub(crate) fn message_type_from_u8(type_u8: u8) -> Result<TMessageType, ThriftException> {
+    TMessageType::try_from(type_u8).map_err(|_| {
+        new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            format!("invalid message type {type_u8}"),
+        )
+    })
+}
+
+// the fewest bytes an element of a container of `ttype` takes
+pub(crate) fn min_element_len(ttype: TType) -> usize {
+    match ttype {
+        TType::I16 => 2,
+        TType::I32 | TType::Binary => 4,
+        TType::I64 | TType::Double => 8,
+        TType::List | TType::Set => 5,
+        TType::Map => 6,
+        TType::Uuid => 16,
+        _ => 1,
+    }
+}
+
+/// The binary protocol, in big-endian unless `LITTLE_ENDIAN` is set.
+///
+/// The little-endian variant, also exported as
+/// [`binary_le::TBinaryProtocol`](super::binary_le::TBinaryProtocol), is
+/// created with [`Self::with_byte_order`]:
+///
+/// ```
+/// use pilota::thrift::{TOutputProtocol, binary::TBinaryProtocol};
+///
+/// let mut buf = pilota::BytesMut::new();
+/// let mut protocol = TBinaryProtocol::<_, true>::with_byte_order(&mut buf, false);
+/// protocol.write_i32(1).unwrap();
+/// assert_eq!(&buf[..], &[1, 0, 0, 0]);
+/// ```
+pub struct TBinaryProtocol<T, const LITTLE_ENDIAN: bool = false> {
     pub(crate) trans: T,
 
     zero_copy: bool,
+    zero_copy_threshold: usize,
     zero_copy_len: usize,
+    budget: Option<BudgetTracker>,
+    pub(crate) max_message_size: Option<usize>,
+    pub(crate) max_container_size: Option<usize>,
+    pub(crate) max_string_size: Option<usize>,
+    pub(crate) depth: Option<DepthTracker>,
+    pub(crate) reject_duplicate_fields: bool,
+    pub(crate) unknown_field_hook: Option<UnknownFieldHook>,
+    pub(crate) allocation: Option<AllocationTracker>,
+    canonical_nan: bool,
+    deterministic: bool,
+    pub(crate) strict_read: bool,
+    strict_write: bool,
+    ttype_recovery: Option<Recovery>,
 }
 
 impl<T> TBinaryProtocol<T> {
@@ -29,10 +91,460 @@ impl<T> TBinaryProtocol<T> {
     /// [`LinkedBytes`] for output.
     #[inline]
     pub fn new(trans: T, zero_copy: bool) -> Self {
+        Self::with_byte_order(trans, zero_copy)
+    }
+}
+
+impl<T, const LITTLE_ENDIAN: bool> TBinaryProtocol<T, LITTLE_ENDIAN> {
+    pub(crate) const VERSION: u32 = if LITTLE_ENDIAN { VERSION_LE } else { VERSION_1 };
+
+    /// Same as [`TBinaryProtocol::new`], for either byte order.
+    #[inline]
+    pub fn with_byte_order(trans: T, zero_copy: bool) -> Self {
         Self {
             trans,
             zero_copy,
+            zero_copy_threshold: ZERO_COPY_THRESHOLD,
             zero_copy_len: 0,
+            budget: None,
+            max_message_size: None,
+            max_container_size: None,
+            max_string_size: None,
+            depth: None,
+            reject_duplicate_fields: false,
+            unknown_field_hook: None,
+            allocation: None,
+            canonical_nan: false,
+            deterministic: false,
+            strict_read: true,
+            strict_write: true,
+            ttype_recovery: None,
+        }
+    }
+
+    /// Rewrites every NaN written by `write_double` to the canonical NaN, see
+    /// [`canonicalize_nan`].
+    #[inline]
+    pub fn set_canonical_nan(&mut self, canonical_nan: bool) {
+        self.canonical_nan = canonical_nan;
+    }
+
+    /// Writes the entries of maps and sets sorted by the bytes of their keys,
+    /// so that equal values encode to the same bytes whatever the order of
+    /// their maps, as for signing or caching the output. The fields are
+    /// already written in the order of the IDL.
+    ///
+    /// The entries are sorted in place, so the output must be a `BytesMut`
+    /// or a `LinkedBytes`, into which binaries are then copied even when
+    /// `zero_copy` is on.
+    #[inline]
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.deterministic = deterministic;
+    }
+
+    /// Accepts the unversioned message header of the legacy clients, the name
+    /// length first, besides the versioned one when off. On by default.
+    #[inline]
+    pub fn set_strict_read(&mut self, strict_read: bool) {
+        self.strict_read = strict_read;
+    }
+
+    /// Fails with [`ProtocolExceptionKind::MessageTooLarge`] when a message
+    /// name, string, binary or container size read from the input is larger
+    /// than `max`, before anything is allocated for it.
+    #[inline]
+    pub fn set_max_message_size(&mut self, max: usize) {
+        self.max_message_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::SizeLimit`] when a list, set or map
+    /// has more elements than `max`. Sizes the rest of the input can't hold
+    /// fail with [`ProtocolExceptionKind::InvalidData`] whatever the limit.
+    #[inline]
+    pub fn set_max_container_size(&mut self, max: usize) {
+        self.max_container_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::InvalidData`] when a string or
+    /// binary, message names included, is longer than `max`.
+    #[inline]
+    pub fn set_max_string_size(&mut self, max: usize) {
+        self.max_string_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::DepthLimit`] when structs and
+    /// containers, skipped ones included, are nested deeper than `max`.
+    #[inline]
+    pub fn set_max_depth(&mut self, max: usize) {
+        self.depth = Some(DepthTracker::new(max));
+    }
+
+    /// Makes the generated decode fail with
+    /// [`ProtocolExceptionKind::InvalidData`] when a field of a struct
+    /// appears twice, instead of keeping its last value.
+    #[inline]
+    pub fn set_reject_duplicate_fields(&mut self, reject: bool) {
+        self.reject_duplicate_fields = reject;
+    }
+
+    /// Calls `hook` with the fields the generated decode skips, not knowing
+    /// their id or their type, to notice a peer sending a newer schema.
+    pub fn set_unknown_field_hook(&mut self, hook: impl Fn(UnknownField) + Send + Sync + 'static) {
+        self.unknown_field_hook = Some(Arc::new(hook));
+    }
+
+    /// Fails with [`ProtocolExceptionKind::BudgetExceeded`] once decoding a
+    /// message allocates more than `max` bytes in total. Strings and binaries
+    /// count their length, whether shared with the input or copied, and lists,
+    /// sets and maps the fewest bytes their elements take once decoded,
+    /// skipped ones included.
+    #[inline]
+    pub fn set_max_allocation(&mut self, max: usize) {
+        self.allocation = Some(AllocationTracker::new(max));
+    }
+
+    /// Writes the unversioned message header when off, for the legacy peers
+    /// rejecting the versioned one. On by default.
+    #[inline]
+    pub fn set_strict_write(&mut self, strict_write: bool) {
+        self.strict_write = strict_write;
+    }
+
+    /// Sets what `read_field_begin` does with an invalid field type, failing
+    /// by default.
+    #[inline]
+    pub fn set_ttype_recovery(&mut self, recovery: TTypeRecovery) {
+        self.set_ttype_recovery_with(move |_| recovery);
+    }
+
+    /// Same as [`Self::set_ttype_recovery`], calling `f` with each invalid
+    /// type byte to pick the recovery, such as after logging it.
+    pub fn set_ttype_recovery_with(
+        &mut self,
+        f: impl Fn(u8) -> TTypeRecovery + Send + Sync + 'static,
+    ) {
+        self.ttype_recovery = Some(Recovery::new(Arc::new(f)));
+    }
+
+    /// Sets the length from which binaries and strings are inserted into the
+    /// output instead of copied when `zero_copy` is on, the default being
+    /// tuned for the platform. A higher one suits loopback traffic, where
+    /// copies are cheap compared to the extra chunks.
+    #[inline]
+    pub fn set_zero_copy_threshold(&mut self, threshold: usize) {
+        self.zero_copy_threshold = threshold;
+    }
+
+    /// Swaps in the transport of the next message on the same connection and
+    /// resets the per-message state, keeping the settings and allocations of
+    /// the protocol. Returns the previous transport.
+    ///
+    /// A budget set with `set_budget` restarts at the next message begin.
+    #[inline]
+    pub fn reset_with(&mut self, trans: T) -> T {
+        self.zero_copy_len = 0;
+        depth::reset(&mut self.depth);
+        allocation::reset(&mut self.allocation);
+        if let Some(recovery) = &mut self.ttype_recovery {
+            recovery.reset();
+        }
+        std::mem::replace(&mut self.trans, trans)
+    }
+}
+
+/// A transport the binary protocol reads from.
+///
+/// Implemented for `&mut Bytes`, which is advanced past what is read, for an
+/// owned `Bytes`, which is consumed by the protocol, for a borrowed `&[u8]`,
+/// which is copied from, and for any [`Buf`] wrapped in a [`BufInput`].
+pub trait InputTrans {
+    type Buf: Buf;
+
+    fn buf(&mut self) -> &mut Self::Buf;
+
+    /// Takes the next `len` bytes, without copying them if the transport
+    /// allows it.
+    fn split_bytes(&mut self, len: usize) -> Result<Bytes, IOError>;
+
+    /// Copies the `len` bytes already read from `ptr`, a pointer taken from
+    /// `buf().chunk()` earlier, as when keeping the unknown fields just
+    /// skipped.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point into the transport at most `len` bytes before the
+    /// current position.
+    #[inline]
+    unsafe fn read_since(&mut self, ptr: *const u8, len: usize) -> Result<Bytes, ThriftException> {
+        Ok(Bytes::copy_from_slice(unsafe {
+            std::slice::from_raw_parts(ptr, len)
+        }))
+    }
+
+    /// A cheap handle on the unread input, when the transport has one, from
+    /// which what is read next can be split without copying.
+    #[inline]
+    fn share(&self) -> Option<Bytes> {
+        None
+    }
+
+    /// Moves back to `input`, a handle from `share` taken earlier, returning
+    /// whether the transport can.
+    #[inline]
+    fn rewind(&mut self, input: Bytes) -> bool {
+        let _ = input;
+        false
+    }
+}
+
+impl InputTrans for Bytes {
+    type Buf = Bytes;
+
+    #[inline]
+    fn buf(&mut self) -> &mut Bytes {
+        self
+    }
+
+    #[inline]
+    fn split_bytes(&mut self, len: usize) -> Result<Bytes, IOError> {
+        assert_remaining!(len <= self.len(), "`len` greater than remaining");
+        Ok(self.split_to(len))
+    }
+
+    #[inline]
+    fn share(&self) -> Option<Bytes> {
+        Some(self.clone())
+    }
+
+    #[inline]
+    fn rewind(&mut self, input: Bytes) -> bool {
+        *self = input;
+        true
+    }
+}
+
+impl InputTrans for &mut Bytes {
+    type Buf = Bytes;
+
+    #[inline]
+    fn buf(&mut self) -> &mut Bytes {
+        self
+    }
+
+    #[inline]
+    fn split_bytes(&mut self, len: usize) -> Result<Bytes, IOError> {
+        (**self).split_bytes(len)
+    }
+
+    #[inline]
+    fn share(&self) -> Option<Bytes> {
+        (**self).share()
+    }
+
+    #[inline]
+    fn rewind(&mut self, input: Bytes) -> bool {
+        (**self).rewind(input)
+    }
+}
+
+impl<'a> InputTrans for &'a [u8] {
+    type Buf = &'a [u8];
+
+    #[inline]
+    fn buf(&mut self) -> &mut &'a [u8] {
+        self
+    }
+
+    #[inline]
+    fn split_bytes(&mut self, len: usize) -> Result<Bytes, IOError> {
+        self.read_to_bytes(len)
+    }
+}
+
+/// Reads from a [`Buf`] made of several chunks, such as a
+/// `Chain<Bytes, Bytes>`, without gathering it into one `Bytes` first.
+///
+/// Values straddling two chunks are copied, the others are read in place and
+/// binaries are split off the chunks with [`Buf::copy_to_bytes`]. Unknown
+/// fields are only kept when they lie in a single chunk, otherwise decoding
+/// fails with [`ProtocolExceptionKind::InvalidData`].
+///
+/// ```
+/// use pilota::{
+///     Buf, Bytes,
+///     thrift::{
+///         TInputProtocol,
+///         binary::{BufInput, TBinaryProtocol},
+///     },
+/// };
+///
+/// let chunks = Bytes::from_static(&[0, 0]).chain(Bytes::from_static(&[0, 7]));
+/// let mut protocol = TBinaryProtocol::new(BufInput::new(chunks), false);
+/// assert_eq!(protocol.read_i32().unwrap(), 7);
+/// ```
+pub struct BufInput<B> {
+    buf: B,
+    // the start of the current chunk, everything from it up to the current
+    // position is still readable
+    chunk_start: usize,
+}
+
+impl<B: Buf> BufInput<B> {
+    pub fn new(buf: B) -> Self {
+        let chunk_start = buf.chunk().as_ptr() as usize;
+        Self { buf, chunk_start }
+    }
+
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    #[inline]
+    fn track(&mut self, chunk_len: usize, cnt: usize) {
+        if cnt >= chunk_len {
+            self.chunk_start = self.buf.chunk().as_ptr() as usize;
+        }
+    }
+}
+
+impl<B: Buf> Buf for BufInput<B> {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.buf.remaining()
+    }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] {
+        self.buf.chunk()
+    }
+
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        let chunk_len = self.buf.chunk().len();
+        self.buf.advance(cnt);
+        self.track(chunk_len, cnt);
+    }
+
+    #[inline]
+    fn chunks_vectored<'a>(&'a self, dst: &mut [std::io::IoSlice<'a>]) -> usize {
+        self.buf.chunks_vectored(dst)
+    }
+
+    #[inline]
+    fn copy_to_bytes(&mut self, len: usize) -> Bytes {
+        let chunk_len = self.buf.chunk().len();
+        let bytes = self.buf.copy_to_bytes(len);
+        self.track(chunk_len, len);
+        bytes
+    }
+}
+
+impl<B: Buf> InputTrans for BufInput<B> {
+    type Buf = Self;
+
+    #[inline]
+    fn buf(&mut self) -> &mut Self {
+        self
+    }
+
+    #[inline]
+    fn split_bytes(&mut self, len: usize) -> Result<Bytes, IOError> {
+        assert_remaining!(len <= self.remaining(), "`len` greater than remaining");
+        Ok(self.copy_to_bytes(len))
+    }
+
+    #[inline]
+    unsafe fn read_since(&mut self, ptr: *const u8, len: usize) -> Result<Bytes, ThriftException> {
+        let start = ptr as usize;
+        if start < self.chunk_start || start + len > self.buf.chunk().as_ptr() as usize {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                "unknown fields straddle a chunk boundary",
+            ));
+        }
+        Ok(Bytes::copy_from_slice(unsafe {
+            std::slice::from_raw_parts(ptr, len)
+        }))
+    }
+}
+
+impl<T: InputTrans, const LITTLE_ENDIAN: bool> TBinaryProtocol<T, LITTLE_ENDIAN> {
+    /// Limits how many bytes and how much time decoding may take, counted
+    /// from the current position and restarted at each message begin.
+    pub fn set_budget(&mut self, budget: Budget) {
+        let remaining = self.trans.buf().remaining();
+        self.budget = Some(BudgetTracker::new(budget, remaining));
+    }
+
+    #[inline]
+    fn check_budget(&mut self) -> Result<(), ThriftException> {
+        match &self.budget {
+            Some(budget) => budget.check(self.trans.buf().remaining()),
+            None => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn read_container_size(&mut self, min_len: usize) -> Result<usize, ThriftException> {
+        let size = self.read_len()?;
+        let remaining = self.trans.buf().remaining();
+        super::check_container_size(size, min_len, Some(remaining), self.max_container_size)?;
+        Ok(size)
+    }
+}
+
+impl<T, const LITTLE_ENDIAN: bool> TBinaryProtocol<T, LITTLE_ENDIAN>
+where
+    Self: TInputProtocol,
+{
+    // reads a length or a container size, checked against the maximum
+    // message size
+    #[inline]
+    pub(crate) fn read_len(&mut self) -> Result<usize, ThriftException> {
+        let len = self.read_i32()?;
+        if len < 0 {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::NegativeSize,
+                format!("negative length {len}"),
+            ));
+        }
+        super::check_message_size(len as usize, self.max_message_size)?;
+        Ok(len as usize)
+    }
+
+    // reads the length of a string or a binary
+    #[inline]
+    pub(crate) fn read_str_len(&mut self) -> Result<usize, ThriftException> {
+        let len = self.read_len()?;
+        super::check_string_size(len, self.max_string_size)?;
+        allocation::charge(&mut self.allocation, len)?;
+        Ok(len)
+    }
+}
+
+impl<'a, const LITTLE_ENDIAN: bool> TBinaryProtocol<&'a [u8], LITTLE_ENDIAN> {
+    /// Reads a binary borrowed from the input instead of copied.
+    #[inline]
+    pub fn read_borrowed_bytes(&mut self) -> Result<&'a [u8], ThriftException> {
+        let len = self.read_str_len()?;
+        assert_remaining!(len <= self.trans.len(), "`len` greater than remaining");
+        let (bytes, rest) = self.trans.split_at(len);
+        self.trans = rest;
+        Ok(bytes)
+    }
+
+    /// Reads a string borrowed from the input instead of copied.
+    #[inline]
+    pub fn read_borrowed_str(&mut self) -> Result<&'a str, ThriftException> {
+        let bytes = self.read_borrowed_bytes()?;
+        #[cfg(feature = "safe-only")]
+        {
+            Ok(str::from_utf8(bytes).map_err(|_| IOError::InvalidUtf8)?)
+        }
+        #[cfg(not(feature = "safe-only"))]
+        {
+            super::rw_ext::check_utf8(bytes)?;
+            Ok(unsafe { str::from_utf8_unchecked(bytes) })
         }
     }
 }
@@ -49,10 +561,56 @@ fn field_type_from_u8(ttype: u8) -> Result<TType, ProtocolException> {
     Ok(ttype)
 }
 
-impl<T> TLengthProtocol for TBinaryProtocol<T> {
+/// Decodes the field header at the start of `header`, the next bytes of the
+/// input, the same as `read_field_begin` reads it.
+pub(crate) fn peek_field_header<const LITTLE_ENDIAN: bool>(
+    header: &[u8],
+    recovery: Option<&Recovery>,
+) -> Result<TFieldIdentifier, ThriftException> {
+    let Some(&field_type_byte) = header.first() else {
+        return Err(IOError::NoRemaining(String::new()).into());
+    };
+    let field_type = match field_type_byte.try_into() {
+        Ok(field_type) => field_type,
+        Err(_) if recovery.is_some_and(|recovery| recovery.would_recover(field_type_byte)) => {
+            TType::Stop
+        }
+        Err(_) => {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                format!("invalid ttype {field_type_byte}"),
+            ));
+        }
+    };
+    let id = match field_type {
+        TType::Stop => 0,
+        _ => {
+            let &[_, a, b, ..] = header else {
+                return Err(IOError::NoRemaining(String::new()).into());
+            };
+            let id = [a, b];
+            if LITTLE_ENDIAN {
+                i16::from_le_bytes(id)
+            } else {
+                i16::from_be_bytes(id)
+            }
+        }
+    };
+    Ok(TFieldIdentifier::new::<Option<&'static str>, i16>(
+        None, field_type, id,
+    ))
+}
+
+impl<T, const LITTLE_ENDIAN: bool> TLengthProtocol for TBinaryProtocol<T, LITTLE_ENDIAN> {
+    const FIXED_WIDTH: bool = true;
+
     #[inline]
     fn message_begin_len(&mut self, identifier: &TMessageIdentifier) -> usize {
-        self.i32_len(0) + self.faststr_len(&identifier.name) + self.i32_len(0)
+        if self.strict_write {
+            self.i32_len(0) + self.faststr_len(&identifier.name) + self.i32_len(0)
+        } else {
+            self.faststr_len(&identifier.name) + self.byte_len(0) + self.i32_len(0)
+        }
     }
 
     #[inline]
@@ -85,6 +643,11 @@ impl<T> TLengthProtocol for TBinaryProtocol<T> {
         self.byte_len(0)
     }
 
+    #[inline]
+    fn empty_struct_len(&mut self, _identifier: &TStructIdentifier) -> usize {
+        1
+    }
+
     #[inline]
     fn bool_len(&mut self, _b: bool) -> usize {
         self.i8_len(0)
@@ -92,7 +655,7 @@ impl<T> TLengthProtocol for TBinaryProtocol<T> {
 
     #[inline]
     fn bytes_len(&mut self, b: &[u8]) -> usize {
-        if self.zero_copy && b.len() >= ZERO_COPY_THRESHOLD {
+        if self.zero_copy && !self.deterministic && b.len() >= self.zero_copy_threshold {
             self.zero_copy_len += b.len();
         }
         self.i32_len(0) + b.len()
@@ -139,7 +702,7 @@ impl<T> TLengthProtocol for TBinaryProtocol<T> {
 
     #[inline]
     fn faststr_len(&mut self, s: &FastStr) -> usize {
-        if self.zero_copy && s.len() >= ZERO_COPY_THRESHOLD {
+        if self.zero_copy && !self.deterministic && s.len() >= self.zero_copy_threshold {
             self.zero_copy_len += s.len();
         }
         self.i32_len(0) + s.len()
@@ -191,8 +754,10 @@ impl<T> TLengthProtocol for TBinaryProtocol<T> {
     }
 }
 
-impl TOutputProtocol for TBinaryProtocol<&mut BytesMut> {
-    type BufMut = BytesMut;
+impl<B: WriteExt + BufMut, const LITTLE_ENDIAN: bool> TOutputProtocol
+    for TBinaryProtocol<&mut B, LITTLE_ENDIAN>
+{
+    type BufMut = B;
 
     #[inline]
     fn write_message_begin(
@@ -200,9 +765,14 @@ impl TOutputProtocol for TBinaryProtocol<&mut BytesMut> {
         identifier: &TMessageIdentifier,
     ) -> Result<(), ThriftException> {
         let msg_type_u8: u8 = identifier.message_type.into();
-        let version = (VERSION_1 | msg_type_u8 as u32) as i32;
-        self.write_i32(version)?;
-        self.write_faststr(identifier.name.clone())?;
+        if self.strict_write {
+            let version = (Self::VERSION | msg_type_u8 as u32) as i32;
+            self.write_i32(version)?;
+            self.write_faststr(identifier.name.clone())?;
+        } else {
+            self.write_faststr(identifier.name.clone())?;
+            self.write_byte(msg_type_u8)?;
+        }
         self.write_i32(identifier.sequence_number)?;
         Ok(())
     }
@@ -226,7 +796,11 @@ impl TOutputProtocol for TBinaryProtocol<&mut BytesMut> {
     fn write_field_begin(&mut self, field_type: TType, id: i16) -> Result<(), ThriftException> {
         let mut data: [u8; 3] = [0; 3];
         data[0] = field_type as u8;
-        let id = id.to_be_bytes();
+        let id = if LITTLE_ENDIAN {
+            id.to_le_bytes()
+        } else {
+            id.to_be_bytes()
+        };
         data[1] = id[0];
         data[2] = id[1];
         self.trans.write_slice(&data);
@@ -243,6 +817,14 @@ impl TOutputProtocol for TBinaryProtocol<&mut BytesMut> {
         self.write_byte(TType::Stop as u8)
     }
 
+    #[inline]
+    fn write_empty_struct(
+        &mut self,
+        _identifier: &TStructIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.write_byte(TType::Stop as u8)
+    }
+
     #[inline]
     fn write_bool(&mut self, b: bool) -> Result<(), ThriftException> {
         if b {
@@ -284,25 +866,46 @@ impl TOutputProtocol for TBinaryProtocol<&mut BytesMut> {
 
     #[inline]
     fn write_i16(&mut self, i: i16) -> Result<(), ThriftException> {
-        self.trans.write_i16(i);
+        if LITTLE_ENDIAN {
+            self.trans.write_i16_le(i);
+        } else {
+            self.trans.write_i16(i);
+        }
         Ok(())
     }
 
     #[inline]
     fn write_i32(&mut self, i: i32) -> Result<(), ThriftException> {
-        self.trans.write_i32(i);
+        if LITTLE_ENDIAN {
+            self.trans.write_i32_le(i);
+        } else {
+            self.trans.write_i32(i);
+        }
         Ok(())
     }
 
     #[inline]
     fn write_i64(&mut self, i: i64) -> Result<(), ThriftException> {
-        self.trans.write_i64(i);
+        if LITTLE_ENDIAN {
+            self.trans.write_i64_le(i);
+        } else {
+            self.trans.write_i64(i);
+        }
         Ok(())
     }
 
     #[inline]
     fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
-        self.trans.write_f64(d);
+        let d = if self.canonical_nan {
+            canonicalize_nan(d)
+        } else {
+            d
+        };
+        if LITTLE_ENDIAN {
+            self.trans.write_f64_le(d);
+        } else {
+            self.trans.write_f64(d);
+        }
         Ok(())
     }
 
@@ -358,6 +961,7 @@ impl TOutputProtocol for TBinaryProtocol<&mut BytesMut> {
 
     #[inline]
     fn flush(&mut self) -> Result<(), ThriftException> {
+        self.trans.flush_staged()?;
         Ok(())
     }
 
@@ -368,13 +972,30 @@ impl TOutputProtocol for TBinaryProtocol<&mut BytesMut> {
         Ok(())
     }
 
+    #[inline]
+    fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    #[inline]
+    fn written_mut(&mut self) -> Option<&mut [u8]> {
+        self.trans.written_mut()
+    }
+
+    #[inline]
+    fn hold_written(&mut self, hold: bool) {
+        self.trans.hold_written(hold)
+    }
+
     #[inline]
     fn buf_mut(&mut self) -> &mut Self::BufMut {
         self.trans
     }
 }
 
-impl TOutputProtocol for TBinaryProtocol<&mut LinkedBytes> {
+impl<const LITTLE_ENDIAN: bool> TOutputProtocol
+    for TBinaryProtocol<&mut LinkedBytes, LITTLE_ENDIAN>
+{
     type BufMut = LinkedBytes;
 
     #[inline]
@@ -383,9 +1004,14 @@ impl TOutputProtocol for TBinaryProtocol<&mut LinkedBytes> {
         identifier: &TMessageIdentifier,
     ) -> Result<(), ThriftException> {
         let msg_type_u8: u8 = identifier.message_type.into();
-        let version = (VERSION_1 | msg_type_u8 as u32) as i32;
-        self.write_i32(version)?;
-        self.write_faststr(identifier.name.clone())?;
+        if self.strict_write {
+            let version = (Self::VERSION | msg_type_u8 as u32) as i32;
+            self.write_i32(version)?;
+            self.write_faststr(identifier.name.clone())?;
+        } else {
+            self.write_faststr(identifier.name.clone())?;
+            self.write_byte(msg_type_u8)?;
+        }
         self.write_i32(identifier.sequence_number)?;
         Ok(())
     }
@@ -409,7 +1035,11 @@ impl TOutputProtocol for TBinaryProtocol<&mut LinkedBytes> {
     fn write_field_begin(&mut self, field_type: TType, id: i16) -> Result<(), ThriftException> {
         let mut data: [u8; 3] = [0; 3];
         data[0] = field_type as u8;
-        let id = id.to_be_bytes();
+        let id = if LITTLE_ENDIAN {
+            id.to_le_bytes()
+        } else {
+            id.to_be_bytes()
+        };
         data[1] = id[0];
         data[2] = id[1];
         self.trans.bytes_mut().write_slice(&data);
@@ -426,6 +1056,14 @@ impl TOutputProtocol for TBinaryProtocol<&mut LinkedBytes> {
         self.write_byte(TType::Stop as u8)
     }
 
+    #[inline]
+    fn write_empty_struct(
+        &mut self,
+        _identifier: &TStructIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.write_byte(TType::Stop as u8)
+    }
+
     #[inline]
     fn write_bool(&mut self, b: bool) -> Result<(), ThriftException> {
         if b {
@@ -443,7 +1081,7 @@ impl TOutputProtocol for TBinaryProtocol<&mut LinkedBytes> {
 
     #[inline]
     fn write_bytes_without_len(&mut self, b: Bytes) -> Result<(), ThriftException> {
-        if self.zero_copy && b.len() >= ZERO_COPY_THRESHOLD {
+        if self.zero_copy && !self.deterministic && b.len() >= self.zero_copy_threshold {
             self.trans.insert(b);
             return Ok(());
         }
@@ -471,25 +1109,46 @@ impl TOutputProtocol for TBinaryProtocol<&mut LinkedBytes> {
 
     #[inline]
     fn write_i16(&mut self, i: i16) -> Result<(), ThriftException> {
-        self.trans.bytes_mut().write_i16(i);
+        if LITTLE_ENDIAN {
+            self.trans.bytes_mut().write_i16_le(i);
+        } else {
+            self.trans.bytes_mut().write_i16(i);
+        }
         Ok(())
     }
 
     #[inline]
     fn write_i32(&mut self, i: i32) -> Result<(), ThriftException> {
-        self.trans.bytes_mut().write_i32(i);
+        if LITTLE_ENDIAN {
+            self.trans.bytes_mut().write_i32_le(i);
+        } else {
+            self.trans.bytes_mut().write_i32(i);
+        }
         Ok(())
     }
 
     #[inline]
     fn write_i64(&mut self, i: i64) -> Result<(), ThriftException> {
-        self.trans.bytes_mut().write_i64(i);
+        if LITTLE_ENDIAN {
+            self.trans.bytes_mut().write_i64_le(i);
+        } else {
+            self.trans.bytes_mut().write_i64(i);
+        }
         Ok(())
     }
 
     #[inline]
     fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
-        self.trans.bytes_mut().write_f64(d);
+        let d = if self.canonical_nan {
+            canonicalize_nan(d)
+        } else {
+            d
+        };
+        if LITTLE_ENDIAN {
+            self.trans.bytes_mut().write_f64_le(d);
+        } else {
+            self.trans.bytes_mut().write_f64(d);
+        }
         Ok(())
     }
 
@@ -502,7 +1161,7 @@ impl TOutputProtocol for TBinaryProtocol<&mut LinkedBytes> {
     #[inline]
     fn write_faststr(&mut self, s: FastStr) -> Result<(), ThriftException> {
         self.write_i32(s.len() as i32)?;
-        if self.zero_copy && s.len() >= ZERO_COPY_THRESHOLD {
+        if self.zero_copy && !self.deterministic && s.len() >= self.zero_copy_threshold {
             self.trans.insert_faststr(s);
             return Ok(());
         }
@@ -557,35 +1216,54 @@ impl TOutputProtocol for TBinaryProtocol<&mut LinkedBytes> {
         Ok(())
     }
 
+    #[inline]
+    fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    #[inline]
+    fn written_mut(&mut self) -> Option<&mut [u8]> {
+        Some(self.trans.bytes_mut())
+    }
+
     #[inline]
     fn buf_mut(&mut self) -> &mut Self::BufMut {
         self.trans
     }
 }
 
-impl TInputProtocol for TBinaryProtocol<&mut Bytes> {
-    type Buf = Bytes;
+impl<T: InputTrans, const LITTLE_ENDIAN: bool> TInputProtocol
+    for TBinaryProtocol<T, LITTLE_ENDIAN>
+{
+    type Buf = T::Buf;
 
     fn read_message_begin(&mut self) -> Result<TMessageIdentifier, ThriftException> {
-        let size = self.trans.read_i32()?;
+        depth::reset(&mut self.depth);
+        allocation::reset(&mut self.allocation);
+        if let Some(budget) = &mut self.budget {
+            budget.rearm(self.trans.buf().remaining());
+        }
+
+        let size = self.read_i32()?;
 
+        if size >= 0 && !self.strict_read {
+            super::check_message_size(size as usize, self.max_message_size)?;
+            super::check_string_size(size as usize, self.max_string_size)?;
+            let name = faststr_from_bytes(self.trans.split_bytes(size as usize)?)?;
+            let message_type = message_type_from_u8(self.read_byte()?)?;
+            let sequence_number = self.read_i32()?;
+            return Ok(TMessageIdentifier::new(name, message_type, sequence_number));
+        }
         if size > 0 {
             return Err(new_protocol_exception(
                 ProtocolExceptionKind::BadVersion,
                 "Missing version in ReadMessageBegin".to_string(),
             ));
         }
-        let type_u8 = (size & 0xf) as u8;
-
-        let message_type = TMessageType::try_from(type_u8).map_err(|_| {
-            new_protocol_exception(
-                ProtocolExceptionKind::InvalidData,
-                format!("invalid message type {type_u8}"),
-            )
-        })?;
+        let message_type = message_type_from_u8((size & 0xf) as u8)?;
 
         let version = size & (VERSION_MASK as i32);
-        if version != (VERSION_1 as i32) {
+        if version != (Self::VERSION as i32) {
             return Err(new_protocol_exception(
                 ProtocolExceptionKind::BadVersion,
                 "Bad version in ReadMessageBegin",
@@ -605,23 +1283,45 @@ impl TInputProtocol for TBinaryProtocol<&mut Bytes> {
 
     #[inline]
     fn read_struct_begin(&mut self) -> Result<Option<TStructIdentifier>, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
         Ok(None)
     }
 
     #[inline]
     fn read_struct_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     fn read_field_begin(&mut self) -> Result<TFieldIdentifier, ThriftException> {
+        if let Some(recovery) = &self.ttype_recovery {
+            if recovery.dropped(self.trans.buf()) {
+                return Ok(TFieldIdentifier::new::<Option<&'static str>, i16>(
+                    None,
+                    TType::Stop,
+                    0,
+                ));
+            }
+        }
         let field_type_byte = self.read_byte()?;
-        let field_type = field_type_byte.try_into().map_err(|_| {
-            new_protocol_exception(
-                ProtocolExceptionKind::InvalidData,
-                format!("invalid ttype {field_type_byte}"),
-            )
-        })?;
+        let field_type = match field_type_byte.try_into() {
+            Ok(field_type) => field_type,
+            Err(_)
+                if self.ttype_recovery.as_mut().is_some_and(|recovery| {
+                    recovery.recover(self.trans.buf(), field_type_byte)
+                }) =>
+            {
+                TType::Stop
+            }
+            Err(_) => {
+                return Err(new_protocol_exception(
+                    ProtocolExceptionKind::InvalidData,
+                    format!("invalid ttype {field_type_byte}"),
+                ));
+            }
+        };
         let id = match field_type {
             TType::Stop => Ok(0),
             _ => self.read_i16(),
@@ -636,6 +1336,47 @@ impl TInputProtocol for TBinaryProtocol<&mut Bytes> {
         Ok(())
     }
 
+    #[inline]
+    fn rejects_duplicate_fields(&self) -> bool {
+        self.reject_duplicate_fields
+    }
+
+    #[inline]
+    fn on_unknown_field(
+        &self,
+        struct_name: &'static str,
+        field: &TFieldIdentifier,
+        type_mismatch: bool,
+    ) {
+        unknown::report(&self.unknown_field_hook, struct_name, field, type_mismatch);
+    }
+
+    fn peek_field_begin(&mut self) -> Result<TFieldIdentifier, ThriftException> {
+        if let Some(recovery) = &self.ttype_recovery {
+            if recovery.dropped(self.trans.buf()) {
+                return Ok(TFieldIdentifier::new::<Option<&'static str>, i16>(
+                    None,
+                    TType::Stop,
+                    0,
+                ));
+            }
+        }
+        let mut header = [0; 3];
+        let len = super::peek_buf(self.trans.buf(), &mut header);
+        peek_field_header::<LITTLE_ENDIAN>(&header[..len], self.ttype_recovery.as_ref())
+    }
+
+    #[inline]
+    fn read_empty_struct(&mut self) -> Result<(), ThriftException> {
+        // an empty struct without unknown fields is a single stop byte
+        if self.trans.buf().chunk().first() == Some(&(TType::Stop as u8)) {
+            self.check_budget()?;
+            self.trans.buf().advance(1);
+            return Ok(());
+        }
+        super::read_empty_struct(self)
+    }
+
     #[inline]
     fn read_bool(&mut self) -> Result<bool, ThriftException> {
         let b = self.read_i8()?;
@@ -647,123 +1388,200 @@ impl TInputProtocol for TBinaryProtocol<&mut Bytes> {
 
     #[inline]
     fn read_bytes(&mut self) -> Result<Bytes, ThriftException> {
-        let len = self.trans.read_i32()?;
-        // split and freeze it
-        Ok(self.trans.split_to(len as usize))
+        let len = self.read_str_len()?;
+        Ok(self.trans.split_bytes(len)?)
     }
 
     #[inline]
     fn get_bytes(&mut self, ptr: Option<*const u8>, len: usize) -> Result<Bytes, ThriftException> {
-        if let Some(ptr) = ptr {
-            Ok(Bytes::copy_from_slice(unsafe {
-                std::slice::from_raw_parts(ptr, len)
-            }))
-        } else {
-            Ok(self.trans.split_to(len))
+        match ptr {
+            Some(ptr) => unsafe { self.trans.read_since(ptr, len) },
+            None => Ok(self.trans.split_bytes(len)?),
         }
     }
 
     #[inline]
     fn read_uuid(&mut self) -> Result<[u8; 16], ThriftException> {
         let mut u = [0; 16];
-        self.trans.read_to_slice(&mut u)?;
+        self.trans.buf().read_to_slice(&mut u)?;
         Ok(u)
     }
 
     #[inline]
     fn read_i8(&mut self) -> Result<i8, ThriftException> {
-        Ok(self.trans.read_i8()?)
+        Ok(self.trans.buf().read_i8()?)
     }
 
     #[inline]
     fn read_i16(&mut self) -> Result<i16, ThriftException> {
-        Ok(self.trans.read_i16()?)
+        Ok(if LITTLE_ENDIAN {
+            self.trans.buf().read_i16_le()?
+        } else {
+            self.trans.buf().read_i16()?
+        })
     }
 
     #[inline]
     fn read_i32(&mut self) -> Result<i32, ThriftException> {
-        Ok(self.trans.read_i32()?)
+        Ok(if LITTLE_ENDIAN {
+            self.trans.buf().read_i32_le()?
+        } else {
+            self.trans.buf().read_i32()?
+        })
     }
 
     #[inline]
     fn read_i64(&mut self) -> Result<i64, ThriftException> {
-        Ok(self.trans.read_i64()?)
+        Ok(if LITTLE_ENDIAN {
+            self.trans.buf().read_i64_le()?
+        } else {
+            self.trans.buf().read_i64()?
+        })
     }
 
     #[inline]
     fn read_double(&mut self) -> Result<f64, ThriftException> {
-        Ok(self.trans.read_f64()?)
+        Ok(if LITTLE_ENDIAN {
+            self.trans.buf().read_f64_le()?
+        } else {
+            self.trans.buf().read_f64()?
+        })
     }
 
     #[inline]
     fn read_string(&mut self) -> Result<String, ThriftException> {
-        let len = self.trans.read_i32()?;
-        Ok(self.trans.read_to_string(len as usize)?)
+        let len = self.read_str_len()?;
+        Ok(self.trans.buf().read_to_string(len)?)
     }
 
     #[inline]
     fn read_faststr(&mut self) -> Result<FastStr, ThriftException> {
-        let len = self.trans.read_i32()? as usize;
-        let bytes = self.trans.split_to(len);
-        unsafe { Ok(FastStr::from_bytes_unchecked(bytes)) }
+        let len = self.read_str_len()?;
+        let bytes = self.trans.split_bytes(len)?;
+        Ok(faststr_from_bytes(bytes)?)
     }
 
     #[inline]
     fn read_list_begin(&mut self) -> Result<TListIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
         let element_type: TType = self.read_byte().and_then(|n| Ok(field_type_from_u8(n)?))?;
-        let size = self.read_i32()?;
-        Ok(TListIdentifier::new(element_type, size as usize))
+        let size = self.read_container_size(min_element_len(element_type))?;
+        allocation::charge_elements(&mut self.allocation, size, element_size(element_type))?;
+        Ok(TListIdentifier::new(element_type, size))
     }
 
     #[inline]
     fn read_list_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     fn read_set_begin(&mut self) -> Result<TSetIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
         let element_type: TType = self.read_byte().and_then(|n| Ok(field_type_from_u8(n)?))?;
-        let size = self.read_i32()?;
-        Ok(TSetIdentifier::new(element_type, size as usize))
+        let size = self.read_container_size(min_element_len(element_type))?;
+        allocation::charge_elements(&mut self.allocation, size, element_size(element_type))?;
+        Ok(TSetIdentifier::new(element_type, size))
     }
 
     #[inline]
     fn read_set_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     fn read_map_begin(&mut self) -> Result<TMapIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
         let key_type: TType = self.read_byte().and_then(|n| Ok(field_type_from_u8(n)?))?;
         let value_type: TType = self.read_byte().and_then(|n| Ok(field_type_from_u8(n)?))?;
-        let size = self.read_i32()?;
-        Ok(TMapIdentifier::new(key_type, value_type, size as usize))
+        let size =
+            self.read_container_size(min_element_len(key_type) + min_element_len(value_type))?;
+        let entry_size = element_size(key_type) + element_size(value_type);
+        allocation::charge_elements(&mut self.allocation, size, entry_size)?;
+        Ok(TMapIdentifier::new(key_type, value_type, size))
     }
 
     #[inline]
     fn read_map_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     fn read_byte(&mut self) -> Result<u8, ThriftException> {
-        Ok(self.trans.read_u8()?)
+        Ok(self.trans.buf().read_u8()?)
     }
 
     #[inline]
     fn read_bytes_vec(&mut self) -> Result<Vec<u8>, ThriftException> {
-        let len = self.trans.read_i32()? as usize;
-        Ok(self.trans.split_to(len).into())
+        let len = self.read_str_len()?;
+        Ok(self.trans.split_bytes(len)?.into())
+    }
+
+    fn skip_capture(&mut self, field_type: TType) -> Result<Bytes, ThriftException> {
+        let shared = self.trans.share();
+        let begin = self.trans.buf().chunk().as_ptr();
+        let remaining = self.trans.buf().remaining();
+        self.skip_till_depth(field_type, MAXIMUM_SKIP_DEPTH)?;
+        let len = remaining - self.trans.buf().remaining();
+        match shared {
+            Some(mut shared) => {
+                shared.truncate(len);
+                Ok(shared)
+            }
+            None => unsafe { self.trans.read_since(begin, len) },
+        }
+    }
+
+    fn mark(&mut self) -> Result<InputMark, ThriftException> {
+        Ok(InputMark {
+            input: self.trans.share().ok_or_else(mark_not_implemented)?,
+            budget: self.budget,
+            depth: self.depth,
+            allocation: self.allocation,
+            recovery_dropped: self
+                .ttype_recovery
+                .as_ref()
+                .is_some_and(Recovery::is_dropped),
+            ..Default::default()
+        })
+    }
+
+    fn reset_to_mark(&mut self, mark: &InputMark) -> Result<(), ThriftException> {
+        if !self.trans.rewind(mark.input.clone()) {
+            return Err(mark_not_implemented());
+        }
+        self.budget = mark.budget;
+        self.depth = mark.depth;
+        self.allocation = mark.allocation;
+        if let Some(recovery) = &mut self.ttype_recovery {
+            recovery.set_dropped(mark.recovery_dropped);
+        }
+        Ok(())
     }
 
     #[inline]
     fn buf(&mut self) -> &mut Self::Buf {
-        self.trans
+        self.trans.buf()
     }
 }
 
-pub struct TAsyncBinaryProtocol<R> {
+/// The async binary protocol, in big-endian unless `LITTLE_ENDIAN` is set.
+pub struct TAsyncBinaryProtocol<R, const LITTLE_ENDIAN: bool = false> {
     reader: R,
+    strict_read: bool,
+    max_message_size: Option<usize>,
+    max_container_size: Option<usize>,
+    max_string_size: Option<usize>,
+    depth: Option<DepthTracker>,
+    reject_duplicate_fields: bool,
+    unknown_field_hook: Option<UnknownFieldHook>,
+    allocation: Option<AllocationTracker>,
 }
 
 impl<R> TAsyncBinaryProtocol<R>
@@ -771,17 +1589,118 @@ where
     R: AsyncRead + Unpin + Send,
 {
     pub fn new(reader: R) -> Self {
-        Self { reader }
+        Self::with_byte_order(reader)
     }
 }
 
-impl<R> TAsyncInputProtocol for TAsyncBinaryProtocol<R>
+impl<R, const LITTLE_ENDIAN: bool> TAsyncBinaryProtocol<R, LITTLE_ENDIAN>
+where
+    R: AsyncRead + Unpin + Send,
+{
+    /// Same as [`TAsyncBinaryProtocol::new`], for either byte order.
+    pub fn with_byte_order(reader: R) -> Self {
+        Self {
+            reader,
+            strict_read: true,
+            max_message_size: None,
+            max_container_size: None,
+            max_string_size: None,
+            depth: None,
+            reject_duplicate_fields: false,
+            unknown_field_hook: None,
+            allocation: None,
+        }
+    }
+
+    /// See [`TBinaryProtocol::set_strict_read`].
+    pub fn set_strict_read(&mut self, strict_read: bool) {
+        self.strict_read = strict_read;
+    }
+
+    /// See [`TBinaryProtocol::set_max_message_size`].
+    pub fn set_max_message_size(&mut self, max: usize) {
+        self.max_message_size = Some(max);
+    }
+
+    /// See [`TBinaryProtocol::set_max_container_size`], the sizes being only
+    /// checked against `max` as what is left of the input is unknown.
+    pub fn set_max_container_size(&mut self, max: usize) {
+        self.max_container_size = Some(max);
+    }
+
+    #[inline]
+    async fn read_len(&mut self) -> Result<usize, ThriftException> {
+        let len = self.read_i32().await?;
+        if len < 0 {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::NegativeSize,
+                format!("negative length {len}"),
+            ));
+        }
+        super::check_message_size(len as usize, self.max_message_size)?;
+        Ok(len as usize)
+    }
+
+    /// See [`TBinaryProtocol::set_max_string_size`].
+    pub fn set_max_string_size(&mut self, max: usize) {
+        self.max_string_size = Some(max);
+    }
+
+    /// See [`TBinaryProtocol::set_max_depth`].
+    pub fn set_max_depth(&mut self, max: usize) {
+        self.depth = Some(DepthTracker::new(max));
+    }
+
+    /// See [`TBinaryProtocol::set_reject_duplicate_fields`].
+    pub fn set_reject_duplicate_fields(&mut self, reject: bool) {
+        self.reject_duplicate_fields = reject;
+    }
+
+    /// See [`TBinaryProtocol::set_unknown_field_hook`].
+    pub fn set_unknown_field_hook(&mut self, hook: impl Fn(UnknownField) + Send + Sync + 'static) {
+        self.unknown_field_hook = Some(Arc::new(hook));
+    }
+
+    /// See [`TBinaryProtocol::set_max_allocation`].
+    pub fn set_max_allocation(&mut self, max: usize) {
+        self.allocation = Some(AllocationTracker::new(max));
+    }
+
+    #[inline]
+    async fn read_str_len(&mut self) -> Result<usize, ThriftException> {
+        let len = self.read_len().await?;
+        super::check_string_size(len, self.max_string_size)?;
+        allocation::charge(&mut self.allocation, len)?;
+        Ok(len)
+    }
+
+    #[inline]
+    async fn read_container_size(&mut self) -> Result<usize, ThriftException> {
+        let size = self.read_len().await?;
+        super::check_container_size(size, 1, None, self.max_container_size)?;
+        Ok(size)
+    }
+}
+
+impl<R, const LITTLE_ENDIAN: bool> TAsyncInputProtocol for TAsyncBinaryProtocol<R, LITTLE_ENDIAN>
 where
     R: AsyncRead + Unpin + Send,
 {
     // https://github.com/apache/thrift/blob/master/doc/specs/thrift-binary-protocol.md
     async fn read_message_begin(&mut self) -> Result<TMessageIdentifier, ThriftException> {
-        let size = self.reader.read_i32().await?;
+        depth::reset(&mut self.depth);
+        allocation::reset(&mut self.allocation);
+        let size = self.read_i32().await?;
+        if size >= 0 && !self.strict_read {
+            super::check_message_size(size as usize, self.max_message_size)?;
+            super::check_string_size(size as usize, self.max_string_size)?;
+            let mut name = vec![0; size as usize];
+            self.reader.read_exact(&mut name).await?;
+            let name = FastStr::from_string(string_from_utf8(name)?);
+            let message_type = message_type_from_u8(self.reader.read_u8().await?)?;
+            let sequence_number = self.read_i32().await?;
+            return Ok(TMessageIdentifier::new(name, message_type, sequence_number));
+        }
         if size > 0 {
             return Err(new_protocol_exception(
                 ProtocolExceptionKind::BadVersion,
@@ -789,17 +1708,10 @@ where
             ));
         }
 
-        let type_u8 = (size & 0xf) as u8;
-
-        let message_type = TMessageType::try_from(type_u8).map_err(|_| {
-            new_protocol_exception(
-                ProtocolExceptionKind::InvalidData,
-                format!("invalid message type {type_u8}"),
-            )
-        })?;
+        let message_type = message_type_from_u8((size & 0xf) as u8)?;
 
         let version = size & (VERSION_MASK as i32);
-        if version != (VERSION_1 as i32) {
+        if version != (TBinaryProtocol::<(), LITTLE_ENDIAN>::VERSION as i32) {
             return Err(new_protocol_exception(
                 ProtocolExceptionKind::BadVersion,
                 "Bad version in ReadMessageBegin",
@@ -819,11 +1731,13 @@ where
 
     #[inline]
     async fn read_struct_begin(&mut self) -> Result<Option<TStructIdentifier>, ThriftException> {
+        depth::enter(&mut self.depth)?;
         Ok(None)
     }
 
     #[inline]
     async fn read_struct_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
@@ -850,6 +1764,21 @@ where
         Ok(())
     }
 
+    #[inline]
+    fn rejects_duplicate_fields(&self) -> bool {
+        self.reject_duplicate_fields
+    }
+
+    #[inline]
+    fn on_unknown_field(
+        &self,
+        struct_name: &'static str,
+        field: &TFieldIdentifier,
+        type_mismatch: bool,
+    ) {
+        unknown::report(&self.unknown_field_hook, struct_name, field, type_mismatch);
+    }
+
     #[inline]
     async fn read_bool(&mut self) -> Result<bool, ThriftException> {
         let b = self.read_i8().await?;
@@ -866,7 +1795,7 @@ where
 
     #[inline]
     async fn read_bytes_vec(&mut self) -> Result<Vec<u8>, ThriftException> {
-        let len = self.reader.read_i32().await? as usize;
+        let len = self.read_str_len().await?;
         // FIXME: use maybe_uninit?
         let mut v = vec![0; len];
         self.reader.read_exact(&mut v).await?;
@@ -882,11 +1811,11 @@ where
 
     #[inline]
     async fn read_string(&mut self) -> Result<String, ThriftException> {
-        let len = self.reader.read_i32().await? as usize;
+        let len = self.read_str_len().await?;
         // FIXME: use maybe_uninit?
         let mut v = vec![0; len];
         self.reader.read_exact(&mut v).await?;
-        Ok(unsafe { String::from_utf8_unchecked(v) })
+        Ok(string_from_utf8(v)?)
     }
 
     #[inline]
@@ -906,56 +1835,79 @@ where
 
     #[inline]
     async fn read_i16(&mut self) -> Result<i16, ThriftException> {
-        Ok(self.reader.read_i16().await?)
+        Ok(if LITTLE_ENDIAN {
+            self.reader.read_i16_le().await?
+        } else {
+            self.reader.read_i16().await?
+        })
     }
 
     #[inline]
     async fn read_i32(&mut self) -> Result<i32, ThriftException> {
-        Ok(self.reader.read_i32().await?)
+        Ok(if LITTLE_ENDIAN {
+            self.reader.read_i32_le().await?
+        } else {
+            self.reader.read_i32().await?
+        })
     }
 
     #[inline]
     async fn read_i64(&mut self) -> Result<i64, ThriftException> {
-        Ok(self.reader.read_i64().await?)
+        Ok(if LITTLE_ENDIAN {
+            self.reader.read_i64_le().await?
+        } else {
+            self.reader.read_i64().await?
+        })
     }
 
     #[inline]
     async fn read_double(&mut self) -> Result<f64, ThriftException> {
-        Ok(self.reader.read_f64().await?)
+        Ok(if LITTLE_ENDIAN {
+            self.reader.read_f64_le().await?
+        } else {
+            self.reader.read_f64().await?
+        })
     }
 
     #[inline]
     async fn read_list_begin(&mut self) -> Result<TListIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
         let element_type: TType = self
             .read_byte()
             .await
             .and_then(|n| Ok(field_type_from_u8(n)?))?;
-        let size = self.read_i32().await?;
-        Ok(TListIdentifier::new(element_type, size as usize))
+        let size = self.read_container_size().await?;
+        allocation::charge_elements(&mut self.allocation, size, element_size(element_type))?;
+        Ok(TListIdentifier::new(element_type, size))
     }
 
     #[inline]
     async fn read_list_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     async fn read_set_begin(&mut self) -> Result<TSetIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
         let element_type: TType = self
             .read_byte()
             .await
             .and_then(|n| Ok(field_type_from_u8(n)?))?;
-        let size = self.read_i32().await?;
-        Ok(TSetIdentifier::new(element_type, size as usize))
+        let size = self.read_container_size().await?;
+        allocation::charge_elements(&mut self.allocation, size, element_size(element_type))?;
+        Ok(TSetIdentifier::new(element_type, size))
     }
 
     #[inline]
     async fn read_set_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     async fn read_map_begin(&mut self) -> Result<TMapIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
         let key_type: TType = self
             .read_byte()
             .await
@@ -964,12 +1916,434 @@ where
             .read_byte()
             .await
             .and_then(|n| Ok(field_type_from_u8(n)?))?;
-        let size = self.read_i32().await?;
-        Ok(TMapIdentifier::new(key_type, value_type, size as usize))
+        let size = self.read_container_size().await?;
+        let entry_size = element_size(key_type) + element_size(value_type);
+        allocation::charge_elements(&mut self.allocation, size, entry_size)?;
+        Ok(TMapIdentifier::new(key_type, value_type, size))
     }
 
     #[inline]
     async fn read_map_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
+
+    async fn skip_capture(&mut self, field_type: TType) -> Result<Bytes, ThriftException> {
+        let mut capture = TAsyncBinaryProtocol::<_, LITTLE_ENDIAN> {
+            reader: CaptureReader::new(&mut self.reader),
+            strict_read: self.strict_read,
+            max_message_size: self.max_message_size,
+            max_container_size: self.max_container_size,
+            max_string_size: self.max_string_size,
+            depth: self.depth,
+            reject_duplicate_fields: self.reject_duplicate_fields,
+            unknown_field_hook: self.unknown_field_hook.clone(),
+            allocation: self.allocation,
+        };
+        capture.skip(field_type).await?;
+        Ok(capture.reader.captured.freeze())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, Bytes, BytesMut};
+    use faststr::FastStr;
+    use linkedbytes::LinkedBytes;
+
+    use super::{BufInput, TBinaryProtocol};
+    use crate::{
+        AHashMap, AHashSet,
+        thrift::{
+            CANONICAL_NAN_BITS, TInputProtocol, TLengthProtocol, TOutputProtocol,
+            TOutputProtocolExt, TStructIdentifier, TType, read_empty_struct, staging::Staged,
+        },
+    };
+
+    const IDENT: TStructIdentifier = TStructIdentifier { name: "Empty" };
+
+    #[test]
+    fn test_empty_struct() {
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, false);
+        protocol.write_empty_struct(&IDENT).unwrap();
+        assert_eq!(protocol.empty_struct_len(&IDENT), 1);
+
+        let mut generic = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut generic, false);
+        protocol.write_struct_begin(&IDENT).unwrap();
+        protocol.write_field_stop().unwrap();
+        protocol.write_struct_end().unwrap();
+        assert_eq!(buf, generic);
+
+        let mut bytes = Bytes::from_static(&[0, 42]);
+        TBinaryProtocol::new(&mut bytes, false)
+            .read_empty_struct()
+            .unwrap();
+        assert_eq!(&bytes[..], [42]);
+    }
+
+    #[test]
+    fn test_empty_struct_skips_unknown_fields() {
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, false);
+        protocol.write_struct_begin(&IDENT).unwrap();
+        protocol.write_field_begin(TType::I32, 1).unwrap();
+        protocol.write_i32(7).unwrap();
+        protocol.write_field_end().unwrap();
+        protocol.write_field_stop().unwrap();
+        protocol.write_struct_end().unwrap();
+        let encoded = buf.freeze();
+
+        let mut bytes = encoded.clone();
+        TBinaryProtocol::new(&mut bytes, false)
+            .read_empty_struct()
+            .unwrap();
+        assert!(!bytes.has_remaining());
+
+        let mut bytes = encoded;
+        read_empty_struct(&mut TBinaryProtocol::new(&mut bytes, false)).unwrap();
+        assert!(!bytes.has_remaining());
+    }
+    #[test]
+    fn test_owned_bytes() {
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, false);
+        protocol.write_bytes(Bytes::from_static(b"frame")).unwrap();
+        protocol.write_faststr("name".into()).unwrap();
+        let frame = buf.freeze();
+
+        let mut protocol = TBinaryProtocol::new(frame.clone(), false);
+        let bytes = protocol.read_bytes().unwrap();
+        assert_eq!(bytes, "frame");
+        // split off the frame rather than copied
+        assert_eq!(bytes.as_ptr(), frame[4..].as_ptr());
+        assert_eq!(protocol.read_faststr().unwrap(), "name");
+        assert!(!protocol.buf().has_remaining());
+    }
+
+    #[test]
+    fn test_borrowed_slice() {
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, false);
+        protocol.write_faststr("name".into()).unwrap();
+        protocol.write_bytes(Bytes::from_static(b"frame")).unwrap();
+        protocol.write_i64(7).unwrap();
+        let frame = buf.to_vec();
+
+        let mut protocol = TBinaryProtocol::new(&frame[..], false);
+        assert_eq!(protocol.read_borrowed_str().unwrap(), "name");
+        let bytes = protocol.read_borrowed_bytes().unwrap();
+        assert_eq!(bytes, b"frame");
+        assert_eq!(bytes.as_ptr(), frame[12..].as_ptr());
+        assert_eq!(protocol.read_i64().unwrap(), 7);
+        assert!(protocol.read_byte().is_err());
+
+        let mut protocol = TBinaryProtocol::new(&frame[..], false);
+        assert_eq!(protocol.read_faststr().unwrap(), "name");
+        assert_eq!(protocol.read_bytes().unwrap(), "frame");
+
+        // a length past the end fails instead of panicking
+        let mut protocol = TBinaryProtocol::new(&frame[..6], false);
+        assert!(protocol.read_bytes().is_err());
+    }
+
+    #[test]
+    fn test_buf_input() {
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, false);
+        protocol.write_i64(-2).unwrap();
+        protocol.write_faststr("name".into()).unwrap();
+        protocol.write_bytes(Bytes::from_static(b"frame")).unwrap();
+        protocol.write_double(1.5).unwrap();
+        let frame = buf.freeze();
+
+        // every split, so values straddle the boundary
+        for at in 0..frame.len() {
+            let chunks = frame.slice(..at).chain(frame.slice(at..));
+            let mut protocol = TBinaryProtocol::new(BufInput::new(chunks), false);
+            assert_eq!(protocol.read_i64().unwrap(), -2);
+            assert_eq!(protocol.read_faststr().unwrap(), "name");
+            let bytes = protocol.read_bytes().unwrap();
+            assert_eq!(bytes, "frame");
+            if at <= 20 {
+                assert_eq!(bytes.as_ptr(), frame[20..].as_ptr());
+            }
+            assert_eq!(protocol.read_double().unwrap(), 1.5);
+            assert!(protocol.read_byte().is_err());
+        }
+
+        let chunks = frame.slice(..10).chain(frame.slice(10..));
+        let mut protocol = TBinaryProtocol::new(BufInput::new(chunks), false);
+        let ptr = protocol.buf().chunk().as_ptr();
+        protocol.read_i64().unwrap();
+        assert_eq!(protocol.get_bytes(Some(ptr), 8).unwrap(), frame.slice(..8));
+        protocol.read_faststr().unwrap();
+        assert!(protocol.get_bytes(Some(ptr), 16).is_err());
+    }
+
+    #[test]
+    fn test_canonical_nan() {
+        let nans = [f64::from_bits(0x7ff8_0000_0000_0001), -f64::NAN];
+        let encode = |d: f64, canonical: bool| {
+            let mut buf = BytesMut::new();
+            let mut protocol = TBinaryProtocol::new(&mut buf, false);
+            protocol.set_canonical_nan(canonical);
+            protocol.write_double(d).unwrap();
+            buf
+        };
+
+        assert_ne!(encode(nans[0], false), encode(nans[1], false));
+        assert_eq!(encode(nans[0], true), encode(nans[1], true));
+        assert_eq!(&encode(nans[0], true)[..], CANONICAL_NAN_BITS.to_be_bytes());
+        assert_eq!(encode(1.5, true), encode(1.5, false));
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let keys = (0..64).map(|i| FastStr::new(format!("key{i:02}")));
+        let a: AHashMap<FastStr, AHashSet<i32>> =
+            keys.clone().map(|k| (k, (0..8).collect())).collect();
+        let b: AHashMap<FastStr, AHashSet<i32>> =
+            keys.rev().map(|k| (k, (0..8).rev().collect())).collect();
+        fn encode_into(
+            protocol: &mut impl TOutputProtocol,
+            map: &AHashMap<FastStr, AHashSet<i32>>,
+        ) {
+            protocol
+                .write_map(
+                    TType::Binary,
+                    TType::Set,
+                    map,
+                    |p, k| p.write_faststr(k.clone()),
+                    |p, v| p.write_set(TType::I32, v, |p, i| p.write_i32(*i)),
+                )
+                .unwrap();
+        }
+        let encode = |map: &AHashMap<_, _>, deterministic: bool| {
+            let mut buf = BytesMut::new();
+            let mut protocol = TBinaryProtocol::new(&mut buf, false);
+            protocol.set_deterministic(deterministic);
+            encode_into(&mut protocol, map);
+            buf.freeze()
+        };
+
+        assert_ne!(encode(&a, false), encode(&b, false));
+        let sorted = encode(&a, true);
+        assert_eq!(sorted, encode(&b, true));
+
+        // the keys, of the same length, are in order
+        let mut input = sorted.clone();
+        let mut protocol = TBinaryProtocol::new(&mut input, false);
+        let ident = protocol.read_map_begin().unwrap();
+        let mut keys = Vec::new();
+        for _ in 0..ident.size {
+            keys.push(protocol.read_faststr().unwrap());
+            let set = protocol.read_set_begin().unwrap();
+            let values = (0..set.size)
+                .map(|_| protocol.read_i32().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(values, (0..8).collect::<Vec<_>>());
+        }
+        assert!(keys.is_sorted());
+
+        // the binaries are copied into a `LinkedBytes` to be sorted
+        let mut buf = LinkedBytes::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, true);
+        protocol.set_zero_copy_threshold(1);
+        protocol.set_deterministic(true);
+        encode_into(&mut protocol, &b);
+        assert_eq!(buf.concat().freeze(), sorted);
+
+        let mut buf = BytesMut::new();
+        let mut staged = Staged::new(&mut buf);
+        let mut protocol = TBinaryProtocol::new(&mut staged, false);
+        protocol.set_deterministic(true);
+        let err = protocol
+            .write_set(TType::I32, &AHashSet::from_iter([1, 2]), |p, i| {
+                p.write_i32(*i)
+            })
+            .unwrap_err();
+        assert!(err.to_string().contains("deterministic"));
+    }
+
+    #[test]
+    fn test_zero_copy_threshold() {
+        let s = FastStr::from_static_str("sixteen  bytes!!");
+        let encode = |threshold: Option<usize>| {
+            let mut buf = LinkedBytes::new();
+            let mut protocol = TBinaryProtocol::new(&mut buf, true);
+            if let Some(threshold) = threshold {
+                protocol.set_zero_copy_threshold(threshold);
+            }
+            let len = protocol.faststr_len(&s);
+            let zero_copy_len = protocol.zero_copy_len();
+            protocol.write_faststr(s.clone()).unwrap();
+            assert_eq!(buf.len(), len);
+            (zero_copy_len, buf.iter_list().count(), buf.concat())
+        };
+
+        let (zero_copy_len, nodes, copied) = encode(None);
+        assert_eq!(zero_copy_len, 0);
+        let (zero_copy_len, more_nodes, inserted) = encode(Some(16));
+        assert_eq!(zero_copy_len, 16);
+        assert!(more_nodes > nodes);
+        assert_eq!(inserted, copied);
+    }
+
+    #[test]
+    fn test_max_message_size() {
+        use std::{
+            future::Future,
+            pin::pin,
+            task::{Context, Poll, Waker},
+        };
+
+        use super::TAsyncBinaryProtocol;
+        use crate::thrift::{
+            ProtocolExceptionKind, TAsyncInputProtocol, TMessageIdentifier, TMessageType,
+            ThriftException, reader::IoReader,
+        };
+
+        fn assert_too_large<T: std::fmt::Debug>(r: Result<T, ThriftException>) {
+            match r {
+                Err(ThriftException::Protocol(e)) => {
+                    assert_eq!(e.kind(), ProtocolExceptionKind::MessageTooLarge)
+                }
+                r => panic!("expected a message too large error, got {r:?}"),
+            }
+        }
+
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, false);
+        protocol
+            .write_message_begin(&TMessageIdentifier::new(
+                FastStr::from_static_str("ping"),
+                TMessageType::Call,
+                1,
+            ))
+            .unwrap();
+        let message = buf.split().freeze();
+        // a list claiming 2^31 - 1 elements
+        let list = Bytes::from_static(&[8, 0x7f, 0xff, 0xff, 0xff]);
+
+        let mut b = message.clone();
+        let mut protocol = TBinaryProtocol::new(&mut b, false);
+        protocol.set_max_message_size(4);
+        assert_eq!(protocol.read_message_begin().unwrap().name, "ping");
+        let mut b = message.clone();
+        let mut protocol = TBinaryProtocol::new(&mut b, false);
+        protocol.set_max_message_size(3);
+        assert_too_large(protocol.read_message_begin());
+
+        let mut b = list.clone();
+        let mut protocol = TBinaryProtocol::new(&mut b, false);
+        protocol.set_max_message_size(1024);
+        assert_too_large(protocol.read_list_begin());
+        let mut protocol = TBinaryProtocol::new(&list[1..], false);
+        protocol.set_max_message_size(1024);
+        assert_too_large(protocol.read_borrowed_bytes());
+        let mut protocol = TBinaryProtocol::new(IoReader::new(&list[..]), false);
+        protocol.set_max_message_size(1024);
+        assert_too_large(protocol.read_list_begin());
+
+        let mut protocol = TAsyncBinaryProtocol::new(&message[..]);
+        protocol.set_max_message_size(3);
+        let poll =
+            pin!(protocol.read_message_begin()).poll(&mut Context::from_waker(Waker::noop()));
+        match poll {
+            Poll::Ready(r) => assert_too_large(r),
+            Poll::Pending => panic!("reading a slice is ready"),
+        }
+        let mut protocol = TAsyncBinaryProtocol::new(&list[..]);
+        protocol.set_max_message_size(1024);
+        let poll = pin!(protocol.read_list_begin()).poll(&mut Context::from_waker(Waker::noop()));
+        match poll {
+            Poll::Ready(r) => assert_too_large(r),
+            Poll::Pending => panic!("reading a slice is ready"),
+        }
+    }
+
+    #[test]
+    fn test_container_size() {
+        use crate::thrift::{
+            ProtocolExceptionKind, TListIdentifier, ThriftException, reader::IoReader,
+        };
+
+        fn kind<T: std::fmt::Debug>(r: Result<T, ThriftException>) -> ProtocolExceptionKind {
+            match r {
+                Err(ThriftException::Protocol(e)) => e.kind(),
+                r => panic!("expected a protocol error, got {r:?}"),
+            }
+        }
+
+        // a list of two i64s, a map of 2^20 i32 entries and a negative size
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, false);
+        protocol
+            .write_list_begin(TListIdentifier::new(TType::I64, 2))
+            .unwrap();
+        protocol.write_i64(1).unwrap();
+        protocol.write_i64(2).unwrap();
+        let list = buf.split().freeze();
+        let map = Bytes::from_static(&[8, 8, 0, 0x10, 0, 0]);
+        let negative = Bytes::from_static(&[8, 0xff, 0xff, 0xff, 0xff]);
+
+        let mut b = list.clone();
+        let mut protocol = TBinaryProtocol::new(&mut b, false);
+        assert_eq!(protocol.read_list_begin().unwrap().size, 2);
+        let mut b = list.slice(..list.len() - 1);
+        let mut protocol = TBinaryProtocol::new(&mut b, false);
+        assert_eq!(
+            kind(protocol.read_list_begin()),
+            ProtocolExceptionKind::InvalidData
+        );
+        let mut b = list.clone();
+        let mut protocol = TBinaryProtocol::new(&mut b, false);
+        protocol.set_max_container_size(1);
+        assert_eq!(
+            kind(protocol.read_list_begin()),
+            ProtocolExceptionKind::SizeLimit
+        );
+
+        let mut b = map.clone();
+        let mut protocol = TBinaryProtocol::new(&mut b, false);
+        assert_eq!(
+            kind(protocol.read_map_begin()),
+            ProtocolExceptionKind::InvalidData
+        );
+        let mut b = negative.clone();
+        let mut protocol = TBinaryProtocol::new(&mut b, false);
+        assert_eq!(
+            kind(protocol.read_set_begin()),
+            ProtocolExceptionKind::NegativeSize
+        );
+
+        let mut protocol = TBinaryProtocol::new(IoReader::new(&map[..]), false);
+        assert_eq!(protocol.read_map_begin().unwrap().size, 1 << 20);
+        let mut protocol = TBinaryProtocol::new(IoReader::new(&map[..]), false);
+        protocol.set_max_container_size(1024);
+        assert_eq!(
+            kind(protocol.read_map_begin()),
+            ProtocolExceptionKind::SizeLimit
+        );
+    }
+
+    #[cfg(feature = "safe-only")]
+    #[test]
+    fn test_safe_only_rejects_invalid_utf8() {
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, false);
+        protocol
+            .write_bytes(Bytes::from_static(&[0xff, 0xfe]))
+            .unwrap();
+        let bytes = buf.freeze();
+
+        let mut b = bytes.clone();
+        let mut protocol = TBinaryProtocol::new(&mut b, false);
+        assert!(protocol.read_string().is_err());
+        let mut b = bytes;
+        let mut protocol = TBinaryProtocol::new(&mut b, false);
+        assert!(protocol.read_faststr().is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use std::{convert::TryInto, ptr, slice, str};
+use std::{borrow::BorrowMut, convert::TryInto, ptr, slice, str, sync::Arc};
 
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use faststr::FastStr;
@@ -6,11 +6,22 @@ use linkedbytes::LinkedBytes;
 use smallvec::SmallVec;
 
 use super::{
-    BINARY_BASIC_TYPE_FIXED_SIZE, ProtocolException, TFieldIdentifier, TInputProtocol,
-    TLengthProtocol, TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType,
-    TOutputProtocol, TSetIdentifier, TStructIdentifier, TType, ThriftException,
-    ZERO_COPY_THRESHOLD, error::ProtocolExceptionKind, new_protocol_exception,
+    BINARY_BASIC_TYPE_FIXED_SIZE, Message, ProtocolException, TFieldIdentifier, TInputProtocol,
+    TLengthProtocol, TListIdentifier, TMapIdentifier, TMessageIdentifier, TOutputProtocol,
+    TSetIdentifier, TStructIdentifier, TType, ThriftException, ZERO_COPY_THRESHOLD,
+    allocation::{self, AllocationTracker, element_size},
+    binary::{message_type_from_u8, min_element_len, peek_field_header},
+    budget::{Budget, BudgetTracker},
+    canonicalize_nan,
+    depth::{self, DepthTracker},
+    error::ProtocolExceptionKind,
+    frame::FRAME_HEADER_LEN,
+    mark::InputMark,
+    new_protocol_exception,
+    rw_ext::{IOError, check_utf8, faststr_from_bytes},
+    unknown::{self, UnknownField, UnknownFieldHook},
 };
+use crate::assert_remaining;
 
 static VERSION_1: u32 = 0x80010000;
 static VERSION_MASK: u32 = 0xffff0000;
@@ -24,6 +35,30 @@ pub struct TBinaryUnsafeOutputProtocol<T> {
 
     zero_copy: bool,
     zero_copy_len: usize,
+    canonical_nan: bool,
+    capacity_check: bool,
+    strict_write: bool,
+}
+
+/// Fails with [`ProtocolExceptionKind::BufferTooSmall`] if the capacity check
+/// is on and `len` bytes don't fit in what is left of `buf`, before the debug
+/// bounds check.
+macro_rules! ensure_capacity {
+    ($self:ident, $len:expr) => {
+        let len = $len;
+        if $self.capacity_check && $self.buf.len().saturating_sub($self.index) < len {
+            return Err(buffer_too_small(
+                len,
+                $self.buf.len().saturating_sub($self.index),
+            ));
+        }
+        check_bounds!($self.buf, $self.index, len);
+    };
+}
+
+#[cold]
+fn buffer_too_small(needed: usize, remaining: usize) -> ThriftException {
+    ProtocolException::buffer_too_small(needed, remaining).into()
 }
 
 impl<T> TBinaryUnsafeOutputProtocol<T> {
@@ -44,9 +79,51 @@ impl<T> TBinaryUnsafeOutputProtocol<T> {
             index: 0,
             zero_copy,
             zero_copy_len: 0,
+            canonical_nan: false,
+            capacity_check: false,
+            strict_write: true,
         }
     }
 
+    /// Rewrites every NaN written by `write_double` to the canonical NaN, see
+    /// [`canonicalize_nan`].
+    #[inline]
+    pub fn set_canonical_nan(&mut self, canonical_nan: bool) {
+        self.canonical_nan = canonical_nan;
+    }
+
+    /// Checks every write against the remaining length of `buf`, failing with
+    /// [`ProtocolExceptionKind::BufferTooSmall`] instead of writing past it
+    /// when the size computed for a message is too small. Off by default, as
+    /// it costs a branch per write, the capacity required by [`Self::new`]
+    /// being trusted instead. [`UnsafeBinaryEncoder`] always turns it on.
+    #[inline]
+    pub fn set_capacity_check(&mut self, capacity_check: bool) {
+        self.capacity_check = capacity_check;
+    }
+
+    /// Writes the unversioned message header when off, see
+    /// [`TBinaryProtocol::set_strict_write`](super::binary::TBinaryProtocol::set_strict_write).
+    #[inline]
+    pub fn set_strict_write(&mut self, strict_write: bool) {
+        self.strict_write = strict_write;
+    }
+
+    /// Swaps in the transport and buffer of the next message on the same
+    /// connection and resets the per-message state, keeping the settings of
+    /// the protocol. Returns the previous transport.
+    ///
+    /// # Safety
+    ///
+    /// The same as [`Self::new`].
+    #[inline]
+    pub unsafe fn reset_with(&mut self, trans: T, buf: &'static mut [u8]) -> T {
+        self.buf = buf;
+        self.index = 0;
+        self.zero_copy_len = 0;
+        std::mem::replace(&mut self.trans, trans)
+    }
+
     #[doc(hidden)]
     pub fn index(&self) -> usize {
         self.index
@@ -66,9 +143,15 @@ fn field_type_from_u8(ttype: u8) -> Result<TType, ProtocolException> {
 }
 
 impl<T> TLengthProtocol for TBinaryUnsafeOutputProtocol<T> {
+    const FIXED_WIDTH: bool = true;
+
     #[inline]
     fn message_begin_len(&mut self, identifier: &TMessageIdentifier) -> usize {
-        self.i32_len(0) + self.faststr_len(&identifier.name) + self.i32_len(0)
+        if self.strict_write {
+            self.i32_len(0) + self.faststr_len(&identifier.name) + self.i32_len(0)
+        } else {
+            self.faststr_len(&identifier.name) + self.byte_len(0) + self.i32_len(0)
+        }
     }
 
     #[inline]
@@ -207,18 +290,52 @@ impl<T> TLengthProtocol for TBinaryUnsafeOutputProtocol<T> {
     }
 }
 
-impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
+/// The transports of [`TBinaryUnsafeOutputProtocol`] other than
+/// [`LinkedBytes`], which only hand out their buffer as the `buf_mut` of the
+/// protocol.
+#[doc(hidden)]
+pub trait UnsafeOutputTrans {
+    type BufMut: BufMut;
+
+    fn buf_mut(&mut self) -> &mut Self::BufMut;
+}
+
+impl UnsafeOutputTrans for &mut BytesMut {
     type BufMut = BytesMut;
 
+    #[inline]
+    fn buf_mut(&mut self) -> &mut BytesMut {
+        self
+    }
+}
+
+/// The slice after the message, empty for [`encode_into_slice`].
+impl<'a> UnsafeOutputTrans for &'a mut [u8] {
+    type BufMut = &'a mut [u8];
+
+    #[inline]
+    fn buf_mut(&mut self) -> &mut &'a mut [u8] {
+        self
+    }
+}
+
+impl<T: UnsafeOutputTrans> TOutputProtocol for TBinaryUnsafeOutputProtocol<T> {
+    type BufMut = T::BufMut;
+
     #[inline]
     fn write_message_begin(
         &mut self,
         identifier: &TMessageIdentifier,
     ) -> Result<(), ThriftException> {
         let msg_type_u8: u8 = identifier.message_type.into();
-        let version = (VERSION_1 | msg_type_u8 as u32) as i32;
-        self.write_i32(version)?;
-        self.write_faststr(identifier.name.clone())?;
+        if self.strict_write {
+            let version = (VERSION_1 | msg_type_u8 as u32) as i32;
+            self.write_i32(version)?;
+            self.write_faststr(identifier.name.clone())?;
+        } else {
+            self.write_faststr(identifier.name.clone())?;
+            self.write_byte(msg_type_u8)?;
+        }
         self.write_i32(identifier.sequence_number)?;
         Ok(())
     }
@@ -240,6 +357,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
 
     #[inline]
     fn write_field_begin(&mut self, field_type: TType, id: i16) -> Result<(), ThriftException> {
+        ensure_capacity!(self, FIELD_BEGIN_LEN);
         unsafe {
             *self.buf.get_unchecked_mut(self.index) = field_type as u8;
             let buf: &mut [u8; 2] = self
@@ -280,6 +398,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
 
     #[inline]
     fn write_bytes_without_len(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        ensure_capacity!(self, b.len());
         unsafe {
             ptr::copy_nonoverlapping(b.as_ptr(), self.buf.as_mut_ptr().add(self.index), b.len());
             self.index += b.len();
@@ -289,6 +408,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
 
     #[inline]
     fn write_byte(&mut self, b: u8) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 1);
         unsafe {
             *self.buf.get_unchecked_mut(self.index) = b;
             self.index += 1;
@@ -298,6 +418,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
 
     #[inline]
     fn write_uuid(&mut self, u: [u8; 16]) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 16);
         unsafe {
             let buf: &mut [u8; 16] = self
                 .buf
@@ -312,6 +433,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
 
     #[inline]
     fn write_i8(&mut self, i: i8) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 1);
         unsafe {
             *self.buf.get_unchecked_mut(self.index) = *i.to_be_bytes().get_unchecked(0);
             self.index += 1;
@@ -321,9 +443,10 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
 
     #[inline]
     fn write_i16(&mut self, i: i16) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 2);
         unsafe {
             let buf: &mut [u8; 2] = self
-                .trans
+                .buf
                 .get_unchecked_mut(self.index..self.index + 2)
                 .try_into()
                 .unwrap_unchecked();
@@ -335,9 +458,10 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
 
     #[inline]
     fn write_i32(&mut self, i: i32) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 4);
         unsafe {
             let buf: &mut [u8; 4] = self
-                .trans
+                .buf
                 .get_unchecked_mut(self.index..self.index + 4)
                 .try_into()
                 .unwrap_unchecked();
@@ -349,9 +473,10 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
 
     #[inline]
     fn write_i64(&mut self, i: i64) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 8);
         unsafe {
             let buf: &mut [u8; 8] = self
-                .trans
+                .buf
                 .get_unchecked_mut(self.index..self.index + 8)
                 .try_into()
                 .unwrap_unchecked();
@@ -363,9 +488,15 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
 
     #[inline]
     fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 8);
+        let d = if self.canonical_nan {
+            canonicalize_nan(d)
+        } else {
+            d
+        };
         unsafe {
             let buf: &mut [u8; 8] = self
-                .trans
+                .buf
                 .get_unchecked_mut(self.index..self.index + 8)
                 .try_into()
                 .unwrap_unchecked();
@@ -378,6 +509,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
     #[inline]
     fn write_string(&mut self, s: &str) -> Result<(), ThriftException> {
         self.write_i32(s.len() as i32)?;
+        ensure_capacity!(self, s.len());
         unsafe {
             ptr::copy_nonoverlapping(s.as_ptr(), self.buf.as_mut_ptr().add(self.index), s.len());
             self.index += s.len();
@@ -388,6 +520,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
     #[inline]
     fn write_faststr(&mut self, s: FastStr) -> Result<(), ThriftException> {
         self.write_i32(s.len() as i32)?;
+        ensure_capacity!(self, s.len());
         unsafe {
             ptr::copy_nonoverlapping(s.as_ptr(), self.buf.as_mut_ptr().add(self.index), s.len());
             self.index += s.len();
@@ -439,6 +572,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
     #[inline]
     fn write_bytes_vec(&mut self, b: &[u8]) -> Result<(), ThriftException> {
         self.write_i32(b.len() as i32)?;
+        ensure_capacity!(self, b.len());
         unsafe {
             ptr::copy_nonoverlapping(b.as_ptr(), self.buf.as_mut_ptr().add(self.index), b.len());
             self.index += b.len();
@@ -448,7 +582,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut BytesMut> {
 
     #[inline]
     fn buf_mut(&mut self) -> &mut Self::BufMut {
-        self.trans
+        self.trans.buf_mut()
     }
 }
 
@@ -472,9 +606,14 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
         identifier: &TMessageIdentifier,
     ) -> Result<(), ThriftException> {
         let msg_type_u8: u8 = identifier.message_type.into();
-        let version = (VERSION_1 | msg_type_u8 as u32) as i32;
-        self.write_i32(version)?;
-        self.write_faststr(identifier.name.clone())?;
+        if self.strict_write {
+            let version = (VERSION_1 | msg_type_u8 as u32) as i32;
+            self.write_i32(version)?;
+            self.write_faststr(identifier.name.clone())?;
+        } else {
+            self.write_faststr(identifier.name.clone())?;
+            self.write_byte(msg_type_u8)?;
+        }
         self.write_i32(identifier.sequence_number)?;
         self.advance_mut(self.index);
         Ok(())
@@ -497,6 +636,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
 
     #[inline]
     fn write_field_begin(&mut self, field_type: TType, id: i16) -> Result<(), ThriftException> {
+        ensure_capacity!(self, FIELD_BEGIN_LEN);
         unsafe {
             *self.buf.get_unchecked_mut(self.index) = field_type as u8;
             let buf: &mut [u8; 2] = self
@@ -550,6 +690,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
             };
             return Ok(());
         }
+        ensure_capacity!(self, b.len());
         unsafe {
             ptr::copy_nonoverlapping(b.as_ptr(), self.buf.as_mut_ptr().add(self.index), b.len());
             self.index += b.len();
@@ -559,6 +700,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
 
     #[inline]
     fn write_byte(&mut self, b: u8) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 1);
         unsafe {
             *self.buf.get_unchecked_mut(self.index) = b;
             self.index += 1;
@@ -568,6 +710,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
 
     #[inline]
     fn write_uuid(&mut self, u: [u8; 16]) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 16);
         unsafe {
             let buf: &mut [u8; 16] = self
                 .buf
@@ -582,6 +725,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
 
     #[inline]
     fn write_i8(&mut self, i: i8) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 1);
         unsafe {
             *self.buf.get_unchecked_mut(self.index) = *i.to_be_bytes().get_unchecked(0);
             self.index += 1;
@@ -591,6 +735,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
 
     #[inline]
     fn write_i16(&mut self, i: i16) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 2);
         unsafe {
             let buf: &mut [u8; 2] = self
                 .buf
@@ -605,6 +750,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
 
     #[inline]
     fn write_i32(&mut self, i: i32) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 4);
         unsafe {
             let buf: &mut [u8; 4] = self
                 .buf
@@ -619,6 +765,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
 
     #[inline]
     fn write_i64(&mut self, i: i64) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 8);
         unsafe {
             let buf: &mut [u8; 8] = self
                 .buf
@@ -633,6 +780,12 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
 
     #[inline]
     fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
+        ensure_capacity!(self, 8);
+        let d = if self.canonical_nan {
+            canonicalize_nan(d)
+        } else {
+            d
+        };
         unsafe {
             let buf: &mut [u8; 8] = self
                 .buf
@@ -648,6 +801,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
     #[inline]
     fn write_string(&mut self, s: &str) -> Result<(), ThriftException> {
         self.write_i32(s.len() as i32)?;
+        ensure_capacity!(self, s.len());
         unsafe {
             ptr::copy_nonoverlapping(s.as_ptr(), self.buf.as_mut_ptr().add(self.index), s.len());
             self.index += s.len();
@@ -670,6 +824,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
             };
             return Ok(());
         }
+        ensure_capacity!(self, s.len());
         unsafe {
             ptr::copy_nonoverlapping(s.as_ptr(), self.buf.as_mut_ptr().add(self.index), s.len());
             self.index += s.len();
@@ -721,6 +876,7 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
     #[inline]
     fn write_bytes_vec(&mut self, b: &[u8]) -> Result<(), ThriftException> {
         self.write_i32(b.len() as i32)?;
+        ensure_capacity!(self, b.len());
         unsafe {
             ptr::copy_nonoverlapping(b.as_ptr(), self.buf.as_mut_ptr().add(self.index), b.len());
             self.index += b.len();
@@ -734,42 +890,381 @@ impl TOutputProtocol for TBinaryUnsafeOutputProtocol<&mut LinkedBytes> {
     }
 }
 
-pub struct TBinaryUnsafeInputProtocol<'a> {
-    pub(crate) trans: &'a mut Bytes,
+/// The spare capacity [`UnsafeBinaryEncoder::encode_framed`] reserves for a
+/// message at least.
+const MIN_FRAME_RESERVE: usize = 256;
+
+/// A safe way to use [`TBinaryUnsafeOutputProtocol`]: owns the buffer the
+/// protocol writes into and reserves the size of every message before
+/// encoding it. As the size comes from [`Message::size`], which may be wrong,
+/// every write is checked against the reserved space, and a message that
+/// doesn't fit fails with [`ProtocolExceptionKind::BufferTooSmall`].
+///
+/// ```
+/// use pilota::thrift::{
+///     ApplicationException, ApplicationExceptionKind, Message, binary::TBinaryProtocol,
+///     binary_unsafe::UnsafeBinaryEncoder,
+/// };
+///
+/// let e = ApplicationException::new(ApplicationExceptionKind::UNKNOWN_METHOD, "no such method");
+/// let mut encoder = UnsafeBinaryEncoder::with_capacity(64);
+/// encoder.encode(&e).unwrap();
+/// let mut bytes = encoder.finish().freeze();
+/// let decoded = ApplicationException::decode(&mut TBinaryProtocol::new(&mut bytes, false)).unwrap();
+/// assert_eq!(decoded.message(), "no such method");
+/// ```
+pub struct UnsafeBinaryEncoder<T = BytesMut> {
+    trans: T,
+    zero_copy: bool,
+}
+
+impl UnsafeBinaryEncoder<BytesMut> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            trans: BytesMut::with_capacity(capacity),
+            zero_copy: false,
+        }
+    }
+
+    /// Appends the encoding of `message` to the buffer.
+    pub fn encode<M: Message>(&mut self, message: &M) -> Result<(), ThriftException> {
+        // SAFETY: the length protocol never writes
+        let size =
+            message.size(&mut unsafe { TBinaryUnsafeOutputProtocol::new((), &mut [], false) });
+        self.trans.reserve(size);
+        // SAFETY: `buf` is the spare capacity of the buffer, and the capacity
+        // check keeps the writes within it
+        unsafe {
+            let spare = self.trans.spare_capacity_mut();
+            let buf = slice::from_raw_parts_mut(spare.as_mut_ptr().cast(), spare.len());
+            let mut protocol = TBinaryUnsafeOutputProtocol::new(&mut self.trans, buf, false);
+            protocol.set_capacity_check(true);
+            message.encode(&mut protocol)?;
+            let len = protocol.index();
+            self.trans.advance_mut(len);
+        }
+        Ok(())
+    }
+
+    /// Appends `message` as a frame, its 4-byte big-endian length followed by
+    /// its encoding.
+    ///
+    /// Unlike [`Self::encode`], the size of the message is not computed
+    /// beforehand: a placeholder is written for the length and backfilled
+    /// once the message is encoded into the spare capacity of the buffer,
+    /// with every write checked against it. A message that doesn't fit is
+    /// encoded again after doubling the capacity, so with a buffer reused
+    /// across frames of similar sizes, the message is traversed once. The
+    /// lengths of binaries, strings and containers are known before they are
+    /// written, so the frame length is the only one to backfill.
+    pub fn encode_framed<M: Message>(&mut self, message: &M) -> Result<(), ThriftException> {
+        let start = self.trans.len();
+        let mut reserve = FRAME_HEADER_LEN + MIN_FRAME_RESERVE;
+        loop {
+            self.trans.reserve(reserve);
+            // SAFETY: `buf` is the spare capacity of the buffer after the
+            // length placeholder, and the capacity check keeps the writes
+            // within it
+            let result = unsafe {
+                let spare = self.trans.spare_capacity_mut();
+                let buf = slice::from_raw_parts_mut(
+                    spare.as_mut_ptr().add(FRAME_HEADER_LEN).cast(),
+                    spare.len() - FRAME_HEADER_LEN,
+                );
+                let mut protocol = TBinaryUnsafeOutputProtocol::new(&mut self.trans, buf, false);
+                protocol.set_capacity_check(true);
+                message.encode(&mut protocol).map(|()| protocol.index())
+            };
+            match result {
+                Ok(size) => {
+                    self.trans.put_u32(size as u32);
+                    // SAFETY: the message was written after the placeholder
+                    unsafe { self.trans.advance_mut(size) };
+                    return Ok(());
+                }
+                Err(ThriftException::Protocol(e))
+                    if e.kind() == ProtocolExceptionKind::BufferTooSmall =>
+                {
+                    reserve = self.trans.capacity().saturating_sub(start) * 2;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Returns the buffer with every message encoded.
+    pub fn finish(self) -> BytesMut {
+        self.trans
+    }
+}
+
+impl UnsafeBinaryEncoder<LinkedBytes> {
+    /// With `zero_copy`, binaries and strings of at least the zero-copy
+    /// threshold are inserted into the [`LinkedBytes`] instead of copied.
+    pub fn linked_with_capacity(capacity: usize, zero_copy: bool) -> Self {
+        Self {
+            trans: LinkedBytes::with_capacity(capacity),
+            zero_copy,
+        }
+    }
+
+    /// Appends the encoding of `message` to the buffer.
+    pub fn encode<M: Message>(&mut self, message: &M) -> Result<(), ThriftException> {
+        // SAFETY: the length protocol never writes
+        let mut length = unsafe { TBinaryUnsafeOutputProtocol::new((), &mut [], self.zero_copy) };
+        let size = message.size(&mut length) - length.zero_copy_len();
+        self.trans.bytes_mut().reserve(size);
+        // SAFETY: as for `BytesMut`, the inserted binaries and strings not
+        // being part of `size`
+        unsafe {
+            let spare = self.trans.bytes_mut().spare_capacity_mut();
+            let buf = slice::from_raw_parts_mut(spare.as_mut_ptr().cast(), spare.len());
+            let mut protocol =
+                TBinaryUnsafeOutputProtocol::new(&mut self.trans, buf, self.zero_copy);
+            protocol.set_capacity_check(true);
+            message.encode(&mut protocol)?;
+            protocol.advance_mut(protocol.index);
+        }
+        Ok(())
+    }
+
+    /// Returns the buffer with every message encoded.
+    pub fn finish(self) -> LinkedBytes {
+        self.trans
+    }
+}
+
+/// Encodes `message` at the start of `buf`, a buffer owned elsewhere such as
+/// a ring buffer in shared memory, returning the number of bytes written.
+///
+/// Every write is checked against the length of `buf`, so a message too large
+/// for it fails with [`ProtocolExceptionKind::BufferTooSmall`], leaving what
+/// was written of it in `buf`.
+///
+/// ```
+/// use pilota::thrift::{
+///     ApplicationException, ApplicationExceptionKind, Message, binary::TBinaryProtocol,
+///     binary_unsafe::encode_into_slice,
+/// };
+///
+/// let e = ApplicationException::new(ApplicationExceptionKind::UNKNOWN_METHOD, "no such method");
+/// let mut buf = [0; 64];
+/// let len = encode_into_slice(&e, &mut buf).unwrap();
+/// let decoded = ApplicationException::decode(&mut TBinaryProtocol::new(&buf[..len], false)).unwrap();
+/// assert_eq!(decoded.message(), "no such method");
+/// ```
+pub fn encode_into_slice<M: Message>(
+    message: &M,
+    buf: &mut [u8],
+) -> Result<usize, ThriftException> {
+    // SAFETY: the protocol doesn't outlive the borrow of `buf`, and the
+    // capacity check keeps its writes within it
+    unsafe {
+        let buf = slice::from_raw_parts_mut(buf.as_mut_ptr(), buf.len());
+        let mut protocol = TBinaryUnsafeOutputProtocol::new(&mut [][..], buf, false);
+        protocol.set_capacity_check(true);
+        message.encode(&mut protocol)?;
+        Ok(protocol.index())
+    }
+}
+
+/// Reads from `&mut Bytes`, advancing it past what is read, or from an owned
+/// `Bytes`.
+pub struct TBinaryUnsafeInputProtocol<'a, T = &'a mut Bytes> {
+    pub(crate) trans: T,
     pub(crate) buf: &'a [u8],
     pub(crate) index: usize,
+    budget: Option<BudgetTracker>,
+    strict_read: bool,
+    max_message_size: Option<usize>,
+    max_container_size: Option<usize>,
+    max_string_size: Option<usize>,
+    depth: Option<DepthTracker>,
+    reject_duplicate_fields: bool,
+    unknown_field_hook: Option<UnknownFieldHook>,
+    allocation: Option<AllocationTracker>,
 }
 
-impl<'a> TBinaryUnsafeInputProtocol<'a> {
+impl<'a, T: BorrowMut<Bytes>> TBinaryUnsafeInputProtocol<'a, T> {
     /// # Safety
     ///
     /// The 'trans' MUST have enough capacity to read from or write to.
     #[inline]
-    pub unsafe fn new(trans: &'a mut Bytes) -> Self {
+    pub unsafe fn new(trans: T) -> Self {
         unsafe {
-            let buf = slice::from_raw_parts(trans.as_ptr(), trans.len());
+            let bytes = trans.borrow();
+            let buf = slice::from_raw_parts(bytes.as_ptr(), bytes.len());
             Self {
                 trans,
                 buf,
                 index: 0,
+                budget: None,
+                strict_read: true,
+                max_message_size: None,
+                max_container_size: None,
+                max_string_size: None,
+                depth: None,
+                reject_duplicate_fields: false,
+                unknown_field_hook: None,
+                allocation: None,
             }
         }
     }
 
+    /// Swaps in the transport of the next message on the same connection,
+    /// keeping the settings of the protocol. Returns the previous transport.
+    ///
+    /// A budget set with `set_budget` restarts at the next message begin.
+    ///
+    /// # Safety
+    ///
+    /// The same as [`Self::new`].
+    #[inline]
+    pub unsafe fn reset_with(&mut self, trans: T) -> T {
+        unsafe {
+            let bytes = trans.borrow();
+            self.buf = slice::from_raw_parts(bytes.as_ptr(), bytes.len());
+        }
+        self.index = 0;
+        depth::reset(&mut self.depth);
+        allocation::reset(&mut self.allocation);
+        std::mem::replace(&mut self.trans, trans)
+    }
+
+    /// Limits how many bytes and how much time decoding may take, counted
+    /// from the current position and restarted at each message begin.
+    pub fn set_budget(&mut self, budget: Budget) {
+        self.budget = Some(BudgetTracker::new(budget, self.remaining()));
+    }
+
+    /// Accepts the unversioned message header when off, see
+    /// [`TBinaryProtocol::set_strict_read`](super::binary::TBinaryProtocol::set_strict_read).
+    #[inline]
+    pub fn set_strict_read(&mut self, strict_read: bool) {
+        self.strict_read = strict_read;
+    }
+
+    /// Fails with [`ProtocolExceptionKind::MessageTooLarge`] when a message
+    /// name, string, binary or container size read from the input is larger
+    /// than `max`, before anything is allocated for it.
+    #[inline]
+    pub fn set_max_message_size(&mut self, max: usize) {
+        self.max_message_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::SizeLimit`] when a list, set or map
+    /// has more elements than `max`, see
+    /// [`TBinaryProtocol::set_max_container_size`](super::binary::TBinaryProtocol::set_max_container_size).
+    #[inline]
+    pub fn set_max_container_size(&mut self, max: usize) {
+        self.max_container_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::InvalidData`] when a string or
+    /// binary, message names included, is longer than `max`, see
+    /// [`TBinaryProtocol::set_max_string_size`](super::binary::TBinaryProtocol::set_max_string_size).
+    #[inline]
+    pub fn set_max_string_size(&mut self, max: usize) {
+        self.max_string_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::DepthLimit`] when structs and
+    /// containers, skipped ones included, are nested deeper than `max`.
+    #[inline]
+    pub fn set_max_depth(&mut self, max: usize) {
+        self.depth = Some(DepthTracker::new(max));
+    }
+
+    /// Fails with [`ProtocolExceptionKind::BudgetExceeded`] once decoding a
+    /// message allocates more than `max` bytes in total, see
+    /// [`TBinaryProtocol::set_max_allocation`](super::binary::TBinaryProtocol::set_max_allocation).
+    #[inline]
+    pub fn set_max_allocation(&mut self, max: usize) {
+        self.allocation = Some(AllocationTracker::new(max));
+    }
+
+    /// Makes the generated decode fail with
+    /// [`ProtocolExceptionKind::InvalidData`] when a field of a struct
+    /// appears twice, instead of keeping its last value.
+    #[inline]
+    pub fn set_reject_duplicate_fields(&mut self, reject: bool) {
+        self.reject_duplicate_fields = reject;
+    }
+
+    /// Calls `hook` with the fields the generated decode skips, not knowing
+    /// their id or their type, to notice a peer sending a newer schema.
+    pub fn set_unknown_field_hook(&mut self, hook: impl Fn(UnknownField) + Send + Sync + 'static) {
+        self.unknown_field_hook = Some(Arc::new(hook));
+    }
+
+    // reads the length of a string or a binary
+    #[inline]
+    fn read_str_len(&mut self) -> Result<i32, ThriftException> {
+        let len = self.read_i32()?;
+        super::check_message_size(len.max(0) as usize, self.max_message_size)?;
+        super::check_string_size(len.max(0) as usize, self.max_string_size)?;
+        allocation::charge(&mut self.allocation, len.max(0) as usize)?;
+        Ok(len)
+    }
+
+    // reads the size of a list, set or map of elements of at least `min_len`
+    // bytes each
+    #[inline]
+    fn read_container_size(&mut self, min_len: usize) -> Result<usize, ThriftException> {
+        let size = self.read_i32()?;
+        if size < 0 {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::NegativeSize,
+                format!("negative length {size}"),
+            ));
+        }
+        super::check_message_size(size as usize, self.max_message_size)?;
+        let remaining = self.remaining();
+        super::check_container_size(
+            size as usize,
+            min_len,
+            Some(remaining),
+            self.max_container_size,
+        )?;
+        Ok(size as usize)
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.trans.borrow().len() - self.index
+    }
+
+    #[inline]
+    fn check_budget(&self) -> Result<(), ThriftException> {
+        match &self.budget {
+            Some(budget) => budget.check(self.remaining()),
+            None => Ok(()),
+        }
+    }
+
     #[doc(hidden)]
     pub fn index(&self) -> usize {
         self.index
     }
 
+    // The unread bytes of the transport.
+    #[inline]
+    unsafe fn rest(&self) -> &'a [u8] {
+        let bytes = self.trans.borrow();
+        unsafe { slice::from_raw_parts(bytes.as_ptr(), bytes.len()) }
+    }
+
     #[doc(hidden)]
     fn advance(&mut self, len: usize) {
-        self.trans.advance(len);
+        self.trans.borrow_mut().advance(len);
         self.buf.advance(len);
         self.index -= len;
     }
 }
 
-impl<'a> TLengthProtocol for TBinaryUnsafeInputProtocol<'a> {
+impl<T> TLengthProtocol for TBinaryUnsafeInputProtocol<'_, T> {
+    const FIXED_WIDTH: bool = true;
+
     #[inline]
     fn message_begin_len(&mut self, identifier: &TMessageIdentifier) -> usize {
         self.i32_len(0) + self.faststr_len(&identifier.name) + self.i32_len(0)
@@ -910,26 +1405,45 @@ macro_rules! skip_stack_pop {
     };
 }
 
-impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
+impl<T: BorrowMut<Bytes>> TInputProtocol for TBinaryUnsafeInputProtocol<'_, T> {
     type Buf = Bytes;
 
     fn read_message_begin(&mut self) -> Result<TMessageIdentifier, ThriftException> {
+        depth::reset(&mut self.depth);
+        allocation::reset(&mut self.allocation);
+        let remaining = self.remaining();
+        if let Some(budget) = &mut self.budget {
+            budget.rearm(remaining);
+        }
+
         let size = self.read_i32()?;
 
+        if size >= 0 && !self.strict_read {
+            super::check_message_size(size as usize, self.max_message_size)?;
+            super::check_string_size(size as usize, self.max_string_size)?;
+            self.advance(self.index);
+            // 1 byte for the message type and 4 for the sequence number
+            if self.remaining() < size as usize + 5 {
+                return Err(new_protocol_exception(
+                    ProtocolExceptionKind::InvalidData,
+                    format!("message name of {size} bytes past the end of the message"),
+                ));
+            }
+            let name = self.trans.borrow_mut().split_to(size as usize);
+            self.buf = unsafe { self.rest() };
+            let name = faststr_from_bytes(name)?;
+            let message_type = message_type_from_u8(self.read_byte()?)?;
+            let sequence_number = self.read_i32()?;
+            self.advance(self.index);
+            return Ok(TMessageIdentifier::new(name, message_type, sequence_number));
+        }
         if size > 0 {
             return Err(new_protocol_exception(
                 ProtocolExceptionKind::BadVersion,
                 "Missing version in ReadMessageBegin".to_string(),
             ));
         }
-        let type_u8 = (size & 0xf) as u8;
-
-        let message_type = TMessageType::try_from(type_u8).map_err(|_| {
-            new_protocol_exception(
-                ProtocolExceptionKind::InvalidData,
-                format!("invalid message type {type_u8}"),
-            )
-        })?;
+        let message_type = message_type_from_u8((size & 0xf) as u8)?;
 
         let version = size & (VERSION_MASK as i32);
         if version != (VERSION_1 as i32) {
@@ -953,11 +1467,14 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
 
     #[inline]
     fn read_struct_begin(&mut self) -> Result<Option<TStructIdentifier>, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
         Ok(None)
     }
 
     #[inline]
     fn read_struct_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
@@ -984,6 +1501,27 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
         Ok(())
     }
 
+    #[inline]
+    fn rejects_duplicate_fields(&self) -> bool {
+        self.reject_duplicate_fields
+    }
+
+    #[inline]
+    fn on_unknown_field(
+        &self,
+        struct_name: &'static str,
+        field: &TFieldIdentifier,
+        type_mismatch: bool,
+    ) {
+        unknown::report(&self.unknown_field_hook, struct_name, field, type_mismatch);
+    }
+
+    #[inline]
+    fn peek_field_begin(&mut self) -> Result<TFieldIdentifier, ThriftException> {
+        let end = self.buf.len().min(self.index + 3);
+        peek_field_header::<false>(&self.buf[self.index..end], None)
+    }
+
     #[inline]
     fn read_bool(&mut self) -> Result<bool, ThriftException> {
         let b = self.read_i8()?;
@@ -995,11 +1533,11 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
 
     #[inline]
     fn read_bytes(&mut self) -> Result<Bytes, ThriftException> {
-        let len = self.read_i32()?;
+        let len = self.read_str_len()?;
         self.advance(self.index);
         // split and freeze it
-        let val = self.trans.split_to(len as usize);
-        self.buf = unsafe { slice::from_raw_parts(self.trans.as_ptr(), self.trans.len()) };
+        let val = self.trans.borrow_mut().split_to(len as usize);
+        self.buf = unsafe { self.rest() };
         Ok(val)
     }
 
@@ -1014,13 +1552,14 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
             self.advance(self.index);
         }
         self.index = 0;
-        let val = self.trans.split_to(len);
-        self.buf = unsafe { slice::from_raw_parts(self.trans.as_ptr(), self.trans.len()) };
+        let val = self.trans.borrow_mut().split_to(len);
+        self.buf = unsafe { self.rest() };
         Ok(val)
     }
 
     #[inline]
     fn read_uuid(&mut self) -> Result<[u8; 16], ThriftException> {
+        check_bounds!(self.buf, self.index, 16);
         let u;
         unsafe {
             u = self
@@ -1035,6 +1574,7 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
 
     #[inline]
     fn read_i8(&mut self) -> Result<i8, ThriftException> {
+        check_bounds!(self.buf, self.index, 1);
         unsafe {
             let val = *self.buf.get_unchecked(self.index) as i8;
             self.index += 1;
@@ -1044,6 +1584,7 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
 
     #[inline]
     fn read_i16(&mut self) -> Result<i16, ThriftException> {
+        check_bounds!(self.buf, self.index, 2);
         unsafe {
             let val = self.buf.get_unchecked(self.index..self.index + 2);
             self.index += 2;
@@ -1053,6 +1594,7 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
 
     #[inline]
     fn read_i32(&mut self) -> Result<i32, ThriftException> {
+        check_bounds!(self.buf, self.index, 4);
         unsafe {
             let val = self.buf.get_unchecked(self.index..self.index + 4);
             self.index += 4;
@@ -1062,6 +1604,7 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
 
     #[inline]
     fn read_i64(&mut self) -> Result<i64, ThriftException> {
+        check_bounds!(self.buf, self.index, 8);
         unsafe {
             let val = self.buf.get_unchecked(self.index..self.index + 8);
             self.index += 8;
@@ -1071,6 +1614,7 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
 
     #[inline]
     fn read_double(&mut self) -> Result<f64, ThriftException> {
+        check_bounds!(self.buf, self.index, 8);
         unsafe {
             let val = self.buf.get_unchecked(self.index..self.index + 8);
             self.index += 8;
@@ -1082,13 +1626,14 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
 
     #[inline]
     fn read_string(&mut self) -> Result<String, ThriftException> {
+        let len = self.read_str_len()?;
         unsafe {
-            let len = self.read_i32().unwrap_unchecked();
-            let val = str::from_utf8_unchecked(
-                self.buf
-                    .get_unchecked(self.index..self.index + len as usize),
-            )
-            .to_string();
+            check_bounds!(self.buf, self.index, len as usize);
+            let bytes = self
+                .buf
+                .get_unchecked(self.index..self.index + len as usize);
+            check_utf8(bytes)?;
+            let val = str::from_utf8_unchecked(bytes).to_string();
             self.index += len as usize;
             Ok(val)
         }
@@ -1096,54 +1641,70 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
 
     #[inline]
     fn read_faststr(&mut self) -> Result<FastStr, ThriftException> {
+        let len = self.read_str_len()? as usize;
         unsafe {
-            let len = self.read_i32().unwrap_unchecked() as usize;
             self.advance(self.index);
-            let bytes = self.trans.split_to(len);
-            self.buf = slice::from_raw_parts(self.trans.as_ptr(), self.trans.len());
+            let bytes = self.trans.borrow_mut().split_to(len);
+            self.buf = self.rest();
+            check_utf8(&bytes)?;
             Ok(FastStr::from_bytes_unchecked(bytes))
         }
     }
 
     #[inline]
     fn read_list_begin(&mut self) -> Result<TListIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
         let element_type: TType = self.read_byte().and_then(|n| Ok(field_type_from_u8(n)?))?;
-        let size = self.read_i32()?;
-        Ok(TListIdentifier::new(element_type, size as usize))
+        let size = self.read_container_size(min_element_len(element_type))?;
+        allocation::charge_elements(&mut self.allocation, size, element_size(element_type))?;
+        Ok(TListIdentifier::new(element_type, size))
     }
 
     #[inline]
     fn read_list_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     fn read_set_begin(&mut self) -> Result<TSetIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
         let element_type: TType = self.read_byte().and_then(|n| Ok(field_type_from_u8(n)?))?;
-        let size = self.read_i32()?;
-        Ok(TSetIdentifier::new(element_type, size as usize))
+        let size = self.read_container_size(min_element_len(element_type))?;
+        allocation::charge_elements(&mut self.allocation, size, element_size(element_type))?;
+        Ok(TSetIdentifier::new(element_type, size))
     }
 
     #[inline]
     fn read_set_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     fn read_map_begin(&mut self) -> Result<TMapIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
         let key_type: TType = self.read_byte().and_then(|n| Ok(field_type_from_u8(n)?))?;
         let value_type: TType = self.read_byte().and_then(|n| Ok(field_type_from_u8(n)?))?;
-        let size = self.read_i32()?;
-        Ok(TMapIdentifier::new(key_type, value_type, size as usize))
+        let size =
+            self.read_container_size(min_element_len(key_type) + min_element_len(value_type))?;
+        let entry_size = element_size(key_type) + element_size(value_type);
+        allocation::charge_elements(&mut self.allocation, size, entry_size)?;
+        Ok(TMapIdentifier::new(key_type, value_type, size))
     }
 
     #[inline]
     fn read_map_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     fn read_byte(&mut self) -> Result<u8, ThriftException> {
+        check_bounds!(self.buf, self.index, 1);
         unsafe {
             let val = *self.buf.get_unchecked(self.index);
             self.index += 1;
@@ -1151,18 +1712,58 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
         }
     }
 
+    #[inline]
+    fn peek_byte(&mut self) -> Result<u8, ThriftException> {
+        assert_remaining!(self.index < self.buf.len());
+        Ok(self.buf[self.index])
+    }
+
     #[inline]
     fn read_bytes_vec(&mut self) -> Result<Vec<u8>, ThriftException> {
-        let len = self.read_i32()? as usize;
+        let len = self.read_str_len()? as usize;
         self.advance(self.index);
-        let val = self.trans.split_to(len).into();
-        self.buf = unsafe { slice::from_raw_parts(self.trans.as_ptr(), self.trans.len()) };
+        let val = self.trans.borrow_mut().split_to(len).into();
+        self.buf = unsafe { self.rest() };
         Ok(val)
     }
 
     #[inline]
     fn buf(&mut self) -> &mut Self::Buf {
-        self.trans
+        self.trans.borrow_mut()
+    }
+
+    fn skip_capture(&mut self, field_type: TType) -> Result<Bytes, ThriftException> {
+        self.advance(self.index);
+        self.buf = unsafe { self.rest() };
+
+        let len = self.skip_till_depth(field_type, crate::thrift::MAXIMUM_SKIP_DEPTH)?;
+        self.index = 0;
+        let val = self.trans.borrow_mut().split_to(len);
+        self.buf = unsafe { self.rest() };
+        Ok(val)
+    }
+
+    fn mark(&mut self) -> Result<InputMark, ThriftException> {
+        let mut input = self.trans.borrow().clone();
+        input.advance(self.index);
+        Ok(InputMark {
+            input,
+            budget: self.budget,
+            depth: self.depth,
+            allocation: self.allocation,
+            ..Default::default()
+        })
+    }
+
+    fn reset_to_mark(&mut self, mark: &InputMark) -> Result<(), ThriftException> {
+        *self.trans.borrow_mut() = mark.input.clone();
+        // SAFETY: `buf` is the input just restored, held by `trans`
+        self.buf = unsafe { self.rest() };
+        self.index = 0;
+        self.budget = mark.budget;
+        self.depth = mark.depth;
+        self.allocation = mark.allocation;
+        Ok(())
     }
 
     #[inline]
@@ -1170,7 +1771,7 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
         debug_assert!(self.index >= FIELD_BEGIN_LEN);
 
         self.advance(self.index - FIELD_BEGIN_LEN);
-        self.buf = unsafe { slice::from_raw_parts(self.trans.as_ptr(), self.trans.len()) };
+        self.buf = unsafe { self.rest() };
 
         self.skip_till_depth(field_type, crate::thrift::MAXIMUM_SKIP_DEPTH)
     }
@@ -1314,6 +1915,7 @@ impl<'a> TInputProtocol for TBinaryUnsafeInputProtocol<'a> {
             };
 
             if stack.is_empty() {
+                check_bounds!(self.buf, self.index, 0);
                 return Ok(len);
             }
 
@@ -0,0 +1,148 @@
+use std::time::{Duration, Instant};
+
+use super::{ThriftException, error::ProtocolExceptionKind, new_protocol_exception};
+
+/// Limits applied while decoding a single message from an input protocol.
+///
+/// The budget is only checked at container boundaries (struct, list, set and
+/// map begin), so a decode may run slightly past the limit before it is
+/// aborted with [`ProtocolExceptionKind::BudgetExceeded`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Budget {
+    /// Maximum number of bytes the decode may consume.
+    pub max_bytes: Option<usize>,
+    /// Maximum wall-clock time the decode may take.
+    pub max_duration: Option<Duration>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct BudgetTracker {
+    budget: Budget,
+    start: Option<Instant>,
+    start_remaining: usize,
+}
+
+impl BudgetTracker {
+    #[inline]
+    pub(crate) fn new(budget: Budget, remaining: usize) -> Self {
+        Self {
+            budget,
+            start: budget.max_duration.map(|_| Instant::now()),
+            start_remaining: remaining,
+        }
+    }
+
+    /// Restarts the budget at the current position, used when a new message
+    /// begins on the same protocol.
+    #[inline]
+    pub(crate) fn rearm(&mut self, remaining: usize) {
+        *self = Self::new(self.budget, remaining);
+    }
+
+    #[inline]
+    pub(crate) fn check(&self, remaining: usize) -> Result<(), ThriftException> {
+        if let Some(max_bytes) = self.budget.max_bytes {
+            let consumed = self.start_remaining.saturating_sub(remaining);
+            if consumed > max_bytes {
+                return Err(new_protocol_exception(
+                    ProtocolExceptionKind::BudgetExceeded,
+                    format!("consumed {consumed} bytes, budget is {max_bytes} bytes"),
+                ));
+            }
+        }
+        if let (Some(max_duration), Some(start)) = (self.budget.max_duration, self.start) {
+            let elapsed = start.elapsed();
+            if elapsed > max_duration {
+                return Err(new_protocol_exception(
+                    ProtocolExceptionKind::BudgetExceeded,
+                    format!("decoding took {elapsed:?}, budget is {max_duration:?}"),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bytes::{BufMut, Bytes, BytesMut};
+
+    use super::Budget;
+    use crate::thrift::{
+        ProtocolExceptionKind, TInputProtocol, TListIdentifier, TOutputProtocol, TType,
+        ThriftException, binary::TBinaryProtocol, compact::TCompactInputProtocol,
+    };
+
+    fn encode_list(len: usize) -> Bytes {
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, false);
+        protocol
+            .write_list_begin(TListIdentifier::new(TType::I64, len))
+            .unwrap();
+        for i in 0..len {
+            protocol.write_i64(i as i64).unwrap();
+        }
+        protocol.write_list_end().unwrap();
+        protocol
+            .write_list_begin(TListIdentifier::new(TType::I64, 0))
+            .unwrap();
+        buf.freeze()
+    }
+
+    fn budget_kind(err: ThriftException) -> ProtocolExceptionKind {
+        match err {
+            ThriftException::Protocol(e) => e.kind(),
+            e => panic!("unexpected error {e:?}"),
+        }
+    }
+
+    #[test]
+    fn test_max_bytes() {
+        let mut bytes = encode_list(16);
+        let mut protocol = TBinaryProtocol::new(&mut bytes, false);
+        protocol.set_budget(Budget {
+            max_bytes: Some(64),
+            max_duration: None,
+        });
+
+        let ident = protocol.read_list_begin().unwrap();
+        for _ in 0..ident.size {
+            protocol.read_i64().unwrap();
+        }
+        let err = protocol.read_list_begin().unwrap_err();
+        assert_eq!(budget_kind(err), ProtocolExceptionKind::BudgetExceeded);
+    }
+
+    #[test]
+    fn test_max_duration() {
+        let mut bytes = encode_list(1);
+        let mut protocol = TBinaryProtocol::new(&mut bytes, false);
+        protocol.set_budget(Budget {
+            max_bytes: None,
+            max_duration: Some(Duration::ZERO),
+        });
+
+        std::thread::sleep(Duration::from_millis(1));
+        let err = protocol.read_list_begin().unwrap_err();
+        assert_eq!(budget_kind(err), ProtocolExceptionKind::BudgetExceeded);
+    }
+
+    #[test]
+    fn test_within_budget() {
+        let mut buf = BytesMut::new();
+        // two empty compact i32 lists
+        buf.put_u8(0x05);
+        buf.put_u8(0x05);
+        let mut bytes = buf.freeze();
+        let mut protocol = TCompactInputProtocol::new(&mut bytes);
+        protocol.set_budget(Budget {
+            max_bytes: Some(2),
+            max_duration: Some(Duration::from_secs(60)),
+        });
+
+        protocol.read_list_begin().unwrap();
+        protocol.read_list_begin().unwrap();
+    }
+}
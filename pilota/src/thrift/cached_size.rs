@@ -0,0 +1,275 @@
+//! Sizes of structs reused within an encode cycle.
+//!
+//! The structs generated with the `cached_size` option of pilota-build keep
+//! the size their `size` computes in a [`CachedSize`], which the later calls
+//! of the same cycle return instead of walking the fields again, so sizing a
+//! message once more, or a struct nested in it, costs nothing.
+//!
+//! A cycle lasts for the closure given to [`cycle`] on the current thread,
+//! outside of which every size is computed. The sizes of a cycle are those of
+//! the first length protocol they were computed with, a cycle is meant to
+//! size with a single protocol:
+//!
+//! ```ignore
+//! let size = pilota::thrift::cached_size::cycle(|| {
+//!     let size = message.size(&mut TBinaryProtocol::new((), false));
+//!     // computed at once from the sizes cached by the first pass
+//!     assert_eq!(message.size(&mut TBinaryProtocol::new((), false)), size);
+//!     size
+//! });
+//! ```
+
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    sync::atomic::{self, AtomicU64, AtomicUsize},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::IgnoredAny};
+
+use super::TLengthProtocol;
+
+thread_local! {
+    static CYCLE: Cell<u64> = const { Cell::new(NO_CYCLE) };
+}
+
+static NEXT_CYCLE: AtomicU64 = AtomicU64::new(1);
+
+const NO_CYCLE: u64 = 0;
+// held by the cell while its size is stored
+const STORING: u64 = u64::MAX;
+
+/// Runs `f` in a new encode cycle, the sizes cached during it are only
+/// reused by the calls within `f`.
+pub fn cycle<R>(f: impl FnOnce() -> R) -> R {
+    struct Restore(u64);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CYCLE.with(|c| c.set(self.0));
+        }
+    }
+
+    let id = NEXT_CYCLE.fetch_add(1, atomic::Ordering::Relaxed);
+    let _restore = Restore(CYCLE.with(|c| c.replace(id)));
+    f()
+}
+
+/// The size of a struct in the encode cycle it was computed in, see the
+/// [module](self) docs.
+///
+/// Being a cache, it is left out of the comparisons, hashes and serialized
+/// forms of the struct, and a clone starts empty.
+pub struct CachedSize {
+    cycle: AtomicU64,
+    size: AtomicUsize,
+}
+
+impl CachedSize {
+    pub const fn new() -> Self {
+        Self {
+            cycle: AtomicU64::new(NO_CYCLE),
+            size: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the size cached in the current cycle, or computes it with
+    /// `size` and caches it.
+    ///
+    /// The sizes of structs with binaries or strings counted in
+    /// [`TLengthProtocol::zero_copy_len`] are not cached, as a cached size
+    /// would not count them again.
+    #[doc(hidden)]
+    #[inline]
+    pub fn size_with<T: TLengthProtocol>(
+        &self,
+        protocol: &mut T,
+        size: impl FnOnce(&mut T) -> usize,
+    ) -> usize {
+        let cycle = CYCLE.with(Cell::get);
+        if cycle == NO_CYCLE {
+            return size(protocol);
+        }
+        if let Some(size) = self.get(cycle) {
+            return size;
+        }
+        let zero_copy_len = protocol.zero_copy_len();
+        let size = size(protocol);
+        if protocol.zero_copy_len() == zero_copy_len {
+            self.set(cycle, size);
+        }
+        size
+    }
+
+    // The cycle and size are a seqlock, read as the cycle, the size and the
+    // cycle again, and dropped when the cycles differ as another thread
+    // stored a size in between.
+    #[inline]
+    fn get(&self, cycle: u64) -> Option<usize> {
+        if self.cycle.load(atomic::Ordering::Acquire) != cycle {
+            return None;
+        }
+        let size = self.size.load(atomic::Ordering::Relaxed);
+        atomic::fence(atomic::Ordering::Acquire);
+        (self.cycle.load(atomic::Ordering::Relaxed) == cycle).then_some(size)
+    }
+
+    #[inline]
+    fn set(&self, cycle: u64, size: usize) {
+        let current = self.cycle.load(atomic::Ordering::Relaxed);
+        if current == STORING
+            || self
+                .cycle
+                .compare_exchange(
+                    current,
+                    STORING,
+                    atomic::Ordering::Relaxed,
+                    atomic::Ordering::Relaxed,
+                )
+                .is_err()
+        {
+            // another thread is storing its size, which this one can do
+            // without
+            return;
+        }
+        atomic::fence(atomic::Ordering::Release);
+        self.size.store(size, atomic::Ordering::Relaxed);
+        self.cycle.store(cycle, atomic::Ordering::Release);
+    }
+}
+
+impl Default for CachedSize {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for CachedSize {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for CachedSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CachedSize")
+    }
+}
+
+impl PartialEq for CachedSize {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for CachedSize {}
+
+impl PartialOrd for CachedSize {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CachedSize {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for CachedSize {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl Serialize for CachedSize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_none()
+    }
+}
+
+impl<'de> Deserialize<'de> for CachedSize {
+    // as an option, for the field to be left out of the input
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<IgnoredAny>::deserialize(deserializer).map(|_| Self::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::{CachedSize, cycle};
+    use crate::thrift::{
+        Message, TAsyncInputProtocol, TInputProtocol, TLengthProtocol, TOutputProtocol,
+        ThriftException, binary::TBinaryProtocol,
+    };
+
+    struct Counted {
+        value: i64,
+        computed: AtomicUsize,
+        _cached_size: CachedSize,
+    }
+
+    impl Message for Counted {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_i64(self.value)
+        }
+
+        fn decode<T: TInputProtocol>(_protocol: &mut T) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(
+            _protocol: &mut T,
+        ) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+            self._cached_size.size_with(protocol, |protocol| {
+                self.computed.fetch_add(1, Ordering::Relaxed);
+                protocol.i64_len(self.value)
+            })
+        }
+    }
+
+    #[test]
+    fn test_cached_size() {
+        let counted = Counted {
+            value: 7,
+            computed: AtomicUsize::new(0),
+            _cached_size: CachedSize::new(),
+        };
+        let size = |c: &Counted| c.size(&mut TBinaryProtocol::new((), false));
+        let computed = |c: &Counted| c.computed.load(Ordering::Relaxed);
+
+        assert_eq!(size(&counted), 8);
+        assert_eq!(size(&counted), 8);
+        assert_eq!(computed(&counted), 2);
+
+        cycle(|| {
+            assert_eq!(size(&counted), 8);
+            assert_eq!(size(&counted), 8);
+        });
+        assert_eq!(computed(&counted), 3);
+
+        // a new cycle computes again, and nothing is reused outside of them
+        cycle(|| size(&counted));
+        size(&counted);
+        assert_eq!(computed(&counted), 5);
+    }
+
+    #[test]
+    fn test_serde() {
+        assert_eq!(serde_json::to_string(&CachedSize::new()).unwrap(), "null");
+        serde_json::from_str::<CachedSize>("null").unwrap();
+        serde_json::from_str::<CachedSize>("42").unwrap();
+
+        #[derive(serde::Deserialize)]
+        struct Tagged {
+            _cached_size: CachedSize,
+        }
+        serde_json::from_str::<Tagged>("{}").unwrap();
+    }
+}
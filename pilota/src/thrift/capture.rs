@@ -0,0 +1,39 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::BytesMut;
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// Reads from `R`, keeping a copy of everything read, for the async
+/// protocols' `skip_capture`.
+pub(crate) struct CaptureReader<R> {
+    reader: R,
+    pub(crate) captured: BytesMut,
+}
+
+impl<R> CaptureReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            captured: BytesMut::new(),
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for CaptureReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        let filled = buf.filled().len();
+        let poll = Pin::new(&mut this.reader).poll_read(cx, buf);
+        if let Poll::Ready(Ok(())) = poll {
+            this.captured.extend_from_slice(&buf.filled()[filled..]);
+        }
+        poll
+    }
+}
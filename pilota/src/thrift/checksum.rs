@@ -0,0 +1,69 @@
+//! Integrity checks of single binary fields.
+//!
+//! Fields annotated with `(pilota.checksum = "crc32c")` carry the CRC32C of
+//! their value, appended as 4 big-endian bytes by the generated encoder and
+//! verified then stripped by the generated decoder.
+
+use bytes::{BufMut, Bytes, BytesMut};
+
+use super::{ProtocolExceptionKind, ThriftException, new_protocol_exception};
+
+const CRC_LEN: usize = 4;
+
+/// The CRC32C (Castagnoli) of `data`.
+#[inline]
+pub fn crc32c(data: &[u8]) -> u32 {
+    crate::simd::crc32c(data)
+}
+
+/// `data` followed by its CRC32C.
+pub fn append_crc32c(data: &[u8]) -> Bytes {
+    let mut buf = BytesMut::with_capacity(data.len() + CRC_LEN);
+    buf.put_slice(data);
+    buf.put_u32(crc32c(data));
+    buf.freeze()
+}
+
+/// Checks the CRC32C appended by [`append_crc32c`] and returns the value
+/// without it.
+pub fn verify_crc32c(data: Bytes) -> Result<Bytes, ThriftException> {
+    let Some(len) = data.len().checked_sub(CRC_LEN) else {
+        return Err(new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            format!(
+                "checksummed field of {} bytes misses its crc32c",
+                data.len()
+            ),
+        ));
+    };
+    let expected = u32::from_be_bytes(data[len..].try_into().unwrap());
+    let actual = crc32c(&data[..len]);
+    if actual != expected {
+        return Err(new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            format!("crc32c mismatch: expected {expected:#010x}, got {actual:#010x}"),
+        ));
+    }
+    Ok(data.slice(..len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32c() {
+        // check values from RFC 3720, B.4
+        assert_eq!(crc32c(&[0; 32]), 0x8a91_36aa);
+        assert_eq!(crc32c(&[0xff; 32]), 0x62a8_ab43);
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+
+        let checked = append_crc32c(b"pilota");
+        assert_eq!(&verify_crc32c(checked.clone()).unwrap()[..], b"pilota");
+
+        let mut corrupted = BytesMut::from(&checked[..]);
+        corrupted[0] ^= 1;
+        assert!(verify_crc32c(corrupted.freeze()).is_err());
+        assert!(verify_crc32c(Bytes::from_static(b"abc")).is_err());
+    }
+}
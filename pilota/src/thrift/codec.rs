@@ -0,0 +1,556 @@
+//! [`tokio_util::codec`] adapters for framed thrift messages.
+//!
+//! Every frame is a 4-byte big-endian length followed by one message encoded
+//! with the binary or compact protocol, compatible with the thrift framed
+//! transport. With these a `TcpStream` can be turned into a stream of
+//! messages with `Framed::new(stream, FramedBinaryCodec::<MyStruct>::new())`.
+
+use std::{
+    marker::PhantomData,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+pub use super::frame::DEFAULT_MAX_FRAME_SIZE;
+use super::{
+    Message, ProtocolExceptionKind, TMessageIdentifier, ThriftException,
+    binary::TBinaryProtocol,
+    compact::{TCompactInputProtocol, TCompactOutputProtocol},
+    frame::{FRAME_HEADER_LEN, read_message_header},
+    new_protocol_exception,
+};
+
+/// The protocol used to encode the payload of a frame.
+pub trait FrameProtocol {
+    fn encode<M: Message>(msg: &M, dst: &mut BytesMut) -> Result<(), ThriftException>;
+
+    fn decode<M: Message>(src: &mut Bytes) -> Result<M, ThriftException>;
+}
+
+/// Frames encoded with [`TBinaryProtocol`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Binary;
+
+impl FrameProtocol for Binary {
+    #[inline]
+    fn encode<M: Message>(msg: &M, dst: &mut BytesMut) -> Result<(), ThriftException> {
+        msg.encode(&mut TBinaryProtocol::new(dst, false))
+    }
+
+    #[inline]
+    fn decode<M: Message>(src: &mut Bytes) -> Result<M, ThriftException> {
+        M::decode(&mut TBinaryProtocol::new(src, true))
+    }
+}
+
+/// Frames encoded with the compact protocol.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Compact;
+
+impl FrameProtocol for Compact {
+    #[inline]
+    fn encode<M: Message>(msg: &M, dst: &mut BytesMut) -> Result<(), ThriftException> {
+        msg.encode(&mut TCompactOutputProtocol::new(dst, false))
+    }
+
+    #[inline]
+    fn decode<M: Message>(src: &mut Bytes) -> Result<M, ThriftException> {
+        M::decode(&mut TCompactInputProtocol::new(src))
+    }
+}
+
+/// What the decoder does with the bytes of a frame left after its message,
+/// usually the sign of a protocol mismatch between the peers.
+#[derive(Clone, Default)]
+pub enum TrailingBytes {
+    /// Drops them.
+    #[default]
+    Ignore,
+    /// Fails the frame with [`ProtocolExceptionKind::InvalidData`].
+    Error,
+    /// Calls the function with their count, then drops them.
+    Warn(Arc<dyn Fn(usize) + Send + Sync>),
+}
+
+impl std::fmt::Debug for TrailingBytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrailingBytes::Ignore => f.write_str("Ignore"),
+            TrailingBytes::Error => f.write_str("Error"),
+            TrailingBytes::Warn(_) => f.write_str("Warn(..)"),
+        }
+    }
+}
+
+/// A frame the decoder failed to decode, as passed to a [`DecodeErrorHook`].
+#[derive(Debug)]
+pub struct DecodeFailure<'a> {
+    pub error: &'a ThriftException,
+    /// The message header the payload starts with, if it has a readable one.
+    pub message: Option<TMessageIdentifier>,
+    /// The payload of the frame, cut to the hook's maximum payload length.
+    pub payload: Bytes,
+    /// The length of the whole payload.
+    pub payload_len: usize,
+}
+
+/// The default cap of [`DecodeFailure::payload`].
+pub const DEFAULT_MAX_SAMPLE_LEN: usize = 4096;
+
+/// Called by the decoder with the frames whose message fails to decode, to
+/// keep samples of the malformed payloads without wrapping every decode.
+///
+/// ```
+/// use pilota::thrift::codec::{DecodeErrorHook, FramedBinaryCodec};
+/// # use pilota::thrift::ApplicationException as MyStruct;
+///
+/// let codec = FramedBinaryCodec::<MyStruct>::new().with_decode_error_hook(
+///     DecodeErrorHook::new(|failure| {
+///         eprintln!("{}: {:?}", failure.error, &failure.payload[..]);
+///     })
+///     .with_sample_every(100),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct DecodeErrorHook {
+    hook: Arc<dyn Fn(DecodeFailure<'_>) + Send + Sync>,
+    max_payload_len: usize,
+    every: u64,
+    failures: Arc<AtomicU64>,
+}
+
+impl DecodeErrorHook {
+    pub fn new(hook: impl Fn(DecodeFailure<'_>) + Send + Sync + 'static) -> Self {
+        Self {
+            hook: Arc::new(hook),
+            max_payload_len: DEFAULT_MAX_SAMPLE_LEN,
+            every: 1,
+            failures: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Sets how many bytes of the payload are passed to the hook,
+    /// [`DEFAULT_MAX_SAMPLE_LEN`] by default.
+    pub fn with_max_payload_len(mut self, max_payload_len: usize) -> Self {
+        self.max_payload_len = max_payload_len;
+        self
+    }
+
+    /// Calls the hook for one failure out of `every`, starting with the
+    /// first. The count is shared by the clones of the hook.
+    pub fn with_sample_every(mut self, every: u64) -> Self {
+        self.every = every.max(1);
+        self
+    }
+
+    fn report(&self, error: &ThriftException, payload: &Bytes) {
+        if self.failures.fetch_add(1, Ordering::Relaxed) % self.every != 0 {
+            return;
+        }
+        (self.hook)(DecodeFailure {
+            error,
+            message: read_message_header(payload).ok(),
+            payload: payload.slice(..payload.len().min(self.max_payload_len)),
+            payload_len: payload.len(),
+        });
+    }
+}
+
+impl std::fmt::Debug for DecodeErrorHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DecodeErrorHook")
+            .field("max_payload_len", &self.max_payload_len)
+            .field("every", &self.every)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A length-delimited codec of `M` encoded with the protocol `P`.
+pub struct FramedCodec<M, P> {
+    max_frame_size: usize,
+    trailing_bytes: TrailingBytes,
+    decode_error_hook: Option<DecodeErrorHook>,
+    bytes_consumed: usize,
+    _marker: PhantomData<fn() -> (M, P)>,
+}
+
+pub type FramedBinaryCodec<M> = FramedCodec<M, Binary>;
+pub type FramedCompactCodec<M> = FramedCodec<M, Compact>;
+
+impl<M, P> FramedCodec<M, P> {
+    pub fn new() -> Self {
+        Self {
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            trailing_bytes: TrailingBytes::default(),
+            decode_error_hook: None,
+            bytes_consumed: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sets the largest frame accepted by the decoder and produced by the
+    /// encoder. Larger frames fail to decode with
+    /// [`ProtocolExceptionKind::MessageTooLarge`], before their payload is
+    /// buffered, and to encode with [`ProtocolExceptionKind::SizeLimit`].
+    pub fn with_max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.max_frame_size = max_frame_size;
+        self
+    }
+
+    pub fn max_frame_size(&self) -> usize {
+        self.max_frame_size
+    }
+
+    /// Sets what the decoder does with the bytes of a frame not read by its
+    /// message, dropped by default.
+    pub fn with_trailing_bytes(mut self, trailing_bytes: TrailingBytes) -> Self {
+        self.trailing_bytes = trailing_bytes;
+        self
+    }
+
+    /// Sets the hook called with the frames failing to decode, including
+    /// those failing on trailing bytes.
+    pub fn with_decode_error_hook(mut self, hook: DecodeErrorHook) -> Self {
+        self.decode_error_hook = Some(hook);
+        self
+    }
+
+    /// The bytes read by the last decoded message, the frame header excluded.
+    /// Less than the frame size when the frame had trailing bytes.
+    pub fn bytes_consumed(&self) -> usize {
+        self.bytes_consumed
+    }
+
+    fn size_limit(&self, kind: ProtocolExceptionKind, size: usize) -> ThriftException {
+        new_protocol_exception(
+            kind,
+            format!(
+                "frame size {size} exceeds max frame size {}",
+                self.max_frame_size
+            ),
+        )
+    }
+}
+
+impl<M, P> Default for FramedCodec<M, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M, P> Clone for FramedCodec<M, P> {
+    fn clone(&self) -> Self {
+        Self {
+            max_frame_size: self.max_frame_size,
+            trailing_bytes: self.trailing_bytes.clone(),
+            decode_error_hook: self.decode_error_hook.clone(),
+            bytes_consumed: self.bytes_consumed,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<M, P> std::fmt::Debug for FramedCodec<M, P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FramedCodec")
+            .field("max_frame_size", &self.max_frame_size)
+            .field("trailing_bytes", &self.trailing_bytes)
+            .field("decode_error_hook", &self.decode_error_hook)
+            .field("bytes_consumed", &self.bytes_consumed)
+            .finish()
+    }
+}
+
+impl<M: Message, P: FrameProtocol> Decoder for FramedCodec<M, P> {
+    type Item = M;
+    type Error = ThriftException;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<M>, ThriftException> {
+        if src.len() < FRAME_HEADER_LEN {
+            return Ok(None);
+        }
+        let size = u32::from_be_bytes(src[..FRAME_HEADER_LEN].try_into().unwrap()) as usize;
+        if size > self.max_frame_size {
+            return Err(self.size_limit(ProtocolExceptionKind::MessageTooLarge, size));
+        }
+        if src.len() < FRAME_HEADER_LEN + size {
+            src.reserve(FRAME_HEADER_LEN + size - src.len());
+            return Ok(None);
+        }
+
+        src.advance(FRAME_HEADER_LEN);
+        let payload = src.split_to(size).freeze();
+        match self.decode_payload(payload.clone()) {
+            Ok(msg) => Ok(Some(msg)),
+            Err(e) => {
+                if let Some(hook) = &self.decode_error_hook {
+                    hook.report(&e, &payload);
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+impl<M: Message, P: FrameProtocol> FramedCodec<M, P> {
+    fn decode_payload(&mut self, mut frame: Bytes) -> Result<M, ThriftException> {
+        let size = frame.len();
+        let msg = P::decode(&mut frame)?;
+        self.bytes_consumed = size - frame.len();
+
+        if !frame.is_empty() {
+            match &self.trailing_bytes {
+                TrailingBytes::Ignore => {}
+                TrailingBytes::Error => {
+                    return Err(new_protocol_exception(
+                        ProtocolExceptionKind::InvalidData,
+                        format!(
+                            "{} trailing bytes after the message in a frame of {size} bytes",
+                            frame.len()
+                        ),
+                    ));
+                }
+                TrailingBytes::Warn(warn) => warn(frame.len()),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<M: Message, P: FrameProtocol> Encoder<&M> for FramedCodec<M, P> {
+    type Error = ThriftException;
+
+    fn encode(&mut self, msg: &M, dst: &mut BytesMut) -> Result<(), ThriftException> {
+        let start = dst.len();
+        dst.put_u32(0);
+        P::encode(msg, dst)?;
+
+        let size = dst.len() - start - FRAME_HEADER_LEN;
+        if size > self.max_frame_size {
+            dst.truncate(start);
+            return Err(self.size_limit(ProtocolExceptionKind::SizeLimit, size));
+        }
+        dst[start..start + FRAME_HEADER_LEN].copy_from_slice(&(size as u32).to_be_bytes());
+        Ok(())
+    }
+}
+
+impl<M: Message, P: FrameProtocol> Encoder<M> for FramedCodec<M, P> {
+    type Error = ThriftException;
+
+    #[inline]
+    fn encode(&mut self, msg: M, dst: &mut BytesMut) -> Result<(), ThriftException> {
+        Encoder::<&M>::encode(self, &msg, dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use tokio_util::codec::{Decoder, Encoder};
+
+    use super::{FramedBinaryCodec, FramedCompactCodec};
+    use crate::thrift::{
+        Message, ProtocolExceptionKind, TAsyncInputProtocol, TInputProtocol, TLengthProtocol,
+        TOutputProtocol, TType, ThriftException, VOID_IDENT,
+    };
+
+    #[derive(Debug, PartialEq)]
+    struct Ping {
+        seq: i32,
+    }
+
+    impl Message for Ping {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_struct_begin(&VOID_IDENT)?;
+            protocol.write_field_begin(TType::I32, 1)?;
+            protocol.write_i32(self.seq)?;
+            protocol.write_field_end()?;
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()
+        }
+
+        fn decode<T: TInputProtocol>(protocol: &mut T) -> Result<Self, ThriftException> {
+            let mut seq = 0;
+            protocol.read_struct_begin()?;
+            loop {
+                let field_ident = protocol.read_field_begin()?;
+                match (field_ident.field_type, field_ident.id) {
+                    (TType::Stop, _) => break,
+                    (TType::I32, Some(1)) => seq = protocol.read_i32()?,
+                    (ttype, _) => {
+                        protocol.skip(ttype)?;
+                    }
+                }
+                protocol.read_field_end()?;
+            }
+            protocol.read_struct_end()?;
+            Ok(Ping { seq })
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(
+            _protocol: &mut T,
+        ) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+            protocol.struct_begin_len(&VOID_IDENT)
+                + protocol.field_begin_len(TType::I32, Some(1))
+                + protocol.i32_len(self.seq)
+                + protocol.field_end_len()
+                + protocol.field_stop_len()
+                + protocol.struct_end_len()
+        }
+    }
+
+    fn assert_roundtrip<C>(mut codec: C)
+    where
+        C: Encoder<Ping, Error = ThriftException> + Decoder<Item = Ping, Error = ThriftException>,
+    {
+        let mut buf = BytesMut::new();
+        codec.encode(Ping { seq: 1 }, &mut buf).unwrap();
+        codec.encode(Ping { seq: 2 }, &mut buf).unwrap();
+
+        let rest = buf.split_off(6);
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+        buf.unsplit(rest);
+
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(Ping { seq: 1 }));
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(Ping { seq: 2 }));
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+        assert!(buf.is_empty());
+    }
+
+    fn assert_size_limit<T>(r: Result<T, ThriftException>, kind: ProtocolExceptionKind) {
+        match r {
+            Err(ThriftException::Protocol(e)) => assert_eq!(e.kind(), kind),
+            Err(e) => panic!("unexpected error {e:?}"),
+            Ok(_) => panic!("expected size limit error"),
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        assert_roundtrip(FramedBinaryCodec::<Ping>::new());
+        assert_roundtrip(FramedCompactCodec::<Ping>::new());
+    }
+
+    #[test]
+    fn test_max_frame_size() {
+        let mut codec = FramedBinaryCodec::<Ping>::new().with_max_frame_size(4);
+        let mut buf = BytesMut::new();
+        assert_size_limit(
+            codec.encode(&Ping { seq: 1 }, &mut buf),
+            ProtocolExceptionKind::SizeLimit,
+        );
+        assert!(buf.is_empty());
+
+        buf.extend_from_slice(&[0, 0, 0, 5]);
+        assert_size_limit(
+            codec.decode(&mut buf),
+            ProtocolExceptionKind::MessageTooLarge,
+        );
+    }
+
+    #[test]
+    fn test_trailing_bytes() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        use super::TrailingBytes;
+
+        // a frame of one ping followed by two bytes
+        let mut frame = BytesMut::new();
+        FramedBinaryCodec::<Ping>::new()
+            .encode(Ping { seq: 1 }, &mut frame)
+            .unwrap();
+        let size = frame.len() - 4;
+        frame[..4].copy_from_slice(&(size as u32 + 2).to_be_bytes());
+        frame.extend_from_slice(&[0, 0]);
+
+        let mut codec = FramedBinaryCodec::<Ping>::new();
+        let mut buf = frame.clone();
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(Ping { seq: 1 }));
+        assert_eq!(codec.bytes_consumed(), size);
+        assert!(buf.is_empty());
+
+        let trailing = Arc::new(AtomicUsize::new(0));
+        let mut codec =
+            FramedBinaryCodec::<Ping>::new().with_trailing_bytes(TrailingBytes::Warn({
+                let trailing = trailing.clone();
+                Arc::new(move |n| trailing.store(n, Ordering::Relaxed))
+            }));
+        let mut buf = frame.clone();
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(Ping { seq: 1 }));
+        assert_eq!(trailing.load(Ordering::Relaxed), 2);
+
+        let mut codec = FramedBinaryCodec::<Ping>::new().with_trailing_bytes(TrailingBytes::Error);
+        let mut buf = frame;
+        match codec.decode(&mut buf) {
+            Err(ThriftException::Protocol(e)) => {
+                assert_eq!(e.kind(), ProtocolExceptionKind::InvalidData)
+            }
+            r => panic!("expected invalid data, got {r:?}"),
+        }
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_decode_error_hook() {
+        use std::sync::{Arc, Mutex};
+
+        use super::DecodeErrorHook;
+        use crate::thrift::{
+            TMessageIdentifier, TMessageType, TOutputProtocol, binary::TBinaryProtocol,
+        };
+
+        // a message header where the ping is expected, its first byte not
+        // being a field type
+        let ident = TMessageIdentifier::new("ping".into(), TMessageType::Call, 7);
+        let mut payload = BytesMut::new();
+        TBinaryProtocol::new(&mut payload, false)
+            .write_message_begin(&ident)
+            .unwrap();
+        let mut frame = BytesMut::new();
+        frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&payload);
+
+        let failures = Arc::new(Mutex::new(Vec::new()));
+        let mut codec = FramedBinaryCodec::<Ping>::new().with_decode_error_hook(
+            DecodeErrorHook::new({
+                let failures = failures.clone();
+                move |failure| {
+                    assert!(matches!(failure.error, ThriftException::Protocol(_)));
+                    failures.lock().unwrap().push((
+                        failure.message,
+                        failure.payload,
+                        failure.payload_len,
+                    ));
+                }
+            })
+            .with_max_payload_len(4)
+            .with_sample_every(2),
+        );
+        for _ in 0..3 {
+            let mut buf = frame.clone();
+            assert!(codec.decode(&mut buf).is_err());
+            assert!(buf.is_empty());
+        }
+
+        let failures = failures.lock().unwrap();
+        assert_eq!(failures.len(), 2);
+        for (message, sample, len) in failures.iter() {
+            assert_eq!(message.as_ref(), Some(&ident));
+            assert_eq!(&sample[..], &payload[..4]);
+            assert_eq!(*len, payload.len());
+        }
+    }
+}
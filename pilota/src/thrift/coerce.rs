@@ -0,0 +1,81 @@
+//! Lenient decoding of integer fields changed between `i32` and `i64`.
+//!
+//! Code generated with `lenient_numeric_decode` reads an `i32` field sent as
+//! an `i64` with [`narrow`] and an `i64` field sent as an `i32` with
+//! [`widen`], so that a field can be widened on one side of a connection
+//! before the other. Every coercion is counted process-wide, see
+//! [`coercions`], to know when the migration is over.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::{ProtocolExceptionKind, ThriftException, new_protocol_exception};
+
+static WIDENED: AtomicU64 = AtomicU64::new(0);
+static NARROWED: AtomicU64 = AtomicU64::new(0);
+static OUT_OF_RANGE: AtomicU64 = AtomicU64::new(0);
+
+/// Counts of the values coerced by the process since it started.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Coercions {
+    /// `i32` values read into `i64` fields.
+    pub widened: u64,
+    /// `i64` values read into `i32` fields.
+    pub narrowed: u64,
+    /// `i64` values too large for their `i32` field, failed.
+    pub out_of_range: u64,
+}
+
+pub fn coercions() -> Coercions {
+    Coercions {
+        widened: WIDENED.load(Ordering::Relaxed),
+        narrowed: NARROWED.load(Ordering::Relaxed),
+        out_of_range: OUT_OF_RANGE.load(Ordering::Relaxed),
+    }
+}
+
+/// Reads an `i32` sent for an `i64` field.
+#[doc(hidden)]
+#[inline]
+pub fn widen(value: i32) -> i64 {
+    WIDENED.fetch_add(1, Ordering::Relaxed);
+    value as i64
+}
+
+/// Reads an `i64` sent for an `i32` field, failing with
+/// [`ProtocolExceptionKind::InvalidData`] if it doesn't fit.
+#[doc(hidden)]
+#[inline]
+pub fn narrow(value: i64) -> Result<i32, ThriftException> {
+    match i32::try_from(value) {
+        Ok(value) => {
+            NARROWED.fetch_add(1, Ordering::Relaxed);
+            Ok(value)
+        }
+        Err(_) => {
+            OUT_OF_RANGE.fetch_add(1, Ordering::Relaxed);
+            Err(new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                format!("i64 value {value} out of the range of its i32 field"),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{coercions, narrow, widen};
+
+    #[test]
+    fn test_coerce() {
+        let before = coercions();
+        assert_eq!(widen(-7), -7);
+        assert_eq!(narrow(i32::MIN as i64).unwrap(), i32::MIN);
+        assert!(narrow(i32::MAX as i64 + 1).is_err());
+
+        // other tests may coerce concurrently
+        let after = coercions();
+        assert!(after.widened > before.widened);
+        assert!(after.narrowed > before.narrowed);
+        assert!(after.out_of_range > before.out_of_range);
+    }
+}
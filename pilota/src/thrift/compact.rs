@@ -2,21 +2,30 @@
 //
 // https://github.com/apache/thrift/blob/ec5e17714a1f9da34173749fc01eea33c7f6af62/lib/rs/src/protocol/compact.rs
 
-use std::str;
+use std::{str, sync::Arc};
 
-use bytes::{Bytes, BytesMut};
+use bytes::{BufMut, Bytes};
 use faststr::FastStr;
 use integer_encoding::VarInt;
 use linkedbytes::LinkedBytes;
 use tokio::io::{AsyncRead, AsyncReadExt};
 
 use super::{
-    ProtocolException, TAsyncInputProtocol, TFieldIdentifier, TInputProtocol, TLengthProtocol,
-    TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType, TOutputProtocol,
-    TSetIdentifier, TStructIdentifier, TType, ThriftException, ZERO_COPY_THRESHOLD,
+    MAXIMUM_SKIP_DEPTH, ProtocolException, TAsyncInputProtocol, TFieldIdentifier, TInputProtocol,
+    TLengthProtocol, TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType,
+    TOutputProtocol, TSetIdentifier, TStructIdentifier, TType, ThriftException,
+    ZERO_COPY_THRESHOLD,
+    allocation::{self, AllocationTracker, element_size},
+    budget::{Budget, BudgetTracker},
+    canonicalize_nan,
+    capture::CaptureReader,
+    depth::{self, DepthTracker},
     error::ProtocolExceptionKind,
+    mark::InputMark,
     new_protocol_exception,
-    rw_ext::{ReadExt, WriteExt},
+    recovery::{Recovery, TTypeRecovery},
+    rw_ext::{IOError, ReadExt, WriteExt, faststr_from_bytes, string_from_utf8},
+    unknown::{self, UnknownField, UnknownFieldHook},
     varint_ext::VarIntProcessor,
 };
 
@@ -117,12 +126,73 @@ impl TryFrom<TCompactType> for TType {
     }
 }
 
+/// Decodes the field header at the start of `header`, the next bytes of the
+/// input, the same as `read_field_begin` reads it after the field
+/// `last_read_field_id`.
+pub(crate) fn peek_field_header(
+    header: &[u8],
+    last_read_field_id: i16,
+    recovery: Option<&Recovery>,
+) -> Result<TFieldIdentifier, ThriftException> {
+    let Some(&field_type_byte) = header.first() else {
+        return Err(IOError::NoRemaining(String::new()).into());
+    };
+    let field_delta = (field_type_byte & 0xF0) >> 4;
+    let field_type = match TCompactType::try_from(field_type_byte & 0x0F).and_then(TType::try_from)
+    {
+        Ok(field_type) => field_type,
+        Err(_) if recovery.is_some_and(|recovery| recovery.would_recover(field_type_byte)) => {
+            TType::Stop
+        }
+        Err(e) => return Err(e.into()),
+    };
+    if field_type == TType::Stop {
+        return Ok(TFieldIdentifier::new::<Option<&'static str>, Option<i16>>(
+            None,
+            TType::Stop,
+            None,
+        ));
+    }
+    let id = if field_delta != 0 {
+        last_read_field_id + field_delta as i16
+    } else {
+        match i16::decode_var(&header[1..]) {
+            Some((id, _)) => id,
+            None if header.len() <= 3 => {
+                return Err(IOError::NoRemaining(String::new()).into());
+            }
+            None => {
+                return Err(new_protocol_exception(
+                    ProtocolExceptionKind::InvalidData,
+                    "field id varint overflows an i16",
+                ));
+            }
+        }
+    };
+    Ok(TFieldIdentifier::new::<Option<&'static str>, i16>(
+        None, field_type, id,
+    ))
+}
+
 const COMPACT_PROTOCOL_ID: u8 = 0x082;
 const COMPACT_VERSION: u8 = 1;
+// the version of fbthrift, whose doubles are big-endian
+const FBTHRIFT_COMPACT_VERSION: u8 = 2;
 const COMPACT_VERSION_MASK: u8 = 0x1f;
 const COMPACT_TYPE_MASK: u8 = 0x0E0;
 const COMPACT_TYPE_SHIFT_AMOUNT: u8 = 5;
 
+/// Whether the doubles of a message of `version` are big-endian, or `None` if
+/// the version is not supported.
+#[inline]
+fn check_version(version: u8, fbthrift: bool) -> Option<bool> {
+    match version {
+        COMPACT_VERSION => Some(false),
+        FBTHRIFT_COMPACT_VERSION if fbthrift => Some(true),
+        _ => None,
+    }
+}
+
 #[inline]
 fn tcompact_get_ttype(ct: TCompactType) -> Result<TType, ProtocolException> {
     ct.try_into().map_err(|_| {
@@ -156,6 +226,9 @@ pub struct TCompactOutputProtocol<T> {
 
     zero_copy: bool,
     zero_copy_len: usize,
+    canonical_nan: bool,
+    deterministic: bool,
+    fbthrift: bool,
 }
 
 impl<T> TCompactOutputProtocol<T> {
@@ -171,9 +244,61 @@ impl<T> TCompactOutputProtocol<T> {
 
             zero_copy,
             zero_copy_len: 0,
+            canonical_nan: false,
+            deterministic: false,
+            fbthrift: false,
         }
     }
 
+    /// Writes the compact protocol of fbthrift instead of the one of Apache
+    /// Thrift, for the fbthrift services: the messages are of version 2 and
+    /// the doubles big-endian.
+    #[inline]
+    pub fn set_fbthrift(&mut self, fbthrift: bool) {
+        self.fbthrift = fbthrift;
+    }
+
+    #[inline]
+    fn version(&self) -> u8 {
+        if self.fbthrift {
+            FBTHRIFT_COMPACT_VERSION
+        } else {
+            COMPACT_VERSION
+        }
+    }
+
+    /// Rewrites every NaN written by `write_double` to the canonical NaN, see
+    /// [`canonicalize_nan`].
+    #[inline]
+    pub fn set_canonical_nan(&mut self, canonical_nan: bool) {
+        self.canonical_nan = canonical_nan;
+    }
+
+    /// Writes the entries of maps and sets sorted by the bytes of their keys,
+    /// so that equal values encode to the same bytes whatever the order of
+    /// their maps, as for signing or caching the output. The fields are
+    /// already written in the order of the IDL.
+    ///
+    /// The entries are sorted in place, so the output must be a `BytesMut`
+    /// or a `LinkedBytes`, into which binaries are then copied even when
+    /// `zero_copy` is on.
+    #[inline]
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.deterministic = deterministic;
+    }
+
+    /// Swaps in the transport of the next message on the same connection and
+    /// resets the per-message state, keeping the settings and allocations of
+    /// the protocol. Returns the previous transport.
+    #[inline]
+    pub fn reset_with(&mut self, trans: T) -> T {
+        self.last_write_field_id = 0;
+        self.write_field_id_stack.clear();
+        self.pending_write_bool_field_identifier = None;
+        self.zero_copy_len = 0;
+        std::mem::replace(&mut self.trans, trans)
+    }
+
     fn assert_no_pending_bool_write(&self) {
         if let Some(ref f) = self.pending_write_bool_field_identifier {
             panic!("pending bool field {f:?} not written");
@@ -399,7 +524,7 @@ impl<T> TLengthProtocol for TCompactOutputProtocol<T> {
     }
 }
 
-impl TCompactOutputProtocol<&mut BytesMut> {
+impl<B: WriteExt + BufMut> TCompactOutputProtocol<&mut B> {
     #[inline]
     fn write_varint<VI: VarInt>(&mut self, n: VI) -> Result<(), ThriftException> {
         let mut buf = [0u8; 10];
@@ -443,8 +568,8 @@ impl TCompactOutputProtocol<&mut BytesMut> {
     }
 }
 
-impl TOutputProtocol for TCompactOutputProtocol<&mut BytesMut> {
-    type BufMut = BytesMut;
+impl<B: WriteExt + BufMut> TOutputProtocol for TCompactOutputProtocol<&mut B> {
+    type BufMut = B;
 
     #[inline]
     fn write_message_begin(
@@ -454,7 +579,7 @@ impl TOutputProtocol for TCompactOutputProtocol<&mut BytesMut> {
         let mtype = identifier.message_type as u8;
         self.trans.write_slice(&[
             COMPACT_PROTOCOL_ID,
-            (COMPACT_VERSION & COMPACT_VERSION_MASK)
+            (self.version() & COMPACT_VERSION_MASK)
                 | ((mtype << COMPACT_TYPE_SHIFT_AMOUNT) & COMPACT_TYPE_MASK),
         ]);
         // cast i32 as u32 so that varint writing won't use zigzag encoding
@@ -591,7 +716,16 @@ impl TOutputProtocol for TCompactOutputProtocol<&mut BytesMut> {
     }
     #[inline]
     fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
-        self.trans.write_f64(d);
+        let d = if self.canonical_nan {
+            canonicalize_nan(d)
+        } else {
+            d
+        };
+        if self.fbthrift {
+            self.trans.write_f64(d);
+        } else {
+            self.trans.write_f64_le(d);
+        }
         Ok(())
     }
 
@@ -655,6 +789,7 @@ impl TOutputProtocol for TCompactOutputProtocol<&mut BytesMut> {
 
     #[inline]
     fn flush(&mut self) -> Result<(), ThriftException> {
+        self.trans.flush_staged()?;
         Ok(())
     }
 
@@ -667,6 +802,21 @@ impl TOutputProtocol for TCompactOutputProtocol<&mut BytesMut> {
         Ok(())
     }
 
+    #[inline]
+    fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    #[inline]
+    fn written_mut(&mut self) -> Option<&mut [u8]> {
+        self.trans.written_mut()
+    }
+
+    #[inline]
+    fn hold_written(&mut self, hold: bool) {
+        self.trans.hold_written(hold)
+    }
+
     #[inline]
     fn buf_mut(&mut self) -> &mut Self::BufMut {
         self.trans
@@ -726,9 +876,10 @@ impl TOutputProtocol for TCompactOutputProtocol<&mut LinkedBytes> {
         identifier: &TMessageIdentifier,
     ) -> Result<(), ThriftException> {
         let mtype = identifier.message_type as u8;
+        let version = self.version();
         self.trans.bytes_mut().write_slice(&[
             COMPACT_PROTOCOL_ID,
-            (COMPACT_VERSION & COMPACT_VERSION_MASK)
+            (version & COMPACT_VERSION_MASK)
                 | ((mtype << COMPACT_TYPE_SHIFT_AMOUNT) & COMPACT_TYPE_MASK),
         ]);
         // cast i32 as u32 so that varint writing won't use zigzag encoding
@@ -828,7 +979,7 @@ impl TOutputProtocol for TCompactOutputProtocol<&mut LinkedBytes> {
     }
     #[inline]
     fn write_bytes_without_len(&mut self, b: Bytes) -> Result<(), ThriftException> {
-        if self.zero_copy && b.len() >= ZERO_COPY_THRESHOLD {
+        if self.zero_copy && !self.deterministic && b.len() >= ZERO_COPY_THRESHOLD {
             self.zero_copy_len += b.len();
             self.trans.insert(b);
             return Ok(());
@@ -870,7 +1021,16 @@ impl TOutputProtocol for TCompactOutputProtocol<&mut LinkedBytes> {
     }
     #[inline]
     fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
-        self.trans.bytes_mut().write_f64(d);
+        let d = if self.canonical_nan {
+            canonicalize_nan(d)
+        } else {
+            d
+        };
+        if self.fbthrift {
+            self.trans.bytes_mut().write_f64(d);
+        } else {
+            self.trans.bytes_mut().write_f64_le(d);
+        }
         Ok(())
     }
 
@@ -888,7 +1048,7 @@ impl TOutputProtocol for TCompactOutputProtocol<&mut LinkedBytes> {
         // length is strictly positive as per the spec, so
         // cast i32 as u32 so that varint writing won't use zigzag encoding
         self.write_varint(s.len() as u32)?;
-        if self.zero_copy && s.len() <= ZERO_COPY_THRESHOLD {
+        if self.zero_copy && !self.deterministic && s.len() <= ZERO_COPY_THRESHOLD {
             self.zero_copy_len += s.len();
             self.trans.insert_faststr(s);
             return Ok(());
@@ -951,18 +1111,46 @@ impl TOutputProtocol for TCompactOutputProtocol<&mut LinkedBytes> {
         Ok(())
     }
 
+    #[inline]
+    fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    #[inline]
+    fn written_mut(&mut self) -> Option<&mut [u8]> {
+        Some(self.trans.bytes_mut())
+    }
+
     #[inline]
     fn buf_mut(&mut self) -> &mut Self::BufMut {
         self.trans
     }
 }
 
+// the fewest bytes an element of a container of `ttype` takes
+pub(crate) fn min_element_len(ttype: TType) -> usize {
+    match ttype {
+        TType::Double => 8,
+        TType::Uuid => 16,
+        _ => 1,
+    }
+}
+
 pub struct TAsyncCompactProtocol<R> {
     reader: R,
 
     last_read_field_id: i16,
     read_field_id_stack: Vec<i16>,
     pending_read_bool_value: Option<bool>,
+    fbthrift: bool,
+    big_endian_doubles: bool,
+    max_message_size: Option<usize>,
+    max_container_size: Option<usize>,
+    max_string_size: Option<usize>,
+    depth: Option<DepthTracker>,
+    reject_duplicate_fields: bool,
+    unknown_field_hook: Option<UnknownFieldHook>,
+    allocation: Option<AllocationTracker>,
 }
 
 impl<R> TAsyncInputProtocol for TAsyncCompactProtocol<R>
@@ -970,6 +1158,8 @@ where
     R: AsyncRead + Unpin + Send,
 {
     async fn read_message_begin(&mut self) -> Result<TMessageIdentifier, ThriftException> {
+        depth::reset(&mut self.depth);
+        allocation::reset(&mut self.allocation);
         let compact_id = self.read_byte().await?;
         if compact_id != COMPACT_PROTOCOL_ID {
             return Err(new_protocol_exception(
@@ -980,12 +1170,12 @@ where
 
         let type_and_byte = self.read_byte().await?;
         let version = type_and_byte & COMPACT_VERSION_MASK;
-        if version != COMPACT_VERSION {
-            return Err(new_protocol_exception(
+        self.big_endian_doubles = check_version(version, self.fbthrift).ok_or_else(|| {
+            new_protocol_exception(
                 ProtocolExceptionKind::BadVersion,
                 format!("cannot process compact protocol version {version:?}"),
-            ));
-        }
+            )
+        })?;
 
         // NOTE: unsigned right shift will pad with 0s
         let type_id = type_and_byte >> 5;
@@ -1010,6 +1200,7 @@ where
 
     #[inline]
     async fn read_struct_begin(&mut self) -> Result<Option<TStructIdentifier>, ThriftException> {
+        depth::enter(&mut self.depth)?;
         self.read_field_id_stack.push(self.last_read_field_id);
         self.last_read_field_id = 0;
         Ok(None)
@@ -1017,6 +1208,13 @@ where
 
     #[inline]
     async fn read_struct_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
+        self.last_read_field_id = self.read_field_id_stack.pop().ok_or_else(|| {
+            new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                "ReadStructEnd called without matching ReadStructBegin",
+            )
+        })?;
         Ok(())
     }
 
@@ -1064,6 +1262,21 @@ where
         Ok(())
     }
 
+    #[inline]
+    fn rejects_duplicate_fields(&self) -> bool {
+        self.reject_duplicate_fields
+    }
+
+    #[inline]
+    fn on_unknown_field(
+        &self,
+        struct_name: &'static str,
+        field: &TFieldIdentifier,
+        type_mismatch: bool,
+    ) {
+        unknown::report(&self.unknown_field_hook, struct_name, field, type_mismatch);
+    }
+
     #[inline]
     async fn read_bool(&mut self) -> Result<bool, ThriftException> {
         match self.pending_read_bool_value.take() {
@@ -1089,7 +1302,7 @@ where
 
     #[inline]
     async fn read_bytes_vec(&mut self) -> Result<Vec<u8>, ThriftException> {
-        let size = self.read_varint_async::<u32>().await? as usize;
+        let size = self.read_str_len().await?;
         // FIXME: use maybe_uninit?
         let mut v = vec![0; size];
         self.reader.read_exact(&mut v).await?;
@@ -1106,7 +1319,7 @@ where
     #[inline]
     async fn read_string(&mut self) -> Result<String, ThriftException> {
         let v = self.read_bytes_vec().await?;
-        Ok(unsafe { String::from_utf8_unchecked(v) })
+        Ok(string_from_utf8(v)?)
     }
 
     #[inline]
@@ -1141,12 +1354,19 @@ where
 
     #[inline]
     async fn read_double(&mut self) -> Result<f64, ThriftException> {
-        Ok(self.reader.read_f64_le().await?)
+        if self.big_endian_doubles {
+            Ok(self.reader.read_f64().await?)
+        } else {
+            Ok(self.reader.read_f64_le().await?)
+        }
     }
 
     #[inline]
     async fn read_list_begin(&mut self) -> Result<TListIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
         let (element_type, element_count) = self.read_collection_begin().await?;
+        let size = element_size(element_type);
+        allocation::charge_elements(&mut self.allocation, element_count, size)?;
         Ok(TListIdentifier {
             element_type,
             size: element_count,
@@ -1155,12 +1375,16 @@ where
 
     #[inline]
     async fn read_list_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     async fn read_set_begin(&mut self) -> Result<TSetIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
         let (element_type, element_count) = self.read_collection_begin().await?;
+        let size = element_size(element_type);
+        allocation::charge_elements(&mut self.allocation, element_count, size)?;
         Ok(TSetIdentifier {
             element_type,
             size: element_count,
@@ -1169,12 +1393,15 @@ where
 
     #[inline]
     async fn read_set_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     async fn read_map_begin(&mut self) -> Result<TMapIdentifier, ThriftException> {
-        let element_count = self.read_varint_async::<u32>().await? as i32;
+        depth::enter(&mut self.depth)?;
+        let element_count = self.read_len().await?;
+        super::check_container_size(element_count, 1, None, self.max_container_size)?;
         if element_count == 0 {
             Ok(TMapIdentifier::new(TType::Stop, TType::Stop, 0))
         } else {
@@ -1182,18 +1409,38 @@ where
             let key_type = tcompact_get_ttype(((type_header & 0xF0) >> 4).try_into()?)?;
             let val_type = tcompact_get_ttype((type_header & 0x0F).try_into()?)?;
 
-            Ok(TMapIdentifier::new(
-                key_type,
-                val_type,
-                element_count as usize,
-            ))
+            let entry_size = element_size(key_type) + element_size(val_type);
+            allocation::charge_elements(&mut self.allocation, element_count, entry_size)?;
+            Ok(TMapIdentifier::new(key_type, val_type, element_count))
         }
     }
 
     #[inline]
     async fn read_map_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
+
+    async fn skip_capture(&mut self, field_type: TType) -> Result<Bytes, ThriftException> {
+        let mut capture = TAsyncCompactProtocol {
+            reader: CaptureReader::new(&mut self.reader),
+            last_read_field_id: 0,
+            read_field_id_stack: Vec::new(),
+            // the value of a bool field read from its header
+            pending_read_bool_value: self.pending_read_bool_value.take(),
+            fbthrift: self.fbthrift,
+            big_endian_doubles: self.big_endian_doubles,
+            max_message_size: self.max_message_size,
+            max_container_size: self.max_container_size,
+            max_string_size: self.max_string_size,
+            depth: self.depth,
+            reject_duplicate_fields: self.reject_duplicate_fields,
+            unknown_field_hook: self.unknown_field_hook.clone(),
+            allocation: self.allocation,
+        };
+        capture.skip(field_type).await?;
+        Ok(capture.reader.captured.freeze())
+    }
 }
 
 impl<R> TAsyncCompactProtocol<R>
@@ -1206,9 +1453,75 @@ where
             last_read_field_id: 0,
             read_field_id_stack: Vec::new(),
             pending_read_bool_value: None,
+            fbthrift: false,
+            big_endian_doubles: false,
+            max_message_size: None,
+            max_container_size: None,
+            max_string_size: None,
+            depth: None,
+            reject_duplicate_fields: false,
+            unknown_field_hook: None,
+            allocation: None,
         }
     }
 
+    /// See [`TCompactInputProtocol::set_fbthrift`].
+    pub fn set_fbthrift(&mut self, fbthrift: bool) {
+        self.fbthrift = fbthrift;
+        self.big_endian_doubles = fbthrift;
+    }
+
+    /// See [`TCompactInputProtocol::set_max_message_size`].
+    pub fn set_max_message_size(&mut self, max: usize) {
+        self.max_message_size = Some(max);
+    }
+
+    /// See [`TCompactInputProtocol::set_max_container_size`], the sizes being
+    /// only checked against `max` as what is left of the input is unknown.
+    pub fn set_max_container_size(&mut self, max: usize) {
+        self.max_container_size = Some(max);
+    }
+
+    /// See [`TCompactInputProtocol::set_max_string_size`].
+    pub fn set_max_string_size(&mut self, max: usize) {
+        self.max_string_size = Some(max);
+    }
+
+    /// See [`TCompactInputProtocol::set_max_depth`].
+    pub fn set_max_depth(&mut self, max: usize) {
+        self.depth = Some(DepthTracker::new(max));
+    }
+
+    /// See [`TCompactInputProtocol::set_reject_duplicate_fields`].
+    pub fn set_reject_duplicate_fields(&mut self, reject: bool) {
+        self.reject_duplicate_fields = reject;
+    }
+
+    /// See [`TCompactInputProtocol::set_unknown_field_hook`].
+    pub fn set_unknown_field_hook(&mut self, hook: impl Fn(UnknownField) + Send + Sync + 'static) {
+        self.unknown_field_hook = Some(Arc::new(hook));
+    }
+
+    /// See [`TCompactInputProtocol::set_max_allocation`].
+    pub fn set_max_allocation(&mut self, max: usize) {
+        self.allocation = Some(AllocationTracker::new(max));
+    }
+
+    #[inline]
+    async fn read_str_len(&mut self) -> Result<usize, ThriftException> {
+        let len = self.read_len().await?;
+        super::check_string_size(len, self.max_string_size)?;
+        allocation::charge(&mut self.allocation, len)?;
+        Ok(len)
+    }
+
+    #[inline]
+    async fn read_len(&mut self) -> Result<usize, ThriftException> {
+        let len = self.read_varint_async::<u32>().await? as usize;
+        super::check_message_size(len, self.max_message_size)?;
+        Ok(len)
+    }
+
     #[inline]
     async fn read_collection_begin(&mut self) -> Result<(TType, usize), ThriftException> {
         let header = self.read_byte().await?;
@@ -1216,11 +1529,12 @@ where
 
         let possible_element_count = (header & 0xF0) >> 4;
         let element_count = if possible_element_count != 15 {
-            possible_element_count as i32
+            possible_element_count as usize
         } else {
-            self.read_varint_async::<u32>().await? as i32
+            self.read_len().await?
         };
-        Ok((element_type, element_count as usize))
+        super::check_container_size(element_count, 1, None, self.max_container_size)?;
+        Ok((element_type, element_count))
     }
 
     #[inline]
@@ -1247,7 +1561,21 @@ pub struct TCompactInputProtocol<T> {
     // Saved because boolean fields and their value are encoded in a single byte,
     // and reading the field only occurs after the field id is read.
     pending_read_bool_value: Option<bool>,
-    pending_read_bool_field_identifier: Option<TFieldIdentifier>,
+    // Bytes taken by the last field header read, reported by `field_begin_len`.
+    last_read_field_header_len: usize,
+    budget: Option<BudgetTracker>,
+    ttype_recovery: Option<Recovery>,
+    fbthrift: bool,
+    // Whether the doubles of the current message are big-endian, as in the
+    // messages of version 2 of fbthrift.
+    big_endian_doubles: bool,
+    max_message_size: Option<usize>,
+    max_container_size: Option<usize>,
+    max_string_size: Option<usize>,
+    depth: Option<DepthTracker>,
+    reject_duplicate_fields: bool,
+    unknown_field_hook: Option<UnknownFieldHook>,
+    allocation: Option<AllocationTracker>,
 }
 
 impl<T> TCompactInputProtocol<T> {
@@ -1257,18 +1585,136 @@ impl<T> TCompactInputProtocol<T> {
             last_read_field_id: 0,
             read_field_id_stack: Vec::with_capacity(24),
             pending_read_bool_value: None,
-            pending_read_bool_field_identifier: None,
+            last_read_field_header_len: 0,
+            budget: None,
+            ttype_recovery: None,
+            fbthrift: false,
+            big_endian_doubles: false,
+            max_message_size: None,
+            max_container_size: None,
+            max_string_size: None,
+            depth: None,
+            reject_duplicate_fields: false,
+            unknown_field_hook: None,
+            allocation: None,
         }
     }
 
-    fn assert_no_pending_bool_read(&self) {
-        if let Some(ref f) = self.pending_read_bool_field_identifier {
-            panic!("pending bool field {f:?} not read");
+    /// Reads the compact protocol of fbthrift as well as the one of Apache
+    /// Thrift, for the fbthrift services. As in fbthrift, the messages of
+    /// version 2 have big-endian doubles and those of version 1
+    /// little-endian ones, and the structs read without a message are taken
+    /// to be of version 2.
+    #[inline]
+    pub fn set_fbthrift(&mut self, fbthrift: bool) {
+        self.fbthrift = fbthrift;
+        self.big_endian_doubles = fbthrift;
+    }
+
+    /// Fails with [`ProtocolExceptionKind::MessageTooLarge`] when a message
+    /// name, string, binary or container size read from the input is larger
+    /// than `max`, before anything is allocated for it.
+    #[inline]
+    pub fn set_max_message_size(&mut self, max: usize) {
+        self.max_message_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::SizeLimit`] when a list, set or map
+    /// has more elements than `max`. Sizes the rest of the input can't hold
+    /// fail with [`ProtocolExceptionKind::InvalidData`] whatever the limit.
+    #[inline]
+    pub fn set_max_container_size(&mut self, max: usize) {
+        self.max_container_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::InvalidData`] when a string or
+    /// binary, message names included, is longer than `max`.
+    #[inline]
+    pub fn set_max_string_size(&mut self, max: usize) {
+        self.max_string_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::DepthLimit`] when structs and
+    /// containers, skipped ones included, are nested deeper than `max`.
+    #[inline]
+    pub fn set_max_depth(&mut self, max: usize) {
+        self.depth = Some(DepthTracker::new(max));
+    }
+
+    /// Makes the generated decode fail with
+    /// [`ProtocolExceptionKind::InvalidData`] when a field of a struct
+    /// appears twice, instead of keeping its last value.
+    #[inline]
+    pub fn set_reject_duplicate_fields(&mut self, reject: bool) {
+        self.reject_duplicate_fields = reject;
+    }
+
+    /// Calls `hook` with the fields the generated decode skips, not knowing
+    /// their id or their type, to notice a peer sending a newer schema.
+    pub fn set_unknown_field_hook(&mut self, hook: impl Fn(UnknownField) + Send + Sync + 'static) {
+        self.unknown_field_hook = Some(Arc::new(hook));
+    }
+
+    /// Fails with [`ProtocolExceptionKind::BudgetExceeded`] once decoding a
+    /// message allocates more than `max` bytes in total. Strings and binaries
+    /// count their length, whether shared with the input or copied, and lists,
+    /// sets and maps the fewest bytes their elements take once decoded,
+    /// skipped ones included.
+    #[inline]
+    pub fn set_max_allocation(&mut self, max: usize) {
+        self.allocation = Some(AllocationTracker::new(max));
+    }
+
+    /// See [`TBinaryProtocol::set_ttype_recovery`](super::binary::TBinaryProtocol::set_ttype_recovery).
+    #[inline]
+    pub fn set_ttype_recovery(&mut self, recovery: TTypeRecovery) {
+        self.set_ttype_recovery_with(move |_| recovery);
+    }
+
+    /// See [`TBinaryProtocol::set_ttype_recovery_with`](super::binary::TBinaryProtocol::set_ttype_recovery_with).
+    pub fn set_ttype_recovery_with(
+        &mut self,
+        f: impl Fn(u8) -> TTypeRecovery + Send + Sync + 'static,
+    ) {
+        self.ttype_recovery = Some(Recovery::new(Arc::new(f)));
+    }
+
+    /// Swaps in the transport of the next message on the same connection and
+    /// resets the per-message state, keeping the settings and allocations of
+    /// the protocol. Returns the previous transport.
+    ///
+    /// A budget set with `set_budget` restarts at the next message begin.
+    #[inline]
+    pub fn reset_with(&mut self, trans: T) -> T {
+        self.last_read_field_id = 0;
+        self.read_field_id_stack.clear();
+        self.pending_read_bool_value = None;
+        self.last_read_field_header_len = 0;
+        self.big_endian_doubles = self.fbthrift;
+        depth::reset(&mut self.depth);
+        allocation::reset(&mut self.allocation);
+        if let Some(recovery) = &mut self.ttype_recovery {
+            recovery.reset();
         }
+        std::mem::replace(&mut self.trans, trans)
     }
 }
 
 impl TCompactInputProtocol<&mut Bytes> {
+    /// Limits how many bytes and how much time decoding may take, counted
+    /// from the current position and restarted at each message begin.
+    pub fn set_budget(&mut self, budget: Budget) {
+        self.budget = Some(BudgetTracker::new(budget, self.trans.len()));
+    }
+
+    #[inline]
+    fn check_budget(&self) -> Result<(), ThriftException> {
+        match &self.budget {
+            Some(budget) => budget.check(self.trans.len()),
+            None => Ok(()),
+        }
+    }
+
     #[inline]
     fn read_varint<VI: VarInt>(&mut self) -> Result<VI, ThriftException> {
         let mut p = VarIntProcessor::new::<VI>();
@@ -1288,25 +1734,34 @@ impl TCompactInputProtocol<&mut Bytes> {
 
         let possible_element_count = (header & 0xF0) >> 4;
         let element_count = if possible_element_count != 15 {
-            possible_element_count as i32
+            possible_element_count as usize
         } else {
-            self.read_varint::<u32>()? as i32
+            self.read_len()?
         };
-        Ok((element_type, element_count as usize))
+        self.check_container_size(element_count, min_element_len(element_type))?;
+        Ok((element_type, element_count))
     }
-}
 
-macro_rules! read_field_header_len {
-    ($self:expr_2021, $ax:expr_2021, $field_type:expr_2021, $id:expr_2021) => {
-        let field_delta = $id - $self.last_read_field_id;
-        if field_delta > 0 && field_delta < 15 {
-            $ax += $self.byte_len(0);
-        } else {
-            $ax += $self.byte_len($field_type as u8);
-            $ax += $self.i16_len($id);
-        }
-        $self.last_read_field_id = $id;
-    };
+    #[inline]
+    fn check_container_size(&self, size: usize, min_len: usize) -> Result<(), ThriftException> {
+        let remaining = Some(self.trans.len());
+        super::check_container_size(size, min_len, remaining, self.max_container_size)
+    }
+
+    #[inline]
+    fn read_str_len(&mut self) -> Result<usize, ThriftException> {
+        let len = self.read_len()?;
+        super::check_string_size(len, self.max_string_size)?;
+        allocation::charge(&mut self.allocation, len)?;
+        Ok(len)
+    }
+
+    #[inline]
+    fn read_len(&mut self) -> Result<usize, ThriftException> {
+        let len = self.read_varint::<u32>()? as usize;
+        super::check_message_size(len, self.max_message_size)?;
+        Ok(len)
+    }
 }
 
 impl<T> TLengthProtocol for TCompactInputProtocol<T> {
@@ -1316,7 +1771,6 @@ impl<T> TLengthProtocol for TCompactInputProtocol<T> {
     }
     #[inline]
     fn message_end_len(&mut self) -> usize {
-        self.assert_no_pending_bool_read();
         0
     }
 
@@ -1328,7 +1782,6 @@ impl<T> TLengthProtocol for TCompactInputProtocol<T> {
     }
     #[inline]
     fn struct_end_len(&mut self) -> usize {
-        self.assert_no_pending_bool_read();
         self.last_read_field_id = self
             .read_field_id_stack
             .pop()
@@ -1343,63 +1796,28 @@ impl<T> TLengthProtocol for TCompactInputProtocol<T> {
     }
 
     #[inline]
-    fn field_begin_len(&mut self, field_type: TType, id: Option<i16>) -> usize {
-        // `id` is an Option<i16> following trait [`TLengthProtocol`]
-        // field_begin_len.
-        match field_type {
-            TType::Bool => {
-                if self.pending_read_bool_field_identifier.is_some() {
-                    panic!(
-                        "should not have a pending bool while reading another bool with id: \
-                        {id:?}",
-                    )
-                }
-                self.pending_read_bool_field_identifier = Some(TFieldIdentifier {
-                    name: None,
-                    field_type,
-                    id,
-                });
-                0
-            }
-            _ => {
-                let tc_field_type = TCompactType::try_from(field_type).unwrap(); // this should never happen
-                let mut ax = 0;
-                read_field_header_len!(self, ax, tc_field_type, id.expect("expecting a field id"));
-                ax
-            }
-        }
+    fn field_begin_len(&mut self, _field_type: TType, _id: Option<i16>) -> usize {
+        // The header has been read by `read_field_begin` already, the id delta
+        // it was encoded with is only known from there. The value of a bool
+        // field is part of its header.
+        self.last_read_field_header_len
     }
     #[inline]
     fn field_end_len(&mut self) -> usize {
-        self.assert_no_pending_bool_read();
         0
     }
     #[inline]
     fn field_stop_len(&mut self) -> usize {
-        self.assert_no_pending_bool_read();
         self.byte_len(TType::Stop as u8)
     }
 
     #[inline]
     fn bool_len(&mut self, b: bool) -> usize {
-        match self.pending_read_bool_field_identifier.take() {
-            Some(pending) => {
-                let field_id = pending.id.expect("bool field should have a field id");
-                let tc_field_type = if b {
-                    TCompactType::BooleanTrue
-                } else {
-                    TCompactType::BooleanFalse
-                };
-                let mut ax = 0;
-                read_field_header_len!(self, ax, tc_field_type, field_id);
-                ax
-            }
-            None => self.byte_len(if b {
-                TCompactType::BooleanTrue as u8
-            } else {
-                TCompactType::BooleanFalse as u8
-            }),
-        }
+        self.byte_len(if b {
+            TCompactType::BooleanTrue as u8
+        } else {
+            TCompactType::BooleanFalse as u8
+        })
     }
 
     #[inline]
@@ -1516,6 +1934,12 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
     type Buf = Bytes;
 
     fn read_message_begin(&mut self) -> Result<TMessageIdentifier, ThriftException> {
+        depth::reset(&mut self.depth);
+        allocation::reset(&mut self.allocation);
+        if let Some(budget) = &mut self.budget {
+            budget.rearm(self.trans.len());
+        }
+
         let compact_id = self.read_byte()?;
         if compact_id != COMPACT_PROTOCOL_ID {
             return Err(new_protocol_exception(
@@ -1526,12 +1950,12 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
 
         let type_and_byte = self.read_byte()?;
         let version = type_and_byte & COMPACT_VERSION_MASK;
-        if version != COMPACT_VERSION {
-            return Err(new_protocol_exception(
+        self.big_endian_doubles = check_version(version, self.fbthrift).ok_or_else(|| {
+            new_protocol_exception(
                 ProtocolExceptionKind::InvalidData,
                 format!("cannot process compact protocol version {version}"),
-            ));
-        }
+            )
+        })?;
 
         // NOTE: unsigned right shift will pad with 0s
         let type_id = type_and_byte >> 5;
@@ -1556,6 +1980,8 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
 
     #[inline]
     fn read_struct_begin(&mut self) -> Result<Option<TStructIdentifier>, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
         self.read_field_id_stack.push(self.last_read_field_id);
         self.last_read_field_id = 0;
         Ok(None)
@@ -1563,17 +1989,33 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
 
     #[inline]
     fn read_struct_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
+        self.last_read_field_id = self.read_field_id_stack.pop().ok_or_else(|| {
+            new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                "ReadStructEnd called without matching ReadStructBegin",
+            )
+        })?;
         Ok(())
     }
 
     // #[inline]
     fn read_field_begin(&mut self) -> Result<TFieldIdentifier, ThriftException> {
+        if let Some(recovery) = &self.ttype_recovery {
+            if recovery.dropped(self.trans) {
+                return Ok(TFieldIdentifier::new::<Option<&'static str>, Option<i16>>(
+                    None,
+                    TType::Stop,
+                    None,
+                ));
+            }
+        }
         // we can read at least one byte, which is:
         // - the type
         // - the field id delta and the type
-        let field_type = self.read_byte()?;
-        let field_delta = (field_type & 0xF0) >> 4;
-        let field_type = match field_type & 0x0F {
+        let field_type_byte = self.read_byte()?;
+        let field_delta = (field_type_byte & 0xF0) >> 4;
+        let field_type = match field_type_byte & 0x0F {
             COMPACT_BOOLEAN_TRUE => {
                 self.pending_read_bool_value = Some(true);
                 Ok(TType::Bool)
@@ -1582,8 +2024,20 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
                 self.pending_read_bool_value = Some(false);
                 Ok(TType::Bool)
             }
-            ttu8 => TType::try_from(TCompactType::try_from(ttu8)?),
-        }?;
+            ttu8 => TCompactType::try_from(ttu8).and_then(TType::try_from),
+        };
+        let field_type = match field_type {
+            Ok(field_type) => field_type,
+            Err(_)
+                if self
+                    .ttype_recovery
+                    .as_mut()
+                    .is_some_and(|recovery| recovery.recover(self.trans, field_type_byte)) =>
+            {
+                TType::Stop
+            }
+            Err(e) => return Err(e.into()),
+        };
         match field_type {
             TType::Stop => Ok(TFieldIdentifier::new::<Option<&'static str>, Option<i16>>(
                 None,
@@ -1593,8 +2047,10 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
             _ => {
                 if field_delta != 0 {
                     self.last_read_field_id += field_delta as i16;
+                    self.last_read_field_header_len = 1;
                 } else {
                     self.last_read_field_id = self.read_i16()?;
+                    self.last_read_field_header_len = 1 + self.i16_len(self.last_read_field_id);
                 }
                 Ok(TFieldIdentifier::new::<Option<&'static str>, i16>(
                     None,
@@ -1610,6 +2066,39 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
         Ok(())
     }
 
+    #[inline]
+    fn rejects_duplicate_fields(&self) -> bool {
+        self.reject_duplicate_fields
+    }
+
+    #[inline]
+    fn on_unknown_field(
+        &self,
+        struct_name: &'static str,
+        field: &TFieldIdentifier,
+        type_mismatch: bool,
+    ) {
+        unknown::report(&self.unknown_field_hook, struct_name, field, type_mismatch);
+    }
+
+    fn peek_field_begin(&mut self) -> Result<TFieldIdentifier, ThriftException> {
+        if let Some(recovery) = &self.ttype_recovery {
+            if recovery.dropped(self.trans) {
+                return Ok(TFieldIdentifier::new::<Option<&'static str>, Option<i16>>(
+                    None,
+                    TType::Stop,
+                    None,
+                ));
+            }
+        }
+        let len = self.trans.len().min(4);
+        peek_field_header(
+            &self.trans[..len],
+            self.last_read_field_id,
+            self.ttype_recovery.as_ref(),
+        )
+    }
+
     #[inline]
     fn read_bool(&mut self) -> Result<bool, ThriftException> {
         match self.pending_read_bool_value.take() {
@@ -1630,8 +2119,8 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
 
     #[inline]
     fn read_bytes(&mut self) -> Result<Bytes, ThriftException> {
-        let size = self.read_varint::<u32>()?;
-        Ok(self.trans.split_to(size as usize))
+        let size = self.read_str_len()?;
+        Ok(self.trans.split_to(size))
     }
 
     #[inline]
@@ -1654,15 +2143,15 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
 
     #[inline]
     fn read_string(&mut self) -> Result<String, ThriftException> {
-        let size = self.read_varint::<u32>()? as usize;
+        let size = self.read_str_len()?;
         Ok(self.trans.read_to_string(size)?)
     }
 
     #[inline]
     fn read_faststr(&mut self) -> Result<FastStr, ThriftException> {
-        let size = self.read_varint::<u32>()? as usize;
+        let size = self.read_str_len()?;
         let bytes = self.trans.split_to(size);
-        unsafe { Ok(FastStr::from_bytes_unchecked(bytes)) }
+        Ok(faststr_from_bytes(bytes)?)
     }
 
     #[inline]
@@ -1689,12 +2178,20 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
 
     #[inline]
     fn read_double(&mut self) -> Result<f64, ThriftException> {
-        Ok(self.trans.read_f64_le()?)
+        if self.big_endian_doubles {
+            Ok(self.trans.read_f64()?)
+        } else {
+            Ok(self.trans.read_f64_le()?)
+        }
     }
 
     #[inline]
     fn read_list_begin(&mut self) -> Result<TListIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
         let (element_type, element_count) = self.read_collection_begin()?;
+        let size = element_size(element_type);
+        allocation::charge_elements(&mut self.allocation, element_count, size)?;
         Ok(TListIdentifier {
             element_type,
             size: element_count,
@@ -1702,12 +2199,17 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
     }
     #[inline]
     fn read_list_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     fn read_set_begin(&mut self) -> Result<TSetIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
         let (element_type, element_count) = self.read_collection_begin()?;
+        let size = element_size(element_type);
+        allocation::charge_elements(&mut self.allocation, element_count, size)?;
         Ok(TSetIdentifier {
             element_type,
             size: element_count,
@@ -1716,39 +2218,164 @@ impl TInputProtocol for TCompactInputProtocol<&mut Bytes> {
 
     #[inline]
     fn read_set_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     // #[inline]
     fn read_map_begin(&mut self) -> Result<TMapIdentifier, ThriftException> {
-        let element_count = self.read_varint::<u32>()? as i32;
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
+        let element_count = self.read_len()?;
         if element_count == 0 {
             Ok(TMapIdentifier::new(TType::Stop, TType::Stop, 0))
         } else {
             let type_header = self.read_byte()?;
             let key_type = tcompact_get_ttype(((type_header & 0xF0) >> 4).try_into()?)?;
             let val_type = tcompact_get_ttype((type_header & 0x0F).try_into()?)?;
+            let min_len = min_element_len(key_type) + min_element_len(val_type);
+            self.check_container_size(element_count, min_len)?;
 
-            Ok(TMapIdentifier::new(
-                key_type,
-                val_type,
-                element_count as usize,
-            ))
+            let entry_size = element_size(key_type) + element_size(val_type);
+            allocation::charge_elements(&mut self.allocation, element_count, entry_size)?;
+            Ok(TMapIdentifier::new(key_type, val_type, element_count))
         }
     }
 
     #[inline]
     fn read_map_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
         Ok(())
     }
 
     #[inline]
     fn read_bytes_vec(&mut self) -> Result<Vec<u8>, ThriftException> {
-        let size = self.read_varint::<u32>()? as usize;
+        let size = self.read_str_len()?;
 
         Ok(self.trans.split_to(size).into())
     }
 
+    fn skip_till_depth(&mut self, field_type: TType, depth: i8) -> Result<usize, ThriftException> {
+        if depth == 0 {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::DepthLimit,
+                format!("cannot parse past {field_type:?}"),
+            ));
+        }
+        let remaining = self.trans.len();
+
+        // the integers and lengths are varints, which the default reads as
+        // fixed-width
+        match field_type {
+            TType::Bool => {
+                self.read_bool()?;
+            }
+            TType::I8 => {
+                self.read_i8()?;
+            }
+            TType::I16 => {
+                self.read_i16()?;
+            }
+            TType::I32 => {
+                self.read_i32()?;
+            }
+            TType::I64 => {
+                self.read_i64()?;
+            }
+            TType::Double => {
+                self.read_double()?;
+            }
+            TType::Uuid => {
+                self.read_uuid()?;
+            }
+            TType::Binary => {
+                self.read_bytes()?;
+            }
+            TType::Struct => {
+                self.read_struct_begin()?;
+                loop {
+                    let field_ident = self.read_field_begin()?;
+                    if field_ident.field_type == TType::Stop {
+                        break;
+                    }
+                    self.skip_till_depth(field_ident.field_type, depth - 1)?;
+                    self.read_field_end()?;
+                }
+                self.read_struct_end()?;
+            }
+            TType::List => {
+                let list_ident = self.read_list_begin()?;
+                for _ in 0..list_ident.size {
+                    self.skip_till_depth(list_ident.element_type, depth - 1)?;
+                }
+                self.read_list_end()?;
+            }
+            TType::Set => {
+                let set_ident = self.read_set_begin()?;
+                for _ in 0..set_ident.size {
+                    self.skip_till_depth(set_ident.element_type, depth - 1)?;
+                }
+                self.read_set_end()?;
+            }
+            TType::Map => {
+                let map_ident = self.read_map_begin()?;
+                for _ in 0..map_ident.size {
+                    self.skip_till_depth(map_ident.key_type, depth - 1)?;
+                    self.skip_till_depth(map_ident.value_type, depth - 1)?;
+                }
+                self.read_map_end()?;
+            }
+            u => {
+                return Err(new_protocol_exception(
+                    ProtocolExceptionKind::DepthLimit,
+                    format!("cannot skip field type {:?}", &u),
+                ));
+            }
+        }
+
+        Ok(remaining - self.trans.len())
+    }
+
+    fn skip_capture(&mut self, field_type: TType) -> Result<Bytes, ThriftException> {
+        let mut captured = self.trans.clone();
+        self.skip_till_depth(field_type, MAXIMUM_SKIP_DEPTH)?;
+        captured.truncate(captured.len() - self.trans.len());
+        Ok(captured)
+    }
+
+    fn mark(&mut self) -> Result<InputMark, ThriftException> {
+        Ok(InputMark {
+            input: self.trans.clone(),
+            budget: self.budget,
+            depth: self.depth,
+            allocation: self.allocation,
+            recovery_dropped: self
+                .ttype_recovery
+                .as_ref()
+                .is_some_and(Recovery::is_dropped),
+            last_read_field_id: self.last_read_field_id,
+            read_field_id_stack: self.read_field_id_stack.clone(),
+            pending_read_bool_value: self.pending_read_bool_value,
+            last_read_field_header_len: self.last_read_field_header_len,
+        })
+    }
+
+    fn reset_to_mark(&mut self, mark: &InputMark) -> Result<(), ThriftException> {
+        *self.trans = mark.input.clone();
+        self.budget = mark.budget;
+        self.depth = mark.depth;
+        self.allocation = mark.allocation;
+        if let Some(recovery) = &mut self.ttype_recovery {
+            recovery.set_dropped(mark.recovery_dropped);
+        }
+        self.last_read_field_id = mark.last_read_field_id;
+        self.read_field_id_stack
+            .clone_from(&mark.read_field_id_stack);
+        self.pending_read_bool_value = mark.pending_read_bool_value;
+        self.last_read_field_header_len = mark.last_read_field_header_len;
+        Ok(())
+    }
+
     #[inline]
     fn buf(&mut self) -> &mut Self::Buf {
         self.trans
@@ -1764,8 +2391,8 @@ mod tests {
     use super::{TCompactInputProtocol, TCompactOutputProtocol};
     use crate::thrift::{
         TFieldIdentifier, TInputProtocol, TLengthProtocol, TListIdentifier, TMapIdentifier,
-        TMessageIdentifier, TMessageType, TOutputProtocol, TSetIdentifier, TStructIdentifier,
-        TType, ThriftException,
+        TMessageIdentifier, TMessageType, TOutputProtocol, TOutputProtocolExt, TSetIdentifier,
+        TStructIdentifier, TType, ThriftException,
     };
 
     #[cfg(test)]
@@ -1915,6 +2542,58 @@ mod tests {
         mteq!(o_prot, o_prot.map_end_len());
     }
 
+    #[test]
+    fn must_write_double_little_endian() {
+        let mut trans = BytesMut::new();
+        let mut o_prot = test_output_prot_bytesmut(&mut trans);
+        assert_success!(o_prot.write_double(13.37f64));
+        let expected = 13.37f64.to_le_bytes();
+        assert_eq_written_bytes!(o_prot, expected);
+
+        let mut bytes = trans.freeze();
+        let mut i_prot = test_input_prot_bytes(&mut bytes);
+        assert_eq!(assert_success!(i_prot.read_double()), 13.37f64);
+    }
+
+    #[test]
+    fn must_reset_state_with_new_transport() {
+        let write = |o_prot: &mut TCompactOutputProtocol<&mut BytesMut>| {
+            o_prot
+                .write_struct_begin(&TStructIdentifier::new("foo"))
+                .unwrap();
+            o_prot.write_field_begin(TType::I32, 3).unwrap();
+            o_prot.write_i32(7).unwrap();
+            o_prot.write_field_stop().unwrap();
+            o_prot.write_struct_end().unwrap();
+        };
+        let mut expected = BytesMut::new();
+        write(&mut TCompactOutputProtocol::new(&mut expected, false));
+
+        // leave a struct open, as an aborted encode would
+        let mut first = BytesMut::new();
+        let mut o_prot = TCompactOutputProtocol::new(&mut first, false);
+        o_prot
+            .write_struct_begin(&TStructIdentifier::new("foo"))
+            .unwrap();
+        o_prot.write_field_begin(TType::I32, 10).unwrap();
+
+        let mut second = BytesMut::new();
+        o_prot.reset_with(&mut second);
+        write(&mut o_prot);
+        assert_eq!(second, expected);
+
+        let mut first = expected.clone().freeze();
+        let mut i_prot = TCompactInputProtocol::new(&mut first);
+        i_prot.read_struct_begin().unwrap();
+        i_prot.read_field_begin().unwrap();
+
+        let mut second = expected.freeze();
+        i_prot.reset_with(&mut second);
+        i_prot.read_struct_begin().unwrap();
+        assert_eq!(i_prot.read_field_begin().unwrap().id, Some(3));
+        assert_eq!(i_prot.read_i32().unwrap(), 7);
+    }
+
     #[test]
     fn must_write_message_begin_largest_maximum_positive_sequence_number() {
         let mut trans = BytesMut::new();
@@ -3917,6 +4596,148 @@ mod tests {
         assert_no_write(trans, |o| o.write_field_end());
     }
 
+    #[test]
+    fn must_write_maps_deterministically() {
+        let a = (0..64)
+            .map(|i| (i, i % 2 == 0))
+            .collect::<crate::AHashMap<i64, bool>>();
+        let b = (0..64)
+            .rev()
+            .map(|i| (i, i % 2 == 0))
+            .collect::<crate::AHashMap<i64, bool>>();
+        let encode = |map: &crate::AHashMap<i64, bool>, deterministic: bool| {
+            let mut trans = BytesMut::new();
+            let mut o_prot = TCompactOutputProtocol::new(&mut trans, false);
+            o_prot.set_deterministic(deterministic);
+            o_prot
+                .write_map(
+                    TType::I64,
+                    TType::Bool,
+                    map,
+                    |o, k| o.write_i64(*k),
+                    |o, v| o.write_bool(*v),
+                )
+                .unwrap();
+            trans
+        };
+
+        assert_ne!(encode(&a, false), encode(&b, false));
+        assert_eq!(encode(&a, true), encode(&b, true));
+    }
+
+    #[test]
+    fn must_fail_on_impossible_container_sizes() {
+        use crate::thrift::ProtocolExceptionKind;
+
+        fn kind<T: std::fmt::Debug>(r: Result<T, ThriftException>) -> ProtocolExceptionKind {
+            match r {
+                Err(ThriftException::Protocol(e)) => e.kind(),
+                r => panic!("expected a protocol error, got {r:?}"),
+            }
+        }
+
+        let mut trans = BytesMut::new();
+        let mut o_prot = test_output_prot_bytesmut(&mut trans);
+        o_prot
+            .write_list_begin(TListIdentifier::new(TType::Double, 2))
+            .unwrap();
+        o_prot.write_double(1.0).unwrap();
+        o_prot.write_double(2.0).unwrap();
+        let list = trans.split().freeze();
+        // a map of 2^20 entries of doubles
+        let map = Bytes::from_static(&[0x80, 0x80, 0x40, 0x44]);
+
+        let mut b = list.clone();
+        let mut i_prot = test_input_prot_bytes(&mut b);
+        assert_eq!(i_prot.read_list_begin().unwrap().size, 2);
+        let mut b = list.slice(..list.len() - 1);
+        let mut i_prot = test_input_prot_bytes(&mut b);
+        assert_eq!(
+            kind(i_prot.read_list_begin()),
+            ProtocolExceptionKind::InvalidData
+        );
+        let mut b = list.clone();
+        let mut i_prot = test_input_prot_bytes(&mut b);
+        i_prot.set_max_container_size(1);
+        assert_eq!(
+            kind(i_prot.read_list_begin()),
+            ProtocolExceptionKind::SizeLimit
+        );
+
+        let mut b = map.clone();
+        let mut i_prot = test_input_prot_bytes(&mut b);
+        assert_eq!(
+            kind(i_prot.read_map_begin()),
+            ProtocolExceptionKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn must_fail_on_lengths_over_max_message_size() {
+        use std::{
+            future::Future,
+            pin::pin,
+            task::{Context, Poll, Waker},
+        };
+
+        use super::TAsyncCompactProtocol;
+        use crate::thrift::{ProtocolExceptionKind, TAsyncInputProtocol};
+
+        fn assert_too_large<T: std::fmt::Debug>(r: Result<T, ThriftException>) {
+            match r {
+                Err(ThriftException::Protocol(e)) => {
+                    assert_eq!(e.kind(), ProtocolExceptionKind::MessageTooLarge)
+                }
+                r => panic!("expected a message too large error, got {r:?}"),
+            }
+        }
+
+        let mut trans = BytesMut::new();
+        let mut o_prot = test_output_prot_bytesmut(&mut trans);
+        o_prot
+            .write_message_begin(&TMessageIdentifier::new(
+                "ping".into(),
+                TMessageType::Call,
+                1,
+            ))
+            .unwrap();
+        let message = trans.freeze();
+        // a binary and a map claiming 2^31 elements
+        let binary = Bytes::from_static(&[0x80, 0x80, 0x80, 0x80, 0x08]);
+        let map = binary.clone();
+
+        let mut b = message.clone();
+        let mut i_prot = test_input_prot_bytes(&mut b);
+        i_prot.set_max_message_size(4);
+        assert_eq!(i_prot.read_message_begin().unwrap().name, "ping");
+        let mut b = message.clone();
+        let mut i_prot = test_input_prot_bytes(&mut b);
+        i_prot.set_max_message_size(3);
+        assert_too_large(i_prot.read_message_begin());
+
+        let mut b = binary.clone();
+        let mut i_prot = test_input_prot_bytes(&mut b);
+        i_prot.set_max_message_size(1024);
+        assert_too_large(i_prot.read_bytes());
+        let mut b = map.clone();
+        let mut i_prot = test_input_prot_bytes(&mut b);
+        i_prot.set_max_message_size(1024);
+        assert_too_large(i_prot.read_map_begin());
+
+        let mut i_prot = TAsyncCompactProtocol::new(&message[..]);
+        i_prot.set_max_message_size(3);
+        match pin!(i_prot.read_message_begin()).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(r) => assert_too_large(r),
+            Poll::Pending => panic!("reading a slice is ready"),
+        }
+        let mut i_prot = TAsyncCompactProtocol::new(&binary[..]);
+        i_prot.set_max_message_size(1024);
+        match pin!(i_prot.read_bytes_vec()).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(r) => assert_too_large(r),
+            Poll::Pending => panic!("reading a slice is ready"),
+        }
+    }
+
     fn assert_no_write<B, F>(mut trans: B, mut write_fn: F)
     where
         B: bytes::Buf,
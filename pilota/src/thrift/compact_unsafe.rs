@@ -0,0 +1,1623 @@
+use std::{ptr, slice, str, sync::Arc};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use faststr::FastStr;
+use integer_encoding::VarInt;
+use linkedbytes::LinkedBytes;
+
+use super::{
+    TFieldIdentifier, TInputProtocol, TLengthProtocol, TListIdentifier, TMapIdentifier,
+    TMessageIdentifier, TMessageType, TOutputProtocol, TSetIdentifier, TStructIdentifier, TType,
+    ThriftException, ZERO_COPY_THRESHOLD,
+    allocation::{self, AllocationTracker, element_size},
+    budget::{Budget, BudgetTracker},
+    canonicalize_nan,
+    compact::{TCompactType, min_element_len, peek_field_header},
+    depth::{self, DepthTracker},
+    error::ProtocolExceptionKind,
+    mark::InputMark,
+    new_protocol_exception,
+    rw_ext::{IOError, check_utf8},
+    unknown::{self, UnknownField, UnknownFieldHook},
+};
+use crate::assert_remaining;
+
+const COMPACT_PROTOCOL_ID: u8 = 0x082;
+const COMPACT_VERSION: u8 = 1;
+const COMPACT_VERSION_MASK: u8 = 0x1f;
+const COMPACT_TYPE_SHIFT_AMOUNT: u8 = 5;
+
+// the longest varints of each width
+const MAX_VARINT16_LEN: usize = 3;
+const MAX_VARINT32_LEN: usize = 5;
+const MAX_VARINT64_LEN: usize = 10;
+
+/// A transport [`TCompactUnsafeOutputProtocol`] writes into, only
+/// implemented for [`BytesMut`] and [`LinkedBytes`].
+///
+/// # Safety
+///
+/// `spare` must return the uninitialized capacity right after the bytes
+/// written so far, which `commit` must append to them.
+pub unsafe trait UnsafeTransport: BufMut + sealed::Sealed {
+    /// Whether `insert_bytes` and `insert_faststr` are supported.
+    const ZERO_COPY: bool = false;
+
+    /// Appends the first `len` bytes of the spare capacity to the written
+    /// bytes.
+    ///
+    /// # Safety
+    ///
+    /// The `len` bytes must have been initialized.
+    unsafe fn commit(&mut self, len: usize);
+
+    /// The pointer to and length of the spare capacity, where the next bytes
+    /// are written.
+    ///
+    /// The capacity is valid until the transport is dropped, reserves more
+    /// or is appended to, and must not be borrowed more than once at a time.
+    fn spare(&mut self) -> (*mut u8, usize);
+
+    /// Appends `b` without copying it, only called if [`Self::ZERO_COPY`].
+    fn insert_bytes(&mut self, _b: Bytes) {
+        unreachable!("the transport does not support zero copy")
+    }
+
+    /// Appends `s` without copying it, only called if [`Self::ZERO_COPY`].
+    fn insert_faststr(&mut self, _s: FastStr) {
+        unreachable!("the transport does not support zero copy")
+    }
+}
+
+unsafe impl UnsafeTransport for BytesMut {
+    #[inline]
+    unsafe fn commit(&mut self, len: usize) {
+        unsafe { self.advance_mut(len) }
+    }
+
+    #[inline]
+    fn spare(&mut self) -> (*mut u8, usize) {
+        let spare = self.spare_capacity_mut();
+        (spare.as_mut_ptr().cast(), spare.len())
+    }
+}
+
+unsafe impl UnsafeTransport for LinkedBytes {
+    const ZERO_COPY: bool = true;
+
+    #[inline]
+    unsafe fn commit(&mut self, len: usize) {
+        unsafe { self.bytes_mut().advance_mut(len) }
+    }
+
+    #[inline]
+    fn spare(&mut self) -> (*mut u8, usize) {
+        self.bytes_mut().spare()
+    }
+
+    #[inline]
+    fn insert_bytes(&mut self, b: Bytes) {
+        self.insert(b);
+    }
+
+    #[inline]
+    fn insert_faststr(&mut self, s: FastStr) {
+        LinkedBytes::insert_faststr(self, s);
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for bytes::BytesMut {}
+
+    impl Sealed for linkedbytes::LinkedBytes {}
+}
+
+/// A compact protocol writer without bounds checks, the counterpart of
+/// [`TBinaryUnsafeOutputProtocol`](super::binary_unsafe::TBinaryUnsafeOutputProtocol).
+///
+/// Over [`LinkedBytes`] with `zero_copy`, binaries and strings of at least
+/// the zero-copy threshold are inserted into it instead of copied, as the
+/// binary one does. They are not counted in the size computed with this
+/// protocol, see `zero_copy_len`.
+///
+/// Its [`TLengthProtocol`] gives an upper bound of the encoded size instead
+/// of the exact one, every varint counting as its longest encoding, which is
+/// cheaper to compute and makes a single reservation enough for the whole
+/// message. Once written, [`Self::index`] is the actual length.
+pub struct TCompactUnsafeOutputProtocol<T> {
+    pub(crate) trans: T,
+    pub(crate) buf: &'static mut [u8],
+    pub(crate) index: usize,
+
+    // Identifier of the last field serialized for a struct.
+    last_write_field_id: i16,
+    // Stack of the last written field ids (new entry added each time a nested struct is written).
+    write_field_id_stack: Vec<i16>,
+    // Field identifier of the boolean field to be written.
+    // Saved because boolean fields and their value are encoded in a single byte
+    pending_write_bool_field_identifier: Option<TFieldIdentifier>,
+
+    zero_copy: bool,
+    zero_copy_len: usize,
+    canonical_nan: bool,
+}
+
+impl<T> TCompactUnsafeOutputProtocol<T> {
+    /// `zero_copy` only takes effect when `T` is [`LinkedBytes`] for output.
+    ///
+    /// # Safety
+    ///
+    /// The 'buf' MUST point to the same area of trans, this is a
+    /// self-referencial struct.
+    ///
+    /// The 'buf' MUST be at least as long as the size computed with this
+    /// protocol of everything written.
+    ///
+    /// [`LinkedBytes`]: linkedbytes::LinkedBytes
+    #[inline]
+    pub unsafe fn new(trans: T, buf: &'static mut [u8], zero_copy: bool) -> Self {
+        Self {
+            trans,
+            buf,
+            index: 0,
+            write_field_id_stack: Vec::with_capacity(24),
+            last_write_field_id: 0,
+            pending_write_bool_field_identifier: None,
+            zero_copy,
+            zero_copy_len: 0,
+            canonical_nan: false,
+        }
+    }
+
+    /// Rewrites every NaN written by `write_double` to the canonical NaN, see
+    /// [`canonicalize_nan`].
+    #[inline]
+    pub fn set_canonical_nan(&mut self, canonical_nan: bool) {
+        self.canonical_nan = canonical_nan;
+    }
+
+    /// Swaps in the transport and buffer of the next message on the same
+    /// connection and resets the per-message state, keeping the settings and
+    /// allocations of the protocol. Returns the previous transport.
+    ///
+    /// # Safety
+    ///
+    /// The same as [`Self::new`].
+    #[inline]
+    pub unsafe fn reset_with(&mut self, trans: T, buf: &'static mut [u8]) -> T {
+        self.buf = buf;
+        self.index = 0;
+        self.last_write_field_id = 0;
+        self.write_field_id_stack.clear();
+        self.pending_write_bool_field_identifier = None;
+        self.zero_copy_len = 0;
+        std::mem::replace(&mut self.trans, trans)
+    }
+
+    #[doc(hidden)]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    fn assert_no_pending_bool_write(&self) {
+        if let Some(ref f) = self.pending_write_bool_field_identifier {
+            panic!("pending bool field {f:?} not written");
+        }
+    }
+}
+
+#[inline]
+fn tcompact_get_compact(tt: TType) -> Result<TCompactType, ThriftException> {
+    tt.try_into().map_err(|_| {
+        new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            format!("invalid ttype {tt:?}"),
+        )
+    })
+}
+
+impl<T> TLengthProtocol for TCompactUnsafeOutputProtocol<T> {
+    #[inline]
+    fn message_begin_len(&mut self, identifier: &TMessageIdentifier) -> usize {
+        2 + MAX_VARINT32_LEN + self.faststr_len(&identifier.name)
+    }
+
+    #[inline]
+    fn message_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn struct_begin_len(&mut self, _identifier: &TStructIdentifier) -> usize {
+        0
+    }
+
+    #[inline]
+    fn struct_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn field_begin_len(&mut self, _field_type: TType, _id: Option<i16>) -> usize {
+        // the long form, a bool field being its header
+        1 + MAX_VARINT16_LEN
+    }
+
+    #[inline]
+    fn field_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn field_stop_len(&mut self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn bool_len(&mut self, _b: bool) -> usize {
+        1
+    }
+
+    #[inline]
+    fn bytes_len(&mut self, b: &[u8]) -> usize {
+        if self.zero_copy && b.len() >= ZERO_COPY_THRESHOLD {
+            self.zero_copy_len += b.len();
+        }
+        MAX_VARINT32_LEN + b.len()
+    }
+
+    #[inline]
+    fn byte_len(&mut self, _b: u8) -> usize {
+        1
+    }
+
+    #[inline]
+    fn uuid_len(&mut self, _u: [u8; 16]) -> usize {
+        16
+    }
+
+    #[inline]
+    fn i8_len(&mut self, _i: i8) -> usize {
+        1
+    }
+
+    #[inline]
+    fn i16_len(&mut self, _i: i16) -> usize {
+        MAX_VARINT16_LEN
+    }
+
+    #[inline]
+    fn i32_len(&mut self, _i: i32) -> usize {
+        MAX_VARINT32_LEN
+    }
+
+    #[inline]
+    fn i64_len(&mut self, _i: i64) -> usize {
+        MAX_VARINT64_LEN
+    }
+
+    #[inline]
+    fn double_len(&mut self, _d: f64) -> usize {
+        8
+    }
+
+    #[inline]
+    fn string_len(&mut self, s: &str) -> usize {
+        MAX_VARINT32_LEN + s.len()
+    }
+
+    #[inline]
+    fn faststr_len(&mut self, s: &FastStr) -> usize {
+        if self.zero_copy && s.len() >= ZERO_COPY_THRESHOLD {
+            self.zero_copy_len += s.len();
+        }
+        MAX_VARINT32_LEN + s.len()
+    }
+
+    #[inline]
+    fn list_begin_len(&mut self, _identifier: TListIdentifier) -> usize {
+        1 + MAX_VARINT32_LEN
+    }
+
+    #[inline]
+    fn list_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn set_begin_len(&mut self, _identifier: TSetIdentifier) -> usize {
+        1 + MAX_VARINT32_LEN
+    }
+
+    #[inline]
+    fn set_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn map_begin_len(&mut self, _identifier: TMapIdentifier) -> usize {
+        MAX_VARINT32_LEN + 1
+    }
+
+    #[inline]
+    fn map_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn bytes_vec_len(&mut self, b: &[u8]) -> usize {
+        MAX_VARINT32_LEN + b.len()
+    }
+
+    #[inline]
+    fn zero_copy_len(&mut self) -> usize {
+        self.zero_copy_len
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.zero_copy_len = 0;
+    }
+}
+
+impl<B: UnsafeTransport> TCompactUnsafeOutputProtocol<&mut B> {
+    #[inline]
+    fn zero_copy(&self, len: usize) -> bool {
+        B::ZERO_COPY && self.zero_copy && len >= ZERO_COPY_THRESHOLD
+    }
+
+    // Hands what is written so far over to the transport for `insert` to
+    // append after it without copying, the buffer then continues after that.
+    #[inline]
+    fn offload(&mut self, insert: impl FnOnce(&mut B)) {
+        unsafe { self.trans.commit(self.index) };
+        self.index = 0;
+        insert(self.trans);
+        // the previous buffer is not used anymore, and this one is only
+        // written through `self.buf` as `new` requires
+        let (ptr, len) = self.trans.spare();
+        self.buf = unsafe { slice::from_raw_parts_mut(ptr, len) };
+    }
+
+    #[inline]
+    fn write_varint(&mut self, mut n: u64) {
+        unsafe {
+            while n >= 0x80 {
+                check_bounds!(self.buf, self.index, 1);
+                *self.buf.get_unchecked_mut(self.index) = n as u8 | 0x80;
+                self.index += 1;
+                n >>= 7;
+            }
+            check_bounds!(self.buf, self.index, 1);
+            *self.buf.get_unchecked_mut(self.index) = n as u8;
+            self.index += 1;
+        }
+    }
+
+    #[inline]
+    fn write_slice(&mut self, s: &[u8]) {
+        check_bounds!(self.buf, self.index, s.len());
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr(), self.buf.as_mut_ptr().add(self.index), s.len());
+            self.index += s.len();
+        }
+    }
+
+    #[inline]
+    fn write_field_header(&mut self, field_type: TCompactType, id: i16) {
+        let field_delta = id.wrapping_sub(self.last_write_field_id);
+        if field_delta > 0 && field_delta < 15 {
+            let _ = self.write_byte(((field_delta as u8) << 4) | (field_type as u8));
+        } else {
+            let _ = self.write_byte(field_type as u8);
+            let _ = self.write_i16(id);
+        }
+        self.last_write_field_id = id;
+    }
+
+    #[inline]
+    fn write_collection_begin(
+        &mut self,
+        element_type: TType,
+        size: usize,
+    ) -> Result<(), ThriftException> {
+        let element_type = tcompact_get_compact(element_type)? as u8;
+        if size <= 14 {
+            self.write_byte(((size as u8) << 4) | element_type)
+        } else {
+            self.write_byte(0xF0 | element_type)?;
+            self.write_varint(size as u64);
+            Ok(())
+        }
+    }
+}
+
+impl<B: UnsafeTransport> TOutputProtocol for TCompactUnsafeOutputProtocol<&mut B> {
+    type BufMut = B;
+
+    #[inline]
+    fn write_message_begin(
+        &mut self,
+        identifier: &TMessageIdentifier,
+    ) -> Result<(), ThriftException> {
+        let mtype = identifier.message_type as u8;
+        self.write_slice(&[
+            COMPACT_PROTOCOL_ID,
+            COMPACT_VERSION | (mtype << COMPACT_TYPE_SHIFT_AMOUNT),
+        ]);
+        // the sequence number is written without zigzag encoding
+        self.write_varint(identifier.sequence_number as u32 as u64);
+        self.write_faststr(identifier.name.clone())
+    }
+
+    #[inline]
+    fn write_message_end(&mut self) -> Result<(), ThriftException> {
+        self.assert_no_pending_bool_write();
+        Ok(())
+    }
+
+    #[inline]
+    fn write_struct_begin(&mut self, _: &TStructIdentifier) -> Result<(), ThriftException> {
+        self.write_field_id_stack.push(self.last_write_field_id);
+        self.last_write_field_id = 0;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_struct_end(&mut self) -> Result<(), ThriftException> {
+        self.assert_no_pending_bool_write();
+        self.last_write_field_id = self.write_field_id_stack.pop().ok_or_else(|| {
+            new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                "WriteStructEnd called without matching WriteStructBegin",
+            )
+        })?;
+        Ok(())
+    }
+
+    #[inline]
+    fn write_field_begin(&mut self, field_type: TType, id: i16) -> Result<(), ThriftException> {
+        match field_type {
+            TType::Bool => {
+                if self.pending_write_bool_field_identifier.is_some() {
+                    panic!(
+                        "should not have a pending bool while writing another bool with id: \
+                        {id:?}",
+                    )
+                }
+                self.pending_write_bool_field_identifier = Some(TFieldIdentifier {
+                    name: None,
+                    field_type,
+                    id: Some(id),
+                });
+            }
+            _ => self.write_field_header(tcompact_get_compact(field_type)?, id),
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_field_end(&mut self) -> Result<(), ThriftException> {
+        self.assert_no_pending_bool_write();
+        Ok(())
+    }
+
+    #[inline]
+    fn write_field_stop(&mut self) -> Result<(), ThriftException> {
+        self.assert_no_pending_bool_write();
+        self.write_byte(TType::Stop as u8)
+    }
+
+    #[inline]
+    fn write_bool(&mut self, b: bool) -> Result<(), ThriftException> {
+        let tc_type = if b {
+            TCompactType::BooleanTrue
+        } else {
+            TCompactType::BooleanFalse
+        };
+        match self.pending_write_bool_field_identifier.take() {
+            Some(pending) => {
+                let field_id = pending.id.expect("bool field should have a field id");
+                self.write_field_header(tc_type, field_id);
+                Ok(())
+            }
+            None => self.write_byte(tc_type as u8),
+        }
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        self.write_varint(b.len() as u64);
+        self.write_bytes_without_len(b)
+    }
+
+    #[inline]
+    fn write_bytes_without_len(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        if self.zero_copy(b.len()) {
+            self.offload(|trans| trans.insert_bytes(b));
+            return Ok(());
+        }
+        self.write_slice(&b);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_byte(&mut self, b: u8) -> Result<(), ThriftException> {
+        check_bounds!(self.buf, self.index, 1);
+        unsafe {
+            *self.buf.get_unchecked_mut(self.index) = b;
+            self.index += 1;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write_uuid(&mut self, u: [u8; 16]) -> Result<(), ThriftException> {
+        self.write_slice(&u);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) -> Result<(), ThriftException> {
+        self.write_byte(i as u8)
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) -> Result<(), ThriftException> {
+        self.write_varint(((i << 1) ^ (i >> 15)) as u16 as u64);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) -> Result<(), ThriftException> {
+        self.write_varint(((i << 1) ^ (i >> 31)) as u32 as u64);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) -> Result<(), ThriftException> {
+        self.write_varint(((i << 1) ^ (i >> 63)) as u64);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
+        let d = if self.canonical_nan {
+            canonicalize_nan(d)
+        } else {
+            d
+        };
+        self.write_slice(&d.to_le_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_string(&mut self, s: &str) -> Result<(), ThriftException> {
+        self.write_bytes_vec(s.as_bytes())
+    }
+
+    #[inline]
+    fn write_faststr(&mut self, s: FastStr) -> Result<(), ThriftException> {
+        self.write_varint(s.len() as u64);
+        if self.zero_copy(s.len()) {
+            self.offload(|trans| trans.insert_faststr(s));
+            return Ok(());
+        }
+        self.write_slice(s.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_list_begin(&mut self, identifier: TListIdentifier) -> Result<(), ThriftException> {
+        self.write_collection_begin(identifier.element_type, identifier.size)
+    }
+
+    #[inline]
+    fn write_list_end(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_set_begin(&mut self, identifier: TSetIdentifier) -> Result<(), ThriftException> {
+        self.write_collection_begin(identifier.element_type, identifier.size)
+    }
+
+    #[inline]
+    fn write_set_end(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_map_begin(&mut self, identifier: TMapIdentifier) -> Result<(), ThriftException> {
+        if identifier.size == 0 {
+            return self.write_byte(TType::Stop as u8);
+        }
+        let types = (tcompact_get_compact(identifier.key_type)? as u8) << 4
+            | tcompact_get_compact(identifier.value_type)? as u8;
+        self.write_varint(identifier.size as u64);
+        self.write_byte(types)
+    }
+
+    #[inline]
+    fn write_map_end(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bytes_vec(&mut self, b: &[u8]) -> Result<(), ThriftException> {
+        self.write_varint(b.len() as u64);
+        self.write_slice(b);
+        Ok(())
+    }
+
+    #[inline]
+    fn buf_mut(&mut self) -> &mut Self::BufMut {
+        self.trans
+    }
+}
+
+/// A compact protocol reader without bounds checks, the counterpart of
+/// [`TBinaryUnsafeInputProtocol`](super::binary_unsafe::TBinaryUnsafeInputProtocol).
+///
+/// Binaries and strings are split off the transport instead of copied.
+pub struct TCompactUnsafeInputProtocol<'a> {
+    pub(crate) trans: &'a mut Bytes,
+    pub(crate) buf: &'a [u8],
+    pub(crate) index: usize,
+
+    last_read_field_id: i16,
+    read_field_id_stack: Vec<i16>,
+    // Boolean value of a bool field, encoded in its header.
+    pending_read_bool_value: Option<bool>,
+    // Bytes taken by the last field header read, reported by `field_begin_len`.
+    last_read_field_header_len: usize,
+    budget: Option<BudgetTracker>,
+    max_message_size: Option<usize>,
+    max_container_size: Option<usize>,
+    max_string_size: Option<usize>,
+    depth: Option<DepthTracker>,
+    reject_duplicate_fields: bool,
+    unknown_field_hook: Option<UnknownFieldHook>,
+    allocation: Option<AllocationTracker>,
+}
+
+impl<'a> TCompactUnsafeInputProtocol<'a> {
+    /// # Safety
+    ///
+    /// The 'trans' MUST hold a complete and valid message, nothing is
+    /// checked while reading it.
+    #[inline]
+    pub unsafe fn new(trans: &'a mut Bytes) -> Self {
+        unsafe {
+            let buf = slice::from_raw_parts(trans.as_ptr(), trans.len());
+            Self {
+                trans,
+                buf,
+                index: 0,
+                last_read_field_id: 0,
+                read_field_id_stack: Vec::with_capacity(24),
+                pending_read_bool_value: None,
+                last_read_field_header_len: 0,
+                budget: None,
+                max_message_size: None,
+                max_container_size: None,
+                max_string_size: None,
+                depth: None,
+                reject_duplicate_fields: false,
+                unknown_field_hook: None,
+                allocation: None,
+            }
+        }
+    }
+
+    /// Swaps in the transport of the next message on the same connection and
+    /// resets the per-message state, keeping the settings and allocations of
+    /// the protocol. Returns the previous transport.
+    ///
+    /// A budget set with `set_budget` restarts at the next message begin.
+    ///
+    /// # Safety
+    ///
+    /// The same as [`Self::new`].
+    #[inline]
+    pub unsafe fn reset_with(&mut self, trans: &'a mut Bytes) -> &'a mut Bytes {
+        unsafe {
+            self.buf = slice::from_raw_parts(trans.as_ptr(), trans.len());
+        }
+        self.index = 0;
+        self.last_read_field_id = 0;
+        self.read_field_id_stack.clear();
+        self.pending_read_bool_value = None;
+        self.last_read_field_header_len = 0;
+        depth::reset(&mut self.depth);
+        allocation::reset(&mut self.allocation);
+        std::mem::replace(&mut self.trans, trans)
+    }
+
+    /// Limits how many bytes and how much time decoding may take, counted
+    /// from the current position and restarted at each message begin.
+    pub fn set_budget(&mut self, budget: Budget) {
+        self.budget = Some(BudgetTracker::new(budget, self.remaining()));
+    }
+
+    /// Fails with [`ProtocolExceptionKind::MessageTooLarge`] when a message
+    /// name, string, binary or container size read from the input is larger
+    /// than `max`, before anything is allocated for it.
+    #[inline]
+    pub fn set_max_message_size(&mut self, max: usize) {
+        self.max_message_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::SizeLimit`] when a list, set or map
+    /// has more elements than `max`. Sizes the rest of the input can't hold
+    /// fail with [`ProtocolExceptionKind::InvalidData`] whatever the limit.
+    #[inline]
+    pub fn set_max_container_size(&mut self, max: usize) {
+        self.max_container_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::InvalidData`] when a string or
+    /// binary, message names included, is longer than `max`.
+    #[inline]
+    pub fn set_max_string_size(&mut self, max: usize) {
+        self.max_string_size = Some(max);
+    }
+
+    /// Fails with [`ProtocolExceptionKind::DepthLimit`] when structs and
+    /// containers, skipped ones included, are nested deeper than `max`.
+    #[inline]
+    pub fn set_max_depth(&mut self, max: usize) {
+        self.depth = Some(DepthTracker::new(max));
+    }
+
+    /// Fails with [`ProtocolExceptionKind::BudgetExceeded`] once decoding a
+    /// message allocates more than `max` bytes in total, see
+    /// [`TBinaryProtocol::set_max_allocation`](super::binary::TBinaryProtocol::set_max_allocation).
+    #[inline]
+    pub fn set_max_allocation(&mut self, max: usize) {
+        self.allocation = Some(AllocationTracker::new(max));
+    }
+
+    /// Makes the generated decode fail with
+    /// [`ProtocolExceptionKind::InvalidData`] when a field of a struct
+    /// appears twice, instead of keeping its last value.
+    #[inline]
+    pub fn set_reject_duplicate_fields(&mut self, reject: bool) {
+        self.reject_duplicate_fields = reject;
+    }
+
+    /// Calls `hook` with the fields the generated decode skips, not knowing
+    /// their id or their type, to notice a peer sending a newer schema.
+    pub fn set_unknown_field_hook(&mut self, hook: impl Fn(UnknownField) + Send + Sync + 'static) {
+        self.unknown_field_hook = Some(Arc::new(hook));
+    }
+
+    // reads the length of a string or a binary
+    #[inline]
+    fn read_str_len(&mut self) -> Result<usize, ThriftException> {
+        let len = self.read_varint() as u32 as usize;
+        super::check_message_size(len, self.max_message_size)?;
+        super::check_string_size(len, self.max_string_size)?;
+        allocation::charge(&mut self.allocation, len)?;
+        Ok(len)
+    }
+
+    #[inline]
+    fn check_container_size(&self, size: usize, min_len: usize) -> Result<(), ThriftException> {
+        super::check_message_size(size, self.max_message_size)?;
+        let remaining = Some(self.remaining());
+        super::check_container_size(size, min_len, remaining, self.max_container_size)
+    }
+
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.trans.len() - self.index
+    }
+
+    #[inline]
+    fn check_budget(&self) -> Result<(), ThriftException> {
+        match &self.budget {
+            Some(budget) => budget.check(self.remaining()),
+            None => Ok(()),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    #[inline]
+    fn advance(&mut self, len: usize) {
+        self.trans.advance(len);
+        self.buf.advance(len);
+        self.index -= len;
+    }
+
+    // Splits the next `len` bytes off the transport.
+    #[inline]
+    fn split_to(&mut self, len: usize) -> Bytes {
+        self.advance(self.index);
+        let val = self.trans.split_to(len);
+        self.buf = unsafe { slice::from_raw_parts(self.trans.as_ptr(), self.trans.len()) };
+        val
+    }
+
+    #[inline]
+    fn read_varint(&mut self) -> u64 {
+        let mut n = 0u64;
+        let mut shift = 0;
+        loop {
+            check_bounds!(self.buf, self.index, 1);
+            let b = unsafe { *self.buf.get_unchecked(self.index) };
+            self.index += 1;
+            n |= ((b & 0x7f) as u64) << shift;
+            if b < 0x80 {
+                return n;
+            }
+            shift += 7;
+        }
+    }
+
+    #[inline]
+    fn read_collection_begin(&mut self) -> Result<(TType, usize), ThriftException> {
+        self.check_budget()?;
+        let header = self.read_byte()?;
+        let element_type = compact_type_to_ttype(header & 0x0F)?;
+        let size = match header >> 4 {
+            15 => self.read_varint() as u32 as usize,
+            size => size as usize,
+        };
+        self.check_container_size(size, min_element_len(element_type))?;
+        allocation::charge_elements(&mut self.allocation, size, element_size(element_type))?;
+        Ok((element_type, size))
+    }
+
+    fn skip_value(&mut self, field_type: TType, depth: i8) -> Result<(), ThriftException> {
+        if depth == 0 {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::DepthLimit,
+                format!("cannot parse past {field_type:?}"),
+            ));
+        }
+        match field_type {
+            TType::Bool => {
+                self.read_bool()?;
+            }
+            TType::I8 => self.index += 1,
+            TType::I16 | TType::I32 | TType::I64 => {
+                self.read_varint();
+            }
+            TType::Double => self.index += 8,
+            TType::Uuid => self.index += 16,
+            TType::Binary => {
+                let len = self.read_varint() as u32 as usize;
+                self.index += len;
+            }
+            TType::Struct => {
+                self.read_struct_begin()?;
+                loop {
+                    let field_ident = self.read_field_begin()?;
+                    if field_ident.field_type == TType::Stop {
+                        break;
+                    }
+                    self.skip_value(field_ident.field_type, depth - 1)?;
+                }
+                self.read_struct_end()?;
+            }
+            TType::List | TType::Set => {
+                let (element_type, size) = self.read_collection_begin()?;
+                for _ in 0..size {
+                    self.skip_value(element_type, depth - 1)?;
+                }
+            }
+            TType::Map => {
+                let map_ident = self.read_map_begin()?;
+                for _ in 0..map_ident.size {
+                    self.skip_value(map_ident.key_type, depth - 1)?;
+                    self.skip_value(map_ident.value_type, depth - 1)?;
+                }
+            }
+            u => {
+                return Err(new_protocol_exception(
+                    ProtocolExceptionKind::InvalidData,
+                    format!("cannot skip field type {u:?}"),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[inline]
+fn compact_type_to_ttype(b: u8) -> Result<TType, ThriftException> {
+    Ok(TType::try_from(TCompactType::try_from(b)?)?)
+}
+
+impl TLengthProtocol for TCompactUnsafeInputProtocol<'_> {
+    #[inline]
+    fn message_begin_len(&mut self, identifier: &TMessageIdentifier) -> usize {
+        2 + (identifier.sequence_number as u32).required_space()
+            + self.faststr_len(&identifier.name)
+    }
+
+    #[inline]
+    fn message_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn struct_begin_len(&mut self, _identifier: &TStructIdentifier) -> usize {
+        0
+    }
+
+    #[inline]
+    fn struct_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn field_begin_len(&mut self, _field_type: TType, _id: Option<i16>) -> usize {
+        // the header has been read already, a bool field's value included
+        self.last_read_field_header_len
+    }
+
+    #[inline]
+    fn field_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn field_stop_len(&mut self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn bool_len(&mut self, _b: bool) -> usize {
+        1
+    }
+
+    #[inline]
+    fn bytes_len(&mut self, b: &[u8]) -> usize {
+        (b.len() as u32).required_space() + b.len()
+    }
+
+    #[inline]
+    fn byte_len(&mut self, _b: u8) -> usize {
+        1
+    }
+
+    #[inline]
+    fn uuid_len(&mut self, _u: [u8; 16]) -> usize {
+        16
+    }
+
+    #[inline]
+    fn i8_len(&mut self, _i: i8) -> usize {
+        1
+    }
+
+    #[inline]
+    fn i16_len(&mut self, i: i16) -> usize {
+        i.required_space()
+    }
+
+    #[inline]
+    fn i32_len(&mut self, i: i32) -> usize {
+        i.required_space()
+    }
+
+    #[inline]
+    fn i64_len(&mut self, i: i64) -> usize {
+        i.required_space()
+    }
+
+    #[inline]
+    fn double_len(&mut self, _d: f64) -> usize {
+        8
+    }
+
+    #[inline]
+    fn string_len(&mut self, s: &str) -> usize {
+        self.bytes_len(s.as_bytes())
+    }
+
+    #[inline]
+    fn faststr_len(&mut self, s: &FastStr) -> usize {
+        self.bytes_len(s.as_bytes())
+    }
+
+    #[inline]
+    fn list_begin_len(&mut self, identifier: TListIdentifier) -> usize {
+        collection_begin_len(identifier.size)
+    }
+
+    #[inline]
+    fn list_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn set_begin_len(&mut self, identifier: TSetIdentifier) -> usize {
+        collection_begin_len(identifier.size)
+    }
+
+    #[inline]
+    fn set_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn map_begin_len(&mut self, identifier: TMapIdentifier) -> usize {
+        if identifier.size == 0 {
+            1
+        } else {
+            (identifier.size as u32).required_space() + 1
+        }
+    }
+
+    #[inline]
+    fn map_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn bytes_vec_len(&mut self, b: &[u8]) -> usize {
+        self.bytes_len(b)
+    }
+}
+
+#[inline]
+fn collection_begin_len(size: usize) -> usize {
+    if size <= 14 {
+        1
+    } else {
+        1 + (size as u32).required_space()
+    }
+}
+
+impl TInputProtocol for TCompactUnsafeInputProtocol<'_> {
+    type Buf = Bytes;
+
+    fn read_message_begin(&mut self) -> Result<TMessageIdentifier, ThriftException> {
+        depth::reset(&mut self.depth);
+        allocation::reset(&mut self.allocation);
+        let remaining = self.remaining();
+        if let Some(budget) = &mut self.budget {
+            budget.rearm(remaining);
+        }
+
+        let compact_id = self.read_byte()?;
+        if compact_id != COMPACT_PROTOCOL_ID {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                format!("invalid compact protocol header {compact_id}"),
+            ));
+        }
+        let type_and_version = self.read_byte()?;
+        let version = type_and_version & COMPACT_VERSION_MASK;
+        if version != COMPACT_VERSION {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::BadVersion,
+                format!("cannot process compact protocol version {version}"),
+            ));
+        }
+        let type_id = type_and_version >> COMPACT_TYPE_SHIFT_AMOUNT;
+        let message_type = TMessageType::try_from(type_id).map_err(|_| {
+            new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                format!("invalid message type {type_id}"),
+            )
+        })?;
+        // the sequence number is read without zigzag decoding
+        let sequence_number = self.read_varint() as u32 as i32;
+        let name = self.read_faststr()?;
+        Ok(TMessageIdentifier::new(name, message_type, sequence_number))
+    }
+
+    #[inline]
+    fn read_message_end(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn read_struct_begin(&mut self) -> Result<Option<TStructIdentifier>, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
+        self.read_field_id_stack.push(self.last_read_field_id);
+        self.last_read_field_id = 0;
+        Ok(None)
+    }
+
+    #[inline]
+    fn read_struct_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
+        self.last_read_field_id = self.read_field_id_stack.pop().ok_or_else(|| {
+            new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                "ReadStructEnd called without matching ReadStructBegin",
+            )
+        })?;
+        Ok(())
+    }
+
+    #[inline]
+    fn read_field_begin(&mut self) -> Result<TFieldIdentifier, ThriftException> {
+        let start = self.index;
+        let header = self.read_byte()?;
+        let field_delta = header >> 4;
+        let field_type = match header & 0x0F {
+            0 => {
+                return Ok(TFieldIdentifier::new::<Option<&'static str>, Option<i16>>(
+                    None,
+                    TType::Stop,
+                    None,
+                ));
+            }
+            b => {
+                let tc_type = TCompactType::try_from(b)?;
+                match tc_type {
+                    TCompactType::BooleanTrue => self.pending_read_bool_value = Some(true),
+                    TCompactType::BooleanFalse => self.pending_read_bool_value = Some(false),
+                    _ => {}
+                }
+                TType::try_from(tc_type)?
+            }
+        };
+        if field_delta != 0 {
+            self.last_read_field_id += field_delta as i16;
+        } else {
+            self.last_read_field_id = self.read_i16()?;
+        }
+        self.last_read_field_header_len = self.index - start;
+        Ok(TFieldIdentifier::new::<Option<&'static str>, i16>(
+            None,
+            field_type,
+            self.last_read_field_id,
+        ))
+    }
+
+    #[inline]
+    fn read_field_end(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn rejects_duplicate_fields(&self) -> bool {
+        self.reject_duplicate_fields
+    }
+
+    #[inline]
+    fn on_unknown_field(
+        &self,
+        struct_name: &'static str,
+        field: &TFieldIdentifier,
+        type_mismatch: bool,
+    ) {
+        unknown::report(&self.unknown_field_hook, struct_name, field, type_mismatch);
+    }
+
+    #[inline]
+    fn peek_field_begin(&mut self) -> Result<TFieldIdentifier, ThriftException> {
+        let end = self.buf.len().min(self.index + 4);
+        peek_field_header(&self.buf[self.index..end], self.last_read_field_id, None)
+    }
+
+    #[inline]
+    fn read_bool(&mut self) -> Result<bool, ThriftException> {
+        match self.pending_read_bool_value.take() {
+            Some(b) => Ok(b),
+            None => Ok(self.read_byte()? == TCompactType::BooleanTrue as u8),
+        }
+    }
+
+    #[inline]
+    fn read_bytes(&mut self) -> Result<Bytes, ThriftException> {
+        let len = self.read_str_len()?;
+        Ok(self.split_to(len))
+    }
+
+    #[inline]
+    fn get_bytes(
+        &mut self,
+        ptr: Option<*const u8>,
+        mut len: usize,
+    ) -> Result<Bytes, ThriftException> {
+        if ptr.is_none() {
+            len -= self.index;
+            self.advance(self.index);
+        }
+        self.index = 0;
+        let val = self.trans.split_to(len);
+        self.buf = unsafe { slice::from_raw_parts(self.trans.as_ptr(), self.trans.len()) };
+        Ok(val)
+    }
+
+    #[inline]
+    fn read_uuid(&mut self) -> Result<[u8; 16], ThriftException> {
+        check_bounds!(self.buf, self.index, 16);
+        unsafe {
+            let u = self
+                .buf
+                .get_unchecked(self.index..self.index + 16)
+                .try_into()
+                .unwrap_unchecked();
+            self.index += 16;
+            Ok(u)
+        }
+    }
+
+    #[inline]
+    fn read_i8(&mut self) -> Result<i8, ThriftException> {
+        Ok(self.read_byte()? as i8)
+    }
+
+    #[inline]
+    fn read_i16(&mut self) -> Result<i16, ThriftException> {
+        let n = self.read_varint() as u16;
+        Ok((n >> 1) as i16 ^ -((n & 1) as i16))
+    }
+
+    #[inline]
+    fn read_i32(&mut self) -> Result<i32, ThriftException> {
+        let n = self.read_varint() as u32;
+        Ok((n >> 1) as i32 ^ -((n & 1) as i32))
+    }
+
+    #[inline]
+    fn read_i64(&mut self) -> Result<i64, ThriftException> {
+        let n = self.read_varint();
+        Ok((n >> 1) as i64 ^ -((n & 1) as i64))
+    }
+
+    #[inline]
+    fn read_double(&mut self) -> Result<f64, ThriftException> {
+        check_bounds!(self.buf, self.index, 8);
+        unsafe {
+            let val = self.buf.get_unchecked(self.index..self.index + 8);
+            self.index += 8;
+            Ok(f64::from_le_bytes(val.try_into().unwrap_unchecked()))
+        }
+    }
+
+    #[inline]
+    fn read_string(&mut self) -> Result<String, ThriftException> {
+        let len = self.read_str_len()?;
+        check_bounds!(self.buf, self.index, len);
+        unsafe {
+            let bytes = self.buf.get_unchecked(self.index..self.index + len);
+            check_utf8(bytes)?;
+            let val = str::from_utf8_unchecked(bytes).to_string();
+            self.index += len;
+            Ok(val)
+        }
+    }
+
+    #[inline]
+    fn read_faststr(&mut self) -> Result<FastStr, ThriftException> {
+        let len = self.read_str_len()?;
+        let bytes = self.split_to(len);
+        check_utf8(&bytes)?;
+        Ok(unsafe { FastStr::from_bytes_unchecked(bytes) })
+    }
+
+    #[inline]
+    fn read_list_begin(&mut self) -> Result<TListIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        let (element_type, size) = self.read_collection_begin()?;
+        Ok(TListIdentifier::new(element_type, size))
+    }
+
+    #[inline]
+    fn read_list_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
+        Ok(())
+    }
+
+    #[inline]
+    fn read_set_begin(&mut self) -> Result<TSetIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        let (element_type, size) = self.read_collection_begin()?;
+        Ok(TSetIdentifier::new(element_type, size))
+    }
+
+    #[inline]
+    fn read_set_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
+        Ok(())
+    }
+
+    #[inline]
+    fn read_map_begin(&mut self) -> Result<TMapIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        self.check_budget()?;
+        let size = self.read_varint() as u32 as usize;
+        if size == 0 {
+            return Ok(TMapIdentifier::new(TType::Stop, TType::Stop, 0));
+        }
+        let types = self.read_byte()?;
+        let key_type = compact_type_to_ttype(types >> 4)?;
+        let value_type = compact_type_to_ttype(types & 0x0F)?;
+        self.check_container_size(
+            size,
+            min_element_len(key_type) + min_element_len(value_type),
+        )?;
+        let entry_size = element_size(key_type) + element_size(value_type);
+        allocation::charge_elements(&mut self.allocation, size, entry_size)?;
+        Ok(TMapIdentifier::new(key_type, value_type, size))
+    }
+
+    #[inline]
+    fn read_map_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
+        Ok(())
+    }
+
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, ThriftException> {
+        check_bounds!(self.buf, self.index, 1);
+        unsafe {
+            let val = *self.buf.get_unchecked(self.index);
+            self.index += 1;
+            Ok(val)
+        }
+    }
+
+    #[inline]
+    fn peek_byte(&mut self) -> Result<u8, ThriftException> {
+        assert_remaining!(self.index < self.buf.len());
+        Ok(self.buf[self.index])
+    }
+
+    #[inline]
+    fn read_bytes_vec(&mut self) -> Result<Vec<u8>, ThriftException> {
+        let len = self.read_str_len()?;
+        Ok(self.split_to(len).into())
+    }
+
+    #[inline]
+    fn buf(&mut self) -> &mut Self::Buf {
+        self.trans
+    }
+
+    fn skip_capture(&mut self, field_type: TType) -> Result<Bytes, ThriftException> {
+        self.advance(self.index);
+        let len = self.skip_till_depth(field_type, crate::thrift::MAXIMUM_SKIP_DEPTH)?;
+        self.index = 0;
+        let val = self.trans.split_to(len);
+        self.buf = unsafe { slice::from_raw_parts(self.trans.as_ptr(), self.trans.len()) };
+        Ok(val)
+    }
+
+    fn mark(&mut self) -> Result<InputMark, ThriftException> {
+        let mut input = self.trans.clone();
+        input.advance(self.index);
+        Ok(InputMark {
+            input,
+            budget: self.budget,
+            depth: self.depth,
+            allocation: self.allocation,
+            last_read_field_id: self.last_read_field_id,
+            read_field_id_stack: self.read_field_id_stack.clone(),
+            pending_read_bool_value: self.pending_read_bool_value,
+            last_read_field_header_len: self.last_read_field_header_len,
+            ..Default::default()
+        })
+    }
+
+    fn reset_to_mark(&mut self, mark: &InputMark) -> Result<(), ThriftException> {
+        *self.trans = mark.input.clone();
+        // SAFETY: `buf` is the input just restored, held by `trans`
+        self.buf = unsafe { slice::from_raw_parts(self.trans.as_ptr(), self.trans.len()) };
+        self.index = 0;
+        self.budget = mark.budget;
+        self.depth = mark.depth;
+        self.allocation = mark.allocation;
+        self.last_read_field_id = mark.last_read_field_id;
+        self.read_field_id_stack
+            .clone_from(&mark.read_field_id_stack);
+        self.pending_read_bool_value = mark.pending_read_bool_value;
+        self.last_read_field_header_len = mark.last_read_field_header_len;
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, field_type: TType) -> Result<usize, ThriftException> {
+        // move the transport to the field header, as `get_bytes` takes the
+        // skipped field from there
+        debug_assert!(self.index >= self.last_read_field_header_len);
+        self.advance(self.index - self.last_read_field_header_len);
+        self.skip_till_depth(field_type, crate::thrift::MAXIMUM_SKIP_DEPTH)
+    }
+
+    #[inline]
+    fn skip_till_depth(&mut self, field_type: TType, depth: i8) -> Result<usize, ThriftException> {
+        let start = self.index;
+        self.skip_value(field_type, depth)?;
+        Ok(self.index - start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::thrift::{
+        TInputProtocol, TMessageType,
+        compact::{TCompactInputProtocol, TCompactOutputProtocol},
+    };
+
+    fn write_all<P: TOutputProtocol>(p: &mut P) {
+        let ident = TMessageIdentifier::new("echo".into(), TMessageType::Call, -3);
+        p.write_message_begin(&ident).unwrap();
+        p.write_struct_begin(&TStructIdentifier { name: "Req" })
+            .unwrap();
+        p.write_field_begin(TType::Bool, 1).unwrap();
+        p.write_bool(true).unwrap();
+        p.write_field_begin(TType::I64, 20).unwrap();
+        p.write_i64(i64::MIN).unwrap();
+        p.write_field_begin(TType::List, 21).unwrap();
+        p.write_list_begin(TListIdentifier::new(TType::I32, 16))
+            .unwrap();
+        for i in -8..8 {
+            p.write_i32(i * 100_000).unwrap();
+        }
+        p.write_list_end().unwrap();
+        p.write_field_begin(TType::Map, 22).unwrap();
+        p.write_map_begin(TMapIdentifier::new(TType::Binary, TType::Double, 1))
+            .unwrap();
+        p.write_faststr("pi".into()).unwrap();
+        p.write_double(std::f64::consts::PI).unwrap();
+        p.write_map_end().unwrap();
+        p.write_field_begin(TType::I16, 23).unwrap();
+        p.write_i16(i16::MIN).unwrap();
+        p.write_field_stop().unwrap();
+        p.write_struct_end().unwrap();
+        p.write_message_end().unwrap();
+    }
+
+    // the lengths of what write_all writes
+    fn len_all<P: TLengthProtocol>(p: &mut P) -> usize {
+        let ident = TMessageIdentifier::new("echo".into(), TMessageType::Call, -3);
+        p.message_begin_len(&ident)
+            + p.struct_begin_len(&TStructIdentifier { name: "Req" })
+            + p.field_begin_len(TType::Bool, Some(1))
+            + p.bool_len(true)
+            + p.field_begin_len(TType::I64, Some(20))
+            + p.i64_len(i64::MIN)
+            + p.field_begin_len(TType::List, Some(21))
+            + p.list_begin_len(TListIdentifier::new(TType::I32, 16))
+            + (-8..8).map(|i| p.i32_len(i * 100_000)).sum::<usize>()
+            + p.field_begin_len(TType::Map, Some(22))
+            + p.map_begin_len(TMapIdentifier::new(TType::Binary, TType::Double, 1))
+            + p.faststr_len(&"pi".into())
+            + p.double_len(std::f64::consts::PI)
+            + p.field_begin_len(TType::I16, Some(23))
+            + p.i16_len(i16::MIN)
+            + p.field_stop_len()
+            + p.struct_end_len()
+            + p.message_end_len()
+    }
+
+    #[test]
+    fn test_compact_unsafe() {
+        let mut expected = BytesMut::new();
+        write_all(&mut TCompactOutputProtocol::new(&mut expected, false));
+
+        let mut buf = BytesMut::new();
+        unsafe {
+            let size = len_all(&mut TCompactUnsafeOutputProtocol::new((), &mut [], false));
+            assert!(size >= expected.len());
+            buf.reserve(size);
+            let spare = buf.spare_capacity_mut();
+            let s = std::slice::from_raw_parts_mut(spare.as_mut_ptr().cast(), spare.len());
+            let mut p = TCompactUnsafeOutputProtocol::new(&mut buf, s, false);
+            write_all(&mut p);
+            let len = p.index();
+            buf.advance_mut(len);
+        }
+        assert_eq!(buf, expected);
+
+        let mut bytes = buf.freeze();
+        let mut p = TCompactInputProtocol::new(&mut bytes);
+        assert_eq!(p.read_message_begin().unwrap().sequence_number, -3);
+    }
+
+    #[test]
+    fn test_compact_unsafe_zero_copy() {
+        let large = FastStr::from_string("x".repeat(ZERO_COPY_THRESHOLD));
+        let write = |p: &mut TCompactUnsafeOutputProtocol<&mut LinkedBytes>| {
+            p.write_struct_begin(&TStructIdentifier { name: "Req" })
+                .unwrap();
+            p.write_field_begin(TType::Binary, 1).unwrap();
+            p.write_faststr(large.clone()).unwrap();
+            p.write_field_begin(TType::Binary, 2).unwrap();
+            p.write_bytes(large.clone().into_bytes()).unwrap();
+            p.write_field_begin(TType::I32, 3).unwrap();
+            p.write_i32(-1).unwrap();
+            p.write_field_stop().unwrap();
+            p.write_struct_end().unwrap();
+        };
+
+        let mut expected = BytesMut::new();
+        let mut p = TCompactOutputProtocol::new(&mut expected, false);
+        p.write_struct_begin(&TStructIdentifier { name: "Req" })
+            .unwrap();
+        p.write_field_begin(TType::Binary, 1).unwrap();
+        p.write_faststr(large.clone()).unwrap();
+        p.write_field_begin(TType::Binary, 2).unwrap();
+        p.write_bytes(large.clone().into_bytes()).unwrap();
+        p.write_field_begin(TType::I32, 3).unwrap();
+        p.write_i32(-1).unwrap();
+        p.write_field_stop().unwrap();
+        p.write_struct_end().unwrap();
+
+        let mut linked = LinkedBytes::new();
+        unsafe {
+            let mut p = TCompactUnsafeOutputProtocol::new((), &mut [], true);
+            let size = p.faststr_len(&large) + p.bytes_len(large.as_bytes()) + 16;
+            linked.reserve(size - p.zero_copy_len());
+            let (ptr, len) = linked.spare();
+            let s = std::slice::from_raw_parts_mut(ptr, len);
+            let mut p = TCompactUnsafeOutputProtocol::new(&mut linked, s, true);
+            write(&mut p);
+            let len = p.index();
+            linked.commit(len);
+        }
+        assert_eq!(linked.iter_list().count(), 4);
+        assert_eq!(linked.concat(), expected);
+    }
+
+    #[test]
+    fn test_compact_unsafe_input() {
+        let mut buf = BytesMut::new();
+        write_all(&mut TCompactOutputProtocol::new(&mut buf, false));
+        let mut bytes = buf.freeze();
+        let mut p = unsafe { TCompactUnsafeInputProtocol::new(&mut bytes) };
+        let ident = p.read_message_begin().unwrap();
+        assert_eq!(ident.name, "echo");
+        assert_eq!(ident.sequence_number, -3);
+        p.read_struct_begin().unwrap();
+        assert_eq!(p.read_field_begin().unwrap().field_type, TType::Bool);
+        assert!(p.read_bool().unwrap());
+        assert_eq!(p.field_begin_len(TType::Bool, Some(1)), 1);
+        assert_eq!(p.read_field_begin().unwrap().id, Some(20));
+        assert_eq!(p.read_i64().unwrap(), i64::MIN);
+
+        // an unknown field is skipped and kept as the generated code does
+        let field = p.read_field_begin().unwrap();
+        assert_eq!(field.id, Some(21));
+        let begin = p.buf().chunk().as_ptr();
+        let mut offset = p.field_begin_len(field.field_type, field.id);
+        offset += p.skip(field.field_type).unwrap();
+        let unknown = p.get_bytes(Some(begin), offset).unwrap();
+        // the header in short form, 1 after the id 20
+        let mut expected = BytesMut::from(&[0x19][..]);
+        let mut o = TCompactOutputProtocol::new(&mut expected, false);
+        o.write_list_begin(TListIdentifier::new(TType::I32, 16))
+            .unwrap();
+        for i in -8..8 {
+            o.write_i32(i * 100_000).unwrap();
+        }
+        assert_eq!(unknown, expected);
+
+        let map = p.read_field_begin().unwrap();
+        assert_eq!(map.id, Some(22));
+        let map = p.read_map_begin().unwrap();
+        assert_eq!(map, TMapIdentifier::new(TType::Binary, TType::Double, 1));
+        assert_eq!(p.read_faststr().unwrap(), "pi");
+        assert_eq!(p.read_double().unwrap(), std::f64::consts::PI);
+        assert_eq!(p.read_field_begin().unwrap().id, Some(23));
+        assert_eq!(p.read_i16().unwrap(), i16::MIN);
+        assert_eq!(p.read_field_begin().unwrap().field_type, TType::Stop);
+        p.read_struct_end().unwrap();
+        let len = p.index();
+        assert_eq!(p.buf().len(), len);
+    }
+}
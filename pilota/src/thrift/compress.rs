@@ -0,0 +1,137 @@
+//! Compression of single binary fields.
+//!
+//! Fields annotated with `(pilota.compress = "zstd")` are compressed by the
+//! generated encoder and decompressed by the generated decoder, the field
+//! itself keeps its plain value. On the wire the field is still a binary,
+//! prefixed by a 5-byte header: the [`Compression`] used followed by the
+//! big-endian length of the uncompressed value.
+
+use std::io::Read;
+
+use bytes::{BufMut, Bytes, BytesMut};
+
+use super::{ProtocolExceptionKind, ThriftException, new_protocol_exception};
+
+const HEADER_LEN: usize = 5;
+
+/// The codec of a compressed field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Compression {
+    /// Stored as is, used when compressing would not make the value smaller.
+    None = 0,
+    Zstd = 1,
+}
+
+impl TryFrom<u8> for Compression {
+    type Error = ThriftException;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Zstd),
+            _ => Err(new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                format!("unknown compression {value}"),
+            )),
+        }
+    }
+}
+
+/// Compresses `data` with `compression` and prepends the header.
+///
+/// The generated `size` calls it too, so a compressed field costs two
+/// compressions per encode.
+pub fn compress(compression: Compression, data: &[u8]) -> Result<Bytes, ThriftException> {
+    let len = u32::try_from(data.len()).map_err(|_| {
+        new_protocol_exception(
+            ProtocolExceptionKind::SizeLimit,
+            format!("cannot compress {} bytes", data.len()),
+        )
+    })?;
+
+    if compression == Compression::Zstd {
+        let compressed =
+            zstd::bulk::compress(data, zstd::DEFAULT_COMPRESSION_LEVEL).map_err(|e| {
+                new_protocol_exception(
+                    ProtocolExceptionKind::InvalidData,
+                    format!("zstd compression failed: {e}"),
+                )
+            })?;
+        if compressed.len() < data.len() {
+            return Ok(with_header(Compression::Zstd, len, &compressed));
+        }
+    }
+    Ok(with_header(Compression::None, len, data))
+}
+
+fn with_header(compression: Compression, len: u32, payload: &[u8]) -> Bytes {
+    let mut buf = BytesMut::with_capacity(HEADER_LEN + payload.len());
+    buf.put_u8(compression as u8);
+    buf.put_u32(len);
+    buf.put_slice(payload);
+    buf.freeze()
+}
+
+/// Reverses [`compress`].
+pub fn decompress(data: Bytes) -> Result<Bytes, ThriftException> {
+    if data.len() < HEADER_LEN {
+        return Err(new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            format!("compressed field of {} bytes misses its header", data.len()),
+        ));
+    }
+    let compression = Compression::try_from(data[0])?;
+    let len = u32::from_be_bytes(data[1..HEADER_LEN].try_into().unwrap()) as usize;
+
+    let value = match compression {
+        Compression::None => data.slice(HEADER_LEN..),
+        Compression::Zstd => {
+            let mut out = Vec::new();
+            // reads one byte past `len` to notice values longer than declared
+            // without ever allocating more than the declared length
+            zstd::stream::read::Decoder::new(&data[HEADER_LEN..])
+                .and_then(|d| d.take(len as u64 + 1).read_to_end(&mut out))
+                .map_err(|e| {
+                    new_protocol_exception(
+                        ProtocolExceptionKind::InvalidData,
+                        format!("zstd decompression failed: {e}"),
+                    )
+                })?;
+            Bytes::from(out)
+        }
+    };
+    if value.len() != len {
+        return Err(new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            format!(
+                "decompressed field has {} bytes, expected {len}",
+                value.len()
+            ),
+        ));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compress_roundtrip() {
+        let data = Bytes::from(b"pilota ".repeat(100));
+        let compressed = compress(Compression::Zstd, &data).unwrap();
+        assert_eq!(compressed[0], Compression::Zstd as u8);
+        assert!(compressed.len() < data.len());
+        assert_eq!(decompress(compressed.clone()).unwrap(), data);
+
+        let small = compress(Compression::Zstd, b"ab").unwrap();
+        assert_eq!(&small[..], &[0, 0, 0, 0, 2, b'a', b'b']);
+        assert_eq!(&decompress(small).unwrap()[..], b"ab");
+
+        let mut lying = BytesMut::from(&compressed[..]);
+        lying[4] -= 1;
+        assert!(decompress(lying.freeze()).is_err());
+        assert!(decompress(Bytes::from_static(&[1, 0])).is_err());
+    }
+}
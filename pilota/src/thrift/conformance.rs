@@ -0,0 +1,1739 @@
+//! Edge values every protocol must roundtrip, written and read by each
+//! implementation: add new protocols to the matrix at the bottom.
+
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use bytes::{Buf, Bytes, BytesMut};
+use linkedbytes::LinkedBytes;
+
+use super::{
+    MAXIMUM_SKIP_DEPTH, TAsyncInputProtocol, TInputProtocol, TLengthProtocol, TListIdentifier,
+    TMapIdentifier, TMessageIdentifier, TMessageType, TOutputProtocol, TSetIdentifier,
+    TStructIdentifier, TType, binary, binary_le, compact, reader, staging::Staged,
+};
+
+#[derive(Clone, Copy, Debug)]
+enum Value {
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    Double(f64),
+    String(&'static str),
+    Binary(&'static [u8]),
+    BoolList(&'static [bool]),
+    StringList(&'static [&'static str]),
+    EmptyList(TType),
+    EmptySet(TType),
+    EmptyMap(TType, TType),
+    // structs nested this deep, skipped when read
+    Nested(usize),
+}
+
+// the utf-8 encodings switch length at each of these, around the surrogates
+const STRINGS: &[&str] = &[
+    "",
+    "\u{7f}",
+    "\u{80}",
+    "\u{7ff}",
+    "\u{800}",
+    "\u{d7ff}",
+    "\u{e000}",
+    "\u{ffff}",
+    "\u{10000}",
+    "\u{10ffff}",
+];
+
+fn values() -> Vec<Value> {
+    let mut values = vec![
+        Value::Bool(true),
+        Value::Bool(false),
+        Value::I8(i8::MIN),
+        Value::I8(i8::MAX),
+        Value::I16(i16::MIN),
+        Value::I16(i16::MAX),
+        Value::I32(i32::MIN),
+        Value::I32(i32::MAX),
+        Value::I32(-1),
+        Value::I64(i64::MIN),
+        Value::I64(i64::MAX),
+        Value::I64(0),
+        Value::Double(-0.0),
+        Value::Double(f64::from_bits(1)),
+        Value::Double(f64::MIN_POSITIVE),
+        Value::Double(f64::MAX),
+        Value::Double(f64::NEG_INFINITY),
+        Value::Double(f64::NAN),
+        Value::Binary(b""),
+        Value::Binary(&[0, 0xff]),
+        Value::BoolList(&[true, false]),
+        Value::StringList(STRINGS),
+        Value::EmptyList(TType::I32),
+        Value::EmptyList(TType::Struct),
+        Value::EmptySet(TType::Binary),
+        Value::EmptyMap(TType::I64, TType::Struct),
+        Value::EmptyMap(TType::Binary, TType::List),
+        Value::Nested(MAXIMUM_SKIP_DEPTH as usize),
+    ];
+    values.extend(STRINGS.iter().copied().map(Value::String));
+    values
+}
+
+// small deltas, the largest id and then a smaller one, as the compact
+// protocol encodes them differently
+fn field_id(i: usize) -> i16 {
+    match i {
+        0 => 1,
+        1 => 16,
+        2 => i16::MAX,
+        i => i as i16,
+    }
+}
+
+fn ttype(value: &Value) -> TType {
+    match value {
+        Value::Bool(_) => TType::Bool,
+        Value::I8(_) => TType::I8,
+        Value::I16(_) => TType::I16,
+        Value::I32(_) => TType::I32,
+        Value::I64(_) => TType::I64,
+        Value::Double(_) => TType::Double,
+        Value::String(_) | Value::Binary(_) => TType::Binary,
+        Value::BoolList(_) | Value::StringList(_) | Value::EmptyList(_) => TType::List,
+        Value::EmptySet(_) => TType::Set,
+        Value::EmptyMap(..) => TType::Map,
+        Value::Nested(_) => TType::Struct,
+    }
+}
+
+fn message() -> TMessageIdentifier {
+    TMessageIdentifier::new("conformance".into(), TMessageType::Call, i32::MIN)
+}
+
+const STRUCT: TStructIdentifier = TStructIdentifier { name: "Edges" };
+
+fn write_nested<P: TOutputProtocol>(p: &mut P, depth: usize) {
+    p.write_struct_begin(&STRUCT).unwrap();
+    if depth > 1 {
+        p.write_field_begin(TType::Struct, 1).unwrap();
+        write_nested(p, depth - 1);
+        p.write_field_end().unwrap();
+    }
+    p.write_field_stop().unwrap();
+    p.write_struct_end().unwrap();
+}
+
+fn write_edges<P: TOutputProtocol>(p: &mut P) {
+    p.write_message_begin(&message()).unwrap();
+    p.write_struct_begin(&STRUCT).unwrap();
+    for (i, value) in values().into_iter().enumerate() {
+        p.write_field_begin(ttype(&value), field_id(i)).unwrap();
+        match value {
+            Value::Bool(v) => p.write_bool(v).unwrap(),
+            Value::I8(v) => p.write_i8(v).unwrap(),
+            Value::I16(v) => p.write_i16(v).unwrap(),
+            Value::I32(v) => p.write_i32(v).unwrap(),
+            Value::I64(v) => p.write_i64(v).unwrap(),
+            Value::Double(v) => p.write_double(v).unwrap(),
+            Value::String(v) => p.write_faststr(v.into()).unwrap(),
+            Value::Binary(v) => p.write_bytes(Bytes::from_static(v)).unwrap(),
+            Value::BoolList(v) => {
+                p.write_list_begin(TListIdentifier::new(TType::Bool, v.len()))
+                    .unwrap();
+                for b in v {
+                    p.write_bool(*b).unwrap();
+                }
+                p.write_list_end().unwrap();
+            }
+            Value::StringList(v) => {
+                p.write_list_begin(TListIdentifier::new(TType::Binary, v.len()))
+                    .unwrap();
+                for s in v {
+                    p.write_faststr((*s).into()).unwrap();
+                }
+                p.write_list_end().unwrap();
+            }
+            Value::EmptyList(element) => {
+                p.write_list_begin(TListIdentifier::new(element, 0))
+                    .unwrap();
+                p.write_list_end().unwrap();
+            }
+            Value::EmptySet(element) => {
+                p.write_set_begin(TSetIdentifier::new(element, 0)).unwrap();
+                p.write_set_end().unwrap();
+            }
+            Value::EmptyMap(key, value) => {
+                p.write_map_begin(TMapIdentifier::new(key, value, 0))
+                    .unwrap();
+                p.write_map_end().unwrap();
+            }
+            Value::Nested(depth) => write_nested(p, depth),
+        }
+        p.write_field_end().unwrap();
+    }
+    p.write_field_stop().unwrap();
+    p.write_struct_end().unwrap();
+    p.write_message_end().unwrap();
+}
+
+// the lengths of what `write_edges` writes, for the unsafe writers
+fn edges_len<P: TLengthProtocol>(p: &mut P) -> usize {
+    fn nested_len<P: TLengthProtocol>(p: &mut P, depth: usize) -> usize {
+        let mut len = p.struct_begin_len(&STRUCT);
+        if depth > 1 {
+            len += p.field_begin_len(TType::Struct, Some(1));
+            len += nested_len(p, depth - 1);
+            len += p.field_end_len();
+        }
+        len + p.field_stop_len() + p.struct_end_len()
+    }
+
+    let mut len = p.message_begin_len(&message()) + p.struct_begin_len(&STRUCT);
+    for (i, value) in values().into_iter().enumerate() {
+        len += p.field_begin_len(ttype(&value), Some(field_id(i)));
+        len += match value {
+            Value::Bool(v) => p.bool_len(v),
+            Value::I8(v) => p.i8_len(v),
+            Value::I16(v) => p.i16_len(v),
+            Value::I32(v) => p.i32_len(v),
+            Value::I64(v) => p.i64_len(v),
+            Value::Double(v) => p.double_len(v),
+            Value::String(v) => p.faststr_len(&v.into()),
+            Value::Binary(v) => p.bytes_len(&Bytes::from_static(v)),
+            Value::BoolList(v) => {
+                p.list_begin_len(TListIdentifier::new(TType::Bool, v.len()))
+                    + v.iter().map(|b| p.bool_len(*b)).sum::<usize>()
+                    + p.list_end_len()
+            }
+            Value::StringList(v) => {
+                p.list_begin_len(TListIdentifier::new(TType::Binary, v.len()))
+                    + v.iter().map(|s| p.faststr_len(&(*s).into())).sum::<usize>()
+                    + p.list_end_len()
+            }
+            Value::EmptyList(element) => {
+                p.list_begin_len(TListIdentifier::new(element, 0)) + p.list_end_len()
+            }
+            Value::EmptySet(element) => {
+                p.set_begin_len(TSetIdentifier::new(element, 0)) + p.set_end_len()
+            }
+            Value::EmptyMap(key, value) => {
+                p.map_begin_len(TMapIdentifier::new(key, value, 0)) + p.map_end_len()
+            }
+            Value::Nested(depth) => nested_len(p, depth),
+        };
+        len += p.field_end_len();
+    }
+    len + p.field_stop_len() + p.struct_end_len() + p.message_end_len()
+}
+
+fn assert_double(actual: f64, expected: f64) {
+    assert_eq!(actual.to_bits(), expected.to_bits());
+}
+
+fn read_edges<P: TInputProtocol>(p: &mut P) {
+    assert_eq!(p.read_message_begin().unwrap(), message());
+    p.read_struct_begin().unwrap();
+    for (i, value) in values().into_iter().enumerate() {
+        let field = p.read_field_begin().unwrap();
+        assert_eq!(field.id, Some(field_id(i)), "{value:?}");
+        assert_eq!(field.field_type, ttype(&value), "{value:?}");
+        match value {
+            Value::Bool(v) => assert_eq!(p.read_bool().unwrap(), v),
+            Value::I8(v) => assert_eq!(p.read_i8().unwrap(), v),
+            Value::I16(v) => assert_eq!(p.read_i16().unwrap(), v),
+            Value::I32(v) => assert_eq!(p.read_i32().unwrap(), v),
+            Value::I64(v) => assert_eq!(p.read_i64().unwrap(), v),
+            Value::Double(v) => assert_double(p.read_double().unwrap(), v),
+            Value::String(v) => assert_eq!(p.read_faststr().unwrap(), v),
+            Value::Binary(v) => assert_eq!(p.read_bytes().unwrap(), v),
+            Value::BoolList(v) => {
+                let list = p.read_list_begin().unwrap();
+                assert_eq!(list, TListIdentifier::new(TType::Bool, v.len()));
+                for b in v {
+                    assert_eq!(p.read_bool().unwrap(), *b);
+                }
+                p.read_list_end().unwrap();
+            }
+            Value::StringList(v) => {
+                let list = p.read_list_begin().unwrap();
+                assert_eq!(list, TListIdentifier::new(TType::Binary, v.len()));
+                for s in v {
+                    assert_eq!(p.read_string().unwrap(), *s);
+                }
+                p.read_list_end().unwrap();
+            }
+            Value::EmptyList(_) => {
+                assert_eq!(p.read_list_begin().unwrap().size, 0);
+                p.read_list_end().unwrap();
+            }
+            Value::EmptySet(_) => {
+                assert_eq!(p.read_set_begin().unwrap().size, 0);
+                p.read_set_end().unwrap();
+            }
+            Value::EmptyMap(..) => {
+                assert_eq!(p.read_map_begin().unwrap().size, 0);
+                p.read_map_end().unwrap();
+            }
+            Value::Nested(_) => {
+                p.skip(TType::Struct).unwrap();
+            }
+        }
+        p.read_field_end().unwrap();
+    }
+    assert_eq!(p.read_field_begin().unwrap().field_type, TType::Stop);
+    p.read_struct_end().unwrap();
+    p.read_message_end().unwrap();
+}
+
+async fn read_edges_async<P: TAsyncInputProtocol>(p: &mut P) {
+    assert_eq!(p.read_message_begin().await.unwrap(), message());
+    p.read_struct_begin().await.unwrap();
+    for (i, value) in values().into_iter().enumerate() {
+        let field = p.read_field_begin().await.unwrap();
+        assert_eq!(field.id, Some(field_id(i)), "{value:?}");
+        assert_eq!(field.field_type, ttype(&value), "{value:?}");
+        match value {
+            Value::Bool(v) => assert_eq!(p.read_bool().await.unwrap(), v),
+            Value::I8(v) => assert_eq!(p.read_i8().await.unwrap(), v),
+            Value::I16(v) => assert_eq!(p.read_i16().await.unwrap(), v),
+            Value::I32(v) => assert_eq!(p.read_i32().await.unwrap(), v),
+            Value::I64(v) => assert_eq!(p.read_i64().await.unwrap(), v),
+            Value::Double(v) => assert_double(p.read_double().await.unwrap(), v),
+            Value::String(v) => assert_eq!(p.read_faststr().await.unwrap(), v),
+            Value::Binary(v) => assert_eq!(p.read_bytes().await.unwrap(), v),
+            Value::BoolList(v) => {
+                let list = p.read_list_begin().await.unwrap();
+                assert_eq!(list, TListIdentifier::new(TType::Bool, v.len()));
+                for b in v {
+                    assert_eq!(p.read_bool().await.unwrap(), *b);
+                }
+                p.read_list_end().await.unwrap();
+            }
+            Value::StringList(v) => {
+                let list = p.read_list_begin().await.unwrap();
+                assert_eq!(list, TListIdentifier::new(TType::Binary, v.len()));
+                for s in v {
+                    assert_eq!(p.read_string().await.unwrap(), *s);
+                }
+                p.read_list_end().await.unwrap();
+            }
+            Value::EmptyList(_) => {
+                assert_eq!(p.read_list_begin().await.unwrap().size, 0);
+                p.read_list_end().await.unwrap();
+            }
+            Value::EmptySet(_) => {
+                assert_eq!(p.read_set_begin().await.unwrap().size, 0);
+                p.read_set_end().await.unwrap();
+            }
+            Value::EmptyMap(..) => {
+                assert_eq!(p.read_map_begin().await.unwrap().size, 0);
+                p.read_map_end().await.unwrap();
+            }
+            Value::Nested(_) => {
+                p.skip(TType::Struct).await.unwrap();
+            }
+        }
+        p.read_field_end().await.unwrap();
+    }
+    assert_eq!(p.read_field_begin().await.unwrap().field_type, TType::Stop);
+    p.read_struct_end().await.unwrap();
+    p.read_message_end().await.unwrap();
+}
+
+// the async protocols read from a slice, which never waits
+fn block_on<F: Future>(f: F) -> F::Output {
+    match pin!(f).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("reading from a slice never waits"),
+    }
+}
+
+fn staged(write: impl FnOnce(&mut Staged<&mut BytesMut>)) -> Bytes {
+    let mut buf = BytesMut::new();
+    write(&mut Staged::new(&mut buf));
+    buf.freeze()
+}
+
+fn linked(write: impl FnOnce(&mut LinkedBytes)) -> Bytes {
+    let mut buf = LinkedBytes::new();
+    write(&mut buf);
+    Bytes::from(buf.concat().to_vec())
+}
+
+// structs nested one level deeper than skipping allows
+fn too_deep<P: TOutputProtocol>(p: &mut P) {
+    write_nested(p, MAXIMUM_SKIP_DEPTH as usize + 1);
+}
+
+#[test]
+fn test_binary_conformance() {
+    let mut buf = BytesMut::new();
+    write_edges(&mut binary::TBinaryProtocol::new(&mut buf, false));
+    let expected = buf.freeze();
+    assert_eq!(
+        expected.len(),
+        edges_len(&mut binary::TBinaryProtocol::new((), false))
+    );
+    assert_eq!(
+        linked(|buf| write_edges(&mut binary::TBinaryProtocol::new(buf, true))),
+        expected
+    );
+    assert_eq!(
+        staged(|buf| write_edges(&mut binary::TBinaryProtocol::new(buf, false))),
+        expected
+    );
+
+    read_edges(&mut binary::TBinaryProtocol::new(
+        &mut expected.clone(),
+        false,
+    ));
+    read_edges(&mut binary::TBinaryProtocol::new(
+        &mut expected.clone(),
+        true,
+    ));
+    read_edges(&mut binary::TBinaryProtocol::new(expected.clone(), false));
+    read_edges(&mut binary::TBinaryProtocol::new(&expected[..], false));
+    read_edges(&mut binary::TBinaryProtocol::new(
+        reader::IoReader::new(&expected[..]),
+        false,
+    ));
+    let mid = expected.len() / 2;
+    read_edges(&mut binary::TBinaryProtocol::new(
+        binary::BufInput::new(expected.slice(..mid).chain(expected.slice(mid..))),
+        false,
+    ));
+    block_on(read_edges_async(&mut binary::TAsyncBinaryProtocol::new(
+        &expected[..],
+    )));
+
+    let mut buf = BytesMut::new();
+    too_deep(&mut binary::TBinaryProtocol::new(&mut buf, false));
+    let deep = buf.freeze();
+    assert!(
+        binary::TBinaryProtocol::new(&mut deep.clone(), false)
+            .skip(TType::Struct)
+            .is_err()
+    );
+    assert!(block_on(binary::TAsyncBinaryProtocol::new(&deep[..]).skip(TType::Struct)).is_err());
+}
+
+#[test]
+fn test_binary_le_conformance() {
+    let mut buf = BytesMut::new();
+    write_edges(&mut binary_le::TBinaryProtocol::with_byte_order(
+        &mut buf, false,
+    ));
+    let expected = buf.freeze();
+    assert_eq!(
+        expected.len(),
+        edges_len(&mut binary_le::TBinaryProtocol::with_byte_order((), false))
+    );
+    assert_eq!(
+        linked(|buf| write_edges(&mut binary_le::TBinaryProtocol::with_byte_order(buf, true))),
+        expected
+    );
+    assert_eq!(
+        staged(|buf| write_edges(&mut binary_le::TBinaryProtocol::with_byte_order(buf, false))),
+        expected
+    );
+
+    read_edges(&mut binary_le::TBinaryProtocol::with_byte_order(
+        &mut expected.clone(),
+        false,
+    ));
+
+    // the little-endian byte order of the binary protocol is the same format
+    let mut buf = BytesMut::new();
+    write_edges(&mut binary::TBinaryProtocol::<_, true>::with_byte_order(
+        &mut buf, false,
+    ));
+    assert_eq!(buf, expected);
+    assert_eq!(
+        linked(
+            |buf| write_edges(&mut binary::TBinaryProtocol::<_, true>::with_byte_order(
+                buf, true
+            ))
+        ),
+        expected
+    );
+    read_edges(&mut binary::TBinaryProtocol::<_, true>::with_byte_order(
+        &mut expected.clone(),
+        false,
+    ));
+    block_on(read_edges_async(
+        &mut binary_le::TAsyncBinaryProtocol::with_byte_order(&expected[..]),
+    ));
+}
+
+#[test]
+fn test_compact_conformance() {
+    let mut buf = BytesMut::new();
+    write_edges(&mut compact::TCompactOutputProtocol::new(&mut buf, false));
+    let expected = buf.freeze();
+    assert_eq!(
+        expected.len(),
+        edges_len(&mut compact::TCompactOutputProtocol::new((), false))
+    );
+    assert_eq!(
+        linked(|buf| write_edges(&mut compact::TCompactOutputProtocol::new(buf, true))),
+        expected
+    );
+    assert_eq!(
+        staged(|buf| write_edges(&mut compact::TCompactOutputProtocol::new(buf, false))),
+        expected
+    );
+
+    read_edges(&mut compact::TCompactInputProtocol::new(
+        &mut expected.clone(),
+    ));
+    block_on(read_edges_async(&mut compact::TAsyncCompactProtocol::new(
+        &expected[..],
+    )));
+
+    let mut buf = BytesMut::new();
+    too_deep(&mut compact::TCompactOutputProtocol::new(&mut buf, false));
+    let deep = buf.freeze();
+    assert!(
+        compact::TCompactInputProtocol::new(&mut deep.clone())
+            .skip(TType::Struct)
+            .is_err()
+    );
+    assert!(block_on(compact::TAsyncCompactProtocol::new(&deep[..]).skip(TType::Struct)).is_err());
+}
+
+#[test]
+fn test_compact_fbthrift_conformance() {
+    let fbthrift = |buf| {
+        let mut p = compact::TCompactOutputProtocol::new(buf, false);
+        p.set_fbthrift(true);
+        p
+    };
+    let mut buf = BytesMut::new();
+    write_edges(&mut fbthrift(&mut buf));
+    let expected = buf.freeze();
+    assert_eq!(expected[1] & 0x1f, 2);
+    assert_eq!(
+        linked(|buf| {
+            let mut p = compact::TCompactOutputProtocol::new(buf, true);
+            p.set_fbthrift(true);
+            write_edges(&mut p);
+        }),
+        expected
+    );
+
+    let mut input = expected.clone();
+    let mut p = compact::TCompactInputProtocol::new(&mut input);
+    p.set_fbthrift(true);
+    read_edges(&mut p);
+    let mut p = compact::TAsyncCompactProtocol::new(&expected[..]);
+    p.set_fbthrift(true);
+    block_on(read_edges_async(&mut p));
+
+    // the messages of Apache Thrift are read too, and fbthrift ones only when
+    // asked for
+    let mut buf = BytesMut::new();
+    write_edges(&mut compact::TCompactOutputProtocol::new(&mut buf, false));
+    let apache = buf.freeze();
+    assert_ne!(apache, expected);
+    let mut input = apache.clone();
+    let mut p = compact::TCompactInputProtocol::new(&mut input);
+    p.set_fbthrift(true);
+    read_edges(&mut p);
+    assert!(
+        compact::TCompactInputProtocol::new(&mut expected.clone())
+            .read_message_begin()
+            .is_err()
+    );
+    assert!(
+        block_on(compact::TAsyncCompactProtocol::new(&expected[..]).read_message_begin()).is_err()
+    );
+}
+
+#[cfg(not(feature = "safe-only"))]
+#[test]
+fn test_unsafe_conformance() {
+    use bytes::BufMut;
+
+    use super::{
+        ApplicationException, ApplicationExceptionKind, Message, binary_unsafe, compact_unsafe,
+    };
+
+    let mut buf = BytesMut::new();
+    write_edges(&mut binary::TBinaryProtocol::new(&mut buf, false));
+    let expected = buf.freeze();
+
+    // the unsafe writers need the whole message to fit in the buffer
+    let mut buf = BytesMut::new();
+    unsafe {
+        let size = edges_len(&mut binary_unsafe::TBinaryUnsafeOutputProtocol::new(
+            (),
+            &mut [],
+            false,
+        ));
+        buf.reserve(size);
+        let spare = buf.spare_capacity_mut();
+        let s = std::slice::from_raw_parts_mut(spare.as_mut_ptr().cast(), spare.len());
+        let mut p = binary_unsafe::TBinaryUnsafeOutputProtocol::new(&mut buf, s, false);
+        write_edges(&mut p);
+        let len = p.index();
+        buf.advance_mut(len);
+    }
+    assert_eq!(buf, expected);
+
+    let e = ApplicationException::new(ApplicationExceptionKind::INTERNAL_ERROR, "x".repeat(4096));
+    let mut safe = BytesMut::new();
+    e.encode(&mut binary::TBinaryProtocol::new(&mut safe, false))
+        .unwrap();
+    let mut encoder = binary_unsafe::UnsafeBinaryEncoder::with_capacity(0);
+    encoder.encode(&e).unwrap();
+    encoder.encode(&e).unwrap();
+    assert_eq!(encoder.finish(), [&safe[..], &safe[..]].concat());
+    for zero_copy in [false, true] {
+        let mut encoder = binary_unsafe::UnsafeBinaryEncoder::linked_with_capacity(0, zero_copy);
+        encoder.encode(&e).unwrap();
+        encoder.encode(&e).unwrap();
+        assert_eq!(encoder.finish().concat(), [&safe[..], &safe[..]].concat());
+    }
+
+    let mut bytes = buf.freeze();
+    read_edges(&mut unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(bytes.clone()) });
+    read_edges(&mut unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut bytes) });
+
+    let mut buf = BytesMut::new();
+    write_edges(&mut compact::TCompactOutputProtocol::new(&mut buf, false));
+    let expected = buf.freeze();
+
+    let mut buf = BytesMut::new();
+    unsafe {
+        let size = edges_len(&mut compact_unsafe::TCompactUnsafeOutputProtocol::new(
+            (),
+            &mut [],
+            false,
+        ));
+        buf.reserve(size);
+        let spare = buf.spare_capacity_mut();
+        let s = std::slice::from_raw_parts_mut(spare.as_mut_ptr().cast(), spare.len());
+        let mut p = compact_unsafe::TCompactUnsafeOutputProtocol::new(&mut buf, s, false);
+        write_edges(&mut p);
+        let len = p.index();
+        buf.advance_mut(len);
+    }
+    assert_eq!(buf, expected);
+    let mut bytes = buf.freeze();
+    read_edges(&mut unsafe { compact_unsafe::TCompactUnsafeInputProtocol::new(&mut bytes) });
+}
+
+#[cfg(all(not(feature = "safe-only"), any(debug_assertions, feature = "checked")))]
+#[test]
+#[should_panic(expected = "out of bounds access of 8 bytes at 0 in a buffer of 4 bytes")]
+fn test_unsafe_bounds_checked() {
+    let mut buf = BytesMut::with_capacity(4);
+    unsafe {
+        let spare = buf.spare_capacity_mut();
+        let s = std::slice::from_raw_parts_mut(spare.as_mut_ptr().cast(), 4);
+        let mut p = super::binary_unsafe::TBinaryUnsafeOutputProtocol::new(&mut buf, s, false);
+        let _ = p.write_i64(1);
+    }
+}
+
+#[cfg(not(feature = "safe-only"))]
+#[test]
+fn test_unsafe_capacity_check() {
+    use super::{
+        Message, TAsyncInputProtocol, ThriftException, binary_unsafe, error::ProtocolExceptionKind,
+    };
+
+    // a message whose size leaves out the string it writes
+    struct Undersized;
+
+    impl Message for Undersized {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_string(&"x".repeat(4096))
+        }
+
+        fn decode<T: TInputProtocol>(_: &mut T) -> Result<Self, ThriftException> {
+            unimplemented!()
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(_: &mut T) -> Result<Self, ThriftException> {
+            unimplemented!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+            protocol.i32_len(0)
+        }
+    }
+
+    let mut encoder = binary_unsafe::UnsafeBinaryEncoder::with_capacity(0);
+    let Err(ThriftException::Protocol(e)) = encoder.encode(&Undersized) else {
+        panic!("expected a protocol exception");
+    };
+    assert_eq!(e.kind(), ProtocolExceptionKind::BufferTooSmall);
+    let sizes = e.buffer_sizes().unwrap();
+    assert_eq!(sizes.needed, 4096);
+    assert!(sizes.remaining < sizes.needed, "{e}");
+    assert!(encoder.finish().is_empty());
+
+    let mut encoder = binary_unsafe::UnsafeBinaryEncoder::linked_with_capacity(0, false);
+    let Err(ThriftException::Protocol(e)) = encoder.encode(&Undersized) else {
+        panic!("expected a protocol exception");
+    };
+    assert_eq!(e.kind(), ProtocolExceptionKind::BufferTooSmall);
+}
+
+#[cfg(not(feature = "safe-only"))]
+#[test]
+fn test_unsafe_encode_framed() {
+    use bytes::BufMut;
+
+    use super::{ApplicationException, ApplicationExceptionKind, Message, binary_unsafe};
+
+    let mut expected = BytesMut::new();
+    let mut encoder = binary_unsafe::UnsafeBinaryEncoder::with_capacity(0);
+    // the second message doesn't fit in what the first leaves, the third does
+    for len in [10, 100_000, 20] {
+        let e =
+            ApplicationException::new(ApplicationExceptionKind::INTERNAL_ERROR, "x".repeat(len));
+        let mut message = BytesMut::new();
+        e.encode(&mut binary::TBinaryProtocol::new(&mut message, false))
+            .unwrap();
+        expected.put_u32(message.len() as u32);
+        expected.extend_from_slice(&message);
+        encoder.encode_framed(&e).unwrap();
+    }
+    assert_eq!(encoder.finish(), expected);
+}
+
+#[cfg(not(feature = "safe-only"))]
+#[test]
+fn test_unsafe_encode_into_slice() {
+    use super::{
+        ApplicationException, ApplicationExceptionKind, Message, ThriftException, binary_unsafe,
+        error::ProtocolExceptionKind,
+    };
+
+    let e = ApplicationException::new(ApplicationExceptionKind::INTERNAL_ERROR, "x".repeat(100));
+    let mut expected = BytesMut::new();
+    e.encode(&mut binary::TBinaryProtocol::new(&mut expected, false))
+        .unwrap();
+
+    let mut buf = vec![0xff; 256];
+    let len = binary_unsafe::encode_into_slice(&e, &mut buf[8..]).unwrap();
+    assert_eq!(&buf[8..8 + len], &expected[..]);
+    // nothing is written around the message
+    assert!(buf[..8].iter().chain(&buf[8 + len..]).all(|b| *b == 0xff));
+
+    let mut small = vec![0; expected.len() - 1];
+    let Err(ThriftException::Protocol(err)) = binary_unsafe::encode_into_slice(&e, &mut small)
+    else {
+        panic!("expected a protocol exception");
+    };
+    assert_eq!(err.kind(), ProtocolExceptionKind::BufferTooSmall);
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_uuid_typed() {
+    let u = uuid::Uuid::from_u128(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210);
+
+    let mut buf = BytesMut::new();
+    binary::TBinaryProtocol::new(&mut buf, false)
+        .write_uuid_typed(u)
+        .unwrap();
+    assert_eq!(buf[..], u.as_bytes()[..]);
+    let bytes = buf.freeze();
+    assert_eq!(
+        binary::TBinaryProtocol::new(&mut bytes.clone(), false)
+            .read_uuid_typed()
+            .unwrap(),
+        u
+    );
+    assert_eq!(
+        block_on(binary::TAsyncBinaryProtocol::new(&bytes[..]).read_uuid_typed()).unwrap(),
+        u
+    );
+
+    let mut buf = BytesMut::new();
+    compact::TCompactOutputProtocol::new(&mut buf, false)
+        .write_uuid_typed(u)
+        .unwrap();
+    let bytes = buf.freeze();
+    assert_eq!(
+        compact::TCompactInputProtocol::new(&mut bytes.clone())
+            .read_uuid_typed()
+            .unwrap(),
+        u
+    );
+    assert_eq!(
+        block_on(compact::TAsyncCompactProtocol::new(&bytes[..]).read_uuid_typed()).unwrap(),
+        u
+    );
+}
+
+#[test]
+fn test_binary_non_strict_header() {
+    use super::{ThriftException, error::ProtocolExceptionKind};
+
+    let ident = TMessageIdentifier::new("ping".into(), TMessageType::Call, 7);
+
+    let mut buf = BytesMut::new();
+    let mut protocol = binary::TBinaryProtocol::new(&mut buf, false);
+    protocol.set_strict_write(false);
+    let len = protocol.message_begin_len(&ident);
+    protocol.write_message_begin(&ident).unwrap();
+    let legacy = buf.freeze();
+    assert_eq!(&legacy[..], b"\0\0\0\x04ping\x01\0\0\0\x07");
+    assert_eq!(legacy.len(), len);
+
+    #[cfg(not(feature = "safe-only"))]
+    {
+        use bytes::BufMut;
+
+        let mut buf = BytesMut::with_capacity(len);
+        unsafe {
+            let spare = buf.spare_capacity_mut();
+            let s = std::slice::from_raw_parts_mut(spare.as_mut_ptr().cast(), spare.len());
+            let mut p = super::binary_unsafe::TBinaryUnsafeOutputProtocol::new(&mut buf, s, false);
+            p.set_strict_write(false);
+            assert_eq!(p.message_begin_len(&ident), len);
+            p.write_message_begin(&ident).unwrap();
+            let len = p.index();
+            buf.advance_mut(len);
+        }
+        assert_eq!(buf, legacy);
+    }
+
+    let mut buf = BytesMut::new();
+    binary::TBinaryProtocol::new(&mut buf, false)
+        .write_message_begin(&ident)
+        .unwrap();
+    let versioned = buf.freeze();
+
+    for header in [&legacy, &versioned] {
+        let mut b = header.clone();
+        let mut protocol = binary::TBinaryProtocol::new(&mut b, false);
+        protocol.set_strict_read(false);
+        assert_eq!(protocol.read_message_begin().unwrap(), ident);
+
+        let mut protocol = binary::TAsyncBinaryProtocol::new(&header[..]);
+        protocol.set_strict_read(false);
+        assert_eq!(block_on(protocol.read_message_begin()).unwrap(), ident);
+
+        let mut protocol = binary::TBinaryProtocol::new(reader::IoReader::new(&header[..]), false);
+        protocol.set_strict_read(false);
+        assert_eq!(protocol.read_message_begin().unwrap(), ident);
+
+        #[cfg(not(feature = "safe-only"))]
+        {
+            let mut b = header.clone();
+            let mut protocol =
+                unsafe { super::binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut b) };
+            protocol.set_strict_read(false);
+            assert_eq!(protocol.read_message_begin().unwrap(), ident);
+            assert!(b.is_empty());
+        }
+    }
+
+    let Err(ThriftException::Protocol(e)) =
+        binary::TBinaryProtocol::new(&mut legacy.clone(), false).read_message_begin()
+    else {
+        panic!("expected a protocol exception");
+    };
+    assert_eq!(e.kind(), ProtocolExceptionKind::BadVersion);
+
+    // a name running past the message
+    #[cfg(not(feature = "safe-only"))]
+    {
+        let mut b = legacy.slice(..8);
+        let mut protocol = unsafe { super::binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut b) };
+        protocol.set_strict_read(false);
+        assert!(protocol.read_message_begin().is_err());
+    }
+}
+
+// the fields skipped with `skip_capture`, each value also written on its own
+const CAPTURED: [TType; 4] = [TType::I32, TType::Binary, TType::List, TType::Struct];
+
+fn write_captured<P: TOutputProtocol>(p: &mut P, i: usize) {
+    match CAPTURED[i] {
+        TType::I32 => p.write_i32(-1).unwrap(),
+        TType::Binary => p.write_bytes(Bytes::from_static(&[0, 0xff])).unwrap(),
+        TType::List => {
+            p.write_list_begin(TListIdentifier::new(TType::Binary, STRINGS.len()))
+                .unwrap();
+            for s in STRINGS {
+                p.write_string(s).unwrap();
+            }
+            p.write_list_end().unwrap();
+        }
+        _ => write_nested(p, 3),
+    }
+}
+
+fn write_capture_fields<P: TOutputProtocol>(p: &mut P) {
+    p.write_struct_begin(&STRUCT).unwrap();
+    for (i, ttype) in CAPTURED.into_iter().enumerate() {
+        p.write_field_begin(ttype, field_id(i)).unwrap();
+        write_captured(p, i);
+        p.write_field_end().unwrap();
+    }
+    p.write_field_stop().unwrap();
+    p.write_struct_end().unwrap();
+}
+
+fn read_captured<P: TInputProtocol>(p: &mut P) -> Vec<Bytes> {
+    let mut captured = Vec::new();
+    p.read_struct_begin().unwrap();
+    loop {
+        let field = p.read_field_begin().unwrap();
+        if field.field_type == TType::Stop {
+            break;
+        }
+        captured.push(p.skip_capture(field.field_type).unwrap());
+        p.read_field_end().unwrap();
+    }
+    p.read_struct_end().unwrap();
+    captured
+}
+
+async fn read_captured_async<P: TAsyncInputProtocol>(p: &mut P) -> Vec<Bytes> {
+    let mut captured = Vec::new();
+    p.read_struct_begin().await.unwrap();
+    loop {
+        let field = p.read_field_begin().await.unwrap();
+        if field.field_type == TType::Stop {
+            break;
+        }
+        captured.push(p.skip_capture(field.field_type).await.unwrap());
+        p.read_field_end().await.unwrap();
+    }
+    p.read_struct_end().await.unwrap();
+    captured
+}
+
+fn split_from(captured: &[Bytes], input: &Bytes) -> bool {
+    let range = input.as_ptr_range();
+    captured
+        .iter()
+        .all(|b| range.contains(&b.as_ptr()) || b.is_empty())
+}
+
+#[test]
+fn test_skip_capture() {
+    let mut buf = BytesMut::new();
+    write_capture_fields(&mut binary::TBinaryProtocol::new(&mut buf, false));
+    let input = buf.freeze();
+    let expected = (0..CAPTURED.len())
+        .map(|i| {
+            let mut buf = BytesMut::new();
+            write_captured(&mut binary::TBinaryProtocol::new(&mut buf, false), i);
+            buf.freeze()
+        })
+        .collect::<Vec<_>>();
+
+    let captured = read_captured(&mut binary::TBinaryProtocol::new(&mut input.clone(), false));
+    assert_eq!(captured, expected);
+    assert!(split_from(&captured, &input));
+    let captured = read_captured(&mut binary::TBinaryProtocol::new(input.clone(), false));
+    assert!(split_from(&captured, &input));
+    assert_eq!(
+        read_captured(&mut binary::TBinaryProtocol::new(&input[..], false)),
+        expected
+    );
+    assert_eq!(
+        block_on(read_captured_async(&mut binary::TAsyncBinaryProtocol::new(
+            &input[..]
+        ))),
+        expected
+    );
+    #[cfg(not(feature = "safe-only"))]
+    {
+        let mut b = input.clone();
+        let captured = read_captured(&mut unsafe {
+            super::binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut b)
+        });
+        assert_eq!(captured, expected);
+        assert!(split_from(&captured, &input));
+    }
+
+    let mut buf = BytesMut::new();
+    write_capture_fields(&mut binary_le::TBinaryProtocol::with_byte_order(
+        &mut buf, false,
+    ));
+    let input = buf.freeze();
+    let expected = (0..CAPTURED.len())
+        .map(|i| {
+            let mut buf = BytesMut::new();
+            write_captured(
+                &mut binary_le::TBinaryProtocol::with_byte_order(&mut buf, false),
+                i,
+            );
+            buf.freeze()
+        })
+        .collect::<Vec<_>>();
+    let captured = read_captured(&mut binary_le::TBinaryProtocol::with_byte_order(
+        &mut input.clone(),
+        false,
+    ));
+    assert_eq!(captured, expected);
+    assert!(split_from(&captured, &input));
+    assert_eq!(
+        block_on(read_captured_async(
+            &mut binary_le::TAsyncBinaryProtocol::with_byte_order(&input[..])
+        )),
+        expected
+    );
+
+    let mut buf = BytesMut::new();
+    write_capture_fields(&mut compact::TCompactOutputProtocol::new(&mut buf, false));
+    let input = buf.freeze();
+    let expected = (0..CAPTURED.len())
+        .map(|i| {
+            let mut buf = BytesMut::new();
+            write_captured(
+                &mut compact::TCompactOutputProtocol::new(&mut buf, false),
+                i,
+            );
+            buf.freeze()
+        })
+        .collect::<Vec<_>>();
+    let captured = read_captured(&mut compact::TCompactInputProtocol::new(&mut input.clone()));
+    assert_eq!(captured, expected);
+    assert!(split_from(&captured, &input));
+    assert_eq!(
+        block_on(read_captured_async(
+            &mut compact::TAsyncCompactProtocol::new(&input[..])
+        )),
+        expected
+    );
+    #[cfg(not(feature = "safe-only"))]
+    {
+        let mut b = input.clone();
+        let captured = read_captured(&mut unsafe {
+            super::compact_unsafe::TCompactUnsafeInputProtocol::new(&mut b)
+        });
+        assert_eq!(captured, expected);
+        assert!(split_from(&captured, &input));
+    }
+}
+
+// an outer struct holding a struct, whose second field is the one corrupted,
+// then an i32; the ids and value of the corrupted field hold no stop byte
+fn write_corruptible<P: TOutputProtocol>(p: &mut P) {
+    p.write_struct_begin(&STRUCT).unwrap();
+    p.write_field_begin(TType::Struct, 1).unwrap();
+    p.write_struct_begin(&STRUCT).unwrap();
+    p.write_field_begin(TType::I32, 1).unwrap();
+    p.write_i32(7).unwrap();
+    p.write_field_end().unwrap();
+    p.write_field_begin(TType::I16, 257).unwrap();
+    p.write_i16(0x0101).unwrap();
+    p.write_field_end().unwrap();
+    p.write_field_stop().unwrap();
+    p.write_struct_end().unwrap();
+    p.write_field_end().unwrap();
+    p.write_field_begin(TType::I32, 2).unwrap();
+    p.write_i32(5).unwrap();
+    p.write_field_end().unwrap();
+    p.write_field_stop().unwrap();
+    p.write_struct_end().unwrap();
+}
+
+// the ids of the fields read, depth first
+fn read_ids<P: TInputProtocol>(
+    p: &mut P,
+    ids: &mut Vec<i16>,
+) -> Result<(), super::ThriftException> {
+    p.read_struct_begin()?;
+    loop {
+        let field = p.read_field_begin()?;
+        if field.field_type == TType::Stop {
+            break;
+        }
+        ids.push(field.id.unwrap());
+        match field.field_type {
+            TType::Struct => read_ids(p, ids)?,
+            ttype => {
+                p.skip(ttype)?;
+            }
+        }
+        p.read_field_end()?;
+    }
+    p.read_struct_end()
+}
+
+#[test]
+fn test_ttype_recovery() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicU8, Ordering},
+    };
+
+    use super::recovery::TTypeRecovery;
+
+    let mut buf = BytesMut::new();
+    write_corruptible(&mut binary::TBinaryProtocol::new(&mut buf, false));
+    // the struct field header, then the i32 one
+    let at = 3 + 7;
+    assert_eq!(buf[at], TType::I16 as u8);
+    buf[at] = 0x7f;
+    let binary_input = buf.freeze();
+
+    let mut buf = BytesMut::new();
+    write_corruptible(&mut compact::TCompactOutputProtocol::new(&mut buf, false));
+    let at = 1 + 2;
+    assert_eq!(buf[at], 0x04);
+    buf[at] = 0x0e;
+    let compact_input = buf.freeze();
+
+    for (recovery, expected) in [
+        (TTypeRecovery::SkipToStop, vec![1, 1, 2]),
+        (TTypeRecovery::DropRest, vec![1, 1]),
+    ] {
+        let mut ids = Vec::new();
+        let mut b = binary_input.clone();
+        let mut p = binary::TBinaryProtocol::new(&mut b, false);
+        p.set_ttype_recovery(recovery);
+        read_ids(&mut p, &mut ids).unwrap();
+        assert_eq!(ids, expected, "{recovery:?}");
+        assert!(b.is_empty());
+
+        let mut ids = Vec::new();
+        let mut b = compact_input.clone();
+        let mut p = compact::TCompactInputProtocol::new(&mut b);
+        p.set_ttype_recovery(recovery);
+        read_ids(&mut p, &mut ids).unwrap();
+        assert_eq!(ids, expected, "{recovery:?}");
+        assert!(b.is_empty());
+    }
+
+    let invalid = Arc::new(AtomicU8::new(0));
+    let mut b = binary_input.clone();
+    let mut p = binary::TBinaryProtocol::new(&mut b, false);
+    p.set_ttype_recovery_with({
+        let invalid = invalid.clone();
+        move |byte| {
+            invalid.store(byte, Ordering::Relaxed);
+            TTypeRecovery::Abort
+        }
+    });
+    assert!(read_ids(&mut p, &mut Vec::new()).is_err());
+    assert_eq!(invalid.load(Ordering::Relaxed), 0x7f);
+
+    let mut b = compact_input.clone();
+    let mut p = compact::TCompactInputProtocol::new(&mut b);
+    p.set_ttype_recovery_with({
+        let invalid = invalid.clone();
+        move |byte| {
+            invalid.store(byte, Ordering::Relaxed);
+            TTypeRecovery::Abort
+        }
+    });
+    assert!(read_ids(&mut p, &mut Vec::new()).is_err());
+    assert_eq!(invalid.load(Ordering::Relaxed), 0x0e);
+
+    // failing by default
+    assert!(
+        read_ids(
+            &mut binary::TBinaryProtocol::new(&mut binary_input.clone(), false),
+            &mut Vec::new()
+        )
+        .is_err()
+    );
+    assert!(
+        read_ids(
+            &mut compact::TCompactInputProtocol::new(&mut compact_input.clone()),
+            &mut Vec::new()
+        )
+        .is_err()
+    );
+}
+
+// the ids of the fields read, depth first, checking that peeking at each
+// header returns it without reading it
+fn read_ids_peeking<P: TInputProtocol>(p: &mut P, ids: &mut Vec<i16>) {
+    p.read_struct_begin().unwrap();
+    loop {
+        let byte = p.peek_byte().unwrap();
+        let peeked = p.peek_field_begin().unwrap();
+        assert_eq!(p.peek_byte().unwrap(), byte);
+        assert_eq!(p.peek_field_begin().unwrap(), peeked);
+        let field = p.read_field_begin().unwrap();
+        assert_eq!(field, peeked);
+        if field.field_type == TType::Stop {
+            break;
+        }
+        ids.push(field.id.unwrap());
+        match field.field_type {
+            TType::Struct => read_ids_peeking(p, ids),
+            ttype => {
+                p.skip(ttype).unwrap();
+            }
+        }
+        p.read_field_end().unwrap();
+    }
+    p.read_struct_end().unwrap();
+}
+
+#[test]
+fn test_peek() {
+    use super::recovery::TTypeRecovery;
+
+    let expected = [1, 1, 257, 2];
+    let peek = |p: &mut dyn FnMut(&mut Vec<i16>)| {
+        let mut ids = Vec::new();
+        p(&mut ids);
+        assert_eq!(ids, expected);
+    };
+
+    let mut buf = BytesMut::new();
+    write_corruptible(&mut binary::TBinaryProtocol::new(&mut buf, false));
+    let input = buf.freeze();
+    peek(&mut |ids| {
+        read_ids_peeking(
+            &mut binary::TBinaryProtocol::new(&mut input.clone(), false),
+            ids,
+        )
+    });
+    peek(&mut |ids| read_ids_peeking(&mut binary::TBinaryProtocol::new(&input[..], false), ids));
+    // the headers straddling two chunks
+    for mid in 0..input.len() {
+        peek(&mut |ids| {
+            let chunks = input.slice(..mid).chain(input.slice(mid..));
+            read_ids_peeking(
+                &mut binary::TBinaryProtocol::new(binary::BufInput::new(chunks), false),
+                ids,
+            )
+        });
+    }
+    #[cfg(not(feature = "safe-only"))]
+    peek(&mut |ids| {
+        let mut b = input.clone();
+        read_ids_peeking(
+            &mut unsafe { super::binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut b) },
+            ids,
+        )
+    });
+
+    let mut buf = BytesMut::new();
+    write_corruptible(&mut binary_le::TBinaryProtocol::with_byte_order(
+        &mut buf, false,
+    ));
+    let input = buf.freeze();
+    peek(&mut |ids| {
+        read_ids_peeking(
+            &mut binary_le::TBinaryProtocol::with_byte_order(&mut input.clone(), false),
+            ids,
+        )
+    });
+
+    let mut buf = BytesMut::new();
+    write_corruptible(&mut compact::TCompactOutputProtocol::new(&mut buf, false));
+    let input = buf.freeze();
+    peek(&mut |ids| {
+        read_ids_peeking(
+            &mut compact::TCompactInputProtocol::new(&mut input.clone()),
+            ids,
+        )
+    });
+    #[cfg(not(feature = "safe-only"))]
+    peek(&mut |ids| {
+        let mut b = input.clone();
+        read_ids_peeking(
+            &mut unsafe { super::compact_unsafe::TCompactUnsafeInputProtocol::new(&mut b) },
+            ids,
+        )
+    });
+
+    // the varint id of the field 257 cut short
+    let mut b = input.slice(..1 + 1 + 1 + 2);
+    let mut p = compact::TCompactInputProtocol::new(&mut b);
+    p.read_field_begin().unwrap();
+    p.read_struct_begin().unwrap();
+    p.read_field_begin().unwrap();
+    p.skip(TType::I32).unwrap();
+    assert!(p.peek_field_begin().is_err());
+    assert_eq!(p.peek_byte().unwrap(), 0x04);
+    p.read_field_begin().unwrap_err();
+    assert!(p.peek_byte().is_err());
+
+    // an invalid type peeks as the stop it is recovered as
+    let mut buf = BytesMut::new();
+    write_corruptible(&mut binary::TBinaryProtocol::new(&mut buf, false));
+    buf[3 + 7] = 0x7f;
+    let mut b = buf.freeze();
+    let mut p = binary::TBinaryProtocol::new(&mut b, false);
+    p.read_field_begin().unwrap();
+    p.read_struct_begin().unwrap();
+    p.read_field_begin().unwrap();
+    p.skip(TType::I32).unwrap();
+    assert!(p.peek_field_begin().is_err());
+    p.set_ttype_recovery(TTypeRecovery::SkipToStop);
+    assert_eq!(p.peek_field_begin().unwrap().field_type, TType::Stop);
+    assert_eq!(p.read_field_begin().unwrap().field_type, TType::Stop);
+
+    // an io::Read can't be peeked at
+    let mut p = binary::TBinaryProtocol::new(reader::IoReader::new(&input[..]), false);
+    assert!(p.peek_field_begin().is_err());
+    assert!(p.peek_byte().is_err());
+}
+
+// the ids of the fields read, depth first, each field read speculatively
+// then again after rewinding to it
+fn read_ids_rewinding<P: TInputProtocol>(p: &mut P, ids: &mut Vec<i16>) {
+    p.read_struct_begin().unwrap();
+    loop {
+        let mark = p.mark().unwrap();
+        let field = p.read_field_begin().unwrap();
+        if field.field_type != TType::Stop {
+            p.skip(field.field_type).unwrap();
+            p.read_field_end().unwrap();
+        }
+        p.reset_to_mark(&mark).unwrap();
+
+        let field = p.read_field_begin().unwrap();
+        if field.field_type == TType::Stop {
+            break;
+        }
+        ids.push(field.id.unwrap());
+        match field.field_type {
+            TType::Struct => read_ids_rewinding(p, ids),
+            ttype => {
+                p.skip(ttype).unwrap();
+            }
+        }
+        p.read_field_end().unwrap();
+    }
+    p.read_struct_end().unwrap();
+}
+
+#[test]
+fn test_mark() {
+    let expected = [1, 1, 257, 2];
+    let rewind = |p: &mut dyn FnMut(&mut Vec<i16>)| {
+        let mut ids = Vec::new();
+        p(&mut ids);
+        assert_eq!(ids, expected);
+    };
+
+    let mut buf = BytesMut::new();
+    write_corruptible(&mut binary::TBinaryProtocol::new(&mut buf, false));
+    let input = buf.freeze();
+    rewind(&mut |ids| {
+        read_ids_rewinding(
+            &mut binary::TBinaryProtocol::new(&mut input.clone(), false),
+            ids,
+        )
+    });
+    rewind(&mut |ids| {
+        read_ids_rewinding(&mut binary::TBinaryProtocol::new(input.clone(), false), ids)
+    });
+    #[cfg(not(feature = "safe-only"))]
+    rewind(&mut |ids| {
+        let mut b = input.clone();
+        read_ids_rewinding(
+            &mut unsafe { super::binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut b) },
+            ids,
+        )
+    });
+    // the borrowed input can't be marked
+    assert!(
+        binary::TBinaryProtocol::new(&input[..], false)
+            .mark()
+            .is_err()
+    );
+    assert!(
+        binary::TBinaryProtocol::new(reader::IoReader::new(&input[..]), false)
+            .mark()
+            .is_err()
+    );
+
+    let mut buf = BytesMut::new();
+    write_corruptible(&mut binary_le::TBinaryProtocol::with_byte_order(
+        &mut buf, false,
+    ));
+    let input = buf.freeze();
+    rewind(&mut |ids| {
+        read_ids_rewinding(
+            &mut binary_le::TBinaryProtocol::with_byte_order(&mut input.clone(), false),
+            ids,
+        )
+    });
+
+    let mut buf = BytesMut::new();
+    write_corruptible(&mut compact::TCompactOutputProtocol::new(&mut buf, false));
+    let input = buf.freeze();
+    rewind(&mut |ids| {
+        read_ids_rewinding(
+            &mut compact::TCompactInputProtocol::new(&mut input.clone()),
+            ids,
+        )
+    });
+    #[cfg(not(feature = "safe-only"))]
+    rewind(&mut |ids| {
+        let mut b = input.clone();
+        read_ids_rewinding(
+            &mut unsafe { super::compact_unsafe::TCompactUnsafeInputProtocol::new(&mut b) },
+            ids,
+        )
+    });
+
+    // the value of a compact bool field is in its header
+    let mut buf = BytesMut::new();
+    let mut p = compact::TCompactOutputProtocol::new(&mut buf, false);
+    p.write_field_begin(TType::Bool, 1).unwrap();
+    p.write_bool(true).unwrap();
+    p.write_field_end().unwrap();
+    let mut b = buf.freeze();
+    let mut p = compact::TCompactInputProtocol::new(&mut b);
+    p.read_field_begin().unwrap();
+    let mark = p.mark().unwrap();
+    assert!(p.read_bool().unwrap());
+    p.reset_to_mark(&mark).unwrap();
+    assert!(p.read_bool().unwrap());
+}
+
+// a string one byte longer than the maximum string size set in the tests
+const TOO_LONG: &str = "too long";
+
+fn assert_too_long<T: std::fmt::Debug>(r: Result<T, super::ThriftException>) {
+    match r {
+        Err(super::ThriftException::Protocol(e)) => {
+            assert_eq!(e.kind(), super::error::ProtocolExceptionKind::InvalidData);
+            assert!(e.message().contains("length 8"), "{e}");
+        }
+        r => panic!("expected a length error, got {r:?}"),
+    }
+}
+
+#[test]
+fn test_max_string_size() {
+    const S: &str = TOO_LONG;
+
+    let mut buf = BytesMut::new();
+    binary::TBinaryProtocol::new(&mut buf, false)
+        .write_string(S)
+        .unwrap();
+    let encoded = buf.split().freeze();
+    let mut bytes = encoded.clone();
+    let mut p = binary::TBinaryProtocol::new(&mut bytes, false);
+    p.set_max_string_size(S.len());
+    assert_eq!(p.read_string().unwrap(), S);
+    let mut bytes = encoded.clone();
+    let mut p = binary::TBinaryProtocol::new(&mut bytes, false);
+    p.set_max_string_size(S.len() - 1);
+    assert_too_long(p.read_faststr());
+    let mut p = binary::TBinaryProtocol::new(reader::IoReader::new(&encoded[..]), false);
+    p.set_max_string_size(S.len() - 1);
+    assert_too_long(p.read_string());
+    let mut p = binary::TAsyncBinaryProtocol::new(&encoded[..]);
+    p.set_max_string_size(S.len() - 1);
+    assert_too_long(block_on(p.read_bytes_vec()));
+
+    binary_le::TBinaryProtocol::with_byte_order(&mut buf, false)
+        .write_string(S)
+        .unwrap();
+    let encoded = buf.split().freeze();
+    let mut bytes = encoded.clone();
+    let mut p = binary_le::TBinaryProtocol::with_byte_order(&mut bytes, false);
+    p.set_max_string_size(S.len() - 1);
+    assert_too_long(p.read_string());
+    let mut p = binary_le::TAsyncBinaryProtocol::with_byte_order(&encoded[..]);
+    p.set_max_string_size(S.len() - 1);
+    assert_too_long(block_on(p.read_faststr()));
+
+    compact::TCompactOutputProtocol::new(&mut buf, false)
+        .write_string(S)
+        .unwrap();
+    let encoded = buf.split().freeze();
+    let mut bytes = encoded.clone();
+    let mut p = compact::TCompactInputProtocol::new(&mut bytes);
+    p.set_max_string_size(S.len());
+    assert_eq!(p.read_string().unwrap(), S);
+    let mut bytes = encoded.clone();
+    let mut p = compact::TCompactInputProtocol::new(&mut bytes);
+    p.set_max_string_size(S.len() - 1);
+    assert_too_long(p.read_bytes());
+    let mut p = compact::TAsyncCompactProtocol::new(&encoded[..]);
+    p.set_max_string_size(S.len() - 1);
+    assert_too_long(block_on(p.read_string()));
+}
+
+#[cfg(not(feature = "safe-only"))]
+#[test]
+fn test_unsafe_max_string_size() {
+    use super::{binary_unsafe, compact_unsafe};
+
+    let mut buf = BytesMut::new();
+    binary::TBinaryProtocol::new(&mut buf, false)
+        .write_string(TOO_LONG)
+        .unwrap();
+    let mut bytes = buf.split().freeze();
+    let mut p = unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut bytes) };
+    p.set_max_string_size(TOO_LONG.len() - 1);
+    assert_too_long(p.read_bytes());
+
+    compact::TCompactOutputProtocol::new(&mut buf, false)
+        .write_string(TOO_LONG)
+        .unwrap();
+    let mut bytes = buf.split().freeze();
+    let mut p = unsafe { compact_unsafe::TCompactUnsafeInputProtocol::new(&mut bytes) };
+    p.set_max_string_size(TOO_LONG.len() - 1);
+    assert_too_long(p.read_faststr());
+}
+
+fn error_kind<T: std::fmt::Debug>(
+    r: Result<T, super::ThriftException>,
+) -> super::error::ProtocolExceptionKind {
+    match r {
+        Err(super::ThriftException::Protocol(e)) => e.kind(),
+        r => panic!("expected a protocol error, got {r:?}"),
+    }
+}
+
+// a list of i32s claiming a negative size, and one claiming 2^20 elements
+const LE_NEGATIVE_LIST: &[u8] = &[8, 0xff, 0xff, 0xff, 0xff];
+const LE_OVERSIZED_LIST: &[u8] = &[8, 0, 0, 0x10, 0];
+
+#[test]
+fn test_binary_le_container_size() {
+    use super::error::ProtocolExceptionKind;
+
+    let mut bytes = Bytes::from_static(LE_NEGATIVE_LIST);
+    let mut p = binary_le::TBinaryProtocol::with_byte_order(&mut bytes, false);
+    assert_eq!(
+        error_kind(p.read_list_begin()),
+        ProtocolExceptionKind::NegativeSize
+    );
+    let mut p = binary_le::TAsyncBinaryProtocol::with_byte_order(LE_NEGATIVE_LIST);
+    assert_eq!(
+        error_kind(block_on(p.read_set_begin())),
+        ProtocolExceptionKind::NegativeSize
+    );
+
+    let mut bytes = Bytes::from_static(LE_OVERSIZED_LIST);
+    let mut p = binary_le::TBinaryProtocol::with_byte_order(&mut bytes, false);
+    assert_eq!(
+        error_kind(p.read_list_begin()),
+        ProtocolExceptionKind::InvalidData
+    );
+    let mut p = binary_le::TAsyncBinaryProtocol::with_byte_order(LE_OVERSIZED_LIST);
+    assert_eq!(block_on(p.read_list_begin()).unwrap().size, 1 << 20);
+    let mut p = binary_le::TAsyncBinaryProtocol::with_byte_order(LE_OVERSIZED_LIST);
+    p.set_max_container_size(1024);
+    assert_eq!(
+        error_kind(block_on(p.read_list_begin())),
+        ProtocolExceptionKind::SizeLimit
+    );
+}
+
+#[cfg(not(feature = "safe-only"))]
+#[test]
+fn test_unsafe_container_size() {
+    use super::{binary_unsafe, compact_unsafe, error::ProtocolExceptionKind};
+
+    // the same lists in big-endian, and maps of 2^20 i32 entries
+    let mut bytes = Bytes::from_static(&[8, 0xff, 0xff, 0xff, 0xff]);
+    let mut p = unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut bytes) };
+    assert_eq!(
+        error_kind(p.read_list_begin()),
+        ProtocolExceptionKind::NegativeSize
+    );
+    let oversized = Bytes::from_static(&[8, 0, 0x10, 0, 0]);
+    let mut bytes = oversized.clone();
+    let mut p = unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut bytes) };
+    assert_eq!(
+        error_kind(p.read_set_begin()),
+        ProtocolExceptionKind::InvalidData
+    );
+    let mut bytes = oversized;
+    let mut p = unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut bytes) };
+    p.set_max_container_size(1024);
+    assert_eq!(
+        error_kind(p.read_list_begin()),
+        ProtocolExceptionKind::SizeLimit
+    );
+    let mut bytes = Bytes::from_static(&[8, 8, 0, 0x10, 0, 0]);
+    let mut p = unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut bytes) };
+    assert_eq!(
+        error_kind(p.read_map_begin()),
+        ProtocolExceptionKind::InvalidData
+    );
+
+    // the compact sizes are unsigned, a negative one reads as 2^32 - 1
+    let mut bytes = Bytes::from_static(&[0xf5, 0xff, 0xff, 0xff, 0xff, 0x0f]);
+    let mut p = unsafe { compact_unsafe::TCompactUnsafeInputProtocol::new(&mut bytes) };
+    assert_eq!(
+        error_kind(p.read_list_begin()),
+        ProtocolExceptionKind::InvalidData
+    );
+    let oversized = Bytes::from_static(&[0xf5, 0x80, 0x80, 0x40]);
+    let mut bytes = oversized.clone();
+    let mut p = unsafe { compact_unsafe::TCompactUnsafeInputProtocol::new(&mut bytes) };
+    assert_eq!(
+        error_kind(p.read_set_begin()),
+        ProtocolExceptionKind::InvalidData
+    );
+    let mut bytes = oversized;
+    let mut p = unsafe { compact_unsafe::TCompactUnsafeInputProtocol::new(&mut bytes) };
+    p.set_max_container_size(1024);
+    assert_eq!(
+        error_kind(p.read_list_begin()),
+        ProtocolExceptionKind::SizeLimit
+    );
+    let mut bytes = Bytes::from_static(&[0x80, 0x80, 0x40, 0x55]);
+    let mut p = unsafe { compact_unsafe::TCompactUnsafeInputProtocol::new(&mut bytes) };
+    assert_eq!(
+        error_kind(p.read_map_begin()),
+        ProtocolExceptionKind::InvalidData
+    );
+}
+
+fn ping() -> TMessageIdentifier {
+    TMessageIdentifier::new("ping".into(), TMessageType::Call, 1)
+}
+
+#[test]
+fn test_binary_le_max_message_size() {
+    use super::error::ProtocolExceptionKind;
+
+    let mut buf = BytesMut::new();
+    binary_le::TBinaryProtocol::with_byte_order(&mut buf, false)
+        .write_message_begin(&ping())
+        .unwrap();
+    let message = buf.split().freeze();
+
+    let mut bytes = message.clone();
+    let mut p = binary_le::TBinaryProtocol::with_byte_order(&mut bytes, false);
+    p.set_max_message_size(4);
+    assert_eq!(p.read_message_begin().unwrap().name, "ping");
+    let mut bytes = message.clone();
+    let mut p = binary_le::TBinaryProtocol::with_byte_order(&mut bytes, false);
+    p.set_max_message_size(3);
+    assert_eq!(
+        error_kind(p.read_message_begin()),
+        ProtocolExceptionKind::MessageTooLarge
+    );
+    let mut p = binary_le::TAsyncBinaryProtocol::with_byte_order(&message[..]);
+    p.set_max_message_size(3);
+    assert_eq!(
+        error_kind(block_on(p.read_message_begin())),
+        ProtocolExceptionKind::MessageTooLarge
+    );
+}
+
+#[cfg(not(feature = "safe-only"))]
+#[test]
+fn test_unsafe_max_message_size() {
+    use super::{binary_unsafe, compact_unsafe, error::ProtocolExceptionKind};
+
+    let mut buf = BytesMut::new();
+    binary::TBinaryProtocol::new(&mut buf, false)
+        .write_message_begin(&ping())
+        .unwrap();
+    let message = buf.split().freeze();
+    let mut bytes = message.clone();
+    let mut p = unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut bytes) };
+    p.set_max_message_size(4);
+    assert_eq!(p.read_message_begin().unwrap().name, "ping");
+    let mut bytes = message;
+    let mut p = unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut bytes) };
+    p.set_max_message_size(3);
+    assert_eq!(
+        error_kind(p.read_message_begin()),
+        ProtocolExceptionKind::MessageTooLarge
+    );
+    let mut bytes = Bytes::from_static(&[8, 0x7f, 0xff, 0xff, 0xff]);
+    let mut p = unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut bytes) };
+    p.set_max_message_size(1024);
+    assert_eq!(
+        error_kind(p.read_list_begin()),
+        ProtocolExceptionKind::MessageTooLarge
+    );
+
+    compact::TCompactOutputProtocol::new(&mut buf, false)
+        .write_message_begin(&ping())
+        .unwrap();
+    let message = buf.split().freeze();
+    let mut bytes = message.clone();
+    let mut p = unsafe { compact_unsafe::TCompactUnsafeInputProtocol::new(&mut bytes) };
+    p.set_max_message_size(4);
+    assert_eq!(p.read_message_begin().unwrap().name, "ping");
+    let mut bytes = message;
+    let mut p = unsafe { compact_unsafe::TCompactUnsafeInputProtocol::new(&mut bytes) };
+    p.set_max_message_size(3);
+    assert_eq!(
+        error_kind(p.read_message_begin()),
+        ProtocolExceptionKind::MessageTooLarge
+    );
+    let mut bytes = Bytes::from_static(&[0xf5, 0xff, 0xff, 0xff, 0x7f]);
+    let mut p = unsafe { compact_unsafe::TCompactUnsafeInputProtocol::new(&mut bytes) };
+    p.set_max_message_size(1024);
+    assert_eq!(
+        error_kind(p.read_list_begin()),
+        ProtocolExceptionKind::MessageTooLarge
+    );
+}
+
+#[cfg(all(feature = "validate-utf8", not(feature = "safe-only")))]
+#[test]
+fn test_validate_utf8() {
+    use super::{binary_unsafe, compact_unsafe};
+
+    fn assert_invalid_utf8<T: std::fmt::Debug>(r: Result<T, super::ThriftException>) {
+        match r {
+            Err(super::ThriftException::Protocol(e)) => {
+                assert_eq!(e.kind(), super::error::ProtocolExceptionKind::InvalidData);
+                assert!(e.message().contains("utf-8"), "{e}");
+            }
+            r => panic!("expected an invalid utf-8 error, got {r:?}"),
+        }
+    }
+
+    const INVALID: &[u8] = &[b'a', 0xff, 0xfe];
+
+    let mut buf = BytesMut::new();
+    binary::TBinaryProtocol::new(&mut buf, false)
+        .write_bytes(Bytes::from_static(INVALID))
+        .unwrap();
+    let encoded = buf.split().freeze();
+    let mut bytes = encoded.clone();
+    assert_invalid_utf8(binary::TBinaryProtocol::new(&mut bytes, false).read_string());
+    let mut bytes = encoded.clone();
+    assert_invalid_utf8(binary::TBinaryProtocol::new(&mut bytes, false).read_faststr());
+    assert_invalid_utf8(binary::TBinaryProtocol::new(&encoded[..], false).read_borrowed_str());
+    assert_invalid_utf8(
+        binary::TBinaryProtocol::new(reader::IoReader::new(&encoded[..]), false).read_string(),
+    );
+    assert_invalid_utf8(block_on(
+        binary::TAsyncBinaryProtocol::new(&encoded[..]).read_faststr(),
+    ));
+    let mut bytes = encoded.clone();
+    assert_invalid_utf8(
+        unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut bytes) }.read_string(),
+    );
+    let mut bytes = encoded;
+    assert_invalid_utf8(
+        unsafe { binary_unsafe::TBinaryUnsafeInputProtocol::new(&mut bytes) }.read_faststr(),
+    );
+
+    compact::TCompactOutputProtocol::new(&mut buf, false)
+        .write_bytes(Bytes::from_static(INVALID))
+        .unwrap();
+    let encoded = buf.split().freeze();
+    let mut bytes = encoded.clone();
+    assert_invalid_utf8(compact::TCompactInputProtocol::new(&mut bytes).read_string());
+    let mut bytes = encoded.clone();
+    assert_invalid_utf8(
+        unsafe { compact_unsafe::TCompactUnsafeInputProtocol::new(&mut bytes) }.read_string(),
+    );
+    let mut bytes = encoded;
+    assert_invalid_utf8(
+        unsafe { compact_unsafe::TCompactUnsafeInputProtocol::new(&mut bytes) }.read_faststr(),
+    );
+}
@@ -0,0 +1,594 @@
+//! Size passes derived from the output protocols.
+//!
+//! [`CountingOutputProtocol`] runs the writes of an output protocol into a
+//! [`ByteCounter`], which keeps no byte but their count. It implements
+//! [`TLengthProtocol`] on top of them, each length being what the matching
+//! write adds to the count, so the sizes it computes are those of the
+//! protocol by construction, without a hand-written length impl to keep in
+//! sync.
+//!
+//! ```
+//! use pilota::thrift::{
+//!     ApplicationException, ApplicationExceptionKind, Message,
+//!     compact::TCompactOutputProtocol,
+//!     counting::{ByteCounter, CountingOutputProtocol},
+//! };
+//!
+//! let e = ApplicationException::new(ApplicationExceptionKind::UNKNOWN_METHOD, "no such method");
+//! let mut counter = ByteCounter::new();
+//! let mut protocol = CountingOutputProtocol::new(TCompactOutputProtocol::new(&mut counter, false));
+//! let size = e.size(&mut protocol);
+//!
+//! let mut buf = bytes::BytesMut::new();
+//! e.encode(&mut TCompactOutputProtocol::new(&mut buf, false)).unwrap();
+//! assert_eq!(size, buf.len());
+//! ```
+
+use bytes::{BufMut, Bytes, buf::UninitSlice};
+use faststr::FastStr;
+
+use super::{
+    TLengthProtocol, TListIdentifier, TMapIdentifier, TMessageIdentifier, TOutputProtocol,
+    TSetIdentifier, TStructIdentifier, TType, ThriftException, rw_ext::WriteExt,
+};
+
+/// A transport counting the bytes written into it, see the [module](self)
+/// docs.
+#[derive(Clone, Debug)]
+pub struct ByteCounter {
+    count: usize,
+    // what `chunk_mut` hands out, overwritten by every write through it
+    scratch: [u8; 64],
+}
+
+impl Default for ByteCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ByteCounter {
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            scratch: [0; 64],
+        }
+    }
+
+    /// The number of bytes written so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl WriteExt for ByteCounter {
+    #[inline]
+    fn write_slice(&mut self, src: &[u8]) {
+        self.count += src.len();
+    }
+}
+
+// SAFETY: the chunk handed out is the scratch buffer, and `advance_mut` only
+// counts what was written into it.
+unsafe impl BufMut for ByteCounter {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        usize::MAX - self.count
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        assert!(cnt <= self.scratch.len(), "advance out of bounds");
+        self.count += cnt;
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        UninitSlice::new(&mut self.scratch)
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.count += src.len();
+    }
+}
+
+/// An output protocol writing into a [`ByteCounter`], which computes the
+/// lengths of [`TLengthProtocol`] with its writes, see the [module](self)
+/// docs.
+pub struct CountingOutputProtocol<P> {
+    inner: P,
+    zero_copy_threshold: Option<usize>,
+    zero_copy_len: usize,
+}
+
+impl<P: TOutputProtocol<BufMut = ByteCounter>> CountingOutputProtocol<P> {
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            zero_copy_threshold: None,
+            zero_copy_len: 0,
+        }
+    }
+
+    /// Counts the binaries and strings of at least `threshold` bytes in
+    /// [`TLengthProtocol::zero_copy_len`], as those a zero-copy output
+    /// protocol with this threshold inserts instead of copying.
+    pub fn with_zero_copy_threshold(mut self, threshold: usize) -> Self {
+        self.zero_copy_threshold = Some(threshold);
+        self
+    }
+
+    /// The number of bytes written or counted so far.
+    pub fn count(&mut self) -> usize {
+        self.inner.buf_mut().count()
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    #[inline]
+    fn count_zero_copy(&mut self, len: usize) {
+        if self
+            .zero_copy_threshold
+            .is_some_and(|threshold| len >= threshold && !self.inner.is_deterministic())
+        {
+            self.zero_copy_len += len;
+        }
+    }
+
+    /// The number of bytes `write` adds.
+    #[inline]
+    fn counted(&mut self, write: impl FnOnce(&mut P) -> Result<(), ThriftException>) -> usize {
+        let before = self.count();
+        // the writes into a counter can't fail
+        let _ = write(&mut self.inner);
+        self.count() - before
+    }
+}
+
+impl<P: TOutputProtocol<BufMut = ByteCounter>> TLengthProtocol for CountingOutputProtocol<P> {
+    #[inline]
+    fn message_begin_len(&mut self, identifier: &TMessageIdentifier) -> usize {
+        self.counted(|p| p.write_message_begin(identifier))
+    }
+
+    #[inline]
+    fn message_end_len(&mut self) -> usize {
+        self.counted(|p| p.write_message_end())
+    }
+
+    #[inline]
+    fn struct_begin_len(&mut self, identifier: &TStructIdentifier) -> usize {
+        self.counted(|p| p.write_struct_begin(identifier))
+    }
+
+    #[inline]
+    fn struct_end_len(&mut self) -> usize {
+        self.counted(|p| p.write_struct_end())
+    }
+
+    #[inline]
+    fn field_begin_len(&mut self, field_type: TType, id: Option<i16>) -> usize {
+        self.counted(|p| p.write_field_begin(field_type, id.unwrap_or_default()))
+    }
+
+    #[inline]
+    fn field_end_len(&mut self) -> usize {
+        self.counted(|p| p.write_field_end())
+    }
+
+    #[inline]
+    fn field_stop_len(&mut self) -> usize {
+        self.counted(|p| p.write_field_stop())
+    }
+
+    #[inline]
+    fn bool_len(&mut self, b: bool) -> usize {
+        self.counted(|p| p.write_bool(b))
+    }
+
+    #[inline]
+    fn bytes_len(&mut self, b: &[u8]) -> usize {
+        self.count_zero_copy(b.len());
+        self.counted(|p| p.write_bytes_vec(b))
+    }
+
+    #[inline]
+    fn bytes_vec_len(&mut self, b: &[u8]) -> usize {
+        self.counted(|p| p.write_bytes_vec(b))
+    }
+
+    #[inline]
+    fn byte_len(&mut self, b: u8) -> usize {
+        self.counted(|p| p.write_byte(b))
+    }
+
+    #[inline]
+    fn uuid_len(&mut self, u: [u8; 16]) -> usize {
+        self.counted(|p| p.write_uuid(u))
+    }
+
+    #[inline]
+    fn i8_len(&mut self, i: i8) -> usize {
+        self.counted(|p| p.write_i8(i))
+    }
+
+    #[inline]
+    fn i16_len(&mut self, i: i16) -> usize {
+        self.counted(|p| p.write_i16(i))
+    }
+
+    #[inline]
+    fn i32_len(&mut self, i: i32) -> usize {
+        self.counted(|p| p.write_i32(i))
+    }
+
+    #[inline]
+    fn i64_len(&mut self, i: i64) -> usize {
+        self.counted(|p| p.write_i64(i))
+    }
+
+    #[inline]
+    fn double_len(&mut self, d: f64) -> usize {
+        self.counted(|p| p.write_double(d))
+    }
+
+    #[inline]
+    fn string_len(&mut self, s: &str) -> usize {
+        self.counted(|p| p.write_string(s))
+    }
+
+    #[inline]
+    fn faststr_len(&mut self, s: &FastStr) -> usize {
+        self.count_zero_copy(s.len());
+        self.counted(|p| p.write_string(s))
+    }
+
+    #[inline]
+    fn list_begin_len(&mut self, identifier: TListIdentifier) -> usize {
+        self.counted(|p| p.write_list_begin(identifier))
+    }
+
+    #[inline]
+    fn list_end_len(&mut self) -> usize {
+        self.counted(|p| p.write_list_end())
+    }
+
+    #[inline]
+    fn set_begin_len(&mut self, identifier: TSetIdentifier) -> usize {
+        self.counted(|p| p.write_set_begin(identifier))
+    }
+
+    #[inline]
+    fn set_end_len(&mut self) -> usize {
+        self.counted(|p| p.write_set_end())
+    }
+
+    #[inline]
+    fn map_begin_len(&mut self, identifier: TMapIdentifier) -> usize {
+        self.counted(|p| p.write_map_begin(identifier))
+    }
+
+    #[inline]
+    fn map_end_len(&mut self) -> usize {
+        self.counted(|p| p.write_map_end())
+    }
+
+    #[inline]
+    fn field_names_len(&mut self, names: &'static [(i16, &'static str)]) -> usize {
+        self.inner.write_field_names(names);
+        0
+    }
+
+    #[inline]
+    fn zero_copy_len(&mut self) -> usize {
+        self.zero_copy_len
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.zero_copy_len = 0;
+        self.inner.reset();
+    }
+}
+
+impl<P: TOutputProtocol<BufMut = ByteCounter>> TOutputProtocol for CountingOutputProtocol<P> {
+    type BufMut = ByteCounter;
+
+    #[inline]
+    fn write_message_begin(
+        &mut self,
+        identifier: &TMessageIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.inner.write_message_begin(identifier)
+    }
+
+    #[inline]
+    fn write_message_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_message_end()
+    }
+
+    #[inline]
+    fn write_struct_begin(
+        &mut self,
+        identifier: &TStructIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.inner.write_struct_begin(identifier)
+    }
+
+    #[inline]
+    fn write_struct_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_struct_end()
+    }
+
+    #[inline]
+    fn write_field_names(&mut self, names: &'static [(i16, &'static str)]) {
+        self.inner.write_field_names(names)
+    }
+
+    #[inline]
+    fn write_field_begin(&mut self, field_type: TType, id: i16) -> Result<(), ThriftException> {
+        self.inner.write_field_begin(field_type, id)
+    }
+
+    #[inline]
+    fn write_field_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_field_end()
+    }
+
+    #[inline]
+    fn write_field_stop(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_field_stop()
+    }
+
+    #[inline]
+    fn write_bool(&mut self, b: bool) -> Result<(), ThriftException> {
+        self.inner.write_bool(b)
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        self.count_zero_copy(b.len());
+        self.inner.write_bytes(b)
+    }
+
+    #[inline]
+    fn write_bytes_without_len(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        self.count_zero_copy(b.len());
+        self.inner.write_bytes_without_len(b)
+    }
+
+    #[inline]
+    fn write_uuid(&mut self, u: [u8; 16]) -> Result<(), ThriftException> {
+        self.inner.write_uuid(u)
+    }
+
+    #[inline]
+    fn write_bytes_vec(&mut self, b: &[u8]) -> Result<(), ThriftException> {
+        self.inner.write_bytes_vec(b)
+    }
+
+    #[inline]
+    fn write_byte(&mut self, b: u8) -> Result<(), ThriftException> {
+        self.inner.write_byte(b)
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) -> Result<(), ThriftException> {
+        self.inner.write_i8(i)
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) -> Result<(), ThriftException> {
+        self.inner.write_i16(i)
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) -> Result<(), ThriftException> {
+        self.inner.write_i32(i)
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) -> Result<(), ThriftException> {
+        self.inner.write_i64(i)
+    }
+
+    #[inline]
+    fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
+        self.inner.write_double(d)
+    }
+
+    #[inline]
+    fn write_string(&mut self, s: &str) -> Result<(), ThriftException> {
+        self.inner.write_string(s)
+    }
+
+    #[inline]
+    fn write_faststr(&mut self, s: FastStr) -> Result<(), ThriftException> {
+        self.count_zero_copy(s.len());
+        self.inner.write_faststr(s)
+    }
+
+    #[inline]
+    fn write_list_begin(&mut self, identifier: TListIdentifier) -> Result<(), ThriftException> {
+        self.inner.write_list_begin(identifier)
+    }
+
+    #[inline]
+    fn write_list_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_list_end()
+    }
+
+    #[inline]
+    fn write_set_begin(&mut self, identifier: TSetIdentifier) -> Result<(), ThriftException> {
+        self.inner.write_set_begin(identifier)
+    }
+
+    #[inline]
+    fn write_set_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_set_end()
+    }
+
+    #[inline]
+    fn write_map_begin(&mut self, identifier: TMapIdentifier) -> Result<(), ThriftException> {
+        self.inner.write_map_begin(identifier)
+    }
+
+    #[inline]
+    fn write_map_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_map_end()
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), ThriftException> {
+        self.inner.flush()
+    }
+
+    #[inline]
+    fn is_deterministic(&self) -> bool {
+        self.inner.is_deterministic()
+    }
+
+    #[inline]
+    fn buf_mut(&mut self) -> &mut ByteCounter {
+        self.inner.buf_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Bytes, BytesMut};
+    use faststr::FastStr;
+    use linkedbytes::LinkedBytes;
+
+    use super::{ByteCounter, CountingOutputProtocol};
+    use crate::{
+        AHashMap,
+        thrift::{
+            Message, TAsyncInputProtocol, TInputProtocol, TLengthProtocol, TLengthProtocolExt,
+            TOutputProtocol, TOutputProtocolExt, TType, ThriftException, VOID_IDENT,
+            binary::TBinaryProtocol, compact::TCompactOutputProtocol,
+        },
+    };
+
+    struct Record {
+        id: i64,
+        name: FastStr,
+        blob: Bytes,
+        scores: Vec<i32>,
+        tags: AHashMap<i16, String>,
+    }
+
+    impl Message for Record {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_struct_begin(&VOID_IDENT)?;
+            protocol.write_i64_field(1, self.id)?;
+            protocol.write_faststr_field(2, self.name.clone())?;
+            protocol.write_bytes_field(3, self.blob.clone())?;
+            protocol.write_list_field(4, TType::I32, &self.scores, |p, s| p.write_i32(*s))?;
+            protocol.write_map_field(
+                5,
+                TType::I16,
+                TType::Binary,
+                &self.tags,
+                |p, k| p.write_i16(*k),
+                |p, v| p.write_string(v),
+            )?;
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()
+        }
+
+        fn decode<T: TInputProtocol>(_protocol: &mut T) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(
+            _protocol: &mut T,
+        ) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+            protocol.struct_begin_len(&VOID_IDENT)
+                + protocol.i64_field_len(Some(1), self.id)
+                + protocol.faststr_field_len(Some(2), &self.name)
+                + protocol.bytes_field_len(Some(3), &self.blob)
+                + protocol.list_field_len(Some(4), TType::I32, &self.scores, |p, s| p.i32_len(*s))
+                + protocol.map_field_len(
+                    Some(5),
+                    TType::I16,
+                    TType::Binary,
+                    &self.tags,
+                    |p, k| p.i16_len(*k),
+                    |p, v| p.string_len(v),
+                )
+                + protocol.field_stop_len()
+                + protocol.struct_end_len()
+        }
+    }
+
+    fn record() -> Record {
+        Record {
+            id: -300,
+            name: FastStr::new("x".repeat(5000)),
+            blob: Bytes::from(vec![1; 10]),
+            scores: vec![1, -1, 100_000],
+            tags: (0..20).map(|i| (i * 100, format!("tag {i}"))).collect(),
+        }
+    }
+
+    #[test]
+    fn test_binary() {
+        let record = record();
+        let mut buf = BytesMut::new();
+        record
+            .encode(&mut TBinaryProtocol::new(&mut buf, false))
+            .unwrap();
+
+        let mut counter = ByteCounter::new();
+        let mut protocol = CountingOutputProtocol::new(TBinaryProtocol::new(&mut counter, false));
+        assert_eq!(record.size(&mut protocol), buf.len());
+        assert_eq!(record.size(&mut TBinaryProtocol::new((), false)), buf.len());
+
+        let mut counter = ByteCounter::new();
+        let mut protocol = CountingOutputProtocol::new(TBinaryProtocol::new(&mut counter, false));
+        record.encode(&mut protocol).unwrap();
+        assert_eq!(protocol.count(), buf.len());
+    }
+
+    #[test]
+    fn test_compact() {
+        let record = record();
+        let mut buf = BytesMut::new();
+        record
+            .encode(&mut TCompactOutputProtocol::new(&mut buf, false))
+            .unwrap();
+
+        let mut counter = ByteCounter::new();
+        let mut protocol =
+            CountingOutputProtocol::new(TCompactOutputProtocol::new(&mut counter, false));
+        assert_eq!(record.size(&mut protocol), buf.len());
+        assert_eq!(
+            record.size(&mut TCompactOutputProtocol::new((), false)),
+            buf.len()
+        );
+    }
+
+    #[test]
+    fn test_zero_copy_len() {
+        let record = record();
+        let mut buf = LinkedBytes::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, true);
+        protocol.set_zero_copy_threshold(1000);
+        record.encode(&mut protocol).unwrap();
+
+        let mut counter = ByteCounter::new();
+        let mut protocol = CountingOutputProtocol::new(TBinaryProtocol::new(&mut counter, false))
+            .with_zero_copy_threshold(1000);
+        let size = record.size(&mut protocol);
+        assert_eq!(protocol.zero_copy_len(), 5000);
+        assert_eq!(size, buf.concat().len());
+    }
+}
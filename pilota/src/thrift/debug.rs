@@ -0,0 +1,858 @@
+//! A write-only protocol rendering messages as indented text, for log lines
+//! and the diffs of failing tests.
+//!
+//! As with the `TDebugProtocol` of Apache Thrift, every value is written with
+//! its type, each field on its own line:
+//!
+//! ```text
+//! Shape {
+//!   1: name (string) = "triangle",
+//!   2: points (list<struct>) = [
+//!     Point {
+//!       1: x (i32) = 0,
+//!       2: y (i32) = -1,
+//!     },
+//!   ],
+//!   3: tags (map<i64, bool>) = {
+//!     7 => true,
+//!   },
+//! }
+//! ```
+//!
+//! The names of the fields are those given to
+//! [`TOutputProtocol::write_field_names`] by the structs generated with
+//! `with_field_names`, the fields of other structs only have their ids.
+//! Binaries are escaped byte strings, uuids are hyphenated and messages are
+//! prefixed with their type, name and sequence id, as in
+//! `call echo #3: EchoArgs { .. }`. The raw unknown fields of a struct are
+//! written as binaries of the type `unknown`, which the sizes of the structs
+//! count as their raw lengths.
+//!
+//! ```
+//! use pilota::thrift::{ApplicationException, ApplicationExceptionKind, debug_message};
+//!
+//! let e = ApplicationException::new(ApplicationExceptionKind::UNKNOWN_METHOD, "no such method");
+//! assert_eq!(
+//!     debug_message(&e).unwrap(),
+//!     "TApplicationException {\n  1: message (string) = \"no such method\",\n  2: type (i32) = 1,\n}",
+//! );
+//! ```
+
+use bytes::{BufMut, Bytes, BytesMut};
+use faststr::FastStr;
+
+use super::{
+    Message, TLengthProtocol, TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType,
+    TOutputProtocol, TSetIdentifier, TStructIdentifier, TType, ThriftException,
+    counting::ByteCounter, rw_ext::WriteExt,
+};
+
+/// Renders `message` with a [`TDebugProtocol`].
+pub fn debug_message<T: Message>(message: &T) -> Result<String, ThriftException> {
+    let mut buf = BytesMut::new();
+    message.encode(&mut TDebugProtocol::new(&mut buf))?;
+    // the strings are written as they are and everything else is ascii
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+pub struct TDebugProtocol<T> {
+    trans: T,
+    scopes: Scopes,
+}
+
+impl<T> TDebugProtocol<T> {
+    pub fn new(trans: T) -> Self {
+        Self {
+            trans,
+            scopes: Scopes(Vec::new()),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.trans
+    }
+
+    /// The number of bytes `write` outputs, which leaves the scopes as they
+    /// were once the value it writes is complete.
+    #[inline]
+    fn counted(&mut self, write: impl FnOnce(&mut Scopes, &mut ByteCounter)) -> usize {
+        let mut counter = ByteCounter::new();
+        write(&mut self.scopes, &mut counter);
+        counter.count()
+    }
+}
+
+/// The containers being written, innermost last.
+struct Scopes(Vec<Scope>);
+
+enum Scope {
+    /// The fields of a struct, `field` being the id of the one whose value
+    /// comes next.
+    Struct {
+        names: &'static [(i16, &'static str)],
+        first: bool,
+        field: Option<i16>,
+    },
+    /// The elements of a list or a set.
+    Seq { first: bool },
+    /// The entries of a map, `key` being whether the next value is a key.
+    Map { first: bool, key: bool },
+}
+
+const INDENT: &[u8] = b"  ";
+
+impl Scopes {
+    /// Writes what comes before a value of the type `label`.
+    fn begin_value<W: WriteExt>(&mut self, w: &mut W, label: &str) {
+        let depth = self.0.len();
+        match self.0.last_mut() {
+            None => {}
+            Some(Scope::Struct {
+                names,
+                first,
+                field,
+            }) => {
+                let Some(id) = field.take() else {
+                    return;
+                };
+                open(w, first);
+                indent(w, depth);
+                w.write_slice(id.to_string().as_bytes());
+                if let Some((_, name)) = names.iter().find(|(i, _)| *i == id) {
+                    w.write_slice(b": ");
+                    w.write_slice(name.as_bytes());
+                }
+                w.write_slice(b" (");
+                w.write_slice(label.as_bytes());
+                w.write_slice(b") = ");
+            }
+            Some(Scope::Seq { first }) => {
+                open(w, first);
+                indent(w, depth);
+            }
+            Some(Scope::Map { first, key: true }) => {
+                open(w, first);
+                indent(w, depth);
+            }
+            Some(Scope::Map { key: false, .. }) => {}
+        }
+    }
+
+    fn end_value<W: WriteExt>(&mut self, w: &mut W) {
+        match self.0.last_mut() {
+            None => {}
+            Some(Scope::Map { key, .. }) => {
+                w.write_slice(if *key { b" => " } else { b",\n" });
+                *key = !*key;
+            }
+            Some(_) => w.write_slice(b",\n"),
+        }
+    }
+
+    fn value<W: WriteExt>(&mut self, w: &mut W, label: &str, text: &[u8]) {
+        self.begin_value(w, label);
+        w.write_slice(text);
+        self.end_value(w);
+    }
+
+    fn string<W: WriteExt>(&mut self, w: &mut W, s: &str) {
+        self.value(w, "string", format!("{s:?}").as_bytes());
+    }
+
+    fn binary<W: WriteExt>(&mut self, w: &mut W, label: &str, b: &[u8]) {
+        self.value(w, label, format!("b\"{}\"", b.escape_ascii()).as_bytes());
+    }
+
+    fn uuid<W: WriteExt>(&mut self, w: &mut W, u: [u8; 16]) {
+        let mut out = String::with_capacity(36);
+        for (i, b) in u.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                out.push('-');
+            }
+            out.push_str(&format!("{b:02x}"));
+        }
+        self.value(w, "uuid", out.as_bytes());
+    }
+
+    /// Writes the raw unknown fields of a struct.
+    fn unknown<W: WriteExt>(&mut self, w: &mut W, b: &[u8]) {
+        let depth = self.0.len();
+        if let Some(Scope::Struct { first, field, .. }) = self.0.last_mut() {
+            if field.is_none() {
+                open(w, first);
+                indent(w, depth);
+                w.write_slice(b".. (unknown) = ");
+            }
+        }
+        self.binary(w, "unknown", b);
+    }
+
+    fn message_begin<W: WriteExt>(&mut self, w: &mut W, identifier: &TMessageIdentifier) {
+        let message_type = match identifier.message_type {
+            TMessageType::Call => "call",
+            TMessageType::Reply => "reply",
+            TMessageType::Exception => "exception",
+            TMessageType::OneWay => "oneway",
+        };
+        w.write_slice(
+            format!(
+                "{message_type} {} #{}: ",
+                identifier.name, identifier.sequence_number
+            )
+            .as_bytes(),
+        );
+    }
+
+    fn struct_begin<W: WriteExt>(&mut self, w: &mut W, identifier: &TStructIdentifier) {
+        self.begin_value(w, "struct");
+        w.write_slice(identifier.name.as_bytes());
+        w.write_slice(b" {");
+        self.0.push(Scope::Struct {
+            names: &[],
+            first: true,
+            field: None,
+        });
+    }
+
+    fn set_field_names(&mut self, names: &'static [(i16, &'static str)]) {
+        if let Some(Scope::Struct { names: n, .. }) = self.0.last_mut() {
+            *n = names;
+        }
+    }
+
+    fn field_begin(&mut self, id: i16) {
+        if let Some(Scope::Struct { field, .. }) = self.0.last_mut() {
+            *field = Some(id);
+        }
+    }
+
+    fn seq_begin<W: WriteExt>(&mut self, w: &mut W, kind: &str, element_type: TType) {
+        self.begin_value(w, &format!("{kind}<{}>", type_name(element_type)));
+        w.write_slice(b"[");
+        self.0.push(Scope::Seq { first: true });
+    }
+
+    fn map_begin<W: WriteExt>(&mut self, w: &mut W, identifier: TMapIdentifier) {
+        self.begin_value(
+            w,
+            &format!(
+                "map<{}, {}>",
+                type_name(identifier.key_type),
+                type_name(identifier.value_type)
+            ),
+        );
+        w.write_slice(b"{");
+        self.0.push(Scope::Map {
+            first: true,
+            key: true,
+        });
+    }
+
+    /// Closes the innermost container.
+    fn end<W: WriteExt>(&mut self, w: &mut W) {
+        let (first, close) = match self.0.pop() {
+            Some(Scope::Struct { first, .. }) => (first, b"}"),
+            Some(Scope::Seq { first }) => (first, b"]"),
+            Some(Scope::Map { first, .. }) => (first, b"}"),
+            None => return,
+        };
+        if !first {
+            indent(w, self.0.len());
+        }
+        w.write_slice(close);
+        self.end_value(w);
+    }
+}
+
+/// Starts the first line of a container's contents.
+#[inline]
+fn open<W: WriteExt>(w: &mut W, first: &mut bool) {
+    if *first {
+        w.write_slice(b"\n");
+    }
+    *first = false;
+}
+
+#[inline]
+fn indent<W: WriteExt>(w: &mut W, depth: usize) {
+    for _ in 0..depth {
+        w.write_slice(INDENT);
+    }
+}
+
+fn type_name(ttype: TType) -> &'static str {
+    match ttype {
+        TType::Stop => "stop",
+        TType::Void => "void",
+        TType::Bool => "bool",
+        TType::I8 => "i8",
+        TType::Double => "double",
+        TType::I16 => "i16",
+        TType::I32 => "i32",
+        TType::I64 => "i64",
+        TType::Binary => "binary",
+        TType::Struct => "struct",
+        TType::Map => "map",
+        TType::Set => "set",
+        TType::List => "list",
+        TType::Uuid => "uuid",
+    }
+}
+
+impl<T> TLengthProtocol for TDebugProtocol<T> {
+    #[inline]
+    fn message_begin_len(&mut self, identifier: &TMessageIdentifier) -> usize {
+        self.counted(|s, w| s.message_begin(w, identifier))
+    }
+
+    #[inline]
+    fn message_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn struct_begin_len(&mut self, identifier: &TStructIdentifier) -> usize {
+        self.counted(|s, w| s.struct_begin(w, identifier))
+    }
+
+    #[inline]
+    fn struct_end_len(&mut self) -> usize {
+        self.counted(|s, w| s.end(w))
+    }
+
+    #[inline]
+    fn field_names_len(&mut self, names: &'static [(i16, &'static str)]) -> usize {
+        self.scopes.set_field_names(names);
+        0
+    }
+
+    #[inline]
+    fn field_begin_len(&mut self, _field_type: TType, id: Option<i16>) -> usize {
+        self.scopes.field_begin(id.unwrap_or_default());
+        0
+    }
+
+    #[inline]
+    fn field_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn field_stop_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn bool_len(&mut self, b: bool) -> usize {
+        self.counted(|s, w| s.value(w, "bool", if b { b"true" } else { b"false" }))
+    }
+
+    #[inline]
+    fn bytes_len(&mut self, b: &[u8]) -> usize {
+        self.counted(|s, w| s.binary(w, "binary", b))
+    }
+
+    #[inline]
+    fn bytes_vec_len(&mut self, b: &[u8]) -> usize {
+        self.counted(|s, w| s.binary(w, "binary", b))
+    }
+
+    #[inline]
+    fn byte_len(&mut self, b: u8) -> usize {
+        self.counted(|s, w| s.value(w, "i8", b.to_string().as_bytes()))
+    }
+
+    #[inline]
+    fn uuid_len(&mut self, u: [u8; 16]) -> usize {
+        self.counted(|s, w| s.uuid(w, u))
+    }
+
+    #[inline]
+    fn i8_len(&mut self, i: i8) -> usize {
+        self.counted(|s, w| s.value(w, "i8", i.to_string().as_bytes()))
+    }
+
+    #[inline]
+    fn i16_len(&mut self, i: i16) -> usize {
+        self.counted(|s, w| s.value(w, "i16", i.to_string().as_bytes()))
+    }
+
+    #[inline]
+    fn i32_len(&mut self, i: i32) -> usize {
+        self.counted(|s, w| s.value(w, "i32", i.to_string().as_bytes()))
+    }
+
+    #[inline]
+    fn i64_len(&mut self, i: i64) -> usize {
+        self.counted(|s, w| s.value(w, "i64", i.to_string().as_bytes()))
+    }
+
+    #[inline]
+    fn double_len(&mut self, d: f64) -> usize {
+        self.counted(|s, w| s.value(w, "double", format!("{d:?}").as_bytes()))
+    }
+
+    #[inline]
+    fn string_len(&mut self, s: &str) -> usize {
+        self.counted(|scopes, w| scopes.string(w, s))
+    }
+
+    #[inline]
+    fn faststr_len(&mut self, s: &FastStr) -> usize {
+        self.counted(|scopes, w| scopes.string(w, s))
+    }
+
+    #[inline]
+    fn list_begin_len(&mut self, identifier: TListIdentifier) -> usize {
+        self.counted(|s, w| s.seq_begin(w, "list", identifier.element_type))
+    }
+
+    #[inline]
+    fn list_end_len(&mut self) -> usize {
+        self.counted(|s, w| s.end(w))
+    }
+
+    #[inline]
+    fn set_begin_len(&mut self, identifier: TSetIdentifier) -> usize {
+        self.counted(|s, w| s.seq_begin(w, "set", identifier.element_type))
+    }
+
+    #[inline]
+    fn set_end_len(&mut self) -> usize {
+        self.counted(|s, w| s.end(w))
+    }
+
+    #[inline]
+    fn map_begin_len(&mut self, identifier: TMapIdentifier) -> usize {
+        self.counted(|s, w| s.map_begin(w, identifier))
+    }
+
+    #[inline]
+    fn map_end_len(&mut self) -> usize {
+        self.counted(|s, w| s.end(w))
+    }
+}
+
+impl<B: WriteExt + BufMut> TOutputProtocol for TDebugProtocol<&mut B> {
+    type BufMut = B;
+
+    #[inline]
+    fn write_message_begin(
+        &mut self,
+        identifier: &TMessageIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.scopes.message_begin(self.trans, identifier);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_message_end(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_struct_begin(
+        &mut self,
+        identifier: &TStructIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.scopes.struct_begin(self.trans, identifier);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_struct_end(&mut self) -> Result<(), ThriftException> {
+        self.scopes.end(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_field_names(&mut self, names: &'static [(i16, &'static str)]) {
+        self.scopes.set_field_names(names);
+    }
+
+    #[inline]
+    fn write_field_begin(&mut self, _field_type: TType, id: i16) -> Result<(), ThriftException> {
+        self.scopes.field_begin(id);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_field_end(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_field_stop(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bool(&mut self, b: bool) -> Result<(), ThriftException> {
+        self.scopes
+            .value(self.trans, "bool", if b { b"true" } else { b"false" });
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        self.scopes.binary(self.trans, "binary", &b);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bytes_without_len(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        self.scopes.unknown(self.trans, &b);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_uuid(&mut self, u: [u8; 16]) -> Result<(), ThriftException> {
+        self.scopes.uuid(self.trans, u);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bytes_vec(&mut self, b: &[u8]) -> Result<(), ThriftException> {
+        self.scopes.binary(self.trans, "binary", b);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_byte(&mut self, b: u8) -> Result<(), ThriftException> {
+        self.scopes
+            .value(self.trans, "i8", b.to_string().as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) -> Result<(), ThriftException> {
+        self.scopes
+            .value(self.trans, "i8", i.to_string().as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) -> Result<(), ThriftException> {
+        self.scopes
+            .value(self.trans, "i16", i.to_string().as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) -> Result<(), ThriftException> {
+        self.scopes
+            .value(self.trans, "i32", i.to_string().as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) -> Result<(), ThriftException> {
+        self.scopes
+            .value(self.trans, "i64", i.to_string().as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
+        self.scopes
+            .value(self.trans, "double", format!("{d:?}").as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn write_string(&mut self, s: &str) -> Result<(), ThriftException> {
+        self.scopes.string(self.trans, s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_faststr(&mut self, s: FastStr) -> Result<(), ThriftException> {
+        self.scopes.string(self.trans, &s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_list_begin(&mut self, identifier: TListIdentifier) -> Result<(), ThriftException> {
+        self.scopes
+            .seq_begin(self.trans, "list", identifier.element_type);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_list_end(&mut self) -> Result<(), ThriftException> {
+        self.scopes.end(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_set_begin(&mut self, identifier: TSetIdentifier) -> Result<(), ThriftException> {
+        self.scopes
+            .seq_begin(self.trans, "set", identifier.element_type);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_set_end(&mut self) -> Result<(), ThriftException> {
+        self.scopes.end(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_map_begin(&mut self, identifier: TMapIdentifier) -> Result<(), ThriftException> {
+        self.scopes.map_begin(self.trans, identifier);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_map_end(&mut self) -> Result<(), ThriftException> {
+        self.scopes.end(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn buf_mut(&mut self) -> &mut Self::BufMut {
+        self.trans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Bytes, BytesMut};
+    use faststr::FastStr;
+
+    use super::{TDebugProtocol, debug_message};
+    use crate::{
+        AHashMap,
+        thrift::{
+            Message, TAsyncInputProtocol, TInputProtocol, TLengthProtocol, TLengthProtocolExt,
+            TMessageIdentifier, TMessageType, TOutputProtocol, TOutputProtocolExt,
+            TStructIdentifier, TType, ThriftException,
+        },
+    };
+
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    struct Shape {
+        name: FastStr,
+        points: Vec<Point>,
+        data: Bytes,
+        scale: f64,
+        tags: AHashMap<i64, bool>,
+        empty: Vec<i8>,
+        id: [u8; 16],
+    }
+
+    const POINT: TStructIdentifier = TStructIdentifier { name: "Point" };
+    const SHAPE: TStructIdentifier = TStructIdentifier { name: "Shape" };
+
+    impl Point {
+        const FIELD_NAMES: &'static [(i16, &'static str)] = &[(1, "x"), (2, "y")];
+    }
+
+    impl Message for Point {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_struct_begin(&POINT)?;
+            protocol.write_field_names(Self::FIELD_NAMES);
+            protocol.write_i32_field(1, self.x)?;
+            protocol.write_i32_field(2, self.y)?;
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()
+        }
+
+        fn decode<T: TInputProtocol>(_protocol: &mut T) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(
+            _protocol: &mut T,
+        ) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+            protocol.struct_begin_len(&POINT)
+                + protocol.field_names_len(Self::FIELD_NAMES)
+                + protocol.i32_field_len(Some(1), self.x)
+                + protocol.i32_field_len(Some(2), self.y)
+                + protocol.field_stop_len()
+                + protocol.struct_end_len()
+        }
+    }
+
+    // without field names
+    impl Message for Shape {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_struct_begin(&SHAPE)?;
+            protocol.write_faststr_field(1, self.name.clone())?;
+            protocol.write_list_field(2, TType::Struct, &self.points, |p, point| {
+                p.write_struct(point)
+            })?;
+            protocol.write_bytes_field(3, self.data.clone())?;
+            protocol.write_double_field(4, self.scale)?;
+            protocol.write_map_field(
+                5,
+                TType::I64,
+                TType::Bool,
+                &self.tags,
+                |p, k| p.write_i64(*k),
+                |p, v| p.write_bool(*v),
+            )?;
+            protocol.write_list_field(6, TType::I8, &self.empty, |p, i| p.write_i8(*i))?;
+            protocol.write_uuid_field(7, self.id)?;
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()
+        }
+
+        fn decode<T: TInputProtocol>(_protocol: &mut T) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(
+            _protocol: &mut T,
+        ) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+            protocol.struct_begin_len(&SHAPE)
+                + protocol.faststr_field_len(Some(1), &self.name)
+                + protocol.list_field_len(Some(2), TType::Struct, &self.points, |p, point| {
+                    p.struct_len(point)
+                })
+                + protocol.bytes_field_len(Some(3), &self.data)
+                + protocol.double_field_len(Some(4), self.scale)
+                + protocol.map_field_len(
+                    Some(5),
+                    TType::I64,
+                    TType::Bool,
+                    &self.tags,
+                    |p, k| p.i64_len(*k),
+                    |p, v| p.bool_len(*v),
+                )
+                + protocol.list_field_len(Some(6), TType::I8, &self.empty, |p, i| p.i8_len(*i))
+                + protocol.uuid_field_len(Some(7), self.id)
+                + protocol.field_stop_len()
+                + protocol.struct_end_len()
+        }
+    }
+
+    fn to_debug<M: Message>(message: &M) -> String {
+        let text = debug_message(message).unwrap();
+        let size = message.size(&mut TDebugProtocol::new(()));
+        assert_eq!(size, text.len());
+        text
+    }
+
+    #[test]
+    fn test_debug() {
+        let shape = Shape {
+            name: "tri\"angle\"\n".into(),
+            points: vec![Point { x: 0, y: -1 }, Point { x: 10, y: 20 }],
+            data: Bytes::from_static(b"hi\0\xff"),
+            scale: 0.5,
+            tags: AHashMap::from_iter([(7, true)]),
+            empty: Vec::new(),
+            id: [
+                0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+                0xe0, 0xc8,
+            ],
+        };
+        assert_eq!(
+            to_debug(&shape),
+            r#"Shape {
+  1 (string) = "tri\"angle\"\n",
+  2 (list<struct>) = [
+    Point {
+      1: x (i32) = 0,
+      2: y (i32) = -1,
+    },
+    Point {
+      1: x (i32) = 10,
+      2: y (i32) = 20,
+    },
+  ],
+  3 (binary) = b"hi\x00\xff",
+  4 (double) = 0.5,
+  5 (map<i64, bool>) = {
+    7 => true,
+  },
+  6 (list<i8>) = [],
+  7 (uuid) = 67e55044-10b1-426f-9247-bb680e5fe0c8,
+}"#
+        );
+    }
+
+    #[test]
+    fn test_message() {
+        struct Call(Point);
+
+        impl Message for Call {
+            fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+                protocol.write_message_begin(&TMessageIdentifier::new(
+                    "locate".into(),
+                    TMessageType::Call,
+                    3,
+                ))?;
+                protocol.write_struct(&self.0)?;
+                protocol.write_message_end()
+            }
+
+            fn decode<T: TInputProtocol>(_protocol: &mut T) -> Result<Self, ThriftException> {
+                unreachable!()
+            }
+
+            async fn decode_async<T: TAsyncInputProtocol>(
+                _protocol: &mut T,
+            ) -> Result<Self, ThriftException> {
+                unreachable!()
+            }
+
+            fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+                protocol.message_begin_len(&TMessageIdentifier::new(
+                    "locate".into(),
+                    TMessageType::Call,
+                    3,
+                )) + protocol.struct_len(&self.0)
+                    + protocol.message_end_len()
+            }
+        }
+
+        assert_eq!(
+            to_debug(&Call(Point { x: 1, y: 2 })),
+            "call locate #3: Point {\n  1: x (i32) = 1,\n  2: y (i32) = 2,\n}"
+        );
+
+        // an empty struct stays on its line
+        let mut buf = BytesMut::new();
+        let mut protocol = TDebugProtocol::new(&mut buf);
+        protocol.write_struct_begin(&POINT).unwrap();
+        protocol.write_field_stop().unwrap();
+        protocol.write_struct_end().unwrap();
+        assert_eq!(&buf[..], b"Point {}");
+
+        let mut buf = BytesMut::new();
+        let mut protocol = TDebugProtocol::new(&mut buf);
+        protocol.write_struct_begin(&POINT).unwrap();
+        protocol.write_i32_field(1, 1).unwrap();
+        protocol
+            .write_bytes_without_len(Bytes::from_static(b"\x08\x00\x02"))
+            .unwrap();
+        protocol.write_field_stop().unwrap();
+        protocol.write_struct_end().unwrap();
+        assert_eq!(
+            &buf[..],
+            br#"Point {
+  1 (i32) = 1,
+  .. (unknown) = b"\x08\x00\x02",
+}"#
+        );
+    }
+}
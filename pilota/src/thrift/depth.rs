@@ -0,0 +1,141 @@
+use super::{ThriftException, error::ProtocolExceptionKind, new_protocol_exception};
+
+/// Counts how deeply the structs and containers being read are nested, so a
+/// crafted message can't recurse the decoder, or `skip`, past `max`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DepthTracker {
+    max: usize,
+    depth: usize,
+}
+
+impl DepthTracker {
+    #[inline]
+    pub(crate) fn new(max: usize) -> Self {
+        Self { max, depth: 0 }
+    }
+}
+
+/// Enters a struct or a container, failing with
+/// [`ProtocolExceptionKind::DepthLimit`] past the maximum depth.
+#[inline]
+pub(crate) fn enter(tracker: &mut Option<DepthTracker>) -> Result<(), ThriftException> {
+    match tracker {
+        Some(tracker) if tracker.depth >= tracker.max => Err(new_protocol_exception(
+            ProtocolExceptionKind::DepthLimit,
+            format!("nested deeper than the maximum depth {}", tracker.max),
+        )),
+        Some(tracker) => {
+            tracker.depth += 1;
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Leaves the struct or container entered last.
+#[inline]
+pub(crate) fn exit(tracker: &mut Option<DepthTracker>) {
+    if let Some(tracker) = tracker {
+        tracker.depth = tracker.depth.saturating_sub(1);
+    }
+}
+
+/// Restarts at the top level, used when a new message begins on the same
+/// protocol.
+#[inline]
+pub(crate) fn reset(tracker: &mut Option<DepthTracker>) {
+    if let Some(tracker) = tracker {
+        tracker.depth = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use bytes::{Bytes, BytesMut};
+
+    use crate::thrift::{
+        ProtocolExceptionKind, TAsyncInputProtocol, TInputProtocol, TListIdentifier,
+        TOutputProtocol, TType, ThriftException,
+        binary::{TAsyncBinaryProtocol, TBinaryProtocol},
+        compact::{TCompactInputProtocol, TCompactOutputProtocol},
+    };
+
+    // lists nested `depth` deep, the innermost one empty
+    fn write_nested<P: TOutputProtocol>(p: &mut P, depth: usize) {
+        for i in 0..depth {
+            let size = usize::from(i + 1 < depth);
+            p.write_list_begin(TListIdentifier::new(TType::List, size))
+                .unwrap();
+        }
+        for _ in 0..depth {
+            p.write_list_end().unwrap();
+        }
+    }
+
+    fn assert_depth_limit<T: std::fmt::Debug>(r: Result<T, ThriftException>) {
+        match r {
+            Err(ThriftException::Protocol(e)) => {
+                assert_eq!(e.kind(), ProtocolExceptionKind::DepthLimit)
+            }
+            r => panic!("expected a depth limit error, got {r:?}"),
+        }
+    }
+
+    #[test]
+    fn test_binary_depth() {
+        let mut buf = BytesMut::new();
+        write_nested(&mut TBinaryProtocol::new(&mut buf, false), 5);
+        let encoded = buf.freeze();
+
+        let mut bytes = encoded.clone();
+        let mut protocol = TBinaryProtocol::new(&mut bytes, false);
+        protocol.set_max_depth(4);
+        assert_depth_limit(protocol.skip(TType::List));
+
+        // the next message starts at the top level again
+        let mut bytes = encoded.clone();
+        protocol.reset_with(&mut bytes);
+        protocol.set_max_depth(5);
+        protocol.skip(TType::List).unwrap();
+        let mut bytes = encoded.clone();
+        protocol.reset_with(&mut bytes);
+        protocol.skip(TType::List).unwrap();
+    }
+
+    #[test]
+    fn test_compact_depth() {
+        let mut buf = BytesMut::new();
+        write_nested(&mut TCompactOutputProtocol::new(&mut buf, false), 5);
+        let encoded = buf.freeze();
+
+        let mut bytes = encoded.clone();
+        let mut protocol = TCompactInputProtocol::new(&mut bytes);
+        protocol.set_max_depth(4);
+        assert_depth_limit(protocol.skip(TType::List));
+
+        let mut bytes = encoded;
+        let mut protocol = TCompactInputProtocol::new(&mut bytes);
+        protocol.set_max_depth(5);
+        protocol.skip(TType::List).unwrap();
+    }
+
+    #[test]
+    fn test_async_depth() {
+        let mut buf = BytesMut::new();
+        write_nested(&mut TBinaryProtocol::new(&mut buf, false), 5);
+        let encoded: Bytes = buf.freeze();
+
+        let mut protocol = TAsyncBinaryProtocol::new(&encoded[..]);
+        protocol.set_max_depth(4);
+        match pin!(protocol.skip(TType::List)).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(r) => assert_depth_limit(r),
+            Poll::Pending => panic!("reading a slice is ready"),
+        }
+    }
+}
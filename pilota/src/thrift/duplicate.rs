@@ -0,0 +1,83 @@
+//! Strict decoding of structs whose fields appear more than once.
+//!
+//! The last value of a field sent twice silently wins by default. Once
+//! `set_reject_duplicate_fields` is called on an input protocol, the
+//! generated decode reports the field read into with [`SeenFields`] and fails
+//! with [`ProtocolExceptionKind::InvalidData`] on the second one, as a
+//! security-sensitive service must not act on a value another hop may have
+//! read differently.
+
+use super::{ProtocolExceptionKind, ThriftException, new_protocol_exception};
+
+/// The fields of a struct read so far, by their position in the struct.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct SeenFields {
+    strict: bool,
+    seen: u64,
+    // the positions past the first 64, in words of 64
+    more: Vec<u64>,
+}
+
+impl SeenFields {
+    #[inline]
+    pub fn new(strict: bool) -> Self {
+        Self {
+            strict,
+            ..Default::default()
+        }
+    }
+
+    /// Records the field `id` at `index` in the struct, failing if it was
+    /// read already and the protocol rejects duplicate fields.
+    #[inline]
+    pub fn insert(&mut self, index: usize, id: i16) -> Result<(), ThriftException> {
+        if !self.strict {
+            return Ok(());
+        }
+        let word = match index / 64 {
+            0 => &mut self.seen,
+            i => {
+                if self.more.len() < i {
+                    self.more.resize(i, 0);
+                }
+                &mut self.more[i - 1]
+            }
+        };
+        let bit = 1 << (index % 64);
+        if *word & bit != 0 {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                format!("field {id} appears more than once"),
+            ));
+        }
+        *word |= bit;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SeenFields;
+
+    #[test]
+    fn test_seen_fields() {
+        let mut lenient = SeenFields::new(false);
+        lenient.insert(0, 1).unwrap();
+        lenient.insert(0, 1).unwrap();
+
+        let mut strict = SeenFields::new(true);
+        for index in [0, 63, 64, 200] {
+            strict.insert(index, index as i16 + 1).unwrap();
+        }
+        for index in [0, 63, 64, 200] {
+            let e = strict.insert(index, index as i16 + 1).unwrap_err();
+            assert!(
+                e.to_string()
+                    .contains(&format!("field {} appears more than once", index + 1)),
+                "{e}"
+            );
+        }
+        strict.insert(1, 2).unwrap();
+    }
+}
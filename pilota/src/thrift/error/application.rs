@@ -18,6 +18,8 @@ const TAPPLICATION_EXCEPTION: TStructIdentifier = TStructIdentifier {
     name: "TApplicationException",
 };
 
+const FIELD_NAMES: &[(i16, &str)] = &[(1, "message"), (2, "type")];
+
 #[deprecated(
     since = "0.11.0",
     note = "Please use the `ApplicationException` instead. This type will be removed in the next release."
@@ -98,6 +100,7 @@ impl Message for ApplicationException {
     #[inline]
     fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
         protocol.write_struct_begin(&TAPPLICATION_EXCEPTION)?;
+        protocol.write_field_names(FIELD_NAMES);
 
         protocol.write_field_begin(TType::Binary, 1)?;
         protocol.write_string(&self.message)?;
@@ -200,6 +203,7 @@ impl Message for ApplicationException {
     #[inline]
     fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
         protocol.struct_begin_len(&TAPPLICATION_EXCEPTION)
+            + protocol.field_names_len(FIELD_NAMES)
             + protocol.field_begin_len(TType::Binary, Some(1))
             + protocol.string_len(&self.message)
             + protocol.field_end_len()
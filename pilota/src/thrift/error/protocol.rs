@@ -23,6 +23,16 @@ pub struct ProtocolException {
     kind: ProtocolExceptionKind,
     /// Human-readable error message.
     message: FastStr,
+    /// The sizes behind a `ProtocolExceptionKind::BufferTooSmall`.
+    buffer_sizes: Option<BufferSizes>,
+}
+
+/// How many bytes an unsafe output protocol needed and how many were left of
+/// its buffer, carried by a [`ProtocolExceptionKind::BufferTooSmall`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct BufferSizes {
+    pub needed: usize,
+    pub remaining: usize,
 }
 
 impl ProtocolException {
@@ -31,6 +41,17 @@ impl ProtocolException {
         ProtocolException {
             kind,
             message: message.into(),
+            buffer_sizes: None,
+        }
+    }
+
+    /// Create a [`ProtocolExceptionKind::BufferTooSmall`] for a write of
+    /// `needed` bytes with `remaining` bytes left.
+    pub fn buffer_too_small(needed: usize, remaining: usize) -> ProtocolException {
+        ProtocolException {
+            kind: ProtocolExceptionKind::BufferTooSmall,
+            message: format!("needed {needed} bytes, {remaining} remaining").into(),
+            buffer_sizes: Some(BufferSizes { needed, remaining }),
         }
     }
 
@@ -46,6 +67,12 @@ impl ProtocolException {
         &self.message
     }
 
+    /// Get the sizes of a [`ProtocolExceptionKind::BufferTooSmall`].
+    #[inline]
+    pub fn buffer_sizes(&self) -> Option<BufferSizes> {
+        self.buffer_sizes
+    }
+
     msg_impl!();
 }
 
@@ -59,6 +86,10 @@ impl Display for ProtocolException {
             ProtocolExceptionKind::BadVersion => "invalid thrift version",
             ProtocolExceptionKind::NotImplemented => "not implemented",
             ProtocolExceptionKind::DepthLimit => "maximum skip depth reached",
+            ProtocolExceptionKind::BudgetExceeded => "decode budget exceeded",
+            ProtocolExceptionKind::Expired => "message expired",
+            ProtocolExceptionKind::BufferTooSmall => "buffer too small",
+            ProtocolExceptionKind::MessageTooLarge => "message too large",
         };
 
         write!(f, "{}: {}", error_text, self.message)
@@ -95,4 +126,18 @@ pub enum ProtocolExceptionKind {
     /// Reached the maximum nested depth to which an encoded Thrift field could
     /// be skipped.
     DepthLimit,
+    /// Decoding exceeded the [`Budget`](crate::thrift::budget::Budget) set on
+    /// the input protocol.
+    BudgetExceeded,
+    /// The message expired before it was decoded, see
+    /// [`Expiring`](crate::thrift::expiry::Expiring).
+    Expired,
+    /// The buffer given to an unsafe output protocol is shorter than what is
+    /// written, see
+    /// [`set_capacity_check`](crate::thrift::binary_unsafe::TBinaryUnsafeOutputProtocol::set_capacity_check).
+    BufferTooSmall,
+    /// A length or element count read from the input exceeds the maximum
+    /// message size set on the input protocol, or a frame exceeds the
+    /// maximum frame size of the codec.
+    MessageTooLarge,
 }
@@ -0,0 +1,283 @@
+//! Expiry of queued messages.
+//!
+//! [`Expiring`] wraps a message in a struct with an optional absolute expiry
+//! time, in milliseconds since the unix epoch, as field 1 and the message as
+//! field 2. The expiry is written first and checked as soon as it is read, so
+//! a stale message fails with [`ProtocolExceptionKind::Expired`] before its
+//! body is decoded.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::{
+    Message, ProtocolExceptionKind, TAsyncInputProtocol, TInputProtocol, TLengthProtocol,
+    TOutputProtocol, TStructIdentifier, TType, ThriftException, new_protocol_exception,
+};
+
+const EXPIRING_IDENT: TStructIdentifier = TStructIdentifier { name: "Expiring" };
+
+const EXPIRES_AT_ID: i16 = 1;
+const MESSAGE_ID: i16 = 2;
+
+/// A message with an optional expiry time, see the [module](self) docs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expiring<M> {
+    /// When the message expires, `None` if it never does.
+    pub expires_at: Option<SystemTime>,
+    pub message: M,
+}
+
+impl<M> Expiring<M> {
+    /// A message that never expires.
+    pub fn new(message: M) -> Self {
+        Self {
+            expires_at: None,
+            message,
+        }
+    }
+
+    /// A message that expires `ttl` from now.
+    pub fn with_ttl(message: M, ttl: Duration) -> Self {
+        Self {
+            expires_at: Some(SystemTime::now() + ttl),
+            message,
+        }
+    }
+
+    /// Whether the message has expired at `now`.
+    pub fn is_expired_at(&self, now: SystemTime) -> bool {
+        self.expires_at.is_some_and(|at| at <= now)
+    }
+}
+
+fn to_millis(t: SystemTime) -> i64 {
+    match t.duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_millis().min(i64::MAX as u128) as i64,
+        Err(e) => -(e.duration().as_millis().min(i64::MAX as u128) as i64),
+    }
+}
+
+fn from_millis(ms: i64) -> SystemTime {
+    if ms >= 0 {
+        UNIX_EPOCH + Duration::from_millis(ms as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_millis(ms.unsigned_abs())
+    }
+}
+
+fn check_expiry(ms: i64) -> Result<SystemTime, ThriftException> {
+    let expires_at = from_millis(ms);
+    if expires_at <= SystemTime::now() {
+        return Err(new_protocol_exception(
+            ProtocolExceptionKind::Expired,
+            format!("message expired at {ms}ms since the unix epoch"),
+        ));
+    }
+    Ok(expires_at)
+}
+
+fn missing_message() -> ThriftException {
+    new_protocol_exception(
+        ProtocolExceptionKind::InvalidData,
+        "expiring envelope without a message",
+    )
+}
+
+impl<M: Message> Message for Expiring<M> {
+    fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+        protocol.write_struct_begin(&EXPIRING_IDENT)?;
+        if let Some(expires_at) = self.expires_at {
+            protocol.write_field_begin(TType::I64, EXPIRES_AT_ID)?;
+            protocol.write_i64(to_millis(expires_at))?;
+            protocol.write_field_end()?;
+        }
+        protocol.write_field_begin(TType::Struct, MESSAGE_ID)?;
+        self.message.encode(protocol)?;
+        protocol.write_field_end()?;
+        protocol.write_field_stop()?;
+        protocol.write_struct_end()
+    }
+
+    fn decode<T: TInputProtocol>(protocol: &mut T) -> Result<Self, ThriftException> {
+        let mut expires_at = None;
+        let mut message = None;
+        protocol.read_struct_begin()?;
+        loop {
+            let field_ident = protocol.read_field_begin()?;
+            match (field_ident.field_type, field_ident.id) {
+                (TType::Stop, _) => break,
+                (TType::I64, Some(EXPIRES_AT_ID)) => {
+                    expires_at = Some(check_expiry(protocol.read_i64()?)?);
+                }
+                (TType::Struct, Some(MESSAGE_ID)) => message = Some(M::decode(protocol)?),
+                (ttype, _) => {
+                    protocol.skip(ttype)?;
+                }
+            }
+            protocol.read_field_end()?;
+        }
+        protocol.read_struct_end()?;
+        Ok(Self {
+            expires_at,
+            message: message.ok_or_else(missing_message)?,
+        })
+    }
+
+    async fn decode_async<T: TAsyncInputProtocol>(
+        protocol: &mut T,
+    ) -> Result<Self, ThriftException> {
+        let mut expires_at = None;
+        let mut message = None;
+        protocol.read_struct_begin().await?;
+        loop {
+            let field_ident = protocol.read_field_begin().await?;
+            match (field_ident.field_type, field_ident.id) {
+                (TType::Stop, _) => break,
+                (TType::I64, Some(EXPIRES_AT_ID)) => {
+                    expires_at = Some(check_expiry(protocol.read_i64().await?)?);
+                }
+                (TType::Struct, Some(MESSAGE_ID)) => {
+                    message = Some(M::decode_async(protocol).await?)
+                }
+                (ttype, _) => {
+                    protocol.skip(ttype).await?;
+                }
+            }
+            protocol.read_field_end().await?;
+        }
+        protocol.read_struct_end().await?;
+        Ok(Self {
+            expires_at,
+            message: message.ok_or_else(missing_message)?,
+        })
+    }
+
+    fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+        protocol.struct_begin_len(&EXPIRING_IDENT)
+            + self.expires_at.map_or(0, |expires_at| {
+                protocol.field_begin_len(TType::I64, Some(EXPIRES_AT_ID))
+                    + protocol.i64_len(to_millis(expires_at))
+                    + protocol.field_end_len()
+            })
+            + protocol.field_begin_len(TType::Struct, Some(MESSAGE_ID))
+            + self.message.size(protocol)
+            + protocol.field_end_len()
+            + protocol.field_stop_len()
+            + protocol.struct_end_len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+
+    use super::*;
+    use crate::thrift::{VOID_IDENT, binary::TBinaryProtocol, compact::TCompactOutputProtocol};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Job {
+        id: i32,
+    }
+
+    impl Message for Job {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_struct_begin(&VOID_IDENT)?;
+            protocol.write_field_begin(TType::I32, 1)?;
+            protocol.write_i32(self.id)?;
+            protocol.write_field_end()?;
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()
+        }
+
+        fn decode<T: TInputProtocol>(protocol: &mut T) -> Result<Self, ThriftException> {
+            let mut id = 0;
+            protocol.read_struct_begin()?;
+            loop {
+                let field_ident = protocol.read_field_begin()?;
+                match (field_ident.field_type, field_ident.id) {
+                    (TType::Stop, _) => break,
+                    (TType::I32, Some(1)) => id = protocol.read_i32()?,
+                    (ttype, _) => {
+                        protocol.skip(ttype)?;
+                    }
+                }
+                protocol.read_field_end()?;
+            }
+            protocol.read_struct_end()?;
+            Ok(Job { id })
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(
+            _protocol: &mut T,
+        ) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+            protocol.struct_begin_len(&VOID_IDENT)
+                + protocol.field_begin_len(TType::I32, Some(1))
+                + protocol.i32_len(self.id)
+                + protocol.field_end_len()
+                + protocol.field_stop_len()
+                + protocol.struct_end_len()
+        }
+    }
+
+    fn encode(message: &Expiring<Job>) -> BytesMut {
+        let mut buf = BytesMut::new();
+        message
+            .encode(&mut TBinaryProtocol::new(&mut buf, false))
+            .unwrap();
+        assert_eq!(
+            message.size(&mut TBinaryProtocol::new((), false)),
+            buf.len()
+        );
+        buf
+    }
+
+    fn decode(buf: BytesMut) -> Result<Expiring<Job>, ThriftException> {
+        Expiring::decode(&mut TBinaryProtocol::new(&mut buf.freeze(), false))
+    }
+
+    #[test]
+    fn test_expiring() {
+        let message = Expiring::new(Job { id: 1 });
+        assert_eq!(decode(encode(&message)).unwrap(), message);
+
+        let message = Expiring::with_ttl(Job { id: 2 }, Duration::from_secs(60));
+        let decoded = decode(encode(&message)).unwrap();
+        assert_eq!(decoded.message, message.message);
+        assert_eq!(
+            decoded.expires_at.map(to_millis),
+            message.expires_at.map(to_millis)
+        );
+        assert!(!decoded.is_expired_at(SystemTime::now()));
+        assert!(decoded.is_expired_at(SystemTime::now() + Duration::from_secs(61)));
+
+        let message = Expiring {
+            expires_at: Some(UNIX_EPOCH + Duration::from_secs(1)),
+            message: Job { id: 3 },
+        };
+        match decode(encode(&message)) {
+            Err(ThriftException::Protocol(e)) => {
+                assert_eq!(e.kind(), ProtocolExceptionKind::Expired)
+            }
+            r => panic!("expected an expired message, got {r:?}"),
+        }
+
+        let mut buf = BytesMut::new();
+        message
+            .encode(&mut TCompactOutputProtocol::new(&mut buf, false))
+            .unwrap();
+        assert_eq!(
+            message.size(&mut TCompactOutputProtocol::new((), false)),
+            buf.len()
+        );
+    }
+
+    #[test]
+    fn test_millis() {
+        for ms in [i64::MIN + 1, -1, 0, 1, 1_700_000_000_000] {
+            assert_eq!(to_millis(from_millis(ms)), ms);
+        }
+    }
+}
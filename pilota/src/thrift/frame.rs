@@ -0,0 +1,299 @@
+//! Scanning of framed thrift messages without decoding them.
+//!
+//! A buffer holding several frames, each a 4-byte big-endian length followed
+//! by a binary or compact encoded message, can be split with
+//! [`FrameScanner`]. Only the message header of every frame is read, which is
+//! enough to pipeline, prioritize or route requests by method before paying
+//! for a full decode.
+
+use bytes::Bytes;
+use faststr::FastStr;
+
+use super::{
+    ProtocolExceptionKind, TMessageIdentifier, TMessageType, ThriftException,
+    new_protocol_exception,
+};
+
+/// Default upper bound of a frame, in bytes.
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+pub(crate) const FRAME_HEADER_LEN: usize = 4;
+
+const BINARY_VERSION_1: u32 = 0x80010000;
+const BINARY_VERSION_MASK: u32 = 0xffff0000;
+const COMPACT_PROTOCOL_ID: u8 = 0x82;
+const COMPACT_VERSION: u8 = 1;
+const COMPACT_VERSION_MASK: u8 = 0x1f;
+
+/// A frame found by [`FrameScanner`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameInfo {
+    /// Where the frame starts in the scanned buffer, its length prefix
+    /// included.
+    pub offset: usize,
+    /// The length of the frame, its length prefix included, so
+    /// `&buf[offset..offset + len]` can be forwarded as is.
+    pub len: usize,
+    /// The header of the message carried by the frame.
+    pub identifier: TMessageIdentifier,
+}
+
+impl FrameInfo {
+    /// The message without its length prefix.
+    pub fn payload<'a>(&self, buf: &'a [u8]) -> &'a [u8] {
+        &buf[self.offset + FRAME_HEADER_LEN..self.offset + self.len]
+    }
+}
+
+/// Iterates over the frames of a buffer, yielding a [`FrameInfo`] per frame.
+///
+/// The strict binary and the compact protocol are recognized per frame. The
+/// iteration stops at the first incomplete frame, whose start is then given
+/// by [`FrameScanner::position`], or after the first error.
+#[derive(Clone, Debug)]
+pub struct FrameScanner {
+    buf: Bytes,
+    pos: usize,
+    max_frame_size: usize,
+    failed: bool,
+}
+
+impl FrameScanner {
+    pub fn new(buf: Bytes) -> Self {
+        Self {
+            buf,
+            pos: 0,
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE,
+            failed: false,
+        }
+    }
+
+    /// Sets the largest frame accepted, larger frames fail with
+    /// [`ProtocolExceptionKind::MessageTooLarge`].
+    pub fn with_max_frame_size(mut self, max_frame_size: usize) -> Self {
+        self.max_frame_size = max_frame_size;
+        self
+    }
+
+    /// The offset of the first frame not yielded yet.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The bytes after the last complete frame.
+    pub fn remaining(&self) -> Bytes {
+        self.buf.slice(self.pos..)
+    }
+
+    fn next_frame(&mut self) -> Result<Option<FrameInfo>, ThriftException> {
+        let rest = &self.buf[self.pos..];
+        if rest.len() < FRAME_HEADER_LEN {
+            return Ok(None);
+        }
+        let size = u32::from_be_bytes(rest[..FRAME_HEADER_LEN].try_into().unwrap()) as usize;
+        if size > self.max_frame_size {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::MessageTooLarge,
+                format!(
+                    "frame size {size} exceeds max frame size {}",
+                    self.max_frame_size
+                ),
+            ));
+        }
+        let len = FRAME_HEADER_LEN + size;
+        if rest.len() < len {
+            return Ok(None);
+        }
+
+        let offset = self.pos;
+        let payload = self.buf.slice(offset + FRAME_HEADER_LEN..offset + len);
+        let identifier = read_message_header(&payload)?;
+        self.pos += len;
+        Ok(Some(FrameInfo {
+            offset,
+            len,
+            identifier,
+        }))
+    }
+}
+
+impl Iterator for FrameScanner {
+    type Item = Result<FrameInfo, ThriftException>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let frame = self.next_frame().transpose();
+        self.failed = matches!(frame, Some(Err(_)));
+        frame
+    }
+}
+
+/// Reads the message header at the start of `payload`, encoded with either
+/// the strict binary or the compact protocol.
+pub fn read_message_header(payload: &Bytes) -> Result<TMessageIdentifier, ThriftException> {
+    let mut cursor = Cursor {
+        buf: payload,
+        pos: 0,
+    };
+    if payload.first() == Some(&COMPACT_PROTOCOL_ID) {
+        cursor.pos = 1;
+        let type_and_version = cursor.read_u8()?;
+        let version = type_and_version & COMPACT_VERSION_MASK;
+        if version != COMPACT_VERSION {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::BadVersion,
+                format!("cannot process compact protocol version {version:?}"),
+            ));
+        }
+        let message_type = TMessageType::try_from(type_and_version >> 5)?;
+        let sequence_number = cursor.read_varint()? as i32;
+        let name_len = cursor.read_varint()? as usize;
+        let name = cursor.read_faststr(name_len)?;
+        Ok(TMessageIdentifier::new(name, message_type, sequence_number))
+    } else {
+        let version = cursor.read_i32()? as u32;
+        if version & BINARY_VERSION_MASK != BINARY_VERSION_1 {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::BadVersion,
+                "Bad version in message header",
+            ));
+        }
+        let message_type = TMessageType::try_from((version & 0xf) as u8)?;
+        let name_len = cursor.read_i32()?;
+        if name_len < 0 {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::NegativeSize,
+                format!("negative message name length {name_len}"),
+            ));
+        }
+        let name = cursor.read_faststr(name_len as usize)?;
+        let sequence_number = cursor.read_i32()?;
+        Ok(TMessageIdentifier::new(name, message_type, sequence_number))
+    }
+}
+
+struct Cursor<'a> {
+    buf: &'a Bytes,
+    pos: usize,
+}
+
+impl Cursor<'_> {
+    fn take(&mut self, n: usize) -> Result<usize, ThriftException> {
+        if self.buf.len() - self.pos < n {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                "message header exceeds its frame",
+            ));
+        }
+        let start = self.pos;
+        self.pos += n;
+        Ok(start)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, ThriftException> {
+        let at = self.take(1)?;
+        Ok(self.buf[at])
+    }
+
+    fn read_i32(&mut self) -> Result<i32, ThriftException> {
+        let at = self.take(4)?;
+        Ok(i32::from_be_bytes(self.buf[at..at + 4].try_into().unwrap()))
+    }
+
+    fn read_varint(&mut self) -> Result<u32, ThriftException> {
+        let mut value = 0u32;
+        for shift in (0..35).step_by(7) {
+            let b = self.read_u8()?;
+            value |= ((b & 0x7f) as u32) << shift;
+            if b < 0x80 {
+                return Ok(value);
+            }
+        }
+        Err(new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            "invalid varint in message header",
+        ))
+    }
+
+    fn read_faststr(&mut self, len: usize) -> Result<FastStr, ThriftException> {
+        let at = self.take(len)?;
+        FastStr::from_bytes(self.buf.slice(at..at + len)).map_err(|_| {
+            new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                "message name is not valid utf-8",
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{BufMut, BytesMut};
+
+    use super::*;
+    use crate::thrift::{
+        TOutputProtocol, binary::TBinaryProtocol, compact::TCompactOutputProtocol,
+    };
+
+    fn push_frame(buf: &mut BytesMut, compact: bool, name: &'static str, seq: i32) {
+        let mut payload = BytesMut::new();
+        let ident =
+            TMessageIdentifier::new(FastStr::from_static_str(name), TMessageType::Call, seq);
+        if compact {
+            TCompactOutputProtocol::new(&mut payload, false)
+                .write_message_begin(&ident)
+                .unwrap();
+        } else {
+            TBinaryProtocol::new(&mut payload, false)
+                .write_message_begin(&ident)
+                .unwrap();
+        }
+        payload.put_slice(&[0; 3]);
+        buf.put_u32(payload.len() as u32);
+        buf.put_slice(&payload);
+    }
+
+    #[test]
+    fn test_frame_scanner() {
+        let mut buf = BytesMut::new();
+        push_frame(&mut buf, false, "ping", 1);
+        push_frame(&mut buf, true, "echo", -7);
+        let complete = buf.len();
+        push_frame(&mut buf, false, "truncated", 3);
+        let buf = buf.split_to(buf.len() - 2).freeze();
+
+        let mut scanner = FrameScanner::new(buf.clone());
+        let frames = scanner.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].offset, 0);
+        assert_eq!(frames[0].identifier.name, "ping");
+        assert_eq!(frames[0].identifier.sequence_number, 1);
+        assert_eq!(frames[1].offset, frames[0].len);
+        assert_eq!(frames[1].identifier.name, "echo");
+        assert_eq!(frames[1].identifier.sequence_number, -7);
+        assert_eq!(frames[1].payload(&buf).first(), Some(&COMPACT_PROTOCOL_ID));
+        assert_eq!(scanner.position(), complete);
+        assert_eq!(scanner.remaining().len(), buf.len() - complete);
+    }
+
+    #[test]
+    fn test_frame_scanner_errors() {
+        let mut buf = BytesMut::new();
+        buf.put_u32(8);
+        buf.put_u32(BINARY_VERSION_1 | 1);
+        buf.put_i32(100);
+        push_frame(&mut buf, false, "ping", 1);
+
+        let mut scanner = FrameScanner::new(buf.freeze());
+        assert!(scanner.next().unwrap().is_err());
+        assert!(scanner.next().is_none());
+
+        let mut buf = BytesMut::new();
+        push_frame(&mut buf, false, "ping", 1);
+        let mut scanner = FrameScanner::new(buf.freeze()).with_max_frame_size(4);
+        let err = scanner.next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("exceeds max frame size"));
+    }
+}
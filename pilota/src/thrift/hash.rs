@@ -0,0 +1,378 @@
+//! Hashes of the content of messages.
+//!
+//! [`hash_message`] and [`digest_message`] hash the deterministic binary
+//! encoding of a message, the same for equal messages whatever the order of
+//! their maps and sets, for idempotency keys and change detection. The
+//! encoding is streamed into the hasher by a [`HashWriter`], so it is never
+//! held in memory as a whole, only the map or set being sorted is.
+
+use std::hash::Hasher;
+
+use bytes::{BufMut, buf::UninitSlice};
+
+use super::{Message, ThriftException, binary::TBinaryProtocol, rw_ext::WriteExt};
+
+/// The bytes a [`HashWriter`] buffers before writing them into its hasher.
+const CAPACITY: usize = 4 * 1024;
+
+/// Hashes the deterministic binary encoding of `message` into `hasher`.
+pub fn hash_message_with<T: Message, H: Hasher>(
+    message: &T,
+    hasher: &mut H,
+) -> Result<(), ThriftException> {
+    let mut writer = HashWriter::new(hasher);
+    let mut protocol = TBinaryProtocol::new(&mut writer, false);
+    protocol.set_deterministic(true);
+    message.encode(&mut protocol)?;
+    writer.finish();
+    Ok(())
+}
+
+/// The first 8 bytes of the [`digest_message`] of `message`, as a big-endian
+/// integer.
+pub fn hash_message<T: Message>(message: &T) -> Result<u64, ThriftException> {
+    let mut hasher = Sha256::new();
+    hash_message_with(message, &mut hasher)?;
+    Ok(hasher.finish())
+}
+
+/// The SHA-256 of the deterministic binary encoding of `message`.
+pub fn digest_message<T: Message>(message: &T) -> Result<[u8; 32], ThriftException> {
+    let mut hasher = Sha256::new();
+    hash_message_with(message, &mut hasher)?;
+    Ok(hasher.finalize())
+}
+
+/// A transport writing into a [`Hasher`], see the [module](self) docs.
+///
+/// The bytes are buffered and written into the hasher when the buffer is
+/// full, except for those held by the deterministic protocols while they sort
+/// the entries of a map or set.
+pub struct HashWriter<H: Hasher> {
+    hasher: H,
+    buf: Vec<u8>,
+    held: usize,
+}
+
+impl<H: Hasher> HashWriter<H> {
+    pub fn new(hasher: H) -> Self {
+        Self {
+            hasher,
+            buf: Vec::with_capacity(CAPACITY),
+            held: 0,
+        }
+    }
+
+    /// Writes the buffered bytes into the hasher and returns it.
+    pub fn finish(mut self) -> H {
+        self.write_buf();
+        self.hasher
+    }
+
+    #[inline]
+    fn write_buf(&mut self) {
+        if self.held == 0 && !self.buf.is_empty() {
+            self.hasher.write(&self.buf);
+            self.buf.clear();
+        }
+    }
+}
+
+impl<H: Hasher> WriteExt for HashWriter<H> {
+    #[inline]
+    fn write_slice(&mut self, src: &[u8]) {
+        if self.buf.len() + src.len() > CAPACITY {
+            self.write_buf();
+            if self.held == 0 && src.len() >= CAPACITY {
+                self.hasher.write(src);
+                return;
+            }
+        }
+        self.buf.extend_from_slice(src);
+    }
+
+    #[inline]
+    fn written_mut(&mut self) -> Option<&mut [u8]> {
+        Some(&mut self.buf)
+    }
+
+    #[inline]
+    fn hold_written(&mut self, hold: bool) {
+        if hold {
+            self.held += 1;
+        } else {
+            self.held -= 1;
+        }
+    }
+}
+
+// SAFETY: the chunk handed out is the spare capacity of the buffer, and
+// `advance_mut` only counts what was written into it.
+unsafe impl<H: Hasher> BufMut for HashWriter<H> {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        isize::MAX as usize - self.buf.len()
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let len = self.buf.len() + cnt;
+        assert!(len <= self.buf.capacity(), "advance out of bounds");
+        unsafe { self.buf.set_len(len) }
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.buf.len() == self.buf.capacity() {
+            self.write_buf();
+            // the bytes are held, the buffer grows instead
+            self.buf.reserve(64);
+        }
+        UninitSlice::uninit(self.buf.spare_capacity_mut())
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.write_slice(src);
+    }
+}
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256, as a [`Hasher`] whose `finish` is the first 8 bytes of the digest
+/// as a big-endian integer.
+#[derive(Clone, Debug)]
+pub struct Sha256 {
+    state: [u32; 8],
+    block: [u8; 64],
+    block_len: usize,
+    len: u64,
+}
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha256 {
+    pub fn new() -> Self {
+        Self {
+            state: [
+                0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+                0x5be0cd19,
+            ],
+            block: [0; 64],
+            block_len: 0,
+            len: 0,
+        }
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.len += data.len() as u64;
+        if self.block_len > 0 {
+            let n = (64 - self.block_len).min(data.len());
+            self.block[self.block_len..self.block_len + n].copy_from_slice(&data[..n]);
+            self.block_len += n;
+            data = &data[n..];
+            if self.block_len < 64 {
+                return;
+            }
+            let block = self.block;
+            self.compress(&block);
+            self.block_len = 0;
+        }
+        let mut blocks = data.chunks_exact(64);
+        for block in &mut blocks {
+            self.compress(block.try_into().unwrap());
+        }
+        let rest = blocks.remainder();
+        self.block[..rest.len()].copy_from_slice(rest);
+        self.block_len = rest.len();
+    }
+
+    pub fn finalize(mut self) -> [u8; 32] {
+        let bits = self.len * 8;
+        let padding = if self.block_len < 56 { 56 } else { 120 } - self.block_len;
+        self.update(&[0x80]);
+        self.update(&[0; 64][..padding - 1]);
+        self.update(&bits.to_be_bytes());
+        debug_assert_eq!(self.block_len, 0);
+
+        let mut digest = [0; 32];
+        for (bytes, word) in digest.chunks_exact_mut(4).zip(self.state) {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+
+    fn compress(&mut self, block: &[u8; 64]) {
+        let mut w = [0u32; 64];
+        for (w, bytes) in w.iter_mut().zip(block.chunks_exact(4)) {
+            *w = u32::from_be_bytes(bytes.try_into().unwrap());
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = self.state;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (state, v) in self.state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *state = state.wrapping_add(v);
+        }
+    }
+}
+
+impl Hasher for Sha256 {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let digest = self.clone().finalize();
+        u64::from_be_bytes(digest[..8].try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::Hasher;
+
+    use bytes::BytesMut;
+
+    use super::*;
+    use crate::{
+        AHashMap,
+        thrift::{
+            TAsyncInputProtocol, TInputProtocol, TLengthProtocol, TOutputProtocol,
+            TOutputProtocolExt, TType, VOID_IDENT,
+        },
+    };
+
+    struct Tags(AHashMap<i64, Vec<i64>>);
+
+    impl Message for Tags {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_struct_begin(&VOID_IDENT)?;
+            protocol.write_map_field(
+                1,
+                TType::I64,
+                TType::List,
+                &self.0,
+                |p, k| p.write_i64(*k),
+                |p, v| p.write_list(TType::I64, v, |p, i| p.write_i64(*i)),
+            )?;
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()
+        }
+
+        fn decode<T: TInputProtocol>(_protocol: &mut T) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(
+            _protocol: &mut T,
+        ) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, _protocol: &mut T) -> usize {
+            unreachable!()
+        }
+    }
+
+    fn hex(digest: [u8; 32]) -> String {
+        digest.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn test_sha256() {
+        let digest = |data: &[u8]| {
+            let mut hasher = Sha256::new();
+            hasher.update(data);
+            hex(hasher.finalize())
+        };
+        assert_eq!(
+            digest(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            digest(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+
+        // however the data is split
+        let data = (0..=255u8).cycle().take(1000).collect::<Vec<_>>();
+        let whole = digest(&data);
+        for split in [1, 55, 64, 100] {
+            let mut hasher = Sha256::new();
+            data.chunks(split).for_each(|chunk| hasher.write(chunk));
+            assert_eq!(hex(hasher.finalize()), whole);
+        }
+    }
+
+    #[test]
+    fn test_hash_message() {
+        // large enough for the writer to hash its buffer while sorting
+        let a = Tags((0..2000).map(|i| (i, vec![i; 3])).collect());
+        let b = Tags((0..2000).rev().map(|i| (i, vec![i; 3])).collect());
+
+        // the same as hashing the deterministic encoding as a whole
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, false);
+        protocol.set_deterministic(true);
+        a.encode(&mut protocol).unwrap();
+        let mut hasher = Sha256::new();
+        hasher.update(&buf);
+        let expected = hasher.finalize();
+
+        assert_eq!(digest_message(&a).unwrap(), expected);
+        assert_eq!(digest_message(&b).unwrap(), expected);
+        assert_eq!(
+            hash_message(&b).unwrap(),
+            u64::from_be_bytes(expected[..8].try_into().unwrap())
+        );
+
+        let mut c = Tags(a.0.clone());
+        c.0.insert(0, vec![1]);
+        assert_ne!(hash_message(&c).unwrap(), hash_message(&a).unwrap());
+    }
+}
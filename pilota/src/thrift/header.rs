@@ -0,0 +1,582 @@
+//! The THeader transport of Apache Thrift and the TTHeader of CloudWeGo.
+//!
+//! Both frame a message with a header carrying its sequence id, the
+//! [`ProtocolId`] of the payload, the [`Transform`]s applied to it and info
+//! records, so metadata such as tracing ids or auth tokens can ride alongside
+//! the message. A frame is laid out as:
+//!
+//! ```text
+//! length: u32 | magic: u16 | flags: u16 | seq id: i32 | header size / 4: u16
+//! header: protocol id, transforms, info records, padding | payload
+//! ```
+//!
+//! where the length counts everything after it. [`FrameHeader`] reads and
+//! writes whole frames, the two formats being told apart by their magic:
+//! THeader writes the integers of its header as varints, TTHeader as fixed
+//! width integers and also has info records keyed by integers.
+//!
+//! [`InfoHeaders`] holds the `INFO_KEYVALUE` records of a header: a record
+//! type, a count, then every key and value as a length followed by UTF-8
+//! bytes. Typed values such as [`RequestId`](crate::metadata::RequestId) are
+//! stored under their [`Metadata::THEADER_KEY`].
+//!
+//! ```
+//! use pilota::thrift::{
+//!     header::{FrameHeader, HeaderFormat},
+//!     negotiate::ProtocolId,
+//! };
+//!
+//! let mut header = FrameHeader::new(HeaderFormat::TTHeader, ProtocolId::Binary);
+//! header.seq_id = 7;
+//! header.info.insert("trace_id".into(), "4bf92f35".into());
+//!
+//! let mut buf = pilota::BytesMut::new();
+//! header.encode(b"payload", &mut buf).unwrap();
+//! let (decoded, payload) = FrameHeader::decode(&mut buf.freeze()).unwrap();
+//! assert_eq!(decoded, header);
+//! assert_eq!(payload, "payload");
+//! ```
+
+use std::collections::BTreeMap;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use faststr::FastStr;
+use integer_encoding::VarInt;
+
+use super::{
+    ProtocolExceptionKind, ThriftException,
+    frame::{DEFAULT_MAX_FRAME_SIZE, FRAME_HEADER_LEN},
+    negotiate::{ProtocolId, Transform},
+    new_protocol_exception,
+};
+use crate::metadata::Metadata;
+
+pub(crate) const INFO_PADDING: u32 = 0;
+pub(crate) const INFO_KEYVALUE: u32 = 1;
+const INFO_INTKEYVALUE: u8 = 0x10;
+
+/// The magic of the THeader frames.
+pub const THEADER_MAGIC: u16 = 0x0fff;
+/// The magic of the TTHeader frames.
+pub const TTHEADER_MAGIC: u16 = 0x1000;
+
+// magic, flags, seq id and header size
+const FIXED_LEN: usize = 10;
+
+/// The key-value info records of a THeader header, in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InfoHeaders {
+    entries: Vec<(FastStr, FastStr)>,
+}
+
+impl InfoHeaders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&FastStr> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Sets `key` to `value`, returning the previous value.
+    pub fn insert(&mut self, key: FastStr, value: FastStr) -> Option<FastStr> {
+        match self.entries.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => Some(std::mem::replace(v, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<FastStr> {
+        let i = self.entries.iter().position(|(k, _)| k == key)?;
+        Some(self.entries.remove(i).1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&FastStr, &FastStr)> {
+        self.entries.iter().map(|(k, v)| (k, v))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The value of type `M`, if set.
+    pub fn get_metadata<M: Metadata>(&self) -> Option<M> {
+        self.get(M::THEADER_KEY).cloned().map(M::from_value)
+    }
+
+    /// Sets the value of type `M`, replacing the previous one.
+    pub fn insert_metadata<M: Metadata>(&mut self, metadata: M) {
+        self.insert(
+            FastStr::from_static_str(M::THEADER_KEY),
+            metadata.into_value(),
+        );
+    }
+
+    /// Writes the records, an empty set writing nothing.
+    pub fn encode(&self, buf: &mut BytesMut) {
+        if self.entries.is_empty() {
+            return;
+        }
+        put_varint(buf, INFO_KEYVALUE);
+        put_varint(buf, self.entries.len() as u32);
+        for (key, value) in &self.entries {
+            put_varint(buf, key.len() as u32);
+            buf.put_slice(key.as_bytes());
+            put_varint(buf, value.len() as u32);
+            buf.put_slice(value.as_bytes());
+        }
+    }
+
+    /// Reads the records up to the end of `buf` or its padding, the whole of
+    /// `buf` being consumed.
+    ///
+    /// Records of other types cannot be skipped and fail with
+    /// [`ProtocolExceptionKind::InvalidData`], as in THeader.
+    pub fn decode(buf: &mut Bytes) -> Result<Self, ThriftException> {
+        let mut headers = Self::new();
+        while buf.has_remaining() {
+            match get_varint(buf)? {
+                INFO_PADDING => break,
+                INFO_KEYVALUE => {
+                    for _ in 0..get_varint(buf)? {
+                        let key = get_str(buf)?;
+                        let value = get_str(buf)?;
+                        headers.insert(key, value);
+                    }
+                }
+                ty => {
+                    return Err(new_protocol_exception(
+                        ProtocolExceptionKind::InvalidData,
+                        format!("unknown info record type {ty}"),
+                    ));
+                }
+            }
+        }
+        buf.advance(buf.remaining());
+        Ok(headers)
+    }
+}
+
+/// The format of a header frame, see the [module](self) docs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HeaderFormat {
+    /// The THeader of Apache Thrift.
+    THeader,
+    /// The TTHeader of CloudWeGo.
+    TTHeader,
+}
+
+impl HeaderFormat {
+    pub const fn magic(self) -> u16 {
+        match self {
+            Self::THeader => THEADER_MAGIC,
+            Self::TTHeader => TTHEADER_MAGIC,
+        }
+    }
+
+    /// The format of the frame at the start of `buf`, if it is a header
+    /// frame, which servers accepting framed messages too can check first.
+    pub fn detect(buf: &[u8]) -> Option<Self> {
+        let magic = buf.get(FRAME_HEADER_LEN..FRAME_HEADER_LEN + 2)?;
+        match u16::from_be_bytes(magic.try_into().unwrap()) {
+            THEADER_MAGIC => Some(Self::THeader),
+            TTHEADER_MAGIC => Some(Self::TTHeader),
+            _ => None,
+        }
+    }
+}
+
+/// The header of a THeader or TTHeader frame, see the [module](self) docs.
+///
+/// The transforms are only listed, applying them to the payload is left to
+/// the caller.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameHeader {
+    pub format: HeaderFormat,
+    pub flags: u16,
+    pub seq_id: i32,
+    /// The protocol of the payload.
+    pub protocol: ProtocolId,
+    /// The transforms applied to the payload, in order.
+    pub transforms: Vec<Transform>,
+    pub info: InfoHeaders,
+    /// The info records keyed by integers, which only TTHeader has.
+    pub int_info: BTreeMap<u16, FastStr>,
+}
+
+impl FrameHeader {
+    pub fn new(format: HeaderFormat, protocol: ProtocolId) -> Self {
+        Self {
+            format,
+            flags: 0,
+            seq_id: 0,
+            protocol,
+            transforms: Vec::new(),
+            info: InfoHeaders::new(),
+            int_info: BTreeMap::new(),
+        }
+    }
+
+    /// Writes a frame of this header and `payload`.
+    ///
+    /// Fails with [`ProtocolExceptionKind::SizeLimit`] if the header or the
+    /// frame are too large to be framed, and with
+    /// [`ProtocolExceptionKind::InvalidData`] if a THeader has integer keyed
+    /// info records.
+    pub fn encode(&self, payload: &[u8], buf: &mut BytesMut) -> Result<(), ThriftException> {
+        let mut header = BytesMut::new();
+        match self.format {
+            HeaderFormat::THeader => {
+                if !self.int_info.is_empty() {
+                    return Err(new_protocol_exception(
+                        ProtocolExceptionKind::InvalidData,
+                        "THeader has no integer keyed info records",
+                    ));
+                }
+                put_varint(&mut header, self.protocol as u32);
+                put_varint(&mut header, self.transforms.len() as u32);
+                for transform in &self.transforms {
+                    put_varint(&mut header, *transform as u32);
+                }
+                self.info.encode(&mut header);
+            }
+            HeaderFormat::TTHeader => {
+                header.put_u8(self.protocol as u8);
+                header.put_u8(fit(self.transforms.len(), u8::MAX as usize, "transforms")? as u8);
+                for transform in &self.transforms {
+                    header.put_u8(*transform as u8);
+                }
+                if !self.info.is_empty() {
+                    header.put_u8(INFO_KEYVALUE as u8);
+                    header.put_u16(fit(self.info.len(), u16::MAX as usize, "info records")? as u16);
+                    for (key, value) in self.info.iter() {
+                        put_str16(&mut header, key)?;
+                        put_str16(&mut header, value)?;
+                    }
+                }
+                if !self.int_info.is_empty() {
+                    header.put_u8(INFO_INTKEYVALUE);
+                    header.put_u16(
+                        fit(self.int_info.len(), u16::MAX as usize, "info records")? as u16
+                    );
+                    for (key, value) in &self.int_info {
+                        header.put_u16(*key);
+                        put_str16(&mut header, value)?;
+                    }
+                }
+            }
+        }
+        header.put_bytes(
+            INFO_PADDING as u8,
+            header.len().next_multiple_of(4) - header.len(),
+        );
+        let header_words = fit(header.len() / 4, u16::MAX as usize, "header words")?;
+        let size = fit(
+            FIXED_LEN + header.len() + payload.len(),
+            i32::MAX as usize,
+            "frame bytes",
+        )?;
+
+        buf.reserve(FRAME_HEADER_LEN + size);
+        buf.put_u32(size as u32);
+        buf.put_u16(self.format.magic());
+        buf.put_u16(self.flags);
+        buf.put_i32(self.seq_id);
+        buf.put_u16(header_words as u16);
+        buf.put_slice(&header);
+        buf.put_slice(payload);
+        Ok(())
+    }
+
+    /// Reads a frame, returning its header and payload, and advances `buf`
+    /// past it.
+    ///
+    /// Frames larger than [`DEFAULT_MAX_FRAME_SIZE`] fail with
+    /// [`ProtocolExceptionKind::SizeLimit`], and info records of other types
+    /// than the key-value ones with [`ProtocolExceptionKind::InvalidData`].
+    pub fn decode(buf: &mut Bytes) -> Result<(Self, Bytes), ThriftException> {
+        if buf.len() < FRAME_HEADER_LEN {
+            return Err(frame_truncated());
+        }
+        let size = u32::from_be_bytes(buf[..FRAME_HEADER_LEN].try_into().unwrap()) as usize;
+        if size > DEFAULT_MAX_FRAME_SIZE {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::SizeLimit,
+                format!("header frame size {size} exceeds {DEFAULT_MAX_FRAME_SIZE}"),
+            ));
+        }
+        if size < FIXED_LEN || buf.len() < FRAME_HEADER_LEN + size {
+            return Err(frame_truncated());
+        }
+        let format = HeaderFormat::detect(buf).ok_or_else(|| {
+            new_protocol_exception(ProtocolExceptionKind::InvalidData, "not a header frame")
+        })?;
+        buf.advance(FRAME_HEADER_LEN + 2);
+        let mut frame = buf.split_to(size - 2);
+        let flags = frame.get_u16();
+        let seq_id = frame.get_i32();
+        let header_len = frame.get_u16() as usize * 4;
+        if frame.len() < header_len {
+            return Err(frame_truncated());
+        }
+        let mut header = frame.split_to(header_len);
+
+        let mut decoded = Self::new(format, ProtocolId::Binary);
+        decoded.flags = flags;
+        decoded.seq_id = seq_id;
+        match format {
+            HeaderFormat::THeader => {
+                decoded.protocol = id(get_varint(&mut header)?)?;
+                for _ in 0..get_varint(&mut header)? {
+                    decoded.transforms.push(id(get_varint(&mut header)?)?);
+                }
+                decoded.info = InfoHeaders::decode(&mut header)?;
+            }
+            HeaderFormat::TTHeader => {
+                decoded.protocol = ProtocolId::try_from(get_u8(&mut header)?)?;
+                for _ in 0..get_u8(&mut header)? {
+                    decoded
+                        .transforms
+                        .push(Transform::try_from(get_u8(&mut header)?)?);
+                }
+                while header.has_remaining() {
+                    match get_u8(&mut header)? {
+                        ty if ty as u32 == INFO_PADDING => break,
+                        ty if ty as u32 == INFO_KEYVALUE => {
+                            for _ in 0..get_u16(&mut header)? {
+                                let key = get_str16(&mut header)?;
+                                let value = get_str16(&mut header)?;
+                                decoded.info.insert(key, value);
+                            }
+                        }
+                        INFO_INTKEYVALUE => {
+                            for _ in 0..get_u16(&mut header)? {
+                                let key = get_u16(&mut header)?;
+                                let value = get_str16(&mut header)?;
+                                decoded.int_info.insert(key, value);
+                            }
+                        }
+                        ty => {
+                            return Err(new_protocol_exception(
+                                ProtocolExceptionKind::InvalidData,
+                                format!("unknown info record type {ty}"),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        Ok((decoded, frame))
+    }
+}
+
+/// `len`, if it is at most `max`.
+fn fit(len: usize, max: usize, what: &str) -> Result<usize, ThriftException> {
+    if len > max {
+        return Err(new_protocol_exception(
+            ProtocolExceptionKind::SizeLimit,
+            format!("{len} {what} exceed the {max} of a header frame"),
+        ));
+    }
+    Ok(len)
+}
+
+/// The protocol or transform of a THeader varint id.
+fn id<T: TryFrom<u8, Error = ThriftException>>(id: u32) -> Result<T, ThriftException> {
+    u8::try_from(id)
+        .map_err(|_| {
+            new_protocol_exception(
+                ProtocolExceptionKind::NotImplemented,
+                format!("unknown id {id}"),
+            )
+        })?
+        .try_into()
+}
+
+fn put_str16(buf: &mut BytesMut, s: &str) -> Result<(), ThriftException> {
+    buf.put_u16(fit(s.len(), u16::MAX as usize, "info record bytes")? as u16);
+    buf.put_slice(s.as_bytes());
+    Ok(())
+}
+
+fn get_u8(buf: &mut Bytes) -> Result<u8, ThriftException> {
+    if !buf.has_remaining() {
+        return Err(truncated());
+    }
+    Ok(buf.get_u8())
+}
+
+fn get_u16(buf: &mut Bytes) -> Result<u16, ThriftException> {
+    if buf.remaining() < 2 {
+        return Err(truncated());
+    }
+    Ok(buf.get_u16())
+}
+
+fn get_str16(buf: &mut Bytes) -> Result<FastStr, ThriftException> {
+    let len = get_u16(buf)? as usize;
+    str_of(buf, len)
+}
+
+fn frame_truncated() -> ThriftException {
+    new_protocol_exception(
+        ProtocolExceptionKind::InvalidData,
+        "header frame is truncated",
+    )
+}
+
+pub(crate) fn put_varint(buf: &mut BytesMut, n: u32) {
+    let mut encoded = [0; 5];
+    let len = n.encode_var(&mut encoded);
+    buf.put_slice(&encoded[..len]);
+}
+
+fn get_varint(buf: &mut Bytes) -> Result<u32, ThriftException> {
+    let (n, len) = u32::decode_var(buf).ok_or_else(truncated)?;
+    buf.advance(len);
+    Ok(n)
+}
+
+fn get_str(buf: &mut Bytes) -> Result<FastStr, ThriftException> {
+    let len = get_varint(buf)? as usize;
+    str_of(buf, len)
+}
+
+fn str_of(buf: &mut Bytes, len: usize) -> Result<FastStr, ThriftException> {
+    if buf.len() < len {
+        return Err(truncated());
+    }
+    FastStr::from_bytes(buf.split_to(len)).map_err(|_| {
+        new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            "info record is not valid utf-8",
+        )
+    })
+}
+
+fn truncated() -> ThriftException {
+    new_protocol_exception(
+        ProtocolExceptionKind::InvalidData,
+        "info records are truncated",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::{IdempotencyKey, RequestId};
+
+    #[test]
+    fn test_info_headers() {
+        let mut headers = InfoHeaders::new();
+        headers.insert("a".into(), "1".into());
+        headers.insert_metadata(RequestId::new("req-1"));
+        headers.insert_metadata(IdempotencyKey::new("order-7"));
+        assert_eq!(headers.insert("a".into(), "2".into()), Some("1".into()));
+
+        let mut buf = BytesMut::new();
+        headers.encode(&mut buf);
+        buf.put_bytes(0, 3);
+        let decoded = InfoHeaders::decode(&mut buf.freeze()).unwrap();
+        assert_eq!(decoded, headers);
+        assert_eq!(decoded.get("a").unwrap(), "2");
+        assert_eq!(decoded.get_metadata(), Some(RequestId::new("req-1")));
+        assert_eq!(decoded.get_metadata(), Some(IdempotencyKey::new("order-7")));
+
+        let mut buf = BytesMut::new();
+        InfoHeaders::new().encode(&mut buf);
+        assert!(buf.is_empty());
+        assert!(
+            InfoHeaders::decode(&mut Bytes::from_static(&[2, 0]))
+                .unwrap_err()
+                .to_string()
+                .contains("unknown info record type")
+        );
+        assert!(InfoHeaders::decode(&mut Bytes::from_static(&[1, 1, 3, b'a'])).is_err());
+    }
+
+    #[test]
+    fn test_frame_header() {
+        let mut header = FrameHeader::new(HeaderFormat::TTHeader, ProtocolId::Compact);
+        header.flags = 1;
+        header.seq_id = -3;
+        header.transforms = vec![Transform::Zstd];
+        header.info.insert_metadata(RequestId::new("req-1"));
+        header.int_info.insert(8, "echo".into());
+
+        let mut buf = BytesMut::new();
+        header.encode(b"first", &mut buf).unwrap();
+        let mut theader = header.clone();
+        theader.format = HeaderFormat::THeader;
+        theader.int_info.clear();
+        theader.encode(b"second", &mut buf).unwrap();
+        assert_eq!(HeaderFormat::detect(&buf), Some(HeaderFormat::TTHeader));
+
+        let mut buf = buf.freeze();
+        assert_eq!(
+            FrameHeader::decode(&mut buf).unwrap(),
+            (header.clone(), Bytes::from_static(b"first"))
+        );
+        assert_eq!(HeaderFormat::detect(&buf), Some(HeaderFormat::THeader));
+        assert_eq!(
+            FrameHeader::decode(&mut buf).unwrap(),
+            (theader, Bytes::from_static(b"second"))
+        );
+        assert!(buf.is_empty());
+
+        let mut buf = BytesMut::new();
+        FrameHeader::new(HeaderFormat::TTHeader, ProtocolId::Binary)
+            .encode(b"m", &mut buf)
+            .unwrap();
+        assert_eq!(
+            &buf[..],
+            [
+                0, 0, 0, 15, // length
+                0x10, 0, 0, 0, // magic and flags
+                0, 0, 0, 0, // seq id
+                0, 1, // header size
+                0, 0, 0, 0, // protocol, transforms and padding
+                b'm',
+            ]
+        );
+    }
+
+    #[test]
+    fn test_frame_header_errors() {
+        let mut buf = BytesMut::new();
+        let mut header = FrameHeader::new(HeaderFormat::THeader, ProtocolId::Binary);
+        header.int_info.insert(1, "a".into());
+        assert!(header.encode(b"", &mut buf).is_err());
+        header.format = HeaderFormat::TTHeader;
+        header.info.insert("key".into(), "x".repeat(70_000).into());
+        assert!(header.encode(b"", &mut buf).is_err());
+        assert!(buf.is_empty());
+
+        FrameHeader::new(HeaderFormat::TTHeader, ProtocolId::Binary)
+            .encode(b"payload", &mut buf)
+            .unwrap();
+        let frame = buf.freeze();
+        assert!(FrameHeader::decode(&mut frame.slice(..frame.len() - 1)).is_err());
+
+        let mut framed = BytesMut::new();
+        // a framed binary call
+        framed.put_u32(12);
+        framed.put_u32(0x8001_0001);
+        framed.put_u32(0);
+        framed.put_i32(1);
+        assert_eq!(HeaderFormat::detect(&framed), None);
+        assert!(
+            FrameHeader::decode(&mut framed.freeze())
+                .unwrap_err()
+                .to_string()
+                .contains("not a header frame")
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use bytes::Bytes;
+
+use super::{
+    ThriftException, allocation::AllocationTracker, budget::BudgetTracker, depth::DepthTracker,
+    error::ProtocolExceptionKind, new_protocol_exception,
+};
+
+/// A position of an input protocol saved by [`TInputProtocol::mark`], to
+/// rewind to with [`TInputProtocol::reset_to_mark`].
+///
+/// A mark is cheap: it holds a handle on the unread input, not a copy of it,
+/// and the state the protocol needs to read from there again, such as the
+/// field ids of the compact protocol.
+///
+/// [`TInputProtocol::mark`]: super::TInputProtocol::mark
+/// [`TInputProtocol::reset_to_mark`]: super::TInputProtocol::reset_to_mark
+#[derive(Clone, Debug, Default)]
+pub struct InputMark {
+    pub(crate) input: Bytes,
+    pub(crate) budget: Option<BudgetTracker>,
+    pub(crate) depth: Option<DepthTracker>,
+    pub(crate) allocation: Option<AllocationTracker>,
+    // whether a `TTypeRecovery` dropped the rest of the input
+    pub(crate) recovery_dropped: bool,
+    // the compact protocols' field ids and pending bool
+    pub(crate) last_read_field_id: i16,
+    pub(crate) read_field_id_stack: Vec<i16>,
+    pub(crate) pending_read_bool_value: Option<bool>,
+    pub(crate) last_read_field_header_len: usize,
+}
+
+pub(crate) fn mark_not_implemented() -> ThriftException {
+    new_protocol_exception(
+        ProtocolExceptionKind::NotImplemented,
+        "only the input read from `Bytes` can be marked",
+    )
+}
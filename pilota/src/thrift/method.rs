@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+/// The value of a method option, typed after the text of its annotation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptionValue {
+    Bool(bool),
+    Int(i64),
+    Str(&'static str),
+}
+
+impl OptionValue {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// The annotations of a service method, such as
+/// `(timeout.ms = "500", retry.max = "2")`.
+///
+/// Services with annotated methods get a generated
+/// `{SERVICE}_METHOD_OPTIONS: &[MethodOptions]` next to their trait.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MethodOptions {
+    /// The name of the method in the IDL.
+    pub method: &'static str,
+    pub options: &'static [(&'static str, OptionValue)],
+}
+
+impl MethodOptions {
+    /// Returns the options of `method` among `all`.
+    pub fn find(all: &'static [MethodOptions], method: &str) -> Option<&'static MethodOptions> {
+        all.iter().find(|m| m.method == method)
+    }
+
+    pub fn get(&self, key: &str) -> Option<OptionValue> {
+        self.options
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| *v)
+    }
+
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key)?.as_bool()
+    }
+
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get(key)?.as_i64()
+    }
+
+    pub fn get_str(&self, key: &str) -> Option<&'static str> {
+        self.get(key)?.as_str()
+    }
+
+    /// The `timeout.ms` option.
+    pub fn timeout(&self) -> Option<Duration> {
+        let ms = self.get_i64("timeout.ms")?;
+        Some(Duration::from_millis(ms.try_into().ok()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{MethodOptions, OptionValue};
+
+    static OPTIONS: &[MethodOptions] = &[MethodOptions {
+        method: "ping",
+        options: &[
+            ("timeout.ms", OptionValue::Int(500)),
+            ("idempotent", OptionValue::Bool(true)),
+            ("api.get", OptionValue::Str("/ping")),
+        ],
+    }];
+
+    #[test]
+    fn test_method_options() {
+        let ping = MethodOptions::find(OPTIONS, "ping").unwrap();
+        assert_eq!(ping.timeout(), Some(Duration::from_millis(500)));
+        assert_eq!(ping.get_bool("idempotent"), Some(true));
+        assert_eq!(ping.get_str("api.get"), Some("/ping"));
+        assert_eq!(ping.get_i64("api.get"), None);
+        assert_eq!(ping.get("retry.max"), None);
+        assert!(MethodOptions::find(OPTIONS, "pong").is_none());
+    }
+}
@@ -1,11 +1,61 @@
+/// Panics if `len` bytes from `index` overrun `buf`, the slice the unchecked
+/// accesses of the unsafe protocols go through. Only compiled in debug
+/// builds or with the `checked` feature, so a length computed wrong by the
+/// generated code panics instead of silently corrupting memory.
+#[cfg(not(feature = "safe-only"))]
+macro_rules! check_bounds {
+    ($buf:expr, $index:expr, $len:expr) => {
+        if cfg!(any(debug_assertions, feature = "checked")) {
+            let (index, len, capacity) = ($index, $len, $buf.len());
+            assert!(
+                index.checked_add(len).is_some_and(|end| end <= capacity),
+                "out of bounds access of {len} bytes at {index} in a buffer of {capacity} bytes"
+            );
+        }
+    };
+}
+
+mod allocation;
+pub mod auto;
 pub mod binary;
 pub mod binary_le;
+#[cfg(not(feature = "safe-only"))]
 pub mod binary_unsafe;
+pub mod budget;
+pub mod cached_size;
+mod capture;
+pub mod checksum;
+#[cfg(feature = "codec")]
+pub mod codec;
+pub mod coerce;
 pub mod compact;
+#[cfg(not(feature = "safe-only"))]
+pub mod compact_unsafe;
+#[cfg(feature = "zstd")]
+pub mod compress;
+#[cfg(test)]
+mod conformance;
+pub mod counting;
+pub mod debug;
+mod depth;
+pub mod duplicate;
 pub mod error;
+pub mod expiry;
+pub mod frame;
+pub mod hash;
+pub mod header;
+pub mod mark;
+pub mod method;
+pub mod multiplexed;
+pub mod negotiate;
+pub mod reader;
+pub mod recovery;
 pub mod rw_ext;
+pub mod simple_json;
+pub mod staging;
 pub mod unknown;
 pub mod varint_ext;
+pub mod writer;
 
 use std::{
     collections::{BTreeMap, BTreeSet},
@@ -18,7 +68,18 @@ use bytes::{Buf, BufMut, Bytes};
 pub use error::*;
 use faststr::FastStr;
 
-pub use self::{binary::TAsyncBinaryProtocol, compact::TAsyncCompactProtocol};
+pub use self::{
+    binary::TAsyncBinaryProtocol,
+    budget::Budget,
+    compact::{TAsyncCompactProtocol, TCompactInputProtocol, TCompactOutputProtocol},
+    debug::{TDebugProtocol, debug_message},
+    frame::{FrameInfo, FrameScanner},
+    hash::{digest_message, hash_message, hash_message_with},
+    mark::InputMark,
+    method::{MethodOptions, OptionValue},
+    multiplexed::{TMultiplexedDispatcher, TMultiplexedOutputProtocol},
+    simple_json::TSimpleJSONProtocol,
+};
 use crate::{AHashMap, AHashSet, assert_remaining, thrift::rw_ext::IOError};
 
 const MAXIMUM_SKIP_DEPTH: i8 = 64;
@@ -34,6 +95,22 @@ const ZERO_COPY_THRESHOLD: usize = 4 * 1024;
 
 pub static VOID_IDENT: TStructIdentifier = TStructIdentifier { name: "void" };
 
+/// Bit pattern of the quiet NaN every NaN is rewritten to by
+/// [`canonicalize_nan`].
+pub const CANONICAL_NAN_BITS: u64 = 0x7ff8_0000_0000_0000;
+
+/// Maps every NaN to the single NaN described by [`CANONICAL_NAN_BITS`], so
+/// that equal messages encode to equal bytes regardless of which NaN payload
+/// the producer happened to carry. Other values are returned unchanged.
+#[inline]
+pub fn canonicalize_nan(d: f64) -> f64 {
+    if d.is_nan() {
+        f64::from_bits(CANONICAL_NAN_BITS)
+    } else {
+        d
+    }
+}
+
 pub trait Message: Sized + Send {
     fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException>;
 
@@ -44,6 +121,14 @@ pub trait Message: Sized + Send {
     ) -> impl Future<Output = Result<Self, ThriftException>> + Send;
 
     fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize;
+
+    /// Decodes the binary protocol `bytes`, copying the binaries and strings
+    /// out of them when they are smaller than [`auto::threshold`] and sharing
+    /// them otherwise.
+    #[inline]
+    fn decode_auto(bytes: Bytes) -> Result<Self, ThriftException> {
+        auto::decode(bytes)
+    }
 }
 
 impl<M: Message> Message for Box<M> {
@@ -106,12 +191,45 @@ pub trait TInputProtocol: TLengthProtocol {
     fn read_field_begin(&mut self) -> Result<TFieldIdentifier, ThriftException>;
     /// Read the end of a Thrift struct field.
     fn read_field_end(&mut self) -> Result<(), ThriftException>;
+    /// Whether the generated decode fails on a struct field appearing twice
+    /// instead of keeping its last value, see [`duplicate`].
+    fn rejects_duplicate_fields(&self) -> bool {
+        false
+    }
+    /// Called by the generated decode with the fields of `struct_name` it
+    /// skips, not knowing their id or, with `type_mismatch`, their type.
+    fn on_unknown_field(
+        &self,
+        struct_name: &'static str,
+        field: &TFieldIdentifier,
+        type_mismatch: bool,
+    ) {
+        let _ = (struct_name, field, type_mismatch);
+    }
+    /// Peek at the beginning of the next Thrift struct field without reading
+    /// it, returning what `read_field_begin` would, as when the field decides
+    /// how the value is decoded.
+    ///
+    /// Fails with [`ProtocolExceptionKind::NotImplemented`] for the protocols
+    /// which can't look ahead.
+    fn peek_field_begin(&mut self) -> Result<TFieldIdentifier, ThriftException> {
+        Err(new_protocol_exception(
+            ProtocolExceptionKind::NotImplemented,
+            "cannot peek at the next field",
+        ))
+    }
     /// Read a bool.
     fn read_bool(&mut self) -> Result<bool, ThriftException>;
     /// Read a binary.
     fn read_bytes(&mut self) -> Result<Bytes, ThriftException>;
     /// Read a uuid.
     fn read_uuid(&mut self) -> Result<[u8; 16], ThriftException>;
+    /// Read a uuid as a [`uuid::Uuid`].
+    #[cfg(feature = "uuid")]
+    #[inline]
+    fn read_uuid_typed(&mut self) -> Result<uuid::Uuid, ThriftException> {
+        self.read_uuid().map(uuid::Uuid::from_bytes)
+    }
     /// Read a word.
     fn read_i8(&mut self) -> Result<i8, ThriftException>;
     /// Read a 16-bit signed integer.
@@ -255,6 +373,41 @@ pub trait TInputProtocol: TLengthProtocol {
         Ok(len)
     }
 
+    /// Skip a value with type `field_type`, returning the bytes it takes on
+    /// the wire, as when forwarding unknown fields verbatim.
+    ///
+    /// The bytes are split off the input without copying when it is
+    /// [`Bytes`], and copied otherwise. A bool field's value being part of
+    /// its header in the compact protocol, nothing is captured for it there.
+    fn skip_capture(&mut self, field_type: TType) -> Result<Bytes, ThriftException> {
+        let begin = self.buf().chunk().as_ptr();
+        let len = self.skip_till_depth(field_type, MAXIMUM_SKIP_DEPTH)?;
+        self.get_bytes(Some(begin), len)
+    }
+
+    /// Save the current position, to rewind to with `reset_to_mark`, as when
+    /// decoding a value speculatively and trying another type on failure.
+    ///
+    /// Only the protocols reading from [`Bytes`] can be marked, the others
+    /// fail with [`ProtocolExceptionKind::NotImplemented`].
+    fn mark(&mut self) -> Result<InputMark, ThriftException> {
+        Err(mark::mark_not_implemented())
+    }
+
+    /// Rewind to the position saved by `mark`, on this protocol, whatever was
+    /// read since.
+    fn reset_to_mark(&mut self, mark: &InputMark) -> Result<(), ThriftException> {
+        let _ = mark;
+        Err(mark::mark_not_implemented())
+    }
+
+    /// Read a struct which has no fields in the IDL, skipping any fields it
+    /// carries.
+    #[inline]
+    fn read_empty_struct(&mut self) -> Result<(), ThriftException> {
+        read_empty_struct(self)
+    }
+
     // utility (DO NOT USE IN GENERATED CODE!!!!)
     //
 
@@ -263,6 +416,13 @@ pub trait TInputProtocol: TLengthProtocol {
     /// This method should **never** be used in generated code.
     fn read_byte(&mut self) -> Result<u8, ThriftException>;
 
+    /// Peek at the next unsigned byte without reading it.
+    fn peek_byte(&mut self) -> Result<u8, ThriftException> {
+        let mut byte = [0];
+        assert_remaining!(peek_buf(self.buf(), &mut byte) == 1);
+        Ok(byte[0])
+    }
+
     /// Read a Vec<u8>.
     fn read_bytes_vec(&mut self) -> Result<Vec<u8>, ThriftException>;
 
@@ -413,6 +573,12 @@ pub trait TLengthProtocolExt: TLengthProtocol + Sized {
 impl<T> TLengthProtocolExt for T where T: TLengthProtocol {}
 
 pub trait TLengthProtocol {
+    /// Whether the scalars are encoded in a fixed width and the structs
+    /// without their names, as with the binary protocols, so that the size of
+    /// a struct of scalars is the same for every value. The generated structs
+    /// then return their `ENCODED_SIZE` from `size` without the length pass.
+    const FIXED_WIDTH: bool = false;
+
     // size
 
     fn message_begin_len(&mut self, identifier: &TMessageIdentifier) -> usize;
@@ -465,6 +631,13 @@ pub trait TLengthProtocol {
 
     fn map_end_len(&mut self) -> usize;
 
+    /// Names the fields of the struct just begun as
+    /// [`TOutputProtocol::write_field_names`] does, which writes nothing.
+    #[inline]
+    fn field_names_len(&mut self, _names: &'static [(i16, &'static str)]) -> usize {
+        0
+    }
+
     /// The zero copy length used to calculate the recommended malloc length.
     fn zero_copy_len(&mut self) -> usize {
         0
@@ -472,6 +645,166 @@ pub trait TLengthProtocol {
 
     /// Resets the zero copy length counter.
     fn reset(&mut self) {}
+
+    /// The length of a struct which has no fields.
+    #[inline]
+    fn empty_struct_len(&mut self, identifier: &TStructIdentifier) -> usize {
+        self.struct_begin_len(identifier) + self.field_stop_len() + self.struct_end_len()
+    }
+}
+
+/// The generic implementation of [`TInputProtocol::read_empty_struct`].
+pub fn read_empty_struct<P: TInputProtocol + ?Sized>(
+    protocol: &mut P,
+) -> Result<(), ThriftException> {
+    protocol.read_struct_begin()?;
+    loop {
+        let field_ident = protocol.read_field_begin()?;
+        if field_ident.field_type == TType::Stop {
+            protocol.field_stop_len();
+            break;
+        }
+        protocol.field_begin_len(field_ident.field_type, field_ident.id);
+        protocol.skip(field_ident.field_type)?;
+        protocol.read_field_end()?;
+        protocol.field_end_len();
+    }
+    protocol.read_struct_end()
+}
+
+/// Copies the next bytes of `buf` to `dst` without advancing it, as many as
+/// `buf` holds up to `dst.len()`, which is at most 4, returning how many.
+pub(crate) fn peek_buf(buf: &impl Buf, dst: &mut [u8]) -> usize {
+    debug_assert!(dst.len() <= 4);
+    let chunk = buf.chunk();
+    if chunk.len() >= dst.len() {
+        dst.copy_from_slice(&chunk[..dst.len()]);
+        return dst.len();
+    }
+    // the chunks not being empty, one per byte is enough
+    let mut chunks = [std::io::IoSlice::new(&[]); 4];
+    let n = buf.chunks_vectored(&mut chunks);
+    let mut len = 0;
+    for chunk in &chunks[..n] {
+        let take = chunk.len().min(dst.len() - len);
+        dst[len..len + take].copy_from_slice(&chunk[..take]);
+        len += take;
+    }
+    len
+}
+
+/// Fails with [`ProtocolExceptionKind::MessageTooLarge`] when `len`, a length
+/// or element count read from the input, exceeds `max`. Every byte or element
+/// takes at least one byte of the message, so this fails before anything is
+/// allocated for a length the message could not hold.
+#[inline]
+pub(crate) fn check_message_size(len: usize, max: Option<usize>) -> Result<(), ThriftException> {
+    match max {
+        Some(max) if len > max => Err(new_protocol_exception(
+            ProtocolExceptionKind::MessageTooLarge,
+            format!("length {len} exceeds the maximum message size {max}"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Fails with [`ProtocolExceptionKind::InvalidData`] when `len`, the length of
+/// a string or binary read from the input, exceeds `max`.
+#[inline]
+pub(crate) fn check_string_size(len: usize, max: Option<usize>) -> Result<(), ThriftException> {
+    match max {
+        Some(max) if len > max => Err(new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            format!("string or binary length {len} exceeds the maximum length {max}"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the `size` read for a container whose elements take at least
+/// `min_len` bytes each: against `max`, failing with
+/// [`ProtocolExceptionKind::SizeLimit`], and against the `remaining` bytes of
+/// the input when they are known, failing with
+/// [`ProtocolExceptionKind::InvalidData`], so that a corrupt size can't make
+/// the decoder reserve room for more elements than the input could hold.
+#[inline]
+pub(crate) fn check_container_size(
+    size: usize,
+    min_len: usize,
+    remaining: Option<usize>,
+    max: Option<usize>,
+) -> Result<(), ThriftException> {
+    if let Some(max) = max.filter(|&max| size > max) {
+        return Err(new_protocol_exception(
+            ProtocolExceptionKind::SizeLimit,
+            format!("container size {size} exceeds the maximum container size {max}"),
+        ));
+    }
+    let needed = size.saturating_mul(min_len);
+    match remaining {
+        Some(remaining) if needed > remaining => Err(new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            format!("container size {size} needs at least {needed} bytes, {remaining} remaining"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn written_len(protocol: &mut impl TOutputProtocol) -> usize {
+    protocol.written_mut().map_or(0, |written| written.len())
+}
+
+/// Writes the entries of a map or set, then sorts them in place by the bytes
+/// of their keys, for the deterministic protocols.
+fn write_sorted<P: TOutputProtocol, I: IntoIterator>(
+    protocol: &mut P,
+    entries: I,
+    encode_key: impl Fn(&mut P, &I::Item) -> Result<(), ThriftException>,
+    encode_value: impl Fn(&mut P, &I::Item) -> Result<(), ThriftException>,
+) -> Result<(), ThriftException> {
+    if protocol.written_mut().is_none() {
+        return Err(new_protocol_exception(
+            ProtocolExceptionKind::NotImplemented,
+            "deterministic encoding needs the output to be a `BytesMut` or `LinkedBytes`",
+        ));
+    }
+    protocol.hold_written(true);
+    let start = written_len(protocol);
+    // the begin, key end and end of each entry, from `start`
+    let mut spans = Vec::new();
+    let written = entries.into_iter().try_for_each(|entry| {
+        let begin = written_len(protocol) - start;
+        encode_key(protocol, &entry)?;
+        let key_end = written_len(protocol) - start;
+        encode_value(protocol, &entry)?;
+        spans.push((begin, key_end, written_len(protocol) - start));
+        Ok(())
+    });
+    let sorted = written.map(|()| sort_written(protocol, start, spans));
+    protocol.hold_written(false);
+    sorted
+}
+
+fn sort_written(
+    protocol: &mut impl TOutputProtocol,
+    start: usize,
+    mut spans: Vec<(usize, usize, usize)>,
+) {
+    if spans.len() < 2 {
+        return;
+    }
+
+    let Some(written) = protocol.written_mut() else {
+        return;
+    };
+    let written = &mut written[start..];
+    let unsorted = written.to_vec();
+    spans.sort_by(|a, b| unsorted[a.0..a.1].cmp(&unsorted[b.0..b.1]));
+    let mut at = 0;
+    for (begin, _, end) in spans {
+        written[at..at + end - begin].copy_from_slice(&unsorted[begin..end]);
+        at += end - begin;
+    }
 }
 
 macro_rules! write_field {
@@ -510,8 +843,12 @@ macro_rules! write_set_field {
                     element_type: el_ttype,
                     size: els.len(),
                 })?;
-                for el in els {
-                    encode(self, el)?;
+                if self.is_deterministic() {
+                    write_sorted(self, els, |p, el| encode(p, el), |_, _| Ok(()))?;
+                } else {
+                    for el in els {
+                        encode(self, el)?;
+                    }
                 }
                 self.write_set_end()
             }
@@ -544,9 +881,13 @@ macro_rules! write_map_field {
                     value_type: val_ttype,
                     size: els.len(),
                 })?;
-                for (k, v) in els {
-                    key_encode(self, k)?;
-                    val_encode(self, v)?;
+                if self.is_deterministic() {
+                    write_sorted(self, els, |p, (k, _)| key_encode(p, k), |p, (_, v)| val_encode(p, v))?;
+                } else {
+                    for (k, v) in els {
+                        key_encode(self, k)?;
+                        val_encode(self, v)?;
+                    }
                 }
                 self.write_map_end()
             }
@@ -666,6 +1007,12 @@ pub trait TOutputProtocol: TLengthProtocol {
     fn write_bytes_without_len(&mut self, b: Bytes) -> Result<(), ThriftException>;
     /// Write a uuid.
     fn write_uuid(&mut self, u: [u8; 16]) -> Result<(), ThriftException>;
+    /// Write a [`uuid::Uuid`].
+    #[cfg(feature = "uuid")]
+    #[inline]
+    fn write_uuid_typed(&mut self, u: uuid::Uuid) -> Result<(), ThriftException> {
+        self.write_uuid(u.into_bytes())
+    }
     /// Write a Vec<u8>.
     fn write_bytes_vec(&mut self, b: &[u8]) -> Result<(), ThriftException>;
     /// Write a byte.
@@ -699,6 +1046,47 @@ pub trait TOutputProtocol: TLengthProtocol {
     /// Flush buffered bytes to the underlying transport.
     fn flush(&mut self) -> Result<(), ThriftException>;
 
+    /// Write a struct which has no fields.
+    #[inline]
+    fn write_empty_struct(
+        &mut self,
+        identifier: &TStructIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.write_struct_begin(identifier)?;
+        self.write_field_stop()?;
+        self.write_struct_end()
+    }
+
+    /// Names the fields of the struct just begun by their ids, for the
+    /// protocols writing names instead of ids, such as
+    /// [`TSimpleJSONProtocol`](simple_json::TSimpleJSONProtocol). The structs
+    /// generated with `with_field_names` call it, the other protocols ignore
+    /// it.
+    #[inline]
+    fn write_field_names(&mut self, _names: &'static [(i16, &'static str)]) {}
+
+    /// Whether the entries of maps and sets are written sorted by the bytes
+    /// of their keys, see the `set_deterministic` of the protocols.
+    #[inline]
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    /// The bytes written since the output last became discontiguous, which
+    /// the deterministic protocols sort the entries of maps and sets in.
+    #[doc(hidden)]
+    #[inline]
+    fn written_mut(&mut self) -> Option<&mut [u8]> {
+        None
+    }
+
+    /// Keeps the bytes from [`TOutputProtocol::written_mut`] in the output
+    /// until as many calls release them as held them, while the entries of a
+    /// map or set are sorted.
+    #[doc(hidden)]
+    #[inline]
+    fn hold_written(&mut self, _hold: bool) {}
+
     #[doc(hidden)]
     fn buf_mut(&mut self) -> &mut Self::BufMut;
 }
@@ -728,6 +1116,23 @@ pub trait TAsyncInputProtocol: Send {
     /// Read the end of a Thrift struct field.
     fn read_field_end(&mut self) -> impl Future<Output = Result<(), ThriftException>> + Send;
 
+    /// Whether the generated decode fails on a struct field appearing twice
+    /// instead of keeping its last value, see [`duplicate`].
+    fn rejects_duplicate_fields(&self) -> bool {
+        false
+    }
+
+    /// Called by the generated decode with the fields of `struct_name` it
+    /// skips, not knowing their id or, with `type_mismatch`, their type.
+    fn on_unknown_field(
+        &self,
+        struct_name: &'static str,
+        field: &TFieldIdentifier,
+        type_mismatch: bool,
+    ) {
+        let _ = (struct_name, field, type_mismatch);
+    }
+
     /// Read a bool.
     fn read_bool(&mut self) -> impl Future<Output = Result<bool, ThriftException>> + Send;
 
@@ -740,6 +1145,14 @@ pub trait TAsyncInputProtocol: Send {
     /// Read a uuid.
     fn read_uuid(&mut self) -> impl Future<Output = Result<[u8; 16], ThriftException>> + Send;
 
+    /// Read a uuid as a [`uuid::Uuid`].
+    #[cfg(feature = "uuid")]
+    fn read_uuid_typed(
+        &mut self,
+    ) -> impl Future<Output = Result<uuid::Uuid, ThriftException>> + Send {
+        async move { self.read_uuid().await.map(uuid::Uuid::from_bytes) }
+    }
+
     /// Read a string.
     fn read_string(&mut self) -> impl Future<Output = Result<String, ThriftException>> + Send;
 
@@ -820,7 +1233,8 @@ pub trait TAsyncInputProtocol: Send {
             TType::I32 => self.read_i32().await.map(|_| ()),
             TType::I64 => self.read_i64().await.map(|_| ()),
             TType::Double => self.read_double().await.map(|_| ()),
-            TType::Binary => self.read_string().await.map(|_| ()),
+            TType::Binary => self.read_bytes_vec().await.map(|_| ()),
+            TType::Uuid => self.read_uuid().await.map(|_| ()),
             TType::Struct => {
                 self.read_struct_begin().await?;
                 loop {
@@ -865,6 +1279,39 @@ pub trait TAsyncInputProtocol: Send {
             )),
         }
     }
+
+    /// Skip a value with type `field_type`, returning the bytes it takes on
+    /// the wire, see [`TInputProtocol::skip_capture`].
+    ///
+    /// Not supported unless the protocol provides it.
+    fn skip_capture(
+        &mut self,
+        field_type: TType,
+    ) -> impl Future<Output = Result<Bytes, ThriftException>> + Send {
+        async move {
+            Err(new_protocol_exception(
+                ProtocolExceptionKind::NotImplemented,
+                format!("cannot capture a skipped {field_type:?}"),
+            ))
+        }
+    }
+
+    /// Read a struct which has no fields in the IDL, skipping any fields it
+    /// carries.
+    fn read_empty_struct(&mut self) -> impl Future<Output = Result<(), ThriftException>> + Send {
+        async move {
+            self.read_struct_begin().await?;
+            loop {
+                let field_ident = self.read_field_begin().await?;
+                if field_ident.field_type == TType::Stop {
+                    break;
+                }
+                self.skip(field_ident.field_type).await?;
+                self.read_field_end().await?;
+            }
+            self.read_struct_end().await
+        }
+    }
 }
 
 // Thrift struct identifier.
@@ -942,6 +1389,7 @@ impl TryFrom<u8> for TType {
     }
 }
 
+#[cfg(not(feature = "safe-only"))]
 const BINARY_BASIC_TYPE_FIXED_SIZE: [usize; 17] = [
     0,  // TType::Stop
     0,  // TType::Void
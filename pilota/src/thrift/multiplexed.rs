@@ -0,0 +1,643 @@
+//! Several services sharing a connection, as with the `TMultiplexedProtocol`
+//! of Apache Thrift.
+//!
+//! The clients name the service they call with a
+//! [`TMultiplexedOutputProtocol`], which prefixes the names of their calls
+//! with `"ServiceName:"`. The server reads the beginning of each message with
+//! a [`TMultiplexedDispatcher`], which strips the prefix and returns the
+//! handler registered for the service, that then reads the rest of the call
+//! with the same input protocol. The replies are written as they are, without
+//! a prefix.
+//!
+//! ```
+//! use pilota::thrift::{
+//!     TInputProtocol, TMessageIdentifier, TMessageType, TOutputProtocol,
+//!     binary::TBinaryProtocol,
+//!     multiplexed::{TMultiplexedDispatcher, TMultiplexedOutputProtocol},
+//! };
+//!
+//! let mut buf = pilota::BytesMut::new();
+//! let mut client =
+//!     TMultiplexedOutputProtocol::new(TBinaryProtocol::new(&mut buf, false), "Calculator");
+//! client
+//!     .write_message_begin(&TMessageIdentifier::new("add".into(), TMessageType::Call, 1))
+//!     .unwrap();
+//!
+//! let mut dispatcher = TMultiplexedDispatcher::new();
+//! dispatcher.register("Calculator", "the calculator");
+//! dispatcher.register("Weather", "the weather");
+//! let (handler, identifier) = dispatcher
+//!     .dispatch(&mut TBinaryProtocol::new(buf.freeze(), false))
+//!     .unwrap();
+//! assert_eq!(*handler, "the calculator");
+//! assert_eq!(identifier.name, "add");
+//! ```
+
+use bytes::Bytes;
+use faststr::FastStr;
+
+use super::{
+    ApplicationExceptionKind, TAsyncInputProtocol, TInputProtocol, TLengthProtocol,
+    TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType, TOutputProtocol,
+    TSetIdentifier, TStructIdentifier, TType, ThriftException, new_application_exception,
+};
+use crate::AHashMap;
+
+/// The separator of the service name and the method name.
+pub const SEPARATOR: char = ':';
+
+/// Splits the name of a multiplexed message into the name of the service and
+/// the name of the method, or returns `None` if it has no service name.
+pub fn split_name(name: &FastStr) -> Option<(FastStr, FastStr)> {
+    let (service, method) = name.split_once(SEPARATOR)?;
+    Some((name.slice_ref(service), name.slice_ref(method)))
+}
+
+/// An output protocol prefixing the names of the calls it writes with the
+/// name of a service, see the [module](self) docs.
+pub struct TMultiplexedOutputProtocol<P> {
+    inner: P,
+    service: FastStr,
+}
+
+impl<P> TMultiplexedOutputProtocol<P> {
+    pub fn new(inner: P, service: impl Into<FastStr>) -> Self {
+        Self {
+            inner,
+            service: service.into(),
+        }
+    }
+
+    pub fn service(&self) -> &FastStr {
+        &self.service
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// The identifier written for `identifier`, whose name is prefixed if it
+    /// begins a call.
+    fn prefixed(&self, identifier: &TMessageIdentifier) -> Option<TMessageIdentifier> {
+        matches!(
+            identifier.message_type,
+            TMessageType::Call | TMessageType::OneWay
+        )
+        .then(|| {
+            TMessageIdentifier::new(
+                format!("{}{SEPARATOR}{}", self.service, identifier.name).into(),
+                identifier.message_type,
+                identifier.sequence_number,
+            )
+        })
+    }
+}
+
+impl<P: TLengthProtocol> TLengthProtocol for TMultiplexedOutputProtocol<P> {
+    const FIXED_WIDTH: bool = P::FIXED_WIDTH;
+
+    #[inline]
+    fn message_begin_len(&mut self, identifier: &TMessageIdentifier) -> usize {
+        match self.prefixed(identifier) {
+            Some(prefixed) => self.inner.message_begin_len(&prefixed),
+            None => self.inner.message_begin_len(identifier),
+        }
+    }
+
+    #[inline]
+    fn message_end_len(&mut self) -> usize {
+        self.inner.message_end_len()
+    }
+
+    #[inline]
+    fn struct_begin_len(&mut self, identifier: &TStructIdentifier) -> usize {
+        self.inner.struct_begin_len(identifier)
+    }
+
+    #[inline]
+    fn struct_end_len(&mut self) -> usize {
+        self.inner.struct_end_len()
+    }
+
+    #[inline]
+    fn field_begin_len(&mut self, field_type: TType, id: Option<i16>) -> usize {
+        self.inner.field_begin_len(field_type, id)
+    }
+
+    #[inline]
+    fn field_end_len(&mut self) -> usize {
+        self.inner.field_end_len()
+    }
+
+    #[inline]
+    fn field_stop_len(&mut self) -> usize {
+        self.inner.field_stop_len()
+    }
+
+    #[inline]
+    fn bool_len(&mut self, b: bool) -> usize {
+        self.inner.bool_len(b)
+    }
+
+    #[inline]
+    fn bytes_len(&mut self, b: &[u8]) -> usize {
+        self.inner.bytes_len(b)
+    }
+
+    #[inline]
+    fn bytes_vec_len(&mut self, b: &[u8]) -> usize {
+        self.inner.bytes_vec_len(b)
+    }
+
+    #[inline]
+    fn byte_len(&mut self, b: u8) -> usize {
+        self.inner.byte_len(b)
+    }
+
+    #[inline]
+    fn uuid_len(&mut self, u: [u8; 16]) -> usize {
+        self.inner.uuid_len(u)
+    }
+
+    #[inline]
+    fn i8_len(&mut self, i: i8) -> usize {
+        self.inner.i8_len(i)
+    }
+
+    #[inline]
+    fn i16_len(&mut self, i: i16) -> usize {
+        self.inner.i16_len(i)
+    }
+
+    #[inline]
+    fn i32_len(&mut self, i: i32) -> usize {
+        self.inner.i32_len(i)
+    }
+
+    #[inline]
+    fn i64_len(&mut self, i: i64) -> usize {
+        self.inner.i64_len(i)
+    }
+
+    #[inline]
+    fn double_len(&mut self, d: f64) -> usize {
+        self.inner.double_len(d)
+    }
+
+    #[inline]
+    fn string_len(&mut self, s: &str) -> usize {
+        self.inner.string_len(s)
+    }
+
+    #[inline]
+    fn faststr_len(&mut self, s: &FastStr) -> usize {
+        self.inner.faststr_len(s)
+    }
+
+    #[inline]
+    fn list_begin_len(&mut self, identifier: TListIdentifier) -> usize {
+        self.inner.list_begin_len(identifier)
+    }
+
+    #[inline]
+    fn list_end_len(&mut self) -> usize {
+        self.inner.list_end_len()
+    }
+
+    #[inline]
+    fn set_begin_len(&mut self, identifier: TSetIdentifier) -> usize {
+        self.inner.set_begin_len(identifier)
+    }
+
+    #[inline]
+    fn set_end_len(&mut self) -> usize {
+        self.inner.set_end_len()
+    }
+
+    #[inline]
+    fn map_begin_len(&mut self, identifier: TMapIdentifier) -> usize {
+        self.inner.map_begin_len(identifier)
+    }
+
+    #[inline]
+    fn map_end_len(&mut self) -> usize {
+        self.inner.map_end_len()
+    }
+
+    #[inline]
+    fn field_names_len(&mut self, names: &'static [(i16, &'static str)]) -> usize {
+        self.inner.field_names_len(names)
+    }
+
+    #[inline]
+    fn zero_copy_len(&mut self) -> usize {
+        self.inner.zero_copy_len()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset()
+    }
+}
+
+impl<P: TOutputProtocol> TOutputProtocol for TMultiplexedOutputProtocol<P> {
+    type BufMut = P::BufMut;
+
+    #[inline]
+    fn write_message_begin(
+        &mut self,
+        identifier: &TMessageIdentifier,
+    ) -> Result<(), ThriftException> {
+        match self.prefixed(identifier) {
+            Some(prefixed) => self.inner.write_message_begin(&prefixed),
+            None => self.inner.write_message_begin(identifier),
+        }
+    }
+
+    #[inline]
+    fn write_message_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_message_end()
+    }
+
+    #[inline]
+    fn write_struct_begin(
+        &mut self,
+        identifier: &TStructIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.inner.write_struct_begin(identifier)
+    }
+
+    #[inline]
+    fn write_struct_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_struct_end()
+    }
+
+    #[inline]
+    fn write_field_names(&mut self, names: &'static [(i16, &'static str)]) {
+        self.inner.write_field_names(names)
+    }
+
+    #[inline]
+    fn write_field_begin(&mut self, field_type: TType, id: i16) -> Result<(), ThriftException> {
+        self.inner.write_field_begin(field_type, id)
+    }
+
+    #[inline]
+    fn write_field_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_field_end()
+    }
+
+    #[inline]
+    fn write_field_stop(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_field_stop()
+    }
+
+    #[inline]
+    fn write_bool(&mut self, b: bool) -> Result<(), ThriftException> {
+        self.inner.write_bool(b)
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        self.inner.write_bytes(b)
+    }
+
+    #[inline]
+    fn write_bytes_without_len(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        self.inner.write_bytes_without_len(b)
+    }
+
+    #[inline]
+    fn write_uuid(&mut self, u: [u8; 16]) -> Result<(), ThriftException> {
+        self.inner.write_uuid(u)
+    }
+
+    #[inline]
+    fn write_bytes_vec(&mut self, b: &[u8]) -> Result<(), ThriftException> {
+        self.inner.write_bytes_vec(b)
+    }
+
+    #[inline]
+    fn write_byte(&mut self, b: u8) -> Result<(), ThriftException> {
+        self.inner.write_byte(b)
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) -> Result<(), ThriftException> {
+        self.inner.write_i8(i)
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) -> Result<(), ThriftException> {
+        self.inner.write_i16(i)
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) -> Result<(), ThriftException> {
+        self.inner.write_i32(i)
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) -> Result<(), ThriftException> {
+        self.inner.write_i64(i)
+    }
+
+    #[inline]
+    fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
+        self.inner.write_double(d)
+    }
+
+    #[inline]
+    fn write_string(&mut self, s: &str) -> Result<(), ThriftException> {
+        self.inner.write_string(s)
+    }
+
+    #[inline]
+    fn write_faststr(&mut self, s: FastStr) -> Result<(), ThriftException> {
+        self.inner.write_faststr(s)
+    }
+
+    #[inline]
+    fn write_list_begin(&mut self, identifier: TListIdentifier) -> Result<(), ThriftException> {
+        self.inner.write_list_begin(identifier)
+    }
+
+    #[inline]
+    fn write_list_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_list_end()
+    }
+
+    #[inline]
+    fn write_set_begin(&mut self, identifier: TSetIdentifier) -> Result<(), ThriftException> {
+        self.inner.write_set_begin(identifier)
+    }
+
+    #[inline]
+    fn write_set_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_set_end()
+    }
+
+    #[inline]
+    fn write_map_begin(&mut self, identifier: TMapIdentifier) -> Result<(), ThriftException> {
+        self.inner.write_map_begin(identifier)
+    }
+
+    #[inline]
+    fn write_map_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_map_end()
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), ThriftException> {
+        self.inner.flush()
+    }
+
+    #[inline]
+    fn is_deterministic(&self) -> bool {
+        self.inner.is_deterministic()
+    }
+
+    #[inline]
+    fn written_mut(&mut self) -> Option<&mut [u8]> {
+        self.inner.written_mut()
+    }
+
+    #[inline]
+    fn hold_written(&mut self, hold: bool) {
+        self.inner.hold_written(hold)
+    }
+
+    #[inline]
+    fn buf_mut(&mut self) -> &mut Self::BufMut {
+        self.inner.buf_mut()
+    }
+}
+
+/// The handlers of the services sharing a connection, picked by the service
+/// names of the calls, see the [module](self) docs.
+///
+/// The handlers are of any type, such as the enum of the services or boxed
+/// closures, and read the rest of the calls themselves.
+pub struct TMultiplexedDispatcher<H> {
+    services: AHashMap<FastStr, H>,
+    default: Option<H>,
+}
+
+impl<H> Default for TMultiplexedDispatcher<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H> TMultiplexedDispatcher<H> {
+    pub fn new() -> Self {
+        Self {
+            services: AHashMap::default(),
+            default: None,
+        }
+    }
+
+    /// Registers the handler of `service`, returning the one it replaces.
+    pub fn register(&mut self, service: impl Into<FastStr>, handler: H) -> Option<H> {
+        self.services.insert(service.into(), handler)
+    }
+
+    /// Sets the handler of the calls without a service name, from the clients
+    /// which are not multiplexed.
+    pub fn set_default(&mut self, handler: H) {
+        self.default = Some(handler);
+    }
+
+    /// Returns the handler of the call `identifier` begins, and the
+    /// identifier without the service name.
+    ///
+    /// Fails with an [`ApplicationExceptionKind::UNKNOWN_METHOD`] exception,
+    /// to be replied to the client, when no handler is registered for the
+    /// call.
+    pub fn route(
+        &self,
+        identifier: TMessageIdentifier,
+    ) -> Result<(&H, TMessageIdentifier), ThriftException> {
+        let Some((service, method)) = split_name(&identifier.name) else {
+            return match &self.default {
+                Some(handler) => Ok((handler, identifier)),
+                None => Err(new_application_exception(
+                    ApplicationExceptionKind::UNKNOWN_METHOD,
+                    format!(
+                        "no service name in the message name {}, the client is not multiplexed",
+                        identifier.name
+                    ),
+                )),
+            };
+        };
+        match self.services.get(&service) {
+            Some(handler) => Ok((
+                handler,
+                TMessageIdentifier::new(
+                    method,
+                    identifier.message_type,
+                    identifier.sequence_number,
+                ),
+            )),
+            None => Err(new_application_exception(
+                ApplicationExceptionKind::UNKNOWN_METHOD,
+                format!("unknown service {service}"),
+            )),
+        }
+    }
+
+    /// Reads the beginning of a message from `protocol` and
+    /// [routes](Self::route) it.
+    pub fn dispatch<T: TInputProtocol>(
+        &self,
+        protocol: &mut T,
+    ) -> Result<(&H, TMessageIdentifier), ThriftException> {
+        self.route(protocol.read_message_begin()?)
+    }
+
+    /// The async [`dispatch`](Self::dispatch).
+    pub async fn dispatch_async<T: TAsyncInputProtocol>(
+        &self,
+        protocol: &mut T,
+    ) -> Result<(&H, TMessageIdentifier), ThriftException> {
+        self.route(protocol.read_message_begin().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use bytes::BytesMut;
+
+    use super::{TMultiplexedDispatcher, TMultiplexedOutputProtocol, split_name};
+    use crate::thrift::{
+        ApplicationExceptionKind, TInputProtocol, TLengthProtocol, TMessageIdentifier,
+        TMessageType, TOutputProtocol, ThriftException,
+        binary::{TAsyncBinaryProtocol, TBinaryProtocol},
+        compact::TCompactOutputProtocol,
+    };
+
+    fn ident(name: &'static str, message_type: TMessageType) -> TMessageIdentifier {
+        TMessageIdentifier::new(name.into(), message_type, 7)
+    }
+
+    fn call(service: &'static str, identifier: TMessageIdentifier) -> BytesMut {
+        let mut buf = BytesMut::new();
+        let mut protocol =
+            TMultiplexedOutputProtocol::new(TBinaryProtocol::new(&mut buf, false), service);
+        protocol.write_message_begin(&identifier).unwrap();
+        protocol.write_i32(42).unwrap();
+        protocol.write_message_end().unwrap();
+        buf
+    }
+
+    fn dispatcher() -> TMultiplexedDispatcher<&'static str> {
+        let mut dispatcher = TMultiplexedDispatcher::new();
+        dispatcher.register("Calculator", "calculator");
+        dispatcher.register("Weather", "weather");
+        dispatcher
+    }
+
+    fn unknown_method(result: Result<(&&str, TMessageIdentifier), ThriftException>) -> bool {
+        matches!(
+            result,
+            Err(ThriftException::Application(e)) if e.kind() == ApplicationExceptionKind::UNKNOWN_METHOD
+        )
+    }
+
+    #[test]
+    fn test_output() {
+        let buf = call("Calculator", ident("add", TMessageType::Call));
+        let mut protocol = TBinaryProtocol::new(buf.clone().freeze(), false);
+        let identifier = protocol.read_message_begin().unwrap();
+        assert_eq!(identifier.name, "Calculator:add");
+        assert_eq!(identifier.sequence_number, 7);
+        assert_eq!(protocol.read_i32().unwrap(), 42);
+
+        let identifier = ident("add", TMessageType::Call);
+        let mut protocol =
+            TMultiplexedOutputProtocol::new(TBinaryProtocol::new((), false), "Calculator");
+        assert_eq!(
+            protocol.message_begin_len(&identifier)
+                + protocol.i32_len(42)
+                + protocol.message_end_len(),
+            buf.len()
+        );
+
+        // replies are written as they are
+        for message_type in [TMessageType::Reply, TMessageType::Exception] {
+            let mut buf = BytesMut::new();
+            TMultiplexedOutputProtocol::new(
+                TCompactOutputProtocol::new(&mut buf, false),
+                "Calculator",
+            )
+            .write_message_begin(&ident("add", message_type))
+            .unwrap();
+            let mut expected = BytesMut::new();
+            TCompactOutputProtocol::new(&mut expected, false)
+                .write_message_begin(&ident("add", message_type))
+                .unwrap();
+            assert_eq!(buf, expected);
+        }
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let dispatcher = dispatcher();
+        let buf = call("Weather", ident("forecast", TMessageType::OneWay));
+        let mut protocol = TBinaryProtocol::new(buf.freeze(), false);
+        let (handler, identifier) = dispatcher.dispatch(&mut protocol).unwrap();
+        assert_eq!(*handler, "weather");
+        assert_eq!(identifier.name, "forecast");
+        assert_eq!(identifier.message_type, TMessageType::OneWay);
+        assert_eq!(identifier.sequence_number, 7);
+        assert_eq!(protocol.read_i32().unwrap(), 42);
+
+        // the async protocols read from a slice, which never waits
+        let buf = call("Calculator", ident("add", TMessageType::Call));
+        let mut protocol = TAsyncBinaryProtocol::new(&buf[..]);
+        let mut dispatch = pin!(dispatcher.dispatch_async(&mut protocol));
+        let Poll::Ready(Ok((handler, identifier))) = dispatch
+            .as_mut()
+            .poll(&mut Context::from_waker(Waker::noop()))
+        else {
+            panic!("dispatching from a slice never waits");
+        };
+        assert_eq!((*handler, &*identifier.name), ("calculator", "add"));
+    }
+
+    #[test]
+    fn test_unknown() {
+        let mut dispatcher = dispatcher();
+        assert!(unknown_method(
+            dispatcher.route(ident("Clock:now", TMessageType::Call))
+        ));
+        assert!(unknown_method(
+            dispatcher.route(ident("add", TMessageType::Call))
+        ));
+
+        // the calls of the clients which are not multiplexed go to the default
+        dispatcher.set_default("default");
+        let (handler, identifier) = dispatcher.route(ident("add", TMessageType::Call)).unwrap();
+        assert_eq!((*handler, &*identifier.name), ("default", "add"));
+        assert!(unknown_method(
+            dispatcher.route(ident("Clock:now", TMessageType::Call))
+        ));
+
+        assert_eq!(dispatcher.register("Weather", "sky"), Some("weather"));
+    }
+
+    #[test]
+    fn test_split_name() {
+        let (service, method) = split_name(&"Calculator:add".into()).unwrap();
+        assert_eq!((&*service, &*method), ("Calculator", "add"));
+        let (service, method) = split_name(&"a:b:c".into()).unwrap();
+        assert_eq!((&*service, &*method), ("a", "b:c"));
+        assert!(split_name(&"add".into()).is_none());
+    }
+}
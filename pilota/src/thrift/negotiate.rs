@@ -0,0 +1,369 @@
+//! Negotiation of the protocol used on a connection.
+//!
+//! Both ends send a [`Negotiation`] frame listing what they support, then
+//! [`Negotiation::agree`] picks the same [`Agreement`] on either side from
+//! the local and the remote frame. The frame is a 4-byte big-endian length
+//! followed by THeader info records: key-value records with the keys in
+//! [`keys`], padded with zeros to a multiple of 4 bytes, so a THeader
+//! implementation can read it as the header of an empty message.
+//!
+//! ```
+//! use pilota::thrift::negotiate::{Negotiation, ProtocolId};
+//!
+//! let client = Negotiation::new(vec![ProtocolId::Compact, ProtocolId::Binary]);
+//! let server = Negotiation::new(vec![ProtocolId::Binary]);
+//!
+//! let mut buf = pilota::BytesMut::new();
+//! client.encode(&mut buf);
+//! let received = Negotiation::decode(&mut buf.freeze()).unwrap();
+//!
+//! let agreement = server.agree(&received).unwrap();
+//! assert_eq!(agreement.protocol, ProtocolId::Binary);
+//! ```
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use super::{
+    ProtocolExceptionKind, ThriftException,
+    binary::TBinaryProtocol,
+    compact::{TCompactInputProtocol, TCompactOutputProtocol},
+    frame::{DEFAULT_MAX_FRAME_SIZE, FRAME_HEADER_LEN},
+    header::{INFO_PADDING, InfoHeaders},
+    new_protocol_exception,
+};
+
+/// The keys of the info records of a negotiation frame.
+pub mod keys {
+    /// The ids of the supported protocols in order of preference, separated
+    /// by commas.
+    pub const PROTOCOLS: &str = "pilota.protocols";
+    /// The largest frame accepted, in bytes.
+    pub const MAX_FRAME_SIZE: &str = "pilota.max_frame_size";
+    /// The ids of the supported transforms in order of preference, separated
+    /// by commas.
+    pub const TRANSFORMS: &str = "pilota.transforms";
+}
+
+/// A protocol, numbered as in THeader.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ProtocolId {
+    Binary = 0,
+    Compact = 2,
+}
+
+impl TryFrom<u8> for ProtocolId {
+    type Error = ThriftException;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Binary),
+            2 => Ok(Self::Compact),
+            _ => Err(new_protocol_exception(
+                ProtocolExceptionKind::NotImplemented,
+                format!("unknown protocol id {value}"),
+            )),
+        }
+    }
+}
+
+/// A transform of the payload, numbered as in THeader.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Transform {
+    Zlib = 1,
+    Snappy = 3,
+    Zstd = 5,
+}
+
+impl TryFrom<u8> for Transform {
+    type Error = ThriftException;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Zlib),
+            3 => Ok(Self::Snappy),
+            5 => Ok(Self::Zstd),
+            _ => Err(new_protocol_exception(
+                ProtocolExceptionKind::NotImplemented,
+                format!("unknown transform id {value}"),
+            )),
+        }
+    }
+}
+
+/// What one end of a connection supports.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Negotiation {
+    /// The supported protocols, the preferred first.
+    pub protocols: Vec<ProtocolId>,
+    /// The largest frame accepted, in bytes.
+    pub max_frame_size: u32,
+    /// The supported transforms, the preferred first.
+    pub transforms: Vec<Transform>,
+}
+
+/// The outcome of a negotiation, the same on both ends.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Agreement {
+    pub protocol: ProtocolId,
+    /// The smaller of the two max frame sizes.
+    pub max_frame_size: u32,
+    /// The transforms supported by both ends.
+    pub transforms: Vec<Transform>,
+}
+
+/// An output protocol of the agreed [`ProtocolId`].
+pub enum NegotiatedOutput<T> {
+    Binary(TBinaryProtocol<T>),
+    Compact(TCompactOutputProtocol<T>),
+}
+
+/// An input protocol of the agreed [`ProtocolId`].
+pub enum NegotiatedInput<T> {
+    Binary(TBinaryProtocol<T>),
+    Compact(TCompactInputProtocol<T>),
+}
+
+impl Negotiation {
+    /// Supports `protocols`, without transforms, up to frames of
+    /// [`DEFAULT_MAX_FRAME_SIZE`].
+    pub fn new(protocols: Vec<ProtocolId>) -> Self {
+        Self {
+            protocols,
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE as u32,
+            transforms: Vec::new(),
+        }
+    }
+
+    pub fn with_max_frame_size(mut self, max_frame_size: u32) -> Self {
+        self.max_frame_size = max_frame_size;
+        self
+    }
+
+    pub fn with_transforms(mut self, transforms: Vec<Transform>) -> Self {
+        self.transforms = transforms;
+        self
+    }
+
+    /// Writes the negotiation frame.
+    pub fn encode(&self, buf: &mut BytesMut) {
+        let mut headers = InfoHeaders::new();
+        headers.insert(
+            keys::PROTOCOLS.into(),
+            join_ids(self.protocols.iter().map(|p| *p as u8)).into(),
+        );
+        headers.insert(
+            keys::MAX_FRAME_SIZE.into(),
+            self.max_frame_size.to_string().into(),
+        );
+        headers.insert(
+            keys::TRANSFORMS.into(),
+            join_ids(self.transforms.iter().map(|t| *t as u8)).into(),
+        );
+
+        let mut info = BytesMut::new();
+        headers.encode(&mut info);
+        info.put_bytes(
+            INFO_PADDING as u8,
+            info.len().next_multiple_of(4) - info.len(),
+        );
+
+        buf.reserve(FRAME_HEADER_LEN + info.len());
+        buf.put_u32(info.len() as u32);
+        buf.put_slice(&info);
+    }
+
+    /// Reads a negotiation frame and advances `buf` past it.
+    ///
+    /// Records with other keys are ignored. A frame without protocols fails
+    /// with [`ProtocolExceptionKind::InvalidData`].
+    pub fn decode(buf: &mut Bytes) -> Result<Self, ThriftException> {
+        if buf.len() < FRAME_HEADER_LEN {
+            return Err(truncated());
+        }
+        let size = u32::from_be_bytes(buf[..FRAME_HEADER_LEN].try_into().unwrap()) as usize;
+        if size > DEFAULT_MAX_FRAME_SIZE {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::SizeLimit,
+                format!("negotiation frame size {size} exceeds {DEFAULT_MAX_FRAME_SIZE}"),
+            ));
+        }
+        if buf.len() < FRAME_HEADER_LEN + size {
+            return Err(truncated());
+        }
+        buf.advance(FRAME_HEADER_LEN);
+        let headers = InfoHeaders::decode(&mut buf.split_to(size))?;
+
+        let mut negotiation = Self {
+            protocols: Vec::new(),
+            max_frame_size: DEFAULT_MAX_FRAME_SIZE as u32,
+            transforms: Vec::new(),
+        };
+        if let Some(value) = headers.get(keys::PROTOCOLS) {
+            negotiation.protocols = parse_ids(value);
+        }
+        if let Some(value) = headers.get(keys::MAX_FRAME_SIZE) {
+            negotiation.max_frame_size = value.parse().map_err(|_| {
+                new_protocol_exception(
+                    ProtocolExceptionKind::InvalidData,
+                    format!("invalid max frame size {value:?}"),
+                )
+            })?;
+        }
+        if let Some(value) = headers.get(keys::TRANSFORMS) {
+            negotiation.transforms = parse_ids(value);
+        }
+
+        if negotiation.protocols.is_empty() {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                "negotiation frame lists no protocols",
+            ));
+        }
+        Ok(negotiation)
+    }
+
+    /// Picks what both ends support. The order of preference of the end that
+    /// calls it wins, so only one end, usually the server, should decide and
+    /// the other one use what it receives in return.
+    ///
+    /// Fails with [`ProtocolExceptionKind::NotImplemented`] if no protocol is
+    /// supported by both.
+    pub fn agree(&self, remote: &Negotiation) -> Result<Agreement, ThriftException> {
+        let Some(protocol) = self.protocols.iter().find(|p| remote.protocols.contains(p)) else {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::NotImplemented,
+                format!(
+                    "no common protocol between {:?} and {:?}",
+                    self.protocols, remote.protocols
+                ),
+            ));
+        };
+        Ok(Agreement {
+            protocol: *protocol,
+            max_frame_size: self.max_frame_size.min(remote.max_frame_size),
+            transforms: self
+                .transforms
+                .iter()
+                .filter(|t| remote.transforms.contains(t))
+                .copied()
+                .collect(),
+        })
+    }
+}
+
+impl Agreement {
+    /// The negotiation frame to send back so the other end uses exactly this
+    /// agreement.
+    pub fn to_negotiation(&self) -> Negotiation {
+        Negotiation {
+            protocols: vec![self.protocol],
+            max_frame_size: self.max_frame_size,
+            transforms: self.transforms.clone(),
+        }
+    }
+
+    pub fn output_protocol<T>(&self, trans: T, zero_copy: bool) -> NegotiatedOutput<T> {
+        match self.protocol {
+            ProtocolId::Binary => NegotiatedOutput::Binary(TBinaryProtocol::new(trans, zero_copy)),
+            ProtocolId::Compact => {
+                NegotiatedOutput::Compact(TCompactOutputProtocol::new(trans, zero_copy))
+            }
+        }
+    }
+
+    pub fn input_protocol<T>(&self, trans: T, zero_copy: bool) -> NegotiatedInput<T> {
+        match self.protocol {
+            ProtocolId::Binary => NegotiatedInput::Binary(TBinaryProtocol::new(trans, zero_copy)),
+            ProtocolId::Compact => NegotiatedInput::Compact(TCompactInputProtocol::new(trans)),
+        }
+    }
+}
+
+fn join_ids(ids: impl Iterator<Item = u8>) -> String {
+    ids.map(|id| id.to_string()).collect::<Vec<_>>().join(",")
+}
+
+fn parse_ids<I: TryFrom<u8>>(value: &str) -> Vec<I> {
+    // ids unknown to this end are skipped, the other end may be newer
+    value
+        .split(',')
+        .filter_map(|id| I::try_from(id.parse::<u8>().ok()?).ok())
+        .collect()
+}
+
+fn truncated() -> ThriftException {
+    new_protocol_exception(
+        ProtocolExceptionKind::InvalidData,
+        "negotiation frame is truncated",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::thrift::header::{INFO_KEYVALUE, put_varint};
+
+    #[test]
+    fn test_negotiation() {
+        let client = Negotiation::new(vec![ProtocolId::Compact, ProtocolId::Binary])
+            .with_max_frame_size(1024)
+            .with_transforms(vec![Transform::Zstd, Transform::Zlib]);
+        let server = Negotiation::new(vec![ProtocolId::Binary, ProtocolId::Compact])
+            .with_transforms(vec![Transform::Zlib, Transform::Zstd]);
+
+        let mut buf = BytesMut::new();
+        client.encode(&mut buf);
+        assert_eq!((buf.len() - FRAME_HEADER_LEN) % 4, 0);
+        let mut bytes = buf.freeze();
+        assert_eq!(Negotiation::decode(&mut bytes).unwrap(), client);
+        assert!(bytes.is_empty());
+
+        let agreement = server.agree(&client).unwrap();
+        assert_eq!(agreement.protocol, ProtocolId::Binary);
+        assert_eq!(agreement.max_frame_size, 1024);
+        assert_eq!(agreement.transforms, [Transform::Zlib, Transform::Zstd]);
+
+        let reply = agreement.to_negotiation();
+        let accepted = client.agree(&reply).unwrap();
+        assert_eq!(accepted.protocol, agreement.protocol);
+        assert_eq!(accepted.max_frame_size, agreement.max_frame_size);
+        assert!(matches!(
+            agreement.output_protocol(BytesMut::new(), false),
+            NegotiatedOutput::Binary(_)
+        ));
+
+        let compact = Negotiation::new(vec![ProtocolId::Compact]);
+        assert!(compact.agree(&server).unwrap().transforms.is_empty());
+        let err = Negotiation::new(vec![ProtocolId::Binary])
+            .agree(&compact)
+            .unwrap_err();
+        assert!(err.to_string().contains("no common protocol"));
+    }
+
+    #[test]
+    fn test_negotiation_decode() {
+        // unknown keys and protocol ids are skipped
+        let mut info = BytesMut::new();
+        put_varint(&mut info, INFO_KEYVALUE);
+        put_varint(&mut info, 2);
+        for s in ["other", "x", keys::PROTOCOLS, "9,2"] {
+            put_varint(&mut info, s.len() as u32);
+            info.put_slice(s.as_bytes());
+        }
+        let mut buf = BytesMut::new();
+        buf.put_u32(info.len() as u32);
+        buf.put_slice(&info);
+        let negotiation = Negotiation::decode(&mut buf.clone().freeze()).unwrap();
+        assert_eq!(negotiation.protocols, [ProtocolId::Compact]);
+
+        let truncated = buf.split_to(buf.len() - 1).freeze();
+        assert!(Negotiation::decode(&mut truncated.clone()).is_err());
+
+        let mut empty = BytesMut::new();
+        Negotiation::new(vec![]).encode(&mut empty);
+        let err = Negotiation::decode(&mut empty.freeze()).unwrap_err();
+        assert!(err.to_string().contains("no protocols"));
+    }
+}
@@ -0,0 +1,512 @@
+//! Decoding from a [`std::io::Read`].
+//!
+//! [`IoReader`] lets the binary protocol decode straight from a blocking
+//! stream such as a file or a socket, reading only what every value needs,
+//! as [`TAsyncBinaryProtocol`](super::binary::TAsyncBinaryProtocol) does for
+//! tokio readers. Wrap the reader in a [`BufReader`](std::io::BufReader) to
+//! avoid a read call per value.
+//!
+//! ```
+//! use pilota::thrift::{TInputProtocol, binary::TBinaryProtocol, reader::IoReader};
+//!
+//! let mut protocol = TBinaryProtocol::new(IoReader::new(&[0, 0, 0, 7][..]), false);
+//! assert_eq!(protocol.read_i32().unwrap(), 7);
+//! ```
+
+use std::io::{self, Read};
+
+use bytes::Bytes;
+use faststr::FastStr;
+
+use super::{
+    ProtocolExceptionKind, TFieldIdentifier, TInputProtocol, TLengthProtocol, TListIdentifier,
+    TMapIdentifier, TMessageIdentifier, TSetIdentifier, TStructIdentifier, TType, ThriftException,
+    allocation::{self, element_size},
+    binary::{TBinaryProtocol, message_type_from_u8},
+    depth, new_protocol_exception,
+    rw_ext::string_from_utf8,
+    unknown,
+};
+
+// how much is allocated up front for a binary or a string, so a corrupt
+// length can't allocate more than the stream actually holds
+const MAX_PREALLOCATION: usize = 8 * 1024;
+
+/// A transport reading from a [`Read`], see the [module](self) docs.
+///
+/// The unknown fields of a message can't be kept, as what was read is not
+/// retained: decoding a message generated with `keep_unknown_fields` fails
+/// when it meets one.
+pub struct IoReader<R: Read> {
+    reader: R,
+    // always empty, the `buf` of the protocol
+    empty: Bytes,
+}
+
+impl<R: Read> IoReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            empty: Bytes::new(),
+        }
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        let mut buf = [0; N];
+        self.reader.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    // reads and drops `len` bytes, returning `len`
+    fn discard(&mut self, len: usize) -> io::Result<usize> {
+        let read = io::copy(&mut (&mut self.reader).take(len as u64), &mut io::sink())?;
+        if read < len as u64 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(len)
+    }
+
+    fn read_vec(&mut self, len: i32) -> Result<Vec<u8>, ThriftException> {
+        if len < 0 {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::NegativeSize,
+                format!("negative length {len}"),
+            ));
+        }
+        let len = len as usize;
+        let mut v = Vec::with_capacity(len.min(MAX_PREALLOCATION));
+        (&mut self.reader).take(len as u64).read_to_end(&mut v)?;
+        if v.len() < len {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        Ok(v)
+    }
+}
+
+impl<R: Read, const LITTLE_ENDIAN: bool> TBinaryProtocol<IoReader<R>, LITTLE_ENDIAN> {
+    /// Returns the reader, dropping the protocol.
+    pub fn into_reader(self) -> R {
+        self.trans.reader
+    }
+}
+
+macro_rules! read_number {
+    ($self:ident, $ty:ty) => {{
+        let bytes = $self.trans.read_array()?;
+        Ok(if LITTLE_ENDIAN {
+            <$ty>::from_le_bytes(bytes)
+        } else {
+            <$ty>::from_be_bytes(bytes)
+        })
+    }};
+}
+
+impl<R: Read, const LITTLE_ENDIAN: bool> TInputProtocol
+    for TBinaryProtocol<IoReader<R>, LITTLE_ENDIAN>
+{
+    type Buf = Bytes;
+
+    fn read_message_begin(&mut self) -> Result<TMessageIdentifier, ThriftException> {
+        depth::reset(&mut self.depth);
+        allocation::reset(&mut self.allocation);
+        let size = self.read_i32()?;
+
+        if size >= 0 && !self.strict_read {
+            super::check_message_size(size as usize, self.max_message_size)?;
+            super::check_string_size(size as usize, self.max_string_size)?;
+            let name = FastStr::from_string(string_from_utf8(self.trans.read_vec(size)?)?);
+            let message_type = message_type_from_u8(self.read_byte()?)?;
+            let sequence_number = self.read_i32()?;
+            return Ok(TMessageIdentifier::new(name, message_type, sequence_number));
+        }
+        if size > 0 {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::BadVersion,
+                "Missing version in ReadMessageBegin".to_string(),
+            ));
+        }
+        let message_type = message_type_from_u8((size & 0xf) as u8)?;
+
+        let version = size & (super::binary::VERSION_MASK as i32);
+        if version != (Self::VERSION as i32) {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::BadVersion,
+                "Bad version in ReadMessageBegin",
+            ));
+        }
+
+        let name = self.read_faststr()?;
+
+        let sequence_number = self.read_i32()?;
+        Ok(TMessageIdentifier::new(name, message_type, sequence_number))
+    }
+
+    #[inline]
+    fn read_message_end(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn read_struct_begin(&mut self) -> Result<Option<TStructIdentifier>, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        Ok(None)
+    }
+
+    #[inline]
+    fn read_struct_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
+        Ok(())
+    }
+
+    #[inline]
+    fn read_field_begin(&mut self) -> Result<TFieldIdentifier, ThriftException> {
+        let field_type_byte = self.read_byte()?;
+        let field_type = field_type_byte.try_into().map_err(|_| {
+            new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                format!("invalid ttype {field_type_byte}"),
+            )
+        })?;
+        let id = match field_type {
+            TType::Stop => Ok(0),
+            _ => self.read_i16(),
+        }?;
+        Ok(TFieldIdentifier::new::<Option<&'static str>, i16>(
+            None, field_type, id,
+        ))
+    }
+
+    #[inline]
+    fn read_field_end(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn rejects_duplicate_fields(&self) -> bool {
+        self.reject_duplicate_fields
+    }
+
+    #[inline]
+    fn on_unknown_field(
+        &self,
+        struct_name: &'static str,
+        field: &TFieldIdentifier,
+        type_mismatch: bool,
+    ) {
+        unknown::report(&self.unknown_field_hook, struct_name, field, type_mismatch);
+    }
+
+    fn skip_till_depth(&mut self, field_type: TType, depth: i8) -> Result<usize, ThriftException> {
+        if depth == 0 {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::DepthLimit,
+                format!("cannot parse past {field_type:?}"),
+            ));
+        }
+        let mut len = 0;
+
+        // the default reads the fixed-size values from `buf`, which is empty
+        match field_type {
+            TType::Bool | TType::I8 => len += self.trans.discard(1)?,
+            TType::I16 => len += self.trans.discard(2)?,
+            TType::I32 => len += self.trans.discard(4)?,
+            TType::I64 | TType::Double => len += self.trans.discard(8)?,
+            TType::Uuid => len += self.trans.discard(16)?,
+            TType::Binary => {
+                let length = self.read_i32()?;
+                if length < 0 {
+                    return Err(new_protocol_exception(
+                        ProtocolExceptionKind::NegativeSize,
+                        format!("negative length {length}"),
+                    ));
+                }
+                len += 4 + self.trans.discard(length as usize)?;
+            }
+            TType::Struct => {
+                len += self.struct_begin_len(&super::VOID_IDENT);
+                loop {
+                    let field_ident = self.read_field_begin()?;
+                    if field_ident.field_type == TType::Stop {
+                        len += self.field_stop_len();
+                        break;
+                    }
+                    len += self.field_begin_len(field_ident.field_type, field_ident.id);
+                    len += self.skip_till_depth(field_ident.field_type, depth - 1)?;
+                    len += self.field_end_len();
+                }
+                len += self.struct_end_len();
+            }
+            TType::List => {
+                let list_ident = self.read_list_begin()?;
+                len += self.list_begin_len(list_ident);
+                for _ in 0..list_ident.size {
+                    len += self.skip_till_depth(list_ident.element_type, depth - 1)?;
+                }
+                len += self.list_end_len();
+            }
+            TType::Set => {
+                let set_ident = self.read_set_begin()?;
+                len += self.set_begin_len(set_ident);
+                for _ in 0..set_ident.size {
+                    len += self.skip_till_depth(set_ident.element_type, depth - 1)?;
+                }
+                len += self.set_end_len();
+            }
+            TType::Map => {
+                let map_ident = self.read_map_begin()?;
+                len += self.map_begin_len(map_ident);
+                for _ in 0..map_ident.size {
+                    len += self.skip_till_depth(map_ident.key_type, depth - 1)?;
+                    len += self.skip_till_depth(map_ident.value_type, depth - 1)?;
+                }
+                len += self.map_end_len();
+            }
+            u => {
+                return Err(new_protocol_exception(
+                    ProtocolExceptionKind::DepthLimit,
+                    format!("cannot skip field type {:?}", &u),
+                ));
+            }
+        }
+
+        Ok(len)
+    }
+
+    #[inline]
+    fn read_bool(&mut self) -> Result<bool, ThriftException> {
+        Ok(self.read_i8()? != 0)
+    }
+
+    #[inline]
+    fn read_bytes(&mut self) -> Result<Bytes, ThriftException> {
+        self.read_bytes_vec().map(Bytes::from)
+    }
+
+    fn peek_byte(&mut self) -> Result<u8, ThriftException> {
+        Err(new_protocol_exception(
+            ProtocolExceptionKind::NotImplemented,
+            "can't peek when reading from an io::Read",
+        ))
+    }
+
+    fn skip_capture(&mut self, _: TType) -> Result<Bytes, ThriftException> {
+        Err(new_protocol_exception(
+            ProtocolExceptionKind::NotImplemented,
+            "skipped values can't be captured when reading from an io::Read",
+        ))
+    }
+
+    #[inline]
+    fn get_bytes(&mut self, ptr: Option<*const u8>, len: usize) -> Result<Bytes, ThriftException> {
+        match ptr {
+            Some(_) => Err(new_protocol_exception(
+                ProtocolExceptionKind::NotImplemented,
+                "unknown fields can't be kept when reading from an io::Read",
+            )),
+            None => {
+                let len = i32::try_from(len).map_err(|_| {
+                    new_protocol_exception(
+                        ProtocolExceptionKind::SizeLimit,
+                        format!("length {len} out of range"),
+                    )
+                })?;
+                self.trans.read_vec(len).map(Bytes::from)
+            }
+        }
+    }
+
+    #[inline]
+    fn read_uuid(&mut self) -> Result<[u8; 16], ThriftException> {
+        Ok(self.trans.read_array()?)
+    }
+
+    #[inline]
+    fn read_i8(&mut self) -> Result<i8, ThriftException> {
+        Ok(self.read_byte()? as i8)
+    }
+
+    #[inline]
+    fn read_i16(&mut self) -> Result<i16, ThriftException> {
+        read_number!(self, i16)
+    }
+
+    #[inline]
+    fn read_i32(&mut self) -> Result<i32, ThriftException> {
+        read_number!(self, i32)
+    }
+
+    #[inline]
+    fn read_i64(&mut self) -> Result<i64, ThriftException> {
+        read_number!(self, i64)
+    }
+
+    #[inline]
+    fn read_double(&mut self) -> Result<f64, ThriftException> {
+        read_number!(self, f64)
+    }
+
+    #[inline]
+    fn read_string(&mut self) -> Result<String, ThriftException> {
+        let len = self.read_i32()?;
+        super::check_message_size(len.max(0) as usize, self.max_message_size)?;
+        super::check_string_size(len.max(0) as usize, self.max_string_size)?;
+        allocation::charge(&mut self.allocation, len.max(0) as usize)?;
+        Ok(string_from_utf8(self.trans.read_vec(len)?)?)
+    }
+
+    #[inline]
+    fn read_faststr(&mut self) -> Result<FastStr, ThriftException> {
+        self.read_string().map(FastStr::from_string)
+    }
+
+    #[inline]
+    fn read_list_begin(&mut self) -> Result<TListIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        let element_type = read_ttype(self)?;
+        let size = self.read_len()?;
+        super::check_container_size(size, 1, None, self.max_container_size)?;
+        allocation::charge_elements(&mut self.allocation, size, element_size(element_type))?;
+        Ok(TListIdentifier::new(element_type, size))
+    }
+
+    #[inline]
+    fn read_list_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
+        Ok(())
+    }
+
+    #[inline]
+    fn read_set_begin(&mut self) -> Result<TSetIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        let element_type = read_ttype(self)?;
+        let size = self.read_len()?;
+        super::check_container_size(size, 1, None, self.max_container_size)?;
+        allocation::charge_elements(&mut self.allocation, size, element_size(element_type))?;
+        Ok(TSetIdentifier::new(element_type, size))
+    }
+
+    #[inline]
+    fn read_set_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
+        Ok(())
+    }
+
+    #[inline]
+    fn read_map_begin(&mut self) -> Result<TMapIdentifier, ThriftException> {
+        depth::enter(&mut self.depth)?;
+        let key_type = read_ttype(self)?;
+        let value_type = read_ttype(self)?;
+        let size = self.read_len()?;
+        super::check_container_size(size, 1, None, self.max_container_size)?;
+        let entry_size = element_size(key_type) + element_size(value_type);
+        allocation::charge_elements(&mut self.allocation, size, entry_size)?;
+        Ok(TMapIdentifier::new(key_type, value_type, size))
+    }
+
+    #[inline]
+    fn read_map_end(&mut self) -> Result<(), ThriftException> {
+        depth::exit(&mut self.depth);
+        Ok(())
+    }
+
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, ThriftException> {
+        let [b] = self.trans.read_array()?;
+        Ok(b)
+    }
+
+    #[inline]
+    fn read_bytes_vec(&mut self) -> Result<Vec<u8>, ThriftException> {
+        let len = self.read_i32()?;
+        super::check_message_size(len.max(0) as usize, self.max_message_size)?;
+        super::check_string_size(len.max(0) as usize, self.max_string_size)?;
+        allocation::charge(&mut self.allocation, len.max(0) as usize)?;
+        self.trans.read_vec(len)
+    }
+
+    #[inline]
+    fn buf(&mut self) -> &mut Self::Buf {
+        &mut self.trans.empty
+    }
+}
+
+#[inline]
+fn read_ttype<P: TInputProtocol>(p: &mut P) -> Result<TType, ThriftException> {
+    let ttype = p.read_byte()?;
+    ttype.try_into().map_err(|_| {
+        new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            format!("invalid ttype {ttype}"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+
+    use super::*;
+    use crate::thrift::{TMessageType, TOutputProtocol, ThriftException};
+
+    fn write<T: TOutputProtocol>(protocol: &mut T) -> Result<(), ThriftException> {
+        protocol.write_message_begin(&TMessageIdentifier::new(
+            "ping".into(),
+            TMessageType::Call,
+            3,
+        ))?;
+        protocol.write_field_begin(TType::Binary, 1)?;
+        protocol.write_bytes_vec(&[1; 40])?;
+        protocol.write_field_begin(TType::List, 2)?;
+        protocol.write_list_begin(TListIdentifier::new(TType::Double, 1))?;
+        protocol.write_double(1.5)?;
+        protocol.write_field_stop()
+    }
+
+    #[test]
+    fn test_io_reader() {
+        let mut buf = BytesMut::new();
+        write(&mut TBinaryProtocol::new(&mut buf, false)).unwrap();
+
+        let mut protocol = TBinaryProtocol::new(IoReader::new(&buf[..]), false);
+        let ident = protocol.read_message_begin().unwrap();
+        assert_eq!((&*ident.name, ident.sequence_number), ("ping", 3));
+        assert_eq!(protocol.read_field_begin().unwrap().id, Some(1));
+        assert_eq!(protocol.read_bytes_vec().unwrap(), [1; 40]);
+        let field = protocol.read_field_begin().unwrap();
+        assert_eq!(protocol.skip(field.field_type).unwrap(), 13);
+        assert_eq!(protocol.read_field_begin().unwrap().field_type, TType::Stop);
+        assert!(protocol.into_reader().is_empty());
+
+        let mut buf = BytesMut::new();
+        write(&mut TBinaryProtocol::<_, true>::with_byte_order(
+            &mut buf, false,
+        ))
+        .unwrap();
+        let mut protocol =
+            TBinaryProtocol::<_, true>::with_byte_order(IoReader::new(&buf[..]), false);
+        assert_eq!(protocol.read_message_begin().unwrap().sequence_number, 3);
+    }
+
+    #[test]
+    fn test_io_reader_truncated() {
+        // a binary claiming more than the stream holds
+        let mut protocol =
+            TBinaryProtocol::new(IoReader::new(&[0x7f, 0xff, 0xff, 0xff, 1][..]), false);
+        assert!(matches!(
+            protocol.read_bytes_vec().unwrap_err(),
+            ThriftException::Transport(_)
+        ));
+
+        let mut protocol = TBinaryProtocol::new(IoReader::new(&[0xff; 4][..]), false);
+        assert!(protocol.read_string().is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use bytes::Buf;
+
+/// What an input protocol does when a field header carries an invalid type,
+/// as when a byte of the payload is corrupted.
+///
+/// The recoveries other than [`TTypeRecovery::Abort`] are best effort: the
+/// decode goes on as if the struct ended at the corrupted field, so it still
+/// fails if a required field is missing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TTypeRecovery {
+    /// Fails the decode with [`ProtocolExceptionKind::InvalidData`](super::ProtocolExceptionKind::InvalidData).
+    #[default]
+    Abort,
+    /// Drops the input up to and including the next stop byte, which ends the
+    /// struct.
+    SkipToStop,
+    /// Drops the rest of the input, which ends the struct and the structs
+    /// enclosing it.
+    DropRest,
+}
+
+pub(crate) type TTypeRecoveryFn = Arc<dyn Fn(u8) -> TTypeRecovery + Send + Sync>;
+
+pub(crate) struct Recovery {
+    policy: TTypeRecoveryFn,
+    dropped: bool,
+}
+
+impl Recovery {
+    pub(crate) fn new(policy: TTypeRecoveryFn) -> Self {
+        Self {
+            policy,
+            dropped: false,
+        }
+    }
+
+    /// Forgets the dropped input, for the next message.
+    #[inline]
+    pub(crate) fn reset(&mut self) {
+        self.dropped = false;
+    }
+
+    /// Whether the input was dropped, for marking the position of the
+    /// protocol.
+    #[inline]
+    pub(crate) fn is_dropped(&self) -> bool {
+        self.dropped
+    }
+
+    /// Restores whether the input was dropped, when rewinding to a mark.
+    #[inline]
+    pub(crate) fn set_dropped(&mut self, dropped: bool) {
+        self.dropped = dropped;
+    }
+
+    /// Whether the field header about to be read is past the input dropped
+    /// by a recovery, which reads as a stop.
+    #[inline]
+    pub(crate) fn dropped(&self, buf: &impl Buf) -> bool {
+        self.dropped && !buf.has_remaining()
+    }
+
+    /// Whether the invalid field type `byte` would be recovered from, for
+    /// peeking at a field header without reading it.
+    #[inline]
+    pub(crate) fn would_recover(&self, byte: u8) -> bool {
+        (self.policy)(byte) != TTypeRecovery::Abort
+    }
+
+    /// Recovers from the invalid field type `byte`, returning whether the
+    /// struct ends there or the decode fails.
+    pub(crate) fn recover(&mut self, buf: &mut impl Buf, byte: u8) -> bool {
+        match (self.policy)(byte) {
+            TTypeRecovery::Abort => false,
+            TTypeRecovery::SkipToStop => {
+                while buf.has_remaining() {
+                    let chunk = buf.chunk();
+                    match crate::simd::find_byte(0, chunk) {
+                        Some(pos) => {
+                            buf.advance(pos + 1);
+                            return true;
+                        }
+                        None => {
+                            let len = chunk.len();
+                            buf.advance(len);
+                        }
+                    }
+                }
+                // no stop left, the struct ends with the input
+                self.dropped = true;
+                true
+            }
+            TTypeRecovery::DropRest => {
+                buf.advance(buf.remaining());
+                self.dropped = true;
+                true
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::mem;
 
-use bytes::{Buf as _, BufMut, BytesMut};
+use bytes::{Buf as _, BufMut, Bytes, BytesMut};
+use faststr::FastStr;
 
 use super::{ThriftException, new_protocol_exception};
 
@@ -8,6 +9,8 @@ use super::{ThriftException, new_protocol_exception};
 pub enum IOError {
     #[error("no remaining {0}")]
     NoRemaining(String),
+    #[error("invalid utf-8 string")]
+    InvalidUtf8,
 }
 
 impl From<IOError> for ThriftException {
@@ -16,6 +19,65 @@ impl From<IOError> for ThriftException {
     }
 }
 
+#[cfg(not(feature = "safe-only"))]
+#[inline(always)]
+fn read_array<const N: usize>(src: &[u8]) -> [u8; N] {
+    unsafe { *(src as *const _ as *const [u8; N]) }
+}
+
+#[cfg(feature = "safe-only")]
+#[inline(always)]
+fn read_array<const N: usize>(src: &[u8]) -> [u8; N] {
+    src.try_into().unwrap()
+}
+
+/// Checks the bytes of a thrift string about to be taken unchecked are UTF-8,
+/// with the `validate-utf8` feature. `safe-only` takes no string unchecked.
+#[cfg(all(feature = "validate-utf8", not(feature = "safe-only")))]
+#[inline]
+pub(crate) fn check_utf8(b: &[u8]) -> Result<(), IOError> {
+    if crate::simd::validate_utf8(b) {
+        Ok(())
+    } else {
+        Err(IOError::InvalidUtf8)
+    }
+}
+
+#[cfg(not(any(feature = "validate-utf8", feature = "safe-only")))]
+#[inline(always)]
+pub(crate) fn check_utf8(_: &[u8]) -> Result<(), IOError> {
+    Ok(())
+}
+
+/// Turns the bytes of a thrift string into a `String`, the bytes are only
+/// validated with the `safe-only` or `validate-utf8` feature.
+#[inline]
+pub(crate) fn string_from_utf8(v: Vec<u8>) -> Result<String, IOError> {
+    #[cfg(feature = "safe-only")]
+    {
+        String::from_utf8(v).map_err(|_| IOError::InvalidUtf8)
+    }
+    #[cfg(not(feature = "safe-only"))]
+    {
+        check_utf8(&v)?;
+        Ok(unsafe { String::from_utf8_unchecked(v) })
+    }
+}
+
+/// Same as [`string_from_utf8`] for `FastStr`.
+#[inline]
+pub(crate) fn faststr_from_bytes(b: Bytes) -> Result<FastStr, IOError> {
+    #[cfg(feature = "safe-only")]
+    {
+        FastStr::from_bytes(b).map_err(|_| IOError::InvalidUtf8)
+    }
+    #[cfg(not(feature = "safe-only"))]
+    {
+        check_utf8(&b)?;
+        Ok(unsafe { FastStr::from_bytes_unchecked(b) })
+    }
+}
+
 macro_rules! io_read_impl {
     ($this:ident, $typ:tt::$conv:tt) => {{
         const SIZE: usize = mem::size_of::<$typ>();
@@ -28,7 +90,8 @@ macro_rules! io_read_impl {
         let ret = $this
             .chunk()
             .get(..SIZE)
-            .map(|src| unsafe { $typ::$conv(*(src as *const _ as *const [_; SIZE])) });
+            .map(|src| read_array::<SIZE>(src))
+            .map($typ::$conv);
 
         if let Some(ret) = ret {
             // if the direct conversion was possible, advance and return
@@ -85,55 +148,28 @@ macro_rules! assert_remaining {
 
 pub trait WriteExt {
     fn write_slice(&mut self, src: &[u8]);
-    fn write_u8(&mut self, n: u8);
-    fn write_i8(&mut self, n: i8);
-    fn write_u16(&mut self, n: u16);
-    fn write_u16_le(&mut self, n: u16);
-    fn write_i16(&mut self, n: i16);
-    fn write_i16_le(&mut self, n: i16);
-    fn write_u32(&mut self, n: u32);
-    fn write_u32_le(&mut self, n: u32);
-    fn write_i32(&mut self, n: i32);
-    fn write_i32_le(&mut self, n: i32);
-    fn write_u64(&mut self, n: u64);
-
-    fn write_u64_le(&mut self, n: u64);
-
-    fn write_i64(&mut self, n: i64);
-
-    fn write_i64_le(&mut self, n: i64);
-
-    fn write_u128(&mut self, n: u128);
-
-    fn write_u128_le(&mut self, n: u128);
-
-    fn write_i128(&mut self, n: i128);
-
-    fn write_i128_le(&mut self, n: i128);
-
-    fn write_uint(&mut self, n: u64, nbytes: usize);
 
-    fn write_uint_le(&mut self, n: u64, nbytes: usize);
-
-    fn write_int(&mut self, n: i64, nbytes: usize);
-
-    fn write_int_le(&mut self, n: i64, nbytes: usize);
-
-    fn write_f32(&mut self, n: f32);
-
-    fn write_f32_le(&mut self, n: f32);
-
-    fn write_f64(&mut self, n: f64);
-
-    fn write_f64_le(&mut self, n: f64);
-}
+    /// Writes out the bytes the writer holds back, if any, see
+    /// [`Staged`](super::staging::Staged) and
+    /// [`IoWriter`](super::writer::IoWriter).
+    #[inline]
+    fn flush_staged(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
 
-impl WriteExt for BytesMut {
+    /// The bytes written so far, if they can be rewritten, for sorting the
+    /// entries of maps and sets in place.
     #[inline]
-    fn write_slice(&mut self, src: &[u8]) {
-        self.put_slice(src);
+    fn written_mut(&mut self) -> Option<&mut [u8]> {
+        None
     }
 
+    /// Keeps the bytes from [`WriteExt::written_mut`] in the writer until as
+    /// many calls release them as held them, for a writer handing them on as
+    /// it goes, see [`HashWriter`](super::hash::HashWriter).
+    #[inline]
+    fn hold_written(&mut self, _hold: bool) {}
+
     #[inline]
     fn write_u8(&mut self, n: u8) {
         let src = [n];
@@ -267,6 +303,18 @@ impl WriteExt for BytesMut {
     }
 }
 
+impl WriteExt for BytesMut {
+    #[inline]
+    fn write_slice(&mut self, src: &[u8]) {
+        self.put_slice(src);
+    }
+
+    #[inline]
+    fn written_mut(&mut self) -> Option<&mut [u8]> {
+        Some(self)
+    }
+}
+
 pub trait ReadExt {
     fn read_to_bytes(&mut self, size: usize) -> Result<bytes::Bytes, IOError>;
     fn read_to_string(&mut self, len: usize) -> Result<String, IOError>;
@@ -345,7 +393,7 @@ where
         // FIXME: use maybe_uninit?
         let mut vec = vec![0; len];
         self.read_to_slice(vec.as_mut_slice())?;
-        unsafe { Ok(String::from_utf8_unchecked(vec)) }
+        string_from_utf8(vec)
     }
 
     #[inline]
@@ -0,0 +1,896 @@
+//! A write-only protocol emitting plain JSON, for the systems reading thrift
+//! payloads which only understand JSON, such as log and analytics pipelines.
+//!
+//! As with the `TSimpleJSONProtocol` of Apache Thrift, the output can't be
+//! decoded back, as it leaves out the types of the values:
+//!
+//! - structs are objects keyed by the names of their fields, those given to
+//!   [`TOutputProtocol::write_field_names`] by the structs generated with
+//!   `with_field_names`, or else by their ids;
+//! - lists and sets are arrays;
+//! - maps are objects when their keys are scalars, which are quoted if they
+//!   aren't strings, and arrays of `[key, value]` pairs otherwise;
+//! - binaries are base64 strings, uuids hyphenated strings, and the doubles
+//!   which aren't numbers the strings `"NaN"`, `"Infinity"` and
+//!   `"-Infinity"`;
+//! - messages are arrays of their name, type, sequence id and body.
+//!
+//! The raw unknown fields of a struct can't be written, and are left out.
+//!
+//! ```
+//! use pilota::thrift::{
+//!     ApplicationException, ApplicationExceptionKind, Message, TSimpleJSONProtocol,
+//! };
+//!
+//! let e = ApplicationException::new(ApplicationExceptionKind::UNKNOWN_METHOD, "no such method");
+//! let mut buf = bytes::BytesMut::new();
+//! e.encode(&mut TSimpleJSONProtocol::new(&mut buf)).unwrap();
+//! assert_eq!(&buf[..], br#"{"message":"no such method","type":1}"#);
+//! ```
+
+use bytes::{BufMut, Bytes};
+use faststr::FastStr;
+
+use super::{
+    TLengthProtocol, TListIdentifier, TMapIdentifier, TMessageIdentifier, TOutputProtocol,
+    TSetIdentifier, TStructIdentifier, TType, ThriftException, counting::ByteCounter,
+    rw_ext::WriteExt,
+};
+
+pub struct TSimpleJSONProtocol<T> {
+    trans: T,
+    scopes: Scopes,
+}
+
+impl<T> TSimpleJSONProtocol<T> {
+    pub fn new(trans: T) -> Self {
+        Self {
+            trans,
+            scopes: Scopes(Vec::new()),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.trans
+    }
+
+    /// The number of bytes `write` outputs, which leaves the scopes as they
+    /// were once the value it writes is complete.
+    #[inline]
+    fn counted(&mut self, write: impl FnOnce(&mut Scopes, &mut ByteCounter)) -> usize {
+        let mut counter = ByteCounter::new();
+        write(&mut self.scopes, &mut counter);
+        counter.count()
+    }
+}
+
+/// The containers being written, innermost last.
+struct Scopes(Vec<Scope>);
+
+enum Scope {
+    /// The fields of a struct, whose keys `field_begin` writes.
+    Struct {
+        names: &'static [(i16, &'static str)],
+        first: bool,
+    },
+    /// The elements of a list, a set or a message.
+    Array { first: bool },
+    /// A map with scalar keys, `key` being whether the next value is a key.
+    Object { first: bool, key: bool },
+    /// A map with other keys, written as `[key, value]` pairs.
+    Pairs { first: bool, key: bool },
+}
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+impl Scopes {
+    /// Writes what comes before a value, and returns whether it is the key of
+    /// an object, which must be a string.
+    #[inline]
+    fn begin_value<W: WriteExt>(&mut self, w: &mut W) -> bool {
+        match self.0.last_mut() {
+            None | Some(Scope::Struct { .. }) => false,
+            Some(Scope::Array { first }) => {
+                separate(w, first);
+                false
+            }
+            Some(Scope::Object { first, key }) => {
+                if *key {
+                    separate(w, first);
+                } else {
+                    w.write_slice(b":");
+                }
+                *key
+            }
+            Some(Scope::Pairs { first, key }) => {
+                if *key {
+                    separate(w, first);
+                    w.write_slice(b"[");
+                } else {
+                    w.write_slice(b",");
+                }
+                false
+            }
+        }
+    }
+
+    #[inline]
+    fn end_value<W: WriteExt>(&mut self, w: &mut W) {
+        match self.0.last_mut() {
+            Some(Scope::Object { key, .. }) => *key = !*key,
+            Some(Scope::Pairs { key, .. }) => {
+                if !*key {
+                    w.write_slice(b"]");
+                }
+                *key = !*key;
+            }
+            _ => {}
+        }
+    }
+
+    /// Writes a number or a literal, quoted as a key.
+    #[inline]
+    fn scalar<W: WriteExt>(&mut self, w: &mut W, text: &[u8]) {
+        let key = self.begin_value(w);
+        if key {
+            w.write_slice(b"\"");
+        }
+        w.write_slice(text);
+        if key {
+            w.write_slice(b"\"");
+        }
+        self.end_value(w);
+    }
+
+    #[inline]
+    fn integer<W: WriteExt>(&mut self, w: &mut W, i: i64) {
+        let mut buf = [0; 20];
+        let mut pos = buf.len();
+        let mut n = i.unsigned_abs();
+        loop {
+            pos -= 1;
+            buf[pos] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        if i < 0 {
+            pos -= 1;
+            buf[pos] = b'-';
+        }
+        self.scalar(w, &buf[pos..]);
+    }
+
+    fn double<W: WriteExt>(&mut self, w: &mut W, d: f64) {
+        if d.is_nan() {
+            self.string(w, "NaN");
+        } else if d.is_infinite() {
+            self.string(w, if d > 0.0 { "Infinity" } else { "-Infinity" });
+        } else if d == 0.0 || (1e-5..1e16).contains(&d.abs()) {
+            self.scalar(w, d.to_string().as_bytes());
+        } else {
+            self.scalar(w, format!("{d:e}").as_bytes());
+        }
+    }
+
+    fn string<W: WriteExt>(&mut self, w: &mut W, s: &str) {
+        self.begin_value(w);
+        write_escaped(w, s.as_bytes());
+        self.end_value(w);
+    }
+
+    fn binary<W: WriteExt>(&mut self, w: &mut W, b: &[u8]) {
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+        self.begin_value(w);
+        w.write_slice(b"\"");
+        let mut out = [0; 64];
+        for chunk in b.chunks(48) {
+            let mut len = 0;
+            for group in chunk.chunks(3) {
+                let n = (group[0] as u32) << 16
+                    | (*group.get(1).unwrap_or(&0) as u32) << 8
+                    | *group.get(2).unwrap_or(&0) as u32;
+                for (i, o) in out[len..len + 4].iter_mut().enumerate() {
+                    *o = if i <= group.len() {
+                        ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize]
+                    } else {
+                        b'='
+                    };
+                }
+                len += 4;
+            }
+            w.write_slice(&out[..len]);
+        }
+        w.write_slice(b"\"");
+        self.end_value(w);
+    }
+
+    fn uuid<W: WriteExt>(&mut self, w: &mut W, u: [u8; 16]) {
+        let mut out = [0; 38];
+        out[0] = b'"';
+        let mut pos = 1;
+        for (i, b) in u.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                out[pos] = b'-';
+                pos += 1;
+            }
+            out[pos] = HEX[(b >> 4) as usize];
+            out[pos + 1] = HEX[(b & 0xf) as usize];
+            pos += 2;
+        }
+        out[pos] = b'"';
+        self.begin_value(w);
+        w.write_slice(&out);
+        self.end_value(w);
+    }
+
+    fn message_begin<W: WriteExt>(&mut self, w: &mut W, identifier: &TMessageIdentifier) {
+        self.begin_value(w);
+        w.write_slice(b"[");
+        self.0.push(Scope::Array { first: true });
+        self.string(w, &identifier.name);
+        self.integer(w, identifier.message_type as u8 as i64);
+        self.integer(w, identifier.sequence_number as i64);
+    }
+
+    fn struct_begin<W: WriteExt>(&mut self, w: &mut W) {
+        self.begin_value(w);
+        w.write_slice(b"{");
+        self.0.push(Scope::Struct {
+            names: &[],
+            first: true,
+        });
+    }
+
+    fn set_field_names(&mut self, names: &'static [(i16, &'static str)]) {
+        if let Some(Scope::Struct { names: n, .. }) = self.0.last_mut() {
+            *n = names;
+        }
+    }
+
+    fn field_begin<W: WriteExt>(&mut self, w: &mut W, id: i16) {
+        let Some(Scope::Struct { names, first }) = self.0.last_mut() else {
+            return;
+        };
+        separate(w, first);
+        match names.iter().find(|(i, _)| *i == id) {
+            Some((_, name)) => write_escaped(w, name.as_bytes()),
+            None => {
+                w.write_slice(b"\"");
+                w.write_slice(id.to_string().as_bytes());
+                w.write_slice(b"\"");
+            }
+        }
+        w.write_slice(b":");
+    }
+
+    fn array_begin<W: WriteExt>(&mut self, w: &mut W) {
+        self.begin_value(w);
+        w.write_slice(b"[");
+        self.0.push(Scope::Array { first: true });
+    }
+
+    fn map_begin<W: WriteExt>(&mut self, w: &mut W, key_type: TType) {
+        self.begin_value(w);
+        if is_scalar(key_type) {
+            w.write_slice(b"{");
+            self.0.push(Scope::Object {
+                first: true,
+                key: true,
+            });
+        } else {
+            w.write_slice(b"[");
+            self.0.push(Scope::Pairs {
+                first: true,
+                key: true,
+            });
+        }
+    }
+
+    /// Closes the innermost container.
+    fn end<W: WriteExt>(&mut self, w: &mut W) {
+        match self.0.pop() {
+            Some(Scope::Struct { .. } | Scope::Object { .. }) => w.write_slice(b"}"),
+            Some(Scope::Array { .. } | Scope::Pairs { .. }) => w.write_slice(b"]"),
+            None => return,
+        }
+        self.end_value(w);
+    }
+}
+
+#[inline]
+fn separate<W: WriteExt>(w: &mut W, first: &mut bool) {
+    if !*first {
+        w.write_slice(b",");
+    }
+    *first = false;
+}
+
+fn is_scalar(ttype: TType) -> bool {
+    matches!(
+        ttype,
+        TType::Bool
+            | TType::I8
+            | TType::I16
+            | TType::I32
+            | TType::I64
+            | TType::Double
+            | TType::Binary
+            | TType::Uuid
+    )
+}
+
+/// Writes `s` as a JSON string.
+fn write_escaped<W: WriteExt>(w: &mut W, s: &[u8]) {
+    w.write_slice(b"\"");
+    let mut start = 0;
+    for (i, &b) in s.iter().enumerate() {
+        let escaped: &[u8] = match b {
+            b'"' => b"\\\"",
+            b'\\' => b"\\\\",
+            b'\n' => b"\\n",
+            b'\r' => b"\\r",
+            b'\t' => b"\\t",
+            0..=0x1f => &[
+                b'\\',
+                b'u',
+                b'0',
+                b'0',
+                HEX[(b >> 4) as usize],
+                HEX[(b & 0xf) as usize],
+            ],
+            _ => continue,
+        };
+        w.write_slice(&s[start..i]);
+        w.write_slice(escaped);
+        start = i + 1;
+    }
+    w.write_slice(&s[start..]);
+    w.write_slice(b"\"");
+}
+
+impl<T> TLengthProtocol for TSimpleJSONProtocol<T> {
+    #[inline]
+    fn message_begin_len(&mut self, identifier: &TMessageIdentifier) -> usize {
+        self.counted(|s, w| s.message_begin(w, identifier))
+    }
+
+    #[inline]
+    fn message_end_len(&mut self) -> usize {
+        self.counted(|s, w| s.end(w))
+    }
+
+    #[inline]
+    fn struct_begin_len(&mut self, _identifier: &TStructIdentifier) -> usize {
+        self.counted(|s, w| s.struct_begin(w))
+    }
+
+    #[inline]
+    fn struct_end_len(&mut self) -> usize {
+        self.counted(|s, w| s.end(w))
+    }
+
+    #[inline]
+    fn field_names_len(&mut self, names: &'static [(i16, &'static str)]) -> usize {
+        self.scopes.set_field_names(names);
+        0
+    }
+
+    #[inline]
+    fn field_begin_len(&mut self, _field_type: TType, id: Option<i16>) -> usize {
+        self.counted(|s, w| s.field_begin(w, id.unwrap_or_default()))
+    }
+
+    #[inline]
+    fn field_end_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn field_stop_len(&mut self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn bool_len(&mut self, b: bool) -> usize {
+        self.counted(|s, w| s.scalar(w, if b { b"true" } else { b"false" }))
+    }
+
+    #[inline]
+    fn bytes_len(&mut self, b: &[u8]) -> usize {
+        self.counted(|s, w| s.binary(w, b))
+    }
+
+    #[inline]
+    fn bytes_vec_len(&mut self, b: &[u8]) -> usize {
+        self.counted(|s, w| s.binary(w, b))
+    }
+
+    #[inline]
+    fn byte_len(&mut self, b: u8) -> usize {
+        self.counted(|s, w| s.integer(w, b as i64))
+    }
+
+    #[inline]
+    fn uuid_len(&mut self, u: [u8; 16]) -> usize {
+        self.counted(|s, w| s.uuid(w, u))
+    }
+
+    #[inline]
+    fn i8_len(&mut self, i: i8) -> usize {
+        self.counted(|s, w| s.integer(w, i as i64))
+    }
+
+    #[inline]
+    fn i16_len(&mut self, i: i16) -> usize {
+        self.counted(|s, w| s.integer(w, i as i64))
+    }
+
+    #[inline]
+    fn i32_len(&mut self, i: i32) -> usize {
+        self.counted(|s, w| s.integer(w, i as i64))
+    }
+
+    #[inline]
+    fn i64_len(&mut self, i: i64) -> usize {
+        self.counted(|s, w| s.integer(w, i))
+    }
+
+    #[inline]
+    fn double_len(&mut self, d: f64) -> usize {
+        self.counted(|s, w| s.double(w, d))
+    }
+
+    #[inline]
+    fn string_len(&mut self, s: &str) -> usize {
+        self.counted(|scopes, w| scopes.string(w, s))
+    }
+
+    #[inline]
+    fn faststr_len(&mut self, s: &FastStr) -> usize {
+        self.counted(|scopes, w| scopes.string(w, s))
+    }
+
+    #[inline]
+    fn list_begin_len(&mut self, _identifier: TListIdentifier) -> usize {
+        self.counted(|s, w| s.array_begin(w))
+    }
+
+    #[inline]
+    fn list_end_len(&mut self) -> usize {
+        self.counted(|s, w| s.end(w))
+    }
+
+    #[inline]
+    fn set_begin_len(&mut self, _identifier: TSetIdentifier) -> usize {
+        self.counted(|s, w| s.array_begin(w))
+    }
+
+    #[inline]
+    fn set_end_len(&mut self) -> usize {
+        self.counted(|s, w| s.end(w))
+    }
+
+    #[inline]
+    fn map_begin_len(&mut self, identifier: TMapIdentifier) -> usize {
+        self.counted(|s, w| s.map_begin(w, identifier.key_type))
+    }
+
+    #[inline]
+    fn map_end_len(&mut self) -> usize {
+        self.counted(|s, w| s.end(w))
+    }
+}
+
+impl<B: WriteExt + BufMut> TOutputProtocol for TSimpleJSONProtocol<&mut B> {
+    type BufMut = B;
+
+    #[inline]
+    fn write_message_begin(
+        &mut self,
+        identifier: &TMessageIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.scopes.message_begin(self.trans, identifier);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_message_end(&mut self) -> Result<(), ThriftException> {
+        self.scopes.end(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_struct_begin(
+        &mut self,
+        _identifier: &TStructIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.scopes.struct_begin(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_struct_end(&mut self) -> Result<(), ThriftException> {
+        self.scopes.end(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_field_names(&mut self, names: &'static [(i16, &'static str)]) {
+        self.scopes.set_field_names(names);
+    }
+
+    #[inline]
+    fn write_field_begin(&mut self, _field_type: TType, id: i16) -> Result<(), ThriftException> {
+        self.scopes.field_begin(self.trans, id);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_field_end(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_field_stop(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bool(&mut self, b: bool) -> Result<(), ThriftException> {
+        self.scopes
+            .scalar(self.trans, if b { b"true" } else { b"false" });
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        self.scopes.binary(self.trans, &b);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bytes_without_len(&mut self, _b: Bytes) -> Result<(), ThriftException> {
+        // raw unknown fields, see the module docs
+        Ok(())
+    }
+
+    #[inline]
+    fn write_uuid(&mut self, u: [u8; 16]) -> Result<(), ThriftException> {
+        self.scopes.uuid(self.trans, u);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_bytes_vec(&mut self, b: &[u8]) -> Result<(), ThriftException> {
+        self.scopes.binary(self.trans, b);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_byte(&mut self, b: u8) -> Result<(), ThriftException> {
+        self.scopes.integer(self.trans, b as i64);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) -> Result<(), ThriftException> {
+        self.scopes.integer(self.trans, i as i64);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) -> Result<(), ThriftException> {
+        self.scopes.integer(self.trans, i as i64);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) -> Result<(), ThriftException> {
+        self.scopes.integer(self.trans, i as i64);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) -> Result<(), ThriftException> {
+        self.scopes.integer(self.trans, i);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
+        self.scopes.double(self.trans, d);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_string(&mut self, s: &str) -> Result<(), ThriftException> {
+        self.scopes.string(self.trans, s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_faststr(&mut self, s: FastStr) -> Result<(), ThriftException> {
+        self.scopes.string(self.trans, &s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_list_begin(&mut self, _identifier: TListIdentifier) -> Result<(), ThriftException> {
+        self.scopes.array_begin(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_list_end(&mut self) -> Result<(), ThriftException> {
+        self.scopes.end(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_set_begin(&mut self, _identifier: TSetIdentifier) -> Result<(), ThriftException> {
+        self.scopes.array_begin(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_set_end(&mut self) -> Result<(), ThriftException> {
+        self.scopes.end(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_map_begin(&mut self, identifier: TMapIdentifier) -> Result<(), ThriftException> {
+        self.scopes.map_begin(self.trans, identifier.key_type);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_map_end(&mut self) -> Result<(), ThriftException> {
+        self.scopes.end(self.trans);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), ThriftException> {
+        Ok(())
+    }
+
+    #[inline]
+    fn buf_mut(&mut self) -> &mut Self::BufMut {
+        self.trans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Bytes, BytesMut};
+    use faststr::FastStr;
+
+    use super::TSimpleJSONProtocol;
+    use crate::{
+        AHashMap,
+        thrift::{
+            Message, TAsyncInputProtocol, TInputProtocol, TLengthProtocol, TLengthProtocolExt,
+            TMessageIdentifier, TMessageType, TOutputProtocol, TOutputProtocolExt, TType,
+            ThriftException, VOID_IDENT,
+        },
+    };
+
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    struct Shape {
+        name: FastStr,
+        points: Vec<Point>,
+        data: Bytes,
+        scale: f64,
+        tags: AHashMap<i64, bool>,
+        anchors: Vec<(Point, FastStr)>,
+        id: [u8; 16],
+    }
+
+    impl Point {
+        const FIELD_NAMES: &'static [(i16, &'static str)] = &[(1, "x"), (2, "y")];
+    }
+
+    impl Message for Point {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_struct_begin(&VOID_IDENT)?;
+            protocol.write_field_names(Self::FIELD_NAMES);
+            protocol.write_i32_field(1, self.x)?;
+            protocol.write_i32_field(2, self.y)?;
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()
+        }
+
+        fn decode<T: TInputProtocol>(_protocol: &mut T) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(
+            _protocol: &mut T,
+        ) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+            protocol.struct_begin_len(&VOID_IDENT)
+                + protocol.field_names_len(Self::FIELD_NAMES)
+                + protocol.i32_field_len(Some(1), self.x)
+                + protocol.i32_field_len(Some(2), self.y)
+                + protocol.field_stop_len()
+                + protocol.struct_end_len()
+        }
+    }
+
+    // without field names
+    impl Message for Shape {
+        fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+            protocol.write_struct_begin(&VOID_IDENT)?;
+            protocol.write_faststr_field(1, self.name.clone())?;
+            protocol.write_list_field(2, TType::Struct, &self.points, |p, point| {
+                p.write_struct(point)
+            })?;
+            protocol.write_bytes_field(3, self.data.clone())?;
+            protocol.write_double_field(4, self.scale)?;
+            protocol.write_map_field(
+                5,
+                TType::I64,
+                TType::Bool,
+                &self.tags,
+                |p, k| p.write_i64(*k),
+                |p, v| p.write_bool(*v),
+            )?;
+            protocol.write_field_begin(TType::Map, 6)?;
+            protocol.write_map_begin(crate::thrift::TMapIdentifier::new(
+                TType::Struct,
+                TType::Binary,
+                self.anchors.len(),
+            ))?;
+            for (k, v) in &self.anchors {
+                protocol.write_struct(k)?;
+                protocol.write_faststr(v.clone())?;
+            }
+            protocol.write_map_end()?;
+            protocol.write_field_end()?;
+            protocol.write_uuid_field(7, self.id)?;
+            protocol.write_field_stop()?;
+            protocol.write_struct_end()
+        }
+
+        fn decode<T: TInputProtocol>(_protocol: &mut T) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        async fn decode_async<T: TAsyncInputProtocol>(
+            _protocol: &mut T,
+        ) -> Result<Self, ThriftException> {
+            unreachable!()
+        }
+
+        fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+            protocol.struct_begin_len(&VOID_IDENT)
+                + protocol.faststr_field_len(Some(1), &self.name)
+                + protocol.list_field_len(Some(2), TType::Struct, &self.points, |p, point| {
+                    p.struct_len(point)
+                })
+                + protocol.bytes_field_len(Some(3), &self.data)
+                + protocol.double_field_len(Some(4), self.scale)
+                + protocol.map_field_len(
+                    Some(5),
+                    TType::I64,
+                    TType::Bool,
+                    &self.tags,
+                    |p, k| p.i64_len(*k),
+                    |p, v| p.bool_len(*v),
+                )
+                + protocol.field_begin_len(TType::Map, Some(6))
+                + protocol.map_begin_len(crate::thrift::TMapIdentifier::new(
+                    TType::Struct,
+                    TType::Binary,
+                    self.anchors.len(),
+                ))
+                + self
+                    .anchors
+                    .iter()
+                    .map(|(k, v)| protocol.struct_len(k) + protocol.faststr_len(v))
+                    .sum::<usize>()
+                + protocol.map_end_len()
+                + protocol.field_end_len()
+                + protocol.uuid_field_len(Some(7), self.id)
+                + protocol.field_stop_len()
+                + protocol.struct_end_len()
+        }
+    }
+
+    fn to_json<M: Message>(message: &M) -> String {
+        let mut buf = BytesMut::new();
+        let mut protocol = TSimpleJSONProtocol::new(&mut buf);
+        message.encode(&mut protocol).unwrap();
+        let size = message.size(&mut TSimpleJSONProtocol::new(()));
+        assert_eq!(size, buf.len());
+        String::from_utf8(buf.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_simple_json() {
+        let shape = Shape {
+            name: "tri\"angle\"\n\u{1}".into(),
+            points: vec![Point { x: 0, y: -1 }, Point { x: 10, y: 20 }],
+            data: Bytes::from_static(b"hello"),
+            scale: 0.5,
+            tags: AHashMap::from_iter([(7, true)]),
+            anchors: vec![(Point { x: 1, y: 2 }, "a".into())],
+            id: [
+                0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+                0xe0, 0xc8,
+            ],
+        };
+        let json = to_json(&shape);
+        assert_eq!(
+            json,
+            r#"{"1":"tri\"angle\"\n\u0001","2":[{"x":0,"y":-1},{"x":10,"y":20}],"3":"aGVsbG8=","4":0.5,"5":{"7":true},"6":[[{"x":1,"y":2},"a"]],"7":"67e55044-10b1-426f-9247-bb680e5fe0c8"}"#
+        );
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["2"][1]["y"], 20);
+    }
+
+    #[test]
+    fn test_scalars() {
+        struct Scalars(Vec<f64>, Vec<Bytes>);
+
+        impl Message for Scalars {
+            fn encode<T: TOutputProtocol>(&self, protocol: &mut T) -> Result<(), ThriftException> {
+                protocol.write_message_begin(&TMessageIdentifier::new(
+                    "m".into(),
+                    TMessageType::Call,
+                    3,
+                ))?;
+                protocol.write_list_field(1, TType::Double, &self.0, |p, d| p.write_double(*d))?;
+                protocol
+                    .write_list_field(2, TType::Binary, &self.1, |p, b| p.write_bytes(b.clone()))?;
+                protocol.write_message_end()
+            }
+
+            fn decode<T: TInputProtocol>(_protocol: &mut T) -> Result<Self, ThriftException> {
+                unreachable!()
+            }
+
+            async fn decode_async<T: TAsyncInputProtocol>(
+                _protocol: &mut T,
+            ) -> Result<Self, ThriftException> {
+                unreachable!()
+            }
+
+            fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize {
+                protocol.message_begin_len(&TMessageIdentifier::new(
+                    "m".into(),
+                    TMessageType::Call,
+                    3,
+                )) + protocol
+                    .list_field_len(Some(1), TType::Double, &self.0, |p, d| p.double_len(*d))
+                    + protocol
+                        .list_field_len(Some(2), TType::Binary, &self.1, |p, b| p.bytes_len(b))
+                    + protocol.message_end_len()
+            }
+        }
+
+        let json = to_json(&Scalars(
+            vec![f64::NAN, f64::NEG_INFINITY, 0.0, -1.5, 1e300, 2.5e-10],
+            ["", "f", "fo", "foo", "foob"]
+                .into_iter()
+                .map(|s| Bytes::from_static(s.as_bytes()))
+                .collect(),
+        ));
+        assert_eq!(
+            json,
+            r#"["m",1,3,["NaN","-Infinity",0,-1.5,1e300,2.5e-10],["","Zg==","Zm8=","Zm9v","Zm9vYg=="]]"#
+        );
+    }
+}
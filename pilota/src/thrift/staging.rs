@@ -0,0 +1,136 @@
+//! Write combining of small writes.
+//!
+//! Structs made mostly of small fields turn into many writes of a few bytes
+//! each into the transport. [`Staged`] collects them in an array on the
+//! stack and copies them into the transport at once when it is full:
+//!
+//! ```
+//! use pilota::thrift::{TOutputProtocol, binary::TBinaryProtocol, staging::Staged};
+//!
+//! let mut buf = pilota::BytesMut::new();
+//! let mut staged = Staged::new(&mut buf);
+//! let mut protocol = TBinaryProtocol::new(&mut staged, false);
+//! protocol.write_i32(1).unwrap();
+//! protocol.flush().unwrap();
+//! drop(staged);
+//! assert_eq!(&buf[..], &[0, 0, 0, 1]);
+//! ```
+
+use bytes::{BufMut, buf::UninitSlice};
+
+use super::rw_ext::WriteExt;
+
+/// The bytes held back by [`Staged`] before they are copied into the
+/// transport.
+pub const STAGING_LEN: usize = 64;
+
+/// A transport that combines the small writes into it, see the
+/// [module](self) docs.
+///
+/// The staged bytes are written out by the `flush` of the protocols, by
+/// [`Staged::flush`] and when it is dropped. Writes of [`STAGING_LEN`]
+/// bytes or more go to the transport directly.
+pub struct Staged<B: BufMut> {
+    trans: B,
+    buf: [u8; STAGING_LEN],
+    len: usize,
+}
+
+impl<B: BufMut> Staged<B> {
+    #[inline]
+    pub fn new(trans: B) -> Self {
+        Self {
+            trans,
+            buf: [0; STAGING_LEN],
+            len: 0,
+        }
+    }
+
+    /// Copies the staged bytes into the transport.
+    #[inline]
+    pub fn flush(&mut self) {
+        if self.len > 0 {
+            self.trans.put_slice(&self.buf[..self.len]);
+            self.len = 0;
+        }
+    }
+}
+
+impl<B: BufMut> WriteExt for Staged<B> {
+    #[inline]
+    fn write_slice(&mut self, src: &[u8]) {
+        if src.len() > STAGING_LEN - self.len {
+            self.flush();
+            if src.len() >= STAGING_LEN {
+                self.trans.put_slice(src);
+                return;
+            }
+        }
+        self.buf[self.len..self.len + src.len()].copy_from_slice(src);
+        self.len += src.len();
+    }
+
+    #[inline]
+    fn flush_staged(&mut self) -> std::io::Result<()> {
+        self.flush();
+        Ok(())
+    }
+}
+
+// SAFETY: the staged bytes are flushed before handing out the chunk of the
+// transport, so what is written there comes after them.
+unsafe impl<B: BufMut> BufMut for Staged<B> {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        self.trans.remaining_mut().saturating_sub(self.len)
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        unsafe { self.trans.advance_mut(cnt) }
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        self.flush();
+        self.trans.chunk_mut()
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.write_slice(src);
+    }
+}
+
+impl<B: BufMut> Drop for Staged<B> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+
+    use super::*;
+
+    #[test]
+    fn test_staged() {
+        let mut buf = BytesMut::new();
+        let mut staged = Staged::new(&mut buf);
+        staged.write_i32(1);
+        staged.write_slice(&[2; STAGING_LEN - 4]);
+        staged.write_u8(3);
+        staged.write_slice(&[4; STAGING_LEN]);
+        staged.put_u16(5);
+        drop(staged);
+
+        let mut expected = BytesMut::new();
+        expected.write_i32(1);
+        expected.write_slice(&[2; STAGING_LEN - 4]);
+        expected.write_u8(3);
+        expected.write_slice(&[4; STAGING_LEN]);
+        expected.put_u16(5);
+        assert_eq!(buf, expected);
+    }
+}
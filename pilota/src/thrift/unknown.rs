@@ -1,7 +1,9 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, sync::Arc};
 
 use bytes::Bytes;
 
+use super::{TFieldIdentifier, TType};
+
 const DEFAULT_DEQUE_SIZE: usize = 16;
 
 #[derive(
@@ -43,3 +45,58 @@ impl Default for BytesVec {
         Self::new()
     }
 }
+
+/// A field the generated decode skipped, not knowing its id or its type, as
+/// passed to the hook set with `set_unknown_field_hook` on the input
+/// protocols to notice a peer sending a newer schema. Only reported by the
+/// code generated with `unknown_field_hook` on the builder.
+///
+/// ```
+/// use std::sync::{
+///     Arc,
+///     atomic::{AtomicU64, Ordering},
+/// };
+///
+/// use pilota::{Bytes, thrift::binary::TBinaryProtocol};
+///
+/// let unknown = Arc::new(AtomicU64::new(0));
+/// let mut bytes = Bytes::new();
+/// let mut protocol = TBinaryProtocol::new(&mut bytes, false);
+/// protocol.set_unknown_field_hook({
+///     let unknown = unknown.clone();
+///     move |field| {
+///         eprintln!("{} sent unknown field {:?}", field.struct_name, field.field_id);
+///         unknown.fetch_add(1, Ordering::Relaxed);
+///     }
+/// });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownField {
+    /// The struct or union being decoded.
+    pub struct_name: &'static str,
+    pub field_id: Option<i16>,
+    pub field_type: TType,
+    /// Whether `field_id` is one of the struct, sent with another type than
+    /// the declared one, rather than an id the struct doesn't have.
+    pub type_mismatch: bool,
+}
+
+pub(crate) type UnknownFieldHook = Arc<dyn Fn(UnknownField) + Send + Sync>;
+
+/// Passes the skipped `field` of `struct_name` to the hook, if any.
+#[inline]
+pub(crate) fn report(
+    hook: &Option<UnknownFieldHook>,
+    struct_name: &'static str,
+    field: &TFieldIdentifier,
+    type_mismatch: bool,
+) {
+    if let Some(hook) = hook {
+        hook(UnknownField {
+            struct_name,
+            field_id: field.id,
+            field_type: field.field_type,
+            type_mismatch,
+        });
+    }
+}
@@ -0,0 +1,217 @@
+//! Encoding into a [`std::io::Write`].
+//!
+//! [`IoWriter`] lets the protocols encode straight into a file or a socket:
+//! the writes into it are buffered and written out when the buffer is full,
+//! so a message is never held in memory as a whole.
+//!
+//! ```
+//! use pilota::thrift::{TOutputProtocol, binary::TBinaryProtocol, writer::IoWriter};
+//!
+//! let mut writer = IoWriter::new(Vec::new());
+//! let mut protocol = TBinaryProtocol::new(&mut writer, false);
+//! protocol.write_i32(1).unwrap();
+//! protocol.flush().unwrap();
+//! assert_eq!(writer.get_ref(), &[0, 0, 0, 1]);
+//! ```
+
+use std::io::{self, Write};
+
+use bytes::{BufMut, buf::UninitSlice};
+
+use super::rw_ext::WriteExt;
+
+/// The size of the buffer of [`IoWriter::new`].
+pub const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// A transport writing into a [`Write`], see the [module](self) docs.
+///
+/// The buffered bytes are written out by the `flush` of the protocols, which
+/// also flushes the writer, by [`IoWriter::flush`] and when it is dropped.
+/// Writes can't fail on their own, so the first error of the writer is kept
+/// and returned by the next flush. The stream has then lost bytes: what is
+/// written after it is dropped, and every later flush fails too.
+pub struct IoWriter<W: Write> {
+    // only taken by `into_inner`
+    writer: Option<W>,
+    buf: Vec<u8>,
+    error: Option<io::Error>,
+    failed: bool,
+}
+
+impl<W: Write> IoWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self::with_capacity(writer, DEFAULT_CAPACITY)
+    }
+
+    pub fn with_capacity(writer: W, capacity: usize) -> Self {
+        Self {
+            writer: Some(writer),
+            buf: Vec::with_capacity(capacity.max(1)),
+            error: None,
+            failed: false,
+        }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        self.writer.as_ref().unwrap()
+    }
+
+    /// Writes out the buffered bytes and flushes the writer. Returns the
+    /// error of the writer the first time, and fails on every later call.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.write_buf();
+        if !self.failed {
+            if let Err(e) = self.writer.as_mut().unwrap().flush() {
+                self.fail(e);
+            }
+        }
+        if !self.failed {
+            return Ok(());
+        }
+        Err(self
+            .error
+            .take()
+            .unwrap_or_else(|| io::Error::other("an earlier write failed")))
+    }
+
+    /// Flushes and returns the writer, failing if any write failed.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush()?;
+        Ok(self.writer.take().unwrap())
+    }
+
+    #[inline]
+    fn fail(&mut self, e: io::Error) {
+        self.error = Some(e);
+        self.failed = true;
+    }
+
+    #[inline]
+    fn write_buf(&mut self) {
+        if !self.buf.is_empty() {
+            self.write_all(0..self.buf.len());
+            self.buf.clear();
+        }
+    }
+
+    #[inline]
+    fn write_all(&mut self, range: std::ops::Range<usize>) {
+        if !self.failed {
+            if let Err(e) = self.writer.as_mut().unwrap().write_all(&self.buf[range]) {
+                self.fail(e);
+            }
+        }
+    }
+}
+
+impl<W: Write> WriteExt for IoWriter<W> {
+    #[inline]
+    fn write_slice(&mut self, src: &[u8]) {
+        if src.len() > self.buf.capacity() - self.buf.len() {
+            self.write_buf();
+            if src.len() >= self.buf.capacity() {
+                if !self.failed {
+                    if let Err(e) = self.writer.as_mut().unwrap().write_all(src) {
+                        self.fail(e);
+                    }
+                }
+                return;
+            }
+        }
+        self.buf.extend_from_slice(src);
+    }
+
+    #[inline]
+    fn flush_staged(&mut self) -> io::Result<()> {
+        self.flush()
+    }
+}
+
+// SAFETY: the chunk handed out is the spare capacity of the buffer, and
+// `advance_mut` only counts what was written into it.
+unsafe impl<W: Write> BufMut for IoWriter<W> {
+    #[inline]
+    fn remaining_mut(&self) -> usize {
+        isize::MAX as usize - self.buf.len()
+    }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let len = self.buf.len() + cnt;
+        assert!(len <= self.buf.capacity(), "advance out of bounds");
+        unsafe { self.buf.set_len(len) }
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.buf.len() == self.buf.capacity() {
+            self.write_buf();
+        }
+        UninitSlice::uninit(self.buf.spare_capacity_mut())
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) {
+        self.write_slice(src);
+    }
+}
+
+impl<W: Write> Drop for IoWriter<W> {
+    fn drop(&mut self) {
+        if self.writer.is_some() {
+            self.write_buf();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+
+    use super::*;
+    use crate::thrift::{
+        TOutputProtocol, ThriftException, binary::TBinaryProtocol, compact::TCompactOutputProtocol,
+    };
+
+    fn write<T: TOutputProtocol>(protocol: &mut T) -> Result<(), ThriftException> {
+        protocol.write_i32(7)?;
+        protocol.write_faststr("name".into())?;
+        protocol.write_bytes_vec(&[1; 40])?;
+        protocol.buf_mut().put_u16(2);
+        protocol.write_double(1.5)?;
+        protocol.flush()
+    }
+
+    #[test]
+    fn test_io_writer() {
+        let mut expected = BytesMut::new();
+        write(&mut TBinaryProtocol::new(&mut expected, false)).unwrap();
+
+        for capacity in [1, 8, DEFAULT_CAPACITY] {
+            let mut writer = IoWriter::with_capacity(Vec::new(), capacity);
+            write(&mut TBinaryProtocol::new(&mut writer, false)).unwrap();
+            assert_eq!(writer.into_inner().unwrap(), expected);
+        }
+
+        let mut expected = BytesMut::new();
+        write(&mut TCompactOutputProtocol::new(&mut expected, false)).unwrap();
+        let mut writer = IoWriter::new(Vec::new());
+        write(&mut TCompactOutputProtocol::new(&mut writer, false)).unwrap();
+        assert_eq!(writer.get_ref(), &expected);
+    }
+
+    #[test]
+    fn test_io_writer_error() {
+        let mut buf = [0; 8];
+        let mut writer = IoWriter::with_capacity(&mut buf[..], 4);
+        let err = write(&mut TBinaryProtocol::new(&mut writer, false)).unwrap_err();
+        assert!(matches!(err, ThriftException::Transport(_)));
+        // the stream lost bytes, so it stays failed
+        assert!(writer.flush().is_err());
+        let mut protocol = TBinaryProtocol::new(&mut writer, false);
+        protocol.write_i32(1).unwrap();
+        assert!(protocol.flush().is_err());
+        assert!(writer.into_inner().is_err());
+        assert_eq!(buf, [0, 0, 0, 7, 0, 0, 0, 4]);
+    }
+}